@@ -0,0 +1,158 @@
+-- Consumes the events published by the router when it is built with the `kafka_events` feature.
+-- Every topic is read through a Kafka engine table, from which a materialized view writes the
+-- events into a table that keeps the latest version of each resource. The payment attempts are
+-- written into the table of config/clickhouse/payment_attempts.sql, the analytics are then
+-- computed from the events, without the router recording the attempts itself.
+--
+-- The timestamps of the events are ISO 8601 strings, and are parsed by the materialized views.
+
+CREATE TABLE IF NOT EXISTS payment_attempt_events_queue
+(
+    `attempt_id` String,
+    `payment_id` String,
+    `merchant_id` String,
+    `status` LowCardinality(String),
+    `amount` Int64,
+    `currency` LowCardinality(Nullable(String)),
+    `connector` LowCardinality(Nullable(String)),
+    `payment_method` LowCardinality(Nullable(String)),
+    `created_at` String,
+    `modified_at` String,
+    `schema_version` UInt16
+)
+ENGINE = Kafka
+SETTINGS kafka_broker_list = 'localhost:9092',
+    kafka_topic_list = 'hyperswitch-payment-attempt-events',
+    kafka_group_name = 'hyperswitch-clickhouse',
+    kafka_format = 'JSONEachRow',
+    input_format_skip_unknown_fields = 1;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS payment_attempt_events_mv TO payment_attempts AS
+SELECT
+    attempt_id,
+    payment_id,
+    merchant_id,
+    status,
+    amount,
+    currency,
+    connector,
+    payment_method,
+    parseDateTime64BestEffort(created_at, 3) AS created_at,
+    parseDateTime64BestEffort(modified_at, 3) AS modified_at
+FROM payment_attempt_events_queue
+WHERE schema_version = 1;
+
+CREATE TABLE IF NOT EXISTS payment_intents
+(
+    `payment_id` String,
+    `merchant_id` String,
+    `status` LowCardinality(String),
+    `amount` Int64,
+    `currency` LowCardinality(Nullable(String)),
+    `amount_captured` Nullable(Int64),
+    `customer_id` Nullable(String),
+    `connector_id` Nullable(String),
+    `profile_id` Nullable(String),
+    `created_at` DateTime64(3),
+    `modified_at` DateTime64(3)
+)
+ENGINE = ReplacingMergeTree(modified_at)
+PARTITION BY toStartOfMonth(created_at)
+ORDER BY (merchant_id, created_at, payment_id);
+
+CREATE TABLE IF NOT EXISTS payment_intent_events_queue
+(
+    `payment_id` String,
+    `merchant_id` String,
+    `status` LowCardinality(String),
+    `amount` Int64,
+    `currency` LowCardinality(Nullable(String)),
+    `amount_captured` Nullable(Int64),
+    `customer_id` Nullable(String),
+    `connector_id` Nullable(String),
+    `profile_id` Nullable(String),
+    `created_at` String,
+    `modified_at` String,
+    `schema_version` UInt16
+)
+ENGINE = Kafka
+SETTINGS kafka_broker_list = 'localhost:9092',
+    kafka_topic_list = 'hyperswitch-payment-intent-events',
+    kafka_group_name = 'hyperswitch-clickhouse',
+    kafka_format = 'JSONEachRow',
+    input_format_skip_unknown_fields = 1;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS payment_intent_events_mv TO payment_intents AS
+SELECT
+    payment_id,
+    merchant_id,
+    status,
+    amount,
+    currency,
+    amount_captured,
+    customer_id,
+    connector_id,
+    profile_id,
+    parseDateTime64BestEffort(created_at, 3) AS created_at,
+    parseDateTime64BestEffort(modified_at, 3) AS modified_at
+FROM payment_intent_events_queue
+WHERE schema_version = 1;
+
+CREATE TABLE IF NOT EXISTS refunds
+(
+    `refund_id` String,
+    `payment_id` String,
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `refund_status` LowCardinality(String),
+    `refund_type` LowCardinality(String),
+    `total_amount` Int64,
+    `refund_amount` Int64,
+    `currency` LowCardinality(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
+    `created_at` DateTime64(3),
+    `modified_at` DateTime64(3)
+)
+ENGINE = ReplacingMergeTree(modified_at)
+PARTITION BY toStartOfMonth(created_at)
+ORDER BY (merchant_id, created_at, refund_id);
+
+CREATE TABLE IF NOT EXISTS refund_events_queue
+(
+    `refund_id` String,
+    `payment_id` String,
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `refund_status` LowCardinality(String),
+    `refund_type` LowCardinality(String),
+    `total_amount` Int64,
+    `refund_amount` Int64,
+    `currency` LowCardinality(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
+    `created_at` String,
+    `modified_at` String,
+    `schema_version` UInt16
+)
+ENGINE = Kafka
+SETTINGS kafka_broker_list = 'localhost:9092',
+    kafka_topic_list = 'hyperswitch-refund-events',
+    kafka_group_name = 'hyperswitch-clickhouse',
+    kafka_format = 'JSONEachRow',
+    input_format_skip_unknown_fields = 1;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS refund_events_mv TO refunds AS
+SELECT
+    refund_id,
+    payment_id,
+    merchant_id,
+    connector,
+    refund_status,
+    refund_type,
+    total_amount,
+    refund_amount,
+    currency,
+    refund_reason_code,
+    parseDateTime64BestEffort(created_at, 3) AS created_at,
+    parseDateTime64BestEffort(modified_at, 3) AS modified_at
+FROM refund_events_queue
+WHERE schema_version = 1;
@@ -0,0 +1,19 @@
+-- Payment attempts recorded by the router when ClickHouse is enabled, from which the analytics of
+-- the merchants are computed. Every update of an attempt is recorded as a new row, and the latest
+-- one is kept when the parts of the table are merged.
+CREATE TABLE IF NOT EXISTS payment_attempts
+(
+    `attempt_id` String,
+    `payment_id` String,
+    `merchant_id` String,
+    `status` LowCardinality(String),
+    `amount` Int64,
+    `currency` LowCardinality(Nullable(String)),
+    `connector` LowCardinality(Nullable(String)),
+    `payment_method` LowCardinality(Nullable(String)),
+    `created_at` DateTime64(3),
+    `modified_at` DateTime64(3)
+)
+ENGINE = ReplacingMergeTree(modified_at)
+PARTITION BY toStartOfMonth(created_at)
+ORDER BY (merchant_id, created_at, attempt_id);
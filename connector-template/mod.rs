@@ -35,6 +35,7 @@ impl api::PaymentVoid for {{project-name | downcase | pascal_case}} {}
 impl api::Refund for {{project-name | downcase | pascal_case}} {}
 impl api::RefundExecute for {{project-name | downcase | pascal_case}} {}
 impl api::RefundSync for {{project-name | downcase | pascal_case}} {}
+impl api::RefundCredit for {{project-name | downcase | pascal_case}} {}
 
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for {{project-name | downcase | pascal_case}} 
 where
@@ -69,6 +70,9 @@ impl ConnectorCommon for {{project-name | downcase | pascal_case}} {
     }
 }
 
+//TODO: Declare the payment methods, currencies, countries, capture methods and refund window supported by the connector
+impl api::ConnectorSpecifications for {{project-name | downcase | pascal_case}} {}
+
 impl
     ConnectorIntegration<
         api::Session,
@@ -409,6 +413,64 @@ impl
     }
 }
 
+impl api::Payouts for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutCreate for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::PayoutFulfill for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::PayoutCancel for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::PayoutSync for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::ConnectorMandateRevoke for {{project-name | downcase | pascal_case}} {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl
+    ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::Dispute for {{project-name | downcase | pascal_case}} {}
+impl api::SubmitEvidence for {{project-name | downcase | pascal_case}} {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for {{project-name | downcase | pascal_case}}
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for {{project-name | downcase | pascal_case}} {
     fn get_webhook_object_reference_id(
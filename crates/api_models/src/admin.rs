@@ -1,13 +1,14 @@
 use common_utils::pii;
 use masking::{Secret, StrongSecret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url;
 use utoipa::ToSchema;
 
 use super::payments::AddressDetails;
-use crate::{enums as api_enums, payment_methods};
+use crate::{enums as api_enums, payment_methods, routing};
 
-#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CreateMerchantAccount {
     /// The identifier for the Merchant Account
@@ -66,6 +67,35 @@ pub struct CreateMerchantAccount {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// What to do with authorizations that are about to expire without being captured
+    pub authorization_expiry_policy: Option<AuthorizationExpiryPolicy>,
+
+    /// Exemptions from strong customer authentication to request for the card payments of the merchant
+    pub sca_exemption_policy: Option<ScaExemptionPolicy>,
+
+    /// Whether the merchant can issue credits not tied to a prior payment, and up to which amount. Credits are disabled unless enabled here.
+    pub credit_policy: Option<CreditPolicy>,
+
+    /// The fraud check connector payments are screened with, and when they are screened. Payments are not screened unless set.
+    pub fraud_check_policy: Option<FraudCheckPolicy>,
+
+    /// How many payments a card can attempt in an hour. Attempts are not limited unless set.
+    pub velocity_policy: Option<VelocityPolicy>,
+
+    /// Whether payments likely duplicating an earlier payment are declined or only flagged. Duplicate payments are not detected unless set.
+    pub duplicate_payment_policy: Option<DuplicatePaymentPolicy>,
+
+    /// The results of the address and security code verification of cards on which authorized payments are voided. Payments are not voided on these results unless set.
+    pub card_verification_policy: Option<CardVerificationPolicy>,
+
+    /// A boolean value to indicate if the payments of the merchant are simulated by the dummy connector instead of being processed by its connectors, so that the merchant can integrate without the credentials of a connector. The outcome of a simulated payment is picked with test card numbers and amounts. By default, its value is false.
+    #[schema(default = false, example = true)]
+    pub test_mode: Option<bool>,
+
+    /// The organization the merchant account belongs to. A new organization is created for the merchant account if not provided. Cannot be changed once the merchant account is created.
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -129,6 +159,42 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// What to do with authorizations that are about to expire without being captured
+    #[schema(value_type = Option<AuthorizationExpiryPolicy>)]
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+
+    /// Exemptions from strong customer authentication to request for the card payments of the merchant
+    #[schema(value_type = Option<ScaExemptionPolicy>)]
+    pub sca_exemption_policy: Option<serde_json::Value>,
+
+    /// Whether the merchant can issue credits not tied to a prior payment, and up to which amount
+    #[schema(value_type = Option<CreditPolicy>)]
+    pub credit_policy: Option<serde_json::Value>,
+
+    /// The fraud check connector payments are screened with, and when they are screened
+    #[schema(value_type = Option<FraudCheckPolicy>)]
+    pub fraud_check_policy: Option<serde_json::Value>,
+
+    /// How many payments a card can attempt in an hour
+    #[schema(value_type = Option<VelocityPolicy>)]
+    pub velocity_policy: Option<serde_json::Value>,
+
+    /// Whether payments likely duplicating an earlier payment are declined or only flagged
+    #[schema(value_type = Option<DuplicatePaymentPolicy>)]
+    pub duplicate_payment_policy: Option<serde_json::Value>,
+
+    /// The results of the address and security code verification of cards on which authorized payments are voided
+    #[schema(value_type = Option<CardVerificationPolicy>)]
+    pub card_verification_policy: Option<serde_json::Value>,
+
+    /// A boolean value to indicate if the payments of the merchant are simulated by the dummy connector
+    #[schema(default = false, example = true)]
+    pub test_mode: bool,
+
+    /// The organization the merchant account belongs to
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub organization_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -177,6 +243,7 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    RuleBased(routing::RoutingConfig),
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -211,6 +278,121 @@ pub struct WebhookDetails {
     pub payment_failed_enabled: Option<bool>,
 }
 
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorizationExpiryPolicy {
+    /// The action taken on an authorization that is about to expire without being captured
+    #[schema(value_type = AuthorizationExpiryAction, example = "void")]
+    pub action: api_enums::AuthorizationExpiryAction,
+
+    /// How long before the authorization expires the action is taken, in seconds. Defaults to an hour.
+    #[schema(example = 3600)]
+    pub notice_period_in_secs: Option<i64>,
+}
+
+/// Exemptions are only requested for the cards issued in the countries where strong customer
+/// authentication is mandated, and never for payments authenticated with 3DS on request.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScaExemptionPolicy {
+    /// Payments up to this amount, in the lowest denomination of their currency, are requested a low value exemption
+    #[schema(example = 3000)]
+    pub low_value_threshold: Option<i64>,
+
+    /// Payments up to this amount, in the lowest denomination of their currency, are requested a transaction risk analysis exemption. The threshold must be within the one allowed by the fraud rate of the acquirer.
+    #[schema(example = 25000)]
+    pub transaction_risk_analysis_threshold: Option<i64>,
+
+    /// Whether the issuers delegated the authentication of customers to the merchant, in which case a delegated authentication exemption is requested for the payments not qualifying for another exemption
+    #[schema(default = false, example = false)]
+    pub delegated_authentication: Option<bool>,
+
+    /// Whether the payments soft declined by the issuer for want of authentication are submitted again with a 3DS challenge. Defaults to true.
+    #[schema(default = true, example = true)]
+    pub challenge_on_soft_decline: Option<bool>,
+}
+
+/// Credits send funds to a payment method without a payment to refund, and so are only enabled
+/// for merchants trusted not to misuse them.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreditPolicy {
+    /// Whether the merchant can issue credits
+    #[schema(default = false, example = true)]
+    pub enabled: bool,
+
+    /// The highest amount of a single credit, in the lowest denomination of its currency
+    #[schema(example = 5000)]
+    pub max_amount: Option<i64>,
+}
+
+/// Payments are screened with the fraud check connector before or after they are authorized. The
+/// credentials of the connector are those of the merchant connector account created for it.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FraudCheckPolicy {
+    /// The fraud check connector payments are screened with
+    #[schema(value_type = FrmConnectors, example = "signifyd")]
+    pub connector: api_enums::FrmConnectors,
+
+    /// Whether payments are screened before or after they are authorized
+    #[schema(value_type = FraudCheckType, default = "pre_auth", example = "pre_auth")]
+    #[serde(default)]
+    pub check_type: api_enums::FraudCheckType,
+}
+
+/// Payments confirmed with a card which attempted more payments in the last hour than allowed are
+/// declined without reaching the connector
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityPolicy {
+    /// The number of payments a card can attempt in an hour
+    #[schema(example = 5)]
+    pub max_attempts_per_card_per_hour: u32,
+}
+
+/// A payment is likely a duplicate when it is confirmed with the card and for the amount of a
+/// payment confirmed earlier in the window. The client can confirm a payment anyway with
+/// `allow_duplicate`.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DuplicatePaymentPolicy {
+    /// The number of seconds after a payment in which payments with its card and amount are
+    /// likely duplicates
+    #[schema(default = 600, example = 600)]
+    #[serde(default = "DuplicatePaymentPolicy::default_window_in_secs")]
+    pub window_in_secs: u32,
+
+    /// Whether likely duplicates are declined or flagged in the payment response
+    #[schema(value_type = DuplicatePaymentAction, default = "warn", example = "warn")]
+    #[serde(default)]
+    pub action: api_enums::DuplicatePaymentAction,
+}
+
+impl DuplicatePaymentPolicy {
+    fn default_window_in_secs() -> u32 {
+        600
+    }
+}
+
+/// Payments authorized with an address or security code verification result listed here are
+/// voided right after their authorization. Payments captured along with their authorization are
+/// not voided, so the policy is best used with manual capture.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CardVerificationPolicy {
+    /// The address verification results payments are voided on. Voiding on `address_match` and
+    /// `no_match` voids payments whose postal code does not match.
+    #[schema(value_type = Vec<AvsResult>, example = json!(["address_match", "no_match"]))]
+    #[serde(default)]
+    pub void_on_avs_results: Vec<api_enums::AvsResult>,
+
+    /// The security code verification results payments are voided on
+    #[schema(value_type = Vec<CvvResult>, example = json!(["no_match"]))]
+    #[serde(default)]
+    pub void_on_cvv_results: Vec<api_enums::CvvResult>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct DeleteMerchantAccountResponse {
     /// The identifier for the Merchant Account
@@ -288,6 +470,18 @@ pub struct PaymentConnectorCreate {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// Details with which the webhooks sent by the connector are verified
+    pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
+}
+
+/// Secret shared with the connector, with which the source of its webhooks is verified
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorWebhookDetails {
+    /// The secret the connector signs its webhooks with, or the `username:password` pair of
+    /// connectors which authenticate their webhooks with basic auth
+    #[schema(value_type = String, example = "12345678900987654321")]
+    pub merchant_secret: Secret<String>,
 }
 
 /// Details of all the payment methods enabled for the connector for the given merchant account
@@ -339,6 +533,16 @@ pub struct DeleteMcaResponse {
     pub deleted: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantConnectorKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Unique IDs of the connectors whose credentials were re-encrypted with the current key
+    #[schema(example = json!(["mca_5apGeP94tMts6rg3U3kR"]))]
+    pub merchant_connector_ids: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVResponse {
     /// The identifier for the Merchant Account
@@ -355,3 +559,148 @@ pub struct ToggleKVRequest {
     #[schema(example = true)]
     pub kv_enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToggleConnectorPayloadsRequest {
+    /// Whether the payloads exchanged with the connectors are captured for the merchant
+    #[schema(example = true)]
+    pub capture_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToggleConnectorPayloadsResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Whether the payloads exchanged with the connectors are captured for the merchant
+    #[schema(example = true)]
+    pub capture_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorPayloadsResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The identifier for the Merchant Account
+    pub merchant_id: String,
+    /// The identifier for the payment attempt the payloads were exchanged for
+    pub attempt_id: String,
+    /// The connector the payloads were exchanged with
+    pub connector: Option<String>,
+    /// The last request sent to the connector for the attempt, with card details, credentials and
+    /// other sensitive values masked
+    #[schema(value_type = Option<Object>)]
+    pub request: Option<serde_json::Value>,
+    /// The response of the connector to the last request, masked the same way as the request
+    #[schema(value_type = Option<Object>)]
+    pub response: Option<serde_json::Value>,
+    /// Time at which the attempt was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationCreateRequest {
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Group")]
+    pub org_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    /// The identifier for the organization
+    #[schema(max_length = 64, example = "org_q98uSGAYbjEwqs0mJwnz")]
+    pub org_id: String,
+
+    /// Name of the organization
+    #[schema(max_length = 255, example = "NewAge Group")]
+    pub org_name: Option<String>,
+
+    /// The identifiers of the merchant accounts of the organization
+    #[schema(example = json!(["y3oqhf46pyzuxjbcn2giaqnb44"]))]
+    pub merchant_ids: Vec<String>,
+
+    /// The time at which the organization was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// Create or update a business profile of the merchant account. The payments made for a business profile are routed and notified with the routing algorithm and webhook details of the profile, falling back to those of the merchant account.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileCreate {
+    /// Name of the business profile, unique for the merchant account. Defaults to the business country and label of the profile.
+    #[schema(max_length = 64, example = "US_food")]
+    pub profile_name: Option<String>,
+
+    /// The country of the business, as an ISO 3166-1 alpha-2 country code
+    #[schema(max_length = 2, example = "US")]
+    pub business_country: Option<String>,
+
+    /// The label of the business within the country
+    #[schema(max_length = 64, example = "food")]
+    pub business_label: Option<String>,
+
+    /// Webhook related details for the payments made for the business profile
+    pub webhook_details: Option<WebhookDetails>,
+
+    /// The routing algorithm for the payments made for the business profile
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BusinessProfileResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: String,
+
+    /// Name of the business profile
+    #[schema(max_length = 64, example = "US_food")]
+    pub profile_name: String,
+
+    /// The country of the business, as an ISO 3166-1 alpha-2 country code
+    #[schema(max_length = 2, example = "US")]
+    pub business_country: Option<String>,
+
+    /// The label of the business within the country
+    #[schema(max_length = 64, example = "food")]
+    pub business_label: Option<String>,
+
+    /// Webhook related details for the payments made for the business profile
+    #[schema(value_type = Option<WebhookDetails>)]
+    pub webhook_details: Option<serde_json::Value>,
+
+    /// The routing algorithm for the payments made for the business profile
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+
+    /// The time at which the business profile was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The time at which the business profile was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DeleteBusinessProfileResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: String,
+    /// If the business profile is deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
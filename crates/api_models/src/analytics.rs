@@ -0,0 +1,133 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The period of time the payments are aggregated over
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyticsTimeRange {
+    /// Start of the period, the payments created at or after it are aggregated
+    #[schema(example = "2023-05-01T00:00:00Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// End of the period, the payments created before it are aggregated. Defaults to now.
+    #[schema(example = "2023-05-08T00:00:00Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+}
+
+/// Size of the time buckets the payments are aggregated in
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsGranularity {
+    Hour,
+    Day,
+    Week,
+    Month,
+}
+
+/// Field of the payments the aggregations are grouped by
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentAnalyticsDimension {
+    Connector,
+    PaymentMethod,
+    Currency,
+    Status,
+}
+
+/// Restricts the aggregations to the payments matching every non-empty filter
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaymentAnalyticsFilters {
+    /// The connectors the payments were made through
+    #[schema(value_type = Vec<Connector>)]
+    pub connector: Vec<api_enums::Connector>,
+    /// The payment methods the payments were made with
+    #[schema(value_type = Vec<PaymentMethod>)]
+    pub payment_method: Vec<api_enums::PaymentMethod>,
+    /// The currencies of the payments
+    #[schema(value_type = Vec<Currency>)]
+    pub currency: Vec<api_enums::Currency>,
+    /// The statuses of the payments
+    #[schema(value_type = Vec<AttemptStatus>)]
+    pub status: Vec<api_enums::AttemptStatus>,
+}
+
+/// Aggregations of the payment attempts of the merchant, by time bucket and group.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentAnalyticsRequest {
+    pub time_range: AnalyticsTimeRange,
+    /// Size of the time buckets. The whole time range is aggregated at once when not given.
+    #[schema(value_type = Option<AnalyticsGranularity>)]
+    pub granularity: Option<AnalyticsGranularity>,
+    /// Fields the aggregations are grouped by
+    #[schema(value_type = Vec<PaymentAnalyticsDimension>)]
+    #[serde(default)]
+    pub group_by: Vec<PaymentAnalyticsDimension>,
+    #[serde(default)]
+    pub filters: PaymentAnalyticsFilters,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PaymentAnalyticsBucket {
+    /// Start of the time bucket, when aggregated by granularity
+    #[schema(example = "2023-05-01T00:00:00Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub time_bucket: Option<PrimitiveDateTime>,
+    /// The connector of the group, when grouped by connector
+    pub connector: Option<String>,
+    /// The payment method of the group, when grouped by payment method
+    pub payment_method: Option<String>,
+    /// The currency of the group, when grouped by currency
+    pub currency: Option<String>,
+    /// The status of the group, when grouped by status
+    pub status: Option<String>,
+    /// The number of payment attempts made
+    pub payment_count: i64,
+    /// The number of payment attempts which were authorized or charged
+    pub success_count: i64,
+    /// Percentage of the payment attempts which were authorized or charged
+    #[schema(example = 92.5)]
+    pub success_rate: f64,
+    /// Total amount of the payment attempts which were authorized or charged, in the lowest
+    /// denomination of their currencies
+    pub total_amount: i64,
+    /// Average time between the creation of the payment attempts which were completed and their
+    /// last update, in milliseconds
+    pub avg_latency_ms: Option<f64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PaymentAnalyticsResponse {
+    /// The aggregations, by time bucket then group
+    pub query_data: Vec<PaymentAnalyticsBucket>,
+}
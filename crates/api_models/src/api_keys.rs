@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums::RoleName;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,12 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role of the API Key, which determines the operations it is permitted to perform.
+    /// Defaults to `admin`.
+    #[serde(default)]
+    #[schema(value_type = Option<RoleName>, example = "read_only")]
+    pub role: RoleName,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +68,10 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role of the API Key, which determines the operations it is permitted to perform.
+    #[schema(example = "read_only")]
+    pub role: RoleName,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +114,10 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role of the API Key, which determines the operations it is permitted to perform.
+    #[schema(example = "read_only")]
+    pub role: RoleName,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +145,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The role of the API Key, which determines the operations it is permitted to perform.
+    #[schema(example = "read_only")]
+    pub role: Option<RoleName>,
 }
 
 /// The response body for revoking an API Key.
@@ -0,0 +1,166 @@
+//! Versions of the API, which merchants pick with the `X-Api-Version` header of their requests.
+//! Responses are built in the schema of the latest version, and converted into the schema of the
+//! requested version when it is an earlier one, so that breaking changes to the shape of
+//! responses only reach merchants once they move to the version introducing them.
+
+use serde_json::{Map, Value};
+
+use crate::payments::{PaymentListResponse, PaymentSearchResponse, PaymentsResponse};
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::IntoStaticStr,
+)]
+pub enum ApiVersion {
+    /// The schema of the API before the fields added to payments in 2023-05-15
+    #[serde(rename = "2023-01-30")]
+    #[strum(serialize = "2023-01-30")]
+    V2023_01_30,
+    /// The latest version, used when requests do not specify one
+    #[default]
+    #[serde(rename = "2023-05-15")]
+    #[strum(serialize = "2023-05-15")]
+    V2023_05_15,
+}
+
+impl ApiVersion {
+    pub const LATEST: Self = Self::V2023_05_15;
+}
+
+/// Fields added to payments in 2023-05-15, which are left out of payments in earlier versions
+const PAYMENT_FIELDS_ADDED_IN_2023_05_15: &[&str] = &[
+    "captures",
+    "unified_code",
+    "unified_message",
+    "split_payments",
+    "forex_quote",
+    "profile_id",
+    "fraud_check",
+    "duplicate_of",
+    "avs_result",
+    "cvv_result",
+    "connector_metadata",
+];
+
+/// A response which can be serialized in the schema of any version of the API
+pub trait VersionedResponse: serde::Serialize {
+    /// Converts the response, serialized in the schema of the latest version, into the schema of
+    /// an earlier version
+    fn downgrade(value: &mut Value, version: ApiVersion);
+
+    fn to_versioned_value(&self, version: ApiVersion) -> serde_json::Result<Value> {
+        let mut value = serde_json::to_value(self)?;
+        if version < ApiVersion::LATEST {
+            Self::downgrade(&mut value, version);
+        }
+        Ok(value)
+    }
+}
+
+impl VersionedResponse for PaymentsResponse {
+    fn downgrade(value: &mut Value, version: ApiVersion) {
+        let payment = match value.as_object_mut() {
+            Some(payment) => payment,
+            None => return,
+        };
+
+        if version < ApiVersion::V2023_05_15 {
+            downgrade_payment_to_2023_01_30(payment);
+        }
+    }
+}
+
+fn downgrade_payment_to_2023_01_30(payment: &mut Map<String, Value>) {
+    for field in PAYMENT_FIELDS_ADDED_IN_2023_05_15 {
+        payment.remove(*field);
+    }
+
+    if let Some(next_action) = payment
+        .get_mut("next_action")
+        .and_then(Value::as_object_mut)
+    {
+        next_action.remove("qr_code_information");
+        // Payments verified with microdeposits redirected the customer to the verification page
+        // before the next action had its own type
+        if next_action.get("type").and_then(Value::as_str) == Some("verify_with_microdeposits") {
+            next_action.insert("type".to_string(), Value::from("redirect_to_url"));
+        }
+    }
+}
+
+fn downgrade_payments(value: &mut Value, version: ApiVersion) {
+    if let Some(payments) = value.get_mut("data").and_then(Value::as_array_mut) {
+        for payment in payments {
+            PaymentsResponse::downgrade(payment, version);
+        }
+    }
+}
+
+impl VersionedResponse for PaymentListResponse {
+    fn downgrade(value: &mut Value, version: ApiVersion) {
+        downgrade_payments(value, version);
+    }
+}
+
+impl VersionedResponse for PaymentSearchResponse {
+    fn downgrade(value: &mut Value, version: ApiVersion) {
+        downgrade_payments(value, version);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::payments::{NextAction, NextActionType};
+
+    #[test]
+    fn test_payment_response_downgrade() {
+        let response = PaymentsResponse {
+            payment_id: Some("pay_mbabizu24mvu3mela5njyhpit4".to_string()),
+            profile_id: Some("pro_abcdefghijklmnopqrst".to_string()),
+            duplicate_of: Some("pay_U42c409qyHwOkWo3vK60".to_string()),
+            next_action: Some(NextAction {
+                next_action_type: NextActionType::VerifyWithMicrodeposits,
+                redirect_to_url: Some("https://hyperswitch.io/verify".to_string()),
+                qr_code_information: None,
+            }),
+            ..Default::default()
+        };
+
+        let latest = response.to_versioned_value(ApiVersion::LATEST).unwrap();
+        assert_eq!(latest, serde_json::to_value(&response).unwrap());
+
+        let legacy = response
+            .to_versioned_value(ApiVersion::V2023_01_30)
+            .unwrap();
+        assert_eq!(legacy["payment_id"], "pay_mbabizu24mvu3mela5njyhpit4");
+        assert!(legacy.get("profile_id").is_none());
+        assert!(legacy.get("duplicate_of").is_none());
+        assert_eq!(legacy["next_action"]["type"], "redirect_to_url");
+        assert!(legacy["next_action"].get("qr_code_information").is_none());
+    }
+
+    #[test]
+    fn test_api_version_parsing() {
+        assert_eq!(
+            "2023-01-30".parse::<ApiVersion>().unwrap(),
+            ApiVersion::V2023_01_30
+        );
+        assert_eq!(ApiVersion::default(), ApiVersion::LATEST);
+        assert!("2023-02-30".parse::<ApiVersion>().is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The type of the resources that were changed
+    #[schema(value_type = Option<AuditResourceType>)]
+    pub resource_type: Option<api_enums::AuditResourceType>,
+    /// The identifier of the resource that was changed
+    pub resource_id: Option<String>,
+    /// The identifier of the API key or dashboard user that made the changes
+    pub actor_id: Option<String>,
+    /// Time less than the time at which the change was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, rename = "created.lt", with = "custom_serde::iso8601::option")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the time at which the change was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, rename = "created.gt", with = "custom_serde::iso8601::option")]
+    pub created_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier of the audit log entry
+    pub audit_log_id: String,
+    /// The identifier of the merchant the changed resource belongs to
+    pub merchant_id: String,
+    /// Who made the change
+    #[schema(value_type = AuditActorType)]
+    pub actor_type: api_enums::AuditActorType,
+    /// The identifier of the API key or dashboard user that made the change
+    pub actor_id: Option<String>,
+    /// The HTTP method of the API call that made the change
+    pub http_method: Option<String>,
+    /// The path of the API call that made the change
+    pub route: Option<String>,
+    /// The type of the resource that was changed
+    #[schema(value_type = AuditResourceType)]
+    pub resource_type: api_enums::AuditResourceType,
+    /// The identifier of the resource that was changed
+    pub resource_id: String,
+    /// The status of the resource before the change
+    pub previous_status: Option<String>,
+    /// The status of the resource after the change
+    pub new_status: Option<String>,
+    /// The time at which the change was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
@@ -0,0 +1,51 @@
+use common_utils::{custom_serde, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The data payments are blocked on, or no longer blocked on
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum BlocklistRequest {
+    /// A card number, of which only the fingerprint is stored
+    #[schema(value_type = String, example = "4242424242424242")]
+    CardNumber(Secret<String, pii::CardNumber>),
+    /// The fingerprint of a card, as returned when its number was blocked
+    CardFingerprint(String),
+    /// The first six digits of a card number
+    #[schema(example = "424242")]
+    CardBin(String),
+    /// The email of the customer
+    #[schema(value_type = String, example = "johntest@test.com")]
+    Email(Secret<String, pii::Email>),
+    /// The IP address of the customer
+    #[schema(value_type = String, example = "123.32.25.123")]
+    IpAddress(std::net::IpAddr),
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistListConstraints {
+    /// The kind of data of the listed entries
+    #[schema(value_type = BlocklistDataKind)]
+    pub data_kind: api_enums::BlocklistDataKind,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip, latest first
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct BlocklistResponse {
+    /// The blocked data. Cards are blocked on their fingerprint, and emails on their lowercase form.
+    pub fingerprint_id: String,
+    /// The kind of the blocked data
+    #[schema(value_type = BlocklistDataKind)]
+    pub data_kind: api_enums::BlocklistDataKind,
+    /// Time at which the data was blocked
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
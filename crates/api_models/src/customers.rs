@@ -3,12 +3,17 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
 /// The customer details
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
 pub struct CustomerRequest {
     /// The identifier for the customer object. If not provided the customer ID will be autogenerated.
     #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
-    #[serde(default = "generate_customer_id")]
+    #[serde(
+        default = "generate_customer_id",
+        skip_serializing_if = "String::is_empty"
+    )]
     pub customer_id: String,
     /// The identifier for the Merchant Account
     #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
@@ -49,7 +54,7 @@ pub struct CustomerRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct CustomerResponse {
     /// The identifier for the customer object. If not provided the customer ID will be autogenerated.
     #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
@@ -91,6 +96,9 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// The identifier of the payment method used by default for the customer
+    #[schema(max_length = 64, example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -114,6 +122,25 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct CustomerPaymentMethodId {
+    pub customer_id: String,
+    pub payment_method_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDefaultPaymentMethodResponse {
+    /// The identifier for the customer object
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifier of the payment method used by default for the customer
+    #[schema(max_length = 64, example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub default_payment_method_id: String,
+    /// The type of the default payment method
+    #[schema(value_type = PaymentMethodType, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
@@ -1 +1,140 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct DisputeResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount
+    pub amount: String,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// Stage of the dispute
+    #[schema(value_type = DisputeStage)]
+    pub dispute_stage: api_enums::DisputeStage,
+    /// Status of the dispute
+    #[schema(value_type = DisputeStatus)]
+    pub dispute_status: api_enums::DisputeStatus,
+    /// connector to which dispute is associated with
+    pub connector: String,
+    /// Status of the dispute sent by connector
+    pub connector_status: String,
+    /// Dispute id sent by connector
+    pub connector_dispute_id: String,
+    /// Reason of dispute sent by connector
+    pub connector_reason: Option<String>,
+    /// Reason code of dispute sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Evidence deadline of dispute sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Dispute created time sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    /// Dispute updated time sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub updated_at: Option<PrimitiveDateTime>,
+    /// Time at which dispute is received
+    #[serde(with = "custom_serde::iso8601")]
+    pub received_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// status of the dispute
+    #[schema(value_type = Option<DisputeStatus>)]
+    pub dispute_status: Option<api_enums::DisputeStatus>,
+    /// stage of the dispute
+    #[schema(value_type = Option<DisputeStage>)]
+    pub dispute_stage: Option<api_enums::DisputeStage>,
+    /// reason for the dispute
+    pub reason: Option<String>,
+    /// connector linked to dispute
+    pub connector: Option<String>,
+    /// The time at which dispute is received
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub received_time: Option<PrimitiveDateTime>,
+    /// Time less than the dispute received time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "received_time.lt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the dispute received time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "received_time.gt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_gt: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the dispute received time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "received_time.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the dispute received time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "received_time.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceRequest {
+    /// Dispute Id
+    pub dispute_id: String,
+    /// Any server or activity logs showing proof that the customer accessed or downloaded the purchased digital product
+    pub access_activity_log: Option<String>,
+    /// Billing address of the customer
+    pub billing_address: Option<String>,
+    /// Your refund or cancellation policy, as shown to the customer
+    pub cancellation_policy_disclosure: Option<String>,
+    /// An explanation of how and when the customer was shown your refund policy prior to purchase
+    pub cancellation_rebuttal: Option<String>,
+    /// The email address of the customer
+    pub customer_email_address: Option<String>,
+    /// The name of the customer
+    pub customer_name: Option<String>,
+    /// The IP address that the customer used when making the purchase
+    pub customer_purchase_ip: Option<String>,
+    /// A description of the product or service that was sold
+    pub product_description: Option<String>,
+    /// Your refund policy, as shown to the customer
+    pub refund_policy_disclosure: Option<String>,
+    /// An explanation of why the cardholder is not entitled to a refund
+    pub refund_refusal_explanation: Option<String>,
+    /// The date on which the customer received or began receiving the purchased service
+    pub service_date: Option<String>,
+    /// The address to which a physical product was shipped
+    pub shipping_address: Option<String>,
+    /// The delivery service that shipped a physical product
+    pub shipping_carrier: Option<String>,
+    /// The date on which a physical product began its route to the shipping address
+    pub shipping_date: Option<String>,
+    /// The tracking number for a physical product
+    pub shipping_tracking_number: Option<String>,
+    /// Any additional evidence or statements
+    pub uncategorized_text: Option<String>,
+}
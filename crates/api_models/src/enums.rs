@@ -11,32 +11,56 @@ use utoipa::ToSchema;
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum AttemptStatus {
+    /// The attempt has been created, and has not been sent to the connector yet
     Started,
+    /// The customer failed the authentication of the payment method, such as 3DS
     AuthenticationFailed,
+    /// The attempt was declined before it reached the connector
     RouterDeclined,
+    /// The customer has to authenticate the payment method, such as with 3DS
     AuthenticationPending,
+    /// The customer authenticated the payment method
     AuthenticationSuccessful,
+    /// The connector authorized the payment, and its funds have to be captured
     Authorized,
+    /// The connector declined the authorization of the payment
     AuthorizationFailed,
+    /// The payment has been authorized and captured
     Charged,
+    /// The connector is processing the authorization of the payment
     Authorizing,
+    /// The payment is to be collected on delivery
     CodInitiated,
+    /// The authorization of the payment was released
     Voided,
+    /// The release of the authorization is being processed by the connector
     VoidInitiated,
+    /// The capture of the funds is being processed by the connector
     CaptureInitiated,
+    /// The connector failed to capture the funds
     CaptureFailed,
+    /// The connector failed to release the authorization
     VoidFailed,
+    /// The payment was refunded automatically after it was charged
     AutoRefunded,
+    /// Part of the authorized amount has been captured
     PartialCharged,
+    /// The attempt is awaiting the response of the connector
     #[default]
     Pending,
+    /// The attempt failed at the connector
     Failure,
+    /// A payment method has to be attached to the payment for the attempt to proceed
     PaymentMethodAwaited,
+    /// The payment has to be confirmed for the attempt to proceed
     ConfirmationAwaited,
 }
 
@@ -51,6 +75,8 @@ pub enum AttemptStatus {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
     ToSchema,
 )]
@@ -70,11 +96,14 @@ pub enum AuthenticationType {
     Debug,
     Default,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
     ToSchema,
 )]
@@ -86,12 +115,359 @@ pub enum CaptureMethod {
     Automatic,
     /// The capture will happen only if the merchant triggers a Capture API request
     Manual,
-    /// The capture will happen only if the merchant triggers a Capture API request
+    /// The authorized amount can be captured in parts through multiple Capture API requests
     ManualMultiple,
     /// The capture can be scheduled to automatically get triggered at a specific date & time
     Scheduled,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    /// Capture request has been initiated
+    #[default]
+    Started,
+    /// Capture amount has been charged to the customer
+    Charged,
+    /// Capture is pending confirmation from the connector
+    Pending,
+    /// Capture request failed
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    /// Funds have been disbursed to the recipient
+    Success,
+    /// Payout was declined by the connector
+    Failed,
+    /// Payout was cancelled before the funds were disbursed
+    Cancelled,
+    /// Payout has been submitted and is awaiting an outcome from the connector
+    #[default]
+    Pending,
+    /// The recipient's card or account cannot receive payouts
+    Ineligible,
+}
+
+/// The role of a dashboard user or an API key, which determines the operations it is permitted
+/// to perform on the merchant account.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoleName {
+    /// Full access to the merchant account
+    #[default]
+    Admin,
+    /// Manages payments, refunds, disputes, customers, mandates and payouts
+    Operator,
+    /// Views all resources and manages the routing configuration
+    Developer,
+    /// Views all resources without modifying any of them
+    ReadOnly,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// The reason a refund is issued, for connectors accepting structured refund reasons
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundReason {
+    /// The customer was charged more than once for the same purchase
+    Duplicate,
+    /// The payment was not authorized by the holder of the payment method
+    Fraudulent,
+    /// The customer asked for the refund
+    RequestedByCustomer,
+    /// The customer returned the product
+    ProductReturned,
+    /// Any other reason, to be described by the free-form reason of the refund
+    Other,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The merchant's endpoint acknowledged the webhook with a 2xx response
+    Delivered,
+    /// The webhook could not be delivered or the merchant's endpoint responded with an error
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStage {
+    /// An inquiry or retrieval request raised by the issuer before a chargeback
+    PreDispute,
+    /// A chargeback raised by the customer's bank
+    #[default]
+    Dispute,
+    /// A second chargeback raised after the merchant has defended the first one
+    PreArbitration,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    /// The dispute has been raised and requires a response from the merchant
+    #[default]
+    DisputeOpened,
+    /// The window to respond to the dispute has passed
+    DisputeExpired,
+    /// The merchant has accepted the dispute
+    DisputeAccepted,
+    /// The dispute was withdrawn by the customer or the issuer
+    DisputeCancelled,
+    /// The merchant has submitted evidence to challenge the dispute
+    DisputeChallenged,
+    /// The dispute was resolved in the merchant's favour
+    DisputeWon,
+    /// The dispute was resolved in the customer's favour
+    DisputeLost,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum UnifiedCode {
+    /// The account of the card has insufficient funds
+    #[strum(message = "The card has insufficient funds to complete the payment")]
+    InsufficientFunds,
+    /// The issuer declined the payment without giving a reason
+    #[strum(message = "The payment was declined by the issuer of the card")]
+    DoNotHonor,
+    /// The card has expired
+    #[strum(message = "The card has expired")]
+    ExpiredCard,
+    /// The security code of the card is incorrect
+    #[strum(message = "The security code of the card is incorrect")]
+    IncorrectCvc,
+    /// The number of the card is incorrect
+    #[strum(message = "The card number is incorrect")]
+    InvalidCardNumber,
+    /// The expiry date of the card is incorrect
+    #[strum(message = "The expiry date of the card is incorrect")]
+    InvalidExpiryDate,
+    /// The card has been reported lost
+    #[strum(message = "The payment was declined by the issuer of the card")]
+    LostCard,
+    /// The card has been reported stolen
+    #[strum(message = "The payment was declined by the issuer of the card")]
+    StolenCard,
+    /// The issuer requested the card to be retained
+    #[strum(message = "The payment was declined by the issuer of the card")]
+    PickupCard,
+    /// The card can't be used for this kind of payment, such as an online or cross border payment
+    #[strum(message = "The card can't be used for this payment")]
+    RestrictedCard,
+    /// The card brand or type isn't supported by the connector or the merchant
+    #[strum(message = "The card isn't supported")]
+    CardNotSupported,
+    /// The issuer doesn't allow the payment for this card holder or merchant
+    #[strum(message = "The payment isn't allowed for this card")]
+    TransactionNotAllowed,
+    /// The amount or frequency limit of the card has been exceeded
+    #[strum(message = "The card has exceeded its spending limit")]
+    LimitExceeded,
+    /// The payment was declined as suspected fraud, by the issuer or the risk checks of the connector
+    #[strum(message = "The payment was declined by the issuer of the card")]
+    FraudSuspected,
+    /// The issuer requires the customer to authenticate the payment, with 3DS for example
+    #[strum(message = "The payment requires authentication")]
+    AuthenticationRequired,
+    /// The customer failed to authenticate the payment
+    #[strum(message = "The authentication of the payment failed")]
+    AuthenticationFailed,
+    /// The amount of the payment is invalid for the connector or the issuer
+    #[strum(message = "The amount of the payment is invalid")]
+    InvalidAmount,
+    /// A payment with the same details was recently made
+    #[strum(message = "The payment is a duplicate of a recent payment")]
+    DuplicateTransaction,
+    /// The issuer couldn't be reached to authorize the payment
+    #[strum(message = "The issuer of the card is unavailable, please try again")]
+    IssuerUnavailable,
+    /// An error occurred while the connector or the issuer processed the payment
+    #[strum(message = "An error occurred while processing the payment, please try again")]
+    ProcessingError,
+    /// The payment was declined for a reason that has no specific code
+    #[default]
+    #[strum(message = "The payment was declined")]
+    GenericDecline,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -100,6 +476,8 @@ pub enum CaptureMethod {
     PartialEq,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     serde::Deserialize,
     serde::Serialize,
     frunk::LabelledGeneric,
@@ -248,6 +626,275 @@ pub enum Currency {
     ZAR,
 }
 
+impl Currency {
+    /// The three-digit ISO 4217 numeric code of the currency
+    pub fn iso_4217_numeric_code(&self) -> &'static str {
+        match self {
+            Self::AED => "784",
+            Self::ALL => "008",
+            Self::AMD => "051",
+            Self::ANG => "532",
+            Self::ARS => "032",
+            Self::AUD => "036",
+            Self::AWG => "533",
+            Self::AZN => "944",
+            Self::BBD => "052",
+            Self::BDT => "050",
+            Self::BHD => "048",
+            Self::BMD => "060",
+            Self::BND => "096",
+            Self::BOB => "068",
+            Self::BRL => "986",
+            Self::BSD => "044",
+            Self::BWP => "072",
+            Self::BZD => "084",
+            Self::CAD => "124",
+            Self::CHF => "756",
+            Self::CNY => "156",
+            Self::COP => "170",
+            Self::CRC => "188",
+            Self::CUP => "192",
+            Self::CZK => "203",
+            Self::DKK => "208",
+            Self::DOP => "214",
+            Self::DZD => "012",
+            Self::EGP => "818",
+            Self::ETB => "230",
+            Self::EUR => "978",
+            Self::FJD => "242",
+            Self::GBP => "826",
+            Self::GHS => "936",
+            Self::GIP => "292",
+            Self::GMD => "270",
+            Self::GTQ => "320",
+            Self::GYD => "328",
+            Self::HKD => "344",
+            Self::HNL => "340",
+            Self::HRK => "191",
+            Self::HTG => "332",
+            Self::HUF => "348",
+            Self::IDR => "360",
+            Self::ILS => "376",
+            Self::INR => "356",
+            Self::JMD => "388",
+            Self::JOD => "400",
+            Self::JPY => "392",
+            Self::KES => "404",
+            Self::KGS => "417",
+            Self::KHR => "116",
+            Self::KRW => "410",
+            Self::KWD => "414",
+            Self::KYD => "136",
+            Self::KZT => "398",
+            Self::LAK => "418",
+            Self::LBP => "422",
+            Self::LKR => "144",
+            Self::LRD => "430",
+            Self::LSL => "426",
+            Self::MAD => "504",
+            Self::MDL => "498",
+            Self::MKD => "807",
+            Self::MMK => "104",
+            Self::MNT => "496",
+            Self::MOP => "446",
+            Self::MUR => "480",
+            Self::MVR => "462",
+            Self::MWK => "454",
+            Self::MXN => "484",
+            Self::MYR => "458",
+            Self::NAD => "516",
+            Self::NGN => "566",
+            Self::NIO => "558",
+            Self::NOK => "578",
+            Self::NPR => "524",
+            Self::NZD => "554",
+            Self::OMR => "512",
+            Self::PEN => "604",
+            Self::PGK => "598",
+            Self::PHP => "608",
+            Self::PKR => "586",
+            Self::PLN => "985",
+            Self::QAR => "634",
+            Self::RUB => "643",
+            Self::SAR => "682",
+            Self::SCR => "690",
+            Self::SEK => "752",
+            Self::SGD => "702",
+            Self::SLL => "694",
+            Self::SOS => "706",
+            Self::SSP => "728",
+            Self::SVC => "222",
+            Self::SZL => "748",
+            Self::THB => "764",
+            Self::TTD => "780",
+            Self::TWD => "901",
+            Self::TZS => "834",
+            Self::USD => "840",
+            Self::UYU => "858",
+            Self::UZS => "860",
+            Self::YER => "886",
+            Self::ZAR => "710",
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentCaptureSucceeded,
+    PaymentCaptureFailed,
+    PaymentAuthorizationExpiring,
+    PaymentAuthorizationExpired,
+    RefundSucceeded,
+    RefundFailed,
+    RefundUpdated,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    MandateActive,
+    MandateRevoked,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    ApiKey,
+    PublishableKey,
+    User,
+    Admin,
+    System,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    Payment,
+    Refund,
+    MerchantAccount,
+    MerchantConnectorAccount,
+    BusinessProfile,
+    RoutingConfig,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventObjectType {
+    PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    MandateDetails,
+}
+
+/// How the settlement report of a connector is obtained
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconReportSource {
+    /// Uploaded by the merchant
+    Csv,
+    /// Downloaded from the SFTP server of the connector
+    Sftp,
+    /// Downloaded from the reporting API of the connector
+    Api,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconReportStatus {
+    /// The report is yet to be reconciled
+    Pending,
+    /// The records of the report are being matched to payments and refunds
+    Processing,
+    /// Every record of the report was matched or flagged
+    Completed,
+    /// The report could not be obtained or read
+    Failed,
+}
+
+/// The kind of transaction a record of a settlement report settles
 #[derive(
     Clone,
     Copy,
@@ -259,13 +906,44 @@ pub enum Currency {
     strum::Display,
     strum::EnumString,
     frunk::LabelledGeneric,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum EventType {
-    PaymentSucceeded,
-    RefundSucceeded,
-    RefundFailed,
+pub enum ReconRecordType {
+    Payment,
+    Refund,
+}
+
+/// The outcome of matching a record of a settlement report to a payment or refund
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconStatus {
+    /// The record is yet to be matched
+    Pending,
+    /// The record agrees with the payment or refund it settles
+    Matched,
+    /// The amount settled differs from the amount of the payment or refund
+    AmountMismatch,
+    /// The currency settled differs from the currency of the payment or refund
+    CurrencyMismatch,
+    /// The payment or refund did not succeed, although the connector settled it
+    StatusMismatch,
+    /// No payment or refund has the connector transaction id of the record
+    NotFound,
 }
 
 #[derive(
@@ -280,19 +958,29 @@ pub enum EventType {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum IntentStatus {
+    /// The payment has been authorized and captured
     Succeeded,
+    /// The payment failed, and can be attempted again with a new payment
     Failed,
+    /// The payment was cancelled before it was captured
     Cancelled,
+    /// The payment is being processed by the connector
     Processing,
+    /// The customer has to complete an action, such as 3DS authentication, for the payment to proceed
     RequiresCustomerAction,
+    /// A payment method has to be attached to the payment
     RequiresPaymentMethod,
+    /// The payment has to be confirmed
     #[default]
     RequiresConfirmation,
+    /// The payment has been authorized, and its funds have to be captured
     RequiresCapture,
 }
 
@@ -307,14 +995,18 @@ pub enum IntentStatus {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
     ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum FutureUsage {
+    /// The payment method is saved to be charged without the customer being present
     #[default]
     OffSession,
+    /// The payment method is saved to be charged while the customer is present
     OnSession,
 }
 
@@ -407,6 +1099,22 @@ pub enum PaymentMethodType {
     GooglePay,
     ApplePay,
     Paypal,
+    Paytm,
+    AliPay,
+    WeChatPay,
+    Pix,
+    Boleto,
+    Oxxo,
+    Paysafecard,
+    UpiCollect,
+    UpiIntent,
+    Blik,
+    Trustly,
+    Przelewy24,
+    ClickToPay,
+    Ach,
+    Sepa,
+    CryptoCurrency,
 }
 
 #[derive(
@@ -432,6 +1140,11 @@ pub enum PaymentMethod {
     PayLater,
     Wallet,
     BankRedirect,
+    BankTransfer,
+    Voucher,
+    Upi,
+    BankDebit,
+    Crypto,
 }
 
 #[derive(
@@ -513,16 +1226,22 @@ pub enum RoutingAlgorithm {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
     frunk::LabelledGeneric,
     ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum MandateStatus {
+    /// Payments can be made with the mandate
     #[default]
     Active,
+    /// Payments can no longer be made with the mandate, as it has ended or failed
     Inactive,
+    /// The mandate is being set up with the connector
     Pending,
+    /// The mandate was revoked by the merchant or the customer
     Revoked,
 }
 
@@ -545,12 +1264,16 @@ pub enum MandateStatus {
 pub enum Connector {
     Aci,
     Adyen,
+    Affirm,
+    AfterpayClearpay,
     Airwallex,
+    Alipay,
     Applepay,
     Authorizedotnet,
     Bluesnap,
     Braintree,
     Checkout,
+    Cryptopay,
     Cybersource,
     #[default]
     Dummy,
@@ -558,20 +1281,34 @@ pub enum Connector {
     Dlocal,
     Fiserv,
     Globalpay,
+    Gocardless,
     Klarna,
+    Mercadopago,
+    Mollie,
     Multisafepay,
     Nuvei,
+    Paypal,
+    Paysafe,
+    Paytm,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
     Stripe,
+    Trustly,
+    Twocheckout,
+    Wechatpay,
+    Wise,
     Worldline,
     Worldpay,
 }
 
 impl Connector {
     pub fn supports_access_token(&self) -> bool {
-        matches!(self, Self::Airwallex | Self::Globalpay | Self::Payu)
+        matches!(
+            self,
+            Self::Airwallex | Self::Globalpay | Self::Paypal | Self::Payu
+        )
     }
 }
 
@@ -592,22 +1329,37 @@ impl Connector {
 pub enum RoutableConnectors {
     Aci,
     Adyen,
+    Affirm,
+    AfterpayClearpay,
     Airwallex,
+    Alipay,
     Authorizedotnet,
     Bambora,
     Bluesnap,
     Braintree,
     Checkout,
+    Cryptopay,
     Cybersource,
     Dlocal,
     Fiserv,
     Globalpay,
+    Gocardless,
     Klarna,
+    Mercadopago,
+    Mollie,
     Nuvei,
+    Paypal,
+    Paysafe,
+    Paytm,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
     Stripe,
+    Trustly,
+    Twocheckout,
+    Wechatpay,
+    Wise,
     Worldline,
     Worldpay,
     Multisafepay,
@@ -621,6 +1373,7 @@ pub enum SupportedWallets {
     ApplePay,
     Klarna,
     Gpay,
+    ClickToPay,
 }
 
 #[derive(
@@ -695,6 +1448,25 @@ pub enum BankNames {
     VolksbankGruppe,
     VolkskreditbankAg,
     VrBankBraunau,
+    BankMillennium,
+    BankNowySa,
+    BankPekaoSa,
+    BankiSpoldzielcze,
+    BlikPsp,
+    BnpParibasPoland,
+    CreditAgricole,
+    ETransferPocztowy24,
+    MBank,
+    PayWithAliorBank,
+    PayWithBos,
+    PayWithCitiHandlowy,
+    PayWithIng,
+    PayWithInteligo,
+    PayWithPlusBank,
+    PlaceZipko,
+    SantanderPrzelew24,
+    ToyotaBank,
+    VeloBank,
 }
 
 #[derive(
@@ -724,6 +1496,329 @@ pub enum CardNetwork {
     Maestro,
 }
 
+/// The funding type of a card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardType {
+    /// The card draws on a line of credit
+    Credit,
+    /// The card draws on the funds of a bank account
+    Debit,
+    /// The card draws on funds loaded onto it in advance
+    Prepaid,
+}
+
+/// The type of a bank account debited in a bank debit payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BankAccountType {
+    #[default]
+    Checking,
+    Savings,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayerDocumentType {
+    /// Brazilian individual taxpayer registry number
+    Cpf,
+    /// Brazilian company taxpayer registry number
+    Cnpj,
+    /// Mexican unique population registry code
+    Curp,
+    /// Mexican federal taxpayer registry number
+    Rfc,
+    /// Argentinian and Peruvian national identity document
+    Dni,
+    /// Argentinian unique tax identification code
+    Cuit,
+    /// Chilean national tax number
+    Rut,
+    /// Colombian citizenship card
+    Cc,
+    /// Uruguayan and Bolivian identity card
+    Ci,
+    Passport,
+    Other,
+}
+
+/// The action taken on an authorization that is about to expire without being captured
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// Void the authorization, releasing the funds held on the customer's account
+    Void,
+    /// Send a `payment_authorization_expiring` webhook, leaving the authorization as is
+    Notify,
+}
+
+/// An exemption from strong customer authentication requested from the issuer of a card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::EnumMessage,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScaExemptionType {
+    /// The amount of the payment is below the low value threshold of the regulation
+    LowValue,
+    /// The acquirer assessed the payment as low risk through transaction risk analysis
+    TransactionRiskAnalysis,
+    /// The customer was authenticated by the merchant, to whom the issuer delegated authentication
+    DelegatedAuthentication,
+}
+
+/// Fraud and risk management connectors, which screen payments without processing them. Their
+/// credentials are configured as merchant connector accounts of the `payment_vas` type.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmConnectors {
+    Signifyd,
+    Riskified,
+}
+
+/// When payments are screened by the fraud check connector of the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckType {
+    /// Before the payment is authorized, so that fraudulent payments are never authorized
+    #[default]
+    PreAuth,
+    /// Once the payment is authorized, with the result of the authorization sent for review
+    PostAuth,
+}
+
+/// The decision of the fraud check connector on a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStatus {
+    /// The payment is under review, the decision is sent later through a webhook
+    Pending,
+    /// The payment was held for a manual review, the decision is sent later through a webhook
+    ManualReview,
+    /// The payment is legitimate
+    Legit,
+    /// The payment can only go on once the customer is authenticated with 3DS
+    ThreeDsRequired,
+    /// The payment is fraudulent. It is not authorized when screened before authorization, and its
+    /// authorization is voided when it is not yet captured otherwise.
+    Fraud,
+}
+
+/// The kind of data a blocklist entry blocks payments on
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistDataKind {
+    /// The fingerprint of a card, the card number itself is not stored
+    CardFingerprint,
+    /// The first six digits of a card number
+    CardBin,
+    /// The email of the customer
+    Email,
+    /// The IP address of the customer, as sent in the browser information of the payment
+    IpAddress,
+}
+
+/// The result of the address verification (AVS) of a card payment, normalized from the codes of
+/// the connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsResult {
+    /// Both the street address and the postal code match those on file with the issuer
+    Match,
+    /// The postal code matches, the street address does not
+    PostalCodeMatch,
+    /// The street address matches, the postal code does not
+    AddressMatch,
+    /// Neither the street address nor the postal code match
+    NoMatch,
+    /// The address was not verified, as the issuer does not support AVS, was unavailable, or no
+    /// address was given
+    Unavailable,
+}
+
+/// The result of the verification of the security code (CVV) of a card payment, normalized from
+/// the codes of the connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CvvResult {
+    /// The security code matches the one of the card
+    Match,
+    /// The security code does not match the one of the card
+    NoMatch,
+    /// The security code was not verified, as the issuer does not support it, was unavailable, or
+    /// no security code was given
+    Unavailable,
+}
+
+/// What is done with payments likely duplicating an earlier payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DuplicatePaymentAction {
+    /// The payment goes on, with the earlier payment it duplicates flagged in its response
+    #[default]
+    Warn,
+    /// The payment is declined without reaching the connector
+    Block,
+}
+
 impl From<AttemptStatus> for IntentStatus {
     fn from(s: AttemptStatus) -> Self {
         match s {
@@ -732,11 +1827,10 @@ impl From<AttemptStatus> for IntentStatus {
             AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
-            AttemptStatus::Authorized => Self::RequiresCapture,
+            AttemptStatus::Authorized | AttemptStatus::PartialCharged => Self::RequiresCapture,
             AttemptStatus::AuthenticationPending => Self::RequiresCustomerAction,
 
-            AttemptStatus::PartialCharged
-            | AttemptStatus::Started
+            AttemptStatus::Started
             | AttemptStatus::AuthenticationSuccessful
             | AttemptStatus::Authorizing
             | AttemptStatus::CodInitiated
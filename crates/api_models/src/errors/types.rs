@@ -82,6 +82,8 @@ pub enum ApiErrorResponse {
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
+    /// Carries the number of seconds after which the request can be retried
+    TooManyRequests(ApiError, u64),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -110,6 +112,7 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::TooManyRequests(i, _)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -125,7 +128,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::ConnectorError(_, _) => "connector",
         }
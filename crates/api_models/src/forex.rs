@@ -0,0 +1,55 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ForexQuoteRequest {
+    /// The three-letter ISO currency code the amount is presented in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub from_currency: api_enums::Currency,
+    /// The three-letter ISO currency code the amount is to be settled in
+    #[schema(value_type = Currency, example = "USD")]
+    pub to_currency: api_enums::Currency,
+    /// The amount to be converted, in the lowest denomination of `from_currency`
+    #[schema(example = 6540)]
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ForexQuoteResponse {
+    /// The three-letter ISO currency code the amount is presented in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub from_currency: api_enums::Currency,
+    /// The three-letter ISO currency code the amount is settled in
+    #[schema(value_type = Currency, example = "USD")]
+    pub to_currency: api_enums::Currency,
+    /// The number of units of `to_currency` one unit of `from_currency` is worth
+    #[schema(example = "1.0821")]
+    pub rate: String,
+    /// The amount in the lowest denomination of `from_currency`
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The converted amount in the lowest denomination of `to_currency`
+    #[schema(example = 7077)]
+    pub converted_amount: i64,
+    /// The provider the rate was obtained from
+    #[schema(value_type = ForexProvider, example = "fixed")]
+    pub provider: ForexProvider,
+    /// The time at which the rate was quoted
+    #[serde(with = "custom_serde::iso8601")]
+    pub quoted_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ForexProvider {
+    /// Rates from the fixed table in the application configuration
+    #[default]
+    Fixed,
+    /// Rates fetched from an external exchange rates API
+    External,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConnectorHealthResponse {
+    /// The name of the connector
+    pub connector: String,
+    /// The state of the circuit breaker of the connector
+    pub circuit_state: CircuitState,
+    /// The number of recent calls to the connector its health is computed from
+    pub calls: usize,
+    /// The fraction of the recent calls to the connector which failed
+    pub error_rate: f64,
+    /// The average latency of the recent calls to the connector, in milliseconds
+    pub average_latency_ms: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// The connector is healthy and payments are routed to it
+    #[default]
+    Closed,
+    /// The connector is degraded and skipped when routing payments
+    Open,
+    /// The connector was degraded and is being tried again, after which the circuit is closed if
+    /// the calls succeed or opened again if they fail
+    HalfOpen,
+}
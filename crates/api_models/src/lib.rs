@@ -1,16 +1,27 @@
 #![forbid(unsafe_code)]
 pub mod admin;
+pub mod analytics;
 pub mod api_keys;
+pub mod api_version;
+pub mod audit_logs;
 pub mod bank_accounts;
+pub mod blocklist;
 pub mod cards;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
 pub mod files;
+pub mod forex;
+pub mod health_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod token_migration;
+pub mod user;
+pub mod user_roles;
 pub mod webhooks;
@@ -139,6 +139,8 @@ pub struct PaymentMethodResponse {
 pub struct CardDetailFromLocker {
     pub scheme: Option<String>,
     pub issuer_country: Option<String>,
+    pub card_issuer: Option<String>,
+    pub card_type: Option<String>,
     pub last4_digits: Option<String>,
     #[serde(skip)]
     #[schema(value_type=Option<String>)]
@@ -470,6 +472,10 @@ pub struct CustomerPaymentMethod {
     #[schema(example = "7ebf443f-a050-4067-84e5-e6f6d4800aef")]
     pub payment_token: String,
 
+    /// The unique identifier of the payment method, which can be set as the customer's default
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: String,
+
     /// The unique identifier of the customer.
     #[schema(example = "cus_meowerunwiuwiwqw")]
     pub customer_id: String,
@@ -514,6 +520,10 @@ pub struct CustomerPaymentMethod {
     #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<time::PrimitiveDateTime>,
+
+    /// Indicates whether this is the payment method used by default for the customer
+    #[schema(example = true)]
+    pub default_payment_method_set: bool,
 }
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodId {
@@ -1,4 +1,7 @@
-use std::num::NonZeroI64;
+use std::{
+    collections::HashMap,
+    num::{NonZeroI64, NonZeroU8},
+};
 
 use common_utils::pii;
 use masking::{PeekInterface, Secret};
@@ -61,7 +64,7 @@ pub struct PaymentsRequest {
     #[schema(max_length = 255, example = "merchant_1668273825")]
     pub merchant_id: Option<String>,
 
-    /// The payment amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.,
+    /// The payment amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc., An amount of 0 verifies the payment method without charging it, at the connectors supporting zero amount authorizations
     #[schema(value_type = Option<u64>, example = 6540)]
     #[serde(default, deserialize_with = "amount::deserialize_option")]
     pub amount: Option<Amount>,
@@ -83,7 +86,8 @@ pub struct PaymentsRequest {
     pub amount_to_capture: Option<i64>,
 
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
-    /// Providing this field will automatically set `capture` to true
+    /// Required when the `capture_method` is `scheduled`, in which case the authorization is
+    /// captured automatically at that time, or voided if it can't be captured before it expires
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub capture_on: Option<PrimitiveDateTime>,
@@ -131,6 +135,16 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds", default = "three_ds")]
     pub authentication_type: Option<api_enums::AuthenticationType>,
 
+    /// Set to true to authenticate the card with 3DS through the external 3DS server of Hyperswitch
+    /// instead of the connector, which then authorizes the payment as already authenticated. Applies
+    /// to card payments with authentication_type three_ds.
+    #[schema(example = false)]
+    pub request_external_three_ds_authentication: Option<bool>,
+
+    /// Set to true to confirm the payment even though it is likely a duplicate of a payment made earlier with the same card and amount, as detected by the duplicate payment policy of the merchant
+    #[schema(example = false)]
+    pub allow_duplicate: Option<bool>,
+
     /// The payment method information provided for making a payment
     #[schema(example = "bank_transfer")]
     pub payment_method_data: Option<PaymentMethodData>,
@@ -143,22 +157,31 @@ pub struct PaymentsRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
 
+    /// The identifier of a payment method saved for the customer, to charge instead of payment_method_data. The card is fetched from the locker, so that the payment can be routed to any connector of the merchant rather than only the one which processed the card first. Off-session payments are made as merchant-initiated transactions referring to the payment the card was saved with, without the card_cvc
+    #[schema(example = "pm_b0rKLgmOkfi3n1SrzXqP")]
+    pub payment_method_id: Option<String>,
+
     /// This is used when payment is to be confirmed and the card is not saved
     #[schema(value_type = Option<String>)]
     pub card_cvc: Option<Secret<String>>,
 
+    /// The network to process the card on, for cards co-badged on several networks such as Cartes Bancaires and Visa. Defaults to the network of the card given in the payment method data
+    #[schema(value_type = Option<CardNetwork>, example = "CartesBancaires")]
+    pub card_network: Option<api_enums::CardNetwork>,
+
     /// The shipping address for the payment
     pub shipping: Option<Address>,
 
     /// The billing address for the payment
     pub billing: Option<Address>,
 
-    /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, maximum 22 characters.
-    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    /// The name of the merchant that appears on your customers’ statements, sent to the connectors which support dynamic descriptors. Must contain at least one letter and none of the characters `<`, `>`, `\`, `'`, `"` or `*`. Also accepted as `statement_descriptor_name`
+    #[schema(max_length = 22, example = "Hyperswitch Router")]
+    #[serde(alias = "statement_descriptor_name")]
     pub statement_descriptor: Option<String>,
 
-    /// Provides information about a card payment that customers see on their statements. Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor. Maximum 22 characters for the concatenated descriptor.
-    #[schema(max_length = 255, example = "Payment for shoes purchase")]
+    /// Appended to the statement descriptor as `<statement_descriptor>* <statement_descriptor_suffix>` to form the complete descriptor of the payment on your customers’ statements. The complete descriptor is limited to 25 characters on Visa, 20 characters on American Express and 22 characters on other card networks
+    #[schema(max_length = 22, example = "SHOES")]
     pub statement_descriptor_suffix: Option<String>,
 
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
@@ -175,6 +198,9 @@ pub struct PaymentsRequest {
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
 
+    /// The mandate or the saved payment method an off-session recurring payment is charged with, used instead of payment_method_data. Requires `off_session: true`. Payments on a mandate are processed by the connector the mandate was set up with
+    pub recurring_details: Option<RecurringDetails>,
+
     /// Additional details required by 3DS 2.0
     #[schema(value_type = Option<Object>, example = r#"{
         "user_agent": "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/70.0.3538.110 Safari/537.36",
@@ -196,6 +222,21 @@ pub struct PaymentsRequest {
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "google_pay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The identity document of the payer, required by some connectors for local payments (e.g. CPF in Brazil)
+    pub payer_document: Option<PayerDocument>,
+    /// Split the payment into installments, where supported by the connector
+    pub installments: Option<InstallmentsData>,
+    /// Collect an application fee on the payment and send the rest to a connected account, where supported by the connector
+    pub split_payments: Option<SplitPaymentsRequest>,
+    /// The three-letter ISO currency code the merchant settles in, when it differs from the currency the payment is presented in. The exchange rate applied is recorded with the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub settlement_currency: Option<api_enums::Currency>,
+    /// The business profile the payment is made for. The payment is routed and notified with the routing algorithm and webhook details of the profile, when set
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<String>,
+    /// Data for features of specific connectors, sent to the connector the payment is processed with
+    pub connector_metadata: Option<ConnectorMetadata>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -279,6 +320,9 @@ pub enum MandateTxnType {
 pub struct MandateIds {
     pub mandate_id: String,
     pub connector_mandate_id: Option<String>,
+    /// Identifier at the card network of the customer-initiated transaction the mandate was set up
+    /// with, referred to by the merchant-initiated transactions on the mandate
+    pub network_transaction_id: Option<String>,
 }
 
 impl MandateIds {
@@ -286,10 +330,21 @@ impl MandateIds {
         Self {
             mandate_id,
             connector_mandate_id: None,
+            network_transaction_id: None,
         }
     }
 }
 
+/// Reference to what an off-session recurring payment is charged with
+#[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RecurringDetails {
+    /// The identifier of an active mandate of the customer
+    MandateId(String),
+    /// The identifier of a payment method saved for the customer
+    PaymentMethodId(String),
+}
+
 #[derive(Default, Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MandateData {
@@ -384,6 +439,22 @@ pub struct Card {
     pub card_issuer: Option<String>,
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
+    /// The two-letter ISO country code of the card issuer, as identified by the card BIN
+    #[schema(example = "US")]
+    pub card_issuing_country: Option<String>,
+    /// The funding type of the card, as identified by the card BIN
+    #[schema(value_type = Option<CardType>, example = "credit")]
+    pub card_type: Option<api_enums::CardType>,
+}
+
+impl Card {
+    /// The last four digits of the card number, the only ones kept along with the payment
+    pub fn get_last4(&self) -> Option<String> {
+        let card_number = self.card_number.peek();
+        card_number
+            .get(card_number.len().saturating_sub(4)..)
+            .map(ToString::to_string)
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -403,7 +474,14 @@ pub enum PayLaterData {
         token: String,
     },
     /// For Affirm redirect as PayLater Option
-    AffirmRedirect {},
+    AffirmRedirect {
+        /// The Affirm financing program to offer, when the merchant has more than one configured
+        #[schema(example = "0_apr_12_months")]
+        financing_program: Option<String>,
+        /// The billing email, when it differs from the customer email
+        #[schema(value_type = Option<String>)]
+        billing_email: Option<Secret<String, pii::Email>>,
+    },
     /// For AfterpayClearpay redirect as PayLater Option
     AfterpayClearpayRedirect {
         /// The billing email
@@ -422,6 +500,11 @@ pub enum PaymentMethodData {
     Wallet(WalletData),
     PayLater(PayLaterData),
     BankRedirect(BankRedirectData),
+    BankTransfer(BankTransferData),
+    Voucher(VoucherData),
+    Upi(UpiData),
+    BankDebit(BankDebitData),
+    Crypto(CryptoData),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -430,12 +513,20 @@ pub enum AdditionalPaymentData {
     Card {
         card_issuer: Option<String>,
         card_network: Option<String>,
+        card_issuing_country: Option<String>,
+        card_type: Option<api_enums::CardType>,
+        last4: Option<String>,
     },
     BankRedirect {
         bank_name: Option<api_enums::BankNames>,
     },
     Wallet {},
     PayLater {},
+    BankTransfer {},
+    Voucher {},
+    Upi {},
+    BankDebit {},
+    Crypto {},
 }
 
 impl From<&PaymentMethodData> for AdditionalPaymentData {
@@ -447,6 +538,9 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
                     .card_network
                     .as_ref()
                     .map(|card_network| card_network.to_string()),
+                card_issuing_country: card_data.card_issuing_country.to_owned(),
+                card_type: card_data.card_type,
+                last4: card_data.get_last4(),
             },
             PaymentMethodData::BankRedirect(bank_redirect_data) => match bank_redirect_data {
                 BankRedirectData::Eps { bank_name, .. } => Self::BankRedirect {
@@ -459,6 +553,11 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
             },
             PaymentMethodData::Wallet(_) => Self::Wallet {},
             PaymentMethodData::PayLater(_) => Self::PayLater {},
+            PaymentMethodData::BankTransfer(_) => Self::BankTransfer {},
+            PaymentMethodData::Voucher(_) => Self::Voucher {},
+            PaymentMethodData::Upi(_) => Self::Upi {},
+            PaymentMethodData::BankDebit(_) => Self::BankDebit {},
+            PaymentMethodData::Crypto(_) => Self::Crypto {},
         }
     }
 }
@@ -488,6 +587,27 @@ pub enum BankRedirectData {
         /// The preferred language
         preferred_language: String,
     },
+    Blik {
+        /// The six digit code generated in the customer's banking app
+        #[schema(example = "777123")]
+        blik_code: String,
+    },
+    Trustly {
+        /// The country of the customer's bank account
+        #[schema(example = "SE")]
+        country: String,
+    },
+    Przelewy24 {
+        /// The billing details for bank redirection
+        billing_details: BankRedirectBilling,
+        /// The bank of the customer. Some connectors require it, others let the customer pick
+        /// their bank on the page of Przelewy24
+        #[schema(value_type = Option<BankNames>)]
+        bank_name: Option<api_enums::BankNames>,
+        /// The email of the customer, to which Przelewy24 sends the payment confirmation
+        #[schema(value_type = String, example = "johndoe@example.com")]
+        billing_email: Secret<String, pii::Email>,
+    },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -506,6 +626,82 @@ pub struct BankRedirectBilling {
     pub billing_name: Secret<String>,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankTransferData {
+    /// For Pix instant bank transfer in Brazil
+    Pix {},
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VoucherData {
+    /// For Boleto Bancario cash voucher in Brazil
+    Boleto {},
+    /// For OXXO cash voucher in Mexico
+    Oxxo {},
+    /// For Paysafecard prepaid voucher, redeemed on the Paysafecard page
+    Paysafecard {},
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UpiData {
+    /// For UPI collect, where a payment request is sent to the customer's UPI id
+    UpiCollect {
+        /// The UPI id (virtual payment address) of the customer
+        #[schema(value_type = String, example = "success@razorpay")]
+        vpa_id: Secret<String>,
+    },
+    /// For UPI intent, where the customer pays from a UPI app of their choice
+    UpiIntent {},
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitData {
+    /// For ACH direct debit from a bank account in the United States
+    AchBankDebit {
+        /// The billing details of the account holder
+        billing_details: BankDebitBilling,
+        /// The number of the bank account to be debited
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: Secret<String>,
+        /// The ABA routing number of the bank holding the account
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: Secret<String>,
+        /// The type of the bank account, `checking` if not given
+        #[schema(value_type = Option<BankAccountType>)]
+        bank_account_type: Option<api_enums::BankAccountType>,
+    },
+    /// For SEPA direct debit from a bank account in the Single Euro Payments Area
+    SepaBankDebit {
+        /// The billing details of the account holder
+        billing_details: BankDebitBilling,
+        /// The IBAN of the bank account to be debited
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: Secret<String>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankDebitBilling {
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Doe")]
+    pub name: Secret<String>,
+    /// The email of the account holder, to which the debit notifications are sent
+    #[schema(value_type = String, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CryptoData {
+    /// The cryptocurrency the customer pays with. If not given, the customer picks it on the
+    /// checkout page of the connector
+    #[schema(example = "BTC")]
+    pub pay_currency: Option<String>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum WalletData {
@@ -517,6 +713,16 @@ pub enum WalletData {
     PaypalSdk(PayPalWalletData),
     /// This is for paypal redirection
     PaypalRedirect(PaypalRedirection),
+    /// This is for paytm wallet redirection
+    PaytmRedirect(PaytmRedirection),
+    /// This is for alipay wallet redirection
+    AliPayRedirect(AliPayRedirection),
+    /// This is for wechat pay h5 redirection
+    WeChatPayRedirect(WeChatPayRedirection),
+    /// This is for wechat pay qr code
+    WeChatPayQr(WeChatPayQr),
+    /// The wallet data for Click to Pay
+    ClickToPay(ClickToPayWalletData),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -535,6 +741,28 @@ pub struct GpayWalletData {
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaypalRedirection {}
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaytmRedirection {}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AliPayRedirection {}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct WeChatPayRedirection {}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct WeChatPayQr {}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ClickToPayWalletData {
+    /// The correlation identifier the SRC system returned on the checkout of the customer, with
+    /// which the payment credentials are retrieved
+    pub src_correlation_id: String,
+    /// The card network whose SRC system the customer checked out with
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GpayPaymentMethodInfo {
     /// The name of the card network
@@ -579,23 +807,47 @@ pub struct ApplepayPaymentMethod {
     pub pm_type: String,
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, serde::Serialize)]
+#[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CardResponse {
     last4: String,
     exp_month: String,
     exp_year: String,
+    card_network: Option<api_enums::CardNetwork>,
+    card_issuer: Option<String>,
+    card_issuing_country: Option<String>,
+    card_type: Option<api_enums::CardType>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum PaymentMethodDataResponse {
     #[serde(rename = "card")]
     Card(CardResponse),
-    #[serde(rename(deserialize = "bank_transfer"))]
+    #[serde(rename(deserialize = "bank_transfer"), alias = "BankTransfer")]
     BankTransfer,
     Wallet(WalletData),
     PayLater(PayLaterData),
     Paypal,
     BankRedirect(BankRedirectData),
+    Voucher(VoucherData),
+    #[serde(rename = "upi")]
+    Upi,
+    #[serde(rename = "bank_debit")]
+    BankDebit(BankDebitResponse),
+    #[serde(rename = "crypto")]
+    Crypto(CryptoData),
+}
+
+/// Bank debit details returned in the payment response, with the account masked
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitResponse {
+    AchBankDebit {
+        account_number_last4: String,
+        routing_number: String,
+    },
+    SepaBankDebit {
+        iban_last4: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -646,6 +898,91 @@ pub struct Address {
     pub phone: Option<PhoneDetails>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayerDocument {
+    /// The type of the identity document
+    #[schema(value_type = PayerDocumentType, example = "cpf")]
+    pub document_type: api_enums::PayerDocumentType,
+
+    /// The identity document number
+    #[schema(value_type = String, max_length = 255, example = "91483309223")]
+    pub document_number: Secret<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentsData {
+    /// The number of installments the payment is to be split into
+    #[schema(value_type = u8, minimum = 1, example = 3)]
+    pub number_of_installments: NonZeroU8,
+
+    /// The identifier of the installment plan offered by the connector, if any
+    #[schema(max_length = 255, example = "INS54434")]
+    pub plan_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPaymentsRequest {
+    /// The fee retained by the platform, in the lowest denomination of the currency. It cannot exceed the payment amount
+    #[schema(example = 123)]
+    pub application_fee: i64,
+
+    /// The identifier of the connected account (sub-merchant) at the connector to which the rest of the payment is transferred
+    #[schema(max_length = 255, example = "acct_1MqxPBBYoOdJwcZs")]
+    pub destination_account_id: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SplitPaymentsBreakdown {
+    /// The fee retained by the platform, in the lowest denomination of the currency
+    #[schema(example = 123)]
+    pub application_fee: i64,
+
+    /// The identifier of the connected account to which the rest of the payment is transferred
+    #[schema(example = "acct_1MqxPBBYoOdJwcZs")]
+    pub destination_account_id: String,
+
+    /// The amount transferred to the connected account, in the lowest denomination of the currency
+    #[schema(example = 6417)]
+    pub destination_amount: i64,
+}
+
+impl From<SplitPaymentsBreakdown> for SplitPaymentsRequest {
+    fn from(breakdown: SplitPaymentsBreakdown) -> Self {
+        Self {
+            application_fee: breakdown.application_fee,
+            destination_account_id: breakdown.destination_account_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMetadata {
+    /// Data sent to Adyen
+    pub adyen: Option<AdyenConnectorMetadata>,
+    /// Data sent to Dlocal
+    pub dlocal: Option<DlocalConnectorMetadata>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdyenConnectorMetadata {
+    /// Entries added to the `additionalData` of the payment request. Entries the router sets from other fields of the payment, such as the capture method, are left out
+    #[schema(value_type = Option<Object>, example = r#"{ "riskdata.basket.item1.sku": "12345" }"#)]
+    pub additional_data: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DlocalConnectorMetadata {
+    /// The description of the payment, shown to the customer on some payment methods. Defaults to the statement descriptor of the payment
+    #[schema(example = "Order #1234")]
+    pub description: Option<String>,
+}
+
 // used by customers also, could be moved outside
 #[derive(
     Clone,
@@ -717,7 +1054,7 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureRequest {
     /// The unique identifier for the payment
     pub payment_id: Option<String>,
@@ -733,6 +1070,34 @@ pub struct PaymentsCaptureRequest {
     pub statement_descriptor_prefix: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CaptureResponse {
+    /// Unique identifier for the capture
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1_capture_1")]
+    pub capture_id: String,
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+    /// The capture amount. Amount for the capture in lowest denomination of the currency.
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The three letter ISO currency code in uppercase
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The connector used for the capture
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The order of this capture among the captures made on the payment, starting from 1
+    #[schema(example = 1)]
+    pub capture_sequence: i16,
+    /// The identifier of the capture at the connector's end
+    pub connector_capture_id: Option<String>,
+    /// If there was an error while capturing, the error code
+    pub error_code: Option<String>,
+    /// If there was an error while capturing, the error message
+    pub error_message: Option<String>,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -742,15 +1107,16 @@ pub struct UrlDetails {
 pub struct AuthenticationForStartResponse {
     pub authentication: UrlDetails,
 }
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum NextActionType {
     RedirectToUrl,
     DisplayQrCode,
     InvokeSdkClient,
     TriggerApi,
+    VerifyWithMicrodeposits,
 }
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct NextAction {
     /// Specifying the action type to be performed next
     #[serde(rename = "type")]
@@ -758,9 +1124,30 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// The QR code the customer scans to pay, for the `display_qr_code` next action
+    pub qr_code_information: Option<QrCodeInformation>,
+}
+
+/// A QR code the customer scans to pay. Connectors of QR based payment methods keep it as the
+/// connector metadata of the payment attempt in this shape, so that it is shown the same way
+/// whichever connector the payment went through.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct QrCodeInformation {
+    /// The data encoded in the QR code, from which the QR code can be generated
+    #[schema(example = "weixin://wxpay/bizpayurl?pr=aIQrOYOzz")]
+    pub qr_code_payload: String,
+    /// An image of the QR code, either as a data URL or as the URL it is hosted at
+    #[schema(example = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJ")]
+    pub image_data_url: Option<String>,
+    /// The time after which the QR code can no longer be paid
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
 }
 
-#[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(
+    Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
 pub struct PaymentsResponse {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
     /// that have been done by a single merchant.
@@ -808,6 +1195,9 @@ pub struct PaymentsResponse {
     /// List of refund that happened on this intent
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
+    /// List of captures made on this payment when it is captured in parts using `manual_multiple` capture method
+    #[schema(value_type = Option<Vec<CaptureResponse>>)]
+    pub captures: Option<Vec<CaptureResponse>>,
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -876,15 +1266,58 @@ pub struct PaymentsResponse {
     /// If there was an error while calling the connector the error message is received here
     #[schema(example = "Failed while verifying the card")]
     pub error_message: Option<String>,
+    /// The code of the error of the connector, normalized so that it is the same for all the connectors
+    #[schema(value_type = Option<UnifiedCode>, example = "insufficient_funds")]
+    pub unified_code: Option<api_enums::UnifiedCode>,
+    /// A message describing the unified code, which can be shown to the customer
+    #[schema(example = "The card has insufficient funds to complete the payment")]
+    pub unified_message: Option<String>,
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
     /// Payment Method Type
     #[schema(value_type = Option<PaymentMethodType>, example = "gpay")]
     pub payment_method_type: Option<api_enums::PaymentMethodType>,
-}
-
-#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+    /// How the payment is split between the platform and the connected account
+    pub split_payments: Option<SplitPaymentsBreakdown>,
+    /// The exchange rate applied to convert the payment amount to the settlement currency
+    pub forex_quote: Option<crate::forex::ForexQuoteResponse>,
+    /// The business profile the payment is made for
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<String>,
+    /// The decision of the fraud check connector of the merchant on the payment
+    pub fraud_check: Option<FraudCheckDetails>,
+    /// The earlier payment with the same card and amount this payment is likely a duplicate of
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub duplicate_of: Option<String>,
+    /// The result of the address verification of the card by its issuer
+    #[schema(value_type = Option<AvsResult>, example = "match")]
+    pub avs_result: Option<api_enums::AvsResult>,
+    /// The result of the verification of the security code of the card by its issuer
+    #[schema(value_type = Option<CvvResult>, example = "match")]
+    pub cvv_result: Option<api_enums::CvvResult>,
+    /// Data for features of specific connectors, sent to the connector the payment is processed with
+    pub connector_metadata: Option<ConnectorMetadata>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FraudCheckDetails {
+    /// The fraud check connector which screened the payment
+    #[schema(value_type = FrmConnectors, example = "signifyd")]
+    pub frm_connector: api_enums::FrmConnectors,
+    /// Whether the payment was screened before or after its authorization
+    #[schema(value_type = FraudCheckType, example = "pre_auth")]
+    pub check_type: api_enums::FraudCheckType,
+    /// The decision of the fraud check connector
+    #[schema(value_type = FraudCheckStatus, example = "legit")]
+    pub status: api_enums::FraudCheckStatus,
+    /// The identifier of the case of the payment at the fraud check connector
+    pub frm_reference_id: Option<String>,
+    /// Why the fraud check connector came to its decision, when it gives one
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentListConstraints {
     /// The identifier for customer
@@ -915,16 +1348,166 @@ pub struct PaymentListConstraints {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[serde(rename = "created.gte")]
     pub created_gte: Option<PrimitiveDateTime>,
+    /// The identifier for the business profile of the payments
+    pub profile_id: Option<String>,
+    /// The status of the payments
+    #[schema(value_type = Option<IntentStatus>, example = "succeeded")]
+    pub status: Option<api_enums::IntentStatus>,
+    /// The connector any attempt of the payments was made through
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector: Option<api_enums::Connector>,
+    /// The payment method any attempt of the payments was made with
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// The currency of the payments
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// Amount greater than or equals to the payment amount
+    #[serde(rename = "amount.gte")]
+    pub amount_gte: Option<i64>,
+    /// Amount less than or equals to the payment amount
+    #[serde(rename = "amount.lte")]
+    pub amount_lte: Option<i64>,
+    /// The field the payments are sorted by
+    #[serde(default)]
+    pub sort_by: PaymentListSortBy,
+    /// The order the payments are sorted in
+    #[serde(default)]
+    pub sort_order: api_enums::SortOrder,
+}
+
+/// The fields payments can be sorted by when listing them. Payments with the same value are sorted
+/// by the order they were created in.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentListSortBy {
+    #[default]
+    CreatedAt,
+    Amount,
 }
 
-#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct PaymentListResponse {
     /// The number of payments included in the list
     pub size: usize,
+    /// Whether there are more payments beyond this list, in the direction it was fetched in. They
+    /// are fetched by passing the identifier of the payment at the end of the list as
+    /// `starting_after`, or the one at its start as `ending_before`
+    pub has_more: bool,
     // The list of payments response objects
     pub data: Vec<PaymentsResponse>,
 }
 
+/// Criteria to search payments by. Payments matching all the given criteria are returned, the
+/// most recent first.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentSearchRequest {
+    /// The email of the customer who made the payments
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub customer_email: Option<Secret<String, pii::Email>>,
+    /// The last four digits of the card any attempt of the payments was made with
+    #[schema(example = "4242")]
+    pub card_last4: Option<String>,
+    /// The identifier of any attempt of the payments at its connector
+    #[schema(example = "pi_3MKEivSFNglxLpam0ZaL98q9")]
+    pub connector_transaction_id: Option<String>,
+    /// Key and value pairs the metadata of the payments contains
+    #[schema(value_type = Option<Object>, example = r#"{ "order_id": "ord_1042" }"#)]
+    pub metadata: Option<HashMap<String, String>>,
+    /// Limit on the number of payments to return
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentSearchResponse {
+    /// The number of payments found
+    pub size: usize,
+    /// The payments found, the most recent first
+    pub data: Vec<PaymentsResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentAttemptListResponse {
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The number of attempts included in the list
+    pub size: usize,
+    /// The attempts of the payment, in the order they were made
+    pub data: Vec<PaymentAttemptResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentAttemptResponse {
+    /// Unique identifier for the attempt
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub status: api_enums::AttemptStatus,
+    /// The amount of the attempt, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the amount of the attempt
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The connector the attempt was made with
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The identifier of the payment at the connector
+    pub connector_transaction_id: Option<String>,
+    /// The payment method used for the attempt
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// The error code returned by the connector, if the attempt failed
+    pub error_code: Option<String>,
+    /// The error message returned by the connector, if the attempt failed
+    pub error_message: Option<String>,
+    /// The code of the error of the connector, normalized so that it is the same for all the connectors
+    #[schema(value_type = Option<UnifiedCode>, example = "insufficient_funds")]
+    pub unified_code: Option<api_enums::UnifiedCode>,
+    /// Time taken by the connector to respond to the last request of the attempt, in milliseconds
+    #[schema(example = 850)]
+    pub latency_in_millis: Option<i64>,
+    /// The calls to other connectors which failed with a retriable error, before the attempt was
+    /// made with its connector
+    pub connector_attempts: Vec<ConnectorAttemptResponse>,
+    /// Time when the attempt was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time when the attempt was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorAttemptResponse {
+    /// The connector that was called
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The HTTP status code the connector responded with, absent when the call failed without a
+    /// response
+    #[schema(example = 503)]
+    pub status_code: Option<u16>,
+    /// The error code returned by the connector
+    pub error_code: Option<String>,
+    /// The error message returned by the connector, or the reason the call failed
+    pub error_message: Option<String>,
+    /// Time taken by the connector to respond, in milliseconds
+    #[schema(example = 850)]
+    pub latency_in_millis: Option<u64>,
+    /// Time when the connector was called
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub attempted_at: PrimitiveDateTime,
+}
+
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct VerifyResponse {
     pub verify_id: Option<String>,
@@ -1036,6 +1619,10 @@ impl From<Card> for CardResponse {
                 .to_string(),
             exp_month: card.card_exp_month.peek().clone(),
             exp_year: card.card_exp_year.peek().clone(),
+            card_network: card.card_network,
+            card_issuer: card.card_issuer,
+            card_issuing_country: card.card_issuing_country,
+            card_type: card.card_type,
         }
     }
 }
@@ -1049,10 +1636,40 @@ impl From<PaymentMethodData> for PaymentMethodDataResponse {
             PaymentMethodData::BankRedirect(bank_redirect_data) => {
                 Self::BankRedirect(bank_redirect_data)
             }
+            PaymentMethodData::BankTransfer(_) => Self::BankTransfer,
+            PaymentMethodData::Voucher(voucher_data) => Self::Voucher(voucher_data),
+            PaymentMethodData::Upi(_) => Self::Upi,
+            PaymentMethodData::BankDebit(bank_debit_data) => {
+                Self::BankDebit(BankDebitResponse::from(bank_debit_data))
+            }
+            PaymentMethodData::Crypto(crypto_data) => Self::Crypto(crypto_data),
+        }
+    }
+}
+
+impl From<BankDebitData> for BankDebitResponse {
+    fn from(bank_debit_data: BankDebitData) -> Self {
+        match bank_debit_data {
+            BankDebitData::AchBankDebit {
+                account_number,
+                routing_number,
+                ..
+            } => Self::AchBankDebit {
+                account_number_last4: get_last4(account_number.peek()),
+                routing_number: routing_number.peek().clone(),
+            },
+            BankDebitData::SepaBankDebit { iban, .. } => Self::SepaBankDebit {
+                iban_last4: get_last4(iban.peek()),
+            },
         }
     }
 }
 
+fn get_last4(value: &str) -> String {
+    let length = value.chars().count();
+    value.chars().skip(length.saturating_sub(4)).collect()
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct PgRedirectResponse {
     pub payment_id: String,
@@ -1190,6 +1807,42 @@ pub struct GpaySessionTokenData {
     pub data: GpayMetaData,
 }
 
+/// Google Pay configuration from which the allowed payment methods are built using the
+/// connector's Google Pay gateway
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GpayGatewayMetaData {
+    pub merchant_info: GpayMerchantInfo,
+    /// The merchant ID registered in the connector, sent as the `gateway_merchant_id`
+    pub gateway_merchant_id: String,
+    /// Defaults to `PAN_ONLY` and `CRYPTOGRAM_3DS`
+    pub allowed_auth_methods: Option<Vec<String>>,
+    /// Defaults to `AMEX`, `DISCOVER`, `MASTERCARD` and `VISA`
+    pub allowed_card_networks: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GpayGatewaySessionTokenData {
+    #[serde(rename = "google_pay")]
+    pub data: GpayGatewayMetaData,
+}
+
+/// The merchant's registration as a digital payment application (DPA) with the SRC systems
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClickToPayMetaData {
+    /// The identifier of the DPA, given by the SRC systems on registration
+    pub dpa_id: String,
+    /// The name of the merchant shown to the customer during the checkout
+    pub dpa_name: String,
+    /// Defaults to Visa and Mastercard
+    pub card_brands: Option<Vec<api_enums::CardNetwork>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClickToPaySessionTokenData {
+    #[serde(rename = "click_to_pay")]
+    pub data: ClickToPayMetaData,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 #[serde(tag = "wallet_name")]
 #[serde(rename_all = "snake_case")]
@@ -1202,6 +1855,8 @@ pub enum SessionToken {
     Paypal(Box<PaypalSessionTokenResponse>),
     /// The session response structure for Apple Pay
     ApplePay(Box<ApplepaySessionTokenResponse>),
+    /// The session response structure for Click to Pay
+    ClickToPay(Box<ClickToPaySessionTokenResponse>),
 }
 
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
@@ -1240,6 +1895,25 @@ pub struct ApplepaySessionTokenResponse {
     pub payment_request_data: ApplePayPaymentRequest,
 }
 
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ClickToPaySessionTokenResponse {
+    /// The identifier of the SRC initiator, with which the SDK initializes the SRC systems
+    pub src_initiator_id: String,
+    /// The identifier of the merchant's digital payment application at the SRC systems
+    pub dpa_id: String,
+    /// The name of the merchant shown to the customer during the checkout
+    pub dpa_name: String,
+    /// The identifier of the checkout at the SRC systems, which is the identifier of the payment
+    pub srci_transaction_id: String,
+    /// The card networks the customer can check out with
+    #[schema(value_type = Vec<CardNetwork>, example = json!(["Visa", "Mastercard"]))]
+    pub card_brands: Vec<api_enums::CardNetwork>,
+    /// The amount of the payment in the lowest denomination of the currency
+    pub transaction_amount: i64,
+    /// The three letter ISO code of the currency of the payment
+    pub transaction_currency_code: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema, serde::Deserialize)]
 pub struct ApplePaySessionResponse {
     /// Timestamp at which session is requested
@@ -1496,4 +2170,18 @@ mod tests {
             r#"{"multi_use":null}"#
         )
     }
+
+    #[test]
+    fn test_qr_code_information_expiry() {
+        let qr_code_information: QrCodeInformation = serde_json::from_value(serde_json::json!({
+            "qr_code_payload": "00020101021226860014br.gov.bcb.pix",
+            "expires_at": "2023-06-10T18:12:56.000-04:00",
+        }))
+        .unwrap();
+        assert_eq!(qr_code_information.image_data_url, None);
+        assert_eq!(
+            serde_json::to_value(&qr_code_information).unwrap()["expires_at"],
+            "2023-06-10T22:12:56.000Z"
+        );
+    }
 }
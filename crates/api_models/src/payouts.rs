@@ -1 +1,154 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts that have been done by a single merchant. If the identifier is not provided, it will be auto generated and returned in the API response.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payout_id: Option<String>,
+
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+
+    /// The payout amount in the lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the payout
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The connector through which the payout is disbursed. If not provided, the merchant's routing algorithm decides the connector
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The card or bank account to which the funds are disbursed
+    pub payout_method_data: PayoutMethodData,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The email address of the customer receiving the payout
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Its my first payout request")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(CardPayout),
+    Bank(BankPayout),
+}
+
+impl PayoutMethodData {
+    pub fn get_payout_type(&self) -> api_enums::PayoutType {
+        match self {
+            Self::Card(_) => api_enums::PayoutType::Card,
+            Self::Bank(_) => api_enums::PayoutType::Bank,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CardPayout {
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: Secret<String, pii::CardNumber>,
+    /// The card's expiry month
+    #[schema(value_type = String, example = "03")]
+    pub expiry_month: Secret<String>,
+    /// The card's expiry year
+    #[schema(value_type = String, example = "2030")]
+    pub expiry_year: Secret<String>,
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct BankPayout {
+    /// The International Bank Account Number of the recipient
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+    /// The Bank Identifier Code of the recipient's bank
+    #[schema(value_type = Option<String>, example = "ABNANL2A")]
+    pub bic: Option<Secret<String>>,
+    /// The name of the recipient's bank
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+    /// The two-letter ISO country code of the recipient's bank
+    #[schema(example = "NL")]
+    pub bank_country_code: Option<String>,
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub account_holder_name: Secret<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+pub struct PayoutRetrieveBody {
+    /// Decider to enable or disable the connector call for retrieve request
+    pub force_sync: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct PayoutRetrieveRequest {
+    /// The identifier for the payout
+    pub payout_id: String,
+    /// Whether the status of a pending payout is synced with the connector
+    pub force_sync: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutCreateResponse {
+    /// The identifier for the payout
+    pub payout_id: String,
+    /// The identifier for the Merchant Account
+    pub merchant_id: String,
+    /// The payout amount in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code of the payout
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// The connector through which the payout was disbursed
+    pub connector: String,
+    /// Whether the funds were disbursed to a card or a bank account
+    #[schema(value_type = PayoutType)]
+    pub payout_type: api_enums::PayoutType,
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus)]
+    pub status: api_enums::PayoutStatus,
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+    /// A description of the payout
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The error code returned by the connector, if the payout failed
+    pub error_code: Option<String>,
+    /// The error message returned by the connector, if the payout failed
+    pub error_message: Option<String>,
+    /// The timestamp at which the payout was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
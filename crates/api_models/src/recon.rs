@@ -0,0 +1,115 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Downloads the settlement report of a connector from the SFTP server or reporting API
+/// configured for it
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportFetchRequest {
+    /// The connector whose settlement report is downloaded
+    #[schema(value_type = Connector, example = "adyen")]
+    pub connector: api_enums::Connector,
+    /// The day of the settlements, in the YYYY-MM-DD format
+    #[schema(example = "2023-05-04")]
+    pub report_date: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportUploadParams {
+    /// Name of the uploaded file, kept for reference
+    pub file_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip, latest first
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconReportResponse {
+    /// The identifier of the settlement report
+    pub report_id: String,
+    /// The identifier of the merchant the report belongs to
+    pub merchant_id: String,
+    /// The connector which settled the transactions of the report
+    pub connector: String,
+    /// How the report was obtained
+    #[schema(value_type = ReconReportSource)]
+    pub source: api_enums::ReconReportSource,
+    /// The status of the reconciliation of the report
+    #[schema(value_type = ReconReportStatus)]
+    pub status: api_enums::ReconReportStatus,
+    /// Name of the uploaded file, or location the report was downloaded from
+    pub file_name: Option<String>,
+    /// The number of payment and refund records of the report
+    pub total_records: i32,
+    /// The number of records agreeing with the payment or refund they settle
+    pub matched_records: i32,
+    /// The number of records disagreeing with the payment or refund they settle
+    pub mismatched_records: i32,
+    /// The number of records whose payment or refund was not found
+    pub missing_records: i32,
+    /// Why the report could not be reconciled, when it failed
+    pub error_message: Option<String>,
+    /// The time at which the report was received
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    /// The time at which the report was last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconRecordListConstraints {
+    /// Only the records with this outcome
+    #[schema(value_type = Option<ReconStatus>)]
+    pub recon_status: Option<api_enums::ReconStatus>,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip, in the order of the report
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconRecordResponse {
+    /// The line of the record in the settlement report
+    pub line_number: i32,
+    /// Whether the record settles a payment or a refund
+    #[schema(value_type = ReconRecordType)]
+    pub record_type: api_enums::ReconRecordType,
+    /// The identifier of the payment or refund at the connector
+    pub connector_transaction_id: String,
+    /// The amount settled, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The currency settled, as it appears in the report
+    pub currency: String,
+    /// The status of the settlement, as it appears in the report
+    pub settlement_status: Option<String>,
+    /// The outcome of matching the record to a payment or refund
+    #[schema(value_type = ReconStatus)]
+    pub recon_status: api_enums::ReconStatus,
+    /// The payment the record settles
+    pub payment_id: Option<String>,
+    /// The attempt of the payment the record settles
+    pub attempt_id: Option<String>,
+    /// The refund the record settles
+    pub refund_id: Option<String>,
+    /// The amount of the payment or refund the record settles
+    pub expected_amount: Option<i64>,
+    /// The currency of the payment or refund the record settles
+    pub expected_currency: Option<String>,
+    /// What differs between the record and the payment or refund
+    pub mismatch_reason: Option<String>,
+}
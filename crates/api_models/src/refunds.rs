@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 use crate::enums;
 
-#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundRequest {
     /// Unique Identifier for the Refund. This is to ensure idempotency for multiple partial refund initiated against the same payment. If the identifiers is not defined by the merchant, this filed shall be auto generated and provide in the API response. It is recommended to generate uuid(v4) as the refund_id.
@@ -36,6 +36,10 @@ pub struct RefundRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
+    /// The reason for the refund, out of a fixed set of reasons. Passed on to the connectors accepting structured refund reasons
+    #[schema(value_type = Option<RefundReason>, example = "product_returned")]
+    pub reason_code: Option<enums::RefundReason>,
+
     /// The type of refund based on waiting time for processing: Scheduled or Instant Refund
     #[schema(default = "Instant", example = "Instant")]
     pub refund_type: Option<RefundType>,
@@ -45,7 +49,7 @@ pub struct RefundRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundUpdateRequest {
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
@@ -57,7 +61,7 @@ pub struct RefundUpdateRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Default, Debug, Clone, ToSchema, Deserialize)]
+#[derive(Default, Debug, Clone, ToSchema, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RefundType {
     #[default]
@@ -77,6 +81,9 @@ pub struct RefundResponse {
     pub currency: String,
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
     pub reason: Option<String>,
+    /// The reason for the refund, out of a fixed set of reasons
+    #[schema(value_type = Option<RefundReason>)]
+    pub reason_code: Option<enums::RefundReason>,
     /// The status for refund
     pub status: RefundStatus,
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
@@ -98,6 +105,9 @@ pub struct RefundResponse {
 pub struct RefundListRequest {
     /// The identifier for the payment
     pub payment_id: Option<String>,
+    /// Only the refunds issued for this reason
+    #[schema(value_type = Option<RefundReason>)]
+    pub reason_code: Option<enums::RefundReason>,
     /// Limit on the number of objects to return
     pub limit: Option<i64>,
     /// The time at which refund is created
@@ -131,14 +141,159 @@ pub struct RefundListResponse {
     pub data: Vec<RefundResponse>,
 }
 
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBulkRequest {
+    /// The refunds to initiate. They are processed in the background, in the order given
+    pub refunds: Vec<RefundRequest>,
+}
+
+/// A refund to a payment method without a payment to refund, such as a goodwill gesture. Only
+/// merchants whose credit policy enables credits can issue them, through connectors accepting
+/// unreferenced refunds.
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreditRequest {
+    /// Unique identifier for the credit. If the identifier is not provided, it will be auto generated and returned in the API response.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "credit_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub credit_id: Option<String>,
+
+    /// The credit amount in the lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code of the credit
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// The connector through which the credit is issued
+    #[schema(value_type = Connector, example = "cybersource")]
+    pub connector: enums::Connector,
+
+    /// The payment method the funds are credited to
+    pub payment_method_data: crate::payments::PaymentMethodData,
+
+    /// The identifier for the customer receiving the credit
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
+    #[schema(max_length = 255, example = "Goodwill credit for the delayed delivery")]
+    pub reason: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type  = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct CreditResponse {
+    /// The identifier for the credit
+    pub credit_id: String,
+    /// The credit amount in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// The connector through which the credit was issued
+    pub connector: String,
+    /// The identifier for the customer receiving the credit
+    pub customer_id: Option<String>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
+    pub reason: Option<String>,
+    /// The status of the credit
+    pub status: RefundStatus,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The error message
+    pub error_message: Option<String>,
+    /// The code for the error
+    pub error_code: Option<String>,
+    /// The timestamp at which the credit is created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The timestamp at which the credit is updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+/// The status of a batch of refunds initiated together
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBulkStatus {
+    /// The batch is waiting to be picked up
+    Pending,
+    /// The refunds of the batch are being initiated
+    Processing,
+    /// Every refund of the batch has been initiated, each with its own outcome
+    Completed,
+    /// The batch was abandoned before all of its refunds could be initiated
+    Failed,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBulkItem {
+    /// The identifier for refund, assigned when the batch is created if it was not given
+    pub refund_id: String,
+    /// The identifier for payment
+    pub payment_id: String,
+    /// The status of the refund, not present until the refund has been initiated
+    pub status: Option<RefundStatus>,
+    /// The code for the error, if the refund could not be initiated
+    pub error_code: Option<String>,
+    /// The error message, if the refund could not be initiated
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBulkResponse {
+    /// The identifier for the batch of refunds
+    pub batch_id: String,
+    /// The status of the batch
+    pub status: RefundBulkStatus,
+    /// The number of refunds in the batch
+    pub total_count: usize,
+    /// The number of refunds of the batch that have been initiated
+    pub processed_count: usize,
+    /// The refunds of the batch, in the order they were given
+    pub refunds: Vec<RefundBulkItem>,
+    /// The timestamp at which the batch is created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The timestamp at which the batch is updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
 /// The status for refunds
-#[derive(Debug, Eq, Clone, PartialEq, Default, Deserialize, Serialize, ToSchema)]
+#[derive(
+    Debug,
+    Eq,
+    Clone,
+    PartialEq,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumMessage,
+)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum RefundStatus {
+    /// The refund has been processed, and the funds are on their way to the customer
     Succeeded,
+    /// The refund was declined by the connector
     Failed,
+    /// The refund is being processed by the connector
     #[default]
     Pending,
+    /// The refund is awaiting a manual review by the connector
     Review,
 }
 
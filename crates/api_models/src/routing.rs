@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConfigCreateRequest {
+    /// A name to identify the routing configuration
+    #[schema(max_length = 64, example = "eu_cards_to_adyen")]
+    pub name: String,
+
+    /// The rules of the configuration. They are evaluated in the given order and the first rule whose conditions match the payment decides the connectors
+    pub rules: Vec<RoutingRule>,
+
+    /// The connectors used when none of the rules match the payment, in order of priority
+    #[schema(value_type = Vec<Connector>, example = json!(["stripe"]))]
+    pub default_connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// The conditions a payment must satisfy for the rule to apply. Conditions which are not provided match every payment
+    pub conditions: RoutingConditions,
+
+    /// The connectors to which a matching payment is routed, in order of priority. When a volume split is provided, these are only used for failover after the connector picked by the split
+    #[serde(default)]
+    #[schema(value_type = Vec<Connector>, example = json!(["adyen", "checkout"]))]
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+
+    /// Splits the matching payments between connectors by volume. The percentages must add up to 100
+    pub volume_split: Option<Vec<ConnectorVolumeSplit>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    /// The connector receiving this share of the traffic
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::RoutableConnectors,
+
+    /// The percentage of matching payments routed to the connector
+    #[schema(maximum = 100, example = 70)]
+    pub percentage: u8,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConditions {
+    /// The payment methods for which the rule applies
+    #[schema(value_type = Option<Vec<PaymentMethod>>, example = json!(["card"]))]
+    pub payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+
+    /// The currencies for which the rule applies
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+
+    /// The band within which the payment amount must fall for the rule to apply
+    pub amount: Option<AmountBand>,
+
+    /// The two-letter ISO country codes of the card issuer, as identified by the card BIN, for which the rule applies
+    #[schema(example = json!(["DE", "FR"]))]
+    pub card_bin_countries: Option<Vec<String>>,
+
+    /// The card networks, as identified by the card BIN, for which the rule applies
+    #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["Visa", "Mastercard"]))]
+    pub card_networks: Option<Vec<api_enums::CardNetwork>>,
+
+    /// The funding types of the card, as identified by the card BIN, for which the rule applies
+    #[schema(value_type = Option<Vec<CardType>>, example = json!(["debit", "prepaid"]))]
+    pub card_types: Option<Vec<api_enums::CardType>>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AmountBand {
+    /// The minimum amount (inclusive) in the lowest denomination of the currency
+    #[schema(example = 100)]
+    pub min: Option<i64>,
+
+    /// The maximum amount (inclusive) in the lowest denomination of the currency
+    #[schema(example = 100000)]
+    pub max: Option<i64>,
+}
+
+/// A routing configuration as stored on the merchant account
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RoutingConfig {
+    pub routing_id: String,
+    pub name: String,
+    pub rules: Vec<RoutingRule>,
+    pub default_connectors: Vec<api_enums::RoutableConnectors>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, ToSchema)]
+pub struct RoutingConfigResponse {
+    /// The identifier for the routing configuration
+    #[schema(example = "routing_q7RsGCYPZ2d7rBqyUFxj")]
+    pub routing_id: String,
+
+    /// The name of the routing configuration
+    #[schema(example = "eu_cards_to_adyen")]
+    pub name: String,
+
+    /// The rules of the configuration, in the order of evaluation
+    pub rules: Vec<RoutingRule>,
+
+    /// The connectors used when none of the rules match the payment, in order of priority
+    #[schema(value_type = Vec<Connector>, example = json!(["stripe"]))]
+    pub default_connectors: Vec<api_enums::RoutableConnectors>,
+
+    /// Whether the configuration is the one currently used to route the merchant's payments
+    #[schema(example = true)]
+    pub is_active: bool,
+
+    /// The timestamp at which the routing configuration was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
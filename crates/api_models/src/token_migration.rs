@@ -0,0 +1,125 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenMigrationRequest {
+    /// Whether a mandate is created for each imported card, charged at the connector of the
+    /// previous processor with the token it issued for the card. Requires the merchant to have a
+    /// connector account for the previous processor. Cards imported without a mandate can still be
+    /// charged at any connector with their payment_method_id.
+    #[serde(default)]
+    pub create_mandates: bool,
+    /// The cards exported by the previous processor of the merchant
+    pub export: TokenExport,
+}
+
+/// The cards of the customers of a merchant, as exported by the processor the merchant migrates
+/// from
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "source", content = "data")]
+pub enum TokenExport {
+    /// Customers exported by Stripe along with their cards
+    Stripe(Vec<StripeCustomerExport>),
+    /// Recurring details exported by Adyen, one for each card stored for a shopper
+    Adyen(Vec<AdyenRecurringDetailExport>),
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+pub struct StripeCustomerExport {
+    /// The identifier of the customer at Stripe, kept as the customer_id of the customer
+    #[schema(example = "cus_NffrFeUfNV2Hib")]
+    pub id: String,
+    /// The email of the customer
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+    /// The name of the customer
+    #[schema(example = "John Test")]
+    pub name: Option<String>,
+    /// The cards of the customer
+    #[serde(default)]
+    pub cards: Vec<StripeCardExport>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+pub struct StripeCardExport {
+    /// The identifier of the card or payment method at Stripe
+    #[schema(example = "pm_1MqLiJLkdIwHu7ixUEgbFdYF")]
+    pub id: String,
+    /// The card number
+    #[schema(value_type = String, example = "4242424242424242")]
+    pub number: Secret<String, pii::CardNumber>,
+    /// The expiry month of the card
+    #[schema(example = 8)]
+    pub exp_month: u8,
+    /// The expiry year of the card
+    #[schema(example = 2026)]
+    pub exp_year: u16,
+    /// The name of the cardholder
+    #[schema(value_type = Option<String>, example = "John Test")]
+    pub name: Option<Secret<String>>,
+    /// The network transaction id of the payment the card was stored with, if exported
+    pub network_transaction_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRecurringDetailExport {
+    /// The reference of the shopper at Adyen, kept as the customer_id of the customer
+    #[schema(example = "shopper_123")]
+    pub shopper_reference: String,
+    /// The email of the shopper
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub shopper_email: Option<Secret<String, pii::Email>>,
+    /// The reference of the stored card at Adyen
+    #[schema(example = "8415718415172200")]
+    pub recurring_detail_reference: String,
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: Secret<String, pii::CardNumber>,
+    /// The expiry month of the card
+    #[schema(value_type = String, example = "03")]
+    pub expiry_month: Secret<String>,
+    /// The expiry year of the card
+    #[schema(value_type = String, example = "2030")]
+    pub expiry_year: Secret<String>,
+    /// The name of the cardholder
+    #[schema(value_type = Option<String>, example = "John Test")]
+    pub holder_name: Option<Secret<String>>,
+    /// The network transaction id of the payment the card was stored with
+    #[schema(example = "MCC0IX5QG0306")]
+    pub network_tx_reference: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TokenMigrationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The number of cards imported
+    pub migrated_count: usize,
+    /// The number of cards which could not be imported
+    pub failed_count: usize,
+    /// The outcome of the import of each card, in the order of the export
+    pub cards: Vec<TokenMigrationCardResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TokenMigrationCardResponse {
+    /// The identifier of the customer the card belongs to
+    #[schema(example = "cus_NffrFeUfNV2Hib")]
+    pub customer_id: String,
+    /// The token the previous processor issued for the card
+    #[schema(example = "pm_1MqLiJLkdIwHu7ixUEgbFdYF")]
+    pub connector_token: String,
+    /// The identifier of the payment method the card is saved as, if it was imported
+    pub payment_method_id: Option<String>,
+    /// The identifier of the mandate created for the card, if any
+    pub mandate_id: Option<String>,
+    /// The code for the error, if the card could not be imported
+    pub error_code: Option<String>,
+    /// The error message, if the card could not be imported
+    pub error_message: Option<String>,
+}
@@ -0,0 +1,75 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums::RoleName;
+
+/// The request body for signing up to the dashboard, which creates a merchant account for the
+/// user.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SignUpRequest {
+    /// The name of the user.
+    #[schema(max_length = 255, example = "John Doe")]
+    pub name: String,
+
+    /// The email address of the user, used to log in to the dashboard.
+    #[schema(value_type = String, max_length = 255, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The password of the user, at least 8 characters long.
+    #[schema(value_type = String, example = "Passw0rd!")]
+    pub password: Secret<String>,
+
+    /// The name of the merchant account created for the user.
+    #[schema(example = "NewAge Retailer")]
+    pub merchant_name: Option<String>,
+}
+
+/// The request body for logging in to the dashboard.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LoginRequest {
+    /// The email address of the user.
+    #[schema(value_type = String, max_length = 255, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The password of the user.
+    #[schema(value_type = String, example = "Passw0rd!")]
+    pub password: Secret<String>,
+
+    /// The merchant account to log in to. Defaults to the first merchant account the user was
+    /// assigned a role for.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+}
+
+/// The JWT issued to a dashboard user, along with the details of the user.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DashboardEntryResponse {
+    /// The JWT to be sent as a bearer token in the `Authorization` header of the requests made by
+    /// the dashboard.
+    #[schema(value_type = String, example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
+    pub token: Secret<String>,
+
+    /// The identifier of the user.
+    #[schema(max_length = 64, example = "user_1xWnHvaBnVzV2IxCpI7D")]
+    pub user_id: String,
+
+    /// The identifier of the merchant account the token grants access to.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The name of the user.
+    #[schema(max_length = 255, example = "John Doe")]
+    pub name: String,
+
+    /// The email address of the user.
+    #[schema(value_type = String, max_length = 255, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The role of the user for the merchant account.
+    #[schema(example = "admin")]
+    pub role: RoleName,
+}
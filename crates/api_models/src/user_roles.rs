@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::RoleName;
+
+/// The request body for assigning a role to a dashboard user.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateUserRoleRequest {
+    /// The identifier of the dashboard user.
+    #[schema(max_length = 64, example = "user_1xWnHvaBnVzV2IxCpI7D")]
+    pub user_id: String,
+
+    /// The role to assign to the user for the merchant account.
+    #[schema(example = "operator")]
+    pub role: RoleName,
+}
+
+/// The request body for updating the role of a dashboard user.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateUserRoleRequest {
+    /// The new role of the user for the merchant account.
+    #[schema(example = "read_only")]
+    pub role: RoleName,
+}
+
+/// The role of a dashboard user for a merchant account.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserRoleResponse {
+    /// The identifier of the dashboard user.
+    #[schema(max_length = 64, example = "user_1xWnHvaBnVzV2IxCpI7D")]
+    pub user_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The role of the user for the merchant account.
+    #[schema(example = "operator")]
+    pub role: RoleName,
+
+    /// The time at which the role was assigned to the user.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The time at which the role of the user was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified: PrimitiveDateTime,
+}
+
+/// The response body for removing the role of a dashboard user.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeleteUserRoleResponse {
+    /// The identifier of the dashboard user.
+    #[schema(max_length = 64, example = "user_1xWnHvaBnVzV2IxCpI7D")]
+    pub user_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// Indicates whether the role of the user was removed.
+    #[schema(example = "true")]
+    pub deleted: bool,
+}
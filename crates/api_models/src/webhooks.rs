@@ -1,22 +1,44 @@
 use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
-use crate::{enums as api_enums, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    PaymentIntentCaptureSuccess,
+    PaymentIntentCaptureFailure,
+    PaymentIntentAuthorizationExpired,
     RefundFailure,
     RefundSuccess,
+    RefundUpdated,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    MandateActive,
+    MandateRevoked,
+    /// The fraud check connector found a payment it held for review to be legitimate
+    FrmApproved,
+    /// The fraud check connector found a payment it held for review to be fraudulent
+    FrmRejected,
     EndpointVerification,
+    EventNotSupported,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
+    Dispute,
+    Mandate,
+    FraudCheck,
     Subscription,
     ReturnResponse,
 }
@@ -26,15 +48,32 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentCaptureSuccess => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentCaptureFailure => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentAuthorizationExpired => Self::Payment,
             IncomingWebhookEvent::RefundSuccess => Self::Refund,
             IncomingWebhookEvent::RefundFailure => Self::Refund,
+            IncomingWebhookEvent::RefundUpdated => Self::Refund,
+            IncomingWebhookEvent::DisputeOpened => Self::Dispute,
+            IncomingWebhookEvent::DisputeExpired => Self::Dispute,
+            IncomingWebhookEvent::DisputeAccepted => Self::Dispute,
+            IncomingWebhookEvent::DisputeCancelled => Self::Dispute,
+            IncomingWebhookEvent::DisputeChallenged => Self::Dispute,
+            IncomingWebhookEvent::DisputeWon => Self::Dispute,
+            IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::MandateActive => Self::Mandate,
+            IncomingWebhookEvent::MandateRevoked => Self::Mandate,
+            IncomingWebhookEvent::FrmApproved => Self::FraudCheck,
+            IncomingWebhookEvent::FrmRejected => Self::FraudCheck,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
+            IncomingWebhookEvent::EventNotSupported => Self::ReturnResponse,
         }
     }
 }
 
 pub struct IncomingWebhookRequestDetails<'a> {
     pub method: actix_web::http::Method,
+    pub uri: &'a actix_web::http::Uri,
     pub headers: &'a actix_web::http::header::HeaderMap,
     pub body: &'a [u8],
 }
@@ -46,19 +85,98 @@ pub struct IncomingWebhookDetails {
     pub resource_object: Vec<u8>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OutgoingWebhook {
     pub merchant_id: String,
     pub event_id: String,
     pub event_type: api_enums::EventType,
     pub content: OutgoingWebhookContent,
-    #[serde(default, with = "custom_serde::iso8601")]
+    #[serde(with = "custom_serde::iso8601")]
     pub timestamp: PrimitiveDateTime,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
     RefundDetails(refunds::RefundResponse),
+    DisputeDetails(Box<disputes::DisputeResponse>),
+    MandateDetails(Box<mandates::MandateResponse>),
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    /// The identifier of the event that was delivered
+    pub event_id: String,
+    /// The identifier of the merchant the event was delivered to
+    pub merchant_id: String,
+    /// The number of the attempt, starting from 1 for the first delivery of the event
+    pub attempt_number: i32,
+    /// Whether the merchant acknowledged the event
+    #[schema(value_type = WebhookDeliveryStatus)]
+    pub delivery_status: api_enums::WebhookDeliveryStatus,
+    /// The HTTP status code the webhook endpoint responded with
+    pub response_status_code: Option<i32>,
+    /// The reason the delivery failed, if it did
+    pub error_message: Option<String>,
+    /// The time at which the delivery was attempted
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The identifier of the payment, refund or dispute the events are about
+    pub object_id: Option<String>,
+    /// The type of the events
+    #[schema(value_type = Option<EventType>)]
+    pub event_type: Option<api_enums::EventType>,
+    /// Time less than the event creation time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, rename = "created.lt", with = "custom_serde::iso8601::option")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the event creation time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, rename = "created.gt", with = "custom_serde::iso8601::option")]
+    pub created_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventResponse {
+    /// The identifier of the event
+    pub event_id: String,
+    /// The identifier of the merchant the event was raised for
+    pub merchant_id: Option<String>,
+    /// The type of the event
+    #[schema(value_type = EventType)]
+    pub event_type: api_enums::EventType,
+    /// Whether the event has been delivered to the merchant
+    pub is_webhook_notified: bool,
+    /// The identifier of the payment the event is about, or of the payment the refund or dispute
+    /// the event is about belongs to
+    pub payment_id: Option<String>,
+    /// The identifier of the payment, refund or dispute the event is about
+    pub object_id: String,
+    /// The type of the object the event is about
+    #[schema(value_type = EventObjectType)]
+    pub object_type: api_enums::EventObjectType,
+    /// The time at which the event was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventRetrieveResponse {
+    #[serde(flatten)]
+    pub event: EventResponse,
+    /// The body of the webhook sent to the merchant for the event
+    #[schema(value_type = Option<Object>)]
+    pub content: Option<serde_json::Value>,
+    /// The attempts made to deliver the event, latest first
+    pub delivery_attempts: Vec<WebhookDeliveryAttemptResponse>,
 }
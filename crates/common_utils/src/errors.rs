@@ -72,6 +72,20 @@ pub enum CryptoError {
     SignatureVerificationFailed,
 }
 
+/// Errors raised while encrypting or decrypting secrets with a secrets manager
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagementError {
+    /// The data or its data key could not be encrypted
+    #[error("Failed to encrypt data")]
+    EncryptionFailed,
+    /// The data or its data key could not be decrypted
+    #[error("Failed to decrypt data")]
+    DecryptionFailed,
+    /// The key management service could not be reached or returned an unexpected response
+    #[error("Failed to communicate with the key management service")]
+    KeyManagementServiceError,
+}
+
 /// Allows [error_stack::Report] to change between error contexts
 /// using the dependent [ErrorSwitch] trait to define relations & mappings between traits
 pub trait ReportSwitchExt<T, U> {
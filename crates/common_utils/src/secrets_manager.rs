@@ -0,0 +1,211 @@
+//! Envelope encryption of secrets with master keys held by a key management service.
+//!
+//! Each secret is encrypted with a freshly generated data key using AES-256-GCM, and the data key
+//! is in turn encrypted with a master key of the secrets manager. Only the encrypted data key is
+//! stored alongside the secret, so the master key never leaves the key management service.
+
+use error_stack::{IntoReport, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    errors::{CustomResult, SecretsManagementError},
+};
+
+const DATA_KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// Key management service holding the master keys that data keys are encrypted with
+#[async_trait::async_trait]
+pub trait SecretsManager: std::fmt::Debug + Send + Sync {
+    /// Identifier of the master key that new data keys are encrypted with
+    fn current_key_id(&self) -> &str;
+
+    /// Encrypts a data key with the current master key
+    async fn encrypt_data_key(
+        &self,
+        data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError>;
+
+    /// Decrypts a data key that was encrypted with the master key identified by `key_id`
+    async fn decrypt_data_key(
+        &self,
+        key_id: &str,
+        encrypted_data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError>;
+}
+
+/// A secret encrypted with a data key, along with the encrypted data key
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Envelope {
+    /// Identifier of the master key the data key is encrypted with
+    pub key_id: String,
+    /// Hex encoded data key, encrypted with the master key
+    pub encrypted_data_key: String,
+    /// Hex encoded nonce used to encrypt the secret
+    pub nonce: String,
+    /// Hex encoded encrypted secret, followed by its authentication tag
+    pub ciphertext: String,
+}
+
+/// Encrypts the secret with a new data key, which is encrypted with the current master key of the
+/// secrets manager
+pub async fn encrypt(
+    secrets_manager: &dyn SecretsManager,
+    secret: &[u8],
+) -> CustomResult<Envelope, SecretsManagementError> {
+    let data_key: [u8; DATA_KEY_LENGTH] = rand::random();
+    let nonce: [u8; NONCE_LENGTH] = rand::random();
+
+    let (mut ciphertext, mut tag) = GcmAes256::new(nonce.to_vec())
+        .encode_message(&data_key, secret)
+        .change_context(SecretsManagementError::EncryptionFailed)?;
+    ciphertext.append(&mut tag);
+
+    let encrypted_data_key = secrets_manager.encrypt_data_key(&data_key).await?;
+
+    Ok(Envelope {
+        key_id: secrets_manager.current_key_id().to_string(),
+        encrypted_data_key: hex::encode(encrypted_data_key),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    })
+}
+
+/// Decrypts the secret held by the envelope
+pub async fn decrypt(
+    secrets_manager: &dyn SecretsManager,
+    envelope: &Envelope,
+) -> CustomResult<Vec<u8>, SecretsManagementError> {
+    let encrypted_data_key = decode_hex(&envelope.encrypted_data_key, "encrypted data key")?;
+    let nonce = decode_hex(&envelope.nonce, "nonce")?;
+    let ciphertext = decode_hex(&envelope.ciphertext, "ciphertext")?;
+
+    let data_key = secrets_manager
+        .decrypt_data_key(&envelope.key_id, &encrypted_data_key)
+        .await?;
+
+    GcmAes256::new(nonce)
+        .decode_message(&data_key, &ciphertext)
+        .change_context(SecretsManagementError::DecryptionFailed)
+}
+
+fn decode_hex(value: &str, field_name: &str) -> CustomResult<Vec<u8>, SecretsManagementError> {
+    hex::decode(value)
+        .into_report()
+        .change_context(SecretsManagementError::DecryptionFailed)
+        .attach_printable_lazy(|| format!("The {field_name} of the envelope is not hex encoded"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    /// Secrets manager wrapping data keys with master keys held in memory
+    #[derive(Debug)]
+    struct InMemorySecretsManager {
+        key_id: String,
+        master_keys: Vec<(String, [u8; DATA_KEY_LENGTH])>,
+    }
+
+    impl InMemorySecretsManager {
+        fn get_master_key(
+            &self,
+            key_id: &str,
+        ) -> CustomResult<&[u8; DATA_KEY_LENGTH], SecretsManagementError> {
+            self.master_keys
+                .iter()
+                .find(|(id, _)| id == key_id)
+                .map(|(_, key)| key)
+                .ok_or(SecretsManagementError::KeyManagementServiceError)
+                .into_report()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SecretsManager for InMemorySecretsManager {
+        fn current_key_id(&self) -> &str {
+            &self.key_id
+        }
+
+        async fn encrypt_data_key(
+            &self,
+            data_key: &[u8],
+        ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+            let (mut encrypted_data_key, mut tag) = GcmAes256::new(vec![0; NONCE_LENGTH])
+                .encode_message(self.get_master_key(&self.key_id)?, data_key)
+                .change_context(SecretsManagementError::EncryptionFailed)?;
+            encrypted_data_key.append(&mut tag);
+            Ok(encrypted_data_key)
+        }
+
+        async fn decrypt_data_key(
+            &self,
+            key_id: &str,
+            encrypted_data_key: &[u8],
+        ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+            GcmAes256::new(vec![0; NONCE_LENGTH])
+                .decode_message(self.get_master_key(key_id)?, encrypted_data_key)
+                .change_context(SecretsManagementError::DecryptionFailed)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_envelope_encryption_round_trip() {
+        let secrets_manager = InMemorySecretsManager {
+            key_id: "key_1".to_string(),
+            master_keys: vec![("key_1".to_string(), rand::random())],
+        };
+        let secret = br#"{"auth_type":"HeaderKey","api_key":"sk_test"}"#;
+
+        let envelope = encrypt(&secrets_manager, secret)
+            .await
+            .expect("Encrypted envelope");
+        assert_eq!(envelope.key_id, "key_1");
+        assert_ne!(envelope.ciphertext, hex::encode(secret));
+
+        let decrypted = decrypt(&secrets_manager, &envelope)
+            .await
+            .expect("Decrypted secret");
+        assert_eq!(decrypted, secret.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_envelope_decryption_after_key_rotation() {
+        let old_key = rand::random();
+        let old_secrets_manager = InMemorySecretsManager {
+            key_id: "key_1".to_string(),
+            master_keys: vec![("key_1".to_string(), old_key)],
+        };
+        let new_secrets_manager = InMemorySecretsManager {
+            key_id: "key_2".to_string(),
+            master_keys: vec![
+                ("key_1".to_string(), old_key),
+                ("key_2".to_string(), rand::random()),
+            ],
+        };
+        let secret = b"secret";
+
+        let envelope = encrypt(&old_secrets_manager, secret)
+            .await
+            .expect("Encrypted envelope");
+        let decrypted = decrypt(&new_secrets_manager, &envelope)
+            .await
+            .expect("Decrypted secret");
+        let reencrypted = encrypt(&new_secrets_manager, &decrypted)
+            .await
+            .expect("Re-encrypted envelope");
+
+        assert_eq!(reencrypted.key_id, "key_2");
+        assert!(decrypt(&old_secrets_manager, &reencrypted).await.is_err());
+        assert_eq!(
+            decrypt(&new_secrets_manager, &reencrypted)
+                .await
+                .expect("Decrypted secret"),
+            secret.to_vec()
+        );
+    }
+}
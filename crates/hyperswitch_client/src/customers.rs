@@ -0,0 +1,30 @@
+use api_models::customers::{CustomerDeleteResponse, CustomerRequest, CustomerResponse};
+
+use crate::{errors::ClientResult, Client};
+
+impl Client {
+    /// Creates a customer, with an identifier generated by the server unless `customer_id` is set.
+    pub async fn create_customer(
+        &self,
+        request: &CustomerRequest,
+    ) -> ClientResult<CustomerResponse> {
+        self.post(&["customers"], request).await
+    }
+
+    pub async fn retrieve_customer(&self, customer_id: &str) -> ClientResult<CustomerResponse> {
+        self.get::<(), _>(&["customers", customer_id], None).await
+    }
+
+    pub async fn update_customer(
+        &self,
+        customer_id: &str,
+        request: &CustomerRequest,
+    ) -> ClientResult<CustomerResponse> {
+        self.post(&["customers", customer_id], request).await
+    }
+
+    /// Deletes a customer, along with their addresses and saved payment methods.
+    pub async fn delete_customer(&self, customer_id: &str) -> ClientResult<CustomerDeleteResponse> {
+        self.delete(&["customers", customer_id]).await
+    }
+}
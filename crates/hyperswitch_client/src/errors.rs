@@ -0,0 +1,60 @@
+//!
+//! Errors returned by the client.
+//!
+
+pub type ClientResult<T> = common_utils::errors::CustomResult<T, ClientError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("Invalid base URL: {0}")]
+    InvalidBaseUrl(String),
+    #[error("Failed to construct the HTTP client")]
+    ClientConstructionFailed,
+    #[error("Failed to encode the request")]
+    RequestEncodingFailed,
+    #[error("Failed to send the request")]
+    RequestNotSent,
+    #[error("Failed to decode the response")]
+    ResponseDecodingFailed,
+    #[error("The server responded with status code {status_code}: {error}")]
+    ErrorResponse { status_code: u16, error: ApiError },
+    #[error("Failed to decode the webhook signature")]
+    WebhookSignatureDecodingFailed,
+    #[error("Failed to verify the webhook signature")]
+    WebhookSignatureVerificationFailed,
+    #[error("The webhook signature doesn't match its payload")]
+    WebhookSignatureMismatch,
+    #[error("Failed to decode the webhook payload")]
+    WebhookPayloadDecodingFailed,
+}
+
+/// The error returned by the server for a request which couldn't be served.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct ApiError {
+    /// The category of the error, such as `invalid_request_error`
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// The code of the error, such as `IR_04`
+    pub code: Option<String>,
+    /// A description of the error
+    pub message: Option<String>,
+    /// The reason given by the connector, for errors returned by the connector
+    pub reason: Option<String>,
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.message.as_deref().unwrap_or("Unknown error"),
+            self.code.as_deref().unwrap_or("no error code")
+        )
+    }
+}
+
+/// The body of the responses of the server for failed requests.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ErrorResponseBody {
+    pub(crate) error: ApiError,
+}
@@ -0,0 +1,245 @@
+//! Async client for the payments, refunds and customers APIs of the router.
+//!
+//! The requests and responses of the client are the types of [`api_models`], which the server
+//! itself uses, so that the client follows the API as it changes. The [`webhooks`] module verifies
+//! and decodes the webhooks sent to merchants.
+//!
+//! # Examples
+//! ```no_run
+//! use hyperswitch_client::{api_models, errors::ClientResult, Client};
+//!
+//! async fn create_payment() -> ClientResult<()> {
+//!     let client = Client::new("https://sandbox.hyperswitch.io", "snd_api_key")?;
+//!     let payment = client
+//!         .create_payment(&api_models::payments::PaymentsRequest {
+//!             amount: Some(6540.into()),
+//!             currency: Some(api_models::enums::Currency::USD),
+//!             ..Default::default()
+//!         })
+//!         .await?;
+//!     println!("Created payment {:?}", payment.payment_id);
+//!     Ok(())
+//! }
+//! ```
+#![forbid(unsafe_code)]
+
+mod customers;
+pub mod errors;
+mod payments;
+mod refunds;
+pub mod webhooks;
+
+use std::time::Duration;
+
+pub use api_models;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::errors::{ApiError, ClientError, ClientResult, ErrorResponseBody};
+
+const API_KEY_HEADER: &str = "api-key";
+
+/// Client for the API of the router, authenticated with the API key of a merchant.
+#[derive(Clone, Debug)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: url::Url,
+    api_key: Secret<String>,
+}
+
+impl Client {
+    /// Creates a client with the default configuration, for the server at `base_url`.
+    pub fn new(base_url: &str, api_key: impl Into<String>) -> ClientResult<Self> {
+        Self::builder(base_url, api_key).build()
+    }
+
+    pub fn builder(base_url: &str, api_key: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            base_url: base_url.to_owned(),
+            api_key: Secret::new(api_key.into()),
+            timeout: None,
+        }
+    }
+
+    /// Builds the URL of an endpoint from its path segments, which are percent encoded, so that
+    /// identifiers can be passed as they are.
+    fn endpoint(&self, segments: &[&str]) -> ClientResult<url::Url> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|()| report!(ClientError::InvalidBaseUrl(self.base_url.to_string())))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    async fn get<Q, Res>(&self, segments: &[&str], query: Option<&Q>) -> ClientResult<Res>
+    where
+        Q: Serialize + ?Sized,
+        Res: DeserializeOwned,
+    {
+        let mut request = self.http_client.get(self.endpoint(segments)?);
+        if let Some(query) = query {
+            request = request.query(query);
+        }
+        self.send(request).await
+    }
+
+    async fn post<Req, Res>(&self, segments: &[&str], body: &Req) -> ClientResult<Res>
+    where
+        Req: Serialize + ?Sized,
+        Res: DeserializeOwned,
+    {
+        let body = serde_json::to_vec(body)
+            .into_report()
+            .change_context(ClientError::RequestEncodingFailed)?;
+        let request = self
+            .http_client
+            .post(self.endpoint(segments)?)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        self.send(request).await
+    }
+
+    async fn delete<Res>(&self, segments: &[&str]) -> ClientResult<Res>
+    where
+        Res: DeserializeOwned,
+    {
+        let request = self.http_client.delete(self.endpoint(segments)?);
+        self.send(request).await
+    }
+
+    async fn send<Res>(&self, request: reqwest::RequestBuilder) -> ClientResult<Res>
+    where
+        Res: DeserializeOwned,
+    {
+        let response = request
+            .header(API_KEY_HEADER, self.api_key.peek())
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .into_report()
+            .change_context(ClientError::RequestNotSent)?;
+        let status_code = response.status();
+        let body = response
+            .bytes()
+            .await
+            .into_report()
+            .change_context(ClientError::ResponseDecodingFailed)?;
+
+        if status_code.is_success() {
+            serde_json::from_slice(&body)
+                .into_report()
+                .change_context(ClientError::ResponseDecodingFailed)
+        } else {
+            Err(report!(ClientError::ErrorResponse {
+                status_code: status_code.as_u16(),
+                error: parse_error_response(&body),
+            }))
+        }
+    }
+}
+
+/// Parses the body of a failed request, keeping the body as the message of the error when it isn't
+/// an error response of the API, as is the case for errors of a proxy in front of the server.
+fn parse_error_response(body: &[u8]) -> ApiError {
+    serde_json::from_slice::<ErrorResponseBody>(body)
+        .map(|response| response.error)
+        .unwrap_or_else(|_| ApiError {
+            message: Some(String::from_utf8_lossy(body).into_owned()),
+            ..Default::default()
+        })
+}
+
+/// Builder of a [`Client`], to configure the underlying HTTP client.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    base_url: String,
+    api_key: Secret<String>,
+    timeout: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// Sets the timeout of the requests, from the start of the connection until the end of the
+    /// response. Requests have no timeout by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> ClientResult<Client> {
+        let base_url = url::Url::parse(&self.base_url)
+            .into_report()
+            .change_context(ClientError::InvalidBaseUrl(self.base_url.clone()))?;
+        if base_url.cannot_be_a_base() {
+            return Err(report!(ClientError::InvalidBaseUrl(self.base_url)));
+        }
+
+        let mut http_client = reqwest::Client::builder()
+            .user_agent(concat!("hyperswitch-client/", env!("CARGO_PKG_VERSION")));
+        if let Some(timeout) = self.timeout {
+            http_client = http_client.timeout(timeout);
+        }
+
+        Ok(Client {
+            http_client: http_client
+                .build()
+                .into_report()
+                .change_context(ClientError::ClientConstructionFailed)?,
+            base_url,
+            api_key: self.api_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_endpoint_keeps_path_of_base_url() {
+        let client = Client::new("https://example.com/api/", "api_key").unwrap();
+        let url = client
+            .endpoint(&["payments", "pay_123", "confirm"])
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://example.com/api/payments/pay_123/confirm"
+        );
+
+        let client = Client::new("https://example.com/api", "api_key").unwrap();
+        let url = client.endpoint(&["refunds"]).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/api/refunds");
+    }
+
+    #[test]
+    fn test_endpoint_encodes_identifiers() {
+        let client = Client::new("https://example.com", "api_key").unwrap();
+        let url = client.endpoint(&["customers", "cus/1?a"]).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/customers/cus%2F1%3Fa");
+    }
+
+    #[test]
+    fn test_invalid_base_url() {
+        let error = Client::new("mailto:merchant@example.com", "api_key").unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ClientError::InvalidBaseUrl(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let error = parse_error_response(
+            br#"{"error":{"type":"invalid_request","message":"Missing required param: amount","code":"IR_04"}}"#,
+        );
+        assert_eq!(error.error_type.as_deref(), Some("invalid_request"));
+        assert_eq!(error.code.as_deref(), Some("IR_04"));
+
+        let error = parse_error_response(b"502 Bad Gateway");
+        assert_eq!(error.message.as_deref(), Some("502 Bad Gateway"));
+        assert_eq!(error.code, None);
+    }
+}
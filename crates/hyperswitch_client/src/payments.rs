@@ -0,0 +1,81 @@
+use api_models::payments::{
+    PaymentAttemptListResponse, PaymentListConstraints, PaymentListResponse, PaymentRetrieveBody,
+    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRequest, PaymentsResponse,
+};
+
+use crate::{errors::ClientResult, Client};
+
+impl Client {
+    /// Creates a payment, which is also authorized with the connector when `confirm` is set.
+    pub async fn create_payment(
+        &self,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.post(&["payments"], request).await
+    }
+
+    /// Retrieves a payment. Its status is synced with the connector when `force_sync` is set in
+    /// the request.
+    pub async fn retrieve_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentRetrieveBody,
+    ) -> ClientResult<PaymentsResponse> {
+        self.get(&["payments", payment_id], Some(request)).await
+    }
+
+    /// Updates a payment which hasn't been confirmed yet.
+    pub async fn update_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.post(&["payments", payment_id], request).await
+    }
+
+    /// Confirms a payment, updating it with the fields set in the request.
+    pub async fn confirm_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.post(&["payments", payment_id, "confirm"], request)
+            .await
+    }
+
+    /// Captures the funds of an authorized payment, all of them unless `amount_to_capture` is set.
+    pub async fn capture_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsCaptureRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.post(&["payments", payment_id, "capture"], request)
+            .await
+    }
+
+    /// Cancels a payment which hasn't been captured.
+    pub async fn cancel_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsCancelRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.post(&["payments", payment_id, "cancel"], request)
+            .await
+    }
+
+    pub async fn list_payments(
+        &self,
+        constraints: &PaymentListConstraints,
+    ) -> ClientResult<PaymentListResponse> {
+        self.get(&["payments", "list"], Some(constraints)).await
+    }
+
+    /// Lists every attempt made for a payment, along with the connectors tried within each.
+    pub async fn list_payment_attempts(
+        &self,
+        payment_id: &str,
+    ) -> ClientResult<PaymentAttemptListResponse> {
+        self.get::<(), _>(&["payments", payment_id, "attempts"], None)
+            .await
+    }
+}
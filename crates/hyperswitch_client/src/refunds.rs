@@ -0,0 +1,33 @@
+use api_models::refunds::{
+    RefundListRequest, RefundListResponse, RefundRequest, RefundResponse, RefundUpdateRequest,
+};
+
+use crate::{errors::ClientResult, Client};
+
+impl Client {
+    /// Refunds a payment, in full unless `amount` is set.
+    pub async fn create_refund(&self, request: &RefundRequest) -> ClientResult<RefundResponse> {
+        self.post(&["refunds"], request).await
+    }
+
+    /// Retrieves a refund, syncing its status with the connector.
+    pub async fn retrieve_refund(&self, refund_id: &str) -> ClientResult<RefundResponse> {
+        self.get::<(), _>(&["refunds", refund_id], None).await
+    }
+
+    /// Updates the reason and the metadata of a refund.
+    pub async fn update_refund(
+        &self,
+        refund_id: &str,
+        request: &RefundUpdateRequest,
+    ) -> ClientResult<RefundResponse> {
+        self.post(&["refunds", refund_id], request).await
+    }
+
+    pub async fn list_refunds(
+        &self,
+        constraints: &RefundListRequest,
+    ) -> ClientResult<RefundListResponse> {
+        self.get(&["refunds", "list"], Some(constraints)).await
+    }
+}
@@ -0,0 +1,126 @@
+//!
+//! Verification of the webhooks sent to merchants.
+//!
+//! Webhooks are signed with the payment response hash key of the merchant account: the
+//! `X-Webhook-Signature` header holds the hex encoded HMAC-SHA512 of the body of the request.
+//!
+
+use api_models::webhooks::OutgoingWebhook;
+use common_utils::crypto::{self, VerifySignature};
+use error_stack::{report, IntoReport, ResultExt};
+
+use crate::errors::{ClientError, ClientResult};
+
+/// The header holding the signature of a webhook.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Checks that `signature`, the value of the signature header, is the signature of `payload` with
+/// the payment response hash key of the merchant account.
+pub fn verify_signature(payload: &[u8], signature: &str, secret: &str) -> ClientResult<bool> {
+    let signature = hex::decode(signature.trim())
+        .into_report()
+        .change_context(ClientError::WebhookSignatureDecodingFailed)?;
+
+    crypto::HmacSha512
+        .verify_signature(secret.as_bytes(), &signature, payload)
+        .change_context(ClientError::WebhookSignatureVerificationFailed)
+}
+
+/// Verifies the signature of a webhook and decodes its body, failing for webhooks whose signature
+/// doesn't match, which weren't sent by the server.
+pub fn parse_webhook(
+    payload: &[u8],
+    signature: &str,
+    secret: &str,
+) -> ClientResult<OutgoingWebhook> {
+    if !verify_signature(payload, signature, secret)? {
+        return Err(report!(ClientError::WebhookSignatureMismatch));
+    }
+
+    serde_json::from_slice(payload)
+        .into_report()
+        .change_context(ClientError::WebhookPayloadDecodingFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::panic, clippy::unwrap_used)]
+
+    use api_models::{enums::EventType, webhooks::OutgoingWebhookContent};
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    const SECRET: &str = "payment_response_hash_key";
+    const PAYLOAD: &[u8] = br#"{
+        "merchant_id": "merchant_1668273825",
+        "event_id": "evt_018e31720d1b7a2b82677d3032cab959",
+        "event_type": "refund_succeeded",
+        "content": {
+            "type": "refund_details",
+            "object": {
+                "refund_id": "ref_mbabizu24mvu3mela5njyhpit4",
+                "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+                "amount": 6540,
+                "currency": "USD",
+                "reason": null,
+                "status": "succeeded",
+                "metadata": null,
+                "error_message": null,
+                "error_code": null,
+                "created_at": "2023-05-10T10:11:12.000Z",
+                "updated_at": "2023-05-10T10:11:13.000Z"
+            }
+        },
+        "timestamp": "2023-05-10T10:11:13.000Z"
+    }"#;
+
+    fn sign(payload: &[u8], secret: &str) -> String {
+        hex::encode(
+            crypto::HmacSha512
+                .sign_message(secret.as_bytes(), payload)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature = sign(PAYLOAD, SECRET);
+
+        assert!(verify_signature(PAYLOAD, &signature, SECRET).unwrap());
+        assert!(!verify_signature(PAYLOAD, &signature, "another_secret").unwrap());
+        assert!(!verify_signature(b"{}", &signature, SECRET).unwrap());
+    }
+
+    #[test]
+    fn test_verify_signature_which_is_not_hex_encoded() {
+        let error = verify_signature(PAYLOAD, "not a signature", SECRET).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ClientError::WebhookSignatureDecodingFailed
+        ));
+    }
+
+    #[test]
+    fn test_parse_webhook() {
+        let webhook = parse_webhook(PAYLOAD, &sign(PAYLOAD, SECRET), SECRET).unwrap();
+
+        assert_eq!(webhook.event_type, EventType::RefundSucceeded);
+        match webhook.content {
+            OutgoingWebhookContent::RefundDetails(refund) => {
+                assert_eq!(refund.refund_id, "ref_mbabizu24mvu3mela5njyhpit4");
+                assert_eq!(refund.amount, 6540);
+            }
+            content => panic!("Unexpected webhook content: {content:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_webhook_with_mismatching_signature() {
+        let error = parse_webhook(PAYLOAD, &sign(b"{}", SECRET), SECRET).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ClientError::WebhookSignatureMismatch
+        ));
+    }
+}
@@ -15,10 +15,10 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
+        MultipleValues, RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
     },
 };
 use futures::StreamExt;
@@ -91,6 +91,35 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    /// Evaluates the Lua script atomically, with the given keys and arguments.
+    #[instrument(level = "DEBUG", skip(self, lua_script))]
+    pub async fn evaluate_redis_script<V, T>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        args: V,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug,
+        V::Error: Into<fred::error::RedisError>,
+        T: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .eval(lua_script, keys, args)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ScriptExecutionFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn exists<V>(&self, key: &str) -> CustomResult<bool, errors::RedisError>
     where
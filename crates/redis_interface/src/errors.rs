@@ -58,4 +58,8 @@ pub enum RedisError {
     SubscribeError,
     #[error("Failed to publish to a channel")]
     PublishError,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
+    #[error("Failed to execute Lua script in Redis")]
+    ScriptExecutionFailed,
 }
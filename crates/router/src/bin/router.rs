@@ -17,8 +17,8 @@ async fn main() -> ApplicationResult<()> {
             #[allow(clippy::expect_used)]
             std::fs::write(
                 file_path,
-                <router::openapi::ApiDoc as utoipa::OpenApi>::openapi()
-                    .to_pretty_json()
+                router::openapi::generate_openapi_spec()
+                    .and_then(|openapi| serde_json::to_string_pretty(&openapi))
                     .expect("Failed to generate serialize OpenAPI specification as JSON"),
             )
             .expect("Failed to write OpenAPI specification to file");
@@ -8,7 +8,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::customers as customer_types,
 };
 
@@ -45,7 +45,7 @@ pub async fn customer_create(
         |state, merchant_account, req| {
             customers::create_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -77,7 +77,7 @@ pub async fn customer_retrieve(
         |state, merchant_account, req| {
             customers::retrieve_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
     )
     .await
 }
@@ -118,7 +118,7 @@ pub async fn customer_update(
         |state, merchant_account, req| {
             customers::update_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -148,7 +148,7 @@ pub async fn customer_delete(
         &req,
         payload,
         customers::delete_customer,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -176,7 +176,7 @@ pub async fn list_customer_payment_method_api(
         &req,
         customer_id.as_ref(),
         cards::list_customer_payment_method,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
     )
     .await
 }
@@ -16,6 +16,18 @@ pub enum StripeErrorCode {
     )]
     Unauthorized,
 
+    #[error(
+        error_type = StripeErrorType::InvalidRequestError, code = "IR_20",
+        message = "The provided API key does not have the permissions required for this request"
+    )]
+    AccessForbidden,
+
+    #[error(
+        error_type = StripeErrorType::InvalidRequestError, code = "rate_limit",
+        message = "Too many requests hit the API too quickly"
+    )]
+    RateLimit { retry_after: u64 },
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_02", message = "Unrecognized request URL.")]
     InvalidRequestUrl,
 
@@ -345,7 +357,12 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::Unauthorized
             | errors::ApiErrorResponse::InvalidJwtToken
             | errors::ApiErrorResponse::GenericUnauthorized { .. }
-            | errors::ApiErrorResponse::InvalidEphemeralKey => Self::Unauthorized,
+            | errors::ApiErrorResponse::InvalidEphemeralKey
+            | errors::ApiErrorResponse::InvalidCredentials => Self::Unauthorized,
+            errors::ApiErrorResponse::AccessForbidden => Self::AccessForbidden,
+            errors::ApiErrorResponse::TooManyRequests { retry_after } => {
+                Self::RateLimit { retry_after }
+            }
             errors::ApiErrorResponse::InvalidRequestUrl
             | errors::ApiErrorResponse::InvalidHttpMethod => Self::InvalidRequestUrl,
             errors::ApiErrorResponse::MissingRequiredField { field_name } => {
@@ -417,6 +434,10 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
+            errors::ApiErrorResponse::MandateStatusValidationFailed { reason } => {
+                Self::PreconditionFailed { message: reason }
+            }
+            errors::ApiErrorResponse::MandateRevokeFailed { .. } => Self::InternalServerError,
             errors::ApiErrorResponse::ReturnUrlUnavailable => Self::ReturnUrlUnavailable,
             errors::ApiErrorResponse::DuplicateMerchantAccount => Self::DuplicateMerchantAccount,
             errors::ApiErrorResponse::DuplicateMerchantConnectorAccount => {
@@ -457,6 +478,31 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::DuplicatePayment { payment_id }
             }
             errors::ApiErrorResponse::NotSupported { .. } => Self::InternalServerError,
+            // Payouts are not exposed through the stripe compatibility layer
+            errors::ApiErrorResponse::PayoutFailed { .. }
+            | errors::ApiErrorResponse::DuplicatePayout { .. }
+            | errors::ApiErrorResponse::PayoutNotFound => Self::InternalServerError,
+            // Disputes are not exposed through the stripe compatibility layer either
+            errors::ApiErrorResponse::DisputeFailed { .. }
+            | errors::ApiErrorResponse::DisputeNotFound { .. }
+            | errors::ApiErrorResponse::DisputeStatusValidationFailed { .. } => {
+                Self::InternalServerError
+            }
+            errors::ApiErrorResponse::EventNotFound
+            | errors::ApiErrorResponse::RefundBatchNotFound
+            | errors::ApiErrorResponse::CreditNotFound
+            | errors::ApiErrorResponse::ReconReportNotFound
+            | errors::ApiErrorResponse::BlocklistEntryNotFound
+            | errors::ApiErrorResponse::RoutingConfigNotFound
+            | errors::ApiErrorResponse::UserRoleNotFound
+            | errors::ApiErrorResponse::OrganizationNotFound
+            | errors::ApiErrorResponse::BusinessProfileNotFound => Self::ResourceIdNotFound,
+            // User roles, users, business profiles and blocklists are managed through the admin and
+            // dashboard APIs only
+            errors::ApiErrorResponse::DuplicateUserRole
+            | errors::ApiErrorResponse::DuplicateUser
+            | errors::ApiErrorResponse::DuplicateBusinessProfile
+            | errors::ApiErrorResponse::DuplicateBlocklistEntry => Self::InternalServerError,
         }
     }
 }
@@ -467,6 +513,8 @@ impl actix_web::ResponseError for StripeErrorCode {
 
         match self {
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::AccessForbidden => StatusCode::FORBIDDEN,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
             Self::InvalidRequestUrl => StatusCode::NOT_FOUND,
             Self::ParameterUnknown { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ParameterMissing { .. }
@@ -518,10 +566,14 @@ impl actix_web::ResponseError for StripeErrorCode {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .insert_header((header::VIA, "Juspay_Router"))
-            .body(self.to_string())
+            .insert_header((header::VIA, "Juspay_Router"));
+        if let Self::RateLimit { retry_after } = self {
+            response.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
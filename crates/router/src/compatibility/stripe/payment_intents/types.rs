@@ -82,6 +82,8 @@ impl From<StripeCard> for payments::Card {
             card_cvc: card.cvc,
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         }
     }
 }
@@ -405,6 +407,15 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            profile_id: None,
+            status: None,
+            connector: None,
+            payment_method: None,
+            currency: None,
+            amount_gte: None,
+            amount_lte: None,
+            sort_by: payments::PaymentListSortBy::default(),
+            sort_order: api_models::enums::SortOrder::default(),
         })
     }
 }
@@ -439,7 +450,7 @@ impl From<payments::PaymentListResponse> for StripePaymentIntentListResponse {
         Self {
             object: "list".to_string(),
             url: "/v1/payment_intents".to_string(),
-            has_more: false,
+            has_more: it.has_more,
             data: it.data.into_iter().map(Into::into).collect(),
         }
     }
@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::refunds,
     routes,
-    services::authentication as auth,
+    services::{authentication as auth, authorization::Permission},
     types::api::refunds as refund_types,
 };
 
@@ -35,7 +35,7 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         refunds::refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
     )
     .await
 }
@@ -69,7 +69,7 @@ pub async fn refund_retrieve(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundRead),
     )
     .await
 }
@@ -102,7 +102,7 @@ pub async fn refund_update(
         |state, merchant_account, req| {
             refunds::refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
     )
     .await
 }
@@ -41,6 +41,8 @@ impl From<StripeCreateRefundRequest> for refunds::RefundRequest {
         Self {
             amount: req.amount,
             payment_id: req.payment_intent,
+            // Stripe only accepts `duplicate`, `fraudulent` and `requested_by_customer` as reasons
+            reason_code: req.reason.as_deref().and_then(|reason| reason.parse().ok()),
             reason: req.reason,
             refund_type: Some(refunds::RefundType::Instant),
             ..Default::default()
@@ -27,6 +27,7 @@ where
     E: Serialize + error_stack::Context + actix_web::ResponseError + Clone,
     errors::ApiErrorResponse: ErrorSwitch<E>,
     T: std::fmt::Debug,
+    U: auth::AuthInfo,
     A: AppStateInfo,
 {
     let resp: common_utils::errors::CustomResult<_, E> =
@@ -11,6 +11,30 @@ impl Default for super::settings::Server {
     }
 }
 
+#[cfg(feature = "grpc")]
+impl Default for super::settings::GrpcServer {
+    fn default() -> Self {
+        Self {
+            host: "localhost".into(),
+            port: 50051,
+        }
+    }
+}
+
+#[cfg(feature = "kafka_events")]
+impl Default for super::settings::KafkaSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            brokers: Vec::new(),
+            payment_intent_topic: "hyperswitch-payment-intent-events".into(),
+            payment_attempt_topic: "hyperswitch-payment-attempt-events".into(),
+            refund_topic: "hyperswitch-refund-events".into(),
+            message_timeout_ms: 5000,
+        }
+    }
+}
+
 impl Default for super::settings::Database {
     fn default() -> Self {
         Self {
@@ -30,6 +54,105 @@ impl Default for super::settings::Secrets {
         Self {
             jwt_secret: "secret".into(),
             admin_api_key: "test_admin".into(),
+            card_fingerprint_secret: "secret".into(),
+        }
+    }
+}
+
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_enabled: false,
+            outgoing_max_delivery_attempts: 5,
+            outgoing_retry_base_delay_in_secs: 60,
+            incoming_async_enabled: false,
+            incoming_max_process_attempts: 5,
+            incoming_retry_base_delay_in_secs: 30,
+            incoming_dead_letter_stream: String::from("WEBHOOK_DEAD_LETTER_STREAM"),
+        }
+    }
+}
+
+impl Default for super::settings::PaymentRetries {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_connector_attempts: 3,
+            retriable_error_codes: None,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorHealthSettings {
+    fn default() -> Self {
+        Self {
+            circuit_breaker_enabled: false,
+            window_size: 100,
+            min_calls: 20,
+            error_rate_threshold: 0.5,
+            latency_threshold_in_ms: 10000,
+            open_duration_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRequestSettings {
+    fn default() -> Self {
+        Self {
+            timeout_in_secs: crate::consts::REQUEST_TIME_OUT,
+            connector_timeouts_in_secs: std::collections::HashMap::new(),
+            pool_idle_timeout_in_secs: 90,
+            pool_max_idle_per_host: 32,
+            retries: super::settings::ConnectorRequestRetries::default(),
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRequestRetries {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay_in_millis: 200,
+            max_delay_in_millis: 2000,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_key: super::settings::RateLimit {
+                burst: 100,
+                requests_per_second: 50,
+            },
+            merchant: super::settings::RateLimit {
+                burst: 200,
+                requests_per_second: 100,
+            },
+            routes: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::PaymentSyncSettings {
+    fn default() -> Self {
+        Self {
+            sync_statuses: vec![
+                storage_models::enums::AttemptStatus::Pending,
+                storage_models::enums::AttemptStatus::AuthenticationPending,
+            ],
+            max_sync_age_in_secs: 86400,
+            connector_schedules: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::ScheduledCaptureSettings {
+    fn default() -> Self {
+        Self {
+            expiry_in_secs: 86400,
+            retry_interval_in_secs: 900,
         }
     }
 }
@@ -40,6 +163,8 @@ impl Default for super::settings::Locker {
             host: "localhost".into(),
             mock_locker: true,
             basilisk_host: "localhost".into(),
+            #[cfg(feature = "internal_locker")]
+            internal_locker_kek: String::new(),
         }
     }
 }
@@ -69,6 +194,35 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            sync_max_attempts: 10,
+            sync_base_delay_in_secs: 60,
+            max_refunds_per_batch: 1000,
+        }
+    }
+}
+
+impl Default for super::settings::ReconSettings {
+    fn default() -> Self {
+        Self {
+            max_file_size: 10 * 1024 * 1024,
+            max_records_per_report: 100_000,
+            connectors: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::SettlementReportFormat {
+    fn default() -> Self {
+        Self {
+            transaction_id_column: "connector_transaction_id".into(),
+            record_type_column: "type".into(),
+            amount_column: "amount".into(),
+            currency_column: "currency".into(),
+            status_column: "status".into(),
+            payment_types: vec!["payment".into()],
+            refund_types: vec!["refund".into()],
+            amount_in_minor_units: true,
+            delimiter: ',',
         }
     }
 }
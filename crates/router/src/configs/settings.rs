@@ -38,6 +38,8 @@ pub enum Subcommand {
 #[serde(default)]
 pub struct Settings {
     pub server: Server,
+    #[cfg(feature = "grpc")]
+    pub grpc_server: GrpcServer,
     pub proxy: Proxy,
     pub env: Env,
     pub master_database: Database,
@@ -54,8 +56,26 @@ pub struct Settings {
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
+    pub secrets_manager: SecretsManagerSettings,
+    pub applepay_decrypt_keys: ApplePayDecryptConfig,
     pub webhooks: WebhooksSettings,
+    pub payment_retries: PaymentRetries,
+    pub connector_health: ConnectorHealthSettings,
+    pub connector_request: ConnectorRequestSettings,
+    pub rate_limit: RateLimitSettings,
+    pub payment_sync: PaymentSyncSettings,
+    pub scheduled_capture: ScheduledCaptureSettings,
+    pub forex: ForexSettings,
+    pub card_bin_lookup: CardBinLookupSettings,
+    pub three_ds_server: ThreeDsServerSettings,
+    pub click_to_pay: ClickToPaySettings,
+    pub opensearch: OpenSearchSettings,
+    pub clickhouse: ClickhouseSettings,
+    #[cfg(feature = "kafka_events")]
+    pub kafka: KafkaSettings,
+    pub recon: ReconSettings,
     pub pm_filters: ConnectorFilters,
+    pub connector_capabilities: ConnectorCapabilities,
     pub bank_config: BankRedirectConfig,
 }
 
@@ -96,6 +116,20 @@ pub struct CurrencyCountryFilter {
     pub country: Option<HashSet<String>>,
 }
 
+/// How long each connector keeps an uncaptured authorization valid. The payment methods,
+/// countries and currencies supported by a connector are declared by the connector itself, through
+/// [`ConnectorSpecifications`](crate::types::api::ConnectorSpecifications).
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorCapabilities(pub HashMap<String, ConnectorCapability>);
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorCapability {
+    /// Time after which an uncaptured authorization expires at the connector (in seconds)
+    pub authorization_validity_in_secs: Option<i64>,
+}
+
 fn string_set_deser<'a, D>(deserializer: D) -> Result<Option<HashSet<String>>, D::Error>
 where
     D: Deserializer<'a>,
@@ -151,6 +185,9 @@ where
 pub struct Secrets {
     pub jwt_secret: String,
     pub admin_api_key: String,
+    /// Key of the HMAC which fingerprints the card numbers blocked by merchants, so that the card
+    /// numbers themselves are not stored
+    pub card_fingerprint_secret: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -159,6 +196,10 @@ pub struct Locker {
     pub host: String,
     pub mock_locker: bool,
     pub basilisk_host: String,
+    /// Hex encoded AES-256 key (KMS encrypted when the `kms` feature is enabled) used to wrap the
+    /// per-merchant data encryption keys of the internal locker
+    #[cfg(feature = "internal_locker")]
+    pub internal_locker_kek: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -166,6 +207,12 @@ pub struct Locker {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    /// Total number of times a pending refund is synced with the connector before giving up
+    pub sync_max_attempts: i32,
+    /// Delay before the first sync of a pending refund, doubled after every sync
+    pub sync_base_delay_in_secs: i32,
+    /// Maximum number of refunds that can be initiated together in a single batch
+    pub max_refunds_per_batch: usize,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -189,6 +236,27 @@ pub struct Jwekey {
     pub locker_decryption_key2: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SecretsManagerSettings {
+    pub backend: SecretsManagerBackend,
+    /// ID or ARN of the AWS KMS key, or resource name of the GCP KMS crypto key that connector
+    /// credentials are encrypted with
+    pub key_id: String,
+    #[cfg(feature = "kms")]
+    pub aws_region: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretsManagerBackend {
+    /// Connector credentials are stored in plaintext
+    #[default]
+    NoEncryption,
+    AwsKms,
+    GcpKms,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Proxy {
@@ -207,6 +275,15 @@ pub struct Server {
     pub shutdown_timeout: u64,
 }
 
+/// Address the gRPC API is served on, alongside the REST API.
+#[cfg(feature = "grpc")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct GrpcServer {
+    pub host: String,
+    pub port: u16,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Database {
@@ -229,30 +306,64 @@ pub struct SupportedConnectors {
 #[serde(default)]
 pub struct Connectors {
     pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
+    pub adyen: ConnectorParamsWithSecondaryBaseUrl,
+    pub affirm: ConnectorParams,
+    pub afterpay_clearpay: ConnectorParamsWithRegionalBaseUrls,
     pub airwallex: ConnectorParams,
+    pub alipay: ConnectorParams,
     pub applepay: ConnectorParams,
     pub authorizedotnet: ConnectorParams,
     pub bambora: ConnectorParams,
     pub bluesnap: ConnectorParams,
     pub braintree: ConnectorParams,
     pub checkout: ConnectorParams,
+    pub cryptopay: ConnectorParams,
     pub cybersource: ConnectorParams,
     pub dlocal: ConnectorParams,
+    #[cfg(feature = "dummy_connector")]
+    pub dummy: ConnectorParams,
     pub fiserv: ConnectorParams,
     pub globalpay: ConnectorParams,
+    pub gocardless: ConnectorParams,
     pub klarna: ConnectorParams,
+    pub mercadopago: ConnectorParams,
+    pub mollie: ConnectorParams,
     pub multisafepay: ConnectorParams,
     pub nuvei: ConnectorParams,
+    pub paypal: ConnectorParams,
+    pub paysafe: ConnectorParams,
+    pub paytm: ConnectorParams,
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
+    pub razorpay: ConnectorParams,
+    pub riskified: ConnectorParams,
     pub shift4: ConnectorParams,
+    pub signifyd: ConnectorParams,
     pub stripe: ConnectorParams,
+    pub trustly: ConnectorParams,
+    pub twocheckout: ConnectorParams,
+    pub wechatpay: ConnectorParams,
+    pub wise: ConnectorParams,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
 
     // Keep this field separate from the remaining fields
     pub supported: SupportedConnectors,
+    /// HTTP client settings of specific connectors, keyed by connector name
+    pub client_settings: HashMap<String, ConnectorClientSettings>,
+}
+
+/// Connectors requiring mutual TLS or requests from fixed IP addresses are called with a client of
+/// their own, which presents the client certificate or goes through the proxy configured here.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorClientSettings {
+    /// Base64 encoded PEM certificate presented to the connector
+    pub client_certificate: Option<String>,
+    /// Base64 encoded PEM private key of the client certificate, in the PKCS#8 format
+    pub client_certificate_key: Option<String>,
+    /// Proxy the requests to the connector go through, instead of the proxy of the application
+    pub proxy: Option<Proxy>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -261,6 +372,24 @@ pub struct ConnectorParams {
     pub base_url: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithSecondaryBaseUrl {
+    pub base_url: String,
+    pub secondary_base_url: String,
+}
+
+/// Base URLs for connectors which run separate deployments per region, `base_url` being the
+/// one for Australia
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithRegionalBaseUrls {
+    pub base_url: String,
+    pub nz_base_url: String,
+    pub us_base_url: String,
+    pub uk_base_url: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -298,10 +427,357 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ApplePayDecryptConfig {
+    /// Apple Pay merchant identifier the payment processing certificate was issued for
+    pub apple_pay_merchant_identifier: String,
+    /// Private key of the payment processing certificate in PKCS#8 PEM format
+    pub apple_pay_ppc_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
+    /// Total number of times an outgoing webhook is delivered before giving up
+    pub outgoing_max_delivery_attempts: i32,
+    /// Delay before the first retry, doubled after every failed retry
+    pub outgoing_retry_base_delay_in_secs: i32,
+    /// Whether incoming webhooks are handed over to the scheduler instead of being processed
+    /// within the API call
+    pub incoming_async_enabled: bool,
+    /// Total number of times an incoming webhook is processed before it is dead lettered
+    pub incoming_max_process_attempts: i32,
+    /// Delay before incoming webhook processing is retried, doubled after every failed retry
+    pub incoming_retry_base_delay_in_secs: i32,
+    /// Redis stream that incoming webhooks are appended to once their retries are exhausted
+    pub incoming_dead_letter_stream: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaymentRetries {
+    /// Whether a failed authorization is retried on the next connector given by the routing rules
+    pub enabled: bool,
+    /// Maximum number of connectors on which a single authorization is attempted
+    pub max_connector_attempts: usize,
+    /// Connector error codes on which the authorization is retried, in addition to failures to
+    /// connect, and to timeouts and 5xx responses after which a sync shows no authorization
+    #[serde(deserialize_with = "string_set_deser")]
+    pub retriable_error_codes: Option<HashSet<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConnectorHealthSettings {
+    /// Whether connectors with a degraded health are skipped when routing payments
+    pub circuit_breaker_enabled: bool,
+    /// Number of the most recent calls to a connector its health is computed from
+    pub window_size: usize,
+    /// Minimum number of calls in the window before the circuit of a connector can be opened
+    pub min_calls: usize,
+    /// Fraction of failed calls in the window at which the circuit of a connector is opened
+    pub error_rate_threshold: f64,
+    /// Average latency of the calls in the window at which the circuit of a connector is opened
+    pub latency_threshold_in_ms: u64,
+    /// Time for which an opened circuit stays open, after which the connector is tried again
+    pub open_duration_in_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConnectorRequestSettings {
+    /// Timeout of a request to a connector, from the start of the connection until the end of the
+    /// response
+    pub timeout_in_secs: u64,
+    /// Timeouts of the requests to specific connectors, keyed by connector name, overriding the
+    /// timeout above
+    pub connector_timeouts_in_secs: HashMap<String, u64>,
+    /// Time for which an idle connection is kept open in the pool of the client
+    pub pool_idle_timeout_in_secs: u64,
+    /// Maximum number of idle connections kept open in the pool of the client for each host
+    pub pool_max_idle_per_host: usize,
+    /// Retries of the requests which are safe to send again, namely payment and refund syncs
+    pub retries: ConnectorRequestRetries,
+}
+
+/// Requests are retried after network failures, timeouts and 5xx responses, with exponential
+/// backoff and jitter.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ConnectorRequestRetries {
+    /// Maximum number of times a request is sent again, zero disabling retries
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every retry after it
+    pub base_delay_in_millis: u64,
+    /// Upper bound of the delay before a retry
+    pub max_delay_in_millis: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    /// Whether API requests are rate limited
+    pub enabled: bool,
+    /// Limit on the requests made with each API key, across all routes
+    pub api_key: RateLimit,
+    /// Limit on the requests made on behalf of each merchant, across all routes
+    pub merchant: RateLimit,
+    /// Limits on the requests made on behalf of each merchant to specific routes, keyed by the
+    /// path prefix of the routes, in addition to the limit across all routes
+    pub routes: HashMap<String, RateLimit>,
+}
+
+/// Token bucket holding up to `burst` requests, refilled with `requests_per_second` requests
+/// every second.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RateLimit {
+    pub burst: u32,
+    pub requests_per_second: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaymentSyncSettings {
+    /// Attempt statuses in which a payment keeps being synced with the connector in the background
+    pub sync_statuses: Vec<storage_models::enums::AttemptStatus>,
+    /// Age of a payment attempt after which it is no longer synced, even if it is still stuck in
+    /// one of the statuses above
+    pub max_sync_age_in_secs: i64,
+    /// Retry schedules of connectors, keyed by connector name, used when syncing with connectors
+    /// that don't have a mapping stored in redis
+    pub connector_schedules: HashMap<String, crate::scheduler::types::process_data::RetryMapping>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ScheduledCaptureSettings {
+    /// Time after the scheduled capture time by which a payment must be captured, after which its
+    /// authorization is voided instead
+    pub expiry_in_secs: i64,
+    /// Delay before a scheduled capture that could not be made is attempted again
+    pub retry_interval_in_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ForexSettings {
+    /// Provider the exchange rates are obtained from
+    pub provider: api_models::forex::ForexProvider,
+    /// Value of each currency against a common base currency, used by the fixed provider, given as
+    /// comma-separated `CURRENCY:value` pairs
+    #[serde(deserialize_with = "forex_rates_deser")]
+    pub fixed_rates: HashMap<api_models::enums::Currency, f64>,
+    /// Base URL of the exchange rates API used by the external provider
+    pub base_url: String,
+    /// API key for the exchange rates API used by the external provider
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct CardBinLookupSettings {
+    /// Whether the BINs missing from the embedded BIN table are looked up with the external BIN
+    /// lookup API
+    pub external_lookup_enabled: bool,
+    /// Base URL of the BIN lookup API, to which the BIN is appended
+    pub base_url: String,
+    /// API key for the BIN lookup API, sent only when it is not empty
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ThreeDsServerSettings {
+    /// Whether payments can be authenticated with 3DS through the external 3DS server before they
+    /// are authorized at the connector
+    pub enabled: bool,
+    /// Base URL of the 3DS server API
+    pub base_url: String,
+    /// API key for the 3DS server API
+    pub api_key: String,
+    /// Identifier of the 3DS requestor, assigned by the directory servers of the card networks
+    pub requestor_id: String,
+    /// Name of the 3DS requestor, as registered at the directory servers of the card networks
+    pub requestor_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ClickToPaySettings {
+    /// Identifier of the SRC initiator, assigned by the SRC systems of the card networks on
+    /// onboarding
+    pub src_initiator_id: String,
+    pub visa: SrcSystemSettings,
+    pub mastercard: SrcSystemSettings,
+}
+
+/// The Secure Remote Commerce (SRC) system of a card network, from which the payment credentials
+/// of Click to Pay checkouts are retrieved
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct SrcSystemSettings {
+    /// Base URL of the SRC system API, Click to Pay checkouts of the card network are not
+    /// supported when it is empty
+    pub base_url: String,
+    /// API key for the SRC system API
+    pub api_key: String,
+}
+
+/// OpenSearch (or Elasticsearch) cluster payments are indexed in, to search them without loading
+/// the database
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct OpenSearchSettings {
+    /// Whether payments are indexed in and searched through the cluster, instead of the database
+    pub enabled: bool,
+    /// Base URL of the cluster
+    pub base_url: String,
+    /// Username for the cluster, requests are not authenticated when it is empty
+    pub username: String,
+    /// Password for the cluster
+    pub password: String,
+    /// Index the payments are indexed in
+    pub payment_index: String,
+}
+
+/// ClickHouse database payment attempts are recorded in, to compute the analytics of the merchants
+/// there instead of the database
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ClickhouseSettings {
+    /// Whether payment attempts are recorded in and analytics computed through ClickHouse
+    pub enabled: bool,
+    /// Base URL of the HTTP interface of ClickHouse
+    pub base_url: String,
+    /// Username for ClickHouse, the default user is used when it is empty
+    pub username: String,
+    /// Password for ClickHouse
+    pub password: String,
+    /// Database the payment attempts table is in
+    pub database: String,
+}
+
+/// Kafka cluster the lifecycle events of payments, payment attempts and refunds are published to,
+/// used when the router is built with the `kafka_events` feature
+#[cfg(feature = "kafka_events")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KafkaSettings {
+    /// Whether events are published to Kafka
+    pub enabled: bool,
+    /// Addresses of the brokers the producer first connects to
+    pub brokers: Vec<String>,
+    /// Topic the events of payments are published to
+    pub payment_intent_topic: String,
+    /// Topic the events of payment attempts are published to
+    pub payment_attempt_topic: String,
+    /// Topic the events of refunds are published to
+    pub refund_topic: String,
+    /// Time within which events must be delivered, after which they are dropped
+    pub message_timeout_ms: u32,
+}
+
+/// Reconciliation of the settlement reports of connectors with the payments and refunds they
+/// settle
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ReconSettings {
+    /// Maximum size of an uploaded settlement report, in bytes
+    pub max_file_size: usize,
+    /// Maximum number of records read from a settlement report
+    pub max_records_per_report: usize,
+    /// Where the settlement reports of specific connectors are downloaded from and how they are
+    /// read, keyed by connector name. The reports of the other connectors can only be uploaded,
+    /// in the default format.
+    pub connectors: HashMap<String, ReconConnectorSettings>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReconConnectorSettings {
+    /// Where the settlement reports of the connector are downloaded from
+    pub source: api_models::enums::ReconReportSource,
+    /// The SFTP server of the connector, when the reports are downloaded from it
+    pub sftp: Option<ReconSftpSettings>,
+    /// The reporting API of the connector, when the reports are downloaded from it
+    pub api: Option<ReconApiSettings>,
+    #[serde(default)]
+    pub format: SettlementReportFormat,
+}
+
+/// SFTP server the settlement reports are downloaded from, used when the router is built with the
+/// `recon_sftp` feature
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReconSftpSettings {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    /// Path of the report of a day, in which `{date}` is replaced by the day in the YYYY-MM-DD
+    /// format
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReconApiSettings {
+    /// URL of the report of a day, in which `{date}` is replaced by the day in the YYYY-MM-DD
+    /// format
+    pub url: String,
+    /// Sent as a bearer token, requests are not authenticated when it is empty
+    #[serde(default)]
+    pub api_key: String,
+}
+
+/// Layout of the CSV settlement reports of a connector. Records of types other than the payment
+/// and refund types, such as fees and payouts, are skipped.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SettlementReportFormat {
+    /// Column of the identifier of the payment or refund at the connector
+    pub transaction_id_column: String,
+    /// Column of the type of the record
+    pub record_type_column: String,
+    /// Column of the amount settled
+    pub amount_column: String,
+    /// Column of the currency settled
+    pub currency_column: String,
+    /// Column of the status of the settlement, not read when empty
+    pub status_column: String,
+    /// Types of the records settling payments
+    pub payment_types: Vec<String>,
+    /// Types of the records settling refunds
+    pub refund_types: Vec<String>,
+    /// Whether amounts are in the lowest denomination of the currency, rather than in its base unit
+    pub amount_in_minor_units: bool,
+    /// Separator of the values of a line
+    pub delimiter: char,
+}
+
+fn forex_rates_deser<'a, D>(
+    deserializer: D,
+) -> Result<HashMap<api_models::enums::Currency, f64>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    let value = <String>::deserialize(deserializer)?;
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (currency, rate) = pair
+                .split_once(':')
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid forex rate `{pair}`")))?;
+            let currency = api_models::enums::Currency::from_str(currency.trim())
+                .map_err(serde::de::Error::custom)?;
+            let rate = rate
+                .trim()
+                .parse::<f64>()
+                .map_err(serde::de::Error::custom)?;
+            Ok((currency, rate))
+        })
+        .collect()
 }
 
 impl Settings {
@@ -347,6 +823,8 @@ impl Settings {
 
     pub fn validate(&self) -> ApplicationResult<()> {
         self.server.validate()?;
+        #[cfg(feature = "grpc")]
+        self.grpc_server.validate()?;
         self.master_database.validate()?;
         #[cfg(feature = "olap")]
         self.replica_database.validate()?;
@@ -370,6 +848,19 @@ impl Settings {
         self.secrets.validate()?;
         self.locker.validate()?;
         self.connectors.validate()?;
+        self.forex.validate()?;
+        self.card_bin_lookup.validate()?;
+        self.three_ds_server.validate()?;
+        self.click_to_pay.validate()?;
+        self.opensearch.validate()?;
+        self.clickhouse.validate()?;
+        #[cfg(feature = "kafka_events")]
+        self.kafka.validate()?;
+        self.recon.validate()?;
+        self.connector_health.validate()?;
+        self.connector_request.validate()?;
+        self.rate_limit.validate()?;
+        self.payment_sync.validate()?;
 
         self.scheduler
             .as_ref()
@@ -378,6 +869,7 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        self.secrets_manager.validate()?;
 
         Ok(())
     }
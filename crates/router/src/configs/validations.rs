@@ -16,11 +16,18 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "admin API key must not be empty".into(),
             ))
+        })?;
+
+        when(self.card_fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card fingerprint secret must not be empty".into(),
+            ))
         })
     }
 }
 
 impl super::settings::Locker {
+    #[cfg(not(feature = "internal_locker"))]
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
@@ -39,6 +46,61 @@ impl super::settings::Locker {
             },
         )
     }
+
+    #[cfg(feature = "internal_locker")]
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            !self.mock_locker && self.internal_locker_kek.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "internal locker key encryption key must not be empty when mock locker is \
+                     disabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::SecretsManagerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        use super::settings::SecretsManagerBackend;
+
+        when(
+            self.backend != SecretsManagerBackend::NoEncryption
+                && self.key_id.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "secrets manager key ID must not be empty when a secrets manager backend is \
+                     configured"
+                        .into(),
+                ))
+            },
+        )?;
+
+        #[cfg(feature = "kms")]
+        when(
+            self.backend == SecretsManagerBackend::AwsKms && self.aws_region.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "secrets manager AWS region must not be empty when the AWS KMS backend is \
+                     configured"
+                        .into(),
+                ))
+            },
+        )?;
+
+        #[cfg(not(feature = "kms"))]
+        when(self.backend == SecretsManagerBackend::AwsKms, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "the AWS KMS secrets manager backend requires the KMS feature to be enabled".into(),
+            ))
+        })?;
+
+        Ok(())
+    }
 }
 
 impl super::settings::Jwekey {
@@ -71,6 +133,17 @@ impl super::settings::Server {
     }
 }
 
+#[cfg(feature = "grpc")]
+impl super::settings::GrpcServer {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "gRPC server host must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::Database {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -115,23 +188,358 @@ impl super::settings::Connectors {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         self.aci.validate()?;
         self.adyen.validate()?;
+        self.affirm.validate()?;
+        self.afterpay_clearpay.validate()?;
+        self.alipay.validate()?;
         self.applepay.validate()?;
         self.authorizedotnet.validate()?;
         self.braintree.validate()?;
         self.checkout.validate()?;
+        self.cryptopay.validate()?;
         self.cybersource.validate()?;
+        #[cfg(feature = "dummy_connector")]
+        self.dummy.validate()?;
         self.globalpay.validate()?;
+        self.gocardless.validate()?;
         self.klarna.validate()?;
+        self.mercadopago.validate()?;
+        self.mollie.validate()?;
+        self.paypal.validate()?;
+        self.paysafe.validate()?;
+        self.paytm.validate()?;
+        self.razorpay.validate()?;
+        self.riskified.validate()?;
         self.shift4.validate()?;
+        self.signifyd.validate()?;
         self.stripe.validate()?;
+        self.trustly.validate()?;
+        self.twocheckout.validate()?;
+        self.wechatpay.validate()?;
+        self.wise.validate()?;
         self.worldpay.validate()?;
 
         self.supported.validate()?;
+        self.client_settings
+            .iter()
+            .try_for_each(|(connector, client_settings)| {
+                let has_partial_certificate = client_settings.client_certificate.is_some()
+                    != client_settings.client_certificate_key.is_some();
+                common_utils::fp_utils::when(has_partial_certificate, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "client certificate of {connector} must be configured with its key"
+                    )))
+                })
+            })?;
 
         Ok(())
     }
 }
 
+impl super::settings::ForexSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.provider == api_models::forex::ForexProvider::External
+                && self.base_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex base URL must not be empty when using the external provider".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::CardBinLookupSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.external_lookup_enabled && self.base_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "BIN lookup base URL must not be empty when the external lookup is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ThreeDsServerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "3DS server base URL must not be empty when the 3DS server is enabled".into(),
+            ))
+        })?;
+
+        when(
+            self.enabled && self.requestor_id.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "3DS requestor ID must not be empty when the 3DS server is enabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ClickToPaySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        let is_src_system_configured = !self.visa.base_url.is_default_or_empty()
+            || !self.mastercard.base_url.is_default_or_empty();
+
+        common_utils::fp_utils::when(
+            is_src_system_configured && self.src_initiator_id.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "SRC initiator ID must not be empty when an SRC system is configured".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::OpenSearchSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "OpenSearch base URL must not be empty when OpenSearch is enabled".into(),
+            ))
+        })?;
+
+        when(
+            self.enabled && self.payment_index.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "OpenSearch payment index must not be empty when OpenSearch is enabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ClickhouseSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "ClickHouse base URL must not be empty when ClickHouse is enabled".into(),
+            ))
+        })?;
+
+        when(self.enabled && self.database.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "ClickHouse database must not be empty when ClickHouse is enabled".into(),
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "kafka_events")]
+impl super::settings::KafkaSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.brokers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka brokers must not be empty when Kafka events are enabled".into(),
+            ))
+        })?;
+
+        when(
+            self.enabled
+                && (self.payment_intent_topic.is_default_or_empty()
+                    || self.payment_attempt_topic.is_default_or_empty()
+                    || self.refund_topic.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka topics must not be empty when Kafka events are enabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ReconSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        use common_utils::fp_utils::when;
+
+        when(self.max_records_per_report == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "recon maximum records per report must be greater than zero".into(),
+            ))
+        })?;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector_name, connector)| {
+                when(
+                    api_models::enums::Connector::from_str(connector_name).is_err(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "recon is configured for the unknown connector {connector_name}"
+                        )))
+                    },
+                )?;
+                connector.validate(connector_name)
+            })
+    }
+}
+
+impl super::settings::ReconConnectorSettings {
+    fn validate(&self, connector_name: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        match self.source {
+            api_models::enums::ReconReportSource::Csv => Ok(()),
+            api_models::enums::ReconReportSource::Sftp => {
+                when(cfg!(not(feature = "recon_sftp")), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "recon SFTP source of {connector_name} requires the recon_sftp feature"
+                    )))
+                })?;
+                when(
+                    self.sftp.as_ref().map_or(true, |sftp| {
+                        sftp.host.is_default_or_empty() || sftp.path.is_default_or_empty()
+                    }),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "recon SFTP host and path of {connector_name} must not be empty"
+                        )))
+                    },
+                )
+            }
+            api_models::enums::ReconReportSource::Api => when(
+                self.api
+                    .as_ref()
+                    .map_or(true, |api| api.url.is_default_or_empty()),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "recon API URL of {connector_name} must not be empty"
+                    )))
+                },
+            ),
+        }?;
+
+        let format = &self.format;
+        when(
+            format.transaction_id_column.is_default_or_empty()
+                || format.record_type_column.is_default_or_empty()
+                || format.amount_column.is_default_or_empty()
+                || format.currency_column.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "recon report columns of {connector_name} must not be empty"
+                )))
+            },
+        )?;
+
+        when(
+            format.payment_types.is_empty() && format.refund_types.is_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "recon report of {connector_name} must have payment or refund types"
+                )))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorHealthSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.window_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health window size must be greater than zero".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.min_calls > self.window_size, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health minimum calls must not exceed the window size".into(),
+            ))
+        })?;
+
+        let is_invalid_error_rate =
+            self.error_rate_threshold <= 0.0 || self.error_rate_threshold > 1.0;
+        common_utils::fp_utils::when(is_invalid_error_rate, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health error rate threshold must be between 0 and 1".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorRequestSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        let has_zero_timeout = self.timeout_in_secs == 0
+            || self
+                .connector_timeouts_in_secs
+                .values()
+                .any(|timeout| *timeout == 0);
+        common_utils::fp_utils::when(has_zero_timeout, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector request timeouts must be greater than zero".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(
+            self.retries.base_delay_in_millis > self.retries.max_delay_in_millis,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector request retry base delay must not exceed the maximum delay".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.api_key.validate("api key")?;
+        self.merchant.validate("merchant")?;
+        self.routes
+            .iter()
+            .try_for_each(|(route, limit)| limit.validate(route))
+    }
+}
+
+impl super::settings::RateLimit {
+    pub fn validate(&self, name: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.burst == 0 || self.requests_per_second == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "rate limit of {name} must allow a burst and requests per second greater than zero"
+            )))
+        })
+    }
+}
+
+impl super::settings::PaymentSyncSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_sync_age_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment sync max age must be greater than zero".into(),
+            ))
+        })?;
+
+        self.connector_schedules
+            .iter()
+            .try_for_each(|(connector, schedule)| {
+                let has_mismatched_lengths = schedule.frequency.len() != schedule.count.len();
+                common_utils::fp_utils::when(has_mismatched_lengths, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "payment sync schedule of {connector} must list a count for every frequency"
+                    )))
+                })
+            })
+    }
+}
+
 impl super::settings::ConnectorParams {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.base_url.is_default_or_empty(), || {
@@ -142,6 +550,54 @@ impl super::settings::ConnectorParams {
     }
 }
 
+impl super::settings::ConnectorParamsWithSecondaryBaseUrl {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.secondary_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector secondary base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorParamsWithRegionalBaseUrls {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.nz_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector NZ base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.us_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector US base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.uk_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector UK base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -1,31 +1,56 @@
 pub mod aci;
 pub mod adyen;
+pub mod affirm;
+pub mod afterpay_clearpay;
 pub mod airwallex;
+pub mod alipay;
 pub mod applepay;
 pub mod authorizedotnet;
 pub mod bambora;
 pub mod bluesnap;
 pub mod braintree;
 pub mod checkout;
+pub mod cryptopay;
 pub mod cybersource;
 pub mod dlocal;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy;
 pub mod fiserv;
 pub mod globalpay;
+pub mod gocardless;
 pub mod klarna;
+pub mod mercadopago;
+pub mod mollie;
 pub mod multisafepay;
 pub mod nuvei;
+pub mod paypal;
+pub mod paysafe;
+pub mod paytm;
 pub mod payu;
 pub mod rapyd;
+pub mod razorpay;
+pub mod riskified;
 pub mod shift4;
+pub mod signifyd;
 pub mod stripe;
+pub mod trustly;
+pub mod twocheckout;
 pub mod utils;
+pub mod wechatpay;
+pub mod wise;
 pub mod worldline;
 pub mod worldpay;
 
+#[cfg(feature = "dummy_connector")]
+pub use self::dummy::Dummy;
 pub use self::{
-    aci::Aci, adyen::Adyen, airwallex::Airwallex, applepay::Applepay,
-    authorizedotnet::Authorizedotnet, bambora::Bambora, bluesnap::Bluesnap, braintree::Braintree,
-    checkout::Checkout, cybersource::Cybersource, dlocal::Dlocal, fiserv::Fiserv,
-    globalpay::Globalpay, klarna::Klarna, multisafepay::Multisafepay, nuvei::Nuvei, payu::Payu,
-    rapyd::Rapyd, shift4::Shift4, stripe::Stripe, worldline::Worldline, worldpay::Worldpay,
+    aci::Aci, adyen::Adyen, affirm::Affirm, afterpay_clearpay::AfterpayClearpay,
+    airwallex::Airwallex, alipay::Alipay, applepay::Applepay, authorizedotnet::Authorizedotnet,
+    bambora::Bambora, bluesnap::Bluesnap, braintree::Braintree, checkout::Checkout,
+    cryptopay::Cryptopay, cybersource::Cybersource, dlocal::Dlocal, fiserv::Fiserv,
+    globalpay::Globalpay, gocardless::Gocardless, klarna::Klarna, mercadopago::Mercadopago,
+    mollie::Mollie, multisafepay::Multisafepay, nuvei::Nuvei, paypal::Paypal, paysafe::Paysafe,
+    paytm::Paytm, payu::Payu, rapyd::Rapyd, razorpay::Razorpay, riskified::Riskified,
+    shift4::Shift4, signifyd::Signifyd, stripe::Stripe, trustly::Trustly, twocheckout::Twocheckout,
+    wechatpay::Wechatpay, wise::Wise, worldline::Worldline, worldpay::Worldpay,
 };
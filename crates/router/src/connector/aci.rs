@@ -43,6 +43,8 @@ impl ConnectorCommon for Aci {
     }
 }
 
+impl api::ConnectorSpecifications for Aci {}
+
 impl api::Payment for Aci {}
 
 impl api::PaymentAuthorize for Aci {}
@@ -194,6 +196,7 @@ impl
                 })
             }),
             status_code: res.status_code,
+            unified_code: None,
         })
     }
 }
@@ -305,6 +308,7 @@ impl
                     )
                 })
             }),
+            unified_code: None,
         })
     }
 }
@@ -406,6 +410,7 @@ impl
                     )
                 })
             }),
+            unified_code: None,
         })
     }
 }
@@ -513,6 +518,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
                     )
                 })
             }),
+            unified_code: None,
         })
     }
 }
@@ -522,6 +528,65 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Aci {}
+impl api::PayoutCreate for Aci {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::PayoutFulfill for Aci {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::PayoutCancel for Aci {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::PayoutSync for Aci {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::RefundCredit for Aci {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Aci
+{
+}
+
+impl api::ConnectorMandateRevoke for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Aci
+{
+}
+
+impl api::Dispute for Aci {}
+impl api::SubmitEvidence for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Aci
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Aci {
     fn get_webhook_object_reference_id(
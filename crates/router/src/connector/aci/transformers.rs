@@ -57,6 +57,13 @@ pub enum PaymentDetails {
     Klarna,
     #[serde(rename = "bankRedirect")]
     BankRedirect,
+    #[serde(rename = "bankTransfer")]
+    BankTransfer,
+    Voucher,
+    Upi,
+    #[serde(rename = "bankDebit")]
+    BankDebit,
+    Crypto,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
@@ -111,6 +118,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AciPaymentsRequest {
             api::PaymentMethodData::PayLater(_) => PaymentDetails::Klarna,
             api::PaymentMethodData::Wallet(_) => PaymentDetails::Wallet,
             api::PaymentMethodData::BankRedirect(_) => PaymentDetails::BankRedirect,
+            api::PaymentMethodData::BankTransfer(_) => PaymentDetails::BankTransfer,
+            api::PaymentMethodData::Voucher(_) => PaymentDetails::Voucher,
+            api::PaymentMethodData::Upi(_) => PaymentDetails::Upi,
+            api::PaymentMethodData::BankDebit(_) => PaymentDetails::BankDebit,
+            api::PaymentMethodData::Crypto(_) => PaymentDetails::Crypto,
         };
 
         let auth = AciAuthType::try_from(&item.connector_auth_type)?;
@@ -215,6 +227,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
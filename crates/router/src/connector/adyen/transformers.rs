@@ -1,10 +1,12 @@
+use std::collections::HashMap;
+
 use api_models::webhooks::IncomingWebhookEvent;
 use masking::PeekInterface;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::RouterData,
+    connector::utils::{PaymentsAuthorizeRequestData, RouterData},
     consts,
     core::errors,
     pii::{self, Email, Secret},
@@ -43,8 +45,48 @@ pub enum AuthType {
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdditionalData {
-    authorisation_type: AuthType,
-    manual_capture: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorisation_type: Option<AuthType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manual_capture: Option<bool>,
+    /// Network transaction id of the customer-initiated transaction a merchant-initiated
+    /// transaction refers to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_tx_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sca_exemption: Option<AdyenScaExemption>,
+    /// Entries given by the merchant in the connector metadata of the payment
+    #[serde(flatten)]
+    merchant_data: HashMap<String, String>,
+}
+
+/// Entries of the additional data set from other fields of the payment, which the connector
+/// metadata of the payment can't override
+const ROUTER_ADDITIONAL_DATA_KEYS: [&str; 4] = [
+    "authorisationType",
+    "manualCapture",
+    "networkTxReference",
+    "scaExemption",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenScaExemption {
+    LowValue,
+    TransactionRiskAnalysis,
+}
+
+impl AdyenScaExemption {
+    /// Adyen doesn't take delegated authentication as an exemption
+    fn from_sca_exemption(sca_exemption: api_enums::ScaExemptionType) -> Option<Self> {
+        match sca_exemption {
+            api_enums::ScaExemptionType::LowValue => Some(Self::LowValue),
+            api_enums::ScaExemptionType::TransactionRiskAnalysis => {
+                Some(Self::TransactionRiskAnalysis)
+            }
+            api_enums::ScaExemptionType::DelegatedAuthentication => None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -97,6 +139,65 @@ pub struct AdyenPaymentRequest<'a> {
     delivery_address: Option<Address>,
     country_code: Option<String>,
     line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installments: Option<AdyenInstallments>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    splits: Option<Vec<AdyenSplitData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpi_data: Option<AdyenMpiData>,
+    /// Dynamic descriptor of the payment on the statement of the shopper
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shopper_statement: Option<String>,
+}
+
+/// Result of a 3DS authentication made outside of Adyen
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenMpiData {
+    directory_response: String,
+    authentication_response: String,
+    cavv: Secret<String>,
+    eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: String,
+}
+
+impl From<&types::ExternalAuthenticationData> for AdyenMpiData {
+    fn from(authentication_data: &types::ExternalAuthenticationData) -> Self {
+        Self {
+            directory_response: authentication_data.trans_status.clone(),
+            authentication_response: authentication_data.trans_status.clone(),
+            cavv: authentication_data.cavv.clone(),
+            eci: authentication_data.eci.clone(),
+            ds_trans_id: authentication_data.ds_trans_id.clone(),
+            three_ds_version: authentication_data.message_version.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct AdyenInstallments {
+    value: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct AdyenSplitData {
+    amount: Amount,
+    #[serde(rename = "type")]
+    split_type: AdyenSplitType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account: Option<String>,
+    reference: String,
+}
+
+#[derive(Debug, Serialize)]
+enum AdyenSplitType {
+    /// The part of the payment booked to the sub-merchant's account
+    MarketPlace,
+    /// The part of the payment booked to the platform's liable account
+    Commission,
 }
 
 #[derive(Debug, Serialize)]
@@ -179,6 +280,40 @@ pub struct AdyenResponse {
     merchant_reference: String,
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
+    additional_data: Option<AdyenResponseAdditionalData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenResponseAdditionalData {
+    network_tx_reference: Option<String>,
+    avs_result: Option<String>,
+    cvc_result: Option<String>,
+}
+
+impl AdyenResponseAdditionalData {
+    /// Maps the AVS result, a code followed by its description such as `7 Both postal code and
+    /// address match`, as listed in <https://docs.adyen.com/risk-management/avs-checks>
+    fn get_avs_result(&self) -> Option<storage_enums::AvsResult> {
+        let code = self.avs_result.as_deref()?.split_whitespace().next()?;
+        Some(match code {
+            "7" => storage_enums::AvsResult::Match,
+            "6" => storage_enums::AvsResult::PostalCodeMatch,
+            "1" => storage_enums::AvsResult::AddressMatch,
+            "2" => storage_enums::AvsResult::NoMatch,
+            _ => storage_enums::AvsResult::Unavailable,
+        })
+    }
+
+    /// Maps the CVC result, a code followed by its description such as `1 Matches`
+    fn get_cvv_result(&self) -> Option<storage_enums::CvvResult> {
+        let code = self.cvc_result.as_deref()?.split_whitespace().next()?;
+        Some(match code {
+            "1" => storage_enums::CvvResult::Match,
+            "2" => storage_enums::CvvResult::NoMatch,
+            _ => storage_enums::CvvResult::Unavailable,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -221,6 +356,7 @@ pub enum AdyenPaymentMethod<'a> {
     Ideal(BankRedirectionWithIssuer<'a>),
     Giropay(BankRedirectionPMData),
     Sofort(BankRedirectionPMData),
+    OnlineBankingPoland(BankRedirectionWithIssuer<'a>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -245,6 +381,43 @@ pub struct AdyenCard {
     expiry_month: Secret<String>,
     expiry_year: Secret<String>,
     cvc: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    brand: Option<CardBrand>,
+}
+
+/// Brands on which Adyen can be asked to process a card, used for co-badged cards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardBrand {
+    Visa,
+    #[serde(rename = "mc")]
+    MasterCard,
+    Amex,
+    #[serde(rename = "cartebancaire")]
+    CarteBancaire,
+    Jcb,
+    Diners,
+    Discover,
+    #[serde(rename = "cup")]
+    UnionPay,
+    Maestro,
+}
+
+impl CardBrand {
+    fn from_card_network(network: &api_enums::CardNetwork) -> Option<Self> {
+        match network {
+            api_enums::CardNetwork::Visa => Some(Self::Visa),
+            api_enums::CardNetwork::Mastercard => Some(Self::MasterCard),
+            api_enums::CardNetwork::AmericanExpress => Some(Self::Amex),
+            api_enums::CardNetwork::CartesBancaires => Some(Self::CarteBancaire),
+            api_enums::CardNetwork::JCB => Some(Self::Jcb),
+            api_enums::CardNetwork::DinersClub => Some(Self::Diners),
+            api_enums::CardNetwork::Discover => Some(Self::Discover),
+            api_enums::CardNetwork::UnionPay => Some(Self::UnionPay),
+            api_enums::CardNetwork::Maestro => Some(Self::Maestro),
+            api_enums::CardNetwork::Interac | api_enums::CardNetwork::RuPay => None,
+        }
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -303,10 +476,33 @@ pub struct AdyenPayLaterData {
 pub struct AdyenRefundRequest {
     merchant_account: String,
     amount: Amount,
-    merchant_refund_reason: Option<String>,
+    merchant_refund_reason: Option<AdyenRefundReason>,
     reference: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenRefundReason {
+    Fraud,
+    #[serde(rename = "CUSTOMER REQUEST")]
+    CustomerRequest,
+    Return,
+    Duplicate,
+    Other,
+}
+
+impl From<storage_enums::RefundReason> for AdyenRefundReason {
+    fn from(reason: storage_enums::RefundReason) -> Self {
+        match reason {
+            storage_enums::RefundReason::Duplicate => Self::Duplicate,
+            storage_enums::RefundReason::Fraudulent => Self::Fraud,
+            storage_enums::RefundReason::RequestedByCustomer => Self::CustomerRequest,
+            storage_enums::RefundReason::ProductReturned => Self::Return,
+            storage_enums::RefundReason::Other => Self::Other,
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenRefundResponse {
@@ -337,6 +533,40 @@ pub enum PaymentType {
     Giropay,
     #[serde(rename = "directEbanking")]
     Sofort,
+    #[serde(rename = "onlineBanking_PL")]
+    OnlineBankingPoland,
+}
+
+/// Issuer of the Polish bank of the customer, for Przelewy24 payments
+fn get_online_banking_poland_issuer(
+    bank_name: &api_enums::BankNames,
+) -> Result<&'static str, error_stack::Report<errors::ConnectorError>> {
+    Ok(match bank_name {
+        api_enums::BankNames::BlikPsp => "154",
+        api_enums::BankNames::PlaceZipko => "31",
+        api_enums::BankNames::MBank => "243",
+        api_enums::BankNames::PayWithIng => "112",
+        api_enums::BankNames::SantanderPrzelew24 => "20",
+        api_enums::BankNames::BankPekaoSa => "65",
+        api_enums::BankNames::BankMillennium => "85",
+        api_enums::BankNames::PayWithAliorBank => "88",
+        api_enums::BankNames::BankiSpoldzielcze => "141",
+        api_enums::BankNames::PayWithInteligo => "120",
+        api_enums::BankNames::BnpParibasPoland => "113",
+        api_enums::BankNames::BankNowySa => "115",
+        api_enums::BankNames::CreditAgricole => "119",
+        api_enums::BankNames::PayWithBos => "131",
+        api_enums::BankNames::PayWithCitiHandlowy => "132",
+        api_enums::BankNames::PayWithPlusBank => "133",
+        api_enums::BankNames::ToyotaBank => "153",
+        api_enums::BankNames::VeloBank => "64",
+        api_enums::BankNames::ETransferPocztowy24 => "94",
+        _ => Err(errors::ConnectorError::NotSupported {
+            payment_method: format!("przelewy24 with {bank_name}"),
+            connector: "Adyen",
+            payment_experience: api_enums::PaymentExperience::RedirectToUrl.to_string(),
+        })?,
+    })
 }
 
 pub struct AdyenTestBankNames<'a>(&'a str);
@@ -430,6 +660,13 @@ impl<'a> TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest<'a
             storage_models::enums::PaymentMethod::BankRedirect => {
                 get_bank_redirect_specific_payment_data(item)
             }
+            storage_models::enums::PaymentMethod::BankTransfer
+            | storage_models::enums::PaymentMethod::Voucher
+            | storage_models::enums::PaymentMethod::Upi
+            | storage_models::enums::PaymentMethod::BankDebit
+            | storage_models::enums::PaymentMethod::Crypto => {
+                Err(errors::ConnectorError::NotImplemented("Payment method".to_string()).into())
+            }
         }
     }
 }
@@ -450,6 +687,11 @@ fn get_recurring_processing_model(
         Some(storage_enums::FutureUsage::OffSession) => {
             Some(AdyenRecurringModel::UnscheduledCardOnFile)
         }
+        // Merchant-initiated transactions are framed as unscheduled ones on the card stored by the
+        // customer-initiated transaction they refer to
+        _ if get_network_transaction_id(item).is_some() => {
+            Some(AdyenRecurringModel::UnscheduledCardOnFile)
+        }
         _ => None,
     }
 }
@@ -475,13 +717,40 @@ fn get_browser_info(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenBr
 }
 
 fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdditionalData> {
-    match item.request.capture_method {
-        Some(storage_models::enums::CaptureMethod::Manual) => Some(AdditionalData {
-            authorisation_type: AuthType::PreAuth,
-            manual_capture: true,
-        }),
-        _ => None,
-    }
+    let (authorisation_type, manual_capture) = match item.request.capture_method {
+        Some(storage_models::enums::CaptureMethod::Manual) => (Some(AuthType::PreAuth), Some(true)),
+        _ => (None, None),
+    };
+    let network_tx_reference = get_network_transaction_id(item);
+    let sca_exemption = item
+        .request
+        .sca_exemption
+        .and_then(AdyenScaExemption::from_sca_exemption);
+    let merchant_data: HashMap<String, String> = item
+        .request
+        .connector_metadata
+        .as_ref()
+        .and_then(|connector_metadata| connector_metadata.adyen.as_ref())
+        .and_then(|adyen_metadata| adyen_metadata.additional_data.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(key, _)| !ROUTER_ADDITIONAL_DATA_KEYS.contains(&key.as_str()))
+        .collect();
+    (authorisation_type.is_some()
+        || network_tx_reference.is_some()
+        || sca_exemption.is_some()
+        || !merchant_data.is_empty())
+    .then_some(AdditionalData {
+        authorisation_type,
+        manual_capture,
+        network_tx_reference,
+        sca_exemption,
+        merchant_data,
+    })
+}
+
+fn get_network_transaction_id(item: &types::PaymentsAuthorizeRouterData) -> Option<String> {
+    item.request.get_network_transaction_id()
 }
 
 fn get_amount_data(item: &types::PaymentsAuthorizeRouterData) -> Amount {
@@ -491,6 +760,32 @@ fn get_amount_data(item: &types::PaymentsAuthorizeRouterData) -> Amount {
     }
 }
 
+fn get_splits(item: &types::PaymentsAuthorizeRouterData) -> Option<Vec<AdyenSplitData>> {
+    item.request.split_payments.as_ref().map(|split_payments| {
+        let currency = item.request.currency.to_string();
+        vec![
+            AdyenSplitData {
+                amount: Amount {
+                    currency: currency.clone(),
+                    value: split_payments.destination_amount,
+                },
+                split_type: AdyenSplitType::MarketPlace,
+                account: Some(split_payments.destination_account_id.clone()),
+                reference: format!("{}_marketplace", item.payment_id),
+            },
+            AdyenSplitData {
+                amount: Amount {
+                    currency,
+                    value: split_payments.application_fee,
+                },
+                split_type: AdyenSplitType::Commission,
+                account: None,
+                reference: format!("{}_commission", item.payment_id),
+            },
+        ]
+    })
+}
+
 fn get_address_info(address: Option<&api_models::payments::Address>) -> Option<Address> {
     address.and_then(|add| {
         add.address.as_ref().map(|a| Address {
@@ -566,7 +861,13 @@ fn get_payment_method_data<'a>(
                 number: card.card_number.clone(),
                 expiry_month: card.card_exp_month.clone(),
                 expiry_year: card.card_exp_year.clone(),
-                cvc: Some(card.card_cvc.clone()),
+                // Cards charged off-session from the locker have no CVC
+                cvc: Some(card.card_cvc.clone()).filter(|cvc| !cvc.peek().is_empty()),
+                brand: item
+                    .request
+                    .card_network
+                    .as_ref()
+                    .and_then(CardBrand::from_card_network),
             };
             Ok(AdyenPaymentMethod::AdyenCard(adyen_card))
         }
@@ -586,7 +887,12 @@ fn get_payment_method_data<'a>(
 
                 Ok(AdyenPaymentMethod::ApplePay(apple_pay_data))
             }
-            api_models::payments::WalletData::PaypalSdk(_) => {
+            api_models::payments::WalletData::PaypalSdk(_)
+            | api_models::payments::WalletData::PaytmRedirect(_)
+            | api_models::payments::WalletData::AliPayRedirect(_)
+            | api_models::payments::WalletData::WeChatPayRedirect(_)
+            | api_models::payments::WalletData::WeChatPayQr(_)
+            | api_models::payments::WalletData::ClickToPay(_) => {
                 Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
             }
             api_models::payments::WalletData::PaypalRedirect(_) => {
@@ -620,31 +926,55 @@ fn get_payment_method_data<'a>(
             }
         }
         api_models::payments::PaymentMethodData::BankRedirect(ref bank_redirect_data) => {
-            match bank_redirect_data {
-                api_models::payments::BankRedirectData::Eps { bank_name, .. } => {
-                    Ok(AdyenPaymentMethod::Eps(BankRedirectionWithIssuer {
-                        payment_type: PaymentType::Eps,
-                        issuer: AdyenTestBankNames::try_from(bank_name)?.0,
-                    }))
-                }
-                api_models::payments::BankRedirectData::Ideal { bank_name, .. } => {
-                    Ok(AdyenPaymentMethod::Ideal(BankRedirectionWithIssuer {
-                        payment_type: PaymentType::Ideal,
-                        issuer: AdyenTestBankNames::try_from(bank_name)?.0,
-                    }))
-                }
+            get_bank_redirect_payment_method(bank_redirect_data)
+        }
+    }
+}
 
-                api_models::payments::BankRedirectData::Giropay { .. } => {
-                    Ok(AdyenPaymentMethod::Giropay(BankRedirectionPMData {
-                        payment_type: PaymentType::Giropay,
-                    }))
-                }
-                api_models::payments::BankRedirectData::Sofort { .. } => {
-                    Ok(AdyenPaymentMethod::Sofort(BankRedirectionPMData {
-                        payment_type: PaymentType::Sofort,
-                    }))
-                }
-            }
+fn get_bank_redirect_payment_method<'a>(
+    bank_redirect_data: &api_models::payments::BankRedirectData,
+) -> Result<AdyenPaymentMethod<'a>, error_stack::Report<errors::ConnectorError>> {
+    match bank_redirect_data {
+        api_models::payments::BankRedirectData::Eps { bank_name, .. } => {
+            Ok(AdyenPaymentMethod::Eps(BankRedirectionWithIssuer {
+                payment_type: PaymentType::Eps,
+                issuer: AdyenTestBankNames::try_from(bank_name)?.0,
+            }))
+        }
+        api_models::payments::BankRedirectData::Ideal { bank_name, .. } => {
+            Ok(AdyenPaymentMethod::Ideal(BankRedirectionWithIssuer {
+                payment_type: PaymentType::Ideal,
+                issuer: AdyenTestBankNames::try_from(bank_name)?.0,
+            }))
+        }
+
+        api_models::payments::BankRedirectData::Giropay { .. } => {
+            Ok(AdyenPaymentMethod::Giropay(BankRedirectionPMData {
+                payment_type: PaymentType::Giropay,
+            }))
+        }
+        api_models::payments::BankRedirectData::Sofort { .. } => {
+            Ok(AdyenPaymentMethod::Sofort(BankRedirectionPMData {
+                payment_type: PaymentType::Sofort,
+            }))
+        }
+        api_models::payments::BankRedirectData::Przelewy24 { bank_name, .. } => {
+            let bank_name =
+                bank_name
+                    .as_ref()
+                    .ok_or(errors::ConnectorError::MissingRequiredField {
+                        field_name: "payment_method_data.bank_redirect.przelewy24.bank_name",
+                    })?;
+            Ok(AdyenPaymentMethod::OnlineBankingPoland(
+                BankRedirectionWithIssuer {
+                    payment_type: PaymentType::OnlineBankingPoland,
+                    issuer: get_online_banking_poland_issuer(bank_name)?,
+                },
+            ))
+        }
+        api_models::payments::BankRedirectData::Blik { .. }
+        | api_models::payments::BankRedirectData::Trustly { .. } => {
+            Err(errors::ConnectorError::NotImplemented("Payment method".to_string()).into())
         }
     }
 }
@@ -660,6 +990,13 @@ fn get_card_specific_payment_data<'a>(
     let additional_data = get_additional_data(item);
     let return_url = item.get_return_url()?;
     let payment_method = get_payment_method_data(item)?;
+    let installments = item
+        .request
+        .installments
+        .as_ref()
+        .map(|installments| AdyenInstallments {
+            value: installments.number_of_installments.get(),
+        });
     Ok(AdyenPaymentRequest {
         amount,
         merchant_account: auth_type.merchant_account,
@@ -678,6 +1015,14 @@ fn get_card_specific_payment_data<'a>(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        installments,
+        splits: get_splits(item),
+        mpi_data: item
+            .request
+            .external_authentication_data
+            .as_ref()
+            .map(AdyenMpiData::from),
+        shopper_statement: item.request.get_statement_descriptor(),
     })
 }
 
@@ -702,6 +1047,20 @@ fn get_sofort_extra_details(
         _ => (None, None),
     }
 }
+
+fn get_bank_redirect_shopper_email(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Option<Secret<String, Email>> {
+    match item.request.payment_method_data {
+        api_models::payments::PaymentMethodData::BankRedirect(
+            api_models::payments::BankRedirectData::Przelewy24 {
+                ref billing_email, ..
+            },
+        ) => Some(billing_email.clone()),
+        _ => item.request.email.clone(),
+    }
+}
+
 fn get_bank_redirect_specific_payment_data<'a>(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<AdyenPaymentRequest<'a>, error_stack::Report<errors::ConnectorError>> {
@@ -714,6 +1073,7 @@ fn get_bank_redirect_specific_payment_data<'a>(
     let return_url = item.get_return_url()?;
     let payment_method = get_payment_method_data(item)?;
     let (shopper_locale, country) = get_sofort_extra_details(item);
+    let shopper_email = get_bank_redirect_shopper_email(item);
 
     Ok(AdyenPaymentRequest {
         amount,
@@ -727,12 +1087,16 @@ fn get_bank_redirect_specific_payment_data<'a>(
         additional_data,
         telephone_number: None,
         shopper_name: None,
-        shopper_email: None,
+        shopper_email,
         shopper_locale,
         billing_address: None,
         delivery_address: None,
         country_code: country,
         line_items: None,
+        installments: None,
+        splits: get_splits(item),
+        mpi_data: None,
+        shopper_statement: item.request.get_statement_descriptor(),
     })
 }
 
@@ -765,6 +1129,10 @@ fn get_wallet_specific_payment_data<'a>(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        installments: None,
+        splits: get_splits(item),
+        mpi_data: None,
+        shopper_statement: item.request.get_statement_descriptor(),
     })
 }
 
@@ -804,6 +1172,10 @@ fn get_paylater_specific_payment_data<'a>(
         delivery_address,
         country_code,
         line_items,
+        installments: None,
+        splits: get_splits(item),
+        mpi_data: None,
+        shopper_statement: item.request.get_statement_descriptor(),
     })
 }
 
@@ -841,6 +1213,9 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<AdyenCancelResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -872,6 +1247,7 @@ pub fn get_adyen_response(
     };
     let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
         Some(types::ErrorResponse {
+            unified_code: get_unified_code(response.refusal_reason_code.as_deref()),
             code: response
                 .refusal_reason_code
                 .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
@@ -885,15 +1261,48 @@ pub fn get_adyen_response(
         None
     };
 
+    let additional_data = response.additional_data;
     let payments_response_data = types::PaymentsResponseData::TransactionResponse {
         resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
         redirection_data: None,
         mandate_reference: None,
         connector_metadata: None,
+        avs_result: additional_data
+            .as_ref()
+            .and_then(AdyenResponseAdditionalData::get_avs_result),
+        cvv_result: additional_data
+            .as_ref()
+            .and_then(AdyenResponseAdditionalData::get_cvv_result),
+        network_txn_id: additional_data
+            .and_then(|additional_data| additional_data.network_tx_reference),
     };
     Ok((status, error, payments_response_data))
 }
 
+/// Maps the refusal reason code of a payment, as listed in
+/// <https://docs.adyen.com/development-resources/refusal-reasons>, to the unified code.
+fn get_unified_code(refusal_reason_code: Option<&str>) -> Option<storage_enums::UnifiedCode> {
+    match refusal_reason_code? {
+        "2" => Some(storage_enums::UnifiedCode::GenericDecline),
+        "3" | "27" => Some(storage_enums::UnifiedCode::DoNotHonor),
+        "4" => Some(storage_enums::UnifiedCode::ProcessingError),
+        "5" | "25" => Some(storage_enums::UnifiedCode::RestrictedCard),
+        "6" => Some(storage_enums::UnifiedCode::ExpiredCard),
+        "7" => Some(storage_enums::UnifiedCode::InvalidAmount),
+        "8" => Some(storage_enums::UnifiedCode::InvalidCardNumber),
+        "9" => Some(storage_enums::UnifiedCode::IssuerUnavailable),
+        "10" => Some(storage_enums::UnifiedCode::CardNotSupported),
+        "11" | "42" => Some(storage_enums::UnifiedCode::AuthenticationFailed),
+        "12" => Some(storage_enums::UnifiedCode::InsufficientFunds),
+        "14" | "20" | "22" | "31" => Some(storage_enums::UnifiedCode::FraudSuspected),
+        "23" | "26" => Some(storage_enums::UnifiedCode::TransactionNotAllowed),
+        "24" => Some(storage_enums::UnifiedCode::IncorrectCvc),
+        "28" | "29" => Some(storage_enums::UnifiedCode::LimitExceeded),
+        "38" => Some(storage_enums::UnifiedCode::AuthenticationRequired),
+        _ => None,
+    }
+}
+
 pub fn get_redirection_response(
     response: AdyenRedirectionResponse,
     status_code: u16,
@@ -909,6 +1318,7 @@ pub fn get_redirection_response(
 
     let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
         Some(types::ErrorResponse {
+            unified_code: get_unified_code(response.refusal_reason_code.as_deref()),
             code: response
                 .refusal_reason_code
                 .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
@@ -944,6 +1354,9 @@ pub fn get_redirection_response(
         redirection_data: Some(redirection_data),
         mandate_reference: None,
         connector_metadata: None,
+        network_txn_id: None,
+        avs_result: None,
+        cvv_result: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -991,9 +1404,14 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for AdyenCaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        // Every partial capture needs its own reference when capturing multiple times
+        let reference = match item.request.multiple_capture_data {
+            Some(ref multiple_capture_data) => multiple_capture_data.capture_reference.clone(),
+            None => item.payment_id.to_string(),
+        };
         Ok(Self {
             merchant_account: auth_type.merchant_account,
-            reference: item.payment_id.to_string(),
+            reference,
             amount: Amount {
                 currency: item.request.currency.to_string(),
                 value: item
@@ -1037,6 +1455,9 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured,
             ..item.data
@@ -1088,7 +1509,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for AdyenRefundRequest {
                 currency: item.request.currency.to_string(),
                 value: item.request.refund_amount,
             },
-            merchant_refund_reason: item.request.reason.clone(),
+            merchant_refund_reason: item.request.reason_code.map(AdyenRefundReason::from),
             reference: item.request.refund_id.clone(),
         })
     }
@@ -1118,6 +1539,316 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     }
 }
 
+// Payouts Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank: Option<AdyenPayoutBank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<AdyenPayoutCard>,
+    merchant_account: String,
+    // Instant card payouts do not store the card details for later submissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurring: Option<AdyenPayoutRecurring>,
+    reference: String,
+    shopper_email: Secret<String, Email>,
+    shopper_reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    owner_name: Secret<String>,
+    country_code: Option<String>,
+    bic: Option<Secret<String>>,
+    bank_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    result_code: AdyenPayoutResultCode,
+    refusal_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AdyenPayoutResultCode {
+    #[serde(rename = "[payout-submit-received]")]
+    PayoutSubmitReceived,
+    Received,
+    Authorised,
+    Refused,
+    Error,
+}
+
+impl From<AdyenPayoutResultCode> for storage_enums::PayoutStatus {
+    fn from(result_code: AdyenPayoutResultCode) -> Self {
+        match result_code {
+            // Submitted payouts are held until they are confirmed (or declined) by a reviewer
+            AdyenPayoutResultCode::PayoutSubmitReceived | AdyenPayoutResultCode::Received => {
+                Self::Pending
+            }
+            AdyenPayoutResultCode::Authorised => Self::Success,
+            AdyenPayoutResultCode::Refused | AdyenPayoutResultCode::Error => Self::Failed,
+        }
+    }
+}
+
+// Payouts Request Transform
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item.request.payout_method_data.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        let (bank, card) = match payout_method_data {
+            api::PayoutMethodData::Bank(bank) => (
+                Some(AdyenPayoutBank {
+                    iban: bank.iban.clone(),
+                    owner_name: bank.account_holder_name.clone(),
+                    country_code: bank.bank_country_code.clone(),
+                    bic: bank.bic.clone(),
+                    bank_name: bank.bank_name.clone(),
+                }),
+                None,
+            ),
+            api::PayoutMethodData::Card(card) => (
+                None,
+                Some(AdyenPayoutCard {
+                    number: card.card_number.clone(),
+                    expiry_month: card.expiry_month.clone(),
+                    expiry_year: card.expiry_year.clone(),
+                    holder_name: card.card_holder_name.clone(),
+                }),
+            ),
+        };
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            merchant_account: auth_type.merchant_account,
+            recurring: bank.is_some().then_some(AdyenPayoutRecurring {
+                contract: AdyenPayoutContract::Payout,
+            }),
+            bank,
+            card,
+            reference: item.request.payout_id.clone(),
+            shopper_email: item.request.email.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "email",
+                },
+            )?,
+            shopper_reference: item.request.customer_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "customer_id",
+                },
+            )?,
+        })
+    }
+}
+
+// Payouts Response Transform
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = storage_enums::PayoutStatus::from(item.response.result_code);
+        let response = match status {
+            storage_enums::PayoutStatus::Failed => Err(types::ErrorResponse {
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: item
+                    .response
+                    .refusal_reason
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.refusal_reason,
+                status_code: item.http_code,
+                unified_code: None,
+            }),
+            _ => Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.psp_reference,
+                status,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Payouts Review Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutReviewRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutReviewResponse {
+    psp_reference: String,
+    response: AdyenPayoutReviewResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AdyenPayoutReviewResult {
+    #[serde(rename = "[payout-confirm-received]")]
+    ConfirmReceived,
+    #[serde(rename = "[payout-decline-received]")]
+    DeclineReceived,
+}
+
+impl From<AdyenPayoutReviewResult> for storage_enums::PayoutStatus {
+    fn from(result: AdyenPayoutReviewResult) -> Self {
+        match result {
+            AdyenPayoutReviewResult::ConfirmReceived => Self::Success,
+            AdyenPayoutReviewResult::DeclineReceived => Self::Cancelled,
+        }
+    }
+}
+
+// Payouts Review Request Transform
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutReviewRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item.request.connector_payout_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "connector_payout_id",
+                },
+            )?,
+        })
+    }
+}
+
+// Payouts Review Response Transform
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutReviewResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutReviewResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The review is acknowledged under a new reference, while notifications for the payout
+        // keep referring to the one it was submitted with
+        let connector_payout_id = item
+            .data
+            .request
+            .connector_payout_id
+            .clone()
+            .unwrap_or(item.response.psp_reference);
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id,
+                status: storage_enums::PayoutStatus::from(item.response.response),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Mandate Revoke Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDisableRecurringRequest {
+    merchant_account: String,
+    shopper_reference: String,
+    recurring_detail_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdyenDisableRecurringResponse {
+    response: String,
+}
+
+impl TryFrom<&types::MandateRevokeRouterData> for AdyenDisableRecurringRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::MandateRevokeRouterData) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            shopper_reference: item.request.customer_id.clone(),
+            recurring_detail_reference: item.request.connector_mandate_id.clone(),
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::MandateRevoke,
+            AdyenDisableRecurringResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    > for types::MandateRevokeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::MandateRevoke,
+            AdyenDisableRecurringResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Adyen acknowledges a disabled stored detail with "[detail-successfully-disabled]"
+        // or "[all-details-successfully-disabled]"
+        let response = if item.response.response.contains("successfully-disabled") {
+            Ok(types::MandateRevokeResponseData {
+                mandate_status: storage_enums::MandateStatus::Revoked,
+            })
+        } else {
+            Err(types::ErrorResponse {
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: item.response.response.clone(),
+                reason: Some(item.response.response),
+                status_code: item.http_code,
+                unified_code: None,
+            })
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
@@ -1221,6 +1952,61 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
             }),
             refusal_reason: None,
             refusal_reason_code: None,
+            additional_data: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn przelewy24(
+        bank_name: Option<api_enums::BankNames>,
+    ) -> api_models::payments::BankRedirectData {
+        api_models::payments::BankRedirectData::Przelewy24 {
+            billing_details: api_models::payments::BankRedirectBilling {
+                billing_name: Secret::new("John Doe".to_string()),
+            },
+            bank_name,
+            billing_email: Secret::new("john.doe@example.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_przelewy24_is_sent_as_online_banking_poland() {
+        let payment_method =
+            get_bank_redirect_payment_method(&przelewy24(Some(api_enums::BankNames::MBank)))
+                .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(payment_method).unwrap(),
+            serde_json::json!({ "type": "onlineBanking_PL", "issuer": "243" })
+        );
+    }
+
+    #[test]
+    fn test_przelewy24_requires_a_bank() {
+        let error = get_bank_redirect_payment_method(&przelewy24(None)).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_method_data.bank_redirect.przelewy24.bank_name"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_przelewy24_rejects_banks_outside_poland() {
+        let error =
+            get_bank_redirect_payment_method(&przelewy24(Some(api_enums::BankNames::AbnAmro)))
+                .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::NotSupported { .. }
+        ));
+    }
+}
@@ -0,0 +1,522 @@
+use api_models::payments as api_payments;
+use common_utils::pii::Email;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, AddressDetailsData, PaymentsAuthorizeRequestData, RouterData},
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct AffirmAuthType {
+    pub(super) public_key: String,
+    pub(super) private_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for AffirmAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                public_key: api_key.to_string(),
+                private_key: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Checkout Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AffirmConfirmationUrlAction {
+    Get,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmMerchant {
+    user_confirmation_url: String,
+    user_cancel_url: String,
+    user_confirmation_url_action: AffirmConfirmationUrlAction,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmName {
+    first: Secret<String>,
+    last: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmAddress {
+    line1: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line2: Option<Secret<String>>,
+    city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<Secret<String>>,
+    zipcode: Secret<String>,
+    country: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmContact {
+    name: AffirmName,
+    address: AffirmAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<Secret<String, Email>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmItem {
+    display_name: String,
+    sku: String,
+    unit_price: i64,
+    qty: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmCheckoutRequest {
+    merchant: AffirmMerchant,
+    shipping: AffirmContact,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing: Option<AffirmContact>,
+    items: Vec<AffirmItem>,
+    order_id: String,
+    currency: enums::Currency,
+    total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    financing_program: Option<String>,
+}
+
+fn get_affirm_contact(
+    address: &api::AddressDetails,
+    email: Option<Secret<String, Email>>,
+) -> Result<AffirmContact, Error> {
+    Ok(AffirmContact {
+        name: AffirmName {
+            first: address.get_first_name()?.to_owned(),
+            last: address.get_last_name()?.to_owned(),
+        },
+        address: AffirmAddress {
+            line1: address.get_line1()?.to_owned(),
+            line2: address.line2.clone(),
+            city: address.get_city()?.to_owned(),
+            state: address.state.clone(),
+            zipcode: address.get_zip()?.to_owned(),
+            country: address.get_country()?.to_owned(),
+        },
+        email,
+    })
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for AffirmCheckoutRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let (financing_program, billing_email) = match &item.request.payment_method_data {
+            api::PaymentMethodData::PayLater(api_payments::PayLaterData::AffirmRedirect {
+                financing_program,
+                billing_email,
+            }) => (financing_program.clone(), billing_email.clone()),
+            _ => return Err(errors::ConnectorError::MismatchedPaymentData.into()),
+        };
+        // Affirm authorizes the loan against a separate capture once the order is fulfilled
+        if item.request.is_auto_capture() {
+            return Err(errors::ConnectorError::NotImplemented(
+                "Automatic capture for affirm".to_string(),
+            )
+            .into());
+        }
+        let email = billing_email.or_else(|| item.request.email.clone());
+        let billing = item
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref());
+        let shipping = item
+            .address
+            .shipping
+            .as_ref()
+            .and_then(|shipping| shipping.address.as_ref())
+            .or(billing)
+            .ok_or_else(utils::missing_field_err("shipping.address"))?;
+        let order_details = item
+            .request
+            .order_details
+            .clone()
+            .ok_or_else(utils::missing_field_err("order_details"))?;
+        let return_url = item.get_return_url()?;
+        Ok(Self {
+            merchant: AffirmMerchant {
+                user_confirmation_url: return_url.clone(),
+                user_cancel_url: return_url,
+                user_confirmation_url_action: AffirmConfirmationUrlAction::Get,
+            },
+            shipping: get_affirm_contact(shipping, email.clone())?,
+            billing: billing
+                .map(|billing| get_affirm_contact(billing, email))
+                .transpose()?,
+            items: vec![AffirmItem {
+                display_name: order_details.product_name.clone(),
+                sku: order_details.product_name,
+                unit_price: item.request.amount / i64::from(order_details.quantity.max(1)),
+                qty: order_details.quantity,
+            }],
+            order_id: item.payment_id.clone(),
+            currency: item.request.currency,
+            total: item.request.amount,
+            financing_program,
+        })
+    }
+}
+
+// Checkout Response
+#[derive(Debug, Deserialize)]
+pub struct AffirmCheckoutResponse {
+    checkout_id: String,
+    redirect_url: Url,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AffirmCheckoutResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, AffirmCheckoutResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.checkout_id),
+                redirection_data: Some(services::RedirectForm::from((
+                    item.response.redirect_url,
+                    services::Method::Get,
+                ))),
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Customers are sent back to the confirmation url with the checkout token once they
+// accept the loan, after which the transaction has to be authorized by the merchant
+#[derive(Debug, Deserialize)]
+pub struct AffirmRedirectResponse {
+    checkout_token: Option<String>,
+}
+
+pub fn get_checkout_token(item: &types::PaymentsSyncRouterData) -> Option<String> {
+    if item.status != enums::AttemptStatus::AuthenticationPending {
+        return None;
+    }
+    item.request
+        .encoded_data
+        .as_ref()
+        .and_then(|data| serde_urlencoded::from_str::<AffirmRedirectResponse>(data).ok())
+        .and_then(|redirect| redirect.checkout_token)
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmAuthorizeRequest {
+    transaction_id: String,
+    order_id: String,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for AffirmAuthorizeRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_id: get_checkout_token(item)
+                .ok_or_else(utils::missing_field_err("checkout_token"))?,
+            order_id: item.payment_id.clone(),
+        })
+    }
+}
+
+// Transaction Response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AffirmTransactionStatus {
+    #[default]
+    Authorized,
+    AuthExpired,
+    Captured,
+    PartiallyCaptured,
+    Voided,
+    PartiallyRefunded,
+    Refunded,
+}
+
+impl From<AffirmTransactionStatus> for enums::AttemptStatus {
+    fn from(item: AffirmTransactionStatus) -> Self {
+        match item {
+            AffirmTransactionStatus::Authorized => Self::Authorized,
+            AffirmTransactionStatus::AuthExpired => Self::Failure,
+            AffirmTransactionStatus::PartiallyCaptured => Self::PartialCharged,
+            AffirmTransactionStatus::Captured
+            | AffirmTransactionStatus::PartiallyRefunded
+            | AffirmTransactionStatus::Refunded => Self::Charged,
+            AffirmTransactionStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AffirmEventType {
+    Auth,
+    Capture,
+    Void,
+    Refund,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffirmTransactionEvent {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: AffirmEventType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AffirmTransactionResponse {
+    pub id: String,
+    pub status: AffirmTransactionStatus,
+    #[serde(default)]
+    pub events: Vec<AffirmTransactionEvent>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AffirmTransactionResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AffirmTransactionResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Capture Request
+#[derive(Debug, Serialize)]
+pub struct AffirmCaptureRequest {
+    amount: i64,
+    order_id: String,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for AffirmCaptureRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+            order_id: item.payment_id.clone(),
+        })
+    }
+}
+
+// Capture, Void and Refund operations respond with the event they created on the transaction
+#[derive(Debug, Deserialize)]
+pub struct AffirmEventResponse {
+    pub id: String,
+    pub amount: Option<i64>,
+}
+
+impl TryFrom<types::PaymentsCaptureResponseRouterData<AffirmEventResponse>>
+    for types::PaymentsCaptureRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsCaptureResponseRouterData<AffirmEventResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.amount {
+            Some(amount) if amount < item.data.request.amount => {
+                enums::AttemptStatus::PartialCharged
+            }
+            _ => enums::AttemptStatus::Charged,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::PaymentsCancelResponseRouterData<AffirmEventResponse>>
+    for types::PaymentsCancelRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsCancelResponseRouterData<AffirmEventResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::Voided,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+pub struct AffirmRefundRequest {
+    amount: i64,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for AffirmRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.refund_amount,
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, AffirmEventResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, AffirmEventResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Refunds are only recorded as events on the transaction, so a refund is synced by looking
+// for its event
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, AffirmTransactionResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, AffirmTransactionResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .data
+            .request
+            .connector_refund_id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+        let refund_status = if item.response.events.iter().any(|event| {
+            matches!(event.event_type, AffirmEventType::Refund) && event.id == connector_refund_id
+        }) {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+pub enum AffirmWebhookEventType {
+    #[serde(rename = "transaction.authorized")]
+    TransactionAuthorized,
+    #[serde(rename = "transaction.captured")]
+    TransactionCaptured,
+    #[serde(rename = "transaction.voided")]
+    TransactionVoided,
+    #[serde(rename = "transaction.auth_expired")]
+    TransactionAuthExpired,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AffirmWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: AffirmWebhookEventType,
+    pub data: AffirmTransactionResponse,
+}
+
+impl TryFrom<AffirmWebhookEventType> for api::IncomingWebhookEvent {
+    type Error = Error;
+    fn try_from(event: AffirmWebhookEventType) -> Result<Self, Self::Error> {
+        match event {
+            AffirmWebhookEventType::TransactionAuthorized => Ok(Self::PaymentIntentSuccess),
+            AffirmWebhookEventType::TransactionCaptured => Ok(Self::PaymentIntentCaptureSuccess),
+            AffirmWebhookEventType::TransactionVoided => Ok(Self::PaymentIntentFailure),
+            AffirmWebhookEventType::TransactionAuthExpired => {
+                Ok(Self::PaymentIntentAuthorizationExpired)
+            }
+            AffirmWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+        }
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+pub struct AffirmErrorResponse {
+    pub code: String,
+    pub message: String,
+    pub field: Option<String>,
+}
@@ -0,0 +1,627 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use base64::Engine;
+use error_stack::ResultExt;
+use transformers as afterpay_clearpay;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct AfterpayClearpay;
+
+impl AfterpayClearpay {
+    fn regional_base_url<'a>(
+        &self,
+        connectors: &'a settings::Connectors,
+        region: afterpay_clearpay::AfterpayClearpayRegion,
+    ) -> &'a str {
+        let urls = &connectors.afterpay_clearpay;
+        match region {
+            afterpay_clearpay::AfterpayClearpayRegion::Au => urls.base_url.as_ref(),
+            afterpay_clearpay::AfterpayClearpayRegion::Nz => urls.nz_base_url.as_ref(),
+            afterpay_clearpay::AfterpayClearpayRegion::Us => urls.us_base_url.as_ref(),
+            afterpay_clearpay::AfterpayClearpayRegion::Uk => urls.uk_base_url.as_ref(),
+        }
+    }
+}
+
+impl api::Payment for AfterpayClearpay {}
+impl api::PaymentSession for AfterpayClearpay {}
+impl api::ConnectorAccessToken for AfterpayClearpay {}
+impl api::PreVerify for AfterpayClearpay {}
+impl api::PaymentAuthorize for AfterpayClearpay {}
+impl api::PaymentSync for AfterpayClearpay {}
+impl api::PaymentCapture for AfterpayClearpay {}
+impl api::PaymentVoid for AfterpayClearpay {}
+impl api::Refund for AfterpayClearpay {}
+impl api::RefundExecute for AfterpayClearpay {}
+impl api::RefundSync for AfterpayClearpay {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for AfterpayClearpay
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ),
+            (
+                headers::ACCEPT.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (
+                headers::USER_AGENT.to_string(),
+                format!("Hyperswitch/{}", req.merchant_id),
+            ),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for AfterpayClearpay {
+    fn id(&self) -> &'static str {
+        "afterpay_clearpay"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.afterpay_clearpay.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = afterpay_clearpay::AfterpayClearpayAuthType::try_from(auth_type)?;
+        let encoded_api_key =
+            consts::BASE64_ENGINE.encode(format!("{}:{}", auth.merchant_id, auth.secret_key));
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Basic {encoded_api_key}"),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: afterpay_clearpay::AfterpayClearpayErrorResponse = res
+            .response
+            .parse_struct("AfterpayClearpay ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: response.error_id,
+            unified_code: None,
+        })
+    }
+}
+
+impl api::ConnectorSpecifications for AfterpayClearpay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::PayLater])
+    }
+
+    fn supported_currencies(&self) -> Option<&'static [api::enums::Currency]> {
+        Some(&[
+            api::enums::Currency::AUD,
+            api::enums::Currency::CAD,
+            api::enums::Currency::GBP,
+            api::enums::Currency::NZD,
+            api::enums::Currency::USD,
+        ])
+    }
+
+    fn supported_countries(&self) -> Option<&'static [&'static str]> {
+        Some(&["AU", "CA", "GB", "NZ", "US"])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+
+    fn supports_mandates(&self) -> bool {
+        false
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for AfterpayClearpay
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let region = afterpay_clearpay::AfterpayClearpayRegion::from(req.request.currency);
+        Ok(format!(
+            "{}v2/checkouts",
+            self.regional_base_url(connectors, region)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let afterpay_clearpay_req = utils::Encode::<
+            afterpay_clearpay::AfterpayClearpayCheckoutRequest,
+        >::convert_and_encode(req)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(afterpay_clearpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(afterpay_clearpay_checkout_response=?res);
+        let response: afterpay_clearpay::AfterpayClearpayCheckoutResponse = res
+            .response
+            .parse_struct("AfterpayClearpay CheckoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let meta = afterpay_clearpay::AfterpayClearpayMeta::from_connector_meta(
+            req.request.connector_meta.clone(),
+        );
+        let base_url = self.regional_base_url(connectors, meta.region);
+        // The payment is only created once the confirmed checkout is captured
+        if afterpay_clearpay::get_confirmed_checkout_token(req).is_some() {
+            return Ok(format!("{base_url}v2/payments/capture"));
+        }
+        let transaction_id = req
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        if req.status == types::storage::enums::AttemptStatus::AuthenticationPending {
+            Ok(format!("{base_url}v2/payments/token:{transaction_id}"))
+        } else {
+            Ok(format!("{base_url}v2/payments/{transaction_id}"))
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        if afterpay_clearpay::get_confirmed_checkout_token(req).is_none() {
+            return Ok(None);
+        }
+        let afterpay_clearpay_req = utils::Encode::<
+            afterpay_clearpay::AfterpayClearpayCaptureRequest,
+        >::convert_and_encode(req)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(afterpay_clearpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let method = if afterpay_clearpay::get_confirmed_checkout_token(req).is_some() {
+            services::Method::Post
+        } else {
+            services::Method::Get
+        };
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(method)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .body(types::PaymentsSyncType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(afterpay_clearpay_payment_sync_response=?res);
+        let response: afterpay_clearpay::AfterpayClearpayPaymentResponse = res
+            .response
+            .parse_struct("AfterpayClearpay PaymentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for AfterpayClearpay
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let region = afterpay_clearpay::AfterpayClearpayRegion::from(req.request.currency);
+        Ok(format!(
+            "{}v2/payments/{}/refund",
+            self.regional_base_url(connectors, region),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let afterpay_clearpay_req =
+            utils::Encode::<afterpay_clearpay::AfterpayClearpayRefundRequest>::convert_and_encode(
+                req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(afterpay_clearpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(afterpay_clearpay_refund_response=?res);
+        let response: afterpay_clearpay::AfterpayClearpayRefundResponse = res
+            .response
+            .parse_struct("AfterpayClearpay RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for AfterpayClearpay
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let region = afterpay_clearpay::AfterpayClearpayRegion::from(req.request.currency);
+        Ok(format!(
+            "{}v2/payments/{}",
+            self.regional_base_url(connectors, region),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::RefundSyncType::get_url(self, req, connectors)?)
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        logger::debug!(afterpay_clearpay_refund_sync_response=?res);
+        let response: afterpay_clearpay::AfterpayClearpayPaymentResponse = res
+            .response
+            .parse_struct("AfterpayClearpay PaymentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Payouts for AfterpayClearpay {}
+impl api::PayoutCreate for AfterpayClearpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl api::PayoutFulfill for AfterpayClearpay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl api::PayoutCancel for AfterpayClearpay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl api::PayoutSync for AfterpayClearpay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl api::RefundCredit for AfterpayClearpay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for AfterpayClearpay
+{
+}
+
+impl api::ConnectorMandateRevoke for AfterpayClearpay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for AfterpayClearpay
+{
+}
+
+impl api::Dispute for AfterpayClearpay {}
+impl api::SubmitEvidence for AfterpayClearpay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for AfterpayClearpay
+{
+}
+
+// Afterpay does not sign its notifications, so they are never trusted as is and the payment or
+// refund is synced with the connector instead
+#[async_trait::async_trait]
+impl api::IncomingWebhook for AfterpayClearpay {
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let webhook: afterpay_clearpay::AfterpayClearpayWebhookBody = request
+            .body
+            .parse_struct("AfterpayClearpayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        webhook.get_reference_id()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: afterpay_clearpay::AfterpayClearpayWebhookBody = request
+            .body
+            .parse_struct("AfterpayClearpayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(webhook.event_type.into())
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        request
+            .body
+            .parse_struct("AfterpayClearpayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+}
+
+impl services::ConnectorRedirectResponse for AfterpayClearpay {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
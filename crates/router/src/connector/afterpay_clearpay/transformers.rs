@@ -0,0 +1,484 @@
+use api_models::payments as api_payments;
+use common_utils::pii::Email;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, RouterData},
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct AfterpayClearpayAuthType {
+    pub(super) merchant_id: String,
+    pub(super) secret_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for AfterpayClearpayAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                merchant_id: api_key.to_string(),
+                secret_key: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Afterpay runs a separate deployment per region, Clearpay being the UK one. The region is
+// decided by the currency of the payment, and kept in the connector metadata so that the
+// payment is synced and refunded against the same deployment.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AfterpayClearpayRegion {
+    #[default]
+    Au,
+    Nz,
+    Us,
+    Uk,
+}
+
+impl From<enums::Currency> for AfterpayClearpayRegion {
+    fn from(currency: enums::Currency) -> Self {
+        match currency {
+            enums::Currency::NZD => Self::Nz,
+            enums::Currency::USD | enums::Currency::CAD => Self::Us,
+            enums::Currency::GBP => Self::Uk,
+            _ => Self::Au,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AfterpayClearpayMeta {
+    pub region: AfterpayClearpayRegion,
+}
+
+impl AfterpayClearpayMeta {
+    pub fn from_connector_meta(connector_meta: Option<serde_json::Value>) -> Self {
+        connector_meta
+            .and_then(|meta| serde_json::from_value(meta).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AfterpayClearpayAmount {
+    amount: String,
+    currency: enums::Currency,
+}
+
+impl AfterpayClearpayAmount {
+    fn new(amount: i64, currency: enums::Currency) -> Result<Self, Error> {
+        Ok(Self {
+            amount: utils::to_currency_base_unit(amount, currency)?,
+            currency,
+        })
+    }
+}
+
+// Checkout Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayConsumer {
+    email: Secret<String, Email>,
+    given_names: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    surname: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line1: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line2: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    area1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    postcode: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<String>,
+}
+
+impl From<&api::AddressDetails> for AfterpayClearpayAddress {
+    fn from(address: &api::AddressDetails) -> Self {
+        Self {
+            name: address.first_name.clone(),
+            line1: address.line1.clone(),
+            line2: address.line2.clone(),
+            area1: address.city.clone(),
+            region: address.state.clone(),
+            postcode: address.zip.clone(),
+            country_code: address.country.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayMerchant {
+    redirect_confirm_url: String,
+    redirect_cancel_url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayCheckoutRequest {
+    amount: AfterpayClearpayAmount,
+    consumer: AfterpayClearpayConsumer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing: Option<AfterpayClearpayAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<AfterpayClearpayAddress>,
+    merchant: AfterpayClearpayMerchant,
+    merchant_reference: String,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for AfterpayClearpayCheckoutRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let (billing_email, billing_name) = match &item.request.payment_method_data {
+            api::PaymentMethodData::PayLater(
+                api_payments::PayLaterData::AfterpayClearpayRedirect {
+                    billing_email,
+                    billing_name,
+                },
+            ) => (billing_email.clone(), billing_name.clone()),
+            _ => return Err(errors::ConnectorError::MismatchedPaymentData.into()),
+        };
+        // Checkouts are captured as soon as the customer confirms them
+        if item.request.capture_method == Some(enums::CaptureMethod::Manual) {
+            return Err(errors::ConnectorError::NotImplemented(
+                "Manual capture for afterpay_clearpay".to_string(),
+            )
+            .into());
+        }
+        let billing = item
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref());
+        let shipping = item
+            .address
+            .shipping
+            .as_ref()
+            .and_then(|shipping| shipping.address.as_ref());
+        let return_url = item.get_return_url()?;
+        Ok(Self {
+            amount: AfterpayClearpayAmount::new(item.request.amount, item.request.currency)?,
+            consumer: AfterpayClearpayConsumer {
+                email: billing_email,
+                given_names: billing_name,
+                surname: billing.and_then(|billing| billing.last_name.clone()),
+            },
+            billing: billing.map(AfterpayClearpayAddress::from),
+            shipping: shipping.map(AfterpayClearpayAddress::from),
+            merchant: AfterpayClearpayMerchant {
+                redirect_confirm_url: return_url.clone(),
+                redirect_cancel_url: return_url,
+            },
+            merchant_reference: item.payment_id.clone(),
+        })
+    }
+}
+
+// Checkout Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayCheckoutResponse {
+    token: String,
+    redirect_checkout_url: Url,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Authorize,
+            AfterpayClearpayCheckoutResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Authorize,
+            AfterpayClearpayCheckoutResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let connector_metadata = serde_json::to_value(AfterpayClearpayMeta {
+            region: AfterpayClearpayRegion::from(item.data.request.currency),
+        })
+        .ok();
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.token),
+                redirection_data: Some(services::RedirectForm::from((
+                    item.response.redirect_checkout_url,
+                    services::Method::Get,
+                ))),
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Customers are sent back to the confirm url with the checkout token and the outcome of the
+// checkout, after which the payment is captured
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayClearpayRedirectStatus {
+    Success,
+    Cancelled,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayRedirectResponse {
+    order_token: String,
+    status: AfterpayClearpayRedirectStatus,
+}
+
+pub fn get_confirmed_checkout_token(item: &types::PaymentsSyncRouterData) -> Option<String> {
+    if item.status != enums::AttemptStatus::AuthenticationPending {
+        return None;
+    }
+    item.request
+        .encoded_data
+        .as_ref()
+        .and_then(|data| serde_urlencoded::from_str::<AfterpayClearpayRedirectResponse>(data).ok())
+        .and_then(|redirect| match redirect.status {
+            AfterpayClearpayRedirectStatus::Success => Some(redirect.order_token),
+            AfterpayClearpayRedirectStatus::Cancelled => None,
+        })
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayCaptureRequest {
+    token: String,
+    merchant_reference: String,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for AfterpayClearpayCaptureRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            token: get_confirmed_checkout_token(item)
+                .ok_or_else(utils::missing_field_err("orderToken"))?,
+            merchant_reference: item.payment_id.clone(),
+        })
+    }
+}
+
+// Payment Response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayClearpayPaymentStatus {
+    Approved,
+    #[default]
+    Declined,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayRefundResponse {
+    pub refund_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayPaymentResponse {
+    pub id: String,
+    pub status: AfterpayClearpayPaymentStatus,
+    #[serde(default)]
+    pub refunds: Vec<AfterpayClearpayRefundResponse>,
+}
+
+impl From<AfterpayClearpayPaymentStatus> for enums::AttemptStatus {
+    fn from(item: AfterpayClearpayPaymentStatus) -> Self {
+        match item {
+            AfterpayClearpayPaymentStatus::Approved => Self::Charged,
+            AfterpayClearpayPaymentStatus::Declined => Self::Failure,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            AfterpayClearpayPaymentResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AfterpayClearpayPaymentResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayRefundRequest {
+    amount: AfterpayClearpayAmount,
+    request_id: String,
+    merchant_reference: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for AfterpayClearpayRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: AfterpayClearpayAmount::new(item.request.refund_amount, item.request.currency)?,
+            request_id: item.request.refund_id.clone(),
+            merchant_reference: item.request.refund_id.clone(),
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, AfterpayClearpayRefundResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, AfterpayClearpayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.refund_id,
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// There is no endpoint to fetch a single refund, the refunds are listed on the payment instead
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, AfterpayClearpayPaymentResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, AfterpayClearpayPaymentResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .data
+            .request
+            .connector_refund_id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+        let refund_status = if item
+            .response
+            .refunds
+            .iter()
+            .any(|refund| refund.refund_id == connector_refund_id)
+        {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayClearpayWebhookEventType {
+    PaymentCaptured,
+    PaymentDeclined,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayWebhookBody {
+    pub event_type: AfterpayClearpayWebhookEventType,
+    pub order_id: String,
+    pub refund_id: Option<String>,
+}
+
+impl AfterpayClearpayWebhookBody {
+    pub fn get_reference_id(self) -> Result<String, Error> {
+        match self.event_type {
+            AfterpayClearpayWebhookEventType::PaymentCaptured
+            | AfterpayClearpayWebhookEventType::PaymentDeclined => Ok(self.order_id),
+            AfterpayClearpayWebhookEventType::RefundSucceeded
+            | AfterpayClearpayWebhookEventType::RefundFailed => self
+                .refund_id
+                .ok_or_else(|| errors::ConnectorError::WebhookReferenceIdNotFound.into()),
+        }
+    }
+}
+
+impl From<AfterpayClearpayWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event: AfterpayClearpayWebhookEventType) -> Self {
+        match event {
+            AfterpayClearpayWebhookEventType::PaymentCaptured => Self::PaymentIntentSuccess,
+            AfterpayClearpayWebhookEventType::PaymentDeclined => Self::PaymentIntentFailure,
+            AfterpayClearpayWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            AfterpayClearpayWebhookEventType::RefundFailed => Self::RefundFailure,
+        }
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayClearpayErrorResponse {
+    pub error_code: String,
+    pub message: String,
+    pub error_id: Option<String>,
+}
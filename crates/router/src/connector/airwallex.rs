@@ -13,7 +13,6 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
-    db::StorageInterface,
     headers, logger, routes,
     services::{self, ConnectorIntegration},
     types::{
@@ -21,7 +20,7 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse, Response, RouterData,
     },
-    utils::{self, crypto, BytesExt},
+    utils::{self, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -83,10 +82,17 @@ impl ConnectorCommon for Airwallex {
             code: response.code,
             message: response.message,
             reason: response.source,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Airwallex {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::Payment for Airwallex {}
 
 impl api::PreVerify for Airwallex {}
@@ -799,13 +805,72 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Airwallex {}
+impl api::PayoutCreate for Airwallex {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl api::PayoutFulfill for Airwallex {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl api::PayoutCancel for Airwallex {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl api::PayoutSync for Airwallex {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl api::RefundCredit for Airwallex {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Airwallex
+{
+}
+
+impl api::ConnectorMandateRevoke for Airwallex {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Airwallex
+{
+}
+
+impl api::Dispute for Airwallex {}
+impl api::SubmitEvidence for Airwallex {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Airwallex
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Airwallex {
-    fn get_webhook_source_verification_algorithm(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::HmacSha256))
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
     }
 
     fn get_webhook_source_verification_signature(
@@ -852,19 +917,6 @@ impl api::IncomingWebhook for Airwallex {
         Ok(format!("{}{}", timestamp, String::from_utf8_lossy(request.body)).into_bytes())
     }
 
-    async fn get_webhook_source_verification_merchant_secret(
-        &self,
-        db: &dyn StorageInterface,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .find_config_by_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
-        Ok(secret.config.into_bytes())
-    }
-
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -0,0 +1,615 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use transformers as alipay;
+
+use crate::{
+    configs::settings,
+    connector::utils as conn_utils,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Alipay;
+
+impl api::Payment for Alipay {}
+impl api::PaymentSession for Alipay {}
+impl api::ConnectorAccessToken for Alipay {}
+impl api::PreVerify for Alipay {}
+impl api::PaymentAuthorize for Alipay {}
+impl api::PaymentSync for Alipay {}
+impl api::PaymentCapture for Alipay {}
+impl api::PaymentVoid for Alipay {}
+impl api::Refund for Alipay {}
+impl api::RefundExecute for Alipay {}
+impl api::RefundSync for Alipay {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Alipay
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // Every call is signed over its path and body, so both are built here the same way as
+        // they are for the request itself
+        let auth = alipay::AlipayAuthType::try_from(&req.connector_auth_type)?;
+        let url = url::Url::parse(&ConnectorIntegration::<Flow, Request, Response>::get_url(
+            self, req, connectors,
+        )?)
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        let body = ConnectorIntegration::<Flow, Request, Response>::get_request_body(self, req)?
+            .unwrap_or_default();
+        let request_time =
+            (date_time::now().assume_utc().unix_timestamp_nanos() / 1_000_000).to_string();
+        // All of the Alipay APIs are called with a POST
+        let message = alipay::get_signature_message(
+            "POST",
+            url.path(),
+            &auth.client_id,
+            &request_time,
+            &body,
+        );
+        let signature = alipay::sign(&message, &auth.private_key)?;
+
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ),
+            (headers::CLIENT_ID.to_string(), auth.client_id),
+            (headers::REQUEST_TIME.to_string(), request_time),
+            (headers::SIGNATURE.to_string(), signature),
+        ])
+    }
+}
+
+impl ConnectorCommon for Alipay {
+    fn id(&self) -> &'static str {
+        "alipay"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json; charset=UTF-8"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.alipay.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: alipay::AlipayErrorResponse = res
+            .response
+            .parse_struct("Alipay ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(response.into_error_response(res.status_code))
+    }
+}
+
+impl api::ConnectorSpecifications for Alipay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Wallet])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+
+    fn supports_mandates(&self) -> bool {
+        false
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Alipay
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Alipay
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Alipay
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Alipay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ams/api/v1/payments/pay",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let alipay_req = utils::Encode::<alipay::AlipayPaymentsRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(alipay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(alipay_payments_response=?res);
+        let response: alipay::AlipayPaymentsResponse = res
+            .response
+            .parse_struct("Alipay PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Alipay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ams/api/v1/payments/inquiryPayment",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let alipay_req = utils::Encode::<alipay::AlipaySyncRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(alipay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .body(types::PaymentsSyncType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(alipay_payment_sync_response=?res);
+        let response: alipay::AlipaySyncResponse = res
+            .response
+            .parse_struct("Alipay SyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Alipay
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Alipay
+{
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData> for Alipay {
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ams/api/v1/payments/refund",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let alipay_req = utils::Encode::<alipay::AlipayRefundRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(alipay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(alipay_refund_response=?res);
+        let response: alipay::AlipayRefundResponse = res
+            .response
+            .parse_struct("Alipay RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData> for Alipay {
+    fn get_headers(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ams/api/v1/payments/inquiryRefund",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let alipay_req = utils::Encode::<alipay::AlipayRefundSyncRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(alipay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundSyncType::get_url(self, req, connectors)?)
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .body(types::RefundSyncType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        logger::debug!(alipay_refund_sync_response=?res);
+        let response: alipay::AlipayRefundResponse = res
+            .response
+            .parse_struct("Alipay RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Payouts for Alipay {}
+impl api::PayoutCreate for Alipay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Alipay
+{
+}
+
+impl api::PayoutFulfill for Alipay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Alipay
+{
+}
+
+impl api::PayoutCancel for Alipay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Alipay
+{
+}
+
+impl api::PayoutSync for Alipay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Alipay {}
+
+impl api::RefundCredit for Alipay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Alipay {}
+
+impl api::ConnectorMandateRevoke for Alipay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Alipay
+{
+}
+
+impl api::Dispute for Alipay {}
+impl api::SubmitEvidence for Alipay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Alipay
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Alipay {
+    // Notifications are signed with the public key Alipay issues to the merchant
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::RsaSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature_header: alipay::AlipaySignatureHeader =
+            conn_utils::get_header_key_value(headers::SIGNATURE, request.headers)?.parse()?;
+
+        signature_header.get_signature()
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let client_id = conn_utils::get_header_key_value(headers::CLIENT_ID, request.headers)?;
+        let request_time =
+            conn_utils::get_header_key_value(headers::REQUEST_TIME, request.headers)?;
+        let body = std::str::from_utf8(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = alipay::get_signature_message(
+            request.method.as_str(),
+            request.uri.path(),
+            client_id,
+            request_time,
+            body,
+        );
+
+        Ok(message.into_bytes())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let notification: alipay::AlipayNotification = request
+            .body
+            .parse_struct("Alipay Notification")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        notification.get_reference_id()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let notification: alipay::AlipayNotification = request
+            .body
+            .parse_struct("Alipay Notification")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&notification))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let notification: alipay::AlipayNotification = request
+            .body
+            .parse_struct("Alipay Notification")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<alipay::AlipaySyncResponse>::encode_to_value(
+            &alipay::AlipaySyncResponse::from(notification),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    fn get_webhook_api_response(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_secret: &[u8],
+    ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
+    {
+        utils::Encode::<alipay::AlipayNotificationResponse>::encode_to_value(
+            &alipay::AlipayNotificationResponse::default(),
+        )
+        .map(services::api::ApplicationResponse::Json)
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+}
+
+impl services::ConnectorRedirectResponse for Alipay {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
@@ -0,0 +1,666 @@
+use base64::Engine;
+use common_utils::{
+    crypto::{self, SignMessage},
+    ext_traits::Encode,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use url::Url;
+
+use crate::{
+    connector::utils::{RefundsRequestData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+const SIGNATURE_ALGORITHM: &str = "RSA256";
+const SIGNATURE_KEY_VERSION: &str = "1";
+
+// Auth Struct
+pub struct AlipayAuthType {
+    pub(super) client_id: String,
+    /// PEM encoded private key whose public key is registered with Alipay
+    pub(super) private_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for AlipayAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                client_id: api_key.to_string(),
+                private_key: Secret::new(key1.to_string()),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// Requests and notifications are signed over the method and path of the call, the client id,
+/// the request time and the body, in that order
+pub fn get_signature_message(
+    http_method: &str,
+    path: &str,
+    client_id: &str,
+    request_time: &str,
+    body: &str,
+) -> String {
+    format!("{http_method} {path}\n{client_id}.{request_time}.{body}")
+}
+
+pub fn sign(message: &str, private_key: &Secret<String>) -> Result<String, Error> {
+    let signature = crypto::RsaSha256
+        .sign_message(private_key.peek().as_bytes(), message.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    let signature: String =
+        url::form_urlencoded::byte_serialize(consts::BASE64_ENGINE.encode(signature).as_bytes())
+            .collect();
+    Ok(format!(
+        "algorithm={SIGNATURE_ALGORITHM},keyVersion={SIGNATURE_KEY_VERSION},signature={signature}"
+    ))
+}
+
+/// The `Signature` header, a comma separated list of url encoded key value pairs
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipaySignatureHeader {
+    pub algorithm: String,
+    pub key_version: Option<String>,
+    pub signature: String,
+}
+
+impl AlipaySignatureHeader {
+    pub fn get_signature(&self) -> Result<Vec<u8>, Error> {
+        if self.algorithm != SIGNATURE_ALGORITHM {
+            return Err(errors::ConnectorError::WebhookSignatureNotFound).into_report();
+        }
+        consts::BASE64_ENGINE
+            .decode(&self.signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+}
+
+impl std::str::FromStr for AlipaySignatureHeader {
+    type Err = Error;
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        serde_urlencoded::from_str(&header.replace(',', "&"))
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlipayAmount {
+    pub currency: String,
+    /// Amount in the minor unit of the currency
+    pub value: String,
+}
+
+impl AlipayAmount {
+    fn new(amount: i64, currency: enums::Currency) -> Self {
+        Self {
+            currency: currency.to_string(),
+            value: amount.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayProductCode {
+    CashierPayment,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayPaymentMethodType {
+    AlipayCn,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayTerminalType {
+    Web,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayOrder {
+    order_amount: AlipayAmount,
+    reference_order_id: String,
+    order_description: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayPaymentMethod {
+    payment_method_type: AlipayPaymentMethodType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayEnv {
+    terminal_type: AlipayTerminalType,
+}
+
+// The url the payment result is notified to is set up on the Alipay dashboard, so it is not
+// sent along with the payment
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayPaymentsRequest {
+    product_code: AlipayProductCode,
+    payment_request_id: String,
+    order: AlipayOrder,
+    payment_amount: AlipayAmount,
+    payment_method: AlipayPaymentMethod,
+    payment_redirect_url: String,
+    env: AlipayEnv,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for AlipayPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Wallet(api_models::payments::WalletData::AliPayRedirect(_)) => {
+                // Alipay wallet payments are captured as soon as the customer pays
+                if item.request.capture_method == Some(enums::CaptureMethod::Manual) {
+                    return Err(errors::ConnectorError::NotImplemented(
+                        "Manual capture for Alipay".to_string(),
+                    )
+                    .into());
+                }
+                let amount = AlipayAmount::new(item.request.amount, item.request.currency);
+                Ok(Self {
+                    product_code: AlipayProductCode::CashierPayment,
+                    payment_request_id: item.attempt_id.clone(),
+                    order: AlipayOrder {
+                        order_amount: amount.clone(),
+                        reference_order_id: item.payment_id.clone(),
+                        order_description: item
+                            .description
+                            .clone()
+                            .unwrap_or_else(|| item.payment_id.clone()),
+                    },
+                    payment_amount: amount,
+                    payment_method: AlipayPaymentMethod {
+                        payment_method_type: AlipayPaymentMethodType::AlipayCn,
+                    },
+                    payment_redirect_url: item.get_return_url()?,
+                    env: AlipayEnv {
+                        terminal_type: AlipayTerminalType::Web,
+                    },
+                })
+            }
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AlipayResultStatus {
+    #[serde(rename = "S")]
+    Success,
+    #[serde(rename = "F")]
+    Failure,
+    #[serde(rename = "U")]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayResult {
+    pub result_code: String,
+    pub result_status: AlipayResultStatus,
+    pub result_message: Option<String>,
+}
+
+impl AlipayResult {
+    fn success() -> Self {
+        Self {
+            result_code: "SUCCESS".to_string(),
+            result_status: AlipayResultStatus::Success,
+            result_message: Some("success".to_string()),
+        }
+    }
+
+    fn get_error_response(&self, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: self.result_code.clone(),
+            message: self
+                .result_message
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+            status_code,
+            unified_code: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayCodeDisplayType {
+    Text,
+    Image,
+    Smallimage,
+    Middleimage,
+    Bigimage,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayCodeDetail {
+    pub code_value: String,
+    pub display_type: AlipayCodeDisplayType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayOrderCodeForm {
+    pub code_details: Vec<AlipayCodeDetail>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expire_time: Option<PrimitiveDateTime>,
+}
+
+impl AlipayOrderCodeForm {
+    /// QR code the customer can scan with the Alipay app instead of following the redirection,
+    /// to be shown by the merchant
+    fn into_qr_code_information(self) -> Option<api_models::payments::QrCodeInformation> {
+        let qr_code_value = self
+            .code_details
+            .iter()
+            .find(|code| matches!(code.display_type, AlipayCodeDisplayType::Text))?
+            .code_value
+            .clone();
+        let qr_code_image_url = self
+            .code_details
+            .into_iter()
+            .find(|code| {
+                !matches!(
+                    code.display_type,
+                    AlipayCodeDisplayType::Text | AlipayCodeDisplayType::Other
+                )
+            })
+            .map(|code| code.code_value);
+        Some(api_models::payments::QrCodeInformation {
+            qr_code_payload: qr_code_value,
+            image_data_url: qr_code_image_url,
+            expires_at: self.expire_time,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayPaymentsResponse {
+    result: AlipayResult,
+    payment_id: Option<String>,
+    normal_url: Option<Url>,
+    order_code_form: Option<AlipayOrderCodeForm>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AlipayPaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, AlipayPaymentsResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let result = item.response.result;
+        if result.result_status == AlipayResultStatus::Failure {
+            return Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(result.get_error_response(item.http_code)),
+                ..item.data
+            });
+        }
+        // Payments are looked up by the payment request id, so an unknown result without a
+        // payment id is left to be synced
+        let resource_id = item
+            .response
+            .payment_id
+            .map_or(types::ResponseId::NoResponseId, |payment_id| {
+                types::ResponseId::ConnectorTransactionId(payment_id)
+            });
+        let redirection_data = item
+            .response
+            .normal_url
+            .map(|url| services::RedirectForm::from((url, services::Method::Get)));
+        let status = match result.result_status {
+            AlipayResultStatus::Success => enums::AttemptStatus::Charged,
+            // The payment waits on the customer, who pays on the Alipay cashier page
+            _ if redirection_data.is_some() => enums::AttemptStatus::AuthenticationPending,
+            _ => enums::AttemptStatus::Pending,
+        };
+        let connector_metadata = item
+            .response
+            .order_code_form
+            .and_then(AlipayOrderCodeForm::into_qr_code_information)
+            .map(|qr_code| {
+                Encode::<'_, api_models::payments::QrCodeInformation>::encode_to_value(&qr_code)
+            })
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipaySyncRequest {
+    payment_request_id: String,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for AlipaySyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_request_id: item.attempt_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayPaymentStatus {
+    Success,
+    Fail,
+    Processing,
+    Pending,
+    Cancelled,
+}
+
+impl From<AlipayPaymentStatus> for enums::AttemptStatus {
+    fn from(item: AlipayPaymentStatus) -> Self {
+        match item {
+            AlipayPaymentStatus::Success => Self::Charged,
+            AlipayPaymentStatus::Fail => Self::Failure,
+            // The customer has not paid on the cashier page, or scanned the QR code, yet
+            AlipayPaymentStatus::Processing => Self::AuthenticationPending,
+            AlipayPaymentStatus::Pending => Self::Pending,
+            AlipayPaymentStatus::Cancelled => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipaySyncResponse {
+    pub result: AlipayResult,
+    pub payment_status: Option<AlipayPaymentStatus>,
+    pub payment_result_code: Option<String>,
+    pub payment_result_message: Option<String>,
+    pub payment_id: Option<String>,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, AlipaySyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, AlipaySyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        let (payment_status, payment_id) = match (response.payment_status, response.payment_id) {
+            (Some(payment_status), Some(payment_id))
+                if response.result.result_status != AlipayResultStatus::Failure =>
+            {
+                (payment_status, payment_id)
+            }
+            _ => {
+                return Ok(Self {
+                    response: Err(response.result.get_error_response(item.http_code)),
+                    ..item.data
+                })
+            }
+        };
+        let status = enums::AttemptStatus::from(payment_status);
+        if status == enums::AttemptStatus::Failure {
+            return Ok(Self {
+                status,
+                response: Err(types::ErrorResponse {
+                    code: response
+                        .payment_result_code
+                        .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                    message: response
+                        .payment_result_message
+                        .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                    reason: None,
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+                ..item.data
+            });
+        }
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(payment_id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayRefundRequest {
+    refund_request_id: String,
+    payment_id: String,
+    refund_amount: AlipayAmount,
+    refund_reason: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for AlipayRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refund_request_id: item.request.refund_id.clone(),
+            payment_id: item.request.connector_transaction_id.clone(),
+            refund_amount: AlipayAmount::new(item.request.refund_amount, item.request.currency),
+            refund_reason: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayRefundSyncRequest {
+    refund_id: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for AlipayRefundSyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refund_id: item.request.get_connector_refund_id()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayRefundStatus {
+    Success,
+    Fail,
+    Processing,
+}
+
+impl From<AlipayRefundStatus> for enums::RefundStatus {
+    fn from(item: AlipayRefundStatus) -> Self {
+        match item {
+            AlipayRefundStatus::Success => Self::Success,
+            AlipayRefundStatus::Fail => Self::Failure,
+            AlipayRefundStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+/// Response of both a refund and a refund inquiry. A refund is not given a status of its own,
+/// only the result of the call, which is pending while the refund is processed
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayRefundResponse {
+    result: AlipayResult,
+    refund_id: Option<String>,
+    refund_status: Option<AlipayRefundStatus>,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, AlipayRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, AlipayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        let result = item.response.result;
+        let refund_id = match (result.result_status, item.response.refund_id) {
+            (AlipayResultStatus::Failure, _) | (_, None) => {
+                return Ok(Self {
+                    response: Err(result.get_error_response(item.http_code)),
+                    ..item.data
+                })
+            }
+            (_, Some(refund_id)) => refund_id,
+        };
+        let refund_status = match (item.response.refund_status, result.result_status) {
+            (Some(refund_status), _) => enums::RefundStatus::from(refund_status),
+            (None, AlipayResultStatus::Success) => enums::RefundStatus::Success,
+            (None, _) => enums::RefundStatus::Pending,
+        };
+
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlipayNotifyType {
+    PaymentResult,
+    PaymentPending,
+    RefundResult,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlipayNotification {
+    pub notify_type: AlipayNotifyType,
+    pub result: AlipayResult,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub refund_status: Option<AlipayRefundStatus>,
+}
+
+impl AlipayNotification {
+    pub fn get_reference_id(&self) -> Result<String, Error> {
+        match self.notify_type {
+            AlipayNotifyType::RefundResult => self.refund_id.clone(),
+            _ => self.payment_id.clone(),
+        }
+        .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+        .into_report()
+    }
+}
+
+impl From<&AlipayNotification> for api::IncomingWebhookEvent {
+    fn from(notification: &AlipayNotification) -> Self {
+        match (notification.notify_type, notification.result.result_status) {
+            (AlipayNotifyType::PaymentResult, AlipayResultStatus::Success) => {
+                Self::PaymentIntentSuccess
+            }
+            (AlipayNotifyType::PaymentResult, AlipayResultStatus::Failure) => {
+                Self::PaymentIntentFailure
+            }
+            (AlipayNotifyType::RefundResult, _) => match notification.refund_status {
+                Some(AlipayRefundStatus::Success) => Self::RefundSuccess,
+                Some(AlipayRefundStatus::Fail) => Self::RefundFailure,
+                Some(AlipayRefundStatus::Processing) | None => Self::EventNotSupported,
+            },
+            _ => Self::EventNotSupported,
+        }
+    }
+}
+
+/// The outcome of a payment, as notified, in the shape of a payment inquiry response
+impl From<AlipayNotification> for AlipaySyncResponse {
+    fn from(notification: AlipayNotification) -> Self {
+        let payment_status = match notification.result.result_status {
+            AlipayResultStatus::Success => AlipayPaymentStatus::Success,
+            AlipayResultStatus::Failure => AlipayPaymentStatus::Fail,
+            AlipayResultStatus::Unknown => AlipayPaymentStatus::Processing,
+        };
+        Self {
+            result: AlipayResult::success(),
+            payment_status: Some(payment_status),
+            payment_result_code: Some(notification.result.result_code),
+            payment_result_message: notification.result.result_message,
+            payment_id: notification.payment_id,
+        }
+    }
+}
+
+/// Alipay keeps resending a notification until it is acknowledged with a successful result
+#[derive(Debug, Serialize)]
+pub struct AlipayNotificationResponse {
+    result: AlipayResult,
+}
+
+impl Default for AlipayNotificationResponse {
+    fn default() -> Self {
+        Self {
+            result: AlipayResult::success(),
+        }
+    }
+}
+
+// ERROR :
+#[derive(Debug, Deserialize)]
+pub struct AlipayErrorResponse {
+    pub result: AlipayResult,
+}
+
+impl AlipayErrorResponse {
+    pub fn into_error_response(self, status_code: u16) -> types::ErrorResponse {
+        self.result.get_error_response(status_code)
+    }
+}
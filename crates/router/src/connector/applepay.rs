@@ -30,6 +30,8 @@ impl ConnectorCommon for Applepay {
     }
 }
 
+impl api::ConnectorSpecifications for Applepay {}
+
 impl api::Payment for Applepay {}
 impl api::PaymentAuthorize for Applepay {}
 impl api::PaymentSync for Applepay {}
@@ -184,6 +186,7 @@ impl
             code: response.status_code,
             message: response.status_message,
             reason: None,
+            unified_code: None,
         })
     }
 
@@ -238,6 +241,65 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 impl services::ConnectorRedirectResponse for Applepay {}
 
+impl api::Payouts for Applepay {}
+impl api::PayoutCreate for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::PayoutFulfill for Applepay {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::PayoutCancel for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::PayoutSync for Applepay {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::RefundCredit for Applepay {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Applepay
+{
+}
+
+impl api::ConnectorMandateRevoke for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Applepay
+{
+}
+
+impl api::Dispute for Applepay {}
+impl api::SubmitEvidence for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Applepay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Applepay {
     fn get_webhook_object_reference_id(
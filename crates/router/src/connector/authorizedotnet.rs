@@ -6,17 +6,19 @@ use std::fmt::Debug;
 use error_stack::{IntoReport, ResultExt};
 use transformers as authorizedotnet;
 
+use super::utils as conn_utils;
 use crate::{
     configs::settings,
     consts,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     headers,
     services::{self, logger},
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, BytesExt, ValueExt},
 };
 
 #[derive(Debug, Clone)]
@@ -36,6 +38,8 @@ impl ConnectorCommon for Authorizedotnet {
     }
 }
 
+impl api::ConnectorSpecifications for Authorizedotnet {}
+
 impl api::Payment for Authorizedotnet {}
 impl api::PaymentAuthorize for Authorizedotnet {}
 impl api::PaymentSync for Authorizedotnet {}
@@ -83,7 +87,98 @@ impl
         types::PaymentsResponseData,
     > for Authorizedotnet
 {
-    // Not Implemented (R)
+    fn get_headers(
+        &self,
+        _req: &types::PaymentsCaptureRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // This connector does not require an auth header, the authentication details are sent in the request body
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PaymentsCaptureType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(self.base_url(connectors).to_string())
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = authorizedotnet::CaptureTransactionRequest::try_from(req)?;
+        let authorizedotnet_req =
+            utils::Encode::<authorizedotnet::CaptureTransactionRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(authorizedotnet_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsCaptureType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsCaptureType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsCaptureType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCaptureRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        use bytes::Buf;
+        logger::debug!(authorizedotnetpayments_capture_response=?res);
+
+        // Handle the case where response bytes contains U+FEFF (BOM) character sent by connector
+        let encoding = encoding_rs::UTF_8;
+        let intermediate_response = encoding.decode_with_bom_removal(res.response.chunk());
+        let intermediate_response =
+            bytes::Bytes::copy_from_slice(intermediate_response.0.as_bytes());
+
+        let response: authorizedotnet::AuthorizedotnetPaymentsResponse = intermediate_response
+            .parse_struct("AuthorizedotnetPaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            authorizedotnet::PaymentFlow::Capture,
+        ))
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        get_error_response(res)
+    }
 }
 
 impl
@@ -267,11 +362,21 @@ impl
             .parse_struct("AuthorizedotnetPaymentsResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        // Payments captured automatically are made as a single auth capture transaction
+        let flow = match data.request.capture_method {
+            Some(types::storage::enums::CaptureMethod::Manual) => {
+                authorizedotnet::PaymentFlow::Authorize
+            }
+            _ => authorizedotnet::PaymentFlow::Capture,
+        };
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            flow,
+        ))
         .change_context(errors::ConnectorError::ResponseDeserializationFailed)
     }
 
@@ -363,11 +468,14 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         logger::debug!(authorizedotnetpayments_create_response=?response);
 
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            authorizedotnet::PaymentFlow::Void,
+        ))
         .change_context(errors::ConnectorError::ResponseDeserializationFailed)
     }
 
@@ -568,27 +676,196 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Authorizedotnet {}
+impl api::PayoutCreate for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::PayoutFulfill for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::PayoutCancel for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::PayoutSync for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::RefundCredit for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::ConnectorMandateRevoke for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl api::Dispute for Authorizedotnet {}
+impl api::SubmitEvidence for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Authorizedotnet
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Authorizedotnet {
-    fn get_webhook_object_reference_id(
+    // Webhooks are signed with the signature key of the merchant
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha512)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            conn_utils::get_header_key_value(headers::X_ANET_SIGNATURE, request.headers)?;
+        let signature = signature
+            .strip_prefix("sha512=")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    async fn verify_webhook_source(
+        &self,
+        db: &dyn StorageInterface,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let notification = authorizedotnet::AuthorizedotnetNotification::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let silent_post = match notification {
+            authorizedotnet::AuthorizedotnetNotification::SilentPost(silent_post) => silent_post,
+            authorizedotnet::AuthorizedotnetNotification::Webhook(_) => {
+                let algorithm = self
+                    .get_webhook_source_verification_scheme(request)
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?
+                    .get_algorithm();
+                let signature = self
+                    .get_webhook_source_verification_signature(request)
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+                let secret = self
+                    .get_webhook_source_verification_merchant_secret(db, merchant_id)
+                    .await
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+                let message = self
+                    .get_webhook_source_verification_message(request, merchant_id, &secret)
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+                return algorithm
+                    .verify_signature(&secret, &signature, &message)
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed);
+            }
+        };
+
+        // Silent posts are hashed over the MD5 hash value of the merchant, their API login id
+        // and the id and amount of the transaction
+        let key = format!("whsec_verification_md5_{}_{}", self.id(), merchant_id);
+        let md5_hash_value = db
+            .find_config_by_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?
+            .config;
+        let merchant_connector_account = db
+            .find_merchant_connector_account_by_merchant_id_connector(merchant_id, self.id())
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let merchant_authentication =
+            authorizedotnet::MerchantAuthentication::try_from(&auth_type)?;
+        let message = format!(
+            "{}{}{}{}",
+            md5_hash_value,
+            merchant_authentication.name,
+            silent_post.x_trans_id,
+            silent_post.x_amount
+        );
+        let signature = hex::decode(silent_post.x_md5_hash)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        crypto::Md5
+            .verify_signature(&[], &signature, message.as_bytes())
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let notification = authorizedotnet::AuthorizedotnetNotification::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(notification.get_transaction_id())
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let notification = authorizedotnet::AuthorizedotnetNotification::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&notification))
     }
 
+    // The notification only carries the transaction id and its outcome, which is handed to the
+    // sync flow in the shape of a transaction details response
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let notification = authorizedotnet::AuthorizedotnetNotification::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<authorizedotnet::AuthorizedotnetSyncResponse>::encode_to_value(
+            &authorizedotnet::AuthorizedotnetSyncResponse::from(&notification),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
@@ -609,19 +886,15 @@ fn get_error_response(
 
     Ok(response
         .transaction_response
-        .errors
+        .and_then(|transaction_response| transaction_response.errors)
         .and_then(|errors| {
             errors.into_iter().next().map(|error| types::ErrorResponse {
                 code: error.error_code,
                 message: error.error_text,
                 reason: None,
                 status_code,
+                unified_code: None,
             })
         })
-        .unwrap_or_else(|| types::ErrorResponse {
-            code: consts::NO_ERROR_CODE.to_string(),
-            message: consts::NO_ERROR_MESSAGE.to_string(),
-            reason: None,
-            status_code,
-        }))
+        .unwrap_or_else(|| response.messages.get_error_response(status_code)))
 }
@@ -1,11 +1,12 @@
 use common_utils::ext_traits::{Encode, ValueExt};
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::RefundsRequestData,
+    connector::utils::{self, RefundsRequestData},
+    consts,
     core::errors,
-    types::{self, api, storage::enums},
+    types::{self, api, storage::enums, transformers::ForeignFrom},
     utils::OptionExt,
 };
 
@@ -13,6 +14,10 @@ use crate::{
 pub enum TransactionType {
     #[serde(rename = "authCaptureTransaction")]
     Payment,
+    #[serde(rename = "authOnlyTransaction")]
+    Authorization,
+    #[serde(rename = "priorAuthCaptureTransaction")]
+    Capture,
     #[serde(rename = "refundTransaction")]
     Refund,
     #[serde(rename = "voidTransaction")]
@@ -20,8 +25,8 @@ pub enum TransactionType {
 }
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct MerchantAuthentication {
-    name: String,
+pub(super) struct MerchantAuthentication {
+    pub(super) name: String,
     transaction_key: String,
 }
 
@@ -66,6 +71,13 @@ enum PaymentDetails {
     Paypal,
     #[serde(rename = "bankRedirect")]
     BankRedirect,
+    #[serde(rename = "bankTransfer")]
+    BankTransfer,
+    Voucher,
+    Upi,
+    #[serde(rename = "bankDebit")]
+    BankDebit,
+    Crypto,
 }
 
 impl From<api_models::payments::PaymentMethodData> for PaymentDetails {
@@ -86,6 +98,11 @@ impl From<api_models::payments::PaymentMethodData> for PaymentDetails {
             api::PaymentMethodData::PayLater(_) => Self::Klarna,
             api::PaymentMethodData::Wallet(_) => Self::Wallet,
             api::PaymentMethodData::BankRedirect(_) => Self::BankRedirect,
+            api::PaymentMethodData::BankTransfer(_) => Self::BankTransfer,
+            api::PaymentMethodData::Voucher(_) => Self::Voucher,
+            api::PaymentMethodData::Upi(_) => Self::Upi,
+            api::PaymentMethodData::BankDebit(_) => Self::BankDebit,
+            api::PaymentMethodData::Crypto(_) => Self::Crypto,
         }
     }
 }
@@ -97,9 +114,38 @@ struct TransactionRequest {
     amount: i64,
     currency_code: String,
     payment: PaymentDetails,
+    transaction_settings: TransactionSettings,
     authorization_indicator_type: Option<AuthorizationIndicator>,
 }
 
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct TransactionSettings {
+    setting: Vec<TransactionSetting>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct TransactionSetting {
+    setting_name: String,
+    setting_value: String,
+}
+
+impl TransactionSettings {
+    // Authorize.Net declines a transaction as a duplicate (error 11) when one with the same card
+    // and amount was submitted within the duplicate window, two minutes by default. Payments and
+    // partial refunds of the same amount are routinely legitimate, and retries are already
+    // deduplicated by their attempt and refund ids, so the window is disabled
+    fn without_duplicate_window() -> Self {
+        Self {
+            setting: vec![TransactionSetting {
+                setting_name: "duplicateWindow".to_string(),
+                setting_value: "0".to_string(),
+            }],
+        }
+    }
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct AuthorizationIndicator {
@@ -113,6 +159,14 @@ struct TransactionVoidRequest {
     ref_trans_id: String,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct TransactionCaptureRequest {
+    transaction_type: TransactionType,
+    amount: i64,
+    ref_trans_id: String,
+}
+
 #[derive(Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetPaymentsRequest {
@@ -140,6 +194,19 @@ pub struct CancelTransactionRequest {
     create_transaction_request: AuthorizedotnetPaymentCancelRequest,
 }
 
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetPaymentCaptureRequest {
+    merchant_authentication: MerchantAuthentication,
+    transaction_request: TransactionCaptureRequest,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureTransactionRequest {
+    create_transaction_request: AuthorizedotnetPaymentCaptureRequest,
+}
+
 #[derive(Debug, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum AuthorizationType {
@@ -164,11 +231,16 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CreateTransactionRequest {
             item.request.capture_method.map(|c| AuthorizationIndicator {
                 authorization_indicator: c.into(),
             });
+        let transaction_type = match item.request.capture_method {
+            Some(enums::CaptureMethod::Manual) => TransactionType::Authorization,
+            _ => TransactionType::Payment,
+        };
         let transaction_request = TransactionRequest {
-            transaction_type: TransactionType::Payment,
+            transaction_type,
             amount: item.request.amount,
             payment: payment_details,
             currency_code: item.request.currency.to_string(),
+            transaction_settings: TransactionSettings::without_duplicate_window(),
             authorization_indicator_type,
         };
 
@@ -202,6 +274,29 @@ impl TryFrom<&types::PaymentsCancelRouterData> for CancelTransactionRequest {
     }
 }
 
+impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let transaction_request = TransactionCaptureRequest {
+            transaction_type: TransactionType::Capture,
+            amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+            ref_trans_id: item.request.connector_transaction_id.to_string(),
+        };
+
+        let merchant_authentication = MerchantAuthentication::try_from(&item.connector_auth_type)?;
+
+        Ok(Self {
+            create_transaction_request: AuthorizedotnetPaymentCaptureRequest {
+                merchant_authentication,
+                transaction_request,
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
 pub enum AuthorizedotnetPaymentStatus {
     #[serde(rename = "1")]
@@ -217,12 +312,26 @@ pub enum AuthorizedotnetPaymentStatus {
 
 pub type AuthorizedotnetRefundStatus = AuthorizedotnetPaymentStatus;
 
-impl From<AuthorizedotnetPaymentStatus> for enums::AttemptStatus {
-    fn from(item: AuthorizedotnetPaymentStatus) -> Self {
-        match item {
-            AuthorizedotnetPaymentStatus::Approved => Self::Charged,
+#[derive(Debug, Clone, Copy)]
+pub enum PaymentFlow {
+    Authorize,
+    Capture,
+    Void,
+}
+
+impl ForeignFrom<(AuthorizedotnetPaymentStatus, PaymentFlow)> for enums::AttemptStatus {
+    fn foreign_from((status, flow): (AuthorizedotnetPaymentStatus, PaymentFlow)) -> Self {
+        match status {
+            AuthorizedotnetPaymentStatus::Approved => match flow {
+                PaymentFlow::Authorize => Self::Authorized,
+                PaymentFlow::Capture => Self::Charged,
+                PaymentFlow::Void => Self::Voided,
+            },
             AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error => {
-                Self::Failure
+                match flow {
+                    PaymentFlow::Authorize | PaymentFlow::Capture => Self::Failure,
+                    PaymentFlow::Void => Self::VoidFailed,
+                }
             }
             AuthorizedotnetPaymentStatus::HeldForReview => Self::Pending,
         }
@@ -248,6 +357,29 @@ pub struct ResponseMessages {
     message: Vec<ResponseMessage>,
 }
 
+impl ResponseMessages {
+    // Requests rejected before a transaction is created, such as for invalid credentials, are
+    // answered without a transaction response and only carry the reason in their messages
+    pub(super) fn get_error_response(&self, status_code: u16) -> types::ErrorResponse {
+        self.message
+            .first()
+            .map(|message| types::ErrorResponse {
+                code: message.code.clone(),
+                message: message.text.clone(),
+                reason: None,
+                status_code,
+                unified_code: None,
+            })
+            .unwrap_or_else(|| types::ErrorResponse {
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: consts::NO_ERROR_MESSAGE.to_string(),
+                reason: None,
+                status_code,
+                unified_code: None,
+            })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct ErrorMessage {
@@ -264,51 +396,66 @@ pub struct TransactionResponse {
     transaction_id: String,
     pub(super) account_number: Option<String>,
     pub(super) errors: Option<Vec<ErrorMessage>>,
+    avs_result_code: Option<String>,
+    cvv_result_code: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetPaymentsResponse {
-    pub transaction_response: TransactionResponse,
+    pub transaction_response: Option<TransactionResponse>,
     pub messages: ResponseMessages,
 }
 
 impl<F, T>
-    TryFrom<
+    TryFrom<(
         types::ResponseRouterData<
             F,
             AuthorizedotnetPaymentsResponse,
             T,
             types::PaymentsResponseData,
         >,
-    > for types::RouterData<F, T, types::PaymentsResponseData>
+        PaymentFlow,
+    )> for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<
-            F,
-            AuthorizedotnetPaymentsResponse,
-            T,
-            types::PaymentsResponseData,
-        >,
+        (item, flow): (
+            types::ResponseRouterData<
+                F,
+                AuthorizedotnetPaymentsResponse,
+                T,
+                types::PaymentsResponseData,
+            >,
+            PaymentFlow,
+        ),
     ) -> Result<Self, Self::Error> {
-        let status = enums::AttemptStatus::from(item.response.transaction_response.response_code);
-        let error = item
-            .response
-            .transaction_response
-            .errors
-            .and_then(|errors| {
-                errors.into_iter().next().map(|error| types::ErrorResponse {
-                    code: error.error_code,
-                    message: error.error_text,
-                    reason: None,
-                    status_code: item.http_code,
-                })
-            });
+        let transaction_response = match item.response.transaction_response {
+            Some(transaction_response) => transaction_response,
+            None => {
+                let status = match flow {
+                    PaymentFlow::Authorize | PaymentFlow::Capture => enums::AttemptStatus::Failure,
+                    PaymentFlow::Void => enums::AttemptStatus::VoidFailed,
+                };
+                return Ok(Self {
+                    status,
+                    response: Err(item.response.messages.get_error_response(item.http_code)),
+                    ..item.data
+                });
+            }
+        };
+        let status = enums::AttemptStatus::foreign_from((transaction_response.response_code, flow));
+        let error = transaction_response.errors.and_then(|errors| {
+            errors.into_iter().next().map(|error| types::ErrorResponse {
+                code: error.error_code,
+                message: error.error_text,
+                reason: None,
+                status_code: item.http_code,
+                unified_code: None,
+            })
+        });
 
-        let metadata = item
-            .response
-            .transaction_response
+        let metadata = transaction_response
             .account_number
             .map(|acc_no| {
                 Encode::<'_, PaymentDetails>::encode_to_value(&construct_refund_payment_details(
@@ -326,11 +473,20 @@ impl<F, T>
                 Some(err) => Err(err),
                 None => Ok(types::PaymentsResponseData::TransactionResponse {
                     resource_id: types::ResponseId::ConnectorTransactionId(
-                        item.response.transaction_response.transaction_id,
+                        transaction_response.transaction_id,
                     ),
                     redirection_data: None,
                     mandate_reference: None,
                     connector_metadata: metadata,
+                    network_txn_id: None,
+                    avs_result: transaction_response
+                        .avs_result_code
+                        .as_deref()
+                        .map(get_avs_result),
+                    cvv_result: transaction_response
+                        .cvv_result_code
+                        .as_deref()
+                        .map(utils::get_cvv_result_from_code),
                 }),
             },
             ..item.data
@@ -338,6 +494,15 @@ impl<F, T>
     }
 }
 
+/// Authorize.net returns B when no address was given and P when AVS does not apply to the
+/// transaction, which the card networks use for partial matches instead
+fn get_avs_result(avs_result_code: &str) -> enums::AvsResult {
+    match avs_result_code {
+        "B" | "P" => enums::AvsResult::Unavailable,
+        avs_result_code => utils::get_avs_result_from_code(avs_result_code),
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct RefundTransactionRequest {
@@ -347,6 +512,7 @@ struct RefundTransactionRequest {
     payment: PaymentDetails,
     #[serde(rename = "refTransId")]
     reference_transaction_id: String,
+    transaction_settings: TransactionSettings,
 }
 
 #[derive(Debug, Serialize)]
@@ -388,6 +554,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for CreateRefundRequest {
                 })?,
             currency_code: item.request.currency.to_string(),
             reference_transaction_id: item.request.connector_transaction_id.clone(),
+            transaction_settings: TransactionSettings::without_duplicate_window(),
         };
 
         Ok(Self {
@@ -414,7 +581,7 @@ impl From<AuthorizedotnetPaymentStatus> for enums::RefundStatus {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetRefundResponse {
-    pub transaction_response: TransactionResponse,
+    pub transaction_response: Option<TransactionResponse>,
     pub messages: ResponseMessages,
 }
 
@@ -425,7 +592,15 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AuthorizedotnetRefundRespons
     fn try_from(
         item: types::RefundsResponseRouterData<F, AuthorizedotnetRefundResponse>,
     ) -> Result<Self, Self::Error> {
-        let transaction_response = &item.response.transaction_response;
+        let transaction_response = match &item.response.transaction_response {
+            Some(transaction_response) => transaction_response,
+            None => {
+                return Ok(Self {
+                    response: Err(item.response.messages.get_error_response(item.http_code)),
+                    ..item.data
+                })
+            }
+        };
         let refund_status = enums::RefundStatus::from(transaction_response.response_code.clone());
         let error = transaction_response.errors.clone().and_then(|errors| {
             errors.first().map(|error| types::ErrorResponse {
@@ -433,6 +608,7 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AuthorizedotnetRefundRespons
                 message: error.error_text.clone(),
                 reason: None,
                 status_code: item.http_code,
+                unified_code: None,
             })
         });
 
@@ -512,7 +688,7 @@ impl TryFrom<&types::PaymentsSyncRouterData> for AuthorizedotnetCreateSyncReques
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SyncStatus {
     RefundSettledSuccessfully,
@@ -524,8 +700,12 @@ pub enum SyncStatus {
     Voided,
     CouldNotVoid,
     GeneralError,
+    #[serde(rename = "FDSPendingReview")]
+    FdsPendingReview,
+    #[serde(rename = "FDSAuthorizedPendingReview")]
+    FdsAuthorizedPendingReview,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncTransactionResponse {
     #[serde(rename = "transId")]
@@ -533,7 +713,7 @@ pub struct SyncTransactionResponse {
     transaction_status: SyncStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AuthorizedotnetSyncResponse {
     transaction: SyncTransactionResponse,
 }
@@ -607,6 +787,9 @@ impl<F, Req>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             status: payment_status,
             ..item.data
@@ -628,6 +811,193 @@ pub struct AuthorizedotnetErrorResponse {
     pub error: ErrorDetails,
 }
 
+// Webhook Types
+#[derive(Debug, Clone, Deserialize)]
+pub enum AuthorizedotnetWebhookEvent {
+    #[serde(rename = "net.authorize.payment.authorization.created")]
+    AuthorizationCreated,
+    #[serde(rename = "net.authorize.payment.authcapture.created")]
+    AuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.capture.created")]
+    CaptureCreated,
+    #[serde(rename = "net.authorize.payment.priorAuthCapture.created")]
+    PriorAuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.void.created")]
+    VoidCreated,
+    #[serde(rename = "net.authorize.payment.refund.created")]
+    RefundCreated,
+    #[serde(rename = "net.authorize.payment.fraud.held")]
+    FraudHeld,
+    #[serde(rename = "net.authorize.payment.fraud.declined")]
+    FraudDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookBody {
+    pub event_type: AuthorizedotnetWebhookEvent,
+    pub payload: AuthorizedotnetWebhookPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookPayload {
+    pub id: String,
+    pub response_code: Option<u8>,
+}
+
+/// Silent Post notifications, posted as a form to the silent post url of the merchant
+#[derive(Debug, Deserialize)]
+pub struct AuthorizedotnetSilentPost {
+    pub x_trans_id: String,
+    pub x_amount: String,
+    pub x_response_code: u8,
+    pub x_type: AuthorizedotnetSilentPostType,
+    #[serde(rename = "x_MD5_Hash")]
+    pub x_md5_hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizedotnetSilentPostType {
+    AuthOnly,
+    AuthCapture,
+    PriorAuthCapture,
+    CaptureOnly,
+    Credit,
+    Void,
+}
+
+/// Authorize.Net notifies merchants through webhooks, signed with HMAC-SHA512 over the body, and
+/// through the older Silent Post, signed with an MD5 hash over the transaction details
+#[derive(Debug)]
+pub enum AuthorizedotnetNotification {
+    Webhook(AuthorizedotnetWebhookBody),
+    SilentPost(AuthorizedotnetSilentPost),
+}
+
+impl TryFrom<&[u8]> for AuthorizedotnetNotification {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(body: &[u8]) -> Result<Self, Self::Error> {
+        match serde_json::from_slice::<AuthorizedotnetWebhookBody>(body) {
+            Ok(webhook) => Ok(Self::Webhook(webhook)),
+            Err(_) => serde_urlencoded::from_bytes::<AuthorizedotnetSilentPost>(body)
+                .map(Self::SilentPost)
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed),
+        }
+    }
+}
+
+impl AuthorizedotnetNotification {
+    pub fn get_transaction_id(&self) -> String {
+        match self {
+            Self::Webhook(webhook) => webhook.payload.id.clone(),
+            Self::SilentPost(silent_post) => silent_post.x_trans_id.clone(),
+        }
+    }
+
+    fn get_event(&self) -> AuthorizedotnetWebhookEvent {
+        match self {
+            Self::Webhook(webhook) => webhook.event_type.clone(),
+            Self::SilentPost(silent_post) => match silent_post.x_type {
+                AuthorizedotnetSilentPostType::AuthOnly => {
+                    AuthorizedotnetWebhookEvent::AuthorizationCreated
+                }
+                AuthorizedotnetSilentPostType::AuthCapture => {
+                    AuthorizedotnetWebhookEvent::AuthCaptureCreated
+                }
+                AuthorizedotnetSilentPostType::PriorAuthCapture => {
+                    AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated
+                }
+                AuthorizedotnetSilentPostType::CaptureOnly => {
+                    AuthorizedotnetWebhookEvent::CaptureCreated
+                }
+                AuthorizedotnetSilentPostType::Credit => AuthorizedotnetWebhookEvent::RefundCreated,
+                AuthorizedotnetSilentPostType::Void => AuthorizedotnetWebhookEvent::VoidCreated,
+            },
+        }
+    }
+
+    // Uses the response codes of the transaction responses, where 1 is approved, 2 declined,
+    // 3 an error and 4 held for review
+    fn get_response_code(&self) -> Option<u8> {
+        match self {
+            Self::Webhook(webhook) => webhook.payload.response_code,
+            Self::SilentPost(silent_post) => Some(silent_post.x_response_code),
+        }
+    }
+}
+
+impl From<&AuthorizedotnetNotification> for api::IncomingWebhookEvent {
+    fn from(notification: &AuthorizedotnetNotification) -> Self {
+        let is_declined = matches!(notification.get_response_code(), Some(2) | Some(3));
+        match notification.get_event() {
+            // The payment is synced from the transaction of the notification, so every payment
+            // event routes to the payment flow
+            AuthorizedotnetWebhookEvent::AuthorizationCreated
+            | AuthorizedotnetWebhookEvent::AuthCaptureCreated
+            | AuthorizedotnetWebhookEvent::VoidCreated
+            | AuthorizedotnetWebhookEvent::FraudHeld => {
+                if is_declined {
+                    Self::PaymentIntentFailure
+                } else {
+                    Self::PaymentIntentSuccess
+                }
+            }
+            AuthorizedotnetWebhookEvent::CaptureCreated
+            | AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated => {
+                if is_declined {
+                    Self::PaymentIntentCaptureFailure
+                } else {
+                    Self::PaymentIntentCaptureSuccess
+                }
+            }
+            AuthorizedotnetWebhookEvent::FraudDeclined => Self::PaymentIntentFailure,
+            AuthorizedotnetWebhookEvent::RefundCreated => {
+                if is_declined {
+                    Self::RefundFailure
+                } else {
+                    Self::RefundSuccess
+                }
+            }
+            AuthorizedotnetWebhookEvent::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+impl From<&AuthorizedotnetNotification> for AuthorizedotnetSyncResponse {
+    fn from(notification: &AuthorizedotnetNotification) -> Self {
+        let transaction_status = match notification.get_response_code() {
+            Some(2) | Some(3) => SyncStatus::Declined,
+            Some(4) => SyncStatus::FdsPendingReview,
+            _ => match notification.get_event() {
+                AuthorizedotnetWebhookEvent::AuthorizationCreated => {
+                    SyncStatus::AuthorizedPendingCapture
+                }
+                AuthorizedotnetWebhookEvent::AuthCaptureCreated
+                | AuthorizedotnetWebhookEvent::CaptureCreated
+                | AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated => {
+                    SyncStatus::CapturedPendingSettlement
+                }
+                AuthorizedotnetWebhookEvent::VoidCreated => SyncStatus::Voided,
+                AuthorizedotnetWebhookEvent::RefundCreated => SyncStatus::RefundPendingSettlement,
+                AuthorizedotnetWebhookEvent::FraudHeld => SyncStatus::FdsPendingReview,
+                AuthorizedotnetWebhookEvent::FraudDeclined => SyncStatus::Declined,
+                AuthorizedotnetWebhookEvent::Unknown => SyncStatus::GeneralError,
+            },
+        };
+        Self {
+            transaction: SyncTransactionResponse {
+                transaction_id: notification.get_transaction_id(),
+                transaction_status,
+            },
+        }
+    }
+}
+
 fn construct_refund_payment_details(masked_number: String) -> PaymentDetails {
     PaymentDetails::CreditCard(CreditCardDetails {
         card_number: masked_number.into(),
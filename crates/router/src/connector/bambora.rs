@@ -82,10 +82,17 @@ impl ConnectorCommon for Bambora {
             code: response.code.to_string(),
             message: response.message,
             reason: Some(serde_json::to_string(&response.details).unwrap_or_default()),
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Bambora {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::Payment for Bambora {}
 
 impl api::PreVerify for Bambora {}
@@ -592,6 +599,59 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Bambora {}
+impl api::PayoutCreate for Bambora {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl api::PayoutFulfill for Bambora {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl api::PayoutCancel for Bambora {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl api::PayoutSync for Bambora {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Bambora {}
+
+impl api::RefundCredit for Bambora {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Bambora {}
+
+impl api::ConnectorMandateRevoke for Bambora {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Bambora
+{
+}
+
+impl api::Dispute for Bambora {}
+impl api::SubmitEvidence for Bambora {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Bambora
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Bambora {
     fn get_webhook_object_reference_id(
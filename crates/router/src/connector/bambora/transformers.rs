@@ -148,6 +148,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -3,7 +3,6 @@ mod transformers;
 use std::fmt::Debug;
 
 use base64::Engine;
-use common_utils::crypto;
 use error_stack::{IntoReport, ResultExt};
 use transformers as bluesnap;
 
@@ -92,12 +91,14 @@ impl ConnectorCommon for Bluesnap {
                     code: consts::NO_ERROR_CODE.to_string(),
                     message: consts::NO_ERROR_MESSAGE.to_string(),
                     reason: None,
+                    unified_code: None,
                 },
                 |error_response| ErrorResponse {
                     status_code: res.status_code,
                     code: error_response.code.clone(),
                     message: error_response.description.clone(),
                     reason: None,
+                    unified_code: None,
                 },
             ),
             bluesnap::BluesnapErrors::AuthError(error_res) => ErrorResponse {
@@ -105,12 +106,19 @@ impl ConnectorCommon for Bluesnap {
                 code: error_res.error_code.clone(),
                 message: error_res.error_description,
                 reason: None,
+                unified_code: None,
             },
         };
         Ok(response_error_message)
     }
 }
 
+impl api::ConnectorSpecifications for Bluesnap {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::Payment for Bluesnap {}
 
 impl api::PreVerify for Bluesnap {}
@@ -371,6 +379,7 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
             code: consts::NO_ERROR_CODE.to_string(),
             message: response,
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -623,13 +632,72 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Bluesnap {}
+impl api::PayoutCreate for Bluesnap {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::PayoutFulfill for Bluesnap {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::PayoutCancel for Bluesnap {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::PayoutSync for Bluesnap {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::RefundCredit for Bluesnap {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::ConnectorMandateRevoke for Bluesnap {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Bluesnap
+{
+}
+
+impl api::Dispute for Bluesnap {}
+impl api::SubmitEvidence for Bluesnap {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Bluesnap
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Bluesnap {
-    fn get_webhook_source_verification_algorithm(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::Md5))
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::Md5)
     }
 
     fn get_webhook_source_verification_signature(
@@ -659,20 +727,6 @@ impl api::IncomingWebhook for Bluesnap {
         Ok(msg.into_bytes())
     }
 
-    async fn get_webhook_source_verification_merchant_secret(
-        &self,
-        db: &dyn StorageInterface,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .find_config_by_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
-
-        Ok(secret.config.into_bytes())
-    }
-
     async fn verify_webhook_source(
         &self,
         db: &dyn StorageInterface,
@@ -680,8 +734,9 @@ impl api::IncomingWebhook for Bluesnap {
         merchant_id: &str,
     ) -> CustomResult<bool, errors::ConnectorError> {
         let algorithm = self
-            .get_webhook_source_verification_algorithm(request)
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+            .get_webhook_source_verification_scheme(request)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?
+            .get_algorithm();
 
         let signature = self
             .get_webhook_source_verification_signature(request)
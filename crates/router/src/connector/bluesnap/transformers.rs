@@ -200,6 +200,35 @@ pub struct ProcessingInfoResponse {
     processing_status: BluesnapProcessingStatus,
     authorization_code: Option<String>,
     network_transaction_id: Option<String>,
+    avs_response_code_zip: Option<String>,
+    avs_response_code_address: Option<String>,
+    cvv_response_code: Option<String>,
+}
+
+impl ProcessingInfoResponse {
+    /// Bluesnap verifies the postal code and the street address separately, answering M when
+    /// they match and N when they don't
+    fn get_avs_result(&self) -> Option<enums::AvsResult> {
+        let zip = self.avs_response_code_zip.as_deref()?;
+        let address = self.avs_response_code_address.as_deref()?;
+        Some(match (zip, address) {
+            ("M", "M") => enums::AvsResult::Match,
+            ("M", "N") => enums::AvsResult::PostalCodeMatch,
+            ("N", "M") => enums::AvsResult::AddressMatch,
+            ("N", "N") => enums::AvsResult::NoMatch,
+            _ => enums::AvsResult::Unavailable,
+        })
+    }
+
+    fn get_cvv_result(&self) -> Option<enums::CvvResult> {
+        self.cvv_response_code
+            .as_deref()
+            .map(|cvv_response_code| match cvv_response_code {
+                "MA" => enums::CvvResult::Match,
+                "NM" => enums::CvvResult::NoMatch,
+                _ => enums::CvvResult::Unavailable,
+            })
+    }
 }
 
 impl<F, T>
@@ -227,6 +256,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                avs_result: item.response.processing_info.get_avs_result(),
+                cvv_result: item.response.processing_info.get_cvv_result(),
+                network_txn_id: item.response.processing_info.network_transaction_id,
             }),
             ..item.data
         })
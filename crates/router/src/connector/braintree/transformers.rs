@@ -1,201 +1,595 @@
 use api_models::payments;
 use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils,
+    connector::utils::{self, RefundsRequestData, RouterData},
     consts,
     core::errors,
     types::{self, api, storage::enums},
 };
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct DeviceData;
+pub const BRAINTREE_API_VERSION: &str = "2019-01-01";
+
+const CREATE_CLIENT_TOKEN_MUTATION: &str =
+    "mutation CreateClientToken { createClientToken { clientToken } }";
+const TOKENIZE_CREDIT_CARD_MUTATION: &str = "mutation TokenizeCreditCard($input: TokenizeCreditCardInput!) { tokenizeCreditCard(input: $input) { paymentMethod { id } } }";
+const CHARGE_PAYMENT_METHOD_MUTATION: &str = "mutation ChargePaymentMethod($input: ChargePaymentMethodInput!) { payment: chargePaymentMethod(input: $input) { transaction { id status paymentMethod { id usage } } } }";
+const AUTHORIZE_PAYMENT_METHOD_MUTATION: &str = "mutation AuthorizePaymentMethod($input: AuthorizePaymentMethodInput!) { payment: authorizePaymentMethod(input: $input) { transaction { id status paymentMethod { id usage } } } }";
+const CAPTURE_TRANSACTION_MUTATION: &str = "mutation CaptureTransaction($input: CaptureTransactionInput!) { payment: captureTransaction(input: $input) { transaction { id status } } }";
+// A reversal of a settled transaction is a refund, which does not match the fragment and fails
+// to deserialize, so a void never silently turns into a refund
+const REVERSE_TRANSACTION_MUTATION: &str = "mutation ReverseTransaction($input: ReverseTransactionInput!) { payment: reverseTransaction(input: $input) { transaction: reversal { ... on Transaction { id status } } } }";
+const REFUND_TRANSACTION_MUTATION: &str = "mutation RefundTransaction($input: RefundTransactionInput!) { refundTransaction(input: $input) { refund { id status } } }";
+const TRANSACTION_QUERY: &str = "query Transaction($id: ID!) { node(id: $id) { ... on Transaction { id status paymentMethod { id usage } } } }";
+const REFUND_QUERY: &str =
+    "query Refund($id: ID!) { node(id: $id) { ... on Refund { id status } } }";
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct PaymentOptions {
-    submit_for_settlement: bool,
+pub struct BraintreeAuthType {
+    pub(super) auth_header: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::SignatureKey {
+            api_key: public_key,
+            api_secret: private_key,
+            ..
+        } = item
+        {
+            let auth_key = format!("{public_key}:{private_key}");
+            let auth_header = format!("Basic {}", consts::BASE64_ENGINE.encode(auth_key));
+            Ok(Self { auth_header })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType)?
+        }
+    }
+}
+
+// GraphQL request envelopes
+#[derive(Debug, Serialize)]
+pub struct GraphqlRequest<V> {
+    query: &'static str,
+    variables: V,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InputVariables<T> {
+    input: T,
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreePaymentsRequest {
-    transaction: TransactionBody,
+#[derive(Debug, Serialize)]
+pub struct NodeVariables {
+    id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmptyVariables {}
+
+/// GraphQL reports failures in the body, usually alongside a successful status code.
+#[derive(Debug, Deserialize)]
+pub struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreeApiVersion {
-    version: String,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphqlErrorExtensions {
+    legacy_code: Option<String>,
+    error_class: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GraphqlError {
+    message: String,
+    extensions: Option<GraphqlErrorExtensions>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraintreeErrorResponse {
+    #[serde(default)]
+    pub errors: Vec<GraphqlError>,
+}
+
+pub fn get_error_response(errors: &[GraphqlError], status_code: u16) -> types::ErrorResponse {
+    let error = errors.first();
+    let code = error
+        .and_then(|error| error.extensions.as_ref())
+        .and_then(|extensions| {
+            extensions
+                .legacy_code
+                .clone()
+                .or_else(|| extensions.error_class.clone())
+        })
+        .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string());
+    let message = error
+        .map(|error| error.message.clone())
+        .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string());
+    types::ErrorResponse {
+        code,
+        message,
+        reason: (errors.len() > 1).then(|| {
+            errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }),
+        status_code,
+        unified_code: None,
+    }
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct BraintreeSessionRequest {
-    client_token: BraintreeApiVersion,
+impl<T> GraphqlResponse<T> {
+    fn into_result(
+        self,
+        status_code: u16,
+    ) -> Result<Result<T, types::ErrorResponse>, error_stack::Report<errors::ConnectorError>> {
+        if !self.errors.is_empty() {
+            return Ok(Err(get_error_response(&self.errors, status_code)));
+        }
+        self.data
+            .map(Ok)
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+            .into_report()
+    }
 }
 
+// Session Request
+pub type BraintreeSessionRequest = GraphqlRequest<EmptyVariables>;
+
 impl TryFrom<&types::PaymentsSessionRouterData> for BraintreeSessionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(_item: &types::PaymentsSessionRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
-            client_token: BraintreeApiVersion {
-                version: "2".to_string(),
-            },
+            query: CREATE_CLIENT_TOKEN_MUTATION,
+            variables: EmptyVariables {},
         })
     }
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct TransactionBody {
-    amount: String,
-    device_data: DeviceData,
-    options: PaymentOptions,
-    #[serde(flatten)]
-    payment_method_data_type: PaymentMethodType,
-    #[serde(rename = "type")]
-    kind: String,
+pub struct ClientToken {
+    pub client_token: String,
 }
 
-#[derive(Debug, Serialize, Eq, PartialEq)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-#[serde(tag = "type")]
-pub enum PaymentMethodType {
-    CreditCard(Card),
-    PaymentMethodNonce(Nonce),
+pub struct ClientTokenData {
+    create_client_token: ClientToken,
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-pub struct Nonce {
-    payment_method_nonce: String,
-}
+pub type BraintreeSessionTokenResponse = GraphqlResponse<ClientTokenData>;
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct Card {
-    credit_card: CardDetails,
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, BraintreeSessionTokenResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            BraintreeSessionTokenResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response.into_result(item.http_code)?.map(|data| {
+            types::PaymentsResponseData::SessionResponse {
+                session_token: types::api::SessionToken::Paypal(Box::new(
+                    payments::PaypalSessionTokenResponse {
+                        session_token: data.create_client_token.client_token,
+                    },
+                )),
+            }
+        });
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
 }
 
-#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+// Card Tokenization Request
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CardDetails {
+pub struct CreditCardInput {
     number: Secret<String, common_utils::pii::CardNumber>,
     expiration_month: Secret<String>,
     expiration_year: Secret<String>,
     cvv: Secret<String>,
+    cardholder_name: Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizeCreditCardInput {
+    credit_card: CreditCardInput,
+}
+
+pub type BraintreeTokenizeRequest = GraphqlRequest<InputVariables<TokenizeCreditCardInput>>;
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for BraintreeTokenizeRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref ccard) => Ok(Self {
+                query: TOKENIZE_CREDIT_CARD_MUTATION,
+                variables: InputVariables {
+                    input: TokenizeCreditCardInput {
+                        credit_card: CreditCardInput {
+                            number: ccard.card_number.clone(),
+                            expiration_month: ccard.card_exp_month.clone(),
+                            expiration_year: ccard.card_exp_year.clone(),
+                            cvv: ccard.card_cvc.clone(),
+                            cardholder_name: ccard.card_holder_name.clone(),
+                        },
+                    },
+                },
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodId {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizedPaymentMethod {
+    payment_method: PaymentMethodId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizeCreditCardData {
+    tokenize_credit_card: TokenizedPaymentMethod,
+}
+
+pub type BraintreeTokenizeResponse = GraphqlResponse<TokenizeCreditCardData>;
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, BraintreeTokenizeResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            BraintreeTokenizeResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        match item.response.into_result(item.http_code)? {
+            Ok(data) => {
+                let payment_method_id = data.tokenize_credit_card.payment_method.id;
+                Ok(Self {
+                    session_token: Some(payment_method_id.clone()),
+                    response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                        session_token: payment_method_id,
+                    }),
+                    ..item.data
+                })
+            }
+            Err(error) => Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(error),
+                ..item.data
+            }),
+        }
+    }
+}
+
+// Payments Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultWhen {
+    OnSuccessfulTransaction,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultPaymentMethodInput {
+    when: VaultWhen,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInput {
+    amount: String,
+    order_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vault_payment_method_after_transacting: Option<VaultPaymentMethodInput>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodInput {
+    payment_method_id: String,
+    transaction: TransactionInput,
+}
+
+pub type BraintreePaymentsRequest = GraphqlRequest<InputVariables<PaymentMethodInput>>;
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let submit_for_settlement = matches!(
-            item.request.capture_method,
-            Some(enums::CaptureMethod::Automatic) | None
-        );
-
-        let amount = utils::to_currency_base_unit(item.request.amount, item.request.currency)?;
-        let device_data = DeviceData {};
-        let options = PaymentOptions {
-            submit_for_settlement,
-        };
-        let kind = "sale".to_string();
-
-        let payment_method_data_type = match item.request.payment_method_data.clone() {
-            api::PaymentMethodData::Card(ccard) => Ok(PaymentMethodType::CreditCard(Card {
-                credit_card: CardDetails {
-                    number: ccard.card_number,
-                    expiration_month: ccard.card_exp_month,
-                    expiration_year: ccard.card_exp_year,
-                    cvv: ccard.card_cvc,
-                },
-            })),
-            api::PaymentMethodData::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodType::PaymentMethodNonce(Nonce {
-                    payment_method_nonce: match wallet_data {
-                        api_models::payments::WalletData::PaypalSdk(wallet_data) => {
-                            Ok(wallet_data.token.to_owned())
-                        }
-                        _ => Err(errors::ConnectorError::InvalidWallet),
-                    }?,
-                }))
+        let connector_mandate_id = item
+            .request
+            .mandate_id
+            .clone()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id);
+        // Vaulted payment methods are charged directly, everything else is charged through a
+        // single use payment method: a tokenized card or a PayPal nonce from the client SDK
+        let (payment_method_id, vault_payment_method_after_transacting) = match connector_mandate_id
+        {
+            Some(connector_mandate_id) => (connector_mandate_id, None),
+            None => {
+                let payment_method_id = match item.request.payment_method_data {
+                    api::PaymentMethodData::Card(_) => item.get_session_token()?,
+                    api::PaymentMethodData::Wallet(ref wallet_data) => match wallet_data {
+                        payments::WalletData::PaypalSdk(paypal_data) => paypal_data.token.clone(),
+                        _ => return Err(errors::ConnectorError::InvalidWallet.into()),
+                    },
+                    _ => {
+                        return Err(errors::ConnectorError::NotImplemented(format!(
+                            "Current Payment Method - {:?}",
+                            item.request.payment_method_data
+                        ))
+                        .into())
+                    }
+                };
+                let vault = (item.request.setup_future_usage
+                    == Some(enums::FutureUsage::OffSession))
+                .then_some(VaultPaymentMethodInput {
+                    when: VaultWhen::OnSuccessfulTransaction,
+                });
+                (payment_method_id, vault)
             }
-            _ => Err(errors::ConnectorError::NotImplemented(format!(
-                "Current Payment Method - {:?}",
-                item.request.payment_method_data
-            ))),
-        }?;
-        let braintree_transaction_body = TransactionBody {
-            amount,
-            device_data,
-            options,
-            payment_method_data_type,
-            kind,
+        };
+
+        let query = match item.request.capture_method {
+            Some(enums::CaptureMethod::Automatic) | None => CHARGE_PAYMENT_METHOD_MUTATION,
+            _ => AUTHORIZE_PAYMENT_METHOD_MUTATION,
         };
         Ok(Self {
-            transaction: braintree_transaction_body,
+            query,
+            variables: InputVariables {
+                input: PaymentMethodInput {
+                    payment_method_id,
+                    transaction: TransactionInput {
+                        amount: utils::to_currency_base_unit(
+                            item.request.amount,
+                            item.request.currency,
+                        )?,
+                        order_id: item.payment_id.clone(),
+                        vault_payment_method_after_transacting,
+                    },
+                },
+            },
         })
     }
 }
 
-pub struct BraintreeAuthType {
-    pub(super) auth_header: String,
-    pub(super) merchant_id: String,
+// Payments Sync Request
+pub type BraintreeSyncRequest = GraphqlRequest<NodeVariables>;
+
+impl TryFrom<&types::PaymentsSyncRouterData> for BraintreeSyncRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            query: TRANSACTION_QUERY,
+            variables: NodeVariables {
+                id: item
+                    .request
+                    .connector_transaction_id
+                    .get_connector_transaction_id()
+                    .change_context(errors::ConnectorError::MissingConnectorTransactionID)?,
+            },
+        })
+    }
 }
 
-impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
+// Capture Request
+#[derive(Debug, Serialize)]
+pub struct CaptureAmountInput {
+    amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureTransactionInput {
+    transaction_id: String,
+    transaction: CaptureAmountInput,
+}
+
+pub type BraintreeCaptureRequest = GraphqlRequest<InputVariables<CaptureTransactionInput>>;
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for BraintreeCaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
-        if let types::ConnectorAuthType::SignatureKey {
-            api_key: public_key,
-            key1: merchant_id,
-            api_secret: private_key,
-        } = item
-        {
-            let auth_key = format!("{public_key}:{private_key}");
-            let auth_header = format!("Basic {}", consts::BASE64_ENGINE.encode(auth_key));
-            Ok(Self {
-                auth_header,
-                merchant_id: merchant_id.to_owned(),
-            })
-        } else {
-            Err(errors::ConnectorError::FailedToObtainAuthType)?
-        }
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            query: CAPTURE_TRANSACTION_MUTATION,
+            variables: InputVariables {
+                input: CaptureTransactionInput {
+                    transaction_id: item.request.connector_transaction_id.clone(),
+                    transaction: CaptureAmountInput {
+                        amount: utils::to_currency_base_unit(
+                            item.request
+                                .amount_to_capture
+                                .unwrap_or(item.request.amount),
+                            item.request.currency,
+                        )?,
+                    },
+                },
+            },
+        })
     }
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum BraintreePaymentStatus {
-    Succeeded,
-    Failed,
+// Void Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseTransactionInput {
+    transaction_id: String,
+}
+
+pub type BraintreeVoidRequest = GraphqlRequest<InputVariables<ReverseTransactionInput>>;
+
+impl TryFrom<&types::PaymentsCancelRouterData> for BraintreeVoidRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            query: REVERSE_TRANSACTION_MUTATION,
+            variables: InputVariables {
+                input: ReverseTransactionInput {
+                    transaction_id: item.request.connector_transaction_id.clone(),
+                },
+            },
+        })
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BraintreeTransactionStatus {
+    Authorizing,
     Authorized,
-    AuthorizedExpired,
-    ProcessorDeclined,
-    GatewayRejected,
-    Voided,
+    AuthorizationExpired,
     SubmittedForSettlement,
-    #[default]
     Settling,
     Settled,
     SettlementPending,
-    SettlementDeclined,
     SettlementConfirmed,
+    SettlementDeclined,
+    Voided,
+    Failed,
+    ProcessorDeclined,
+    GatewayRejected,
 }
 
-impl From<BraintreePaymentStatus> for enums::AttemptStatus {
-    fn from(item: BraintreePaymentStatus) -> Self {
+impl From<BraintreeTransactionStatus> for enums::AttemptStatus {
+    fn from(item: BraintreeTransactionStatus) -> Self {
         match item {
-            BraintreePaymentStatus::Succeeded
-            | BraintreePaymentStatus::SubmittedForSettlement
-            | BraintreePaymentStatus::Settling => Self::Charged,
-            BraintreePaymentStatus::AuthorizedExpired => Self::AuthorizationFailed,
-            BraintreePaymentStatus::Failed
-            | BraintreePaymentStatus::GatewayRejected
-            | BraintreePaymentStatus::ProcessorDeclined
-            | BraintreePaymentStatus::SettlementDeclined => Self::Failure,
-            BraintreePaymentStatus::Authorized => Self::Authorized,
-            BraintreePaymentStatus::Voided => Self::Voided,
-            _ => Self::Pending,
+            BraintreeTransactionStatus::Authorizing => Self::Authorizing,
+            BraintreeTransactionStatus::Authorized => Self::Authorized,
+            BraintreeTransactionStatus::AuthorizationExpired => Self::AuthorizationFailed,
+            BraintreeTransactionStatus::SubmittedForSettlement
+            | BraintreeTransactionStatus::Settling
+            | BraintreeTransactionStatus::Settled
+            | BraintreeTransactionStatus::SettlementConfirmed => Self::Charged,
+            BraintreeTransactionStatus::SettlementPending => Self::Pending,
+            BraintreeTransactionStatus::Voided => Self::Voided,
+            BraintreeTransactionStatus::Failed
+            | BraintreeTransactionStatus::ProcessorDeclined
+            | BraintreeTransactionStatus::GatewayRejected
+            | BraintreeTransactionStatus::SettlementDeclined => Self::Failure,
+        }
+    }
+}
+
+impl From<BraintreeTransactionStatus> for enums::RefundStatus {
+    fn from(item: BraintreeTransactionStatus) -> Self {
+        match item {
+            BraintreeTransactionStatus::SubmittedForSettlement
+            | BraintreeTransactionStatus::Settling
+            | BraintreeTransactionStatus::Settled
+            | BraintreeTransactionStatus::SettlementConfirmed => Self::Success,
+            BraintreeTransactionStatus::Voided
+            | BraintreeTransactionStatus::Failed
+            | BraintreeTransactionStatus::ProcessorDeclined
+            | BraintreeTransactionStatus::GatewayRejected
+            | BraintreeTransactionStatus::SettlementDeclined
+            | BraintreeTransactionStatus::AuthorizationExpired => Self::Failure,
+            BraintreeTransactionStatus::Authorizing
+            | BraintreeTransactionStatus::Authorized
+            | BraintreeTransactionStatus::SettlementPending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentMethodUsage {
+    SingleUse,
+    MultiUse,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionPaymentMethod {
+    id: String,
+    usage: PaymentMethodUsage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    id: String,
+    status: BraintreeTransactionStatus,
+    payment_method: Option<TransactionPaymentMethod>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionPayload {
+    transaction: Transaction,
+}
+
+/// Charge, authorize, capture and void responses, all aliased to the same shape
+#[derive(Debug, Deserialize)]
+pub struct PaymentData {
+    payment: TransactionPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NodeData<T> {
+    node: T,
+}
+
+pub type BraintreePaymentsResponse = GraphqlResponse<PaymentData>;
+pub type BraintreeSyncResponse = GraphqlResponse<NodeData<Transaction>>;
+
+fn get_payment_router_data<F, T>(
+    transaction: Result<Transaction, types::ErrorResponse>,
+    data: types::RouterData<F, T, types::PaymentsResponseData>,
+) -> types::RouterData<F, T, types::PaymentsResponseData> {
+    match transaction {
+        Ok(transaction) => {
+            // Only payment methods vaulted with the transaction can be charged again
+            let mandate_reference = transaction
+                .payment_method
+                .filter(|payment_method| payment_method.usage == PaymentMethodUsage::MultiUse)
+                .map(|payment_method| payment_method.id);
+            types::RouterData {
+                status: enums::AttemptStatus::from(transaction.status),
+                response: Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(transaction.id),
+                    redirection_data: None,
+                    mandate_reference,
+                    connector_metadata: None,
+                    network_txn_id: None,
+                    avs_result: None,
+                    cvv_result: None,
+                }),
+                ..data
+            }
         }
+        Err(error) => types::RouterData {
+            response: Err(error),
+            ..data
+        },
     }
 }
 
@@ -212,160 +606,141 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.transaction.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction.id,
-                ),
-                redirection_data: None,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
-            ..item.data
-        })
+        let transaction = item
+            .response
+            .into_result(item.http_code)?
+            .map(|data| data.payment.transaction);
+        Ok(get_payment_router_data(transaction, item.data))
     }
 }
 
 impl<F, T>
-    TryFrom<
-        types::ResponseRouterData<F, BraintreeSessionTokenResponse, T, types::PaymentsResponseData>,
-    > for types::RouterData<F, T, types::PaymentsResponseData>
+    TryFrom<types::ResponseRouterData<F, BraintreeSyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<
-            F,
-            BraintreeSessionTokenResponse,
-            T,
-            types::PaymentsResponseData,
-        >,
+        item: types::ResponseRouterData<F, BraintreeSyncResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::PaymentsResponseData::SessionResponse {
-                session_token: types::api::SessionToken::Paypal(Box::new(
-                    payments::PaypalSessionTokenResponse {
-                        session_token: item.response.client_token.value,
-                    },
-                )),
-            }),
-            ..item.data
-        })
+        let transaction = item
+            .response
+            .into_result(item.http_code)?
+            .map(|data| data.node);
+        Ok(get_payment_router_data(transaction, item.data))
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BraintreePaymentsResponse {
-    transaction: TransactionResponse,
-}
-
-#[derive(Default, Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ClientToken {
-    pub value: String,
-}
-
-#[derive(Default, Debug, Clone, Deserialize)]
+// REFUND :
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BraintreeSessionTokenResponse {
-    pub client_token: ClientToken,
-}
-
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct TransactionResponse {
-    id: String,
-    currency_iso_code: String,
+pub struct RefundInput {
     amount: String,
-    status: BraintreePaymentStatus,
+    order_id: String,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ErrorResponse {
-    pub api_error_response: ApiErrorResponse,
+pub struct RefundTransactionInput {
+    transaction_id: String,
+    refund: RefundInput,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
-pub struct ApiErrorResponse {
-    pub message: String,
-}
+pub type BraintreeRefundRequest = GraphqlRequest<InputVariables<RefundTransactionInput>>;
 
-#[derive(Default, Debug, Clone, Serialize)]
-pub struct BraintreeRefundRequest {
-    transaction: Amount,
+impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            query: REFUND_TRANSACTION_MUTATION,
+            variables: InputVariables {
+                input: RefundTransactionInput {
+                    transaction_id: item.request.connector_transaction_id.clone(),
+                    refund: RefundInput {
+                        amount: utils::to_currency_base_unit(
+                            item.request.refund_amount,
+                            item.request.currency,
+                        )?,
+                        order_id: item.request.refund_id.clone(),
+                    },
+                },
+            },
+        })
+    }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct Amount {
-    amount: Option<String>,
-}
+pub type BraintreeRefundSyncRequest = GraphqlRequest<NodeVariables>;
 
-impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
+impl TryFrom<&types::RefundSyncRouterData> for BraintreeRefundSyncRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(_item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+    fn try_from(item: &types::RefundSyncRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
-            transaction: Amount { amount: None },
+            query: REFUND_QUERY,
+            variables: NodeVariables {
+                id: item.request.get_connector_refund_id()?,
+            },
         })
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
-pub enum RefundStatus {
-    Succeeded,
-    Failed,
-    #[default]
-    Processing,
+#[derive(Debug, Deserialize)]
+pub struct Refund {
+    id: String,
+    status: BraintreeTransactionStatus,
 }
 
-impl From<RefundStatus> for enums::RefundStatus {
-    fn from(item: RefundStatus) -> Self {
-        match item {
-            RefundStatus::Succeeded => Self::Success,
-            RefundStatus::Failed => Self::Failure,
-            RefundStatus::Processing => Self::Pending,
-        }
-    }
+#[derive(Debug, Deserialize)]
+pub struct RefundPayload {
+    refund: Refund,
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
-pub struct RefundResponse {
-    pub id: String,
-    pub status: RefundStatus,
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundTransactionData {
+    refund_transaction: RefundPayload,
+}
+
+pub type RefundResponse = GraphqlResponse<RefundTransactionData>;
+pub type RefundSyncResponse = GraphqlResponse<NodeData<Refund>>;
+
+fn get_refund_router_data<F>(
+    refund: Result<Refund, types::ErrorResponse>,
+    data: types::RefundsRouterData<F>,
+) -> types::RefundsRouterData<F> {
+    types::RouterData {
+        response: refund.map(|refund| types::RefundsResponseData {
+            connector_refund_id: refund.id,
+            refund_status: enums::RefundStatus::from(refund.status),
+        }),
+        ..data
+    }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
-    for types::RefundsRouterData<api::Execute>
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
-        })
+        let refund = item
+            .response
+            .into_result(item.http_code)?
+            .map(|data| data.refund_transaction.refund);
+        Ok(get_refund_router_data(refund, item.data))
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
-    for types::RefundsRouterData<api::RSync>
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundSyncResponse>>
+    for types::RefundsRouterData<F>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
+        item: types::RefundsResponseRouterData<F, RefundSyncResponse>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
-        })
+        let refund = item
+            .response
+            .into_result(item.http_code)?
+            .map(|data| data.node);
+        Ok(get_refund_router_data(refund, item.data))
     }
 }
@@ -51,6 +51,20 @@ impl ConnectorCommon for Checkout {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.checkout.base_url.as_ref()
     }
+
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("checkoutltd")
+    }
+}
+
+impl api::ConnectorSpecifications for Checkout {
+    fn supports_zero_amount_authorization(&self) -> bool {
+        true
+    }
+
+    fn supports_external_authentication(&self) -> bool {
+        true
+    }
 }
 
 impl api::Payment for Checkout {}
@@ -84,6 +98,11 @@ impl
 
 impl api::PreVerify for Checkout {}
 
+type Verify = dyn services::ConnectorIntegration<
+    api::Verify,
+    types::VerifyRequestData,
+    types::PaymentsResponseData,
+>;
 impl
     services::ConnectorIntegration<
         api::Verify,
@@ -91,7 +110,85 @@ impl
         types::PaymentsResponseData,
     > for Checkout
 {
-    // Issue: #173
+    fn get_headers(
+        &self,
+        req: &types::VerifyRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                Verify::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::VerifyRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "payments"))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::VerifyRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = checkout::PaymentsRequest::try_from(req)?;
+        let checkout_req =
+            utils::Encode::<checkout::PaymentsRequest>::encode_to_string_of_json(&connector_req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::VerifyRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&Verify::get_url(self, req, connectors)?)
+                .headers(Verify::get_headers(self, req, connectors)?)
+                .header(headers::X_ROUTER, "test")
+                .body(Verify::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::VerifyRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::VerifyRouterData, errors::ConnectorError> {
+        let response: checkout::PaymentsResponse = res
+            .response
+            .parse_struct("PaymentIntentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        types::PaymentsAuthorizeType::get_error_response(self, res)
+    }
 }
 
 impl
@@ -187,6 +284,11 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -284,6 +386,11 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -401,6 +508,11 @@ impl
 
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -502,6 +614,11 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -614,6 +731,11 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -709,6 +831,11 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response
+                .error_codes
+                .iter()
+                .flatten()
+                .find_map(|code| checkout::get_unified_code(code)),
             code: response
                 .error_codes
                 .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
@@ -721,6 +848,65 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Checkout {}
+impl api::PayoutCreate for Checkout {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl api::PayoutFulfill for Checkout {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl api::PayoutCancel for Checkout {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl api::PayoutSync for Checkout {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl api::RefundCredit for Checkout {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Checkout
+{
+}
+
+impl api::ConnectorMandateRevoke for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Checkout
+{
+}
+
+impl api::Dispute for Checkout {}
+impl api::SubmitEvidence for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Checkout
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Checkout {
     fn get_webhook_object_reference_id(
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    connector::utils::{self, PaymentsAuthorizeRequestData},
+    consts,
     core::errors,
     pii, services,
     types::{self, api, storage::enums, transformers::ForeignFrom},
@@ -16,10 +18,60 @@ pub struct CardSource {
     pub expiry_year: Option<pii::Secret<String>>,
 }
 
+/// Network token of a card with the cryptogram of the payment, as given by Click to Pay
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub token: pii::Secret<String, pii::CardNumber>,
+    pub expiry_month: pii::Secret<String>,
+    pub expiry_year: pii::Secret<String>,
+    pub token_type: NetworkTokenType,
+    pub cryptogram: pii::Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eci: Option<String>,
+}
+
+/// Token services of the card networks whose network tokens checkout accepts
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkTokenType {
+    /// Visa Token Service
+    Vts,
+    /// Mastercard Digital Enablement Service
+    Mdes,
+}
+
+impl TryFrom<&types::ClickToPayTokenData> for NetworkTokenSource {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(token_data: &types::ClickToPayTokenData) -> Result<Self, Self::Error> {
+        let token_type = match token_data.card_network {
+            api::enums::CardNetwork::Visa => NetworkTokenType::Vts,
+            api::enums::CardNetwork::Mastercard => NetworkTokenType::Mdes,
+            card_network => Err(errors::ConnectorError::NotSupported {
+                payment_method: format!("Click to Pay with {card_network}"),
+                connector: "Checkout",
+                payment_experience: api::enums::PaymentExperience::InvokeSdkClient.to_string(),
+            })?,
+        };
+
+        Ok(Self {
+            source_type: "network_token".to_owned(),
+            token: token_data.network_token.clone(),
+            expiry_month: token_data.token_exp_month.clone(),
+            expiry_year: token_data.token_exp_year.clone(),
+            token_type,
+            cryptogram: token_data.cryptogram.clone(),
+            eci: token_data.eci.clone(),
+        })
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum Source {
     Card(CardSource),
+    NetworkToken(NetworkTokenSource),
 }
 
 pub struct CheckoutAuthType {
@@ -44,12 +96,113 @@ pub struct PaymentsRequest {
     #[serde(flatten)]
     pub return_url: ReturnUrl,
     pub capture: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing: Option<ProcessingSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_type: Option<CheckoutPaymentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_initiated: Option<bool>,
+    /// Scheme transaction id of the customer-initiated transaction a merchant-initiated one
+    /// refers to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_payment_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum CheckoutPaymentType {
+    Unscheduled,
+}
+
+fn get_payment_type(
+    setup_future_usage: Option<enums::FutureUsage>,
+    previous_payment_id: Option<&String>,
+) -> Option<CheckoutPaymentType> {
+    (setup_future_usage == Some(enums::FutureUsage::OffSession) || previous_payment_id.is_some())
+        .then_some(CheckoutPaymentType::Unscheduled)
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProcessingSettings {
+    pub preferred_scheme: PreferredScheme,
+}
+
+/// Schemes which checkout can be asked to process a co-badged card on
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreferredScheme {
+    Visa,
+    Mastercard,
+    CartesBancaires,
+}
+
+impl PreferredScheme {
+    fn from_card_network(network: &api::enums::CardNetwork) -> Option<Self> {
+        match network {
+            api::enums::CardNetwork::Visa => Some(Self::Visa),
+            api::enums::CardNetwork::Mastercard => Some(Self::Mastercard),
+            api::enums::CardNetwork::CartesBancaires => Some(Self::CartesBancaires),
+            api::enums::CardNetwork::AmericanExpress
+            | api::enums::CardNetwork::JCB
+            | api::enums::CardNetwork::DinersClub
+            | api::enums::CardNetwork::Discover
+            | api::enums::CardNetwork::UnionPay
+            | api::enums::CardNetwork::Interac
+            | api::enums::CardNetwork::RuPay
+            | api::enums::CardNetwork::Maestro => None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
 pub struct CheckoutThreeDS {
     enabled: bool,
     force_3ds: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exemption: Option<CheckoutScaExemption>,
+    /// Result of a 3DS authentication made outside of Checkout
+    #[serde(flatten)]
+    third_party: Option<CheckoutThirdPartyThreeDS>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckoutThirdPartyThreeDS {
+    cryptogram: pii::Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eci: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xid: Option<String>,
+    version: String,
+}
+
+impl From<&types::ExternalAuthenticationData> for CheckoutThirdPartyThreeDS {
+    fn from(authentication_data: &types::ExternalAuthenticationData) -> Self {
+        Self {
+            cryptogram: authentication_data.cavv.clone(),
+            eci: authentication_data.eci.clone(),
+            xid: authentication_data.ds_trans_id.clone(),
+            version: authentication_data.message_version.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutScaExemption {
+    LowValue,
+    TransactionRiskAssessment,
+    ScaDelegation,
+}
+
+impl From<api::enums::ScaExemptionType> for CheckoutScaExemption {
+    fn from(sca_exemption: api::enums::ScaExemptionType) -> Self {
+        match sca_exemption {
+            api::enums::ScaExemptionType::LowValue => Self::LowValue,
+            api::enums::ScaExemptionType::TransactionRiskAnalysis => {
+                Self::TransactionRiskAssessment
+            }
+            api::enums::ScaExemptionType::DelegatedAuthentication => Self::ScaDelegation,
+        }
+    }
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -65,60 +218,122 @@ impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
         }
     }
 }
+fn get_card_source(payment_method_data: &api::PaymentMethodData) -> Source {
+    let ccard = match payment_method_data {
+        api::PaymentMethodData::Card(ref ccard) => Some(ccard),
+        api::PaymentMethodData::Wallet(_)
+        | api::PaymentMethodData::PayLater(_)
+        | api::PaymentMethodData::BankRedirect(_)
+        | api::PaymentMethodData::BankTransfer(_)
+        | api::PaymentMethodData::Voucher(_)
+        | api::PaymentMethodData::Upi(_)
+        | api::PaymentMethodData::BankDebit(_)
+        | api::PaymentMethodData::Crypto(_) => None,
+    };
+
+    Source::Card(CardSource {
+        source_type: Some("card".to_owned()),
+        number: ccard.map(|x| x.card_number.clone()),
+        expiry_month: ccard.map(|x| x.card_exp_month.clone()),
+        expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+    })
+}
+
+fn get_three_ds(
+    auth_type: enums::AuthenticationType,
+    sca_exemption: Option<api::enums::ScaExemptionType>,
+    external_authentication_data: Option<&types::ExternalAuthenticationData>,
+) -> CheckoutThreeDS {
+    let exemption = sca_exemption.map(CheckoutScaExemption::from);
+    let third_party = external_authentication_data.map(CheckoutThirdPartyThreeDS::from);
+    match auth_type {
+        // The cardholder is not authenticated again by Checkout when already authenticated
+        enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
+            enabled: true,
+            force_3ds: third_party.is_none(),
+            exemption,
+            third_party,
+        },
+        enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
+            enabled: false,
+            force_3ds: false,
+            exemption,
+            third_party: None,
+        },
+    }
+}
+
+fn get_return_url(router_return_url: Option<&String>) -> ReturnUrl {
+    ReturnUrl {
+        success_url: router_return_url.map(|return_url| format!("{return_url}?status=success")),
+        failure_url: router_return_url.map(|return_url| format!("{return_url}?status=failure")),
+    }
+}
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let ccard = match item.request.payment_method_data {
-            api::PaymentMethodData::Card(ref ccard) => Some(ccard),
-            api::PaymentMethodData::Wallet(_)
-            | api::PaymentMethodData::PayLater(_)
-            | api::PaymentMethodData::BankRedirect(_) => None,
-        };
-
-        let three_ds = match item.auth_type {
-            enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
-                enabled: true,
-                force_3ds: true,
-            },
-            enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
-                enabled: false,
-                force_3ds: false,
-            },
-        };
-
-        let return_url = ReturnUrl {
-            success_url: item
-                .router_return_url
-                .as_ref()
-                .map(|return_url| format!("{return_url}?status=success")),
-            failure_url: item
-                .router_return_url
-                .as_ref()
-                .map(|return_url| format!("{return_url}?status=failure")),
-        };
-
         let capture = matches!(
             item.request.capture_method,
             Some(enums::CaptureMethod::Automatic)
         );
 
-        let source_var = Source::Card(CardSource {
-            source_type: Some("card".to_owned()),
-            number: ccard.map(|x| x.card_number.clone()),
-            expiry_month: ccard.map(|x| x.card_exp_month.clone()),
-            expiry_year: ccard.map(|x| x.card_exp_year.clone()),
-        });
+        let processing = item
+            .request
+            .card_network
+            .as_ref()
+            .and_then(PreferredScheme::from_card_network)
+            .map(|preferred_scheme| ProcessingSettings { preferred_scheme });
+        let previous_payment_id = item.request.get_network_transaction_id();
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
+        // Click to Pay checkouts are paid with the network token retrieved from the SRC system
+        let source = match item.request.click_to_pay_token_data {
+            Some(ref token_data) => Source::NetworkToken(token_data.try_into()?),
+            None => get_card_source(&item.request.payment_method_data),
+        };
         Ok(Self {
-            source: source_var,
+            source,
             amount: item.request.amount,
             currency: item.request.currency.to_string(),
             processing_channel_id,
-            three_ds,
-            return_url,
+            three_ds: get_three_ds(
+                item.auth_type,
+                item.request.sca_exemption,
+                item.request.external_authentication_data.as_ref(),
+            ),
+            return_url: get_return_url(item.router_return_url.as_ref()),
             capture,
+            processing,
+            payment_type: get_payment_type(
+                item.request.setup_future_usage,
+                previous_payment_id.as_ref(),
+            ),
+            merchant_initiated: previous_payment_id.as_ref().map(|_| true),
+            previous_payment_id,
+        })
+    }
+}
+
+/// Cards are verified through a payment of zero amount, which checkout answers with the
+/// `Card Verified` status
+impl TryFrom<&types::VerifyRouterData> for PaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::VerifyRouterData) -> Result<Self, Self::Error> {
+        let auth_type: CheckoutAuthType = (&item.connector_auth_type).try_into()?;
+        Ok(Self {
+            source: get_card_source(&item.request.payment_method_data),
+            amount: 0,
+            currency: item.request.currency.to_string(),
+            processing_channel_id: auth_type.processing_channel_id,
+            three_ds: get_three_ds(item.auth_type, None, None),
+            return_url: get_return_url(item.router_return_url.as_ref()),
+            capture: false,
+            processing: None,
+            payment_type: get_payment_type(item.request.setup_future_usage, None),
+            merchant_initiated: None,
+            previous_payment_id: None,
         })
     }
 }
@@ -196,6 +411,83 @@ pub struct PaymentsResponse {
     #[serde(rename = "_links")]
     links: Links,
     balances: Option<Balances>,
+    response_code: Option<String>,
+    response_summary: Option<String>,
+    source: Option<ResponseSource>,
+    scheme_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct ResponseSource {
+    id: Option<String>,
+    avs_check: Option<String>,
+    cvv_check: Option<String>,
+}
+
+impl ResponseSource {
+    fn get_avs_result(&self) -> Option<enums::AvsResult> {
+        self.avs_check
+            .as_deref()
+            .map(utils::get_avs_result_from_code)
+    }
+
+    /// Checkout.com answers matching security codes with Y rather than the M of the card networks
+    fn get_cvv_result(&self) -> Option<enums::CvvResult> {
+        self.cvv_check.as_deref().map(|cvv_check| match cvv_check {
+            "Y" => enums::CvvResult::Match,
+            cvv_check => utils::get_cvv_result_from_code(cvv_check),
+        })
+    }
+}
+
+/// Declined payments are answered with a success status code, the reason of the decline being in
+/// the response code of the payment.
+fn get_decline_error_response(
+    response: &PaymentsResponse,
+    status_code: u16,
+) -> Option<types::ErrorResponse> {
+    (response.status == CheckoutPaymentStatus::Declined).then(|| types::ErrorResponse {
+        code: response
+            .response_code
+            .clone()
+            .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+        message: response
+            .response_summary
+            .clone()
+            .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+        reason: None,
+        status_code,
+        unified_code: response.response_code.as_deref().and_then(get_unified_code),
+    })
+}
+
+/// Maps a response code of a payment, as listed in
+/// <https://www.checkout.com/docs/resources/codes/response-codes>, or an error code of a request to
+/// the unified code.
+pub fn get_unified_code(code: &str) -> Option<enums::UnifiedCode> {
+    match code {
+        "20051" => Some(enums::UnifiedCode::InsufficientFunds),
+        "20001" | "20005" => Some(enums::UnifiedCode::DoNotHonor),
+        "20033" | "20054" | "card_expired" => Some(enums::UnifiedCode::ExpiredCard),
+        "200N7" | "cvv_invalid" => Some(enums::UnifiedCode::IncorrectCvc),
+        "20014" | "card_number_invalid" => Some(enums::UnifiedCode::InvalidCardNumber),
+        "card_expiry_month_invalid" | "card_expiry_year_invalid" => {
+            Some(enums::UnifiedCode::InvalidExpiryDate)
+        }
+        "20041" => Some(enums::UnifiedCode::LostCard),
+        "20043" => Some(enums::UnifiedCode::StolenCard),
+        "20004" | "20007" => Some(enums::UnifiedCode::PickupCard),
+        "20062" => Some(enums::UnifiedCode::RestrictedCard),
+        "20012" | "20057" | "20058" => Some(enums::UnifiedCode::TransactionNotAllowed),
+        "20061" | "20065" => Some(enums::UnifiedCode::LimitExceeded),
+        "20059" => Some(enums::UnifiedCode::FraudSuspected),
+        "20154" => Some(enums::UnifiedCode::AuthenticationRequired),
+        "20013" | "amount_invalid" => Some(enums::UnifiedCode::InvalidAmount),
+        "20094" => Some(enums::UnifiedCode::DuplicateTransaction),
+        "20091" => Some(enums::UnifiedCode::IssuerUnavailable),
+        "20096" => Some(enums::UnifiedCode::ProcessingError),
+        _ => None,
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
@@ -210,21 +502,100 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let error_response = get_decline_error_response(&item.response, item.http_code);
         let redirection_data = item.response.links.redirect.map(|href| {
             services::RedirectForm::from((href.redirection_url, services::Method::Get))
         });
+        let avs_result = item
+            .response
+            .source
+            .as_ref()
+            .and_then(ResponseSource::get_avs_result);
+        let cvv_result = item
+            .response
+            .source
+            .as_ref()
+            .and_then(ResponseSource::get_cvv_result);
 
         Ok(Self {
             status: enums::AttemptStatus::foreign_from((
                 item.response.status,
                 item.data.request.capture_method,
             )),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
-                redirection_data,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
+            response: error_response.map_or(
+                Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                    redirection_data,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                    network_txn_id: item.response.scheme_id,
+                    avs_result,
+                    cvv_result,
+                }),
+                Err,
+            ),
+            ..item.data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Verify,
+            PaymentsResponse,
+            types::VerifyRequestData,
+            types::PaymentsResponseData,
+        >,
+    > for types::VerifyRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Verify,
+            PaymentsResponse,
+            types::VerifyRequestData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let error_response = get_decline_error_response(&item.response, item.http_code);
+        let redirection_data = item.response.links.redirect.map(|href| {
+            services::RedirectForm::from((href.redirection_url, services::Method::Get))
+        });
+        let status = match item.response.status {
+            CheckoutPaymentStatus::CardVerified
+            | CheckoutPaymentStatus::Authorized
+            | CheckoutPaymentStatus::Captured => enums::AttemptStatus::Charged,
+            CheckoutPaymentStatus::Declined => enums::AttemptStatus::Failure,
+            CheckoutPaymentStatus::Pending => enums::AttemptStatus::AuthenticationPending,
+        };
+        let avs_result = item
+            .response
+            .source
+            .as_ref()
+            .and_then(ResponseSource::get_avs_result);
+        let cvv_result = item
+            .response
+            .source
+            .as_ref()
+            .and_then(ResponseSource::get_cvv_result);
+        // The id of the source is used to charge the card later, without the customer
+        let mandate_reference = item.response.source.and_then(|source| source.id);
+
+        Ok(Self {
+            status,
+            response: error_response.map_or(
+                Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                    redirection_data,
+                    mandate_reference,
+                    connector_metadata: None,
+                    network_txn_id: item.response.scheme_id,
+                    avs_result,
+                    cvv_result,
+                }),
+                Err,
+            ),
             ..item.data
         })
     }
@@ -237,6 +608,7 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsSyncResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let error_response = get_decline_error_response(&item.response, item.http_code);
         let redirection_data = item.response.links.redirect.map(|href| {
             services::RedirectForm::from((href.redirection_url, services::Method::Get))
         });
@@ -246,12 +618,18 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
                 item.response.status,
                 item.response.balances,
             )),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
-                redirection_data,
-                mandate_reference: None,
-                connector_metadata: None,
-            }),
+            response: error_response.map_or(
+                Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                    redirection_data,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                    network_txn_id: None,
+                    avs_result: None,
+                    cvv_result: None,
+                }),
+                Err,
+            ),
             ..item.data
         })
     }
@@ -293,6 +671,9 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<PaymentVoidResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             status: response.into(),
             ..item.data
@@ -364,6 +745,9 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             status,
             amount_captured,
@@ -0,0 +1,468 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use time::{format_description, OffsetDateTime};
+use transformers as cryptopay;
+
+use super::utils as conn_utils;
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Cryptopay;
+
+impl api::Payment for Cryptopay {}
+impl api::PaymentSession for Cryptopay {}
+impl api::ConnectorAccessToken for Cryptopay {}
+impl api::PreVerify for Cryptopay {}
+impl api::PaymentAuthorize for Cryptopay {}
+impl api::PaymentSync for Cryptopay {}
+impl api::PaymentCapture for Cryptopay {}
+impl api::PaymentVoid for Cryptopay {}
+impl api::Refund for Cryptopay {}
+impl api::RefundExecute for Cryptopay {}
+impl api::RefundSync for Cryptopay {}
+
+impl Cryptopay {
+    pub fn get_current_date_time() -> CustomResult<String, errors::ConnectorError> {
+        let format = format_description::parse(
+            "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT",
+        )
+        .into_report()
+        .change_context(errors::ConnectorError::InvalidDateFormat)?;
+        OffsetDateTime::now_utc()
+            .format(&format)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidDateFormat)
+    }
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cryptopay
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let body = self.get_request_body(req)?;
+        let path = self
+            .get_url(req, connectors)?
+            .replace(self.base_url(connectors), "/");
+        let date = Self::get_current_date_time()?;
+        let auth = cryptopay::CryptopayAuthType::try_from(&req.connector_auth_type)?;
+        let authorization = cryptopay::generate_authorization_header(
+            &auth,
+            self.get_http_method(),
+            body.as_deref(),
+            self.get_content_type(),
+            &date,
+            &path,
+        )?;
+
+        let mut headers = vec![
+            (headers::DATE.to_string(), date),
+            (headers::AUTHORIZATION.to_string(), authorization),
+        ];
+        if body.is_some() {
+            headers.push((
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ));
+        }
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Cryptopay {
+    fn id(&self) -> &'static str {
+        "cryptopay"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.cryptopay.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: cryptopay::CryptopayErrorResponse = res
+            .response
+            .parse_struct("Cryptopay ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error.code,
+            message: response.error.message,
+            reason: None,
+            unified_code: None,
+        })
+    }
+}
+
+impl api::ConnectorSpecifications for Cryptopay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Crypto])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+
+    fn supports_mandates(&self) -> bool {
+        false
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Cryptopay
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Cryptopay
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Cryptopay
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Cryptopay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}api/invoices", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let cryptopay_req =
+            utils::Encode::<cryptopay::CryptopayPaymentsRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(cryptopay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::PaymentsAuthorizeType::get_http_method(self))
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(cryptopay_payments_response=?res);
+        let response: cryptopay::CryptopayPaymentsResponse = res
+            .response
+            .parse_struct("Cryptopay PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Cryptopay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_http_method(&self) -> services::Method {
+        services::Method::Get
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let invoice_id = req
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        Ok(format!(
+            "{}api/invoices/{invoice_id}",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::PaymentsSyncType::get_http_method(self))
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(cryptopay_payment_sync_response=?res);
+        let response: cryptopay::CryptopayPaymentsResponse = res
+            .response
+            .parse_struct("Cryptopay PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+// Invoices are paid in full by the customer, there is nothing to capture or void
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Cryptopay
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Cryptopay
+{
+}
+
+// Refunds of crypto payments are sent to an address of the customer, which is not collected
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Cryptopay
+{
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::Payouts for Cryptopay {}
+impl api::PayoutCreate for Cryptopay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::PayoutFulfill for Cryptopay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::PayoutCancel for Cryptopay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::PayoutSync for Cryptopay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::RefundCredit for Cryptopay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Cryptopay
+{
+}
+
+impl api::ConnectorMandateRevoke for Cryptopay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Cryptopay
+{
+}
+
+impl api::Dispute for Cryptopay {}
+impl api::SubmitEvidence for Cryptopay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cryptopay
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Cryptopay {
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            conn_utils::get_header_key_value(headers::X_CRYPTOPAY_SIGNATURE, request.headers)?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let webhook: cryptopay::CryptopayWebhookDetails = request
+            .body
+            .parse_struct("CryptopayWebhookDetails")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(webhook.data.id)
+    }
+
+    // Underpaid and late payments are left to be resolved on Cryptopay, the payment is synced
+    // once the invoice settles instead
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: cryptopay::CryptopayWebhookDetails = request
+            .body
+            .parse_struct("CryptopayWebhookDetails")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&webhook.data))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let webhook: cryptopay::CryptopayWebhookDetails = request
+            .body
+            .parse_struct("CryptopayWebhookDetails")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        serde_json::to_value(cryptopay::CryptopayPaymentsResponse { data: webhook.data })
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+}
+
+impl services::ConnectorRedirectResponse for Cryptopay {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
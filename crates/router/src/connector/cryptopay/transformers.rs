@@ -0,0 +1,323 @@
+use base64::Engine;
+use common_utils::crypto::{self, GenerateDigest, SignMessage};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils,
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+// The api key identifies the merchant on Cryptopay and the api secret signs every request. The
+// callback secret Cryptopay signs its webhooks with is the webhook secret of the connector account.
+pub struct CryptopayAuthType {
+    pub(super) api_key: String,
+    pub(super) api_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for CryptopayAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::BodyKey { api_key, key1 } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+                api_secret: key1.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+/// The signed message is the method, the MD5 digest of the body, the content type, the date and
+/// the path of the request, separated by newlines. The digest and the content type are left
+/// empty for requests without a body.
+pub fn generate_authorization_header(
+    auth: &CryptopayAuthType,
+    method: services::Method,
+    body: Option<&str>,
+    content_type: &str,
+    date: &str,
+    path: &str,
+) -> Result<String, Error> {
+    let (body_digest, content_type) = match body {
+        Some(body) => (
+            hex::encode(
+                crypto::Md5
+                    .generate_digest(body.as_bytes())
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+            ),
+            content_type,
+        ),
+        None => (String::new(), ""),
+    };
+    let message = format!("{method}\n{body_digest}\n{content_type}\n{date}\n{path}");
+    let signature = crypto::HmacSha1
+        .sign_message(auth.api_secret.as_bytes(), message.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(format!(
+        "HMAC {}:{}",
+        auth.api_key,
+        consts::BASE64_ENGINE.encode(signature)
+    ))
+}
+
+// Invoice Request
+#[derive(Debug, Serialize)]
+pub struct CryptopayPaymentsRequest {
+    price_amount: String,
+    price_currency: enums::Currency,
+    pay_currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    success_redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsuccess_redirect_url: Option<String>,
+    custom_id: String,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for CryptopayPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let pay_currency = match &item.request.payment_method_data {
+            api::PaymentMethodData::Crypto(crypto_data) => crypto_data
+                .pay_currency
+                .clone()
+                .ok_or_else(utils::missing_field_err("crypto.pay_currency"))?,
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment method".to_string(),
+            ))?,
+        };
+
+        Ok(Self {
+            price_amount: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            price_currency: item.request.currency,
+            pay_currency,
+            success_redirect_url: item.router_return_url.clone(),
+            unsuccess_redirect_url: item.router_return_url.clone(),
+            custom_id: item.payment_id.clone(),
+        })
+    }
+}
+
+// Invoice Response
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CryptopayPaymentStatus {
+    New,
+    Completed,
+    Unresolved,
+    Refunded,
+    Cancelled,
+}
+
+/// Why an invoice is unresolved
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CryptopayStatusContext {
+    Overpaid,
+    Underpaid,
+    PaidLate,
+    IllegalPayment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CryptopayPaymentResponseData {
+    pub id: String,
+    pub custom_id: Option<String>,
+    pub status: CryptopayPaymentStatus,
+    pub status_context: Option<CryptopayStatusContext>,
+    pub price_amount: String,
+    pub price_currency: String,
+    pub pay_amount: Option<String>,
+    pub pay_currency: String,
+    pub paid_amount: Option<String>,
+    pub network: Option<String>,
+    pub hosted_page_url: Option<Url>,
+}
+
+impl CryptopayPaymentResponseData {
+    // An overpaid invoice still settles the full price, the excess is left for the merchant to
+    // return. Underpaid and late payments wait for the merchant to resolve them on Cryptopay.
+    pub fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match (self.status, self.status_context) {
+            (CryptopayPaymentStatus::New, _) => enums::AttemptStatus::AuthenticationPending,
+            (CryptopayPaymentStatus::Completed, _)
+            | (CryptopayPaymentStatus::Refunded, _)
+            | (CryptopayPaymentStatus::Unresolved, Some(CryptopayStatusContext::Overpaid)) => {
+                enums::AttemptStatus::Charged
+            }
+            (CryptopayPaymentStatus::Unresolved, Some(CryptopayStatusContext::IllegalPayment))
+            | (CryptopayPaymentStatus::Cancelled, _) => enums::AttemptStatus::Failure,
+            (CryptopayPaymentStatus::Unresolved, _) => enums::AttemptStatus::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CryptopayPaymentsResponse {
+    pub data: CryptopayPaymentResponseData,
+}
+
+/// What the customer paid in, kept with the payment attempt as the price is settled in the
+/// currency of the payment
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CryptopayMeta {
+    pub pay_currency: String,
+    pub pay_amount: Option<String>,
+    pub paid_amount: Option<String>,
+    pub network: Option<String>,
+    pub status_context: Option<CryptopayStatusContext>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, CryptopayPaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            CryptopayPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let invoice = item.response.data;
+        let status = invoice.get_attempt_status();
+        // The customer is sent to the hosted page of the invoice until they pay it
+        let redirection_data = match status {
+            enums::AttemptStatus::AuthenticationPending => invoice
+                .hosted_page_url
+                .map(|url| services::RedirectForm::from((url, services::Method::Get))),
+            _ => None,
+        };
+        let connector_metadata = serde_json::to_value(CryptopayMeta {
+            pay_currency: invoice.pay_currency,
+            pay_amount: invoice.pay_amount,
+            paid_amount: invoice.paid_amount,
+            network: invoice.network,
+            status_context: invoice.status_context,
+        })
+        .ok();
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(invoice.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+#[derive(Debug, Deserialize)]
+pub struct CryptopayWebhookDetails {
+    #[serde(rename = "type")]
+    pub service_type: String,
+    pub event: String,
+    pub data: CryptopayPaymentResponseData,
+}
+
+impl From<&CryptopayPaymentResponseData> for api::IncomingWebhookEvent {
+    fn from(invoice: &CryptopayPaymentResponseData) -> Self {
+        match invoice.get_attempt_status() {
+            enums::AttemptStatus::Charged => Self::PaymentIntentSuccess,
+            enums::AttemptStatus::Failure => Self::PaymentIntentFailure,
+            _ => Self::EventNotSupported,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CryptopayErrorDetails {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CryptopayErrorResponse {
+    pub error: CryptopayErrorDetails,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_auth() -> CryptopayAuthType {
+        CryptopayAuthType {
+            api_key: "api_key".to_string(),
+            api_secret: "api_secret".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_authorization_header_with_body() {
+        let header = generate_authorization_header(
+            &get_auth(),
+            services::Method::Post,
+            Some(r#"{"price_amount":"10.5"}"#),
+            "application/json",
+            "Wed, 16 Oct 2024 10:00:00 GMT",
+            "/api/invoices",
+        )
+        .unwrap();
+        assert_eq!(header, "HMAC api_key:2eM8GXS2x5uHAD4h1+dkkG7OkaI=");
+    }
+
+    #[test]
+    fn test_authorization_header_without_body() {
+        let header = generate_authorization_header(
+            &get_auth(),
+            services::Method::Get,
+            None,
+            "application/json",
+            "Wed, 16 Oct 2024 10:00:00 GMT",
+            "/api/invoices/inv_1",
+        )
+        .unwrap();
+        assert_eq!(header, "HMAC api_key:aYz7oaqD2u2zVd/x59UBubbLFuM=");
+    }
+
+    #[test]
+    fn test_unresolved_invoice_status() {
+        let mut invoice: CryptopayPaymentResponseData = serde_json::from_value(serde_json::json!({
+            "id": "inv_1",
+            "custom_id": "pay_1",
+            "status": "unresolved",
+            "status_context": "overpaid",
+            "price_amount": "10.5",
+            "price_currency": "EUR",
+            "pay_amount": "0.00042",
+            "pay_currency": "BTC",
+            "paid_amount": "0.0005",
+            "network": "bitcoin",
+            "hosted_page_url": null
+        }))
+        .unwrap();
+        assert_eq!(invoice.get_attempt_status(), enums::AttemptStatus::Charged);
+
+        invoice.status_context = Some(CryptopayStatusContext::Underpaid);
+        assert_eq!(invoice.get_attempt_status(), enums::AttemptStatus::Pending);
+
+        invoice.status_context = Some(CryptopayStatusContext::IllegalPayment);
+        assert_eq!(invoice.get_attempt_status(), enums::AttemptStatus::Failure);
+    }
+}
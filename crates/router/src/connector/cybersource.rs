@@ -85,6 +85,10 @@ impl ConnectorCommon for Cybersource {
         connectors.cybersource.base_url.as_ref()
     }
 
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("cybersource")
+    }
+
     fn build_error_response(
         &self,
         res: types::Response,
@@ -109,10 +113,24 @@ impl ConnectorCommon for Cybersource {
                 })
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
             reason: response.reason,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Cybersource {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::Wallet,
+        ])
+    }
+
+    fn supports_unreferenced_refunds(&self) -> bool {
+        true
+    }
+}
+
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cybersource
 where
     Self: ConnectorIntegration<Flow, Request, Response>,
@@ -536,6 +554,7 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
 impl api::Refund for Cybersource {}
 impl api::RefundExecute for Cybersource {}
 impl api::RefundSync for Cybersource {}
+impl api::RefundCredit for Cybersource {}
 
 #[allow(dead_code)]
 impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
@@ -686,6 +705,133 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Cybersource
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundCreditRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundCreditRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}pts/v2/credits", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundCreditRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = cybersource::CybersourceCreditRequest::try_from(req)?;
+        let req = utils::Encode::<cybersource::CybersourceCreditRequest>::encode_to_string_of_json(
+            &req_obj,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundCreditRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundCreditType::get_url(self, req, connectors)?)
+                .headers(types::RefundCreditType::get_headers(self, req, connectors)?)
+                .body(self.get_request_body(req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundCreditRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::RefundCreditRouterData, errors::ConnectorError> {
+        let response: cybersource::CybersourcePaymentsResponse = res
+            .response
+            .parse_struct("Cybersource CreditResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Payouts for Cybersource {}
+impl api::PayoutCreate for Cybersource {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::PayoutFulfill for Cybersource {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::PayoutCancel for Cybersource {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::PayoutSync for Cybersource {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::ConnectorMandateRevoke for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Cybersource
+{
+}
+
+impl api::Dispute for Cybersource {}
+impl api::SubmitEvidence for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cybersource
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Cybersource {
     fn get_webhook_object_reference_id(
@@ -4,7 +4,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{self, AddressDetailsData, PhoneDetailsData, RouterData},
+    connector::utils::{self, AddressDetailsData, ApplePayDecrypt, PhoneDetailsData, RouterData},
     consts,
     core::errors,
     pii::PeekInterface,
@@ -24,6 +24,16 @@ pub struct CybersourcePaymentsRequest {
 pub struct ProcessingInformation {
     capture: bool,
     capture_options: Option<CaptureOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_solution: Option<PaymentSolution>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub enum PaymentSolution {
+    #[serde(rename = "001")]
+    ApplePay,
+    #[serde(rename = "027")]
+    ClickToPay,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -34,8 +44,12 @@ pub struct CaptureOptions {
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentInformation {
-    card: Card,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<Card>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tokenized_card: Option<TokenizedCard>,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -47,6 +61,27 @@ pub struct Card {
     security_code: Secret<String>,
 }
 
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenizedCard {
+    number: Secret<String, pii::CardNumber>,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Secret<String>,
+    transaction_type: TransactionType,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+pub enum TransactionType {
+    /// The token was provisioned in-app, as done by Apple Pay
+    #[default]
+    #[serde(rename = "1")]
+    InApp,
+    /// The token is a credential stored by a wallet, as done by the SRC systems of Click to Pay
+    #[serde(rename = "3")]
+    StoredCredentials,
+}
+
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderInformationWithBill {
@@ -107,53 +142,98 @@ fn build_bill_to(
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        match item.request.payment_method_data.clone() {
-            api::PaymentMethodData::Card(ccard) => {
-                let phone = item.get_billing_phone()?;
-                let phone_number = phone.get_number()?;
-                let country_code = phone.get_country_code()?;
-                let number_with_code =
-                    Secret::new(format!("{}{}", country_code, phone_number.peek()));
-                let email = item
-                    .request
-                    .email
-                    .clone()
-                    .ok_or_else(utils::missing_field_err("email"))?;
-                let bill_to = build_bill_to(item.get_billing()?, email, number_with_code)?;
-
-                let order_information = OrderInformationWithBill {
-                    amount_details: Amount {
-                        total_amount: item.request.amount.to_string(),
-                        currency: item.request.currency.to_string().to_uppercase(),
-                    },
-                    bill_to,
-                };
-
-                let payment_information = PaymentInformation {
-                    card: Card {
+        let (payment_information, payment_solution) = match item.request.payment_method_data.clone()
+        {
+            api::PaymentMethodData::Card(ccard) => (
+                PaymentInformation {
+                    card: Some(Card {
                         number: ccard.card_number,
                         expiration_month: ccard.card_exp_month,
                         expiration_year: ccard.card_exp_year,
                         security_code: ccard.card_cvc,
+                    }),
+                    tokenized_card: None,
+                },
+                None,
+            ),
+            api::PaymentMethodData::Wallet(payments::WalletData::ApplePay(_)) => {
+                let apple_pay_data = item
+                    .request
+                    .apple_pay_decrypted_data
+                    .clone()
+                    .ok_or_else(utils::missing_field_err("apple_pay_decrypted_data"))?;
+                (
+                    PaymentInformation {
+                        card: None,
+                        tokenized_card: Some(TokenizedCard {
+                            expiration_month: apple_pay_data.get_expiry_month(),
+                            expiration_year: apple_pay_data.get_four_digit_expiry_year(),
+                            number: apple_pay_data.application_primary_account_number,
+                            cryptogram: apple_pay_data.payment_data.online_payment_cryptogram,
+                            transaction_type: TransactionType::InApp,
+                        }),
                     },
-                };
-
-                let processing_information = ProcessingInformation {
-                    capture: matches!(
-                        item.request.capture_method,
-                        Some(enums::CaptureMethod::Automatic) | None
-                    ),
-                    capture_options: None,
-                };
-
-                Ok(Self {
-                    processing_information,
-                    payment_information,
-                    order_information,
-                })
+                    Some(PaymentSolution::ApplePay),
+                )
             }
-            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
-        }
+            api::PaymentMethodData::Wallet(payments::WalletData::ClickToPay(_)) => {
+                let token_data = item
+                    .request
+                    .click_to_pay_token_data
+                    .clone()
+                    .ok_or_else(utils::missing_field_err("click_to_pay_token_data"))?;
+                (
+                    PaymentInformation {
+                        card: None,
+                        tokenized_card: Some(TokenizedCard {
+                            number: token_data.network_token,
+                            expiration_month: token_data.token_exp_month,
+                            expiration_year: token_data.token_exp_year,
+                            cryptogram: token_data.cryptogram,
+                            transaction_type: TransactionType::StoredCredentials,
+                        }),
+                    },
+                    Some(PaymentSolution::ClickToPay),
+                )
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            ))?,
+        };
+
+        let phone = item.get_billing_phone()?;
+        let phone_number = phone.get_number()?;
+        let country_code = phone.get_country_code()?;
+        let number_with_code = Secret::new(format!("{}{}", country_code, phone_number.peek()));
+        let email = item
+            .request
+            .email
+            .clone()
+            .ok_or_else(utils::missing_field_err("email"))?;
+        let bill_to = build_bill_to(item.get_billing()?, email, number_with_code)?;
+
+        let order_information = OrderInformationWithBill {
+            amount_details: Amount {
+                total_amount: item.request.amount.to_string(),
+                currency: item.request.currency.to_string().to_uppercase(),
+            },
+            bill_to,
+        };
+
+        let processing_information = ProcessingInformation {
+            capture: matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::Automatic) | None
+            ),
+            capture_options: None,
+            payment_solution,
+        };
+
+        Ok(Self {
+            processing_information,
+            payment_information,
+            order_information,
+        })
     }
 }
 
@@ -275,6 +355,25 @@ pub struct CybersourcePaymentsResponse {
     id: String,
     status: CybersourcePaymentStatus,
     error_information: Option<CybersourceErrorInformation>,
+    processor_information: Option<ProcessorInformation>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessorInformation {
+    avs: Option<Avs>,
+    card_verification: Option<CardVerification>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+pub struct Avs {
+    code: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CardVerification {
+    result_code: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
@@ -311,12 +410,26 @@ impl<F, T>
                     message: error.message,
                     reason: Some(error.reason),
                     status_code: item.http_code,
+                    unified_code: None,
                 }),
                 _ => Ok(types::PaymentsResponseData::TransactionResponse {
                     resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                     redirection_data: None,
                     mandate_reference: None,
                     connector_metadata: None,
+                    network_txn_id: None,
+                    avs_result: item
+                        .response
+                        .processor_information
+                        .as_ref()
+                        .and_then(|info| info.avs.as_ref()?.code.as_deref())
+                        .map(utils::get_avs_result_from_code),
+                    cvv_result: item
+                        .response
+                        .processor_information
+                        .as_ref()
+                        .and_then(|info| info.card_verification.as_ref()?.result_code.as_deref())
+                        .map(utils::get_cvv_result_from_code),
                 }),
             },
             ..item.data
@@ -380,6 +493,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -440,6 +556,79 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, CybersourcePaymentsR
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceCreditRequest {
+    client_reference_information: ClientReferenceInformation,
+    payment_information: PaymentInformation,
+    order_information: OrderInformation,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientReferenceInformation {
+    code: String,
+}
+
+impl TryFrom<&types::RefundCreditRouterData> for CybersourceCreditRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundCreditRouterData) -> Result<Self, Self::Error> {
+        let card = match item.request.payment_method_data.clone() {
+            Some(api::PaymentMethodData::Card(ccard)) => Card {
+                number: ccard.card_number,
+                expiration_month: ccard.card_exp_month,
+                expiration_year: ccard.card_exp_year,
+                security_code: ccard.card_cvc,
+            },
+            Some(_) => Err(errors::ConnectorError::NotImplemented(
+                "Payment methods".to_string(),
+            ))?,
+            None => Err(utils::missing_field_err("payment_method_data")())?,
+        };
+
+        Ok(Self {
+            client_reference_information: ClientReferenceInformation {
+                code: item.request.credit_id.clone(),
+            },
+            payment_information: PaymentInformation {
+                card: Some(card),
+                tokenized_card: None,
+            },
+            order_information: OrderInformation {
+                amount_details: Amount {
+                    total_amount: item.request.amount.to_string(),
+                    currency: item.request.currency.to_string(),
+                },
+            },
+        })
+    }
+}
+
+impl TryFrom<types::CreditsResponseRouterData<api::Credit, CybersourcePaymentsResponse>>
+    for types::RefundCreditRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::CreditsResponseRouterData<api::Credit, CybersourcePaymentsResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: match item.response.error_information {
+                Some(error) => Err(types::ErrorResponse {
+                    code: consts::NO_ERROR_CODE.to_string(),
+                    message: error.message,
+                    reason: Some(error.reason),
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+                None => Ok(types::RefundsResponseData {
+                    connector_refund_id: item.response.id,
+                    refund_status: enums::RefundStatus::from(item.response.status),
+                }),
+            },
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<types::RefundsResponseRouterData<api::RSync, CybersourceTransactionResponse>>
     for types::RefundsRouterData<api::RSync>
 {
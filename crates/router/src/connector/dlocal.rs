@@ -10,6 +10,7 @@ use error_stack::{IntoReport, ResultExt};
 use hex::encode;
 use transformers as dlocal;
 
+use super::utils as conn_utils;
 use crate::{
     configs::settings,
     core::{
@@ -23,7 +24,7 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse, Response,
     },
-    utils::{self, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -97,6 +98,10 @@ impl ConnectorCommon for Dlocal {
         connectors.dlocal.base_url.as_ref()
     }
 
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("dlocal")
+    }
+
     fn build_error_response(
         &self,
         res: Response,
@@ -111,14 +116,66 @@ impl ConnectorCommon for Dlocal {
             code: response.code.to_string(),
             message: response.message,
             reason: response.param,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Dlocal {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::BankTransfer,
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::Voucher,
+            api::enums::PaymentMethod::Wallet,
+        ])
+    }
+
+    fn supported_currencies(&self) -> Option<&'static [api::enums::Currency]> {
+        Some(&[
+            api::enums::Currency::ARS,
+            api::enums::Currency::BOB,
+            api::enums::Currency::BRL,
+            api::enums::Currency::CNY,
+            api::enums::Currency::COP,
+            api::enums::Currency::CRC,
+            api::enums::Currency::DOP,
+            api::enums::Currency::EGP,
+            api::enums::Currency::GHS,
+            api::enums::Currency::GTQ,
+            api::enums::Currency::IDR,
+            api::enums::Currency::INR,
+            api::enums::Currency::JPY,
+            api::enums::Currency::KES,
+            api::enums::Currency::KRW,
+            api::enums::Currency::MAD,
+            api::enums::Currency::MXN,
+            api::enums::Currency::MYR,
+            api::enums::Currency::NGN,
+            api::enums::Currency::PEN,
+            api::enums::Currency::PHP,
+            api::enums::Currency::SVC,
+            api::enums::Currency::THB,
+            api::enums::Currency::USD,
+            api::enums::Currency::UYU,
+            api::enums::Currency::ZAR,
+        ])
+    }
+
+    fn supported_countries(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            "AR", "BO", "BR", "CL", "CN", "CO", "CR", "DO", "EC", "EG", "GH", "GT", "ID", "IN",
+            "JP", "KE", "KR", "MA", "MX", "MY", "NG", "PA", "PE", "PH", "PY", "SV", "TH", "TR",
+            "UY", "VN", "ZA",
+        ])
+    }
+}
+
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
     for Dlocal
 {
-    //TODO: implement sessions flow
+    // Dlocal has no session API, Google Pay session tokens are created from the connector
+    // metadata using the `dlocal` gateway
 }
 
 impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
@@ -563,35 +620,231 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Dlocal {}
+impl api::PayoutCreate for Dlocal {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl api::PayoutFulfill for Dlocal {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl api::PayoutCancel for Dlocal {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl api::PayoutSync for Dlocal {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Dlocal {}
+
+impl api::RefundCredit for Dlocal {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Dlocal {}
+
+impl api::ConnectorMandateRevoke for Dlocal {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Dlocal
+{
+}
+
+impl api::Dispute for Dlocal {}
+impl api::SubmitEvidence for Dlocal {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Dlocal
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Dlocal {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let authorization =
+            conn_utils::get_header_key_value(headers::AUTHORIZATION, request.headers)?;
+        let signature = authorization
+            .split_once("Signature:")
+            .map(|(_, signature)| signature.trim())
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let x_login = conn_utils::get_header_key_value(headers::X_LOGIN, request.headers)?;
+        let x_date = conn_utils::get_header_key_value(headers::X_DATE, request.headers)?;
+
+        Ok(format!(
+            "{}{}{}",
+            x_login,
+            x_date,
+            String::from_utf8_lossy(request.body)
+        )
+        .into_bytes())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: dlocal::DlocalWebhookBody = request
+            .body
+            .parse_struct("DlocalWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        // Disputes are looked up through the payment they were opened on
+        match (webhook.kind(), webhook.payment_id) {
+            (dlocal::DlocalWebhookKind::Chargeback, Some(payment_id)) => Ok(payment_id),
+            _ => Ok(webhook.id),
+        }
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: dlocal::DlocalWebhookBody = request
+            .body
+            .parse_struct("DlocalWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        match webhook.kind() {
+            dlocal::DlocalWebhookKind::Payment => {
+                let payment: dlocal::DlocalPaymentWebhookBody = request
+                    .body
+                    .parse_struct("DlocalPaymentWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                payment.status.try_into()
+            }
+            dlocal::DlocalWebhookKind::Refund => {
+                let refund: dlocal::RefundResponse = request
+                    .body
+                    .parse_struct("RefundResponse")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                refund.status.try_into()
+            }
+            dlocal::DlocalWebhookKind::Chargeback => {
+                let chargeback: dlocal::DlocalChargebackWebhookBody = request
+                    .body
+                    .parse_struct("DlocalChargebackWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                Ok(chargeback.status.into())
+            }
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: dlocal::DlocalWebhookBody = request
+            .body
+            .parse_struct("DlocalWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        let resource_object = match webhook.kind() {
+            dlocal::DlocalWebhookKind::Payment => {
+                let payment: dlocal::DlocalPaymentsResponse = request
+                    .body
+                    .parse_struct("DlocalPaymentsResponse")
+                    .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+                utils::Encode::<dlocal::DlocalPaymentsResponse>::encode_to_value(&payment)
+            }
+            dlocal::DlocalWebhookKind::Refund => {
+                let refund: dlocal::RefundResponse = request
+                    .body
+                    .parse_struct("RefundResponse")
+                    .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+                utils::Encode::<dlocal::RefundResponse>::encode_to_value(&refund)
+            }
+            dlocal::DlocalWebhookKind::Chargeback => {
+                let chargeback: dlocal::DlocalChargebackWebhookBody = request
+                    .body
+                    .parse_struct("DlocalChargebackWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+                utils::Encode::<dlocal::DlocalChargebackWebhookBody>::encode_to_value(&chargeback)
+            }
+        };
+
+        resource_object.change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let chargeback: dlocal::DlocalChargebackWebhookBody = request
+            .body
+            .parse_struct("DlocalChargebackWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(chargeback.into())
     }
 }
 
 impl services::ConnectorRedirectResponse for Dlocal {
     fn get_flow_type(
         &self,
-        _query_params: &str,
+        query_params: &str,
     ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
-        Ok(payments::CallConnectorAction::Trigger)
+        let query = serde_urlencoded::from_str::<dlocal::DlocalRedirectResponse>(query_params)
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        logger::debug!(dlocal_redirect_response=?query);
+
+        // Failed and still pending challenges are synced so the attempt picks up
+        // the error details (or final status) from the connector
+        Ok(query.status.map_or(
+            payments::CallConnectorAction::Trigger,
+            |status| match status {
+                dlocal::DlocalPaymentStatus::Pending
+                | dlocal::DlocalPaymentStatus::Rejected
+                | dlocal::DlocalPaymentStatus::Cancelled => payments::CallConnectorAction::Trigger,
+                _ => payments::CallConnectorAction::StatusUpdate(
+                    types::storage::enums::AttemptStatus::from(status),
+                ),
+            },
+        ))
     }
 }
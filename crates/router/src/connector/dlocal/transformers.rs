@@ -1,12 +1,17 @@
 use api_models::payments::AddressDetails;
-use common_utils::pii::{self, Email};
-use error_stack::ResultExt;
+use common_utils::{
+    ext_traits::Encode,
+    pii::{self, Email},
+};
+use error_stack::{IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
-    connector::utils::{AddressDetailsData, RouterData},
+    connector::utils::{
+        self, AddressDetailsData, ApplePayDecrypt, PaymentsAuthorizeRequestData, RouterData,
+    },
     core::errors,
     services,
     types::{self, api, storage::enums},
@@ -16,19 +21,26 @@ use crate::{
 pub struct Payer {
     pub name: Option<Secret<String>>,
     pub email: Option<Secret<String, Email>>,
-    pub document: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Secret<String>>,
 }
 
 #[derive(Debug, Default, Eq, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Card {
     pub holder_name: Secret<String>,
     pub number: Secret<String, pii::CardNumber>,
-    pub cvv: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvv: Option<Secret<String>>,
     pub expiration_month: Secret<String>,
     pub expiration_year: Secret<String>,
     pub capture: String,
     pub installments_id: Option<String>,
     pub installments: Option<String>,
+    /// Network token cryptogram of a decrypted wallet token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cryptogram: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eci: Option<String>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Serialize)]
@@ -41,6 +53,12 @@ pub struct ThreeDSecureReqData {
 pub enum PaymentMethodId {
     #[default]
     Card,
+    #[serde(rename = "PQ")]
+    Pix,
+    #[serde(rename = "BL")]
+    Boleto,
+    #[serde(rename = "OX")]
+    Oxxo,
 }
 
 #[derive(Debug, Serialize, Default, Deserialize, Clone, Eq, PartialEq)]
@@ -63,6 +81,8 @@ pub struct DlocalPaymentsRequest {
     pub order_id: String,
     pub three_dsecure: Option<ThreeDSecureReqData>,
     pub callback_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for DlocalPaymentsRequest {
@@ -72,12 +92,25 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for DlocalPaymentsRequest {
         let address = item.get_billing_address()?;
         let country = address.get_country()?;
         let name = get_payer_name(address);
+        let document = item
+            .request
+            .payer_document
+            .as_ref()
+            .map(|document| document.document_number.clone());
+        let description = item
+            .request
+            .connector_metadata
+            .as_ref()
+            .and_then(|connector_metadata| connector_metadata.dlocal.as_ref())
+            .and_then(|dlocal_metadata| dlocal_metadata.description.clone())
+            .or_else(|| item.request.get_statement_descriptor());
         match item.request.payment_method_data {
             api::PaymentMethodData::Card(ref ccard) => {
                 let should_capture = matches!(
                     item.request.capture_method,
                     Some(enums::CaptureMethod::Automatic)
                 );
+                let installments = item.request.installments.as_ref();
                 let payment_request = Self {
                     amount: item.request.amount,
                     currency: item.request.currency,
@@ -87,23 +120,21 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for DlocalPaymentsRequest {
                     payer: Payer {
                         name,
                         email,
-                        // [#589]: Allow securely collecting PII from customer in payments request
-                        document: get_doc_from_currency(country.to_string()),
+                        document,
                     },
                     card: Some(Card {
                         holder_name: ccard.card_holder_name.clone(),
                         number: ccard.card_number.clone(),
-                        cvv: ccard.card_cvc.clone(),
+                        cvv: Some(ccard.card_cvc.clone()),
                         expiration_month: ccard.card_exp_month.clone(),
                         expiration_year: ccard.card_exp_year.clone(),
                         capture: should_capture.to_string(),
-                        installments_id: item
-                            .request
-                            .mandate_id
-                            .as_ref()
-                            .map(|ids| ids.mandate_id.clone()),
-                        // [#595[FEATURE] Pass Mandate history information in payment flows/request]
-                        installments: item.request.mandate_id.clone().map(|_| "1".to_string()),
+                        installments_id: installments
+                            .and_then(|installments| installments.plan_id.clone()),
+                        installments: installments
+                            .map(|installments| installments.number_of_installments.to_string()),
+                        cryptogram: None,
+                        eci: None,
                     }),
                     order_id: item.payment_id.clone(),
                     three_dsecure: match item.auth_type {
@@ -112,10 +143,112 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for DlocalPaymentsRequest {
                         }
                         storage_models::enums::AuthenticationType::NoThreeDs => None,
                     },
-                    callback_url: item.return_url.clone(),
+                    callback_url: item.router_return_url.clone(),
+                    description,
                 };
                 Ok(payment_request)
             }
+            api::PaymentMethodData::Wallet(api_models::payments::WalletData::ApplePay(_)) => {
+                let apple_pay_data = item
+                    .request
+                    .apple_pay_decrypted_data
+                    .as_ref()
+                    .ok_or_else(utils::missing_field_err("apple_pay_decrypted_data"))?;
+                let should_capture = matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic)
+                );
+                Ok(Self {
+                    amount: item.request.amount,
+                    currency: item.request.currency,
+                    payment_method_id: PaymentMethodId::Card,
+                    payment_method_flow: PaymentMethodFlow::Direct,
+                    country: country.to_string(),
+                    payer: Payer {
+                        name: name.clone(),
+                        email,
+                        document,
+                    },
+                    card: Some(Card {
+                        holder_name: name.unwrap_or_default(),
+                        number: apple_pay_data.application_primary_account_number.clone(),
+                        cvv: None,
+                        expiration_month: apple_pay_data.get_expiry_month(),
+                        expiration_year: apple_pay_data.get_four_digit_expiry_year(),
+                        capture: should_capture.to_string(),
+                        installments_id: None,
+                        installments: None,
+                        cryptogram: Some(
+                            apple_pay_data
+                                .payment_data
+                                .online_payment_cryptogram
+                                .clone(),
+                        ),
+                        eci: apple_pay_data.payment_data.eci_indicator.clone(),
+                    }),
+                    order_id: item.payment_id.clone(),
+                    three_dsecure: None,
+                    callback_url: item.router_return_url.clone(),
+                    description,
+                })
+            }
+            api::PaymentMethodData::BankTransfer(ref bank_transfer_data) => {
+                let payment_method_id = match bank_transfer_data {
+                    api_models::payments::BankTransferData::Pix {} => PaymentMethodId::Pix,
+                };
+                Ok(Self {
+                    amount: item.request.amount,
+                    currency: item.request.currency,
+                    payment_method_id,
+                    payment_method_flow: PaymentMethodFlow::ReDirect,
+                    country: country.to_string(),
+                    payer: Payer {
+                        name,
+                        email,
+                        document,
+                    },
+                    card: None,
+                    order_id: item.payment_id.clone(),
+                    three_dsecure: None,
+                    callback_url: item.router_return_url.clone(),
+                    description,
+                })
+            }
+            api::PaymentMethodData::Voucher(ref voucher_data) => {
+                let payment_method_id = match voucher_data {
+                    api_models::payments::VoucherData::Boleto {} => PaymentMethodId::Boleto,
+                    api_models::payments::VoucherData::Oxxo {} => PaymentMethodId::Oxxo,
+                    api_models::payments::VoucherData::Paysafecard {} => {
+                        return Err(errors::ConnectorError::NotImplemented(
+                            "Paysafecard for dlocal".to_string(),
+                        )
+                        .into())
+                    }
+                };
+                // Boleto vouchers are issued against the payer's CPF/CNPJ
+                if payment_method_id == PaymentMethodId::Boleto && document.is_none() {
+                    Err(errors::ConnectorError::MissingRequiredField {
+                        field_name: "payer_document",
+                    })?
+                }
+                Ok(Self {
+                    amount: item.request.amount,
+                    currency: item.request.currency,
+                    payment_method_id,
+                    payment_method_flow: PaymentMethodFlow::Direct,
+                    country: country.to_string(),
+                    payer: Payer {
+                        name,
+                        email,
+                        document,
+                    },
+                    card: None,
+                    order_id: item.payment_id.clone(),
+                    three_dsecure: None,
+                    callback_url: item.router_return_url.clone(),
+                    description,
+                })
+            }
             _ => Err(errors::ConnectorError::NotImplemented("Payment Method".to_string()).into()),
         }
     }
@@ -183,11 +316,15 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for DlocalPaymentsCaptureRequest
             Some(val) => val,
             None => item.request.amount,
         };
+        let order_id = match item.request.multiple_capture_data {
+            Some(ref multiple_capture_data) => multiple_capture_data.capture_reference.clone(),
+            None => item.payment_id.clone(),
+        };
         Ok(Self {
             authorization_id: item.request.connector_transaction_id.clone(),
             amount: amount_to_capture,
             currency: item.request.currency.to_string(),
-            order_id: item.payment_id.clone(),
+            order_id,
         })
     }
 }
@@ -247,11 +384,24 @@ pub struct ThreeDSecureResData {
     pub redirect_url: Option<Url>,
 }
 
+/// Payment instructions returned for cash voucher payments, to be shown to the customer
+#[derive(Debug, Eq, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DlocalTicket {
+    pub number: Option<String>,
+    pub expiration_date: Option<String>,
+    pub barcode: Option<String>,
+    pub company_name: Option<String>,
+    pub provider_name: Option<String>,
+    pub image_url: Option<Url>,
+}
+
 #[derive(Debug, Default, Eq, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DlocalPaymentsResponse {
     status: DlocalPaymentStatus,
     id: String,
     three_dsecure: Option<ThreeDSecureResData>,
+    redirect_url: Option<Url>,
+    ticket: Option<DlocalTicket>,
 }
 
 impl<F, T>
@@ -266,15 +416,25 @@ impl<F, T>
             .response
             .three_dsecure
             .and_then(|three_secure_data| three_secure_data.redirect_url)
+            .or(item.response.redirect_url)
             .map(|redirect_url| {
                 services::RedirectForm::from((redirect_url, services::Method::Get))
             });
+        let connector_metadata = item
+            .response
+            .ticket
+            .map(|ticket| Encode::<'_, DlocalTicket>::encode_to_value(&ticket))
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
 
         let response = types::PaymentsResponseData::TransactionResponse {
             resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
             redirection_data,
             mandate_reference: None,
-            connector_metadata: None,
+            connector_metadata,
+            network_txn_id: None,
+            avs_result: None,
+            cvv_result: None,
         };
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -311,6 +471,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -344,6 +507,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -376,6 +542,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -389,6 +558,7 @@ pub struct RefundRequest {
     pub payment_id: String,
     pub currency: enums::Currency,
     pub id: String,
+    pub description: Option<String>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
@@ -400,6 +570,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
             payment_id: item.request.connector_transaction_id.clone(),
             currency: item.request.currency,
             id: item.request.refund_id.clone(),
+            description: item.request.reason.clone(),
         })
     }
 }
@@ -486,6 +657,119 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DlocalRedirectResponse {
+    pub payment_id: Option<String>,
+    pub status: Option<DlocalPaymentStatus>,
+}
+
+/// The fields shared by the notifications of payments, refunds and chargebacks
+#[derive(Debug, Deserialize)]
+pub struct DlocalWebhookBody {
+    pub id: String,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DlocalWebhookKind {
+    Payment,
+    Refund,
+    Chargeback,
+}
+
+impl DlocalWebhookBody {
+    /// Notifications of refunds and chargebacks refer to their payment, and chargebacks are told
+    /// apart from refunds by the prefix of their id
+    pub fn kind(&self) -> DlocalWebhookKind {
+        match self.payment_id {
+            None => DlocalWebhookKind::Payment,
+            Some(_) if self.id.starts_with("CHAR") => DlocalWebhookKind::Chargeback,
+            Some(_) => DlocalWebhookKind::Refund,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DlocalPaymentWebhookBody {
+    pub status: DlocalPaymentStatus,
+}
+
+impl TryFrom<DlocalPaymentStatus> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(value: DlocalPaymentStatus) -> Result<Self, Self::Error> {
+        match value {
+            DlocalPaymentStatus::Authorized
+            | DlocalPaymentStatus::Verified
+            | DlocalPaymentStatus::Paid => Ok(Self::PaymentIntentSuccess),
+            DlocalPaymentStatus::Rejected | DlocalPaymentStatus::Cancelled => {
+                Ok(Self::PaymentIntentFailure)
+            }
+            DlocalPaymentStatus::Pending => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+impl TryFrom<RefundStatus> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(value: RefundStatus) -> Result<Self, Self::Error> {
+        match value {
+            RefundStatus::Success => Ok(Self::RefundSuccess),
+            RefundStatus::Rejected | RefundStatus::Cancelled => Ok(Self::RefundFailure),
+            RefundStatus::Pending => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum DlocalChargebackStatus {
+    Pending,
+    Completed,
+    Reversed,
+}
+
+impl From<DlocalChargebackStatus> for api::IncomingWebhookEvent {
+    fn from(value: DlocalChargebackStatus) -> Self {
+        match value {
+            DlocalChargebackStatus::Pending => Self::DisputeOpened,
+            DlocalChargebackStatus::Completed => Self::DisputeLost,
+            DlocalChargebackStatus::Reversed => Self::DisputeWon,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlocalChargebackWebhookBody {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub status: DlocalChargebackStatus,
+    pub status_detail: Option<String>,
+}
+
+impl From<DlocalChargebackWebhookBody> for api::DisputePayload {
+    fn from(chargeback: DlocalChargebackWebhookBody) -> Self {
+        Self {
+            amount: chargeback.amount.to_string(),
+            currency: chargeback.currency,
+            dispute_stage: api_models::enums::DisputeStage::Dispute,
+            connector_status: chargeback.status.to_string(),
+            connector_dispute_id: chargeback.id,
+            connector_reason: chargeback.status_detail,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DlocalErrorResponse {
     pub code: i32,
@@ -493,21 +777,52 @@ pub struct DlocalErrorResponse {
     pub param: Option<String>,
 }
 
-fn get_doc_from_currency(country: String) -> Secret<String> {
-    let doc = match country.as_str() {
-        "BR" => "91483309223",
-        "ZA" => "2001014800086",
-        "BD" | "GT" | "HN" | "PK" | "SN" | "TH" => "1234567890001",
-        "CR" | "SV" | "VN" => "123456789",
-        "DO" | "NG" => "12345678901",
-        "EG" => "12345678901112",
-        "GH" | "ID" | "RW" | "UG" => "1234567890111123",
-        "IN" => "NHSTP6374G",
-        "CI" => "CA124356789",
-        "JP" | "MY" | "PH" => "123456789012",
-        "NI" => "1234567890111A",
-        "TZ" => "12345678912345678900",
-        _ => "12345678",
-    };
-    Secret::new(doc.to_string())
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_webhook_kind() {
+        let payment: DlocalWebhookBody =
+            serde_json::from_str(r#"{"id":"D-4-cf8f8f0c","status":"PAID"}"#).unwrap();
+        assert_eq!(payment.kind(), DlocalWebhookKind::Payment);
+
+        let refund: DlocalWebhookBody = serde_json::from_str(
+            r#"{"id":"REF-15104-a9cc29fa","payment_id":"D-4-cf8f8f0c","status":"SUCCESS"}"#,
+        )
+        .unwrap();
+        assert_eq!(refund.kind(), DlocalWebhookKind::Refund);
+
+        let chargeback: DlocalWebhookBody = serde_json::from_str(
+            r#"{"id":"CHAR42-2019-0000","payment_id":"D-4-cf8f8f0c","status":"PENDING"}"#,
+        )
+        .unwrap();
+        assert_eq!(chargeback.kind(), DlocalWebhookKind::Chargeback);
+    }
+
+    #[test]
+    fn test_chargeback_dispute_details() {
+        let chargeback: DlocalChargebackWebhookBody = serde_json::from_str(
+            r#"{
+                "id": "CHAR42-2019-0000",
+                "payment_id": "D-4-cf8f8f0c",
+                "amount": 1000,
+                "currency": "USD",
+                "status": "COMPLETED",
+                "status_detail": "The chargeback was completed"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            api::IncomingWebhookEvent::from(chargeback.status),
+            api::IncomingWebhookEvent::DisputeLost
+        );
+
+        let dispute = api::DisputePayload::from(chargeback);
+        assert_eq!(dispute.connector_dispute_id, "CHAR42-2019-0000");
+        assert_eq!(dispute.connector_status, "COMPLETED");
+        assert_eq!(dispute.amount, "1000");
+    }
 }
@@ -0,0 +1,236 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+#[derive(Debug, Serialize)]
+pub struct DummyPaymentsRequest {
+    amount: i64,
+    currency: enums::Currency,
+    card: DummyCard,
+    captured: bool,
+    return_url: Option<String>,
+    test_mode: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyCard {
+    number: Secret<String, common_utils::pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cvc: Secret<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for DummyPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data.clone() {
+            api::PaymentMethodData::Card(ccard) => Ok(Self {
+                amount: item.request.amount,
+                currency: item.request.currency,
+                card: DummyCard {
+                    number: ccard.card_number,
+                    expiry_month: ccard.card_exp_month,
+                    expiry_year: ccard.card_exp_year,
+                    cvc: ccard.card_cvc,
+                },
+                captured: matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                ),
+                return_url: item.router_return_url.clone(),
+                test_mode: DummyMetadata::from(item.connector_meta_data.as_ref()).test_mode,
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyCaptureRequest {
+    amount_to_capture: Option<i64>,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount_to_capture: item.request.amount_to_capture,
+        })
+    }
+}
+
+/// Metadata of the connector account, set on the account made up for merchants in test mode
+#[derive(Debug, Default, Deserialize)]
+pub struct DummyMetadata {
+    #[serde(default)]
+    test_mode: bool,
+}
+
+impl From<Option<&serde_json::Value>> for DummyMetadata {
+    fn from(metadata: Option<&serde_json::Value>) -> Self {
+        metadata
+            .and_then(|metadata| serde_json::from_value(metadata.clone()).ok())
+            .unwrap_or_default()
+    }
+}
+
+// Auth Struct
+pub struct DummyAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for DummyAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyPaymentStatus {
+    Succeeded,
+    Authorized,
+    Processing,
+    Failed,
+    Voided,
+}
+
+impl From<DummyPaymentStatus> for enums::AttemptStatus {
+    fn from(item: DummyPaymentStatus) -> Self {
+        match item {
+            DummyPaymentStatus::Succeeded => Self::Charged,
+            DummyPaymentStatus::Authorized => Self::Authorized,
+            DummyPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyPaymentStatus::Failed => Self::Failure,
+            DummyPaymentStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyNextAction {
+    redirect_to_url: url::Url,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyPaymentsResponse {
+    id: String,
+    status: DummyPaymentStatus,
+    amount_captured: i64,
+    next_action: Option<DummyNextAction>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, DummyPaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, DummyPaymentsResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item.response.next_action.map(|next_action| {
+            services::RedirectForm::from((next_action.redirect_to_url, services::Method::Get))
+        });
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            amount_captured: Some(item.response.amount_captured),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct DummyRefundRequest {
+    payment_id: String,
+    amount: i64,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.request.connector_transaction_id.clone(),
+            amount: item.request.refund_amount,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyRefundStatus {
+    Succeeded,
+    Failed,
+}
+
+impl From<DummyRefundStatus> for enums::RefundStatus {
+    fn from(item: DummyRefundStatus) -> Self {
+        match item {
+            DummyRefundStatus::Succeeded => Self::Success,
+            DummyRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundResponse {
+    id: String,
+    status: DummyRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyErrorResponse {
+    pub error: DummyErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyErrorDetails {
+    pub code: String,
+    pub message: String,
+}
+
+pub fn get_unified_code(code: &str) -> Option<enums::UnifiedCode> {
+    match code {
+        "card_declined_temporarily" => Some(enums::UnifiedCode::IssuerUnavailable),
+        "insufficient_funds" => Some(enums::UnifiedCode::InsufficientFunds),
+        _ => None,
+    }
+}
@@ -63,6 +63,12 @@ impl api::ConnectorCommon for Fiserv {
     }
 }
 
+impl api::ConnectorSpecifications for Fiserv {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::ConnectorAccessToken for Fiserv {}
 
 impl
@@ -241,6 +247,7 @@ impl
             code: consts::NO_ERROR_CODE.to_string(),
             message,
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -392,6 +399,7 @@ impl
             code: consts::NO_ERROR_CODE.to_string(),
             message,
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -412,6 +420,65 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Fiserv {}
+impl api::PayoutCreate for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::PayoutFulfill for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::PayoutCancel for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::PayoutSync for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::RefundCredit for Fiserv {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Fiserv
+{
+}
+
+impl api::ConnectorMandateRevoke for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Fiserv
+{
+}
+
+impl api::Dispute for Fiserv {}
+impl api::SubmitEvidence for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Fiserv
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Fiserv {
     fn get_webhook_object_reference_id(
@@ -73,6 +73,10 @@ impl ConnectorCommon for Globalpay {
         connectors.globalpay.base_url.as_ref()
     }
 
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("globalpayments")
+    }
+
     fn get_auth_header(
         &self,
         _auth_type: &types::ConnectorAuthType,
@@ -94,10 +98,13 @@ impl ConnectorCommon for Globalpay {
             code: response.error_code,
             message: response.detailed_error_description,
             reason: None,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Globalpay {}
+
 impl api::ConnectorAccessToken for Globalpay {}
 
 impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
@@ -188,6 +195,7 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
             code: response.error_code,
             message: response.detailed_error_description,
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -681,6 +689,65 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Globalpay {}
+impl api::PayoutCreate for Globalpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::PayoutFulfill for Globalpay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::PayoutCancel for Globalpay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::PayoutSync for Globalpay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::RefundCredit for Globalpay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Globalpay
+{
+}
+
+impl api::ConnectorMandateRevoke for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Globalpay
+{
+}
+
+impl api::Dispute for Globalpay {}
+impl api::SubmitEvidence for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Globalpay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Globalpay {
     fn get_webhook_object_reference_id(
@@ -165,6 +165,9 @@ fn get_payment_response(
             redirection_data: None,
             mandate_reference: None,
             connector_metadata: None,
+            network_txn_id: None,
+            avs_result: None,
+            cvv_result: None,
         }),
     }
 }
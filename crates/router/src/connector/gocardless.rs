@@ -0,0 +1,969 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use transformers as gocardless;
+
+use crate::{
+    configs::settings,
+    connector::utils::RefundsRequestData,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    headers, logger, routes,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        storage::enums,
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Gocardless;
+
+impl api::Payment for Gocardless {}
+impl api::PaymentSession for Gocardless {}
+impl api::ConnectorAccessToken for Gocardless {}
+impl api::PreVerify for Gocardless {}
+impl api::PaymentAuthorize for Gocardless {}
+impl api::PaymentSync for Gocardless {}
+impl api::PaymentCapture for Gocardless {}
+impl api::PaymentVoid for Gocardless {}
+impl api::Refund for Gocardless {}
+impl api::RefundExecute for Gocardless {}
+impl api::RefundSync for Gocardless {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Gocardless
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ),
+            (
+                headers::GOCARDLESS_VERSION.to_string(),
+                gocardless::GOCARDLESS_API_VERSION.to_string(),
+            ),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Gocardless {
+    fn id(&self) -> &'static str {
+        "gocardless"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.gocardless.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = gocardless::GocardlessAuthType::try_from(auth_type)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Bearer {}", auth.access_token),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: gocardless::GocardlessErrorResponse = res
+            .response
+            .parse_struct("Gocardless ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        // Validation failures list the rejected fields, the first of which is reported
+        let detail = response.error.errors.into_iter().next();
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: detail
+                .as_ref()
+                .map(|detail| detail.reason.clone())
+                .unwrap_or(response.error.error_type),
+            message: response.error.message,
+            reason: detail.map(|detail| match detail.field {
+                Some(field) => format!("{field}: {}", detail.message),
+                None => detail.message,
+            }),
+            unified_code: None,
+        })
+    }
+}
+
+impl api::ConnectorSpecifications for Gocardless {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::BankDebit])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+
+    fn supports_mandates(&self) -> bool {
+        true
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Gocardless
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Gocardless
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Gocardless
+{
+}
+
+impl
+    ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}customers", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let gocardless_req =
+            utils::Encode::<gocardless::GocardlessCustomerRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(gocardless_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorCustomerType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::ConnectorCustomerType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::ConnectorCustomerType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+        res: Response,
+    ) -> CustomResult<
+        types::PaymentsPretaskRouterData<api::CreateConnectorCustomer>,
+        errors::ConnectorError,
+    > {
+        logger::debug!(gocardless_customer_response=?res);
+        let response: gocardless::GocardlessCustomerResponse = res
+            .response
+            .parse_struct("Gocardless CustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::CreateBankAccount,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}customer_bank_accounts",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let gocardless_req =
+            utils::Encode::<gocardless::GocardlessBankAccountRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(gocardless_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::BankAccountCreateType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::BankAccountCreateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::BankAccountCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+        res: Response,
+    ) -> CustomResult<
+        types::PaymentsPretaskRouterData<api::CreateBankAccount>,
+        errors::ConnectorError,
+    > {
+        logger::debug!(gocardless_bank_account_response=?res);
+        let response: gocardless::GocardlessBankAccountResponse = res
+            .response
+            .parse_struct("Gocardless BankAccountResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        api::CreateMandate,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateMandate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsPretaskRouterData<api::CreateMandate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}mandates", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateMandate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let gocardless_req =
+            utils::Encode::<gocardless::GocardlessMandateRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(gocardless_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsPretaskRouterData<api::CreateMandate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateCreateType::get_url(self, req, connectors)?)
+                .headers(types::MandateCreateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::MandateCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsPretaskRouterData<api::CreateMandate>,
+        res: Response,
+    ) -> CustomResult<types::PaymentsPretaskRouterData<api::CreateMandate>, errors::ConnectorError>
+    {
+        logger::debug!(gocardless_mandate_response=?res);
+        let response: gocardless::GocardlessMandateResponse = res
+            .response
+            .parse_struct("Gocardless MandateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+fn is_mandate_payment(req: &types::PaymentsAuthorizeRouterData) -> bool {
+    req.request
+        .mandate_id
+        .as_ref()
+        .and_then(|mandate_ids| mandate_ids.connector_mandate_id.as_ref())
+        .is_some()
+}
+
+/// Records the error of a failed step of setting up the mandate as the outcome of the payment,
+/// returning whether the step succeeded
+fn settle_pretask<F>(
+    router_data: &mut types::PaymentsAuthorizeRouterData,
+    resp: types::PaymentsPretaskRouterData<F>,
+) -> bool {
+    match resp.response {
+        Ok(_) => {
+            router_data.session_token = resp.session_token;
+            true
+        }
+        Err(error) => {
+            router_data.status = enums::AttemptStatus::Failure;
+            router_data.session_token = None;
+            router_data.response = Err(error);
+            false
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Gocardless
+{
+    async fn execute_pretasks(
+        &self,
+        router_data: &mut types::PaymentsAuthorizeRouterData,
+        app_state: &routes::AppState,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Bank accounts are only debited through a mandate, set up on the bank account of a
+        // customer, all of which are created before the payment. Payments on a mandate set up
+        // with an earlier payment skip this.
+        if is_mandate_payment(router_data) {
+            return Ok(());
+        }
+
+        let customer_integ: Box<
+            &(dyn ConnectorIntegration<
+                api::CreateConnectorCustomer,
+                types::PaymentsAuthorizeData,
+                types::PaymentsResponseData,
+            > + Send
+                  + Sync
+                  + 'static),
+        > = Box::new(&Self);
+        let customer_data =
+            &types::PaymentsPretaskRouterData::<api::CreateConnectorCustomer>::from(&router_data);
+        let customer_resp = services::execute_connector_processing_step(
+            app_state,
+            customer_integ,
+            customer_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await?;
+        if !settle_pretask(router_data, customer_resp) {
+            return Ok(());
+        }
+
+        let bank_account_integ: Box<
+            &(dyn ConnectorIntegration<
+                api::CreateBankAccount,
+                types::PaymentsAuthorizeData,
+                types::PaymentsResponseData,
+            > + Send
+                  + Sync
+                  + 'static),
+        > = Box::new(&Self);
+        let bank_account_data =
+            &types::PaymentsPretaskRouterData::<api::CreateBankAccount>::from(&router_data);
+        let bank_account_resp = services::execute_connector_processing_step(
+            app_state,
+            bank_account_integ,
+            bank_account_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await?;
+        if !settle_pretask(router_data, bank_account_resp) {
+            return Ok(());
+        }
+
+        let mandate_integ: Box<
+            &(dyn ConnectorIntegration<
+                api::CreateMandate,
+                types::PaymentsAuthorizeData,
+                types::PaymentsResponseData,
+            > + Send
+                  + Sync
+                  + 'static),
+        > = Box::new(&Self);
+        let mandate_data =
+            &types::PaymentsPretaskRouterData::<api::CreateMandate>::from(&router_data);
+        let mandate_resp = services::execute_connector_processing_step(
+            app_state,
+            mandate_integ,
+            mandate_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await?;
+        settle_pretask(router_data, mandate_resp);
+        Ok(())
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}payments", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let gocardless_req =
+            utils::Encode::<gocardless::GocardlessPaymentsRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(gocardless_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        // The payment is not created when setting up the mandate failed in `execute_pretasks`,
+        // leaving its error as the outcome of the payment
+        if !is_mandate_payment(req) && req.session_token.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(gocardless_payments_response=?res);
+        let response: gocardless::GocardlessPaymentsResponse = res
+            .response
+            .parse_struct("Gocardless PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let payment_id = req
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        Ok(format!(
+            "{}payments/{payment_id}",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(gocardless_payment_sync_response=?res);
+        let response: gocardless::GocardlessPaymentsResponse = res
+            .response
+            .parse_struct("Gocardless PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+// Debits are collected once submitted to the bank of the customer, there is nothing to capture
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Gocardless
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    // Only payments not yet submitted to the bank of the customer can be cancelled
+    fn get_url(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}payments/{}/actions/cancel",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCancelRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+        logger::debug!(gocardless_cancel_response=?res);
+        let response: gocardless::GocardlessPaymentsResponse = res
+            .response
+            .parse_struct("Gocardless PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}refunds", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let gocardless_req =
+            utils::Encode::<gocardless::GocardlessRefundRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(gocardless_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(gocardless_refund_response=?res);
+        let response: gocardless::GocardlessRefundResponse = res
+            .response
+            .parse_struct("Gocardless RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Gocardless
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}refunds/{}",
+            self.base_url(connectors),
+            req.request.get_connector_refund_id()?
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::RefundSyncType::get_url(self, req, connectors)?)
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        logger::debug!(gocardless_refund_sync_response=?res);
+        let response: gocardless::GocardlessRefundResponse = res
+            .response
+            .parse_struct("Gocardless RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Payouts for Gocardless {}
+impl api::PayoutCreate for Gocardless {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Gocardless
+{
+}
+
+impl api::PayoutFulfill for Gocardless {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Gocardless
+{
+}
+
+impl api::PayoutCancel for Gocardless {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Gocardless
+{
+}
+
+impl api::PayoutSync for Gocardless {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Gocardless
+{
+}
+
+impl api::RefundCredit for Gocardless {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Gocardless
+{
+}
+
+impl api::ConnectorMandateRevoke for Gocardless {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Gocardless
+{
+}
+
+impl api::Dispute for Gocardless {}
+impl api::SubmitEvidence for Gocardless {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Gocardless
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Gocardless {
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+}
+
+impl services::ConnectorRedirectResponse for Gocardless {}
@@ -0,0 +1,640 @@
+use api_models::payments::{BankDebitBilling, BankDebitData};
+use common_utils::pii::{Email, IpAddress};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self, RouterData},
+    core::errors,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+pub const GOCARDLESS_API_VERSION: &str = "2015-07-06";
+
+// Auth Struct
+pub struct GocardlessAuthType {
+    pub(super) access_token: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for GocardlessAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                access_token: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GocardlessScheme {
+    Ach,
+    SepaCore,
+}
+
+fn get_bank_debit_data(item: &types::PaymentsAuthorizeData) -> Result<&BankDebitData, Error> {
+    match &item.payment_method_data {
+        api::PaymentMethodData::BankDebit(bank_debit_data) => Ok(bank_debit_data),
+        _ => Err(errors::ConnectorError::NotImplemented(
+            "Payment method".to_string(),
+        ))?,
+    }
+}
+
+fn get_billing_details(bank_debit_data: &BankDebitData) -> &BankDebitBilling {
+    match bank_debit_data {
+        BankDebitData::AchBankDebit {
+            billing_details, ..
+        }
+        | BankDebitData::SepaBankDebit {
+            billing_details, ..
+        } => billing_details,
+    }
+}
+
+// CUSTOMER :
+#[derive(Debug, Serialize)]
+pub struct GocardlessCustomerRequest {
+    customers: GocardlessCustomer,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessCustomer {
+    email: Secret<String, Email>,
+    given_name: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    family_name: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_line1: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    postal_code: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<String>,
+}
+
+impl<F> TryFrom<&types::PaymentsPretaskRouterData<F>> for GocardlessCustomerRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsPretaskRouterData<F>) -> Result<Self, Self::Error> {
+        let billing_details = get_billing_details(get_bank_debit_data(&item.request)?);
+        // GoCardless keeps the first and the last name of the customer apart, the account holder
+        // name is sent whole with the bank account
+        let (given_name, family_name) = match billing_details.name.peek().split_once(' ') {
+            Some((given_name, family_name)) => (
+                Secret::new(given_name.to_string()),
+                Some(Secret::new(family_name.to_string())),
+            ),
+            None => (billing_details.name.clone(), None),
+        };
+        let address = item.get_billing_address().ok();
+
+        Ok(Self {
+            customers: GocardlessCustomer {
+                email: billing_details.email.clone(),
+                given_name,
+                family_name,
+                address_line1: address.and_then(|address| address.line1.clone()),
+                city: address.and_then(|address| address.city.clone()),
+                region: address.and_then(|address| address.state.clone()),
+                postal_code: address.and_then(|address| address.zip.clone()),
+                country_code: address.and_then(|address| address.country.clone()),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessCustomerResponse {
+    customers: GocardlessResource,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessResource {
+    id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, GocardlessCustomerResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessCustomerResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_token: Some(item.response.customers.id.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.customers.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// BANK ACCOUNT :
+#[derive(Debug, Serialize)]
+pub struct GocardlessBankAccountRequest {
+    customer_bank_accounts: GocardlessBankAccount,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessBankAccount {
+    account_holder_name: Secret<String>,
+    #[serde(flatten)]
+    account_details: GocardlessAccountDetails,
+    links: GocardlessBankAccountLinks,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum GocardlessAccountDetails {
+    UsBankAccount {
+        account_number: Secret<String>,
+        branch_code: Secret<String>,
+        account_type: api_models::enums::BankAccountType,
+        country_code: &'static str,
+        currency: enums::Currency,
+    },
+    Iban {
+        iban: Secret<String>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessBankAccountLinks {
+    customer: String,
+}
+
+impl<F> TryFrom<&types::PaymentsPretaskRouterData<F>> for GocardlessBankAccountRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsPretaskRouterData<F>) -> Result<Self, Self::Error> {
+        let bank_debit_data = get_bank_debit_data(&item.request)?;
+        let account_details = match bank_debit_data {
+            BankDebitData::AchBankDebit {
+                account_number,
+                routing_number,
+                bank_account_type,
+                ..
+            } => GocardlessAccountDetails::UsBankAccount {
+                account_number: account_number.clone(),
+                branch_code: routing_number.clone(),
+                account_type: bank_account_type.unwrap_or_default(),
+                country_code: "US",
+                currency: enums::Currency::USD,
+            },
+            BankDebitData::SepaBankDebit { iban, .. } => {
+                GocardlessAccountDetails::Iban { iban: iban.clone() }
+            }
+        };
+
+        Ok(Self {
+            customer_bank_accounts: GocardlessBankAccount {
+                account_holder_name: get_billing_details(bank_debit_data).name.clone(),
+                account_details,
+                links: GocardlessBankAccountLinks {
+                    customer: item.get_session_token()?,
+                },
+            },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessBankAccountResponse {
+    customer_bank_accounts: GocardlessResource,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, GocardlessBankAccountResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessBankAccountResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_token: Some(item.response.customer_bank_accounts.id.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.customer_bank_accounts.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// MANDATE :
+#[derive(Debug, Serialize)]
+pub struct GocardlessMandateRequest {
+    mandates: GocardlessMandate,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessMandate {
+    scheme: GocardlessScheme,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer_ip_address: Option<Secret<String, IpAddress>>,
+    links: GocardlessMandateLinks,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessMandateLinks {
+    customer_bank_account: String,
+}
+
+/// Address the customer accepted the mandate from, which GoCardless requires for ACH debits
+fn get_payer_ip_address(
+    item: &types::PaymentsAuthorizeData,
+) -> Result<Secret<String, IpAddress>, Error> {
+    if let Some(online) = item
+        .setup_mandate_details
+        .as_ref()
+        .and_then(|mandate_data| mandate_data.customer_acceptance.online.as_ref())
+    {
+        return Ok(online.ip_address.clone());
+    }
+
+    // Customers accept the mandate text of one-time debits on the checkout page, from which the
+    // details of their browser are sent
+    item.browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| Secret::new(ip_address.to_string()))
+        .ok_or_else(utils::missing_field_err("browser_info.ip_address"))
+}
+
+impl<F> TryFrom<&types::PaymentsPretaskRouterData<F>> for GocardlessMandateRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsPretaskRouterData<F>) -> Result<Self, Self::Error> {
+        let (scheme, payer_ip_address) = match get_bank_debit_data(&item.request)? {
+            BankDebitData::AchBankDebit { .. } => (
+                GocardlessScheme::Ach,
+                Some(get_payer_ip_address(&item.request)?),
+            ),
+            BankDebitData::SepaBankDebit { .. } => (GocardlessScheme::SepaCore, None),
+        };
+
+        Ok(Self {
+            mandates: GocardlessMandate {
+                scheme,
+                payer_ip_address,
+                links: GocardlessMandateLinks {
+                    customer_bank_account: item.get_session_token()?,
+                },
+            },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessMandateResponse {
+    mandates: GocardlessResource,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, GocardlessMandateResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessMandateResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_token: Some(item.response.mandates.id.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.mandates.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// PAYMENT :
+#[derive(Debug, Serialize)]
+pub struct GocardlessPaymentsRequest {
+    payments: GocardlessPayment,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessPayment {
+    amount: i64,
+    currency: enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    metadata: GocardlessMetadata,
+    links: GocardlessPaymentLinks,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessMetadata {
+    payment_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GocardlessPaymentLinks {
+    mandate: String,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for GocardlessPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        // Payments on a mandate set up with an earlier payment skip the set up of the mandate,
+        // whose id is then left as the session token
+        let mandate = match item
+            .request
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id.clone())
+        {
+            Some(connector_mandate_id) => connector_mandate_id,
+            None => item.get_session_token()?,
+        };
+
+        Ok(Self {
+            payments: GocardlessPayment {
+                amount: item.request.amount,
+                currency: item.request.currency,
+                description: item.description.clone(),
+                metadata: GocardlessMetadata {
+                    payment_id: item.payment_id.clone(),
+                },
+                links: GocardlessPaymentLinks { mandate },
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GocardlessPaymentStatus {
+    PendingCustomerApproval,
+    PendingSubmission,
+    Submitted,
+    Confirmed,
+    PaidOut,
+    Cancelled,
+    CustomerApprovalDenied,
+    Failed,
+    ChargedBack,
+}
+
+impl From<GocardlessPaymentStatus> for enums::AttemptStatus {
+    fn from(item: GocardlessPaymentStatus) -> Self {
+        match item {
+            // Debits are collected from the bank of the customer days after being submitted, and
+            // are only paid once GoCardless confirms the collection
+            GocardlessPaymentStatus::PendingCustomerApproval
+            | GocardlessPaymentStatus::PendingSubmission
+            | GocardlessPaymentStatus::Submitted => Self::Pending,
+            GocardlessPaymentStatus::Confirmed | GocardlessPaymentStatus::PaidOut => Self::Charged,
+            GocardlessPaymentStatus::Cancelled => Self::Voided,
+            GocardlessPaymentStatus::CustomerApprovalDenied
+            | GocardlessPaymentStatus::Failed
+            | GocardlessPaymentStatus::ChargedBack => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessPaymentsResponse {
+    payments: GocardlessPaymentResource,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessPaymentResource {
+    id: String,
+    status: GocardlessPaymentStatus,
+    links: GocardlessPaymentLinks,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, GocardlessPaymentsResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let payment = item.response.payments;
+        Ok(Self {
+            status: enums::AttemptStatus::from(payment.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(payment.id),
+                redirection_data: None,
+                mandate_reference: Some(payment.links.mandate),
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct GocardlessRefundRequest {
+    refunds: GocardlessRefund,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessRefund {
+    amount: i64,
+    /// The refunded amount of the payment including this refund, which GoCardless checks to
+    /// guard against refunding a payment twice. Only the first refund of a payment is known to
+    /// match it, GoCardless declines later partial refunds.
+    total_amount_confirmation: i64,
+    metadata: GocardlessRefundMetadata,
+    links: GocardlessRefundLinks,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessRefundMetadata {
+    refund_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessRefundLinks {
+    payment: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for GocardlessRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunds: GocardlessRefund {
+                amount: item.request.refund_amount,
+                total_amount_confirmation: item.request.refund_amount,
+                metadata: GocardlessRefundMetadata {
+                    refund_id: item.request.refund_id.clone(),
+                },
+                links: GocardlessRefundLinks {
+                    payment: item.request.connector_transaction_id.clone(),
+                },
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GocardlessRefundStatus {
+    Created,
+    PendingSubmission,
+    Submitted,
+    Paid,
+    Cancelled,
+    Bounced,
+    FundsReturned,
+}
+
+impl From<GocardlessRefundStatus> for enums::RefundStatus {
+    fn from(item: GocardlessRefundStatus) -> Self {
+        match item {
+            GocardlessRefundStatus::Created
+            | GocardlessRefundStatus::PendingSubmission
+            | GocardlessRefundStatus::Submitted => Self::Pending,
+            GocardlessRefundStatus::Paid => Self::Success,
+            GocardlessRefundStatus::Cancelled
+            | GocardlessRefundStatus::Bounced
+            | GocardlessRefundStatus::FundsReturned => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GocardlessRefundResponse {
+    refunds: GocardlessRefundResource,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GocardlessRefundResource {
+    id: String,
+    status: GocardlessRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, GocardlessRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, GocardlessRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.refunds.id,
+                refund_status: enums::RefundStatus::from(item.response.refunds.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessErrorResponse {
+    pub error: GocardlessError,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessError {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: String,
+    #[serde(default)]
+    pub errors: Vec<GocardlessErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessErrorDetail {
+    pub reason: String,
+    pub message: String,
+    pub field: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_account_details() {
+        let ach = GocardlessAccountDetails::UsBankAccount {
+            account_number: Secret::new("2715500356".to_string()),
+            branch_code: Secret::new("026073150".to_string()),
+            account_type: api_models::enums::BankAccountType::Checking,
+            country_code: "US",
+            currency: enums::Currency::USD,
+        };
+        assert_eq!(
+            serde_json::to_value(ach).unwrap(),
+            serde_json::json!({
+                "account_number": "2715500356",
+                "branch_code": "026073150",
+                "account_type": "checking",
+                "country_code": "US",
+                "currency": "USD",
+            })
+        );
+
+        let sepa = GocardlessAccountDetails::Iban {
+            iban: Secret::new("DE89370400440532013000".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_value(sepa).unwrap(),
+            serde_json::json!({ "iban": "DE89370400440532013000" })
+        );
+    }
+
+    #[test]
+    fn test_payment_status() {
+        let status: GocardlessPaymentStatus =
+            serde_json::from_str("\"pending_submission\"").unwrap();
+        assert_eq!(
+            enums::AttemptStatus::from(status),
+            enums::AttemptStatus::Pending
+        );
+        let status: GocardlessPaymentStatus = serde_json::from_str("\"paid_out\"").unwrap();
+        assert_eq!(
+            enums::AttemptStatus::from(status),
+            enums::AttemptStatus::Charged
+        );
+        let status: GocardlessPaymentStatus = serde_json::from_str("\"charged_back\"").unwrap();
+        assert_eq!(
+            enums::AttemptStatus::from(status),
+            enums::AttemptStatus::Failure
+        );
+    }
+}
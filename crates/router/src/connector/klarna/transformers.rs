@@ -3,8 +3,9 @@ use error_stack::report;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    connector::utils::PaymentsAuthorizeRequestData,
     core::errors,
-    types::{self, storage::enums},
+    types::{self, api, storage::enums},
 };
 
 #[derive(Default, Debug, Serialize)]
@@ -13,6 +14,8 @@ pub struct KlarnaPaymentsRequest {
     order_amount: i64,
     purchase_country: String,
     purchase_currency: enums::Currency,
+    auto_capture: bool,
+    merchant_reference1: String,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -99,6 +102,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for KlarnaPaymentsRequest {
                     unit_price: request.amount,
                     total_amount: request.amount,
                 }],
+                auto_capture: request.is_auto_capture(),
+                merchant_reference1: item.payment_id.clone(),
             }),
             None => Err(report!(errors::ConnectorError::MissingRequiredField {
                 field_name: "product_name"
@@ -120,12 +125,31 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
-            status: item.response.fraud_status.into(),
+            status: get_fraud_classified_status(
+                item.response.fraud_status,
+                item.data.request.is_auto_capture(),
+            ),
             ..item.data
         })
     }
 }
+
+fn get_fraud_classified_status(
+    fraud_status: KlarnaFraudStatus,
+    is_auto_capture: bool,
+) -> enums::AttemptStatus {
+    match fraud_status {
+        KlarnaFraudStatus::Accepted if is_auto_capture => enums::AttemptStatus::Charged,
+        KlarnaFraudStatus::Accepted => enums::AttemptStatus::Authorized,
+        KlarnaFraudStatus::Pending => enums::AttemptStatus::Pending,
+        KlarnaFraudStatus::Rejected => enums::AttemptStatus::Failure,
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct OrderLines {
     name: String,
@@ -166,13 +190,160 @@ pub enum KlarnaFraudStatus {
     Accepted,
     #[default]
     Pending,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaOrderStatus {
+    #[default]
+    Authorized,
+    PartCaptured,
+    Captured,
+    Cancelled,
+    Expired,
+    Closed,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlarnaOrderRefund {
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaOrderResponse {
+    pub order_id: String,
+    pub status: KlarnaOrderStatus,
+    pub fraud_status: KlarnaFraudStatus,
+    #[serde(default)]
+    pub refunds: Vec<KlarnaOrderRefund>,
+}
+
+impl From<&KlarnaOrderResponse> for enums::AttemptStatus {
+    fn from(item: &KlarnaOrderResponse) -> Self {
+        match (&item.fraud_status, &item.status) {
+            (KlarnaFraudStatus::Rejected, _) => Self::Failure,
+            (KlarnaFraudStatus::Pending, _) => Self::Pending,
+            (KlarnaFraudStatus::Accepted, KlarnaOrderStatus::Authorized) => Self::Authorized,
+            (KlarnaFraudStatus::Accepted, KlarnaOrderStatus::PartCaptured) => Self::PartialCharged,
+            (
+                KlarnaFraudStatus::Accepted,
+                KlarnaOrderStatus::Captured | KlarnaOrderStatus::Closed,
+            ) => Self::Charged,
+            (KlarnaFraudStatus::Accepted, KlarnaOrderStatus::Cancelled) => Self::Voided,
+            (KlarnaFraudStatus::Accepted, KlarnaOrderStatus::Expired) => Self::Failure,
+        }
+    }
+}
+
+impl TryFrom<types::PaymentsSyncResponseRouterData<KlarnaOrderResponse>>
+    for types::PaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsSyncResponseRouterData<KlarnaOrderResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(&item.response),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureRequest {
+    captured_amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for KlarnaCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            captured_amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundRequest {
+    refunded_amount: i64,
+    description: Option<String>,
+    reference: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for KlarnaRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunded_amount: item.request.refund_amount,
+            description: item.request.reason.clone(),
+            reference: item.request.refund_id.clone(),
+        })
+    }
+}
+
+/// Klarna does not return a refund object when a refund is created, so the merchant
+/// reference sent with the refund is used to find it in the order later on
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_id = item.data.request.refund_id.clone();
+        let refund_status = if item
+            .response
+            .refunds
+            .iter()
+            .any(|refund| refund.reference.as_ref() == Some(&refund_id))
+        {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaWebhookEventType {
+    FraudRiskAccepted,
+    FraudRiskRejected,
+    FraudRiskStopped,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaWebhookBody {
+    pub order_id: String,
+    pub event_type: KlarnaWebhookEventType,
 }
 
-impl From<KlarnaFraudStatus> for enums::AttemptStatus {
-    fn from(item: KlarnaFraudStatus) -> Self {
-        match item {
-            KlarnaFraudStatus::Accepted => Self::Charged,
-            KlarnaFraudStatus::Pending => Self::Authorizing,
+impl From<KlarnaWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event: KlarnaWebhookEventType) -> Self {
+        match event {
+            KlarnaWebhookEventType::FraudRiskAccepted => Self::PaymentIntentSuccess,
+            KlarnaWebhookEventType::FraudRiskRejected
+            | KlarnaWebhookEventType::FraudRiskStopped => Self::PaymentIntentFailure,
         }
     }
 }
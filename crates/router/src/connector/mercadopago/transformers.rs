@@ -0,0 +1,535 @@
+use common_utils::{
+    ext_traits::Encode,
+    pii::{self, Email},
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use url::Url;
+
+use crate::{
+    connector::utils::{self, CardData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+// Auth Struct
+pub struct MercadopagoAuthType {
+    pub(super) access_token: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for MercadopagoAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                access_token: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// Mercado Pago takes amounts in the major unit of the currency, as a JSON number.
+fn get_transaction_amount(
+    amount: i64,
+    currency: enums::Currency,
+) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
+    utils::to_currency_base_unit(amount, currency)?
+        .parse::<f64>()
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+}
+
+#[derive(Debug, Serialize)]
+pub struct MercadopagoIdentification {
+    #[serde(rename = "type")]
+    identification_type: String,
+    number: Secret<String>,
+}
+
+impl From<&api_models::payments::PayerDocument> for MercadopagoIdentification {
+    fn from(document: &api_models::payments::PayerDocument) -> Self {
+        Self {
+            identification_type: document.document_type.to_string().to_uppercase(),
+            number: document.document_number.clone(),
+        }
+    }
+}
+
+// Card Token Request
+#[derive(Debug, Serialize)]
+pub struct MercadopagoCardholder {
+    name: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identification: Option<MercadopagoIdentification>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MercadopagoCardTokenRequest {
+    card_number: Secret<String, pii::CardNumber>,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    security_code: Secret<String>,
+    cardholder: MercadopagoCardholder,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for MercadopagoCardTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref ccard) => Ok(Self {
+                card_number: ccard.card_number.clone(),
+                expiration_month: ccard.card_exp_month.clone(),
+                expiration_year: ccard.card_exp_year.clone(),
+                security_code: ccard.card_cvc.clone(),
+                cardholder: MercadopagoCardholder {
+                    name: ccard.card_holder_name.clone(),
+                    identification: None,
+                },
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MercadopagoCardTokenResponse {
+    id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, MercadopagoCardTokenResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            MercadopagoCardTokenResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_token: Some(item.response.id.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Payments Request
+#[derive(Debug, Serialize)]
+pub struct MercadopagoPayer {
+    email: Secret<String, Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identification: Option<MercadopagoIdentification>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MercadopagoThreeDsMode {
+    Optional,
+    NotSupported,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MercadopagoPaymentsRequest {
+    transaction_amount: f64,
+    payment_method_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installments: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capture: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    three_d_secure_mode: Option<MercadopagoThreeDsMode>,
+    payer: MercadopagoPayer,
+    external_reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_url: Option<String>,
+}
+
+fn get_card_payment_method_id(
+    ccard: &api::Card,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    match ccard.get_card_issuer()? {
+        utils::CardIssuer::Visa => Ok("visa".to_string()),
+        utils::CardIssuer::Master => Ok("master".to_string()),
+        utils::CardIssuer::AmericanExpress => Ok("amex".to_string()),
+        utils::CardIssuer::Maestro | utils::CardIssuer::Discover => {
+            Err(errors::ConnectorError::NotImplemented("Card network".to_string()).into())
+        }
+    }
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for MercadopagoPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let payer = MercadopagoPayer {
+            email: item
+                .request
+                .email
+                .clone()
+                .ok_or_else(utils::missing_field_err("email"))?,
+            identification: item
+                .request
+                .payer_document
+                .as_ref()
+                .map(MercadopagoIdentification::from),
+        };
+        let transaction_amount =
+            get_transaction_amount(item.request.amount, item.request.currency)?;
+        match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref ccard) => Ok(Self {
+                transaction_amount,
+                payment_method_id: get_card_payment_method_id(ccard)?,
+                // Card details are exchanged for a card token before authorizing
+                token: Some(item.get_session_token()?),
+                installments: Some(
+                    item.request
+                        .installments
+                        .as_ref()
+                        .map_or(1, |installments| installments.number_of_installments.get()),
+                ),
+                capture: Some(matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                )),
+                three_d_secure_mode: Some(match item.auth_type {
+                    enums::AuthenticationType::ThreeDs => MercadopagoThreeDsMode::Optional,
+                    enums::AuthenticationType::NoThreeDs => MercadopagoThreeDsMode::NotSupported,
+                }),
+                payer,
+                external_reference: item.payment_id.clone(),
+                description: item.description.clone(),
+                callback_url: item.router_return_url.clone(),
+            }),
+            api::PaymentMethodData::BankTransfer(ref bank_transfer_data) => {
+                let payment_method_id = match bank_transfer_data {
+                    api_models::payments::BankTransferData::Pix {} => "pix".to_string(),
+                };
+                Ok(Self {
+                    transaction_amount,
+                    payment_method_id,
+                    token: None,
+                    installments: None,
+                    capture: None,
+                    three_d_secure_mode: None,
+                    payer,
+                    external_reference: item.payment_id.clone(),
+                    description: item.description.clone(),
+                    callback_url: item.router_return_url.clone(),
+                })
+            }
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MercadopagoCaptureRequest {
+    capture: bool,
+    transaction_amount: f64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for MercadopagoCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            capture: true,
+            transaction_amount: get_transaction_amount(
+                item.request
+                    .amount_to_capture
+                    .unwrap_or(item.request.amount),
+                item.request.currency,
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MercadopagoCancelStatus {
+    Cancelled,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MercadopagoCancelRequest {
+    status: MercadopagoCancelStatus,
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for MercadopagoCancelRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(_item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: MercadopagoCancelStatus::Cancelled,
+        })
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MercadopagoPaymentStatus {
+    Pending,
+    Approved,
+    Authorized,
+    InProcess,
+    InMediation,
+    Rejected,
+    Cancelled,
+    Refunded,
+    ChargedBack,
+}
+
+impl From<MercadopagoPaymentStatus> for enums::AttemptStatus {
+    fn from(item: MercadopagoPaymentStatus) -> Self {
+        match item {
+            // Pending payments wait on the customer, either for a 3DS challenge or to pay a PIX code
+            MercadopagoPaymentStatus::Pending => Self::AuthenticationPending,
+            MercadopagoPaymentStatus::InProcess => Self::Pending,
+            MercadopagoPaymentStatus::Authorized => Self::Authorized,
+            MercadopagoPaymentStatus::Approved
+            | MercadopagoPaymentStatus::InMediation
+            | MercadopagoPaymentStatus::Refunded
+            | MercadopagoPaymentStatus::ChargedBack => Self::Charged,
+            MercadopagoPaymentStatus::Rejected => Self::Failure,
+            MercadopagoPaymentStatus::Cancelled => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MercadopagoThreeDsInfo {
+    external_resource_url: Url,
+    creq: String,
+}
+
+/// PIX payment instructions, to be shown to the customer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MercadopagoPixData {
+    pub qr_code: Option<String>,
+    pub qr_code_base64: Option<String>,
+    pub ticket_url: Option<Url>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MercadopagoPointOfInteraction {
+    transaction_data: Option<MercadopagoPixData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MercadopagoPaymentsResponse {
+    id: i64,
+    status: MercadopagoPaymentStatus,
+    status_detail: Option<String>,
+    three_ds_info: Option<MercadopagoThreeDsInfo>,
+    point_of_interaction: Option<MercadopagoPointOfInteraction>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    date_of_expiration: Option<PrimitiveDateTime>,
+}
+
+impl MercadopagoPixData {
+    /// The PIX code, which the customer either scans or copies into the app of their bank
+    fn get_qr_code_information(
+        &self,
+        expires_at: Option<PrimitiveDateTime>,
+    ) -> Option<api_models::payments::QrCodeInformation> {
+        Some(api_models::payments::QrCodeInformation {
+            qr_code_payload: self.qr_code.clone()?,
+            image_data_url: self
+                .qr_code_base64
+                .as_ref()
+                .map(|image| format!("data:image/png;base64,{image}")),
+            expires_at,
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, MercadopagoPaymentsResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            MercadopagoPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        if status == enums::AttemptStatus::Failure {
+            let code = item
+                .response
+                .status_detail
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string());
+            return Ok(Self {
+                status,
+                response: Err(types::ErrorResponse {
+                    message: code.clone(),
+                    code,
+                    reason: None,
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+                ..item.data
+            });
+        }
+
+        let pix_data = item
+            .response
+            .point_of_interaction
+            .and_then(|point_of_interaction| point_of_interaction.transaction_data);
+        let redirection_data = match item.response.three_ds_info {
+            Some(three_ds_info) => Some(services::RedirectForm {
+                endpoint: three_ds_info.external_resource_url.to_string(),
+                method: services::Method::Post,
+                form_fields: std::collections::HashMap::from([(
+                    "creq".to_string(),
+                    three_ds_info.creq,
+                )]),
+            }),
+            None => pix_data
+                .as_ref()
+                .and_then(|pix_data| pix_data.ticket_url.clone())
+                .map(|ticket_url| {
+                    services::RedirectForm::from((ticket_url, services::Method::Get))
+                }),
+        };
+        let connector_metadata = pix_data
+            .and_then(|pix_data| pix_data.get_qr_code_information(item.response.date_of_expiration))
+            .map(|qr_code| {
+                Encode::<'_, api_models::payments::QrCodeInformation>::encode_to_value(&qr_code)
+            })
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.id.to_string(),
+                ),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct MercadopagoRefundRequest {
+    amount: f64,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for MercadopagoRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: get_transaction_amount(item.request.refund_amount, item.request.currency)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MercadopagoRefundStatus {
+    Approved,
+    InProcess,
+    Authorized,
+    Rejected,
+    Cancelled,
+}
+
+impl From<MercadopagoRefundStatus> for enums::RefundStatus {
+    fn from(item: MercadopagoRefundStatus) -> Self {
+        match item {
+            MercadopagoRefundStatus::Approved => Self::Success,
+            MercadopagoRefundStatus::InProcess | MercadopagoRefundStatus::Authorized => {
+                Self::Pending
+            }
+            MercadopagoRefundStatus::Rejected | MercadopagoRefundStatus::Cancelled => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundResponse {
+    id: i64,
+    status: MercadopagoRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id.to_string(),
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MercadopagoWebhookType {
+    Payment,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MercadopagoWebhookData {
+    pub id: String,
+}
+
+/// Notifications only carry the identifier of the object which changed.
+#[derive(Debug, Deserialize)]
+pub struct MercadopagoWebhookBody {
+    #[serde(rename = "type")]
+    pub webhook_type: MercadopagoWebhookType,
+    pub data: MercadopagoWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MercadopagoErrorCause {
+    pub code: serde_json::Value,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MercadopagoErrorResponse {
+    pub message: String,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub cause: Vec<MercadopagoErrorCause>,
+}
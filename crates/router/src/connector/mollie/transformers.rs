@@ -0,0 +1,340 @@
+use api_models::{enums::BankNames, payments::BankRedirectData};
+use common_utils::pii::Email;
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::RouterData,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct MollieAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for MollieAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+// Mollie calls the webhook url given with every payment on each change of its status, which is
+// configured in the metadata of the merchant connector account
+#[derive(Debug, Deserialize)]
+pub struct MollieConnectorMeta {
+    pub webhook_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MollieAmount {
+    pub currency: enums::Currency,
+    pub value: String,
+}
+
+impl MollieAmount {
+    // Every currency of the bank redirect methods on Mollie has two decimals
+    fn new(amount: i64, currency: enums::Currency) -> Self {
+        Self {
+            currency,
+            value: format!("{}.{:02}", amount / 100, amount % 100),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MolliePaymentMethod {
+    Ideal,
+    Sofort,
+    Giropay,
+    Eps,
+    Przelewy24,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MolliePaymentsRequest {
+    amount: MollieAmount,
+    description: String,
+    redirect_url: String,
+    webhook_url: String,
+    method: MolliePaymentMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issuer: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing_email: Option<Secret<String, Email>>,
+    metadata: MollieMetadata,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MollieMetadata {
+    order_id: String,
+}
+
+/// Issuer of the bank of the customer, for which Mollie skips its own bank selection page
+fn get_ideal_issuer(bank_name: &BankNames) -> Result<&'static str, Error> {
+    Ok(match bank_name {
+        BankNames::AbnAmro => "ideal_ABNANL2A",
+        BankNames::AsnBank => "ideal_ASNBNL21",
+        BankNames::Bunq => "ideal_BUNQNL2A",
+        BankNames::Handelsbanken => "ideal_HANDNL2A",
+        BankNames::Ing => "ideal_INGBNL2A",
+        BankNames::Knab => "ideal_KNABNL2H",
+        BankNames::Moneyou => "ideal_MOYONL21",
+        BankNames::Rabobank => "ideal_RABONL2U",
+        BankNames::Regiobank => "ideal_RBRBNL21",
+        BankNames::Revolut => "ideal_REVOLT21",
+        BankNames::SnsBank => "ideal_SNSBNL2A",
+        BankNames::TriodosBank => "ideal_TRIONL2U",
+        BankNames::VanLanschot => "ideal_FVLBNL22",
+        _ => Err(errors::ConnectorError::NotSupported {
+            payment_method: format!("ideal with {bank_name}"),
+            connector: "mollie",
+            payment_experience: api_models::enums::PaymentExperience::RedirectToUrl.to_string(),
+        })?,
+    })
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for MolliePaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let (method, issuer, billing_email) = match &item.request.payment_method_data {
+            api::PaymentMethodData::BankRedirect(bank_redirect_data) => match bank_redirect_data {
+                BankRedirectData::Ideal { bank_name, .. } => (
+                    MolliePaymentMethod::Ideal,
+                    Some(get_ideal_issuer(bank_name)?),
+                    None,
+                ),
+                BankRedirectData::Sofort { .. } => (MolliePaymentMethod::Sofort, None, None),
+                BankRedirectData::Giropay { .. } => (MolliePaymentMethod::Giropay, None, None),
+                BankRedirectData::Eps { .. } => (MolliePaymentMethod::Eps, None, None),
+                BankRedirectData::Przelewy24 { billing_email, .. } => (
+                    MolliePaymentMethod::Przelewy24,
+                    None,
+                    Some(billing_email.clone()),
+                ),
+                BankRedirectData::Blik { .. } | BankRedirectData::Trustly { .. } => Err(
+                    errors::ConnectorError::NotImplemented("Payment method".to_string()),
+                )?,
+            },
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Payment method".to_string(),
+            ))?,
+        };
+        let meta: MollieConnectorMeta = item.to_connector_meta()?;
+
+        Ok(Self {
+            amount: MollieAmount::new(item.request.amount, item.request.currency),
+            description: item
+                .description
+                .clone()
+                .unwrap_or_else(|| item.payment_id.clone()),
+            redirect_url: item.get_return_url()?,
+            webhook_url: meta.webhook_url,
+            method,
+            issuer,
+            billing_email,
+            metadata: MollieMetadata {
+                order_id: item.payment_id.clone(),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MolliePaymentStatus {
+    Open,
+    Pending,
+    Authorized,
+    Paid,
+    Canceled,
+    Expired,
+    Failed,
+}
+
+impl From<MolliePaymentStatus> for enums::AttemptStatus {
+    fn from(item: MolliePaymentStatus) -> Self {
+        match item {
+            MolliePaymentStatus::Open => Self::AuthenticationPending,
+            MolliePaymentStatus::Pending => Self::Pending,
+            MolliePaymentStatus::Authorized => Self::Authorized,
+            MolliePaymentStatus::Paid => Self::Charged,
+            MolliePaymentStatus::Canceled => Self::Voided,
+            MolliePaymentStatus::Expired | MolliePaymentStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MollieLink {
+    pub href: Url,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MolliePaymentLinks {
+    /// The page of the bank, or of Mollie, the customer completes the payment on. Only given while
+    /// the payment is open.
+    pub checkout: Option<MollieLink>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MolliePaymentsResponse {
+    pub id: String,
+    pub status: MolliePaymentStatus,
+    pub amount: MollieAmount,
+    #[serde(rename = "_links")]
+    pub links: MolliePaymentLinks,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, MolliePaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, MolliePaymentsResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data =
+            item.response.links.checkout.map(|checkout| {
+                services::RedirectForm::from((checkout.href, services::Method::Get))
+            });
+
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct MollieRefundRequest {
+    amount: MollieAmount,
+    description: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for MollieRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: MollieAmount::new(item.request.refund_amount, item.request.currency),
+            description: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MollieRefundStatus {
+    Queued,
+    Pending,
+    Processing,
+    Refunded,
+    Failed,
+    Canceled,
+}
+
+impl From<MollieRefundStatus> for enums::RefundStatus {
+    fn from(item: MollieRefundStatus) -> Self {
+        match item {
+            MollieRefundStatus::Queued
+            | MollieRefundStatus::Pending
+            | MollieRefundStatus::Processing => Self::Pending,
+            MollieRefundStatus::Refunded => Self::Success,
+            MollieRefundStatus::Failed | MollieRefundStatus::Canceled => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MollieRefundResponse {
+    pub id: String,
+    pub status: MollieRefundStatus,
+    pub payment_id: String,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, MollieRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, MollieRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+/// Mollie only posts the id of the payment whose status changed, as a form
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MollieWebhookBody {
+    pub id: String,
+}
+
+impl MollieWebhookBody {
+    pub fn try_from_body(body: &[u8]) -> Result<Self, Error> {
+        serde_urlencoded::from_bytes(body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MollieErrorResponse {
+    pub status: u16,
+    pub title: String,
+    pub detail: String,
+    pub field: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_amount_value() {
+        assert_eq!(MollieAmount::new(1000, enums::Currency::EUR).value, "10.00");
+        assert_eq!(MollieAmount::new(1005, enums::Currency::PLN).value, "10.05");
+        assert_eq!(MollieAmount::new(7, enums::Currency::EUR).value, "0.07");
+    }
+
+    #[test]
+    fn test_webhook_body() {
+        let body = MollieWebhookBody::try_from_body(b"id=tr_WDqYK6vllg").unwrap();
+        assert_eq!(body.id, "tr_WDqYK6vllg");
+    }
+}
@@ -73,10 +73,24 @@ impl ConnectorCommon for Multisafepay {
             code: response.error_code.to_string(),
             message: response.error_info,
             reason: None,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Multisafepay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::PayLater,
+        ])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+}
+
 impl api::Payment for Multisafepay {}
 
 impl api::PreVerify for Multisafepay {}
@@ -425,6 +439,65 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Multisafepay {}
+impl api::PayoutCreate for Multisafepay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::PayoutFulfill for Multisafepay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::PayoutCancel for Multisafepay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::PayoutSync for Multisafepay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::RefundCredit for Multisafepay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::ConnectorMandateRevoke for Multisafepay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Multisafepay
+{
+}
+
+impl api::Dispute for Multisafepay {}
+impl api::SubmitEvidence for Multisafepay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Multisafepay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Multisafepay {
     fn get_webhook_object_reference_id(
@@ -16,6 +16,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon, ConnectorCommonExt},
+        storage::enums,
         ErrorResponse, Response, RouterData,
     },
     utils::{self, BytesExt},
@@ -54,6 +55,10 @@ impl ConnectorCommon for Nuvei {
         connectors.nuvei.base_url.as_ref()
     }
 
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("nuveidigital")
+    }
+
     fn get_auth_header(
         &self,
         _auth_type: &types::ConnectorAuthType,
@@ -62,6 +67,12 @@ impl ConnectorCommon for Nuvei {
     }
 }
 
+impl api::ConnectorSpecifications for Nuvei {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::Payment for Nuvei {}
 
 impl api::PreVerify for Nuvei {}
@@ -174,12 +185,18 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
 
     fn get_url(
         &self,
-        _req: &types::PaymentsSyncRouterData,
+        req: &types::PaymentsSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        let endpoint = if nuvei::is_three_ds_completion(req) {
+            "payment.do"
+        } else {
+            "getPaymentStatus.do"
+        };
         Ok(format!(
-            "{}ppp/api/v1/getPaymentStatus.do",
-            api::ConnectorCommon::base_url(self, connectors)
+            "{}ppp/api/v1/{}",
+            api::ConnectorCommon::base_url(self, connectors),
+            endpoint
         ))
     }
 
@@ -187,10 +204,14 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         &self,
         req: &types::PaymentsSyncRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
-        let req_obj = nuvei::NuveiPaymentSyncRequest::try_from(req)?;
-        let req =
+        let req = if nuvei::is_three_ds_completion(req) {
+            let req_obj = nuvei::NuveiPaymentsRequest::try_from(req)?;
+            utils::Encode::<nuvei::NuveiPaymentsRequest>::encode_to_string_of_json(&req_obj)
+        } else {
+            let req_obj = nuvei::NuveiPaymentSyncRequest::try_from(req)?;
             utils::Encode::<nuvei::NuveiPaymentSyncRequest>::encode_to_string_of_json(&req_obj)
-                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        }
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
         Ok(Some(req))
     }
     fn build_request(
@@ -374,6 +395,32 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         )
         .await?;
         router_data.session_token = resp.session_token;
+
+        if router_data.auth_type == enums::AuthenticationType::ThreeDs {
+            let integ: Box<
+                &(dyn ConnectorIntegration<
+                    api::InitPayment,
+                    types::PaymentsAuthorizeData,
+                    types::PaymentsResponseData,
+                > + Send
+                      + Sync
+                      + 'static),
+            > = Box::new(&Self);
+            let init_data = &types::PaymentsInitRouterData::from(&router_data);
+            let init_resp = services::execute_connector_processing_step(
+                app_state,
+                integ,
+                init_data,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await?;
+            if let Err(error) = init_resp.response {
+                return Err(errors::ConnectorError::ProcessingStepFailed(None))
+                    .into_report()
+                    .attach_printable(format!("Nuvei initPayment failed: {}", error.message));
+            }
+            router_data.reference_id = init_resp.reference_id;
+        }
         Ok(())
     }
     fn get_request_body(
@@ -431,6 +478,98 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl
+    ConnectorIntegration<
+        api::InitPayment,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Nuvei
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsInitRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsInitRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ppp/api/v1/initPayment.do",
+            api::ConnectorCommon::base_url(self, connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsInitRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = nuvei::NuveiPaymentsRequest::try_from(req)?;
+        let req = utils::Encode::<nuvei::NuveiPaymentsRequest>::encode_to_string_of_json(&req_obj)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsInitRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsInitType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsInitType::get_headers(self, req, connectors)?)
+                .body(types::PaymentsInitType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsInitRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsInitRouterData, errors::ConnectorError> {
+        let response: nuvei::NuveiPaymentsResponse = res
+            .response
+            .parse_struct("nuvei NuveiPaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(nuvei_init_payment_response=?response);
+        // Only cards supporting 3DS2 are authenticated with the initPayment transaction
+        let three_ds_transaction_id = response
+            .payment_option
+            .as_ref()
+            .and_then(|payment_option| payment_option.card.three_d.as_ref())
+            .filter(|three_d| three_d.v2supported.as_deref() == Some("true"))
+            .and(response.transaction_id.clone());
+        let router_data = types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(types::PaymentsInitRouterData {
+            reference_id: three_ds_transaction_id,
+            ..router_data
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 impl
     ConnectorIntegration<
         api::AuthorizeSessionToken,
@@ -636,27 +775,117 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
 
 impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData> for Nuvei {}
 
+impl api::Payouts for Nuvei {}
+impl api::PayoutCreate for Nuvei {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData> for Nuvei {}
+
+impl api::PayoutFulfill for Nuvei {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Nuvei
+{
+}
+
+impl api::PayoutCancel for Nuvei {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData> for Nuvei {}
+
+impl api::Dispute for Nuvei {}
+impl api::SubmitEvidence for Nuvei {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Nuvei
+{
+}
+
+impl api::PayoutSync for Nuvei {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Nuvei {}
+
+impl api::RefundCredit for Nuvei {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Nuvei {}
+
+impl api::ConnectorMandateRevoke for Nuvei {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Nuvei
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Nuvei {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::Sha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let details: nuvei::NuveiWebhookDetails = serde_urlencoded::from_bytes(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        hex::decode(details.advance_response_checksum)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let details: nuvei::NuveiWebhookDetails = serde_urlencoded::from_bytes(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        Ok(details.get_checksum_message(secret))
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: nuvei::NuveiWebhookDetails = serde_urlencoded::from_bytes(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        details.get_reference_id()
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: nuvei::NuveiWebhookDetails = serde_urlencoded::from_bytes(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        api::IncomingWebhookEvent::try_from(&details)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: nuvei::NuveiWebhookDetails = serde_urlencoded::from_bytes(request.body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        utils::Encode::<nuvei::NuveiPaymentsResponse>::encode_to_value(
+            &nuvei::NuveiPaymentsResponse::from(details),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
@@ -7,15 +7,21 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::{PaymentsCancelRequestData, RouterData},
+    connector::utils::{self, PaymentsCancelRequestData, RouterData},
     consts,
     core::errors,
+    services,
     types::{self, api, storage::enums},
 };
 
+type Error = error_stack::Report<errors::ConnectorError>;
+
 #[derive(Debug, Serialize, Default, Deserialize)]
 pub struct NuveiMeta {
     pub session_token: String,
+    /// Amount details of the payment, sent again when completing a 3DS2 challenge
+    pub amount: Option<String>,
+    pub currency: Option<String>,
 }
 
 #[derive(Debug, Serialize, Default, Deserialize)]
@@ -55,6 +61,7 @@ pub struct NuveiPaymentsRequest {
     pub user_token_id: String,
     pub client_unique_id: String,
     pub transaction_type: TransactionType,
+    pub related_transaction_id: Option<String>,
     pub payment_option: PaymentOption,
     pub checksum: String,
 }
@@ -130,10 +137,17 @@ pub struct Card {
 pub struct ThreeD {
     pub browser_details: Option<BrowserDetails>,
     pub version: Option<String>,
+    #[serde(rename = "notificationURL")]
     pub notification_url: Option<String>,
+    #[serde(rename = "merchantURL")]
     pub merchant_url: Option<String>,
     pub platform_type: Option<String>,
+    pub method_notification_url: Option<String>,
+    pub method_completion_ind: Option<String>,
     pub v2_additional_params: Option<V2AdditionalParams>,
+    pub v2supported: Option<String>,
+    pub acs_url: Option<String>,
+    pub c_req: Option<String>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -151,6 +165,27 @@ pub struct BrowserDetails {
     pub user_agent: String,
 }
 
+impl TryFrom<types::BrowserInformation> for BrowserDetails {
+    type Error = Error;
+    fn try_from(browser_info: types::BrowserInformation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accept_header: browser_info.accept_header,
+            ip: browser_info
+                .ip_address
+                .ok_or_else(utils::missing_field_err("browser_info.ip_address"))?
+                .to_string(),
+            java_enabled: browser_info.java_enabled.to_string().to_uppercase(),
+            java_script_enabled: browser_info.java_script_enabled.to_string().to_uppercase(),
+            language: browser_info.language,
+            color_depth: browser_info.color_depth.to_string(),
+            screen_height: browser_info.screen_height.to_string(),
+            screen_width: browser_info.screen_width.to_string(),
+            time_zone: browser_info.time_zone.to_string(),
+            user_agent: browser_info.user_agent,
+        })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct V2AdditionalParams {
@@ -229,56 +264,170 @@ impl<F, T>
     }
 }
 
+fn get_card_payment_request<F, T>(
+    item: &types::RouterData<F, T, types::PaymentsResponseData>,
+    session_token: String,
+    card: api::Card,
+    amount: String,
+    currency: String,
+) -> Result<NuveiPaymentsRequest, Error> {
+    let connector_meta: NuveiAuthType = NuveiAuthType::try_from(&item.connector_auth_type)?;
+    let merchant_id = connector_meta.merchant_id;
+    let merchant_site_id = connector_meta.merchant_site_id;
+    let client_request_id = item.attempt_id.clone();
+    let time_stamp = date_time::date_as_yyyymmddhhmmss();
+    let merchant_secret = connector_meta.merchant_secret;
+    Ok(NuveiPaymentsRequest {
+        merchant_id: merchant_id.clone(),
+        merchant_site_id: merchant_site_id.clone(),
+        client_request_id: client_request_id.clone(),
+        amount: amount.clone(),
+        currency: currency.clone(),
+        payment_option: PaymentOption {
+            card: Card {
+                card_number: Some(card.card_number),
+                card_holder_name: Some(card.card_holder_name),
+                expiration_month: Some(card.card_exp_month),
+                expiration_year: Some(card.card_exp_year),
+                cvv: Some(card.card_cvc),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        time_stamp: time_stamp.clone(),
+        session_token,
+        checksum: encode_payload(vec![
+            merchant_id,
+            merchant_site_id,
+            client_request_id,
+            amount,
+            currency,
+            time_stamp,
+            merchant_secret,
+        ])?,
+        ..Default::default()
+    })
+}
+
+fn get_three_d_details(item: &types::PaymentsAuthorizeRouterData) -> Result<ThreeD, Error> {
+    let browser_info = item
+        .request
+        .browser_info
+        .clone()
+        .ok_or_else(utils::missing_field_err("browser_info"))?;
+    let notification_url = item.get_return_url()?;
+    Ok(ThreeD {
+        browser_details: Some(BrowserDetails::try_from(browser_info)?),
+        merchant_url: Some(
+            item.return_url
+                .clone()
+                .unwrap_or_else(|| notification_url.clone()),
+        ),
+        notification_url: Some(notification_url),
+        // Browser based authentication, without running the 3DS method beforehand
+        platform_type: Some("02".to_string()),
+        method_completion_ind: Some("U".to_string()),
+        v2_additional_params: Some(V2AdditionalParams {
+            challenge_window_size: "05".to_string(),
+        }),
+        ..Default::default()
+    })
+}
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for NuveiPaymentsRequest {
-    type Error = error_stack::Report<errors::ConnectorError>;
+    type Error = Error;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let connector_meta: NuveiAuthType = NuveiAuthType::try_from(&item.connector_auth_type)?;
-        let merchant_id = connector_meta.merchant_id;
-        let merchant_site_id = connector_meta.merchant_site_id;
-        let client_request_id = item.attempt_id.clone();
-        let time_stamp = date_time::date_as_yyyymmddhhmmss();
-        let merchant_secret = connector_meta.merchant_secret;
         match item.request.payment_method_data.clone() {
-            api::PaymentMethodData::Card(card) => Ok(Self {
-                merchant_id: merchant_id.clone(),
-                merchant_site_id: merchant_site_id.clone(),
-                client_request_id: client_request_id.clone(),
-                amount: item.request.amount.clone().to_string(),
-                currency: item.request.currency.clone().to_string(),
-                transaction_type: item
-                    .request
-                    .capture_method
-                    .map(TransactionType::from)
-                    .unwrap_or_default(),
-                payment_option: PaymentOption {
-                    card: Card {
-                        card_number: Some(card.card_number),
-                        card_holder_name: Some(card.card_holder_name),
-                        expiration_month: Some(card.card_exp_month),
-                        expiration_year: Some(card.card_exp_year),
-                        cvv: Some(card.card_cvc),
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                },
-                time_stamp: time_stamp.clone(),
-                session_token: item.get_session_token()?,
-                checksum: encode_payload(vec![
-                    merchant_id,
-                    merchant_site_id,
-                    client_request_id,
+            api::PaymentMethodData::Card(card) => {
+                let mut request = get_card_payment_request(
+                    item,
+                    item.get_session_token()?,
+                    card,
                     item.request.amount.to_string(),
                     item.request.currency.to_string(),
-                    time_stamp,
-                    merchant_secret,
-                ])?,
-                ..Default::default()
-            }),
+                )?;
+                // The reference id is the initPayment transaction of a card enrolled for 3DS2
+                if item.reference_id.is_some() {
+                    request.payment_option.card.three_d = Some(get_three_d_details(item)?);
+                }
+                Ok(Self {
+                    transaction_type: item
+                        .request
+                        .capture_method
+                        .map(TransactionType::from)
+                        .unwrap_or_default(),
+                    related_transaction_id: item.reference_id.clone(),
+                    ..request
+                })
+            }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
         }
     }
 }
 
+impl TryFrom<&types::PaymentsInitRouterData> for NuveiPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsInitRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data.clone() {
+            api::PaymentMethodData::Card(card) => {
+                let mut request = get_card_payment_request(
+                    item,
+                    item.get_session_token()?,
+                    card,
+                    item.request.amount.to_string(),
+                    item.request.currency.to_string(),
+                )?;
+                request.payment_option.card.three_d = Some(ThreeD {
+                    method_notification_url: Some(item.get_return_url()?),
+                    ..Default::default()
+                });
+                Ok(request)
+            }
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
+/// Whether the sync follows the customer's return from a 3DS2 challenge, in which case the
+/// payment is completed with a second payment request
+pub fn is_three_ds_completion(item: &types::PaymentsSyncRouterData) -> bool {
+    item.status == enums::AttemptStatus::AuthenticationPending
+        && item.request.encoded_data.is_some()
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for NuveiPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let meta = get_nuvei_meta(item)?;
+        let card = match item.request.payment_method_data.clone() {
+            Some(api::PaymentMethodData::Card(card)) => card,
+            _ => return Err(utils::missing_field_err("payment_method_data")()),
+        };
+        let request = get_card_payment_request(
+            item,
+            meta.session_token,
+            card,
+            meta.amount.ok_or_else(utils::missing_field_err("amount"))?,
+            meta.currency
+                .ok_or_else(utils::missing_field_err("currency"))?,
+        )?;
+        Ok(Self {
+            transaction_type: item
+                .request
+                .capture_method
+                .map(TransactionType::from)
+                .unwrap_or_default(),
+            related_transaction_id: Some(
+                item.request
+                    .connector_transaction_id
+                    .get_connector_transaction_id()
+                    .change_context(errors::ConnectorError::MissingConnectorTransactionID)?,
+            ),
+            ..request
+        })
+    }
+}
+
 impl TryFrom<&types::PaymentsCaptureRouterData> for NuveiPaymentFlowRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
@@ -341,10 +490,21 @@ impl TryFrom<&types::RefundExecuteRouterData> for NuveiPaymentFlowRequest {
     }
 }
 
+fn get_nuvei_meta(item: &types::PaymentsSyncRouterData) -> Result<NuveiMeta, Error> {
+    let connector_meta = item
+        .request
+        .connector_meta
+        .clone()
+        .ok_or_else(utils::missing_field_err("connector_meta"))?;
+    serde_json::from_value(connector_meta)
+        .into_report()
+        .change_context(errors::ConnectorError::NoConnectorMetaData)
+}
+
 impl TryFrom<&types::PaymentsSyncRouterData> for NuveiPaymentSyncRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(value: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
-        let meta: NuveiMeta = value.to_connector_meta()?;
+        let meta = get_nuvei_meta(value)?;
         Ok(Self {
             session_token: meta.session_token,
         })
@@ -427,6 +587,7 @@ pub enum NuveiTransactionStatus {
     Approved,
     Declined,
     Error,
+    Redirect,
     #[default]
     Processing,
 }
@@ -441,7 +602,7 @@ impl From<NuveiTransactionStatus> for enums::AttemptStatus {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NuveiPaymentsResponse {
     pub order_id: Option<String>,
@@ -479,6 +640,8 @@ pub enum NuveiTransactionType {
     Credit,
     Settle,
     Void,
+    InitAuth3D,
+    Auth3D,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -508,6 +671,7 @@ fn get_payment_status(response: &NuveiPaymentsResponse) -> enums::AttemptStatus
                     _ => enums::AttemptStatus::Pending,
                 }
             }
+            NuveiTransactionStatus::Redirect => enums::AttemptStatus::AuthenticationPending,
             NuveiTransactionStatus::Processing => enums::AttemptStatus::Pending,
         },
         None => match response.status {
@@ -517,14 +681,78 @@ fn get_payment_status(response: &NuveiPaymentsResponse) -> enums::AttemptStatus
     }
 }
 
+/// Amount details of the request, kept with the session token so that a payment awaiting a
+/// 3DS2 challenge can be completed from the sync
+pub trait NuveiAmountDetails {
+    fn get_amount_details(&self) -> Option<(i64, enums::Currency)> {
+        None
+    }
+}
+
+impl NuveiAmountDetails for types::PaymentsAuthorizeData {
+    fn get_amount_details(&self) -> Option<(i64, enums::Currency)> {
+        Some((self.amount, self.currency))
+    }
+}
+
+impl NuveiAmountDetails for types::PaymentsSyncData {}
+impl NuveiAmountDetails for types::PaymentsCaptureData {}
+impl NuveiAmountDetails for types::PaymentsCancelData {}
+
+fn get_redirection_data(response: &NuveiPaymentsResponse) -> Option<services::RedirectForm> {
+    let three_d = response
+        .payment_option
+        .as_ref()
+        .and_then(|payment_option| payment_option.card.three_d.as_ref())?;
+    match (
+        &response.transaction_status,
+        &three_d.acs_url,
+        &three_d.c_req,
+    ) {
+        (Some(NuveiTransactionStatus::Redirect), Some(acs_url), Some(c_req)) => {
+            Some(services::RedirectForm {
+                endpoint: acs_url.to_string(),
+                method: services::Method::Post,
+                form_fields: std::collections::HashMap::from([(
+                    "creq".to_string(),
+                    c_req.to_string(),
+                )]),
+            })
+        }
+        _ => None,
+    }
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, NuveiPaymentsResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
+where
+    T: NuveiAmountDetails,
 {
     type Error = error_stack::Report<errors::ParsingError>;
     fn try_from(
         item: types::ResponseRouterData<F, NuveiPaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let redirection_data = get_redirection_data(&item.response);
+        // Syncs, captures and voids keep the metadata stored with the payment
+        let connector_metadata = item
+            .data
+            .request
+            .get_amount_details()
+            .map(|(amount, currency)| {
+                serde_json::to_value(NuveiMeta {
+                    session_token: item
+                        .response
+                        .session_token
+                        .clone()
+                        .ok_or(errors::ParsingError)?,
+                    amount: Some(amount.to_string()),
+                    currency: Some(currency.to_string()),
+                })
+                .into_report()
+                .change_context(errors::ParsingError)
+            })
+            .transpose()?;
         Ok(Self {
             status: get_payment_status(&item.response),
             response: match item.response.status {
@@ -540,6 +768,7 @@ impl<F, T>
                         .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                     reason: None,
                     status_code: item.http_code,
+                    unified_code: None,
                 }),
                 _ => match item.response.transaction_status {
                     Some(NuveiTransactionStatus::Error) => Err(types::ErrorResponse {
@@ -554,23 +783,18 @@ impl<F, T>
                             .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                         reason: None,
                         status_code: item.http_code,
+                        unified_code: None,
                     }),
                     _ => Ok(types::PaymentsResponseData::TransactionResponse {
                         resource_id: types::ResponseId::ConnectorTransactionId(
                             item.response.transaction_id.ok_or(errors::ParsingError)?,
                         ),
-                        redirection_data: None,
+                        redirection_data,
                         mandate_reference: None,
-                        connector_metadata: Some(
-                            serde_json::to_value(NuveiMeta {
-                                session_token: item
-                                    .response
-                                    .session_token
-                                    .ok_or(errors::ParsingError)?,
-                            })
-                            .into_report()
-                            .change_context(errors::ParsingError)?,
-                        ),
+                        connector_metadata,
+                        network_txn_id: None,
+                        avs_result: None,
+                        cvv_result: None,
                     }),
                 },
             },
@@ -584,7 +808,7 @@ impl From<NuveiTransactionStatus> for enums::RefundStatus {
         match item {
             NuveiTransactionStatus::Approved => Self::Success,
             NuveiTransactionStatus::Declined | NuveiTransactionStatus::Error => Self::Failure,
-            NuveiTransactionStatus::Processing => Self::Pending,
+            NuveiTransactionStatus::Processing | NuveiTransactionStatus::Redirect => Self::Pending,
         }
     }
 }
@@ -614,6 +838,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, NuveiPaymentsRespons
                     .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                 reason: None,
                 status_code: http_code,
+                unified_code: None,
             }),
             _ => match response.transaction_status {
                 Some(NuveiTransactionStatus::Error) => Err(types::ErrorResponse {
@@ -626,6 +851,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, NuveiPaymentsRespons
                         .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                     reason: None,
                     status_code: http_code,
+                    unified_code: None,
                 }),
                 _ => Ok(types::RefundsResponseData {
                     connector_refund_id: response.transaction_id.ok_or(errors::ParsingError)?,
@@ -665,6 +891,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, NuveiPaymentsResponse>
                     .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                 reason: None,
                 status_code: http_code,
+                unified_code: None,
             }),
             _ => match response.transaction_status {
                 Some(NuveiTransactionStatus::Error) => Err(types::ErrorResponse {
@@ -677,6 +904,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, NuveiPaymentsResponse>
                         .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
                     reason: None,
                     status_code: http_code,
+                    unified_code: None,
                 }),
                 _ => Ok(types::RefundsResponseData {
                     connector_refund_id: response.transaction_id.ok_or(errors::ParsingError)?,
@@ -694,3 +922,143 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, NuveiPaymentsResponse>
 //TODO: Fill the struct with respective fields
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct NuveiErrorResponse {}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NuveiWebhookStatus {
+    Approved,
+    Declined,
+    Error,
+    Pending,
+}
+
+impl From<NuveiWebhookStatus> for NuveiTransactionStatus {
+    fn from(status: NuveiWebhookStatus) -> Self {
+        match status {
+            NuveiWebhookStatus::Approved => Self::Approved,
+            NuveiWebhookStatus::Declined => Self::Declined,
+            NuveiWebhookStatus::Error => Self::Error,
+            NuveiWebhookStatus::Pending => Self::Processing,
+        }
+    }
+}
+
+/// Direct Merchant Notification, posted as form data
+#[derive(Debug, Deserialize)]
+pub struct NuveiWebhookDetails {
+    #[serde(rename = "PPP_TransactionID")]
+    pub ppp_transaction_id: String,
+    #[serde(rename = "TransactionID")]
+    pub transaction_id: Option<String>,
+    #[serde(rename = "relatedTransactionId")]
+    pub related_transaction_id: Option<String>,
+    #[serde(rename = "transactionType")]
+    pub transaction_type: Option<NuveiTransactionType>,
+    #[serde(rename = "Status")]
+    pub status: NuveiWebhookStatus,
+    #[serde(rename = "totalAmount")]
+    pub total_amount: String,
+    pub currency: String,
+    #[serde(rename = "responseTimeStamp")]
+    pub response_time_stamp: String,
+    #[serde(rename = "productId", default)]
+    pub product_id: String,
+    #[serde(rename = "advanceResponseChecksum")]
+    pub advance_response_checksum: String,
+    #[serde(rename = "ErrCode")]
+    pub err_code: Option<String>,
+    #[serde(rename = "Reason")]
+    pub reason: Option<String>,
+}
+
+impl NuveiWebhookDetails {
+    /// The checksum is computed over the merchant secret followed by these values
+    pub fn get_checksum_message(&self, secret: &[u8]) -> Vec<u8> {
+        [
+            secret,
+            self.total_amount.as_bytes(),
+            self.currency.as_bytes(),
+            self.response_time_stamp.as_bytes(),
+            self.ppp_transaction_id.as_bytes(),
+            self.get_status_value().as_bytes(),
+            self.product_id.as_bytes(),
+        ]
+        .concat()
+    }
+
+    fn get_status_value(&self) -> &'static str {
+        match self.status {
+            NuveiWebhookStatus::Approved => "APPROVED",
+            NuveiWebhookStatus::Declined => "DECLINED",
+            NuveiWebhookStatus::Error => "ERROR",
+            NuveiWebhookStatus::Pending => "PENDING",
+        }
+    }
+
+    /// Settlements and voids are notified with their own transaction, which relates to the
+    /// payment transaction known to us
+    pub fn get_reference_id(&self) -> Result<String, Error> {
+        match self.transaction_type {
+            Some(NuveiTransactionType::Settle) | Some(NuveiTransactionType::Void) => {
+                self.related_transaction_id.clone()
+            }
+            _ => self.transaction_id.clone(),
+        }
+        .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+        .into_report()
+    }
+}
+
+impl TryFrom<&NuveiWebhookDetails> for api::IncomingWebhookEvent {
+    type Error = Error;
+    fn try_from(details: &NuveiWebhookDetails) -> Result<Self, Self::Error> {
+        Ok(match (&details.transaction_type, &details.status) {
+            (Some(NuveiTransactionType::Credit), NuveiWebhookStatus::Approved) => {
+                Self::RefundSuccess
+            }
+            (
+                Some(NuveiTransactionType::Credit),
+                NuveiWebhookStatus::Declined | NuveiWebhookStatus::Error,
+            ) => Self::RefundFailure,
+            (
+                Some(NuveiTransactionType::Auth | NuveiTransactionType::Sale),
+                NuveiWebhookStatus::Approved,
+            ) => Self::PaymentIntentSuccess,
+            (
+                Some(NuveiTransactionType::Auth | NuveiTransactionType::Sale),
+                NuveiWebhookStatus::Declined | NuveiWebhookStatus::Error,
+            ) => Self::PaymentIntentFailure,
+            (Some(NuveiTransactionType::Settle), NuveiWebhookStatus::Approved) => {
+                Self::PaymentIntentCaptureSuccess
+            }
+            (
+                Some(NuveiTransactionType::Settle),
+                NuveiWebhookStatus::Declined | NuveiWebhookStatus::Error,
+            ) => Self::PaymentIntentCaptureFailure,
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+        })
+    }
+}
+
+impl From<NuveiWebhookDetails> for NuveiPaymentsResponse {
+    fn from(details: NuveiWebhookDetails) -> Self {
+        let transaction_id = details.get_reference_id().ok();
+        let transaction_status = NuveiTransactionStatus::from(details.status);
+        let (gw_error_code, gw_error_reason) = match transaction_status {
+            NuveiTransactionStatus::Approved | NuveiTransactionStatus::Processing => (None, None),
+            _ => (
+                details.err_code.and_then(|code| code.parse().ok()),
+                details.reason,
+            ),
+        };
+        Self {
+            transaction_status: Some(transaction_status),
+            transaction_type: details.transaction_type,
+            transaction_id,
+            gw_error_code,
+            gw_error_reason,
+            status: NuveiPaymentStatus::Success,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,673 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, RouterData},
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+// The client id and secret are the api key and key1 of the connector account, and are exchanged
+// for an access token in the access token flow. The api secret is the id of the webhook the
+// merchant registered on PayPal.
+pub struct PaypalAuthType {
+    pub(super) webhook_id: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PaypalAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey { api_secret, .. } => Ok(Self {
+                webhook_id: api_secret.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Access Token
+#[derive(Debug, Serialize)]
+pub struct PaypalAuthUpdateRequest {
+    grant_type: &'static str,
+}
+
+impl TryFrom<&types::RefreshTokenRouterData> for PaypalAuthUpdateRequest {
+    type Error = Error;
+    fn try_from(_item: &types::RefreshTokenRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grant_type: "client_credentials",
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalAuthUpdateResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>>
+    for types::RouterData<F, T, types::AccessToken>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AccessToken {
+                token: item.response.access_token,
+                expires: item.response.expires_in,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// The order is kept in the connector metadata, as the transaction id of the payment moves on to
+// the authorization or capture made on it once the customer approves the order
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaypalMeta {
+    pub order_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalAmount {
+    currency_code: enums::Currency,
+    value: String,
+}
+
+impl PaypalAmount {
+    fn new(amount: i64, currency: enums::Currency) -> Result<Self, Error> {
+        Ok(Self {
+            currency_code: currency,
+            value: utils::to_currency_base_unit(amount, currency)?,
+        })
+    }
+}
+
+// Order Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalOrderIntent {
+    Capture,
+    Authorize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalPurchaseUnit {
+    reference_id: String,
+    amount: PaypalAmount,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalUserAction {
+    PayNow,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalExperienceContext {
+    return_url: String,
+    cancel_url: String,
+    user_action: PaypalUserAction,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalWallet {
+    experience_context: PaypalExperienceContext,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalPaymentSource {
+    paypal: PaypalWallet,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalOrderRequest {
+    intent: PaypalOrderIntent,
+    purchase_units: Vec<PaypalPurchaseUnit>,
+    payment_source: PaypalPaymentSource,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaypalOrderRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        if !matches!(
+            item.request.payment_method_data,
+            api::PaymentMethodData::Wallet(api_models::payments::WalletData::PaypalRedirect(_))
+        ) {
+            return Err(errors::ConnectorError::NotImplemented(
+                "Payment methods other than the PayPal wallet".to_string(),
+            )
+            .into());
+        }
+        let intent = match item.request.capture_method {
+            Some(enums::CaptureMethod::Manual) => PaypalOrderIntent::Authorize,
+            _ => PaypalOrderIntent::Capture,
+        };
+        let return_url = item.get_return_url()?;
+        Ok(Self {
+            intent,
+            purchase_units: vec![PaypalPurchaseUnit {
+                reference_id: item.payment_id.clone(),
+                amount: PaypalAmount::new(item.request.amount, item.request.currency)?,
+            }],
+            payment_source: PaypalPaymentSource {
+                paypal: PaypalWallet {
+                    experience_context: PaypalExperienceContext {
+                        return_url: return_url.clone(),
+                        cancel_url: return_url,
+                        user_action: PaypalUserAction::PayNow,
+                    },
+                },
+            },
+        })
+    }
+}
+
+// Order Response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalOrderStatus {
+    Created,
+    Saved,
+    Approved,
+    Voided,
+    Completed,
+    PayerActionRequired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalLink {
+    href: Url,
+    rel: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalCaptureStatus {
+    Completed,
+    Declined,
+    PartiallyRefunded,
+    Pending,
+    Refunded,
+    Failed,
+}
+
+impl From<PaypalCaptureStatus> for enums::AttemptStatus {
+    fn from(item: PaypalCaptureStatus) -> Self {
+        match item {
+            PaypalCaptureStatus::Completed
+            | PaypalCaptureStatus::PartiallyRefunded
+            | PaypalCaptureStatus::Refunded => Self::Charged,
+            PaypalCaptureStatus::Pending => Self::Pending,
+            PaypalCaptureStatus::Declined | PaypalCaptureStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalAuthorizationStatus {
+    Created,
+    Captured,
+    Denied,
+    PartiallyCaptured,
+    Voided,
+    Pending,
+    Expired,
+}
+
+impl From<PaypalAuthorizationStatus> for enums::AttemptStatus {
+    fn from(item: PaypalAuthorizationStatus) -> Self {
+        match item {
+            PaypalAuthorizationStatus::Created => Self::Authorized,
+            PaypalAuthorizationStatus::Captured | PaypalAuthorizationStatus::PartiallyCaptured => {
+                Self::Charged
+            }
+            PaypalAuthorizationStatus::Pending => Self::Pending,
+            PaypalAuthorizationStatus::Denied | PaypalAuthorizationStatus::Expired => Self::Failure,
+            PaypalAuthorizationStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalCapture {
+    pub id: String,
+    pub status: PaypalCaptureStatus,
+    /// Only present on captures, which tells them apart from orders in the responses of syncs
+    pub amount: PaypalAmount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalAuthorization {
+    pub id: String,
+    pub status: PaypalAuthorizationStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaypalPayments {
+    #[serde(default)]
+    captures: Vec<PaypalCapture>,
+    #[serde(default)]
+    authorizations: Vec<PaypalAuthorization>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalPurchaseUnitResponse {
+    payments: Option<PaypalPayments>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalOrderResponse {
+    pub id: String,
+    pub status: PaypalOrderStatus,
+    #[serde(default)]
+    links: Vec<PaypalLink>,
+    #[serde(default)]
+    purchase_units: Vec<PaypalPurchaseUnitResponse>,
+}
+
+impl PaypalOrderResponse {
+    fn get_payer_action_url(&self) -> Option<Url> {
+        self.links
+            .iter()
+            .find(|link| link.rel == "payer-action" || link.rel == "approve")
+            .map(|link| link.href.clone())
+    }
+
+    /// The latest capture or authorization made on the order, which decides the status of the
+    /// payment once the customer approved it
+    fn get_status_and_transaction_id(&self) -> (enums::AttemptStatus, String) {
+        let payments = self
+            .purchase_units
+            .first()
+            .and_then(|purchase_unit| purchase_unit.payments.as_ref());
+        if let Some(capture) = payments.and_then(|payments| payments.captures.last()) {
+            return (capture.status.clone().into(), capture.id.clone());
+        }
+        if let Some(authorization) = payments.and_then(|payments| payments.authorizations.last()) {
+            return (
+                authorization.status.clone().into(),
+                authorization.id.clone(),
+            );
+        }
+        let status = match self.status {
+            PaypalOrderStatus::Created
+            | PaypalOrderStatus::Saved
+            | PaypalOrderStatus::Approved
+            | PaypalOrderStatus::PayerActionRequired => enums::AttemptStatus::AuthenticationPending,
+            PaypalOrderStatus::Voided => enums::AttemptStatus::Failure,
+            PaypalOrderStatus::Completed => enums::AttemptStatus::Pending,
+        };
+        (status, self.id.clone())
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Authorize,
+            PaypalOrderResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Authorize,
+            PaypalOrderResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirect_url = item
+            .response
+            .get_payer_action_url()
+            .ok_or_else(utils::missing_field_err("links.payer-action"))?;
+        let connector_metadata = serde_json::to_value(PaypalMeta {
+            order_id: item.response.id.clone(),
+        })
+        .ok();
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: Some(services::RedirectForm::from((
+                    redirect_url,
+                    services::Method::Get,
+                ))),
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Customers are sent back to the return url with the order id as the token, and the id of the
+// payer once they approved the order
+#[derive(Debug, Deserialize)]
+pub struct PaypalRedirectResponse {
+    #[serde(rename = "PayerID")]
+    payer_id: Option<String>,
+}
+
+pub fn get_order_id(item: &types::PaymentsSyncRouterData) -> Result<String, Error> {
+    match item.request.connector_meta.clone() {
+        Some(meta) => serde_json::from_value::<PaypalMeta>(meta)
+            .map(|meta| meta.order_id)
+            .map_err(|_| errors::ConnectorError::NoConnectorMetaData.into()),
+        None => item
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .map_err(|_| errors::ConnectorError::MissingConnectorTransactionID.into()),
+    }
+}
+
+/// Whether the customer came back from PayPal having approved the order, which is then to be
+/// captured or authorized
+pub fn is_order_approved(item: &types::PaymentsSyncRouterData) -> bool {
+    item.status == enums::AttemptStatus::AuthenticationPending
+        && item
+            .request
+            .encoded_data
+            .as_ref()
+            .and_then(|data| serde_urlencoded::from_str::<PaypalRedirectResponse>(data).ok())
+            .map_or(false, |redirect| redirect.payer_id.is_some())
+}
+
+// Sync Response
+// Webhooks carry the capture rather than the order, and are handled as sync responses
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PaypalSyncResponse {
+    Capture(PaypalCapture),
+    Order(PaypalOrderResponse),
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaypalSyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalSyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let (status, transaction_id) = match item.response {
+            PaypalSyncResponse::Capture(capture) => (capture.status.into(), capture.id),
+            PaypalSyncResponse::Order(order) => order.get_status_and_transaction_id(),
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(transaction_id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Capture Request
+#[derive(Debug, Serialize)]
+pub struct PaypalCaptureRequest {
+    amount: PaypalAmount,
+    final_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for PaypalCaptureRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: PaypalAmount::new(
+                item.request
+                    .amount_to_capture
+                    .unwrap_or(item.request.amount),
+                item.request.currency,
+            )?,
+            final_capture: true,
+        })
+    }
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaypalCapture, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalCapture, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Void Response
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaypalAuthorization, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalAuthorization, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+pub struct PaypalRefundRequest {
+    amount: PaypalAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note_to_payer: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaypalRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: PaypalAmount::new(item.request.refund_amount, item.request.currency)?,
+            note_to_payer: item.request.reason.clone(),
+        })
+    }
+}
+
+// Refund Response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalRefundStatus {
+    Cancelled,
+    Failed,
+    Pending,
+    Completed,
+}
+
+impl From<PaypalRefundStatus> for enums::RefundStatus {
+    fn from(item: PaypalRefundStatus) -> Self {
+        match item {
+            PaypalRefundStatus::Completed => Self::Success,
+            PaypalRefundStatus::Pending => Self::Pending,
+            PaypalRefundStatus::Cancelled | PaypalRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalRefundResponse {
+    id: String,
+    status: PaypalRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, PaypalRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, PaypalRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+pub enum PaypalWebhookEventType {
+    #[serde(rename = "PAYMENT.CAPTURE.COMPLETED")]
+    PaymentCaptureCompleted,
+    #[serde(rename = "PAYMENT.CAPTURE.DENIED")]
+    PaymentCaptureDenied,
+    #[serde(rename = "PAYMENT.CAPTURE.DECLINED")]
+    PaymentCaptureDeclined,
+    #[serde(rename = "PAYMENT.CAPTURE.REFUNDED")]
+    PaymentCaptureRefunded,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookResource {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookBody {
+    pub event_type: PaypalWebhookEventType,
+    pub resource: PaypalWebhookResource,
+}
+
+/// The resource of payment events is the capture, which is handled as the response of a sync
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookResourceObject {
+    pub resource: serde_json::Value,
+}
+
+impl From<PaypalWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event: PaypalWebhookEventType) -> Self {
+        match event {
+            PaypalWebhookEventType::PaymentCaptureCompleted => Self::PaymentIntentSuccess,
+            PaypalWebhookEventType::PaymentCaptureDenied
+            | PaypalWebhookEventType::PaymentCaptureDeclined => Self::PaymentIntentFailure,
+            PaypalWebhookEventType::PaymentCaptureRefunded => Self::RefundSuccess,
+            PaypalWebhookEventType::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+/// The signed message is the transmission id and time, the id of the webhook the merchant
+/// registered on PayPal and the CRC32 checksum of the body, separated by pipes
+pub fn get_signature_message(
+    transmission_id: &str,
+    transmission_time: &str,
+    webhook_id: &str,
+    body: &[u8],
+) -> String {
+    format!(
+        "{transmission_id}|{transmission_time}|{webhook_id}|{}",
+        crc32(body)
+    )
+}
+
+/// CRC-32 as per ISO 3309, the checksum PayPal takes of webhook bodies
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorDetails {
+    pub issue: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorResponse {
+    pub name: String,
+    pub message: String,
+    #[serde(default)]
+    pub details: Vec<PaypalErrorDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalAccessTokenErrorResponse {
+    pub error: String,
+    pub error_description: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_signature_message() {
+        assert_eq!(
+            get_signature_message(
+                "69cd13f0-d67a-11e5-baa3-778b53f4ae55",
+                "2016-02-18T20:01:35Z",
+                "1JE4291016473214C",
+                b"123456789"
+            ),
+            "69cd13f0-d67a-11e5-baa3-778b53f4ae55|2016-02-18T20:01:35Z|1JE4291016473214C|3421780262"
+        );
+    }
+}
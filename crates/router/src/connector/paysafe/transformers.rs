@@ -0,0 +1,705 @@
+use api_models::payments as api_payments;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, AddressDetailsData, PaymentsAuthorizeRequestData, RouterData},
+    consts,
+    core::errors,
+    pii, services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct PaysafeAuthType {
+    pub(super) username: String,
+    pub(super) password: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PaysafeAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                username: api_key.to_string(),
+                password: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Payment Handle Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafeTransactionType {
+    Payment,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafePaymentType {
+    Card,
+    Paysafecard,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaysafeCardExpiry {
+    month: Secret<String>,
+    year: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafeCard {
+    card_num: Secret<String, pii::CardNumber>,
+    card_expiry: PaysafeCardExpiry,
+    cvv: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafecardDetails {
+    consumer_id: Secret<String, pii::Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaysafeLinkType {
+    Default,
+    OnCompleted,
+    OnFailed,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaysafeReturnLink {
+    rel: PaysafeLinkType,
+    href: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaysafeBillingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    street: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<Secret<String>>,
+    country: String,
+    zip: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafePaymentHandleRequest {
+    merchant_ref_num: String,
+    transaction_type: PaysafeTransactionType,
+    payment_type: PaysafePaymentType,
+    amount: i64,
+    currency_code: enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<PaysafeCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paysafecard: Option<PaysafecardDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing_details: Option<PaysafeBillingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    return_links: Option<Vec<PaysafeReturnLink>>,
+}
+
+fn get_billing_details<F, Req, Res>(
+    item: &types::RouterData<F, Req, Res>,
+) -> Result<Option<PaysafeBillingDetails>, Error> {
+    item.address
+        .billing
+        .as_ref()
+        .and_then(|billing| billing.address.as_ref())
+        .map(|address| {
+            Ok(PaysafeBillingDetails {
+                street: address.line1.clone(),
+                city: address.city.clone(),
+                state: address.state.clone(),
+                country: address.get_country()?.to_owned(),
+                zip: address.get_zip()?.to_owned(),
+            })
+        })
+        .transpose()
+}
+
+// Cards are turned into a payable payment handle before the payment is processed
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for PaysafePaymentHandleRequest {
+    type Error = Error;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        let card = match &item.request.payment_method_data {
+            api::PaymentMethodData::Card(ccard) => PaysafeCard {
+                card_num: ccard.card_number.clone(),
+                card_expiry: PaysafeCardExpiry {
+                    month: ccard.card_exp_month.clone(),
+                    year: ccard.card_exp_year.clone(),
+                },
+                cvv: ccard.card_cvc.clone(),
+                holder_name: ccard.card_holder_name.clone(),
+            },
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment Method".to_string()).into(),
+                )
+            }
+        };
+        Ok(Self {
+            merchant_ref_num: item.payment_id.clone(),
+            transaction_type: PaysafeTransactionType::Payment,
+            payment_type: PaysafePaymentType::Card,
+            amount: item.request.amount,
+            currency_code: item.request.currency,
+            card: Some(card),
+            paysafecard: None,
+            billing_details: get_billing_details(item)?,
+            return_links: None,
+        })
+    }
+}
+
+// Paysafecard vouchers are redeemed on the Paysafecard page, the payment handle becomes
+// payable once the customer is back
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaysafePaymentHandleRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        if !matches!(
+            item.request.payment_method_data,
+            api::PaymentMethodData::Voucher(api_payments::VoucherData::Paysafecard {})
+        ) {
+            return Err(
+                errors::ConnectorError::NotImplemented("Payment Method".to_string()).into(),
+            );
+        }
+        let return_url = item.get_return_url()?;
+        Ok(Self {
+            merchant_ref_num: item.payment_id.clone(),
+            transaction_type: PaysafeTransactionType::Payment,
+            payment_type: PaysafePaymentType::Paysafecard,
+            amount: item.request.amount,
+            currency_code: item.request.currency,
+            card: None,
+            paysafecard: Some(PaysafecardDetails {
+                consumer_id: item.request.get_email()?,
+            }),
+            billing_details: None,
+            return_links: Some(vec![
+                PaysafeReturnLink {
+                    rel: PaysafeLinkType::Default,
+                    href: return_url.clone(),
+                },
+                PaysafeReturnLink {
+                    rel: PaysafeLinkType::OnCompleted,
+                    href: return_url.clone(),
+                },
+                PaysafeReturnLink {
+                    rel: PaysafeLinkType::OnFailed,
+                    href: return_url,
+                },
+            ]),
+        })
+    }
+}
+
+// Payment Handle Response
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafePaymentHandleStatus {
+    Initiated,
+    Payable,
+    Processing,
+    Completed,
+    Failed,
+    Expired,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaysafeLink {
+    rel: String,
+    href: Url,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafePaymentHandleResponse {
+    id: String,
+    payment_handle_token: String,
+    status: PaysafePaymentHandleStatus,
+    #[serde(default)]
+    links: Vec<PaysafeLink>,
+}
+
+// The payment handle is kept on the attempt, as the payment is only processed once the
+// customer is back from the redirect
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafePaymentHandleMeta {
+    pub payment_handle_token: String,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub settle_with_auth: bool,
+}
+
+pub fn get_payment_handle_meta(
+    item: &types::PaymentsSyncRouterData,
+) -> Option<PaysafePaymentHandleMeta> {
+    item.request
+        .connector_meta
+        .clone()
+        .and_then(|meta| serde_json::from_value(meta).ok())
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::AuthorizeSessionToken,
+            PaysafePaymentHandleResponse,
+            types::AuthorizeSessionTokenData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeSessionTokenRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::AuthorizeSessionToken,
+            PaysafePaymentHandleResponse,
+            types::AuthorizeSessionTokenData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        if item.response.status != PaysafePaymentHandleStatus::Payable {
+            return Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(types::ErrorResponse {
+                    code: consts::NO_ERROR_CODE.to_string(),
+                    message: "Payment handle is not payable".to_string(),
+                    reason: None,
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+                ..item.data
+            });
+        }
+        let payment_handle_token = item.response.payment_handle_token;
+        Ok(Self {
+            session_token: Some(payment_handle_token.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: payment_handle_token,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Authorize,
+            PaysafePaymentHandleResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Authorize,
+            PaysafePaymentHandleResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item
+            .response
+            .links
+            .into_iter()
+            .find(|link| link.rel == "redirect_payment")
+            .map(|link| services::RedirectForm::from((link.href, services::Method::Get)));
+        let status = match item.response.status {
+            PaysafePaymentHandleStatus::Failed | PaysafePaymentHandleStatus::Expired => {
+                enums::AttemptStatus::Failure
+            }
+            _ => enums::AttemptStatus::AuthenticationPending,
+        };
+        let connector_metadata = serde_json::to_value(PaysafePaymentHandleMeta {
+            payment_handle_token: item.response.payment_handle_token,
+            amount: item.data.request.amount,
+            currency: item.data.request.currency,
+            settle_with_auth: item.data.request.is_auto_capture(),
+        })
+        .ok();
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Payment Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafePaymentsRequest {
+    merchant_ref_num: String,
+    amount: i64,
+    currency_code: enums::Currency,
+    payment_handle_token: String,
+    settle_with_auth: bool,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaysafePaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merchant_ref_num: item.payment_id.clone(),
+            amount: item.request.amount,
+            currency_code: item.request.currency,
+            payment_handle_token: item
+                .session_token
+                .clone()
+                .ok_or_else(utils::missing_field_err("payment_handle_token"))?,
+            settle_with_auth: item.request.is_auto_capture(),
+        })
+    }
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for PaysafePaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let meta =
+            get_payment_handle_meta(item).ok_or_else(utils::missing_field_err("connector_meta"))?;
+        Ok(Self {
+            merchant_ref_num: item.payment_id.clone(),
+            amount: meta.amount,
+            currency_code: meta.currency,
+            payment_handle_token: meta.payment_handle_token,
+            settle_with_auth: meta.settle_with_auth,
+        })
+    }
+}
+
+// Payment Response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafePaymentStatus {
+    Received,
+    Completed,
+    Held,
+    Failed,
+    Cancelled,
+    #[default]
+    Pending,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafePaymentsResponse {
+    pub id: String,
+    pub status: PaysafePaymentStatus,
+    #[serde(default)]
+    pub settle_with_auth: bool,
+    pub available_to_settle: Option<i64>,
+}
+
+impl From<&PaysafePaymentsResponse> for enums::AttemptStatus {
+    fn from(item: &PaysafePaymentsResponse) -> Self {
+        match item.status {
+            PaysafePaymentStatus::Completed => {
+                // Authorizations stay completed once settled, only the amount left to
+                // settle tells them apart
+                if item.settle_with_auth || item.available_to_settle == Some(0) {
+                    Self::Charged
+                } else {
+                    Self::Authorized
+                }
+            }
+            PaysafePaymentStatus::Received
+            | PaysafePaymentStatus::Held
+            | PaysafePaymentStatus::Pending => Self::Pending,
+            PaysafePaymentStatus::Failed => Self::Failure,
+            PaysafePaymentStatus::Cancelled => Self::Voided,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaysafePaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaysafePaymentsResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(&item.response),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Settlement, Void Authorization and Refund Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafeOperationRequest {
+    merchant_ref_num: String,
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for PaysafeOperationRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merchant_ref_num: item.attempt_id.clone(),
+            amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+        })
+    }
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for PaysafeOperationRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merchant_ref_num: item.attempt_id.clone(),
+            amount: item
+                .request
+                .amount
+                .ok_or_else(utils::missing_field_err("amount"))?,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaysafeOperationRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merchant_ref_num: item.request.refund_id.clone(),
+            amount: item.request.refund_amount,
+        })
+    }
+}
+
+// Settlement, Void Authorization and Refund Response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafeOperationStatus {
+    Received,
+    #[default]
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaysafeOperationResponse {
+    pub id: String,
+    pub status: PaysafeOperationStatus,
+}
+
+// Refunds are made against the settlement, which is only a separate resource for payments
+// that were not settled along with the authorization
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafeSettlementMeta {
+    pub settlement_id: String,
+}
+
+pub fn get_settlement_id(item: &types::RefundsData) -> String {
+    item.connector_metadata
+        .clone()
+        .and_then(|meta| serde_json::from_value::<PaysafeSettlementMeta>(meta).ok())
+        .map(|meta| meta.settlement_id)
+        .unwrap_or_else(|| item.connector_transaction_id.clone())
+}
+
+impl TryFrom<types::PaymentsCaptureResponseRouterData<PaysafeOperationResponse>>
+    for types::PaymentsCaptureRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsCaptureResponseRouterData<PaysafeOperationResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            PaysafeOperationStatus::Completed => enums::AttemptStatus::Charged,
+            PaysafeOperationStatus::Received
+            | PaysafeOperationStatus::Pending
+            | PaysafeOperationStatus::Processing => enums::AttemptStatus::CaptureInitiated,
+            PaysafeOperationStatus::Failed | PaysafeOperationStatus::Cancelled => {
+                enums::AttemptStatus::CaptureFailed
+            }
+        };
+        let connector_metadata = serde_json::to_value(PaysafeSettlementMeta {
+            settlement_id: item.response.id,
+        })
+        .ok();
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::PaymentsCancelResponseRouterData<PaysafeOperationResponse>>
+    for types::PaymentsCancelRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsCancelResponseRouterData<PaysafeOperationResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            PaysafeOperationStatus::Completed => enums::AttemptStatus::Voided,
+            PaysafeOperationStatus::Received
+            | PaysafeOperationStatus::Pending
+            | PaysafeOperationStatus::Processing => enums::AttemptStatus::VoidInitiated,
+            PaysafeOperationStatus::Failed | PaysafeOperationStatus::Cancelled => {
+                enums::AttemptStatus::VoidFailed
+            }
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl From<PaysafeOperationStatus> for enums::RefundStatus {
+    fn from(item: PaysafeOperationStatus) -> Self {
+        match item {
+            PaysafeOperationStatus::Completed => Self::Success,
+            PaysafeOperationStatus::Received
+            | PaysafeOperationStatus::Pending
+            | PaysafeOperationStatus::Processing => Self::Pending,
+            PaysafeOperationStatus::Failed | PaysafeOperationStatus::Cancelled => Self::Failure,
+        }
+    }
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, PaysafeOperationResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, PaysafeOperationResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaysafeWebhookEventType {
+    PaymentCompleted,
+    PaymentFailed,
+    RefundCompleted,
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaysafeWebhookPayload {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafeWebhookBody {
+    pub event_type: PaysafeWebhookEventType,
+    pub payload: serde_json::Value,
+}
+
+impl TryFrom<PaysafeWebhookEventType> for api::IncomingWebhookEvent {
+    type Error = Error;
+    fn try_from(event: PaysafeWebhookEventType) -> Result<Self, Self::Error> {
+        match event {
+            PaysafeWebhookEventType::PaymentCompleted => Ok(Self::PaymentIntentSuccess),
+            PaysafeWebhookEventType::PaymentFailed => Ok(Self::PaymentIntentFailure),
+            PaysafeWebhookEventType::RefundCompleted => Ok(Self::RefundSuccess),
+            PaysafeWebhookEventType::RefundFailed => Ok(Self::RefundFailure),
+            PaysafeWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+        }
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaysafeError {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub details: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaysafeErrorResponse {
+    pub error: PaysafeError,
+}
+
+impl PaysafeError {
+    pub fn get_reason(&self) -> Option<String> {
+        (!self.details.is_empty()).then(|| self.details.join(", "))
+    }
+}
@@ -0,0 +1,678 @@
+use std::collections::HashMap;
+
+use api_models::payments::UpiData;
+use base64::Engine;
+use common_utils::{
+    crypto::{self, EncodeMessage, GenerateDigest},
+    date_time,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils,
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Fixed initialization vector of the Paytm checksum
+const CHECKSUM_IV: &[u8] = b"@@@@&&&&####$$$$";
+const CHECKSUM_SALT_LENGTH: usize = 4;
+
+// Auth Struct
+pub struct PaytmAuthType {
+    pub(super) merchant_id: String,
+    pub(super) website_name: String,
+    pub(super) merchant_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PaytmAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                merchant_id: api_key.to_string(),
+                website_name: key1.to_string(),
+                merchant_key: api_secret.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// The checksum is the SHA-256 hash of the request body and a random salt, followed by the salt,
+/// encrypted with the merchant key
+fn generate_checksum(body: &str, merchant_key: &str) -> Result<String, Error> {
+    let salt = crypto::generate_cryptographically_secure_random_string(CHECKSUM_SALT_LENGTH);
+    let hash = crypto::Sha256
+        .generate_digest(format!("{body}|{salt}").as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    let (checksum, _) = crypto::CbcAes128 {
+        iv: CHECKSUM_IV.to_vec(),
+    }
+    .encode_message(
+        merchant_key.as_bytes(),
+        format!("{}{}", hex::encode(hash), salt).as_bytes(),
+    )
+    .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(consts::BASE64_ENGINE.encode(checksum))
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaytmSignatureHead {
+    signature: String,
+}
+
+/// Requests made with the merchant credentials carry the checksum of their body in the head
+#[derive(Debug, Serialize)]
+pub struct PaytmSignedRequest<T> {
+    body: T,
+    head: PaytmSignatureHead,
+}
+
+impl<T: Serialize> PaytmSignedRequest<T> {
+    fn try_new(body: T, merchant_key: &str) -> Result<Self, Error> {
+        let encoded_body = serde_json::to_string(&body)
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Self {
+            head: PaytmSignatureHead {
+                signature: generate_checksum(&encoded_body, merchant_key)?,
+            },
+            body,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub enum PaytmResultStatus {
+    #[serde(rename = "S")]
+    Success,
+    #[serde(rename = "F")]
+    Failure,
+    #[serde(rename = "U")]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmResultInfo {
+    result_status: PaytmResultStatus,
+    result_code: String,
+    result_msg: String,
+}
+
+impl PaytmResultInfo {
+    fn get_error_response(self, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: self.result_code,
+            message: self.result_msg,
+            reason: None,
+            status_code,
+            unified_code: None,
+        }
+    }
+}
+
+// Initiate Transaction Request
+#[derive(Debug, Serialize)]
+pub struct PaytmAmount {
+    value: String,
+    currency: enums::Currency,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmUserInfo {
+    cust_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub enum PaytmRequestType {
+    Payment,
+    #[serde(rename = "NATIVE")]
+    Native,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmInitiateTransactionBody {
+    request_type: PaytmRequestType,
+    mid: String,
+    website_name: String,
+    order_id: String,
+    txn_amount: PaytmAmount,
+    user_info: PaytmUserInfo,
+    callback_url: String,
+}
+
+pub type PaytmInitiateTransactionRequest = PaytmSignedRequest<PaytmInitiateTransactionBody>;
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for PaytmInitiateTransactionRequest {
+    type Error = Error;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+        let body = PaytmInitiateTransactionBody {
+            request_type: PaytmRequestType::Payment,
+            mid: auth.merchant_id,
+            website_name: auth.website_name,
+            order_id: item.attempt_id.clone(),
+            txn_amount: PaytmAmount {
+                value: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+                currency: item.request.currency,
+            },
+            user_info: PaytmUserInfo {
+                cust_id: item.payment_id.clone(),
+            },
+            callback_url: item
+                .router_return_url
+                .clone()
+                .ok_or_else(utils::missing_field_err("return_url"))?,
+        };
+        Self::try_new(body, &auth.merchant_key)
+    }
+}
+
+// Initiate Transaction Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmInitiateTransactionResponseBody {
+    result_info: PaytmResultInfo,
+    txn_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaytmInitiateTransactionResponse {
+    body: PaytmInitiateTransactionResponseBody,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PaytmInitiateTransactionResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaytmInitiateTransactionResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let body = item.response.body;
+        match body.txn_token {
+            Some(txn_token) if body.result_info.result_status == PaytmResultStatus::Success => {
+                Ok(Self {
+                    session_token: Some(txn_token.clone()),
+                    response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                        session_token: txn_token,
+                    }),
+                    ..item.data
+                })
+            }
+            _ => Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(body.result_info.get_error_response(item.http_code)),
+                ..item.data
+            }),
+        }
+    }
+}
+
+/// The Paytm wallet is paid on the payment page of Paytm, opened with the transaction token
+pub fn get_payment_page_redirect(
+    item: &types::PaymentsAuthorizeRouterData,
+    base_url: &str,
+) -> Result<types::PaymentsResponseData, Error> {
+    let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+    let txn_token = item
+        .session_token
+        .clone()
+        .ok_or_else(utils::missing_field_err("txn_token"))?;
+    let endpoint = format!(
+        "{}theia/api/v1/showPaymentPage?mid={}&orderId={}",
+        base_url, auth.merchant_id, item.attempt_id
+    );
+    Ok(types::PaymentsResponseData::TransactionResponse {
+        resource_id: types::ResponseId::ConnectorTransactionId(item.attempt_id.clone()),
+        redirection_data: Some(services::RedirectForm {
+            endpoint,
+            method: services::Method::Post,
+            form_fields: HashMap::from([
+                ("mid".to_string(), auth.merchant_id),
+                ("orderId".to_string(), item.attempt_id.clone()),
+                ("txnToken".to_string(), txn_token),
+            ]),
+        }),
+        mandate_reference: None,
+        connector_metadata: None,
+        network_txn_id: None,
+        avs_result: None,
+        cvv_result: None,
+    })
+}
+
+// Process Transaction Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmTokenHead {
+    version: String,
+    request_timestamp: String,
+    channel_id: String,
+    txn_token: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaytmPaymentMode {
+    Upi,
+    UpiIntent,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmProcessTransactionBody {
+    request_type: PaytmRequestType,
+    mid: String,
+    order_id: String,
+    payment_mode: PaytmPaymentMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer_account: Option<Secret<String>>,
+}
+
+/// Payments are processed against the transaction token, which takes the place of the checksum
+#[derive(Debug, Serialize)]
+pub struct PaytmPaymentsRequest {
+    head: PaytmTokenHead,
+    body: PaytmProcessTransactionBody,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaytmPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+        let (payment_mode, payer_account) = match &item.request.payment_method_data {
+            api::PaymentMethodData::Upi(UpiData::UpiCollect { vpa_id }) => {
+                (PaytmPaymentMode::Upi, Some(vpa_id.clone()))
+            }
+            api::PaymentMethodData::Upi(UpiData::UpiIntent {}) => {
+                (PaytmPaymentMode::UpiIntent, None)
+            }
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment Method".to_string()).into(),
+                )
+            }
+        };
+        Ok(Self {
+            head: PaytmTokenHead {
+                version: "v1".to_string(),
+                request_timestamp: date_time::now_unix_timestamp().to_string(),
+                channel_id: "WEB".to_string(),
+                // The transaction is initiated before processing it, see `execute_pretasks`
+                txn_token: item
+                    .session_token
+                    .clone()
+                    .ok_or_else(utils::missing_field_err("txn_token"))?,
+            },
+            body: PaytmProcessTransactionBody {
+                request_type: PaytmRequestType::Native,
+                mid: auth.merchant_id,
+                order_id: item.attempt_id.clone(),
+                payment_mode,
+                payer_account,
+            },
+        })
+    }
+}
+
+// Process Transaction Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmDeepLinkInfo {
+    deep_link: Url,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmProcessTransactionResponseBody {
+    result_info: PaytmResultInfo,
+    deep_link_info: Option<PaytmDeepLinkInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaytmPaymentsResponse {
+    body: PaytmProcessTransactionResponseBody,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaytmPaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaytmPaymentsResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let body = item.response.body;
+        if body.result_info.result_status == PaytmResultStatus::Failure {
+            return Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(body.result_info.get_error_response(item.http_code)),
+                ..item.data
+            });
+        }
+        // Collect requests are approved by the customer in their UPI app, intents are opened
+        // through the deep link, either way the outcome is picked up by syncing the payment
+        let redirection_data = body
+            .deep_link_info
+            .map(|info| services::RedirectForm::from((info.deep_link, services::Method::Get)));
+        let status = if redirection_data.is_some() {
+            enums::AttemptStatus::AuthenticationPending
+        } else {
+            enums::AttemptStatus::Pending
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.attempt_id.clone(),
+                ),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Transaction Status Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmSyncBody {
+    mid: String,
+    order_id: String,
+}
+
+pub type PaytmSyncRequest = PaytmSignedRequest<PaytmSyncBody>;
+
+impl TryFrom<&types::PaymentsSyncRouterData> for PaytmSyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+        let body = PaytmSyncBody {
+            mid: auth.merchant_id,
+            order_id: item
+                .request
+                .connector_transaction_id
+                .get_connector_transaction_id()
+                .change_context(errors::ConnectorError::MissingConnectorTransactionID)?,
+        };
+        Self::try_new(body, &auth.merchant_key)
+    }
+}
+
+// Transaction Status Response
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaytmTransactionStatus {
+    TxnSuccess,
+    TxnFailure,
+    Pending,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmSyncResultInfo {
+    result_status: PaytmTransactionStatus,
+    result_code: String,
+    result_msg: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmSyncResponseBody {
+    result_info: PaytmSyncResultInfo,
+    order_id: String,
+    txn_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaytmSyncResponse {
+    body: PaytmSyncResponseBody,
+}
+
+/// Refunds are issued against both the order and the transaction id assigned by Paytm
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaytmPaymentsMeta {
+    pub txn_id: String,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaytmSyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaytmSyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let body = item.response.body;
+        let (status, response) = match body.result_info.result_status {
+            PaytmTransactionStatus::TxnFailure => (
+                enums::AttemptStatus::Failure,
+                Err(types::ErrorResponse {
+                    code: body.result_info.result_code,
+                    message: body.result_info.result_msg,
+                    reason: None,
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+            ),
+            transaction_status => {
+                let status = match transaction_status {
+                    PaytmTransactionStatus::TxnSuccess => enums::AttemptStatus::Charged,
+                    // The customer may still be on the payment page or in their UPI app
+                    _ if item.data.status == enums::AttemptStatus::AuthenticationPending => {
+                        enums::AttemptStatus::AuthenticationPending
+                    }
+                    _ => enums::AttemptStatus::Pending,
+                };
+                let connector_metadata = body
+                    .txn_id
+                    .map(|txn_id| serde_json::to_value(PaytmPaymentsMeta { txn_id }))
+                    .transpose()
+                    .into_report()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+                (
+                    status,
+                    Ok(types::PaymentsResponseData::TransactionResponse {
+                        resource_id: types::ResponseId::ConnectorTransactionId(body.order_id),
+                        redirection_data: None,
+                        mandate_reference: None,
+                        connector_metadata,
+                        network_txn_id: None,
+                        avs_result: None,
+                        cvv_result: None,
+                    }),
+                )
+            }
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+pub enum PaytmTxnType {
+    #[serde(rename = "REFUND")]
+    Refund,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmRefundBody {
+    mid: String,
+    txn_type: PaytmTxnType,
+    order_id: String,
+    txn_id: String,
+    ref_id: String,
+    refund_amount: String,
+}
+
+pub type PaytmRefundRequest = PaytmSignedRequest<PaytmRefundBody>;
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaytmRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+        let meta: PaytmPaymentsMeta = item
+            .request
+            .connector_metadata
+            .clone()
+            .ok_or_else(utils::missing_field_err("connector_metadata"))
+            .and_then(|meta| {
+                serde_json::from_value(meta)
+                    .into_report()
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)
+            })?;
+        let body = PaytmRefundBody {
+            mid: auth.merchant_id,
+            txn_type: PaytmTxnType::Refund,
+            order_id: item.request.connector_transaction_id.clone(),
+            txn_id: meta.txn_id,
+            ref_id: item.request.refund_id.clone(),
+            refund_amount: utils::to_currency_base_unit(
+                item.request.refund_amount,
+                item.request.currency,
+            )?,
+        };
+        Self::try_new(body, &auth.merchant_key)
+    }
+}
+
+// Refund Status Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmRefundSyncBody {
+    mid: String,
+    order_id: String,
+    ref_id: String,
+}
+
+pub type PaytmRefundSyncRequest = PaytmSignedRequest<PaytmRefundSyncBody>;
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaytmRefundSyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth = PaytmAuthType::try_from(&item.connector_auth_type)?;
+        let body = PaytmRefundSyncBody {
+            mid: auth.merchant_id,
+            order_id: item.request.connector_transaction_id.clone(),
+            ref_id: item.request.refund_id.clone(),
+        };
+        Self::try_new(body, &auth.merchant_key)
+    }
+}
+
+// Refund Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmRefundResultInfo {
+    result_status: PaytmTransactionStatus,
+    result_code: String,
+    result_msg: String,
+}
+
+impl From<PaytmTransactionStatus> for enums::RefundStatus {
+    fn from(status: PaytmTransactionStatus) -> Self {
+        match status {
+            PaytmTransactionStatus::TxnSuccess => Self::Success,
+            PaytmTransactionStatus::TxnFailure => Self::Failure,
+            PaytmTransactionStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmRefundResponseBody {
+    result_info: PaytmRefundResultInfo,
+    refund_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaytmRefundResponse {
+    body: PaytmRefundResponseBody,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, PaytmRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, PaytmRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        let body = item.response.body;
+        let response = match body.refund_id {
+            Some(refund_id) => Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status: enums::RefundStatus::from(body.result_info.result_status),
+            }),
+            // Refunds rejected up front are never assigned an id
+            None => Err(types::ErrorResponse {
+                code: body.result_info.result_code,
+                message: body.result_info.result_msg,
+                reason: None,
+                status_code: item.http_code,
+                unified_code: None,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmErrorResultInfo {
+    pub result_code: Option<String>,
+    pub result_msg: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaytmErrorResponseBody {
+    pub result_info: PaytmErrorResultInfo,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaytmErrorResponse {
+    pub body: PaytmErrorResponseBody,
+}
@@ -5,6 +5,7 @@ use std::fmt::Debug;
 use error_stack::{IntoReport, ResultExt};
 use transformers as payu;
 
+use super::utils as conn_utils;
 use crate::{
     configs::settings,
     core::{
@@ -18,7 +19,7 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse,
     },
-    utils::{self, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -89,10 +90,35 @@ impl ConnectorCommon for Payu {
             code: response.status.status_code,
             message: response.status.status_desc,
             reason: response.status.code_literal,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Payu {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::BankRedirect,
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::Wallet,
+        ])
+    }
+
+    fn supported_currencies(&self) -> Option<&'static [api::enums::Currency]> {
+        Some(&[
+            api::enums::Currency::CZK,
+            api::enums::Currency::EUR,
+            api::enums::Currency::GBP,
+            api::enums::Currency::PLN,
+            api::enums::Currency::USD,
+        ])
+    }
+
+    fn supported_countries(&self) -> Option<&'static [&'static str]> {
+        Some(&["CZ", "PL"])
+    }
+}
+
 impl api::Payment for Payu {}
 
 impl api::PreVerify for Payu {}
@@ -258,6 +284,7 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
             code: response.error,
             message: response.error_description,
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -669,27 +696,124 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Payu {}
+impl api::PayoutCreate for Payu {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData> for Payu {}
+
+impl api::PayoutFulfill for Payu {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData> for Payu {}
+
+impl api::PayoutCancel for Payu {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData> for Payu {}
+
+impl api::Dispute for Payu {}
+impl api::SubmitEvidence for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Payu
+{
+}
+
+impl api::PayoutSync for Payu {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Payu {}
+
+impl api::RefundCredit for Payu {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Payu {}
+
+impl api::ConnectorMandateRevoke for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Payu
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Payu {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::Md5)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        // The header is of the form `sender=checkout;signature=...;algorithm=MD5;content=DOCUMENT`
+        let header =
+            conn_utils::get_header_key_value(headers::OPENPAYU_SIGNATURE, request.headers)?;
+        let signature = header
+            .split(';')
+            .find_map(|field| field.trim().strip_prefix("signature="))
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        // The signature is the digest of the body followed by the second key of the POS
+        Ok([request.body, secret].concat())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: payu::PayuWebhookBody = request
+            .body
+            .parse_struct("PayuWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(webhook.get_reference_id())
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: payu::PayuWebhookBody = request
+            .body
+            .parse_struct("PayuWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        webhook.try_into()
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: payu::PayuOrderWebhook = request
+            .body
+            .parse_struct("PayuOrderWebhook")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<payu::PayuPaymentsSyncResponse>::encode_to_value(
+            &payu::PayuPaymentsSyncResponse::from(webhook),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
@@ -7,10 +7,12 @@ use crate::{
     consts,
     core::errors,
     pii::{self, Secret},
+    services,
     types::{self, api, storage::enums},
 };
 
 const WALLET_IDENTIFIER: &str = "PBL";
+const BLIK_IDENTIFIER: &str = "BLIK_AUTHORIZATION_CODE";
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +37,7 @@ pub struct PayuPaymentMethod {
 pub enum PayuPaymentMethodData {
     Card(PayuCard),
     Wallet(PayuWallet),
+    Blik(PayuBlik),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -57,6 +60,14 @@ pub struct PayuWallet {
     pub wallet_type: String,
     pub authorization_code: String,
 }
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct PayuBlik {
+    #[serde(rename = "type")]
+    pub blik_type: String,
+    /// The six digit code the customer generated in their banking app
+    pub value: String,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PayuWalletCode {
@@ -101,6 +112,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
                     "Unknown Wallet in Payment Method".to_string(),
                 )),
             },
+            api::PaymentMethodData::BankRedirect(
+                api_models::payments::BankRedirectData::Blik { blik_code },
+            ) => Ok(PayuPaymentMethod {
+                pay_method: PayuPaymentMethodData::Blik(PayuBlik {
+                    blik_type: BLIK_IDENTIFIER.to_string(),
+                    value: blik_code,
+                }),
+            }),
             _ => Err(errors::ConnectorError::NotImplemented(
                 "Unknown payment method".to_string(),
             )),
@@ -125,7 +144,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
                 },
             )?,
             pay_methods: payment_method,
-            continue_url: None,
+            continue_url: item.router_return_url.clone(),
         })
     }
 }
@@ -164,9 +183,9 @@ impl From<PayuPaymentStatus> for enums::AttemptStatus {
     fn from(item: PayuPaymentStatus) -> Self {
         match item {
             PayuPaymentStatus::Success => Self::Pending,
-            PayuPaymentStatus::WarningContinue3ds => Self::Pending,
+            PayuPaymentStatus::WarningContinue3ds => Self::AuthenticationPending,
             PayuPaymentStatus::WarningContinueCvv => Self::Pending,
-            PayuPaymentStatus::WarningContinueRedirect => Self::Pending,
+            PayuPaymentStatus::WarningContinueRedirect => Self::AuthenticationPending,
             PayuPaymentStatus::Pending => Self::Pending,
         }
     }
@@ -176,7 +195,7 @@ impl From<PayuPaymentStatus> for enums::AttemptStatus {
 #[serde(rename_all = "camelCase")]
 pub struct PayuPaymentsResponse {
     pub status: PayuPaymentStatusData,
-    pub redirect_uri: String,
+    pub redirect_uri: Option<url::Url>,
     pub iframe_allowed: Option<bool>,
     pub three_ds_protocol_version: Option<String>,
     pub order_id: String,
@@ -191,13 +210,26 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PayuPaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status.status_code);
+        // The redirect uri is also returned for completed payments, pointing at the continue url
+        let redirection_data = match status {
+            enums::AttemptStatus::AuthenticationPending => {
+                item.response.redirect_uri.map(|redirect_uri| {
+                    services::RedirectForm::from((redirect_uri, services::Method::Get))
+                })
+            }
+            _ => None,
+        };
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status.status_code),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
-                redirection_data: None,
+                redirection_data,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -248,6 +280,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -326,6 +361,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured: None,
             ..item.data
@@ -375,7 +413,7 @@ pub struct PayuProductData {
     listing_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PayuOrderResponseData {
     order_id: String,
@@ -409,12 +447,13 @@ pub struct PayuOrderResponseBuyerData {
     customer_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PayuOrderResponsePayMethod {
     CardToken,
     Pbl,
     Installemnts,
+    BlikAuthorizationCode,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -423,7 +462,7 @@ pub struct PayuOrderResponseProperty {
     value: String,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PayuPaymentsSyncResponse {
     orders: Vec<PayuOrderResponseData>,
     status: PayuPaymentStatusData,
@@ -454,6 +493,9 @@ impl<F, T>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured: Some(
                 order
@@ -488,7 +530,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for PayuRefundRequest {
                         field_name: "item.request.reason",
                     },
                 )?,
-                amount: None,
+                amount: Some(item.request.refund_amount),
             },
         })
     }
@@ -597,3 +639,80 @@ pub struct PayuAccessTokenErrorResponse {
     pub error: String,
     pub error_description: String,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuOrderWebhook {
+    pub order: PayuOrderResponseData,
+    pub properties: Option<Vec<PayuOrderResponseProperty>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuWebhookRefund {
+    pub refund_id: String,
+    pub status: RefundStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuRefundWebhook {
+    pub order_id: String,
+    pub refund: PayuWebhookRefund,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PayuWebhookBody {
+    Refund(PayuRefundWebhook),
+    Order(Box<PayuOrderWebhook>),
+}
+
+impl PayuWebhookBody {
+    pub fn get_reference_id(&self) -> String {
+        match self {
+            Self::Refund(webhook) => webhook.refund.refund_id.clone(),
+            Self::Order(webhook) => webhook.order.order_id.clone(),
+        }
+    }
+}
+
+impl TryFrom<PayuWebhookBody> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: PayuWebhookBody) -> Result<Self, Self::Error> {
+        match webhook {
+            PayuWebhookBody::Order(webhook) => match webhook.order.status {
+                OrderStatus::Completed | OrderStatus::WaitingForConfirmation => {
+                    Ok(Self::PaymentIntentSuccess)
+                }
+                OrderStatus::Canceled => Ok(Self::PaymentIntentFailure),
+                OrderStatus::New | OrderStatus::Pending => {
+                    Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+                }
+            },
+            PayuWebhookBody::Refund(webhook) => match webhook.refund.status {
+                RefundStatus::Finalized | RefundStatus::Completed => Ok(Self::RefundSuccess),
+                RefundStatus::Canceled => Ok(Self::RefundFailure),
+                RefundStatus::Pending => {
+                    Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+                }
+            },
+        }
+    }
+}
+
+/// Order notifications carry the same order as a payment retrieve, so they are handed to the
+/// sync flow in the shape of its response.
+impl From<PayuOrderWebhook> for PayuPaymentsSyncResponse {
+    fn from(webhook: PayuOrderWebhook) -> Self {
+        Self {
+            orders: vec![webhook.order],
+            status: PayuPaymentStatusData {
+                status_code: PayuPaymentStatus::Success,
+                severity: None,
+                status_desc: None,
+            },
+            properties: webhook.properties,
+        }
+    }
+}
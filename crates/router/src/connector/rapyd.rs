@@ -23,7 +23,7 @@ use crate::{
         api::{self, ConnectorCommon},
         ErrorResponse,
     },
-    utils::{self, crypto, ByteSliceExt, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -86,10 +86,20 @@ impl ConnectorCommon for Rapyd {
             code: response.status.error_code,
             message: response.status.status.unwrap_or_default(),
             reason: response.status.message,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Rapyd {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::Wallet,
+        ])
+    }
+}
+
 impl api::ConnectorAccessToken for Rapyd {}
 
 impl
@@ -662,13 +672,72 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Rapyd {}
+impl api::PayoutCreate for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::PayoutFulfill for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::PayoutCancel for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::PayoutSync for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::RefundCredit for Rapyd {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Rapyd
+{
+}
+
+impl api::ConnectorMandateRevoke for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Rapyd
+{
+}
+
+impl api::Dispute for Rapyd {}
+impl api::SubmitEvidence for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Rapyd {
-    fn get_webhook_source_verification_algorithm(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::HmacSha256))
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
     }
 
     fn get_webhook_source_verification_signature(
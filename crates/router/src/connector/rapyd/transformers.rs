@@ -1,4 +1,4 @@
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -384,6 +384,7 @@ impl<F, T>
                             status_code: item.http_code,
                             message: item.response.status.status.unwrap_or_default(),
                             reason: data.failure_message.to_owned(),
+                            unified_code: None,
                         }),
                     ),
                     _ => {
@@ -402,6 +403,9 @@ impl<F, T>
                                 redirection_data,
                                 mandate_reference: None,
                                 connector_metadata: None,
+                                network_txn_id: None,
+                                avs_result: None,
+                                cvv_result: None,
                             }),
                         )
                     }
@@ -414,6 +418,7 @@ impl<F, T>
                     status_code: item.http_code,
                     message: item.response.status.status.unwrap_or_default(),
                     reason: item.response.status.message,
+                    unified_code: None,
                 }),
             ),
         };
@@ -453,9 +458,11 @@ impl TryFrom<RapydWebhookObjectEventType> for api::IncomingWebhookEvent {
     fn try_from(value: RapydWebhookObjectEventType) -> Result<Self, Self::Error> {
         match value {
             RapydWebhookObjectEventType::PaymentCompleted => Ok(Self::PaymentIntentSuccess),
-            RapydWebhookObjectEventType::PaymentCaptured => Ok(Self::PaymentIntentSuccess),
+            RapydWebhookObjectEventType::PaymentCaptured => Ok(Self::PaymentIntentCaptureSuccess),
             RapydWebhookObjectEventType::PaymentFailed => Ok(Self::PaymentIntentFailure),
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            RapydWebhookObjectEventType::RefundCompleted => Ok(Self::RefundSuccess),
+            RapydWebhookObjectEventType::PaymentRefundRejected
+            | RapydWebhookObjectEventType::PaymentRefundFailed => Ok(Self::RefundFailure),
         }
     }
 }
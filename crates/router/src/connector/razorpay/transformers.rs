@@ -0,0 +1,480 @@
+use std::net::IpAddr;
+
+use common_utils::pii::{self, Email};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, PhoneDetailsData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+// Auth Struct
+pub struct RazorpayAuthType {
+    pub(super) key_id: String,
+    pub(super) key_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for RazorpayAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                key_id: api_key.to_string(),
+                key_secret: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Order Request
+#[derive(Debug, Serialize)]
+pub struct RazorpayOrderRequest {
+    amount: i64,
+    currency: enums::Currency,
+    receipt: String,
+    payment_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for RazorpayOrderRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency,
+            receipt: item.request.connector_transaction_id.clone(),
+            payment_capture: matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::Automatic) | None
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayOrderResponse {
+    id: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayOrderResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, RazorpayOrderResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            session_token: Some(item.response.id.clone()),
+            response: Ok(types::PaymentsResponseData::SessionTokenResponse {
+                session_token: item.response.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Payments Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayPaymentMethod {
+    Card,
+    Upi,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCard {
+    number: Secret<String, pii::CardNumber>,
+    name: Secret<String>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cvv: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayUpiFlow {
+    Collect,
+    Intent,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayUpi {
+    flow: RazorpayUpiFlow,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vpa: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayPaymentsRequest {
+    amount: i64,
+    currency: enums::Currency,
+    order_id: String,
+    email: Secret<String, Email>,
+    contact: Secret<String>,
+    method: RazorpayPaymentMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<RazorpayCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upi: Option<RazorpayUpi>,
+    description: Option<String>,
+    callback_url: Option<String>,
+    ip: Option<IpAddr>,
+    user_agent: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for RazorpayPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let (method, card, upi) = match item.request.payment_method_data {
+            api::PaymentMethodData::Card(ref ccard) => (
+                RazorpayPaymentMethod::Card,
+                Some(RazorpayCard {
+                    number: ccard.card_number.clone(),
+                    name: ccard.card_holder_name.clone(),
+                    expiry_month: ccard.card_exp_month.clone(),
+                    expiry_year: ccard.card_exp_year.clone(),
+                    cvv: ccard.card_cvc.clone(),
+                }),
+                None,
+            ),
+            api::PaymentMethodData::Upi(ref upi_data) => {
+                let upi = match upi_data {
+                    api_models::payments::UpiData::UpiCollect { vpa_id } => RazorpayUpi {
+                        flow: RazorpayUpiFlow::Collect,
+                        vpa: Some(vpa_id.clone()),
+                    },
+                    api_models::payments::UpiData::UpiIntent {} => RazorpayUpi {
+                        flow: RazorpayUpiFlow::Intent,
+                        vpa: None,
+                    },
+                };
+                (RazorpayPaymentMethod::Upi, None, Some(upi))
+            }
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment methods".to_string()).into(),
+                )
+            }
+        };
+        let browser_info = item.request.browser_info.as_ref();
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency,
+            // The order is created before authorizing, see `execute_pretasks`
+            order_id: item.get_session_token()?,
+            email: item
+                .request
+                .email
+                .clone()
+                .ok_or_else(utils::missing_field_err("email"))?,
+            contact: item.get_billing_phone()?.get_number()?,
+            method,
+            card,
+            upi,
+            description: item.description.clone(),
+            callback_url: item.router_return_url.clone(),
+            ip: browser_info.and_then(|browser_info| browser_info.ip_address),
+            user_agent: browser_info.map(|browser_info| browser_info.user_agent.clone()),
+        })
+    }
+}
+
+// Payments Response
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayNextActionType {
+    /// Customer authentication on the issuer's page
+    Redirect,
+    /// Deep link to be opened in a UPI app
+    Intent,
+    /// The customer has been asked to approve the payment in their UPI app
+    Poll,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RazorpayNextAction {
+    action: RazorpayNextActionType,
+    url: Option<Url>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RazorpayPaymentCreateResponse {
+    razorpay_payment_id: String,
+    #[serde(default)]
+    next: Vec<RazorpayNextAction>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, RazorpayPaymentCreateResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayPaymentCreateResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let next_action = item.response.next.into_iter().find_map(|next| match next {
+            RazorpayNextAction {
+                action: RazorpayNextActionType::Redirect | RazorpayNextActionType::Intent,
+                url: Some(url),
+            } => Some(url),
+            _ => None,
+        });
+        // Collect requests are approved by the customer outside of the checkout, so the
+        // payment stays pending until a webhook or sync picks up the outcome
+        let status = if next_action.is_some() {
+            enums::AttemptStatus::AuthenticationPending
+        } else {
+            enums::AttemptStatus::Pending
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.razorpay_payment_id,
+                ),
+                redirection_data: next_action
+                    .map(|url| services::RedirectForm::from((url, services::Method::Get))),
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayPaymentStatus {
+    Created,
+    Authorized,
+    Captured,
+    Refunded,
+    Failed,
+}
+
+impl From<RazorpayPaymentStatus> for enums::AttemptStatus {
+    fn from(item: RazorpayPaymentStatus) -> Self {
+        match item {
+            RazorpayPaymentStatus::Created => Self::AuthenticationPending,
+            RazorpayPaymentStatus::Authorized => Self::Authorized,
+            RazorpayPaymentStatus::Captured | RazorpayPaymentStatus::Refunded => Self::Charged,
+            RazorpayPaymentStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+/// The payment entity, returned when fetching or capturing a payment and sent in webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayPaymentResponse {
+    pub id: String,
+    status: RazorpayPaymentStatus,
+    error_code: Option<String>,
+    error_description: Option<String>,
+    error_reason: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = if status == enums::AttemptStatus::Failure {
+            Err(types::ErrorResponse {
+                code: item
+                    .response
+                    .error_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .error_description
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.error_reason,
+                status_code: item.http_code,
+                unified_code: None,
+            })
+        } else {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Capture Request
+#[derive(Debug, Serialize)]
+pub struct RazorpayCaptureRequest {
+    amount: i64,
+    currency: enums::Currency,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for RazorpayCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+            currency: item.request.currency,
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct RazorpayRefundRequest {
+    amount: i64,
+    receipt: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for RazorpayRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.refund_amount,
+            receipt: item.request.refund_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RazorpayRefundStatus {
+    Pending,
+    Processed,
+    Failed,
+}
+
+impl From<RazorpayRefundStatus> for enums::RefundStatus {
+    fn from(item: RazorpayRefundStatus) -> Self {
+        match item {
+            RazorpayRefundStatus::Pending => Self::Pending,
+            RazorpayRefundStatus::Processed => Self::Success,
+            RazorpayRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundResponse {
+    id: String,
+    status: RazorpayRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+pub enum RazorpayWebhookEvent {
+    #[serde(rename = "payment.authorized")]
+    PaymentAuthorized,
+    #[serde(rename = "payment.captured")]
+    PaymentCaptured,
+    #[serde(rename = "payment.failed")]
+    PaymentFailed,
+    #[serde(rename = "refund.processed")]
+    RefundProcessed,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TryFrom<RazorpayWebhookEvent> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event: RazorpayWebhookEvent) -> Result<Self, Self::Error> {
+        match event {
+            RazorpayWebhookEvent::PaymentAuthorized => Ok(Self::PaymentIntentSuccess),
+            RazorpayWebhookEvent::PaymentCaptured => Ok(Self::PaymentIntentCaptureSuccess),
+            RazorpayWebhookEvent::PaymentFailed => Ok(Self::PaymentIntentFailure),
+            RazorpayWebhookEvent::RefundProcessed => Ok(Self::RefundSuccess),
+            RazorpayWebhookEvent::RefundFailed => Ok(Self::RefundFailure),
+            RazorpayWebhookEvent::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookEntity<T> {
+    pub entity: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookRefund {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookPayload {
+    pub payment: Option<RazorpayWebhookEntity<RazorpayPaymentResponse>>,
+    pub refund: Option<RazorpayWebhookEntity<RazorpayWebhookRefund>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookBody {
+    pub event: RazorpayWebhookEvent,
+    pub payload: RazorpayWebhookPayload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorDetails {
+    pub code: String,
+    pub description: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorResponse {
+    pub error: RazorpayErrorDetails,
+}
@@ -0,0 +1,331 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use ring::hmac;
+use transformers as riskified;
+
+use super::utils as conn_utils;
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Riskified;
+
+impl api::FraudCheckPreAuth for Riskified {}
+impl api::FraudCheckPostAuth for Riskified {}
+
+impl Riskified {
+    /// Requests are signed with the hex encoded HMAC-SHA256 of their body, keyed with the auth
+    /// token of the shop
+    pub fn generate_signature(&self, auth: &riskified::RiskifiedAuthType, body: &str) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, auth.auth_token.as_bytes());
+        hex::encode(hmac::sign(&key, body.as_bytes()))
+    }
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Riskified
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = riskified::RiskifiedAuthType::try_from(&req.connector_auth_type)?;
+        let body = self.get_request_body(req)?.unwrap_or_default();
+
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ),
+            (
+                headers::ACCEPT.to_string(),
+                "application/vnd.riskified.com; version=2".to_string(),
+            ),
+            (
+                headers::X_RISKIFIED_SHOP_DOMAIN.to_string(),
+                auth.shop_domain.clone(),
+            ),
+            (
+                headers::X_RISKIFIED_HMAC_SHA256.to_string(),
+                self.generate_signature(&auth, &body),
+            ),
+        ])
+    }
+}
+
+impl ConnectorCommon for Riskified {
+    fn id(&self) -> &'static str {
+        "riskified"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.riskified.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: riskified::RiskifiedErrorResponse = res
+            .response
+            .parse_struct("Riskified ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response.error.message,
+            reason: None,
+            unified_code: None,
+        })
+    }
+}
+
+impl ConnectorIntegration<api::FrmPreAuth, types::FraudCheckData, types::FraudCheckResponseData>
+    for Riskified
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}api/decide", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let riskified_req =
+            utils::Encode::<riskified::RiskifiedOrderRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(riskified_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::FraudCheckPreAuthType::get_http_method(self))
+                .url(&types::FraudCheckPreAuthType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::FraudCheckPreAuthType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FraudCheckPreAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckPreAuthRouterData,
+        res: Response,
+    ) -> CustomResult<types::FraudCheckPreAuthRouterData, errors::ConnectorError> {
+        logger::debug!(riskified_decide_response=?res);
+        let response: riskified::RiskifiedOrderResponse = res
+            .response
+            .parse_struct("Riskified OrderResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::FrmPostAuth, types::FraudCheckData, types::FraudCheckResponseData>
+    for Riskified
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    // Authorized orders are submitted for a review, whose decision is sent through a webhook
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}api/submit", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let riskified_req =
+            utils::Encode::<riskified::RiskifiedOrderRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(riskified_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::FraudCheckPostAuthType::get_http_method(self))
+                .url(&types::FraudCheckPostAuthType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::FraudCheckPostAuthType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FraudCheckPostAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckPostAuthRouterData,
+        res: Response,
+    ) -> CustomResult<types::FraudCheckPostAuthRouterData, errors::ConnectorError> {
+        logger::debug!(riskified_submit_response=?res);
+        let response: riskified::RiskifiedOrderResponse = res
+            .response
+            .parse_struct("Riskified OrderResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Riskified {
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            conn_utils::get_header_key_value(headers::X_RISKIFIED_HMAC_SHA256, request.headers)?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let webhook: riskified::RiskifiedOrderResponse = request
+            .body
+            .parse_struct("RiskifiedOrderResponse")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(webhook.order.id)
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: riskified::RiskifiedOrderResponse = request
+            .body
+            .parse_struct("RiskifiedOrderResponse")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&webhook))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let webhook: riskified::RiskifiedOrderResponse = request
+            .body
+            .parse_struct("RiskifiedOrderResponse")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<types::FraudCheckResponseData>::encode_to_value(
+            &types::FraudCheckResponseData::from(webhook),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+}
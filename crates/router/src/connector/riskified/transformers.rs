@@ -0,0 +1,218 @@
+use common_utils::{custom_serde, pii::Email};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    connector::utils,
+    core::errors,
+    types::{self, api, storage::enums},
+};
+
+// Auth Struct
+pub struct RiskifiedAuthType {
+    pub(super) auth_token: String,
+    pub(super) shop_domain: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for RiskifiedAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                auth_token: api_key.to_string(),
+                shop_domain: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Order Request, the same for the decision before authorization and the submission after it
+#[derive(Debug, Serialize)]
+pub struct RiskifiedOrderRequest {
+    order: RiskifiedOrder,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiskifiedOrder {
+    id: String,
+    checkout_id: String,
+    #[serde(with = "custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    currency: enums::Currency,
+    total_price: f64,
+    email: Option<Secret<String, Email>>,
+    browser_ip: Option<std::net::IpAddr>,
+    gateway: Option<String>,
+    line_items: Vec<RiskifiedLineItem>,
+    payment_details: Vec<RiskifiedPaymentDetails>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiskifiedLineItem {
+    title: String,
+    quantity: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiskifiedPaymentDetails {
+    credit_card_bin: Option<String>,
+    /// The card number with all but its last four digits masked
+    credit_card_number: Option<String>,
+    authorization_id: Option<String>,
+    authorization_error: Option<RiskifiedAuthorizationError>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiskifiedAuthorizationError {
+    error_code: String,
+}
+
+impl<F> TryFrom<&types::FraudCheckRouterData<F>> for RiskifiedOrderRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FraudCheckRouterData<F>) -> Result<Self, Self::Error> {
+        let (credit_card_bin, credit_card_number) = match &item.request.payment_method_data {
+            Some(api::PaymentMethodData::Card(card)) => {
+                let card_number = card.card_number.peek();
+                (
+                    card_number.get(..6).map(ToOwned::to_owned),
+                    card_number
+                        .len()
+                        .checked_sub(4)
+                        .and_then(|start| card_number.get(start..))
+                        .map(|last4| format!("XXXX-XXXX-XXXX-{last4}")),
+                )
+            }
+            _ => (None, None),
+        };
+        let line_items = item
+            .request
+            .order_details
+            .as_ref()
+            .map(|order_details| {
+                vec![RiskifiedLineItem {
+                    title: order_details.product_name.clone(),
+                    quantity: order_details.quantity,
+                }]
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            order: RiskifiedOrder {
+                id: item.attempt_id.clone(),
+                checkout_id: item.payment_id.clone(),
+                created_at: common_utils::date_time::now(),
+                currency: item.request.currency,
+                total_price: utils::to_currency_base_unit_asf64(
+                    item.request.amount,
+                    item.request.currency,
+                )?,
+                email: item.request.email.clone(),
+                browser_ip: item
+                    .request
+                    .browser_info
+                    .as_ref()
+                    .and_then(|browser_info| browser_info.ip_address),
+                gateway: item.request.payment_connector.clone(),
+                line_items,
+                payment_details: vec![RiskifiedPaymentDetails {
+                    credit_card_bin,
+                    credit_card_number,
+                    authorization_id: item.request.connector_transaction_id.clone(),
+                    authorization_error: item
+                        .request
+                        .error_code
+                        .clone()
+                        .map(|error_code| RiskifiedAuthorizationError { error_code }),
+                }],
+            },
+        })
+    }
+}
+
+// Order Response, also the body of webhooks
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskifiedOrderStatus {
+    Approved,
+    Declined,
+    Submitted,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<RiskifiedOrderStatus> for api::enums::FraudCheckStatus {
+    fn from(status: RiskifiedOrderStatus) -> Self {
+        match status {
+            RiskifiedOrderStatus::Approved => Self::Legit,
+            RiskifiedOrderStatus::Declined => Self::Fraud,
+            RiskifiedOrderStatus::Submitted | RiskifiedOrderStatus::Unknown => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RiskifiedOrderDecision {
+    pub id: String,
+    pub status: RiskifiedOrderStatus,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RiskifiedOrderResponse {
+    pub order: RiskifiedOrderDecision,
+}
+
+impl From<RiskifiedOrderResponse> for types::FraudCheckResponseData {
+    fn from(response: RiskifiedOrderResponse) -> Self {
+        Self {
+            frm_reference_id: Some(response.order.id),
+            status: response.order.status.into(),
+            reason: response.order.description,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RiskifiedOrderResponse, T, types::FraudCheckResponseData>>
+    for types::RouterData<F, T, types::FraudCheckResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RiskifiedOrderResponse,
+            T,
+            types::FraudCheckResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(item.response.into()),
+            ..item.data
+        })
+    }
+}
+
+impl From<&RiskifiedOrderResponse> for api::IncomingWebhookEvent {
+    fn from(response: &RiskifiedOrderResponse) -> Self {
+        match response.order.status {
+            RiskifiedOrderStatus::Approved => Self::FrmApproved,
+            RiskifiedOrderStatus::Declined => Self::FrmRejected,
+            RiskifiedOrderStatus::Submitted | RiskifiedOrderStatus::Unknown => {
+                Self::EventNotSupported
+            }
+        }
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+pub struct RiskifiedErrorResponse {
+    pub error: RiskifiedErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RiskifiedErrorDetails {
+    pub message: String,
+}
@@ -91,10 +91,17 @@ impl ConnectorCommon for Shift4 {
                 .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
             message: response.error.message,
             reason: None,
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Shift4 {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::Payment for Shift4 {}
 impl api::ConnectorAccessToken for Shift4 {}
 
@@ -490,6 +497,59 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Shift4 {}
+impl api::PayoutCreate for Shift4 {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::PayoutFulfill for Shift4 {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::PayoutCancel for Shift4 {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::PayoutSync for Shift4 {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Shift4 {}
+
+impl api::RefundCredit for Shift4 {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Shift4 {}
+
+impl api::ConnectorMandateRevoke for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Shift4
+{
+}
+
+impl api::Dispute for Shift4 {}
+impl api::SubmitEvidence for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Shift4
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Shift4 {
     fn get_webhook_object_reference_id(
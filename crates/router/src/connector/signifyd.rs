@@ -0,0 +1,327 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+use transformers as signifyd;
+
+use super::utils as conn_utils;
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Signifyd;
+
+impl api::FraudCheckPreAuth for Signifyd {}
+impl api::FraudCheckPostAuth for Signifyd {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Signifyd
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )];
+        let mut auth_header = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut auth_header);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Signifyd {
+    fn id(&self) -> &'static str {
+        "signifyd"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.signifyd.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = signifyd::SignifydAuthType::try_from(auth_type)?;
+        let encoded_api_key = consts::BASE64_ENGINE.encode(auth.api_key);
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Basic {encoded_api_key}"),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: signifyd::SignifydErrorResponse = res
+            .response
+            .parse_struct("Signifyd ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.errors.map(|errors| errors.to_string()),
+            unified_code: None,
+        })
+    }
+}
+
+impl ConnectorIntegration<api::FrmPreAuth, types::FraudCheckData, types::FraudCheckResponseData>
+    for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/checkouts",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let signifyd_req =
+            utils::Encode::<signifyd::SignifydCheckoutRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::FraudCheckPreAuthType::get_http_method(self))
+                .url(&types::FraudCheckPreAuthType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::FraudCheckPreAuthType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FraudCheckPreAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckPreAuthRouterData,
+        res: Response,
+    ) -> CustomResult<types::FraudCheckPreAuthRouterData, errors::ConnectorError> {
+        logger::debug!(signifyd_checkout_response=?res);
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("Signifyd DecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::FrmPostAuth, types::FraudCheckData, types::FraudCheckResponseData>
+    for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/transactions",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let signifyd_req =
+            utils::Encode::<signifyd::SignifydTransactionRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(types::FraudCheckPostAuthType::get_http_method(self))
+                .url(&types::FraudCheckPostAuthType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::FraudCheckPostAuthType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::FraudCheckPostAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckPostAuthRouterData,
+        res: Response,
+    ) -> CustomResult<types::FraudCheckPostAuthRouterData, errors::ConnectorError> {
+        logger::debug!(signifyd_transaction_response=?res);
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("Signifyd DecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Signifyd {
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let base64_signature =
+            conn_utils::get_header_key_value(headers::SIGNIFYD_SEC_HMAC_SHA256, request.headers)?;
+        consts::BASE64_ENGINE
+            .decode(base64_signature.as_bytes())
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let webhook: signifyd::SignifydDecisionResponse = request
+            .body
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(webhook.order_id)
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: signifyd::SignifydDecisionResponse = request
+            .body
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&webhook))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let webhook: signifyd::SignifydDecisionResponse = request
+            .body
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<types::FraudCheckResponseData>::encode_to_value(
+            &types::FraudCheckResponseData::from(webhook),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+}
@@ -0,0 +1,360 @@
+use common_utils::pii::Email;
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils,
+    core::errors,
+    types::{self, api, storage::enums},
+};
+
+// Auth Struct
+pub struct SignifydAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for SignifydAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Checkout Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydCheckoutRequest {
+    order_id: String,
+    checkout_id: String,
+    purchase: SignifydPurchase,
+    device: Option<SignifydDevice>,
+    transactions: Vec<SignifydTransaction>,
+    coverage_requests: Vec<SignifydCoverageRequest>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydPurchase {
+    order_channel: SignifydOrderChannel,
+    total_price: f64,
+    currency: enums::Currency,
+    products: Vec<SignifydProduct>,
+    confirmation_email: Option<Secret<String, Email>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydOrderChannel {
+    Web,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydProduct {
+    item_name: String,
+    item_quantity: u16,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDevice {
+    client_ip_address: Option<std::net::IpAddr>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydCoverageRequest {
+    Fraud,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransaction {
+    transaction_id: String,
+    payment_method: SignifydPaymentMethod,
+    amount: f64,
+    currency: enums::Currency,
+    gateway: Option<String>,
+    gateway_status_code: Option<SignifydGatewayStatusCode>,
+    gateway_error_code: Option<String>,
+    checkout_payment_details: Option<SignifydPaymentDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydPaymentMethod {
+    CreditCard,
+    Other,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydGatewayStatusCode {
+    Success,
+    Failure,
+    Pending,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydPaymentDetails {
+    card_bin: Option<String>,
+    card_last4: Option<String>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    account_holder_name: Secret<String>,
+}
+
+fn get_payment_details(
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> (SignifydPaymentMethod, Option<SignifydPaymentDetails>) {
+    match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => {
+            let card_number = card.card_number.peek();
+            (
+                SignifydPaymentMethod::CreditCard,
+                Some(SignifydPaymentDetails {
+                    card_bin: card_number.get(..6).map(ToOwned::to_owned),
+                    card_last4: card_number
+                        .len()
+                        .checked_sub(4)
+                        .and_then(|start| card_number.get(start..))
+                        .map(ToOwned::to_owned),
+                    card_exp_month: card.card_exp_month.clone(),
+                    card_exp_year: card.card_exp_year.clone(),
+                    account_holder_name: card.card_holder_name.clone(),
+                }),
+            )
+        }
+        _ => (SignifydPaymentMethod::Other, None),
+    }
+}
+
+fn get_transaction<F>(
+    item: &types::FraudCheckRouterData<F>,
+) -> Result<SignifydTransaction, error_stack::Report<errors::ConnectorError>> {
+    let (payment_method, checkout_payment_details) =
+        get_payment_details(item.request.payment_method_data.as_ref());
+    let gateway_status_code = item
+        .request
+        .authorization_status
+        .map(|authorization_status| match authorization_status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged => SignifydGatewayStatusCode::Success,
+            enums::AttemptStatus::Failure
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::RouterDeclined => SignifydGatewayStatusCode::Failure,
+            _ => SignifydGatewayStatusCode::Pending,
+        });
+
+    Ok(SignifydTransaction {
+        transaction_id: item
+            .request
+            .connector_transaction_id
+            .clone()
+            .unwrap_or_else(|| item.attempt_id.clone()),
+        payment_method,
+        amount: utils::to_currency_base_unit_asf64(item.request.amount, item.request.currency)?,
+        currency: item.request.currency,
+        gateway: item.request.payment_connector.clone(),
+        gateway_status_code,
+        gateway_error_code: item.request.error_code.clone(),
+        checkout_payment_details,
+    })
+}
+
+impl TryFrom<&types::FraudCheckPreAuthRouterData> for SignifydCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FraudCheckPreAuthRouterData) -> Result<Self, Self::Error> {
+        let products = item
+            .request
+            .order_details
+            .as_ref()
+            .map(|order_details| {
+                vec![SignifydProduct {
+                    item_name: order_details.product_name.clone(),
+                    item_quantity: order_details.quantity,
+                }]
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            order_id: item.attempt_id.clone(),
+            checkout_id: item.payment_id.clone(),
+            purchase: SignifydPurchase {
+                order_channel: SignifydOrderChannel::Web,
+                total_price: utils::to_currency_base_unit_asf64(
+                    item.request.amount,
+                    item.request.currency,
+                )?,
+                currency: item.request.currency,
+                products,
+                confirmation_email: item.request.email.clone(),
+            },
+            device: item
+                .request
+                .browser_info
+                .as_ref()
+                .map(|browser_info| SignifydDevice {
+                    client_ip_address: browser_info.ip_address,
+                }),
+            transactions: vec![get_transaction(item)?],
+            coverage_requests: vec![SignifydCoverageRequest::Fraud],
+        })
+    }
+}
+
+// Transaction Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransactionRequest {
+    order_id: String,
+    checkout_id: String,
+    transactions: Vec<SignifydTransaction>,
+}
+
+impl TryFrom<&types::FraudCheckPostAuthRouterData> for SignifydTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FraudCheckPostAuthRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            order_id: item.attempt_id.clone(),
+            checkout_id: item.payment_id.clone(),
+            transactions: vec![get_transaction(item)?],
+        })
+    }
+}
+
+// Decision Response, returned by both events and sent in webhooks
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydCheckpointAction {
+    Accept,
+    Reject,
+    Hold,
+    Challenge,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<SignifydCheckpointAction> for api::enums::FraudCheckStatus {
+    fn from(action: SignifydCheckpointAction) -> Self {
+        match action {
+            SignifydCheckpointAction::Accept => Self::Legit,
+            SignifydCheckpointAction::Reject => Self::Fraud,
+            SignifydCheckpointAction::Hold => Self::ManualReview,
+            SignifydCheckpointAction::Challenge => Self::ThreeDsRequired,
+            SignifydCheckpointAction::Unknown => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDecision {
+    checkpoint_action: SignifydCheckpointAction,
+    checkpoint_action_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDecisionResponse {
+    pub signifyd_id: i64,
+    pub order_id: String,
+    pub decision: SignifydDecision,
+}
+
+impl From<SignifydDecisionResponse> for types::FraudCheckResponseData {
+    fn from(response: SignifydDecisionResponse) -> Self {
+        Self {
+            frm_reference_id: Some(response.signifyd_id.to_string()),
+            status: response.decision.checkpoint_action.into(),
+            reason: response.decision.checkpoint_action_reason,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, SignifydDecisionResponse, T, types::FraudCheckResponseData>,
+    > for types::RouterData<F, T, types::FraudCheckResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            SignifydDecisionResponse,
+            T,
+            types::FraudCheckResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(item.response.into()),
+            ..item.data
+        })
+    }
+}
+
+impl From<&SignifydDecisionResponse> for api::IncomingWebhookEvent {
+    fn from(response: &SignifydDecisionResponse) -> Self {
+        match response.decision.checkpoint_action {
+            SignifydCheckpointAction::Accept => Self::FrmApproved,
+            SignifydCheckpointAction::Reject => Self::FrmRejected,
+            SignifydCheckpointAction::Hold
+            | SignifydCheckpointAction::Challenge
+            | SignifydCheckpointAction::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydErrorResponse {
+    pub message: Option<String>,
+    pub errors: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_action_is_mapped_to_fraud_check_status() {
+        let response: SignifydDecisionResponse = serde_json::from_str(
+            r#"{"signifydId":4471,"orderId":"pay_1_1","decision":{"checkpointAction":"HOLD","score":512}}"#,
+        )
+        .unwrap();
+        let response_data = types::FraudCheckResponseData::from(response);
+
+        assert_eq!(response_data.frm_reference_id, Some("4471".to_string()));
+        assert_eq!(
+            response_data.status,
+            api::enums::FraudCheckStatus::ManualReview
+        );
+    }
+
+    #[test]
+    fn test_unknown_checkpoint_action_is_pending() {
+        let response: SignifydDecisionResponse = serde_json::from_str(
+            r#"{"signifydId":4471,"orderId":"pay_1_1","decision":{"checkpointAction":"CREDIT"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            api::IncomingWebhookEvent::from(&response),
+            api::IncomingWebhookEvent::EventNotSupported
+        );
+        assert_eq!(
+            types::FraudCheckResponseData::from(response).status,
+            api::enums::FraudCheckStatus::Pending
+        );
+    }
+}
@@ -15,13 +15,12 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
-    db::StorageInterface,
     headers, services,
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, crypto, ByteSliceExt, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -55,6 +54,16 @@ impl ConnectorCommon for Stripe {
     }
 }
 
+impl api::ConnectorSpecifications for Stripe {
+    fn supports_zero_amount_authorization(&self) -> bool {
+        true
+    }
+
+    fn supports_external_authentication(&self) -> bool {
+        true
+    }
+}
+
 impl api::Payment for Stripe {}
 
 impl api::PaymentAuthorize for Stripe {}
@@ -188,6 +197,7 @@ impl
 
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -288,6 +298,7 @@ impl
 
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -399,6 +410,7 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -505,6 +517,7 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -631,6 +644,7 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -733,6 +747,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -826,6 +841,7 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         Ok(types::ErrorResponse {
             status_code: res.status_code,
+            unified_code: response.error.unified_code(),
             code: response
                 .error
                 .code
@@ -869,13 +885,167 @@ fn get_signature_elements_from_header(
     Ok(security_header_kvs)
 }
 
+impl api::Payouts for Stripe {}
+impl api::PayoutCreate for Stripe {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl api::PayoutFulfill for Stripe {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl api::PayoutCancel for Stripe {}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl api::PayoutSync for Stripe {}
+
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl api::RefundCredit for Stripe {}
+
+impl services::ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Stripe
+{
+}
+
+impl api::ConnectorMandateRevoke for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Stripe
+{
+}
+
+impl api::Dispute for Stripe {}
+impl api::SubmitEvidence for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::SubmitEvidenceType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req = utils::Encode::<stripe::Evidence>::convert_and_url_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+            .headers(types::SubmitEvidenceType::get_headers(
+                self, req, connectors,
+            )?)
+            .body(types::SubmitEvidenceType::get_request_body(self, req)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        let response: stripe::DisputeObj = res
+            .response
+            .parse_struct("Stripe DisputeObj")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            unified_code: response.error.unified_code(),
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Stripe {
-    fn get_webhook_source_verification_algorithm(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::HmacSha256))
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
     }
 
     fn get_webhook_source_verification_signature(
@@ -915,24 +1085,24 @@ impl api::IncomingWebhook for Stripe {
         .into_bytes())
     }
 
-    async fn get_webhook_source_verification_merchant_secret(
-        &self,
-        db: &dyn StorageInterface,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .get_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
-
-        Ok(secret)
-    }
-
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
+        let event_type: stripe::StripeWebhookObjectEventType = request
+            .body
+            .parse_struct("StripeWebhookObjectEventType")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        if event_type.event_type.starts_with("charge.dispute.") {
+            let details: stripe::StripeDisputeWebhookObject = request
+                .body
+                .parse_struct("StripeDisputeWebhookObject")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return Ok(details.data.object.payment_intent);
+        }
+
         let details: stripe::StripeWebhookObjectId = request
             .body
             .parse_struct("StripeWebhookObjectId")
@@ -952,7 +1122,42 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(match details.event_type.as_str() {
             "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
-            "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            // Payment intents captured manually only succeed once they are captured
+            "payment_intent.succeeded" => {
+                let details: stripe::StripePaymentIntentWebhookObject = request
+                    .body
+                    .parse_struct("StripePaymentIntentWebhookObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                match details.data.object.capture_method {
+                    Some(stripe::StripeCaptureMethod::Manual) => {
+                        api::IncomingWebhookEvent::PaymentIntentCaptureSuccess
+                    }
+                    _ => api::IncomingWebhookEvent::PaymentIntentSuccess,
+                }
+            }
+            "payment_intent.canceled" => {
+                let details: stripe::StripePaymentIntentWebhookObject = request
+                    .body
+                    .parse_struct("StripePaymentIntentWebhookObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                match details.data.object.cancellation_reason {
+                    Some(stripe::StripeWebhookCancellationReason::Automatic) => {
+                        api::IncomingWebhookEvent::PaymentIntentAuthorizationExpired
+                    }
+                    _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+                }
+            }
+            "charge.refund.updated" => api::IncomingWebhookEvent::RefundUpdated,
+            "charge.dispute.created" | "charge.dispute.updated" | "charge.dispute.closed" => {
+                let details: stripe::StripeDisputeWebhookObject = request
+                    .body
+                    .parse_struct("StripeDisputeWebhookObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                details.data.object.status.into()
+            }
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
@@ -968,6 +1173,18 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(details.data.object)
     }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let details: stripe::StripeDisputeWebhookObject = request
+            .body
+            .parse_struct("StripeDisputeWebhookObject")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        api::DisputePayload::try_from(details.data.object)
+    }
 }
 
 impl services::ConnectorRedirectResponse for Stripe {
@@ -1,7 +1,10 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{self, enums as api_enums, payments};
-use common_utils::{fp_utils, pii::Email};
+use common_utils::{
+    fp_utils,
+    pii::{Email, IpAddress},
+};
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 use serde::{Deserialize, Serialize};
@@ -33,11 +36,12 @@ impl TryFrom<&types::ConnectorAuthType> for StripeAuthType {
     }
 }
 
-#[derive(Debug, Default, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum StripeCaptureMethod {
     Manual,
     #[default]
+    #[serde(other)]
     Automatic,
 }
 
@@ -86,6 +90,11 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    pub application_fee_amount: Option<i64>,
+    #[serde(rename = "transfer_data[destination]")]
+    pub transfer_destination: Option<String>,
+    #[serde(flatten)]
+    pub mandate_data: Option<StripeMandateRequest>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -119,6 +128,78 @@ pub struct StripeCardData {
     pub payment_method_data_card_cvc: Secret<String>,
     #[serde(rename = "payment_method_options[card][request_three_d_secure]")]
     pub payment_method_auth_type: Auth3ds,
+    #[serde(
+        rename = "payment_method_options[card][network]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payment_method_data_card_preferred_network: Option<StripeCardNetwork>,
+    #[serde(flatten)]
+    pub payment_method_data_card_three_d_secure: Option<StripeThreeDSecureData>,
+}
+
+/// Result of a 3DS authentication made outside of stripe
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeThreeDSecureData {
+    #[serde(rename = "payment_method_options[card][three_d_secure][cryptogram]")]
+    pub cryptogram: Secret<String>,
+    #[serde(
+        rename = "payment_method_options[card][three_d_secure][electronic_commerce_indicator]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub electronic_commerce_indicator: Option<String>,
+    #[serde(
+        rename = "payment_method_options[card][three_d_secure][transaction_id]",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transaction_id: Option<String>,
+    #[serde(rename = "payment_method_options[card][three_d_secure][version]")]
+    pub version: String,
+    #[serde(rename = "payment_method_options[card][three_d_secure][ares_trans_status]")]
+    pub ares_trans_status: String,
+}
+
+impl From<&types::ExternalAuthenticationData> for StripeThreeDSecureData {
+    fn from(authentication_data: &types::ExternalAuthenticationData) -> Self {
+        Self {
+            cryptogram: authentication_data.cavv.clone(),
+            electronic_commerce_indicator: authentication_data.eci.clone(),
+            transaction_id: authentication_data.ds_trans_id.clone(),
+            version: authentication_data.message_version.clone(),
+            ares_trans_status: authentication_data.trans_status.clone(),
+        }
+    }
+}
+
+/// Networks on which stripe can be asked to process a co-badged card
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardNetwork {
+    Amex,
+    CartesBancaires,
+    Diners,
+    Discover,
+    Interac,
+    Jcb,
+    Mastercard,
+    Unionpay,
+    Visa,
+}
+
+impl StripeCardNetwork {
+    fn from_card_network(network: &api_enums::CardNetwork) -> Option<Self> {
+        match network {
+            api_enums::CardNetwork::AmericanExpress => Some(Self::Amex),
+            api_enums::CardNetwork::CartesBancaires => Some(Self::CartesBancaires),
+            api_enums::CardNetwork::DinersClub => Some(Self::Diners),
+            api_enums::CardNetwork::Discover => Some(Self::Discover),
+            api_enums::CardNetwork::Interac => Some(Self::Interac),
+            api_enums::CardNetwork::JCB => Some(Self::Jcb),
+            api_enums::CardNetwork::Mastercard => Some(Self::Mastercard),
+            api_enums::CardNetwork::UnionPay => Some(Self::Unionpay),
+            api_enums::CardNetwork::Visa => Some(Self::Visa),
+            api_enums::CardNetwork::RuPay | api_enums::CardNetwork::Maestro => None,
+        }
+    }
 }
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripePayLaterData {
@@ -186,6 +267,92 @@ pub struct StripeBankRedirectData {
     pub bank_specific_data: Option<BankSpecificData>,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(flatten)]
+    pub bank_specific_data: BankDebitSpecificData,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BankDebitSpecificData {
+    UsBankAccount {
+        #[serde(rename = "payment_method_data[us_bank_account][account_number]")]
+        account_number: Secret<String>,
+        #[serde(rename = "payment_method_data[us_bank_account][routing_number]")]
+        routing_number: Secret<String>,
+        #[serde(rename = "payment_method_data[us_bank_account][account_type]")]
+        account_type: api_enums::BankAccountType,
+        // The account is verified with micro-deposits, as it is not linked through Stripe's
+        // Financial Connections
+        #[serde(rename = "payment_method_options[us_bank_account][verification_method]")]
+        verification_method: StripeVerificationMethod,
+    },
+    SepaDebit {
+        #[serde(rename = "payment_method_data[sepa_debit][iban]")]
+        iban: Secret<String>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeVerificationMethod {
+    Microdeposits,
+}
+
+/// Acceptance of the debit mandate by the customer, which Stripe requires for bank debits
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeMandateRequest {
+    #[serde(rename = "mandate_data[customer_acceptance][type]")]
+    pub acceptance_type: payments::AcceptanceType,
+    #[serde(rename = "mandate_data[customer_acceptance][online][ip_address]")]
+    pub ip_address: Option<Secret<String, IpAddress>>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][user_agent]")]
+    pub user_agent: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for StripeMandateRequest {
+    type Error = errors::ConnectorError;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        if let Some(customer_acceptance) = item
+            .request
+            .setup_mandate_details
+            .as_ref()
+            .map(|mandate_data| &mandate_data.customer_acceptance)
+        {
+            let online = customer_acceptance.online.as_ref();
+            return Ok(Self {
+                acceptance_type: customer_acceptance.acceptance_type.clone(),
+                ip_address: online.map(|online| online.ip_address.clone()),
+                user_agent: online.map(|online| online.user_agent.clone()),
+            });
+        }
+
+        // Customers accept the mandate text of one-time debits on the checkout page, from which
+        // the details of their browser are sent
+        let browser_info = item.request.browser_info.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "browser_info",
+            },
+        )?;
+        let ip_address =
+            browser_info
+                .ip_address
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.ip_address",
+                })?;
+        Ok(Self {
+            acceptance_type: payments::AcceptanceType::Online,
+            ip_address: Some(Secret::new(ip_address.to_string())),
+            user_agent: Some(browser_info.user_agent.clone()),
+        })
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StripePaymentMethodData {
@@ -193,6 +360,7 @@ pub enum StripePaymentMethodData {
     PayLater(StripePayLaterData),
     Wallet,
     BankRedirect(StripeBankRedirectData),
+    BankDebit(StripeBankDebitData),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -206,6 +374,8 @@ pub enum StripePaymentMethodType {
     Giropay,
     Ideal,
     Sofort,
+    UsBankAccount,
+    SepaDebit,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -417,7 +587,7 @@ impl TryFrom<(&api_models::payments::PayLaterData, StripePaymentMethodType)>
                 country: Some(billing_country.to_owned()),
                 ..Self::default()
             }),
-            (payments::PayLaterData::AffirmRedirect {}, StripePaymentMethodType::Affirm) => {
+            (payments::PayLaterData::AffirmRedirect { .. }, StripePaymentMethodType::Affirm) => {
                 Ok(Self::default())
             }
             (
@@ -482,6 +652,8 @@ fn create_stripe_payment_method(
     experience: Option<&enums::PaymentExperience>,
     payment_method_data: &api_models::payments::PaymentMethodData,
     auth_type: enums::AuthenticationType,
+    card_network: Option<&api_enums::CardNetwork>,
+    external_authentication_data: Option<&types::ExternalAuthenticationData>,
 ) -> Result<
     (
         StripePaymentMethodData,
@@ -492,9 +664,13 @@ fn create_stripe_payment_method(
 > {
     match payment_method_data {
         payments::PaymentMethodData::Card(card_details) => {
+            let three_d_secure = external_authentication_data.map(StripeThreeDSecureData::from);
+            // Cardholders already authenticated are not asked to authenticate again
             let payment_method_auth_type = match auth_type {
-                enums::AuthenticationType::ThreeDs => Auth3ds::Any,
-                enums::AuthenticationType::NoThreeDs => Auth3ds::Automatic,
+                enums::AuthenticationType::ThreeDs if three_d_secure.is_none() => Auth3ds::Any,
+                enums::AuthenticationType::ThreeDs | enums::AuthenticationType::NoThreeDs => {
+                    Auth3ds::Automatic
+                }
             };
             Ok((
                 StripePaymentMethodData::Card(StripeCardData {
@@ -505,6 +681,9 @@ fn create_stripe_payment_method(
                     payment_method_data_card_exp_year: card_details.card_exp_year.clone(),
                     payment_method_data_card_cvc: card_details.card_cvc.clone(),
                     payment_method_auth_type,
+                    payment_method_data_card_preferred_network: card_network
+                        .and_then(StripeCardNetwork::from_card_network),
+                    payment_method_data_card_three_d_secure: three_d_secure,
                 }),
                 StripePaymentMethodType::Card,
                 StripeBillingAddress::default(),
@@ -549,6 +728,46 @@ fn create_stripe_payment_method(
                 billing_address,
             ))
         }
+        payments::PaymentMethodData::BankDebit(bank_debit_data) => {
+            let (pm_type, bank_specific_data, billing_details) = match bank_debit_data {
+                payments::BankDebitData::AchBankDebit {
+                    billing_details,
+                    account_number,
+                    routing_number,
+                    bank_account_type,
+                } => (
+                    StripePaymentMethodType::UsBankAccount,
+                    BankDebitSpecificData::UsBankAccount {
+                        account_number: account_number.clone(),
+                        routing_number: routing_number.clone(),
+                        account_type: bank_account_type.unwrap_or_default(),
+                        verification_method: StripeVerificationMethod::Microdeposits,
+                    },
+                    billing_details,
+                ),
+                payments::BankDebitData::SepaBankDebit {
+                    billing_details,
+                    iban,
+                } => (
+                    StripePaymentMethodType::SepaDebit,
+                    BankDebitSpecificData::SepaDebit { iban: iban.clone() },
+                    billing_details,
+                ),
+            };
+            Ok((
+                StripePaymentMethodData::BankDebit(StripeBankDebitData {
+                    payment_method_types: pm_type.clone(),
+                    payment_method_data_type: pm_type.clone(),
+                    bank_specific_data,
+                }),
+                pm_type,
+                StripeBillingAddress {
+                    email: Some(billing_details.email.clone()),
+                    name: Some(billing_details.name.clone()),
+                    ..StripeBillingAddress::default()
+                },
+            ))
+        }
         _ => Err(errors::ConnectorError::NotImplemented(
             "stripe does not support this payment method".to_string(),
         )),
@@ -606,6 +825,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                             item.request.payment_experience.as_ref(),
                             &item.request.payment_method_data,
                             item.auth_type,
+                            item.request.card_network.as_ref(),
+                            item.request.external_authentication_data.as_ref(),
                         )?;
 
                     validate_shipping_address_against_payment_method(
@@ -619,6 +840,26 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             }
         };
 
+        // Stripe transfers the amount left after the application fee to the destination account
+        let (application_fee_amount, transfer_destination) = item
+            .request
+            .split_payments
+            .as_ref()
+            .map(|split_payments| {
+                (
+                    Some(split_payments.application_fee),
+                    Some(split_payments.destination_account_id.clone()),
+                )
+            })
+            .unwrap_or_default();
+
+        let mandate_data = match payment_data {
+            Some(StripePaymentMethodData::BankDebit(_)) => {
+                Some(StripeMandateRequest::try_from(item)?)
+            }
+            _ => None,
+        };
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -639,6 +880,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
             payment_data,
             mandate,
+            application_fee_amount,
+            transfer_destination,
+            mandate_data,
         })
     }
 }
@@ -751,6 +995,7 @@ impl std::ops::Deref for PaymentSyncResponse {
 pub struct LastPaymentError {
     code: String,
     message: String,
+    decline_code: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -790,12 +1035,9 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item.response.next_action.map(|next_action| {
+            services::RedirectForm::from((next_action.get_url(), services::Method::Get))
+        });
 
         let mandate_reference =
             item.response
@@ -818,6 +1060,9 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -838,11 +1083,9 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                services::RedirectForm::from((response.url.clone(), services::Method::Get))
-            },
-        );
+        let redirection_data = item.response.next_action.as_ref().map(|next_action| {
+            services::RedirectForm::from((next_action.clone().get_url(), services::Method::Get))
+        });
 
         let mandate_reference =
             item.response
@@ -858,7 +1101,9 @@ impl<F, T>
                     | StripePaymentMethodOptions::Eps {}
                     | StripePaymentMethodOptions::Giropay {}
                     | StripePaymentMethodOptions::Ideal {}
-                    | StripePaymentMethodOptions::Sofort {} => None,
+                    | StripePaymentMethodOptions::Sofort {}
+                    | StripePaymentMethodOptions::UsBankAccount {}
+                    | StripePaymentMethodOptions::SepaDebit {} => None,
                 });
 
         let error_res =
@@ -870,6 +1115,10 @@ impl<F, T>
                     message: error.message.to_owned(),
                     reason: None,
                     status_code: item.http_code,
+                    unified_code: get_unified_code(
+                        Some(error.code.as_str()),
+                        error.decline_code.as_deref(),
+                    ),
                 });
 
         let response = error_res.map_or(
@@ -878,6 +1127,9 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             Err,
         );
@@ -899,12 +1151,9 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data =
-            item.response
-                .next_action
-                .map(|StripeNextActionResponse::RedirectToUrl(response)| {
-                    services::RedirectForm::from((response.url, services::Method::Get))
-                });
+        let redirection_data = item.response.next_action.map(|next_action| {
+            services::RedirectForm::from((next_action.get_url(), services::Method::Get))
+        });
 
         let mandate_reference =
             item.response
@@ -923,6 +1172,9 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -933,6 +1185,18 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    VerifyWithMicrodeposits(StripeVerifyWithMicroDepositsResponse),
+}
+
+impl StripeNextActionResponse {
+    /// The page the customer is sent to, which for micro-deposit verifications is the page hosted
+    /// by Stripe to enter the amounts of the deposits
+    fn get_url(self) -> Url {
+        match self {
+            Self::RedirectToUrl(response) => response.url,
+            Self::VerifyWithMicrodeposits(response) => response.hosted_verification_url,
+        }
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -961,6 +1225,12 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeVerifyWithMicroDepositsResponse {
+    arrival_date: i64,
+    hosted_verification_url: Url,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -974,6 +1244,17 @@ pub struct RefundRequest {
     pub metadata_txn_id: String,
     #[serde(rename = "metadata[txn_uuid]")]
     pub metadata_txn_uuid: String,
+    pub reason: Option<StripeRefundReason>,
+    #[serde(flatten)]
+    pub merchant_metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRefundReason {
+    Duplicate,
+    Fraudulent,
+    RequestedByCustomer,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
@@ -983,16 +1264,48 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
         let metadata_txn_id = "Fetch txn_id from DB".to_string();
         let metadata_txn_uuid = "Fetch txn_id from DB".to_string();
         let payment_intent = item.request.connector_transaction_id.clone();
+        // Stripe has no reason for returned products, nor a catch-all one
+        let reason = item.request.reason_code.and_then(|reason| match reason {
+            enums::RefundReason::Duplicate => Some(StripeRefundReason::Duplicate),
+            enums::RefundReason::Fraudulent => Some(StripeRefundReason::Fraudulent),
+            enums::RefundReason::RequestedByCustomer | enums::RefundReason::ProductReturned => {
+                Some(StripeRefundReason::RequestedByCustomer)
+            }
+            enums::RefundReason::Other => None,
+        });
         Ok(Self {
             amount: Some(amount),
             payment_intent,
             metadata_order_id: item.payment_id.clone(),
             metadata_txn_id,
             metadata_txn_uuid,
+            reason,
+            merchant_metadata: get_refund_metadata(item.request.metadata.as_ref()),
         })
     }
 }
 
+/// Stripe takes the metadata as form fields of string values, the keys set by the connector
+/// itself taking precedence over those of the merchant
+fn get_refund_metadata(metadata: Option<&serde_json::Value>) -> HashMap<String, String> {
+    metadata
+        .and_then(serde_json::Value::as_object)
+        .map(|metadata| {
+            metadata
+                .iter()
+                .filter(|(key, _)| !["order_id", "txn_id", "txn_uuid"].contains(&key.as_str()))
+                .map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(value) => value.clone(),
+                        value => value.to_string(),
+                    };
+                    (format!("metadata[{key}]"), value)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 // Type definition for Stripe Refund Response
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone)]
@@ -1068,6 +1381,56 @@ pub struct ErrorDetails {
     pub error_type: Option<String>,
     pub message: Option<String>,
     pub param: Option<String>,
+    pub decline_code: Option<String>,
+}
+
+impl ErrorDetails {
+    pub fn unified_code(&self) -> Option<enums::UnifiedCode> {
+        get_unified_code(self.code.as_deref(), self.decline_code.as_deref())
+    }
+}
+
+/// Declined card payments have the `card_declined` error code, with the reason given by the issuer
+/// in the decline code, which is then the one to map.
+fn get_unified_code(code: Option<&str>, decline_code: Option<&str>) -> Option<enums::UnifiedCode> {
+    match decline_code.or(code)? {
+        "insufficient_funds" => Some(enums::UnifiedCode::InsufficientFunds),
+        "do_not_honor" => Some(enums::UnifiedCode::DoNotHonor),
+        "expired_card" => Some(enums::UnifiedCode::ExpiredCard),
+        "incorrect_cvc" | "invalid_cvc" => Some(enums::UnifiedCode::IncorrectCvc),
+        "incorrect_number" | "invalid_number" | "invalid_account" => {
+            Some(enums::UnifiedCode::InvalidCardNumber)
+        }
+        "invalid_expiry_month" | "invalid_expiry_year" => {
+            Some(enums::UnifiedCode::InvalidExpiryDate)
+        }
+        "lost_card" => Some(enums::UnifiedCode::LostCard),
+        "stolen_card" => Some(enums::UnifiedCode::StolenCard),
+        "pickup_card" => Some(enums::UnifiedCode::PickupCard),
+        "restricted_card" => Some(enums::UnifiedCode::RestrictedCard),
+        "card_not_supported" | "currency_not_supported" => {
+            Some(enums::UnifiedCode::CardNotSupported)
+        }
+        "transaction_not_allowed" | "not_permitted" | "revocation_of_authorization" => {
+            Some(enums::UnifiedCode::TransactionNotAllowed)
+        }
+        "card_velocity_exceeded" | "withdrawal_count_limit_exceeded" => {
+            Some(enums::UnifiedCode::LimitExceeded)
+        }
+        "fraudulent" | "merchant_blacklist" | "security_violation" => {
+            Some(enums::UnifiedCode::FraudSuspected)
+        }
+        "authentication_required" => Some(enums::UnifiedCode::AuthenticationRequired),
+        "payment_intent_authentication_failure" => Some(enums::UnifiedCode::AuthenticationFailed),
+        "invalid_amount" | "amount_too_large" | "amount_too_small" => {
+            Some(enums::UnifiedCode::InvalidAmount)
+        }
+        "duplicate_transaction" => Some(enums::UnifiedCode::DuplicateTransaction),
+        "issuer_not_available" | "try_again_later" => Some(enums::UnifiedCode::IssuerUnavailable),
+        "processing_error" => Some(enums::UnifiedCode::ProcessingError),
+        "card_declined" | "generic_decline" => Some(enums::UnifiedCode::GenericDecline),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
@@ -1161,6 +1524,8 @@ pub enum StripePaymentMethodOptions {
     Giropay {},
     Ideal {},
     Sofort {},
+    UsBankAccount {},
+    SepaDebit {},
 }
 // #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 // pub struct Card
@@ -1252,6 +1617,245 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StripePaymentIntentWebhookObject {
+    pub data: StripePaymentIntentWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripePaymentIntentWebhookData {
+    pub object: StripePaymentIntentWebhookDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripePaymentIntentWebhookDetails {
+    pub capture_method: Option<StripeCaptureMethod>,
+    pub cancellation_reason: Option<StripeWebhookCancellationReason>,
+}
+
+/// Stripe cancels payment intents itself, with the `automatic` reason, when their authorization
+/// expires before being captured
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeWebhookCancellationReason {
+    Automatic,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookObject {
+    pub data: StripeDisputeWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookData {
+    pub object: StripeDisputeObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeObject {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: String,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+    pub created: i64,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+}
+
+impl From<StripeDisputeStatus> for enums::DisputeStatus {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse | StripeDisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            StripeDisputeStatus::WarningUnderReview | StripeDisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            StripeDisputeStatus::WarningClosed => Self::DisputeCancelled,
+            StripeDisputeStatus::ChargeRefunded => Self::DisputeAccepted,
+            StripeDisputeStatus::Won => Self::DisputeWon,
+            StripeDisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+impl From<StripeDisputeStatus> for api::IncomingWebhookEvent {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match enums::DisputeStatus::from(status) {
+            enums::DisputeStatus::DisputeOpened => Self::DisputeOpened,
+            enums::DisputeStatus::DisputeExpired => Self::DisputeExpired,
+            enums::DisputeStatus::DisputeAccepted => Self::DisputeAccepted,
+            enums::DisputeStatus::DisputeCancelled => Self::DisputeCancelled,
+            enums::DisputeStatus::DisputeChallenged => Self::DisputeChallenged,
+            enums::DisputeStatus::DisputeWon => Self::DisputeWon,
+            enums::DisputeStatus::DisputeLost => Self::DisputeLost,
+        }
+    }
+}
+
+fn get_datetime_from_unix_timestamp(
+    timestamp: i64,
+) -> Result<time::PrimitiveDateTime, error_stack::Report<errors::ConnectorError>> {
+    let date_time = time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .into_report()
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+    Ok(time::PrimitiveDateTime::new(
+        date_time.date(),
+        date_time.time(),
+    ))
+}
+
+impl TryFrom<StripeDisputeObject> for api::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(dispute: StripeDisputeObject) -> Result<Self, Self::Error> {
+        let dispute_stage = match dispute.status {
+            StripeDisputeStatus::WarningNeedsResponse
+            | StripeDisputeStatus::WarningUnderReview
+            | StripeDisputeStatus::WarningClosed => api_enums::DisputeStage::PreDispute,
+            _ => api_enums::DisputeStage::Dispute,
+        };
+        let challenge_required_by = dispute
+            .evidence_details
+            .and_then(|evidence_details| evidence_details.due_by)
+            .map(get_datetime_from_unix_timestamp)
+            .transpose()?;
+
+        Ok(Self {
+            amount: dispute.amount.to_string(),
+            currency: dispute.currency,
+            dispute_stage,
+            connector_status: dispute.status.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by,
+            created_at: Some(get_datetime_from_unix_timestamp(dispute.created)?),
+            updated_at: None,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Evidence {
+    #[serde(rename = "evidence[access_activity_log]")]
+    pub access_activity_log: Option<String>,
+    #[serde(rename = "evidence[billing_address]")]
+    pub billing_address: Option<String>,
+    #[serde(rename = "evidence[cancellation_policy_disclosure]")]
+    pub cancellation_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[cancellation_rebuttal]")]
+    pub cancellation_rebuttal: Option<String>,
+    #[serde(rename = "evidence[customer_email_address]")]
+    pub customer_email_address: Option<String>,
+    #[serde(rename = "evidence[customer_name]")]
+    pub customer_name: Option<String>,
+    #[serde(rename = "evidence[customer_purchase_ip]")]
+    pub customer_purchase_ip: Option<String>,
+    #[serde(rename = "evidence[product_description]")]
+    pub product_description: Option<String>,
+    #[serde(rename = "evidence[refund_policy_disclosure]")]
+    pub refund_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[refund_refusal_explanation]")]
+    pub refund_refusal_explanation: Option<String>,
+    #[serde(rename = "evidence[service_date]")]
+    pub service_date: Option<String>,
+    #[serde(rename = "evidence[shipping_address]")]
+    pub shipping_address: Option<String>,
+    #[serde(rename = "evidence[shipping_carrier]")]
+    pub shipping_carrier: Option<String>,
+    #[serde(rename = "evidence[shipping_date]")]
+    pub shipping_date: Option<String>,
+    #[serde(rename = "evidence[shipping_tracking_number]")]
+    pub shipping_tracking_number: Option<String>,
+    #[serde(rename = "evidence[uncategorized_text]")]
+    pub uncategorized_text: Option<String>,
+    pub submit: bool,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for Evidence {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let submit_evidence_request_data = item.request.clone();
+        Ok(Self {
+            access_activity_log: submit_evidence_request_data.access_activity_log,
+            billing_address: submit_evidence_request_data.billing_address,
+            cancellation_policy_disclosure: submit_evidence_request_data
+                .cancellation_policy_disclosure,
+            cancellation_rebuttal: submit_evidence_request_data.cancellation_rebuttal,
+            customer_email_address: submit_evidence_request_data.customer_email_address,
+            customer_name: submit_evidence_request_data.customer_name,
+            customer_purchase_ip: submit_evidence_request_data.customer_purchase_ip,
+            product_description: submit_evidence_request_data.product_description,
+            refund_policy_disclosure: submit_evidence_request_data.refund_policy_disclosure,
+            refund_refusal_explanation: submit_evidence_request_data.refund_refusal_explanation,
+            service_date: submit_evidence_request_data.service_date,
+            shipping_address: submit_evidence_request_data.shipping_address,
+            shipping_carrier: submit_evidence_request_data.shipping_carrier,
+            shipping_date: submit_evidence_request_data.shipping_date,
+            shipping_tracking_number: submit_evidence_request_data.shipping_tracking_number,
+            uncategorized_text: submit_evidence_request_data.uncategorized_text,
+            submit: true,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DisputeObj {
+    pub id: String,
+    pub status: StripeDisputeStatus,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Evidence,
+            DisputeObj,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    > for types::SubmitEvidenceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Evidence,
+            DisputeObj,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: enums::DisputeStatus::from(item.response.status),
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl
     TryFrom<(
         api::PaymentMethodData,
@@ -1281,6 +1885,8 @@ impl
                     payment_method_data_card_exp_year: ccard.card_exp_year.clone(),
                     payment_method_data_card_cvc: ccard.card_cvc.clone(),
                     payment_method_auth_type,
+                    payment_method_data_card_preferred_network: None,
+                    payment_method_data_card_three_d_secure: None,
                 }
             })),
             api::PaymentMethodData::PayLater(_) => Ok(Self::PayLater(StripePayLaterData {
@@ -1296,6 +1902,86 @@ impl
                 }))
             }
             api::PaymentMethodData::Wallet(_) => Ok(Self::Wallet),
+            api::PaymentMethodData::BankTransfer(_)
+            | api::PaymentMethodData::Voucher(_)
+            | api::PaymentMethodData::Upi(_)
+            | api::PaymentMethodData::BankDebit(_)
+            | api::PaymentMethodData::Crypto(_) => Err(errors::ConnectorError::NotImplemented(
+                "Payment method".to_string(),
+            )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_unified_code_of_declined_card() {
+        let error = ErrorDetails {
+            code: Some("card_declined".to_string()),
+            decline_code: Some("insufficient_funds".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            error.unified_code(),
+            Some(enums::UnifiedCode::InsufficientFunds)
+        );
+
+        let error = ErrorDetails {
+            code: Some("expired_card".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(error.unified_code(), Some(enums::UnifiedCode::ExpiredCard));
+
+        let error = ErrorDetails {
+            code: Some("parameter_missing".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(error.unified_code(), None);
+    }
+
+    #[test]
+    fn test_micro_deposit_verification_next_action() {
+        let next_action: StripeNextActionResponse = serde_json::from_str(
+            r#"{
+                "type": "verify_with_microdeposits",
+                "verify_with_microdeposits": {
+                    "arrival_date": 1687392000,
+                    "hosted_verification_url": "https://payments.stripe.com/microdeposit/pacs_test",
+                    "microdeposit_type": "descriptor_code"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            next_action.get_url().as_str(),
+            "https://payments.stripe.com/microdeposit/pacs_test"
+        );
+    }
+
+    #[test]
+    fn test_refund_metadata() {
+        let metadata = serde_json::json!({
+            "ticket": "T-1042",
+            "items": 2,
+            "order_id": "ord_1"
+        });
+        let metadata = get_refund_metadata(Some(&metadata));
+
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(
+            metadata.get("metadata[ticket]").map(String::as_str),
+            Some("T-1042")
+        );
+        assert_eq!(
+            metadata.get("metadata[items]").map(String::as_str),
+            Some("2")
+        );
+        assert!(get_refund_metadata(None).is_empty());
+    }
+}
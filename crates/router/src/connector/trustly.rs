@@ -0,0 +1,504 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use transformers as trustly;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    db::StorageInterface,
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, ByteSliceExt, BytesExt, ValueExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Trustly;
+
+impl api::Payment for Trustly {}
+impl api::PaymentSession for Trustly {}
+impl api::ConnectorAccessToken for Trustly {}
+impl api::PreVerify for Trustly {}
+impl api::PaymentAuthorize for Trustly {}
+impl api::PaymentSync for Trustly {}
+impl api::PaymentCapture for Trustly {}
+impl api::PaymentVoid for Trustly {}
+impl api::Refund for Trustly {}
+impl api::RefundExecute for Trustly {}
+impl api::RefundSync for Trustly {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Trustly
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        _req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The credentials are sent, and signed, as part of the call data
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )])
+    }
+}
+
+impl ConnectorCommon for Trustly {
+    fn id(&self) -> &'static str {
+        "trustly"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.trustly.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: trustly::TrustlyErrorResponse = res
+            .response
+            .parse_struct("Trustly ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(response.error.get_error_response(res.status_code))
+    }
+}
+
+impl api::ConnectorSpecifications for Trustly {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::BankRedirect])
+    }
+
+    fn supports_mandates(&self) -> bool {
+        false
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Trustly
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Trustly
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Trustly
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Trustly
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}1", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let trustly_req = utils::Encode::<
+            trustly::TrustlyRequest<trustly::TrustlyDepositData>,
+        >::convert_and_encode(req)
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(trustly_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(trustly_deposit_response=?res);
+        let response: trustly::TrustlyResponse<trustly::TrustlyDepositResponseData> = res
+            .response
+            .parse_struct("Trustly DepositResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Trustly
+{
+    fn build_request(
+        &self,
+        _req: &types::PaymentsSyncRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        // Deposits cannot be fetched, they are only updated by the notifications from Trustly
+        Ok(None)
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(trustly_notification=?res);
+        let response: trustly::TrustlyNotification = res
+            .response
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Trustly
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Trustly
+{
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Trustly
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}1", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let trustly_req =
+            utils::Encode::<trustly::TrustlyRequest<trustly::TrustlyRefundData>>::convert_and_encode(
+                req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(trustly_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(trustly_refund_response=?res);
+        let response: trustly::TrustlyResponse<trustly::TrustlyRefundResponseData> = res
+            .response
+            .parse_struct("Trustly RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+// Refunds are confirmed by the debit notifications, there is no call to fetch them
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData> for Trustly {}
+
+impl api::Payouts for Trustly {}
+impl api::PayoutCreate for Trustly {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Trustly
+{
+}
+
+impl api::PayoutFulfill for Trustly {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Trustly
+{
+}
+
+impl api::PayoutCancel for Trustly {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Trustly
+{
+}
+
+impl api::PayoutSync for Trustly {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData> for Trustly {}
+
+impl api::RefundCredit for Trustly {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData> for Trustly {}
+
+impl api::ConnectorMandateRevoke for Trustly {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Trustly
+{
+}
+
+impl api::Dispute for Trustly {}
+impl api::SubmitEvidence for Trustly {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Trustly
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Trustly {
+    // The notifications are signed with the public key published by Trustly
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::RsaSha1)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let notification: trustly::TrustlyNotification = request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+
+        consts::BASE64_ENGINE
+            .decode(notification.params.signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let notification: trustly::TrustlyNotification = request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = trustly::get_signature_message(
+            notification.method,
+            &notification.params.uuid,
+            &notification.params.data,
+        )
+        .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        Ok(message.into_bytes())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let notification: trustly::TrustlyNotification = request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        notification.get_order_id()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let notification: trustly::TrustlyNotification = request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        notification.method.try_into()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    // The acknowledgement of a notification is signed with the private key of the merchant
+    async fn get_webhook_api_response_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let merchant_connector_account = db
+            .find_merchant_connector_account_by_merchant_id_connector(merchant_id, self.id())
+            .await
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth = trustly::TrustlyAuthType::try_from(&auth_type)?;
+
+        Ok(auth.private_key.peek().as_bytes().to_vec())
+    }
+
+    fn get_webhook_api_response(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_secret: &[u8],
+    ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
+    {
+        let notification: trustly::TrustlyNotification = request
+            .body
+            .parse_struct("Trustly Notification")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let private_key = String::from_utf8(merchant_secret.to_vec())
+            .into_report()
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let response = trustly::TrustlyNotificationResponse::try_new(
+            notification,
+            &masking::Secret::new(private_key),
+        )?;
+
+        utils::Encode::<trustly::TrustlyNotificationResponse>::encode_to_value(&response)
+            .map(services::api::ApplicationResponse::Json)
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+}
+
+impl services::ConnectorRedirectResponse for Trustly {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
@@ -0,0 +1,507 @@
+use base64::Engine;
+use common_utils::{
+    crypto::{self, SignMessage},
+    pii::Email,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{AddressDetailsData, PaymentsAuthorizeRequestData, RouterData},
+    consts,
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+const JSON_RPC_VERSION: &str = "1.1";
+
+// Auth Struct
+pub struct TrustlyAuthType {
+    pub(super) username: String,
+    pub(super) password: Secret<String>,
+    /// PEM encoded private key the merchant registered with Trustly
+    pub(super) private_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for TrustlyAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                username: api_key.to_string(),
+                password: Secret::new(key1.to_string()),
+                private_key: Secret::new(api_secret.to_string()),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Trustly posts the outcome of a deposit to a notification url given with every deposit,
+// which is configured in the metadata of the merchant connector account
+#[derive(Debug, Deserialize)]
+pub struct TrustlyConnectorMeta {
+    pub notification_url: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, strum::Display)]
+pub enum TrustlyMethod {
+    Deposit,
+    Refund,
+    #[serde(rename = "credit")]
+    #[strum(serialize = "credit")]
+    Credit,
+    #[serde(rename = "debit")]
+    #[strum(serialize = "debit")]
+    Debit,
+    #[serde(rename = "pending")]
+    #[strum(serialize = "pending")]
+    Pending,
+    #[serde(rename = "cancel")]
+    #[strum(serialize = "cancel")]
+    Cancel,
+    #[serde(rename = "account")]
+    #[strum(serialize = "account")]
+    Account,
+}
+
+/// Flattens the data of a call the way Trustly does before signing it: object keys are
+/// sorted and concatenated with their values, and nulls are left out
+fn serialize_data(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            keys.into_iter()
+                .map(|key| format!("{key}{}", serialize_data(&map[key])))
+                .collect()
+        }
+        serde_json::Value::Array(items) => items.iter().map(serialize_data).collect(),
+        serde_json::Value::String(value) => value.to_owned(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+pub fn get_signature_message<T: Serialize>(
+    method: TrustlyMethod,
+    uuid: &str,
+    data: &T,
+) -> Result<String, Error> {
+    let data = serde_json::to_value(data)
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(format!("{method}{uuid}{}", serialize_data(&data)))
+}
+
+pub fn sign<T: Serialize>(
+    method: TrustlyMethod,
+    uuid: &str,
+    data: &T,
+    private_key: &Secret<String>,
+) -> Result<String, Error> {
+    let message = get_signature_message(method, uuid, data)?;
+    let signature = crypto::RsaSha1
+        .sign_message(private_key.peek().as_bytes(), message.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(consts::BASE64_ENGINE.encode(signature))
+}
+
+fn get_amount(amount: i64) -> String {
+    format!("{}.{:02}", amount / 100, amount % 100)
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrustlyRequest<T> {
+    pub method: TrustlyMethod,
+    pub params: TrustlyParams<T>,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustlyParams<T> {
+    pub signature: String,
+    #[serde(rename = "UUID")]
+    pub uuid: String,
+    pub data: T,
+}
+
+impl<T: Serialize> TrustlyRequest<T> {
+    fn try_new(
+        method: TrustlyMethod,
+        data: T,
+        private_key: &Secret<String>,
+    ) -> Result<Self, Error> {
+        let uuid = uuid::Uuid::new_v4().to_string();
+        let signature = sign(method, &uuid, &data, private_key)?;
+        Ok(Self {
+            method,
+            params: TrustlyParams {
+                signature,
+                uuid,
+                data,
+            },
+            version: JSON_RPC_VERSION.to_string(),
+        })
+    }
+}
+
+// Deposit Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustlyDepositData {
+    username: String,
+    password: Secret<String>,
+    #[serde(rename = "NotificationURL")]
+    notification_url: String,
+    #[serde(rename = "EndUserID")]
+    end_user_id: Secret<String, Email>,
+    #[serde(rename = "MessageID")]
+    message_id: String,
+    attributes: TrustlyDepositAttributes,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustlyDepositAttributes {
+    amount: String,
+    currency: String,
+    country: String,
+    firstname: Secret<String>,
+    lastname: Secret<String>,
+    email: Secret<String, Email>,
+    #[serde(rename = "SuccessURL")]
+    success_url: String,
+    #[serde(rename = "FailURL")]
+    fail_url: String,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for TrustlyRequest<TrustlyDepositData> {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let country = match &item.request.payment_method_data {
+            api::PaymentMethodData::BankRedirect(
+                api_models::payments::BankRedirectData::Trustly { country },
+            ) => country.to_owned(),
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment methods".to_string()).into(),
+                )
+            }
+        };
+        let auth = TrustlyAuthType::try_from(&item.connector_auth_type)?;
+        let meta: TrustlyConnectorMeta = item.to_connector_meta()?;
+        let billing_address = item.get_billing_address()?;
+        let email = item.request.get_email()?;
+        let return_url = item.get_return_url()?;
+        let data = TrustlyDepositData {
+            username: auth.username,
+            password: auth.password,
+            notification_url: meta.notification_url,
+            end_user_id: email.clone(),
+            message_id: item.attempt_id.clone(),
+            attributes: TrustlyDepositAttributes {
+                amount: get_amount(item.request.amount),
+                currency: item.request.currency.to_string(),
+                country,
+                firstname: billing_address.get_first_name()?.to_owned(),
+                lastname: billing_address.get_last_name()?.to_owned(),
+                email,
+                success_url: return_url.clone(),
+                fail_url: return_url,
+            },
+        };
+        Self::try_new(TrustlyMethod::Deposit, data, &auth.private_key)
+    }
+}
+
+// Trustly answers every call with a 200, a failed call carries an error instead of a result
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum TrustlyResponse<T> {
+    Success { result: TrustlyResult<T> },
+    Error { error: TrustlyError },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyResult<T> {
+    pub signature: String,
+    pub uuid: String,
+    pub method: TrustlyMethod,
+    pub data: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyError {
+    pub name: String,
+    pub code: i64,
+    pub message: String,
+}
+
+impl TrustlyError {
+    pub fn get_error_response(self, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: self.code.to_string(),
+            message: self.message,
+            reason: Some(self.name),
+            status_code,
+            unified_code: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyErrorResponse {
+    pub error: TrustlyError,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyDepositResponseData {
+    pub orderid: String,
+    pub url: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            TrustlyResponse<TrustlyDepositResponseData>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TrustlyResponse<TrustlyDepositResponseData>,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        match item.response {
+            TrustlyResponse::Success { result } => Ok(Self {
+                status: enums::AttemptStatus::AuthenticationPending,
+                response: Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(result.data.orderid),
+                    redirection_data: Some(services::RedirectForm::from((
+                        url::Url::parse(&result.data.url)
+                            .into_report()
+                            .change_context(errors::ConnectorError::ResponseHandlingFailed)?,
+                        services::Method::Get,
+                    ))),
+                    mandate_reference: None,
+                    connector_metadata: None,
+                    network_txn_id: None,
+                    avs_result: None,
+                    cvv_result: None,
+                }),
+                ..item.data
+            }),
+            TrustlyResponse::Error { error } => Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(error.get_error_response(item.http_code)),
+                ..item.data
+            }),
+        }
+    }
+}
+
+// Notifications
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrustlyNotification {
+    pub method: TrustlyMethod,
+    pub params: TrustlyNotificationParams,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrustlyNotificationParams {
+    pub signature: String,
+    pub uuid: String,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyNotificationData {
+    pub orderid: String,
+}
+
+impl TrustlyNotification {
+    pub fn get_order_id(&self) -> Result<String, Error> {
+        let data: TrustlyNotificationData = serde_json::from_value(self.params.data.clone())
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(data.orderid)
+    }
+}
+
+impl TryFrom<TrustlyMethod> for api::IncomingWebhookEvent {
+    type Error = Error;
+    fn try_from(method: TrustlyMethod) -> Result<Self, Self::Error> {
+        match method {
+            TrustlyMethod::Credit => Ok(Self::PaymentIntentSuccess),
+            TrustlyMethod::Cancel => Ok(Self::PaymentIntentFailure),
+            // Money leaving the merchant account for an order is the refund being executed
+            TrustlyMethod::Debit => Ok(Self::RefundSuccess),
+            TrustlyMethod::Pending | TrustlyMethod::Account => Ok(Self::EventNotSupported),
+            TrustlyMethod::Deposit | TrustlyMethod::Refund => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+        }
+    }
+}
+
+// Trustly has no call to fetch a deposit, its outcome is only known from the notifications
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, TrustlyNotification, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, TrustlyNotification, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.method {
+            TrustlyMethod::Credit => enums::AttemptStatus::Charged,
+            TrustlyMethod::Cancel => enums::AttemptStatus::Failure,
+            TrustlyMethod::Pending => enums::AttemptStatus::Pending,
+            _ => item.data.status,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.get_order_id()?,
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrustlyNotificationResponse {
+    pub result: TrustlyNotificationResult,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrustlyNotificationResult {
+    pub signature: String,
+    pub uuid: String,
+    pub method: TrustlyMethod,
+    pub data: TrustlyNotificationStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrustlyNotificationStatus {
+    pub status: String,
+}
+
+impl TrustlyNotificationResponse {
+    /// Acknowledges a notification, unacknowledged notifications are sent again by Trustly
+    pub fn try_new(
+        notification: TrustlyNotification,
+        private_key: &Secret<String>,
+    ) -> Result<Self, Error> {
+        let data = TrustlyNotificationStatus {
+            status: "OK".to_string(),
+        };
+        let signature = sign(
+            notification.method,
+            &notification.params.uuid,
+            &data,
+            private_key,
+        )?;
+        Ok(Self {
+            result: TrustlyNotificationResult {
+                signature,
+                uuid: notification.params.uuid,
+                method: notification.method,
+                data,
+            },
+            version: JSON_RPC_VERSION.to_string(),
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustlyRefundData {
+    username: String,
+    password: Secret<String>,
+    #[serde(rename = "OrderID")]
+    order_id: String,
+    amount: String,
+    currency: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for TrustlyRequest<TrustlyRefundData> {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth = TrustlyAuthType::try_from(&item.connector_auth_type)?;
+        let data = TrustlyRefundData {
+            username: auth.username,
+            password: auth.password,
+            order_id: item.request.connector_transaction_id.clone(),
+            amount: get_amount(item.request.refund_amount),
+            currency: item.request.currency.to_string(),
+        };
+        Self::try_new(TrustlyMethod::Refund, data, &auth.private_key)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrustlyRefundResponseData {
+    pub orderid: String,
+    pub result: String,
+}
+
+// A refund has no id of its own at Trustly, it is referred to by the order it was made against.
+// An accepted refund is executed later on and confirmed with a debit notification.
+impl
+    TryFrom<
+        types::RefundsResponseRouterData<api::Execute, TrustlyResponse<TrustlyRefundResponseData>>,
+    > for types::RefundsRouterData<api::Execute>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<
+            api::Execute,
+            TrustlyResponse<TrustlyRefundResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response {
+            TrustlyResponse::Success { result } => Ok(types::RefundsResponseData {
+                refund_status: if result.data.result == "1" {
+                    enums::RefundStatus::Pending
+                } else {
+                    enums::RefundStatus::Failure
+                },
+                connector_refund_id: result.data.orderid,
+            }),
+            TrustlyResponse::Error { error } => Err(error.get_error_response(item.http_code)),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
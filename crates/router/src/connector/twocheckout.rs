@@ -0,0 +1,596 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use common_utils::crypto::{self, SignMessage};
+use error_stack::{IntoReport, ResultExt};
+use time::{format_description, OffsetDateTime};
+use transformers as twocheckout;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    db::StorageInterface,
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt, ValueExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Twocheckout;
+
+impl Twocheckout {
+    // Requests are authenticated with an HMAC over the merchant code and the request date, each
+    // prefixed by its length
+    fn get_authentication_header(
+        &self,
+        auth: &twocheckout::TwocheckoutAuthType,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidDateFormat)?;
+        let date = OffsetDateTime::now_utc()
+            .format(&format)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidDateFormat)?;
+        let message = format!(
+            "{}{}{}{}",
+            auth.merchant_code.len(),
+            auth.merchant_code,
+            date.len(),
+            date
+        );
+        let hash = crypto::HmacSha256
+            .sign_message(auth.secret_key.as_bytes(), message.as_bytes())
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to sign the message")?;
+        Ok(format!(
+            "code=\"{}\" date=\"{}\" hash=\"{}\" algo=\"sha256\"",
+            auth.merchant_code,
+            date,
+            hex::encode(hash)
+        ))
+    }
+
+    async fn get_secret_key(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let merchant_connector_account = db
+            .find_merchant_connector_account_by_merchant_id_connector(merchant_id, self.id())
+            .await
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth = twocheckout::TwocheckoutAuthType::try_from(&auth_type)?;
+
+        Ok(auth.secret_key.into_bytes())
+    }
+}
+
+impl api::Payment for Twocheckout {}
+impl api::PaymentSession for Twocheckout {}
+impl api::ConnectorAccessToken for Twocheckout {}
+impl api::PreVerify for Twocheckout {}
+impl api::PaymentAuthorize for Twocheckout {}
+impl api::PaymentSync for Twocheckout {}
+impl api::PaymentCapture for Twocheckout {}
+impl api::PaymentVoid for Twocheckout {}
+impl api::Refund for Twocheckout {}
+impl api::RefundExecute for Twocheckout {}
+impl api::RefundSync for Twocheckout {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Twocheckout
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string(),
+            ),
+            (
+                headers::ACCEPT.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Twocheckout {
+    fn id(&self) -> &'static str {
+        "twocheckout"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.twocheckout.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = twocheckout::TwocheckoutAuthType::try_from(auth_type)?;
+        Ok(vec![(
+            headers::X_AVANGATE_AUTHENTICATION.to_string(),
+            self.get_authentication_header(&auth)?,
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: twocheckout::TwocheckoutErrorResponse = res
+            .response
+            .parse_struct("Twocheckout ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+            unified_code: None,
+        })
+    }
+}
+
+impl api::ConnectorSpecifications for Twocheckout {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Twocheckout
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Twocheckout
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Twocheckout
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Twocheckout
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}orders/", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let twocheckout_req =
+            utils::Encode::<twocheckout::TwocheckoutPaymentsRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(twocheckout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(twocheckout_payments_response=?res);
+        let response: twocheckout::TwocheckoutPaymentsResponse = res
+            .response
+            .parse_struct("Twocheckout PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Twocheckout
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let ref_no = req
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        Ok(format!("{}orders/{}/", self.base_url(connectors), ref_no))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(twocheckout_payment_sync_response=?res);
+        let response: twocheckout::TwocheckoutPaymentsResponse = res
+            .response
+            .parse_struct("Twocheckout PaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Twocheckout
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Twocheckout
+{
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Twocheckout
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}orders/{}/refund/",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let twocheckout_req =
+            utils::Encode::<twocheckout::TwocheckoutRefundRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(twocheckout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(twocheckout_refund_response=?res);
+        let response: bool = res
+            .response
+            .parse_struct("Twocheckout RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+// Refunds are issued synchronously and 2Checkout has no endpoint to look a single one up
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::Payouts for Twocheckout {}
+impl api::PayoutCreate for Twocheckout {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::PayoutFulfill for Twocheckout {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::PayoutCancel for Twocheckout {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::PayoutSync for Twocheckout {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::RefundCredit for Twocheckout {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Twocheckout
+{
+}
+
+impl api::ConnectorMandateRevoke for Twocheckout {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Twocheckout
+{
+}
+
+impl api::Dispute for Twocheckout {}
+impl api::SubmitEvidence for Twocheckout {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Twocheckout
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Twocheckout {
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)?;
+        let signature = ipn
+            .get_field("SIGNATURE_SHA2_256")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)?;
+        Ok(ipn.get_signature_message())
+    }
+
+    // IPNs are signed with the secret key of the merchant account
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        self.get_secret_key(db, merchant_id)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(ipn.get_ref_no()?.to_string())
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(ipn.get_order_status()?))
+    }
+
+    // The notification carries the order reference and status, which is handed to the sync
+    // flow in the shape of an order response
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<twocheckout::TwocheckoutPaymentsResponse>::encode_to_value(
+            &twocheckout::TwocheckoutPaymentsResponse::try_from(&ipn)?,
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    // 2Checkout keeps resending an IPN until it is acknowledged with a signed read receipt
+    async fn get_webhook_api_response_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        self.get_secret_key(db, merchant_id).await
+    }
+
+    fn get_webhook_api_response(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_secret: &[u8],
+    ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
+    {
+        let ipn = twocheckout::TwocheckoutIpn::try_from(request.body)?;
+
+        Ok(services::api::ApplicationResponse::TextPlain(
+            ipn.get_read_receipt(merchant_secret)?,
+        ))
+    }
+}
+
+impl services::ConnectorRedirectResponse for Twocheckout {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
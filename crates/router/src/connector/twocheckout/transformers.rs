@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+
+use common_utils::crypto::{self, SignMessage};
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{
+        self, AddressDetailsData, CardData, PaymentsAuthorizeRequestData, RouterData,
+    },
+    core::errors,
+    pii, services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct TwocheckoutAuthType {
+    pub(super) merchant_code: String,
+    pub(super) secret_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for TwocheckoutAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                merchant_code: api_key.to_string(),
+                secret_key: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Order Request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TwocheckoutPurchaseType {
+    Product,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TwocheckoutPriceType {
+    Custom,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPrice {
+    amount: f64,
+    #[serde(rename = "Type")]
+    price_type: TwocheckoutPriceType,
+}
+
+// Orders are placed with a single dynamic product carrying the payment amount, so merchants
+// don't need to maintain a catalogue in their 2Checkout account
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutItem {
+    name: String,
+    quantity: u8,
+    is_dynamic: bool,
+    tangible: bool,
+    purchase_type: TwocheckoutPurchaseType,
+    price: TwocheckoutPrice,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutBillingDetails {
+    address1: Secret<String>,
+    city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<Secret<String>>,
+    country_code: String,
+    email: Secret<String, pii::Email>,
+    first_name: Secret<String>,
+    last_name: Secret<String>,
+    zip: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TwocheckoutCardType {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+    Maestro,
+}
+
+impl From<utils::CardIssuer> for TwocheckoutCardType {
+    fn from(issuer: utils::CardIssuer) -> Self {
+        match issuer {
+            utils::CardIssuer::Visa => Self::Visa,
+            utils::CardIssuer::Master => Self::Mastercard,
+            utils::CardIssuer::AmericanExpress => Self::Amex,
+            utils::CardIssuer::Discover => Self::Discover,
+            utils::CardIssuer::Maestro => Self::Maestro,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutCard {
+    card_number: Secret<String, pii::CardNumber>,
+    card_type: TwocheckoutCardType,
+    expiration_year: Secret<String>,
+    expiration_month: Secret<String>,
+    #[serde(rename = "CCID")]
+    ccid: Secret<String>,
+    holder_name: Secret<String>,
+    #[serde(rename = "Vendor3DSReturnURL")]
+    vendor_3ds_return_url: String,
+    #[serde(rename = "Vendor3DSCancelURL")]
+    vendor_3ds_cancel_url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub enum TwocheckoutPaymentType {
+    #[serde(rename = "CC")]
+    CreditCard,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPaymentDetails {
+    #[serde(rename = "Type")]
+    payment_type: TwocheckoutPaymentType,
+    currency: enums::Currency,
+    #[serde(rename = "CustomerIP")]
+    customer_ip: String,
+    payment_method: TwocheckoutCard,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPaymentsRequest {
+    currency: enums::Currency,
+    language: String,
+    country: String,
+    #[serde(rename = "CustomerIP")]
+    customer_ip: String,
+    external_reference: String,
+    items: Vec<TwocheckoutItem>,
+    billing_details: TwocheckoutBillingDetails,
+    payment_details: TwocheckoutPaymentDetails,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for TwocheckoutPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        // Card orders are authorized and captured by 2Checkout in one go
+        if !item.request.is_auto_capture() {
+            return Err(errors::ConnectorError::NotImplemented(
+                "Manual capture for twocheckout".to_string(),
+            )
+            .into());
+        }
+        let ccard = match &item.request.payment_method_data {
+            api::PaymentMethodData::Card(ccard) => ccard,
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment Method".to_string()).into(),
+                )
+            }
+        };
+        let customer_ip = item
+            .request
+            .browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.ip_address)
+            .ok_or_else(utils::missing_field_err("browser_info.ip_address"))?
+            .to_string();
+        let address = item.get_billing_address()?;
+        let country = address.get_country()?.to_owned();
+        let return_url = item.get_return_url()?;
+        Ok(Self {
+            currency: item.request.currency,
+            language: "en".to_string(),
+            country: country.clone(),
+            customer_ip: customer_ip.clone(),
+            external_reference: item.attempt_id.clone(),
+            items: vec![TwocheckoutItem {
+                name: item.payment_id.clone(),
+                quantity: 1,
+                is_dynamic: true,
+                tangible: false,
+                purchase_type: TwocheckoutPurchaseType::Product,
+                price: TwocheckoutPrice {
+                    amount: utils::to_currency_base_unit_asf64(
+                        item.request.amount,
+                        item.request.currency,
+                    )?,
+                    price_type: TwocheckoutPriceType::Custom,
+                },
+            }],
+            billing_details: TwocheckoutBillingDetails {
+                address1: address.get_line1()?.to_owned(),
+                city: address.get_city()?.to_owned(),
+                state: address.state.clone(),
+                country_code: country,
+                email: item.request.get_email()?,
+                first_name: address.get_first_name()?.to_owned(),
+                last_name: address.get_last_name()?.to_owned(),
+                zip: address.get_zip()?.to_owned(),
+            },
+            payment_details: TwocheckoutPaymentDetails {
+                payment_type: TwocheckoutPaymentType::CreditCard,
+                currency: item.request.currency,
+                customer_ip,
+                payment_method: TwocheckoutCard {
+                    card_number: ccard.card_number.clone(),
+                    card_type: TwocheckoutCardType::from(ccard.get_card_issuer()?),
+                    expiration_year: ccard.card_exp_year.clone(),
+                    expiration_month: ccard.card_exp_month.clone(),
+                    ccid: ccard.card_cvc.clone(),
+                    holder_name: ccard.card_holder_name.clone(),
+                    vendor_3ds_return_url: return_url.clone(),
+                    vendor_3ds_cancel_url: return_url,
+                },
+            },
+        })
+    }
+}
+
+// Order Response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TwocheckoutOrderStatus {
+    Pending,
+    PurchasePending,
+    PendingApproval,
+    PaymentAuthorized,
+    #[serde(rename = "AUTHRECEIVED")]
+    AuthReceived,
+    Suspect,
+    Complete,
+    Refund,
+    Canceled,
+    Reversed,
+    Invalid,
+}
+
+impl From<TwocheckoutOrderStatus> for enums::AttemptStatus {
+    fn from(status: TwocheckoutOrderStatus) -> Self {
+        match status {
+            // Orders stay pending until 2Checkout's fraud review approves them, the outcome is
+            // notified through the IPN
+            TwocheckoutOrderStatus::Pending
+            | TwocheckoutOrderStatus::PurchasePending
+            | TwocheckoutOrderStatus::PendingApproval
+            | TwocheckoutOrderStatus::PaymentAuthorized
+            | TwocheckoutOrderStatus::AuthReceived
+            | TwocheckoutOrderStatus::Suspect => Self::Pending,
+            TwocheckoutOrderStatus::Complete | TwocheckoutOrderStatus::Refund => Self::Charged,
+            TwocheckoutOrderStatus::Reversed => Self::Voided,
+            TwocheckoutOrderStatus::Canceled | TwocheckoutOrderStatus::Invalid => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutAuthorize3ds {
+    href: Url,
+    #[serde(default)]
+    params: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPaymentMethodResponse {
+    #[serde(rename = "Authorize3DS")]
+    authorize_3ds: Option<TwocheckoutAuthorize3ds>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPaymentDetailsResponse {
+    payment_method: Option<TwocheckoutPaymentMethodResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TwocheckoutPaymentsResponse {
+    pub ref_no: String,
+    pub status: TwocheckoutOrderStatus,
+    pub payment_details: Option<TwocheckoutPaymentDetailsResponse>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, TwocheckoutPaymentsResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TwocheckoutPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item
+            .response
+            .payment_details
+            .and_then(|details| details.payment_method)
+            .and_then(|payment_method| payment_method.authorize_3ds)
+            .map(|authorize_3ds| services::RedirectForm {
+                endpoint: authorize_3ds.href.to_string(),
+                method: services::Method::Get,
+                form_fields: authorize_3ds.params,
+            });
+        let status = match (&item.response.status, &redirection_data) {
+            (TwocheckoutOrderStatus::Pending, Some(_)) => {
+                enums::AttemptStatus::AuthenticationPending
+            }
+            (status, _) => enums::AttemptStatus::from(status.clone()),
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.ref_no),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Refund Request
+#[derive(Debug, Serialize)]
+pub struct TwocheckoutRefundRequest {
+    amount: f64,
+    comment: String,
+    reason: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for TwocheckoutRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: utils::to_currency_base_unit_asf64(
+                item.request.refund_amount,
+                item.request.currency,
+            )?,
+            comment: item
+                .request
+                .reason
+                .clone()
+                .unwrap_or_else(|| item.request.refund_id.clone()),
+            // Reasons are configured per merchant account, "Other" is always available
+            reason: "Other".to_string(),
+        })
+    }
+}
+
+// Refund Response
+// 2Checkout answers a refund with a plain boolean and doesn't issue an id for it, so the refund
+// is tracked by our own refund id
+impl<F> TryFrom<types::RefundsResponseRouterData<F, bool>> for types::RefundsRouterData<F> {
+    type Error = Error;
+    fn try_from(item: types::RefundsResponseRouterData<F, bool>) -> Result<Self, Self::Error> {
+        let refund_status = if item.response {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Failure
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.data.request.refund_id.clone(),
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Instant Payment Notification
+#[derive(Debug)]
+pub struct TwocheckoutIpn {
+    pub fields: Vec<(String, String)>,
+}
+
+impl TwocheckoutIpn {
+    pub fn get_field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_ref_no(&self) -> Result<&str, Error> {
+        self.get_field("REFNO")
+            .ok_or_else(|| errors::ConnectorError::WebhookReferenceIdNotFound.into())
+    }
+
+    pub fn get_order_status(&self) -> Result<TwocheckoutOrderStatus, Error> {
+        let status = self
+            .get_field("ORDERSTATUS")
+            .ok_or(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        serde_json::from_value(serde_json::Value::String(status.to_string()))
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)
+    }
+
+    // Every value except the hashes themselves is signed, in the order it was posted and
+    // prefixed by its length in bytes
+    pub fn get_signature_message(&self) -> Vec<u8> {
+        self.fields
+            .iter()
+            .filter(|(key, _)| key != "HASH" && !key.starts_with("SIGNATURE_"))
+            .map(|(_, value)| format!("{}{}", value.len(), value))
+            .collect::<String>()
+            .into_bytes()
+    }
+}
+
+impl TwocheckoutIpn {
+    // The read receipt expected in reply is signed over the first product id and name, the date
+    // of the notification and the date of the reply
+    pub fn get_read_receipt(&self, secret_key: &[u8]) -> Result<String, Error> {
+        let date = common_utils::date_time::date_as_yyyymmddhhmmss();
+        let message = [
+            self.get_field("IPN_PID[]").unwrap_or_default(),
+            self.get_field("IPN_PNAME[]").unwrap_or_default(),
+            self.get_field("IPN_DATE").unwrap_or_default(),
+            date.as_str(),
+        ]
+        .iter()
+        .map(|value| format!("{}{}", value.len(), value))
+        .collect::<String>();
+        let hash = crypto::HmacSha256
+            .sign_message(secret_key, message.as_bytes())
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(format!(
+            "<sig algo=\"sha256\" date=\"{}\">{}</sig>",
+            date,
+            hex::encode(hash)
+        ))
+    }
+}
+
+impl From<TwocheckoutOrderStatus> for api::IncomingWebhookEvent {
+    fn from(status: TwocheckoutOrderStatus) -> Self {
+        match status {
+            TwocheckoutOrderStatus::Complete => Self::PaymentIntentSuccess,
+            TwocheckoutOrderStatus::Canceled
+            | TwocheckoutOrderStatus::Reversed
+            | TwocheckoutOrderStatus::Invalid => Self::PaymentIntentFailure,
+            // Refunds are confirmed when they are issued
+            _ => Self::EventNotSupported,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for TwocheckoutIpn {
+    type Error = Error;
+    fn try_from(body: &[u8]) -> Result<Self, Self::Error> {
+        let fields: Vec<(String, String)> = serde_urlencoded::from_bytes(body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(Self { fields })
+    }
+}
+
+impl TryFrom<&TwocheckoutIpn> for TwocheckoutPaymentsResponse {
+    type Error = Error;
+    fn try_from(ipn: &TwocheckoutIpn) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ref_no: ipn.get_ref_no()?.to_string(),
+            status: ipn.get_order_status()?,
+            payment_details: None,
+        })
+    }
+}
+
+// Error Response
+#[derive(Debug, Deserialize)]
+pub struct TwocheckoutErrorResponse {
+    pub error_code: String,
+    pub message: String,
+}
@@ -7,7 +7,10 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::{
-    core::errors::{self, CustomResult},
+    core::{
+        errors::{self, CustomResult},
+        payments::helpers,
+    },
     pii::PeekInterface,
     types::{self, api, PaymentsCancelData},
     utils::OptionExt,
@@ -132,12 +135,44 @@ impl PaymentsRequestData for types::PaymentsAuthorizeRouterData {
 
 pub trait PaymentsAuthorizeRequestData {
     fn is_auto_capture(&self) -> bool;
+    fn get_payment_method_type(&self) -> Result<storage_models::enums::PaymentMethodType, Error>;
+    fn get_payment_experience(&self) -> Result<storage_models::enums::PaymentExperience, Error>;
+    fn get_email(&self) -> Result<Secret<String, pii::Email>, Error>;
+    fn get_statement_descriptor(&self) -> Option<String>;
+    fn get_network_transaction_id(&self) -> Option<String>;
 }
 
 impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
     fn is_auto_capture(&self) -> bool {
         self.capture_method == Some(storage_models::enums::CaptureMethod::Automatic)
     }
+    fn get_payment_method_type(&self) -> Result<storage_models::enums::PaymentMethodType, Error> {
+        self.payment_method_type
+            .clone()
+            .ok_or_else(missing_field_err("payment_method_type"))
+    }
+    fn get_payment_experience(&self) -> Result<storage_models::enums::PaymentExperience, Error> {
+        self.payment_experience
+            .clone()
+            .ok_or_else(missing_field_err("payment_experience"))
+    }
+    fn get_email(&self) -> Result<Secret<String, pii::Email>, Error> {
+        self.email.clone().ok_or_else(missing_field_err("email"))
+    }
+    fn get_statement_descriptor(&self) -> Option<String> {
+        helpers::get_statement_descriptor(
+            self.statement_descriptor.as_deref(),
+            self.statement_descriptor_suffix.as_deref(),
+        )
+    }
+    /// Network transaction id of the customer-initiated transaction a merchant-initiated payment
+    /// refers to, from its mandate or the saved card it is charged with
+    fn get_network_transaction_id(&self) -> Option<String> {
+        self.mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.network_transaction_id.clone())
+            .or_else(|| self.network_transaction_id.clone())
+    }
 }
 
 pub trait PaymentsSyncRequestData {
@@ -221,6 +256,32 @@ impl CardData for api::Card {
     }
 }
 
+pub trait ApplePayDecrypt {
+    fn get_expiry_month(&self) -> Secret<String>;
+    fn get_four_digit_expiry_year(&self) -> Secret<String>;
+}
+
+impl ApplePayDecrypt for types::ApplePayDecryptedData {
+    // The expiration date of the decrypted token is in the format YYMMDD
+    fn get_expiry_month(&self) -> Secret<String> {
+        Secret::new(
+            self.application_expiration_date
+                .get(2..4)
+                .unwrap_or_default()
+                .to_string(),
+        )
+    }
+
+    fn get_four_digit_expiry_year(&self) -> Secret<String> {
+        Secret::new(format!(
+            "20{}",
+            self.application_expiration_date
+                .get(0..2)
+                .unwrap_or_default()
+        ))
+    }
+}
+
 fn get_card_issuer(card_number: &str) -> Result<CardIssuer, Error> {
     for (k, v) in CARD_REGEX.iter() {
         let regex: Regex = v
@@ -355,3 +416,33 @@ pub fn to_currency_base_unit(
         _ => Ok((f64::from(amount_u32) / 100.0).to_string()),
     }
 }
+
+pub fn to_currency_base_unit_asf64(
+    amount: i64,
+    currency: storage_models::enums::Currency,
+) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
+    to_currency_base_unit(amount, currency)?
+        .parse::<f64>()
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+}
+
+/// Maps an AVS result code of the card networks, as relayed by most processors, to the AVS result
+pub fn get_avs_result_from_code(avs_code: &str) -> storage_models::enums::AvsResult {
+    match avs_code.trim().to_uppercase().as_str() {
+        "Y" | "X" | "D" | "F" | "M" => storage_models::enums::AvsResult::Match,
+        "Z" | "W" | "P" => storage_models::enums::AvsResult::PostalCodeMatch,
+        "A" | "B" => storage_models::enums::AvsResult::AddressMatch,
+        "N" | "C" => storage_models::enums::AvsResult::NoMatch,
+        _ => storage_models::enums::AvsResult::Unavailable,
+    }
+}
+
+/// Maps a CVV result code of the card networks, as relayed by most processors, to the CVV result
+pub fn get_cvv_result_from_code(cvv_code: &str) -> storage_models::enums::CvvResult {
+    match cvv_code.trim().to_uppercase().as_str() {
+        "M" => storage_models::enums::CvvResult::Match,
+        "N" => storage_models::enums::CvvResult::NoMatch,
+        _ => storage_models::enums::CvvResult::Unavailable,
+    }
+}
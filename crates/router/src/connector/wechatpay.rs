@@ -0,0 +1,647 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use transformers as wechatpay;
+
+use crate::{
+    configs::settings,
+    connector::utils as conn_utils,
+    core::{
+        errors::{self, CustomResult},
+        payments,
+    },
+    db::StorageInterface,
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse, Response,
+    },
+    utils::{self, BytesExt, ValueExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Wechatpay;
+
+impl Wechatpay {
+    // Notifications are signed with the same api key the calls are, which is a part of the
+    // connector account
+    async fn get_api_key(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let merchant_connector_account = db
+            .find_merchant_connector_account_by_merchant_id_connector(merchant_id, self.id())
+            .await
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let auth = wechatpay::WechatpayAuthType::try_from(&auth_type)?;
+
+        Ok(auth.api_key.peek().as_bytes().to_vec())
+    }
+}
+
+impl api::Payment for Wechatpay {}
+impl api::PaymentSession for Wechatpay {}
+impl api::ConnectorAccessToken for Wechatpay {}
+impl api::PreVerify for Wechatpay {}
+impl api::PaymentAuthorize for Wechatpay {}
+impl api::PaymentSync for Wechatpay {}
+impl api::PaymentCapture for Wechatpay {}
+impl api::PaymentVoid for Wechatpay {}
+impl api::Refund for Wechatpay {}
+impl api::RefundExecute for Wechatpay {}
+impl api::RefundSync for Wechatpay {}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Wechatpay
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        _req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )])
+    }
+}
+
+impl ConnectorCommon for Wechatpay {
+    fn id(&self) -> &'static str {
+        "wechatpay"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "text/xml"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.wechatpay.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: wechatpay::WechatpayResponseStatus = wechatpay::parse_xml(&res.response)?;
+
+        Ok(response
+            .get_error_response(res.status_code)
+            .unwrap_or_else(|| ErrorResponse {
+                code: crate::consts::NO_ERROR_CODE.to_string(),
+                message: crate::consts::NO_ERROR_MESSAGE.to_string(),
+                reason: None,
+                status_code: res.status_code,
+                unified_code: None,
+            }))
+    }
+}
+
+impl api::ConnectorSpecifications for Wechatpay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Wallet])
+    }
+
+    fn supported_capture_methods(&self) -> Option<&'static [api::enums::CaptureMethod]> {
+        Some(&[api::enums::CaptureMethod::Automatic])
+    }
+
+    fn refund_window_days(&self) -> Option<i64> {
+        Some(365)
+    }
+
+    fn supports_mandates(&self) -> bool {
+        false
+    }
+}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for Wechatpay
+{
+}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for Wechatpay
+{
+}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for Wechatpay
+{
+}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for Wechatpay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}pay/unifiedorder", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let auth = wechatpay::WechatpayAuthType::try_from(&req.connector_auth_type)?;
+        let wechatpay_req = wechatpay::WechatpayPaymentsRequest::try_from(req)?;
+        let wechatpay_req = wechatpay::to_signed_xml(&wechatpay_req, &auth.api_key)?;
+        Ok(Some(wechatpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthorizeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        logger::debug!(wechatpay_payments_response=?res);
+        let response: wechatpay::WechatpayPaymentsResponse = wechatpay::parse_xml(&res.response)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Wechatpay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}pay/orderquery", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let auth = wechatpay::WechatpayAuthType::try_from(&req.connector_auth_type)?;
+        let wechatpay_req = wechatpay::WechatpaySyncRequest::try_from(req)?;
+        let wechatpay_req = wechatpay::to_signed_xml(&wechatpay_req, &auth.api_key)?;
+        Ok(Some(wechatpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .body(types::PaymentsSyncType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        logger::debug!(wechatpay_payment_sync_response=?res);
+        // Payment notifications are handed over here as json, while order queries are answered
+        // with xml
+        let response: wechatpay::WechatpaySyncResponse =
+            match res.response.parse_struct("Wechatpay SyncResponse") {
+                Ok(response) => response,
+                Err(_) => wechatpay::parse_xml(&res.response)?,
+            };
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for Wechatpay
+{
+}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Wechatpay
+{
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Wechatpay
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}secapi/pay/refund", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let auth = wechatpay::WechatpayAuthType::try_from(&req.connector_auth_type)?;
+        let wechatpay_req = wechatpay::WechatpayRefundRequest::try_from(req)?;
+        let wechatpay_req = wechatpay::to_signed_xml(&wechatpay_req, &auth.api_key)?;
+        Ok(Some(wechatpay_req))
+    }
+
+    // Refunds are only taken over a connection authenticated with the merchant's client
+    // certificate
+    fn get_certificate(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let metadata: wechatpay::WechatpayConnectorMeta =
+            conn_utils::RouterData::to_connector_meta(req)?;
+        metadata
+            .client_certificate
+            .ok_or_else(conn_utils::missing_field_err(
+                "connector_meta_data.client_certificate",
+            ))
+            .map(Some)
+    }
+
+    fn get_certificate_key(
+        &self,
+        req: &types::RefundExecuteRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let metadata: wechatpay::WechatpayConnectorMeta =
+            conn_utils::RouterData::to_connector_meta(req)?;
+        metadata
+            .client_certificate_key
+            .ok_or_else(conn_utils::missing_field_err(
+                "connector_meta_data.client_certificate_key",
+            ))
+            .map(Some)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundExecuteRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+                .headers(types::RefundExecuteType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::RefundExecuteType::get_request_body(self, req)?)
+                .add_certificate(types::RefundExecuteType::get_certificate(self, req)?)
+                .add_certificate_key(types::RefundExecuteType::get_certificate_key(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundExecuteRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundExecuteRouterData, errors::ConnectorError> {
+        logger::debug!(wechatpay_refund_response=?res);
+        let response: wechatpay::WechatpayRefundResponse = wechatpay::parse_xml(&res.response)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Wechatpay
+{
+    fn get_headers(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}pay/refundquery", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundSyncRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let auth = wechatpay::WechatpayAuthType::try_from(&req.connector_auth_type)?;
+        let wechatpay_req = wechatpay::WechatpayRefundSyncRequest::try_from(req)?;
+        let wechatpay_req = wechatpay::to_signed_xml(&wechatpay_req, &auth.api_key)?;
+        Ok(Some(wechatpay_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::RefundSyncType::get_url(self, req, connectors)?)
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .body(types::RefundSyncType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        res: Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        logger::debug!(wechatpay_refund_sync_response=?res);
+        let response: wechatpay::WechatpayRefundSyncResponse = wechatpay::parse_xml(&res.response)?;
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Payouts for Wechatpay {}
+impl api::PayoutCreate for Wechatpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Wechatpay
+{
+}
+
+impl api::PayoutFulfill for Wechatpay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Wechatpay
+{
+}
+
+impl api::PayoutCancel for Wechatpay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Wechatpay
+{
+}
+
+impl api::PayoutSync for Wechatpay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Wechatpay
+{
+}
+
+impl api::RefundCredit for Wechatpay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Wechatpay
+{
+}
+
+impl api::ConnectorMandateRevoke for Wechatpay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Wechatpay
+{
+}
+
+impl api::Dispute for Wechatpay {}
+impl api::SubmitEvidence for Wechatpay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Wechatpay
+{
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Wechatpay {
+    fn get_webhook_source_verification_scheme(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let params = wechatpay::from_xml(request.body)
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        let signature = params
+            .get("sign")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let params = wechatpay::from_xml(request.body)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let api_key = std::str::from_utf8(secret)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        Ok(wechatpay::get_signature_message(&params, api_key).into_bytes())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        self.get_api_key(db, merchant_id)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let notification: wechatpay::WechatpayNotification = wechatpay::parse_xml(request.body)
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(notification.out_trade_no)
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let notification: wechatpay::WechatpayNotification = wechatpay::parse_xml(request.body)
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(api::IncomingWebhookEvent::from(&notification))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let notification: wechatpay::WechatpayNotification = wechatpay::parse_xml(request.body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        utils::Encode::<wechatpay::WechatpaySyncResponse>::encode_to_value(
+            &wechatpay::WechatpaySyncResponse::from(notification),
+        )
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    fn get_webhook_api_response(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_secret: &[u8],
+    ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
+    {
+        Ok(services::api::ApplicationResponse::TextPlain(
+            wechatpay::get_notification_response(),
+        ))
+    }
+}
+
+impl services::ConnectorRedirectResponse for Wechatpay {
+    fn get_flow_type(
+        &self,
+        _query_params: &str,
+    ) -> CustomResult<payments::CallConnectorAction, errors::ConnectorError> {
+        Ok(payments::CallConnectorAction::Trigger)
+    }
+}
@@ -0,0 +1,770 @@
+use std::collections::BTreeMap;
+
+use common_utils::crypto::{self, SignMessage};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, RouterData},
+    core::errors,
+    services,
+    types::{self, api, storage::enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+const SIGN_TYPE: &str = "HMAC-SHA256";
+const NONCE_LENGTH: usize = 32;
+
+// Auth Struct
+pub struct WechatpayAuthType {
+    pub(super) app_id: String,
+    pub(super) mch_id: String,
+    /// Key the calls are signed with, set up by the merchant on the WeChat Pay merchant platform
+    pub(super) api_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for WechatpayAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                app_id: api_key.to_string(),
+                mch_id: key1.to_string(),
+                api_key: Secret::new(api_secret.to_string()),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// WeChat Pay requires an url for payment notifications with every order, and a client
+/// certificate, issued by WeChat Pay, for refunds. Both are configured in the metadata of the
+/// merchant connector account, with the certificate and its key as base64 encoded PEMs
+#[derive(Debug, Deserialize)]
+pub struct WechatpayConnectorMeta {
+    pub notify_url: String,
+    pub client_certificate: Option<String>,
+    pub client_certificate_key: Option<String>,
+}
+
+/// The calls, their responses and the notifications are flat `<xml>` documents, which are
+/// handled as maps of their element names to their text
+pub type WechatpayParams = BTreeMap<String, String>;
+
+static XML_ELEMENT_REGEX: Lazy<Result<Regex, regex::Error>> =
+    Lazy::new(|| Regex::new(r"<(\w+)>(?:<!\[CDATA\[((?s).*?)\]\]>|([^<]*))</(\w+)>"));
+
+pub fn to_xml(params: &WechatpayParams) -> String {
+    let elements: String = params
+        .iter()
+        .map(|(name, value)| format!("<{name}><![CDATA[{value}]]></{name}>"))
+        .collect();
+    format!("<xml>{elements}</xml>")
+}
+
+pub fn from_xml(xml: &[u8]) -> Result<WechatpayParams, Error> {
+    let xml = std::str::from_utf8(xml)
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+    let regex = XML_ELEMENT_REGEX
+        .as_ref()
+        .map_err(|_| errors::ConnectorError::ResponseDeserializationFailed)
+        .into_report()?;
+    Ok(regex
+        .captures_iter(xml)
+        .filter(|element| element.get(1).map(|m| m.as_str()) == element.get(4).map(|m| m.as_str()))
+        .filter_map(|element| {
+            let name = element.get(1)?.as_str().to_string();
+            let value = match element.get(2) {
+                Some(cdata) => cdata.as_str().to_string(),
+                None => unescape_xml(element.get(3)?.as_str()),
+            };
+            Some((name, value))
+        })
+        .collect())
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn parse_xml<T: DeserializeOwned>(xml: &[u8]) -> Result<T, Error> {
+    let params = from_xml(xml)?;
+    serde_json::to_value(params)
+        .and_then(serde_json::from_value)
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+}
+
+/// Params are signed as an url query of the non empty ones, sorted by their names, followed by
+/// the api key. The `sign` param itself is left out.
+pub fn get_signature_message(params: &WechatpayParams, api_key: &str) -> String {
+    let query: Vec<String> = params
+        .iter()
+        .filter(|(name, value)| name.as_str() != "sign" && !value.is_empty())
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect();
+    format!("{}&key={api_key}", query.join("&"))
+}
+
+fn sign(params: &WechatpayParams, api_key: &Secret<String>) -> Result<String, Error> {
+    let message = get_signature_message(params, api_key.peek());
+    let signature = crypto::HmacSha256
+        .sign_message(api_key.peek().as_bytes(), message.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(hex::encode_upper(signature))
+}
+
+fn to_params<T: Serialize>(request: &T) -> Result<WechatpayParams, Error> {
+    let value = serde_json::to_value(request)
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    match value {
+        serde_json::Value::Object(map) => Ok(map
+            .into_iter()
+            .filter_map(|(name, value)| match value {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(value) => Some((name, value)),
+                value => Some((name, value.to_string())),
+            })
+            .collect()),
+        _ => Err(errors::ConnectorError::RequestEncodingFailed).into_report(),
+    }
+}
+
+pub fn to_signed_xml<T: Serialize>(request: &T, api_key: &Secret<String>) -> Result<String, Error> {
+    let mut params = to_params(request)?;
+    let signature = sign(&params, api_key)?;
+    params.insert("sign".to_string(), signature);
+    Ok(to_xml(&params))
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpayCommonParams {
+    appid: String,
+    mch_id: String,
+    nonce_str: String,
+    sign_type: &'static str,
+}
+
+impl From<WechatpayAuthType> for WechatpayCommonParams {
+    fn from(auth: WechatpayAuthType) -> Self {
+        Self {
+            appid: auth.app_id,
+            mch_id: auth.mch_id,
+            nonce_str: crypto::generate_cryptographically_secure_random_string(NONCE_LENGTH),
+            sign_type: SIGN_TYPE,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WechatpayTradeType {
+    /// A QR code the customer scans with the WeChat app
+    Native,
+    /// A payment page opened in a mobile browser, which hands over to the WeChat app
+    Mweb,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpayH5Info {
+    #[serde(rename = "type")]
+    h5_type: &'static str,
+    wap_url: String,
+    wap_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpaySceneInfo {
+    h5_info: WechatpayH5Info,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpayPaymentsRequest {
+    #[serde(flatten)]
+    common: WechatpayCommonParams,
+    body: String,
+    out_trade_no: String,
+    /// Amount in the minor unit of the currency
+    total_fee: i64,
+    fee_type: String,
+    spbill_create_ip: String,
+    notify_url: String,
+    trade_type: WechatpayTradeType,
+    product_id: Option<String>,
+    /// Json encoded [`WechatpaySceneInfo`], required for H5 payments
+    scene_info: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for WechatpayPaymentsRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let trade_type = match item.request.payment_method_data {
+            api::PaymentMethodData::Wallet(api_models::payments::WalletData::WeChatPayQr(_)) => {
+                WechatpayTradeType::Native
+            }
+            api::PaymentMethodData::Wallet(
+                api_models::payments::WalletData::WeChatPayRedirect(_),
+            ) => WechatpayTradeType::Mweb,
+            _ => {
+                return Err(
+                    errors::ConnectorError::NotImplemented("Payment methods".to_string()).into(),
+                )
+            }
+        };
+        // WeChat Pay payments are captured as soon as the customer pays
+        if item.request.capture_method == Some(enums::CaptureMethod::Manual) {
+            return Err(errors::ConnectorError::NotImplemented(
+                "Manual capture for WeChat Pay".to_string(),
+            )
+            .into());
+        }
+        let auth = WechatpayAuthType::try_from(&item.connector_auth_type)?;
+        let meta: WechatpayConnectorMeta = item.to_connector_meta()?;
+        let ip_address = item
+            .request
+            .browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.ip_address)
+            .ok_or_else(utils::missing_field_err("browser_info.ip_address"))?;
+        let description = item
+            .description
+            .clone()
+            .unwrap_or_else(|| item.payment_id.clone());
+        let (product_id, scene_info) = match trade_type {
+            WechatpayTradeType::Native => (Some(item.payment_id.clone()), None),
+            WechatpayTradeType::Mweb => {
+                let scene_info = WechatpaySceneInfo {
+                    h5_info: WechatpayH5Info {
+                        h5_type: "Wap",
+                        wap_url: item.get_return_url()?,
+                        wap_name: description.clone(),
+                    },
+                };
+                let scene_info = serde_json::to_string(&scene_info)
+                    .into_report()
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+                (None, Some(scene_info))
+            }
+        };
+
+        Ok(Self {
+            common: WechatpayCommonParams::from(auth),
+            body: description,
+            out_trade_no: item.attempt_id.clone(),
+            total_fee: item.request.amount,
+            fee_type: item.request.currency.to_string(),
+            spbill_create_ip: ip_address.to_string(),
+            notify_url: meta.notify_url,
+            trade_type,
+            product_id,
+            scene_info,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WechatpayReturnCode {
+    Success,
+    Fail,
+}
+
+/// Every response tells whether the call was taken at all, with its `return_code`, and whether
+/// it succeeded, with its `result_code`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WechatpayResponseStatus {
+    pub return_code: WechatpayReturnCode,
+    pub return_msg: Option<String>,
+    pub result_code: Option<WechatpayReturnCode>,
+    pub err_code: Option<String>,
+    pub err_code_des: Option<String>,
+}
+
+impl WechatpayResponseStatus {
+    pub fn get_error_response(&self, status_code: u16) -> Option<types::ErrorResponse> {
+        match (self.return_code, self.result_code) {
+            (WechatpayReturnCode::Fail, _) => Some(types::ErrorResponse {
+                code: "FAIL".to_string(),
+                message: self
+                    .return_msg
+                    .clone()
+                    .unwrap_or_else(|| crate::consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code,
+                unified_code: None,
+            }),
+            (_, Some(WechatpayReturnCode::Fail)) => Some(types::ErrorResponse {
+                code: self
+                    .err_code
+                    .clone()
+                    .unwrap_or_else(|| crate::consts::NO_ERROR_CODE.to_string()),
+                message: self
+                    .err_code_des
+                    .clone()
+                    .unwrap_or_else(|| crate::consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code,
+                unified_code: None,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WechatpayPaymentsResponse {
+    #[serde(flatten)]
+    status: WechatpayResponseStatus,
+    code_url: Option<String>,
+    mweb_url: Option<Url>,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Authorize,
+            WechatpayPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsAuthorizeRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Authorize,
+            WechatpayPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        if let Some(error) = item.response.status.get_error_response(item.http_code) {
+            return Ok(Self {
+                status: enums::AttemptStatus::Failure,
+                response: Err(error),
+                ..item.data
+            });
+        }
+        // The customer is sent back to the return url once done on the H5 payment page
+        let redirection_data = item
+            .response
+            .mweb_url
+            .map(|mut mweb_url| {
+                let return_url = item.data.get_return_url()?;
+                mweb_url
+                    .query_pairs_mut()
+                    .append_pair("redirect_url", &return_url);
+                Ok::<_, Error>(services::RedirectForm::from((
+                    mweb_url,
+                    services::Method::Get,
+                )))
+            })
+            .transpose()?;
+        // The customer scans the code url with the WeChat app, of which WeChat Pay gives no image
+        let connector_metadata = item
+            .response
+            .code_url
+            .map(|code_url| {
+                serde_json::to_value(api_models::payments::QrCodeInformation {
+                    qr_code_payload: code_url,
+                    image_data_url: None,
+                    expires_at: None,
+                })
+                .into_report()
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)
+            })
+            .transpose()?;
+
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                // Payments are referred to by the out trade number they were ordered with,
+                // which is also what they are notified with
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.attempt_id.clone(),
+                ),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpaySyncRequest {
+    #[serde(flatten)]
+    common: WechatpayCommonParams,
+    out_trade_no: String,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for WechatpaySyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let auth = WechatpayAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            common: WechatpayCommonParams::from(auth),
+            out_trade_no: item.attempt_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WechatpayTradeState {
+    Success,
+    Refund,
+    Notpay,
+    Closed,
+    Revoked,
+    Userpaying,
+    Payerror,
+}
+
+impl From<WechatpayTradeState> for enums::AttemptStatus {
+    fn from(item: WechatpayTradeState) -> Self {
+        match item {
+            WechatpayTradeState::Success | WechatpayTradeState::Refund => Self::Charged,
+            WechatpayTradeState::Notpay => Self::AuthenticationPending,
+            WechatpayTradeState::Userpaying => Self::Pending,
+            WechatpayTradeState::Closed | WechatpayTradeState::Revoked => Self::Voided,
+            WechatpayTradeState::Payerror => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WechatpaySyncResponse {
+    #[serde(flatten)]
+    pub status: WechatpayResponseStatus,
+    pub trade_state: Option<WechatpayTradeState>,
+    pub trade_state_desc: Option<String>,
+    pub out_trade_no: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, WechatpaySyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<F, WechatpaySyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        let (trade_state, out_trade_no) = match (
+            response.status.get_error_response(item.http_code),
+            response.trade_state,
+            response.out_trade_no,
+        ) {
+            (None, Some(trade_state), Some(out_trade_no)) => (trade_state, out_trade_no),
+            (error, _, _) => {
+                return Ok(Self {
+                    response: Err(error.unwrap_or_else(|| types::ErrorResponse {
+                        code: crate::consts::NO_ERROR_CODE.to_string(),
+                        message: crate::consts::NO_ERROR_MESSAGE.to_string(),
+                        reason: None,
+                        status_code: item.http_code,
+                        unified_code: None,
+                    })),
+                    ..item.data
+                })
+            }
+        };
+        let status = enums::AttemptStatus::from(trade_state);
+        if status == enums::AttemptStatus::Failure {
+            return Ok(Self {
+                status,
+                response: Err(types::ErrorResponse {
+                    code: "PAYERROR".to_string(),
+                    message: response
+                        .trade_state_desc
+                        .unwrap_or_else(|| crate::consts::NO_ERROR_MESSAGE.to_string()),
+                    reason: None,
+                    status_code: item.http_code,
+                    unified_code: None,
+                }),
+                ..item.data
+            });
+        }
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(out_trade_no),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct WechatpayRefundRequest {
+    #[serde(flatten)]
+    common: WechatpayCommonParams,
+    out_trade_no: String,
+    out_refund_no: String,
+    total_fee: i64,
+    refund_fee: i64,
+    refund_fee_type: String,
+    refund_desc: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for WechatpayRefundRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth = WechatpayAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            common: WechatpayCommonParams::from(auth),
+            out_trade_no: item.request.connector_transaction_id.clone(),
+            out_refund_no: item.request.refund_id.clone(),
+            total_fee: item.request.amount,
+            refund_fee: item.request.refund_amount,
+            refund_fee_type: item.request.currency.to_string(),
+            refund_desc: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WechatpayRefundSyncRequest {
+    #[serde(flatten)]
+    common: WechatpayCommonParams,
+    out_refund_no: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for WechatpayRefundSyncRequest {
+    type Error = Error;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth = WechatpayAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            common: WechatpayCommonParams::from(auth),
+            out_refund_no: item.request.refund_id.clone(),
+        })
+    }
+}
+
+/// A refund that is taken is processed afterwards, so it stays pending until it is synced
+#[derive(Debug, Deserialize)]
+pub struct WechatpayRefundResponse {
+    #[serde(flatten)]
+    status: WechatpayResponseStatus,
+    refund_id: Option<String>,
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, WechatpayRefundResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, WechatpayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = match (
+            item.response.status.get_error_response(item.http_code),
+            item.response.refund_id,
+        ) {
+            (None, Some(refund_id)) => Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status: enums::RefundStatus::Pending,
+            }),
+            (error, _) => Err(error.unwrap_or_else(|| types::ErrorResponse {
+                code: crate::consts::NO_ERROR_CODE.to_string(),
+                message: crate::consts::NO_ERROR_MESSAGE.to_string(),
+                reason: None,
+                status_code: item.http_code,
+                unified_code: None,
+            })),
+        };
+
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WechatpayRefundStatus {
+    Success,
+    Refundclose,
+    Processing,
+    Change,
+}
+
+impl From<WechatpayRefundStatus> for enums::RefundStatus {
+    fn from(item: WechatpayRefundStatus) -> Self {
+        match item {
+            WechatpayRefundStatus::Success => Self::Success,
+            WechatpayRefundStatus::Refundclose | WechatpayRefundStatus::Change => Self::Failure,
+            WechatpayRefundStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+/// Refunds are queried by the refund number they were made with, so only the first of the
+/// numbered refund fields is of interest
+#[derive(Debug, Deserialize)]
+pub struct WechatpayRefundSyncResponse {
+    #[serde(flatten)]
+    status: WechatpayResponseStatus,
+    refund_id_0: Option<String>,
+    refund_status_0: Option<WechatpayRefundStatus>,
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, WechatpayRefundSyncResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, WechatpayRefundSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = match (
+            item.response.status.get_error_response(item.http_code),
+            item.response.refund_id_0,
+            item.response.refund_status_0,
+        ) {
+            (None, Some(refund_id), Some(refund_status)) => Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status: enums::RefundStatus::from(refund_status),
+            }),
+            (error, _, _) => Err(error.unwrap_or_else(|| types::ErrorResponse {
+                code: crate::consts::NO_ERROR_CODE.to_string(),
+                message: crate::consts::NO_ERROR_MESSAGE.to_string(),
+                reason: None,
+                status_code: item.http_code,
+                unified_code: None,
+            })),
+        };
+
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+#[derive(Debug, Deserialize)]
+pub struct WechatpayNotification {
+    #[serde(flatten)]
+    pub status: WechatpayResponseStatus,
+    pub out_trade_no: String,
+}
+
+impl From<&WechatpayNotification> for api::IncomingWebhookEvent {
+    fn from(notification: &WechatpayNotification) -> Self {
+        match (
+            notification.status.return_code,
+            notification.status.result_code,
+        ) {
+            (WechatpayReturnCode::Success, Some(WechatpayReturnCode::Success)) => {
+                Self::PaymentIntentSuccess
+            }
+            (WechatpayReturnCode::Success, Some(WechatpayReturnCode::Fail)) => {
+                Self::PaymentIntentFailure
+            }
+            _ => Self::EventNotSupported,
+        }
+    }
+}
+
+/// The outcome of a payment, as notified, in the shape of an order query response
+impl From<WechatpayNotification> for WechatpaySyncResponse {
+    fn from(notification: WechatpayNotification) -> Self {
+        let trade_state = match notification.status.result_code {
+            Some(WechatpayReturnCode::Success) => WechatpayTradeState::Success,
+            _ => WechatpayTradeState::Payerror,
+        };
+        Self {
+            trade_state_desc: notification.status.err_code_des.clone(),
+            status: WechatpayResponseStatus {
+                return_code: WechatpayReturnCode::Success,
+                return_msg: None,
+                result_code: Some(WechatpayReturnCode::Success),
+                err_code: None,
+                err_code_des: None,
+            },
+            trade_state: Some(trade_state),
+            out_trade_no: Some(notification.out_trade_no),
+        }
+    }
+}
+
+/// WeChat Pay keeps resending a notification until it is acknowledged
+pub fn get_notification_response() -> String {
+    to_xml(&WechatpayParams::from([
+        ("return_code".to_string(), "SUCCESS".to_string()),
+        ("return_msg".to_string(), "OK".to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_xml_round_trip() {
+        let params = WechatpayParams::from([
+            ("body".to_string(), "Tea & <biscuits>".to_string()),
+            ("total_fee".to_string(), "100".to_string()),
+        ]);
+        let xml = to_xml(&params);
+
+        assert_eq!(from_xml(xml.as_bytes()).unwrap(), params);
+    }
+
+    #[test]
+    fn test_from_xml_reads_plain_text_elements() {
+        let xml = "<xml><return_code>SUCCESS</return_code><return_msg>A &amp; B</return_msg></xml>";
+        let params = from_xml(xml.as_bytes()).unwrap();
+
+        assert_eq!(params.get("return_code").unwrap(), "SUCCESS");
+        assert_eq!(params.get("return_msg").unwrap(), "A & B");
+    }
+
+    #[test]
+    fn test_get_signature_message() {
+        // Reference: the signature example of the WeChat Pay API documentation
+        let params = WechatpayParams::from([
+            ("appid".to_string(), "wxd930ea5d5a258f4f".to_string()),
+            ("mch_id".to_string(), "10000100".to_string()),
+            ("device_info".to_string(), "1000".to_string()),
+            ("body".to_string(), "test".to_string()),
+            ("nonce_str".to_string(), "ibuaiVcKdpRxkhJA".to_string()),
+            ("sign".to_string(), "ignored".to_string()),
+            ("attach".to_string(), String::new()),
+        ]);
+
+        assert_eq!(
+            get_signature_message(&params, "192006250b4c09247ec02edce69f6a2d"),
+            "appid=wxd930ea5d5a258f4f&body=test&device_info=1000&mch_id=10000100&nonce_str=ibuaiVcKdpRxkhJA&key=192006250b4c09247ec02edce69f6a2d"
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils,
+    consts,
+    core::errors,
+    types::{self, api, storage::enums as storage_enums},
+};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+// Auth Struct
+pub struct WiseAuthType {
+    pub(super) api_key: String,
+    pub(super) profile_id: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for WiseAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                api_key: api_key.to_string(),
+                profile_id: key1.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Quote Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WisePayOutMethod {
+    BankTransfer,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseQuoteRequest {
+    source_currency: String,
+    target_currency: String,
+    target_amount: f64,
+    pay_out: WisePayOutMethod,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseQuoteResponse {
+    id: String,
+}
+
+// Payouts are funded from the balance held in the payout currency, so the quote is taken
+// without a conversion and fixes the amount the recipient receives
+impl TryFrom<&types::PayoutsRouterData<api::PoQuote>> for WiseQuoteRequest {
+    type Error = Error;
+    fn try_from(item: &types::PayoutsRouterData<api::PoQuote>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_currency: item.request.currency.to_string(),
+            target_currency: item.request.currency.to_string(),
+            target_amount: utils::to_currency_base_unit_asf64(
+                item.request.amount,
+                item.request.currency,
+            )?,
+            pay_out: WisePayOutMethod::BankTransfer,
+        })
+    }
+}
+
+impl TryFrom<types::PayoutsResponseRouterData<api::PoQuote, WiseQuoteResponse>>
+    for types::PayoutsRouterData<api::PoQuote>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<api::PoQuote, WiseQuoteResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            request: types::PayoutsData {
+                quote_id: Some(item.response.id.clone()),
+                ..item.data.request
+            },
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.id,
+                status: storage_enums::PayoutStatus::Pending,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Recipient Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WiseRecipientType {
+    Iban,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WiseLegalType {
+    Private,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseRecipientRequest {
+    currency: String,
+    #[serde(rename = "type")]
+    recipient_type: WiseRecipientType,
+    profile: String,
+    account_holder_name: Secret<String>,
+    details: WiseRecipientDetails,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseRecipientDetails {
+    legal_type: WiseLegalType,
+    #[serde(rename = "IBAN")]
+    iban: Secret<String>,
+    #[serde(rename = "BIC", skip_serializing_if = "Option::is_none")]
+    bic: Option<Secret<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseRecipientResponse {
+    id: i64,
+}
+
+impl TryFrom<&types::PayoutsRouterData<api::PoRecipient>> for WiseRecipientRequest {
+    type Error = Error;
+    fn try_from(item: &types::PayoutsRouterData<api::PoRecipient>) -> Result<Self, Self::Error> {
+        let auth_type = WiseAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item.request.payout_method_data.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        match payout_method_data {
+            api::PayoutMethodData::Bank(bank) => Ok(Self {
+                currency: item.request.currency.to_string(),
+                recipient_type: WiseRecipientType::Iban,
+                profile: auth_type.profile_id,
+                account_holder_name: bank.account_holder_name.clone(),
+                details: WiseRecipientDetails {
+                    legal_type: WiseLegalType::Private,
+                    iban: bank.iban.clone(),
+                    bic: bank.bic.clone(),
+                },
+            }),
+            api::PayoutMethodData::Card(_) => Err(errors::ConnectorError::NotImplemented(
+                "Card payouts for wise".to_string(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl TryFrom<types::PayoutsResponseRouterData<api::PoRecipient, WiseRecipientResponse>>
+    for types::PayoutsRouterData<api::PoRecipient>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<api::PoRecipient, WiseRecipientResponse>,
+    ) -> Result<Self, Self::Error> {
+        let recipient_id = item.response.id.to_string();
+        Ok(Self {
+            request: types::PayoutsData {
+                recipient_id: Some(recipient_id.clone()),
+                ..item.data.request
+            },
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: recipient_id,
+                status: storage_enums::PayoutStatus::Pending,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Transfer Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseTransferRequest {
+    target_account: i64,
+    quote_uuid: String,
+    customer_transaction_id: String,
+    details: WiseTransferDetails,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WiseTransferDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseTransferResponse {
+    id: i64,
+    status: WiseTransferStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WiseTransferStatus {
+    IncomingPaymentWaiting,
+    IncomingPaymentInitiated,
+    WaitingRecipientInputToProceed,
+    Processing,
+    FundsConverted,
+    OutgoingPaymentSent,
+    Cancelled,
+    FundsRefunded,
+    BouncedBack,
+    ChargedBack,
+}
+
+impl From<WiseTransferStatus> for storage_enums::PayoutStatus {
+    fn from(status: WiseTransferStatus) -> Self {
+        match status {
+            // Transfers wait for the payout to be fulfilled, which funds them from the balance
+            WiseTransferStatus::IncomingPaymentWaiting
+            | WiseTransferStatus::IncomingPaymentInitiated
+            | WiseTransferStatus::WaitingRecipientInputToProceed
+            | WiseTransferStatus::Processing
+            | WiseTransferStatus::FundsConverted => Self::Pending,
+            WiseTransferStatus::OutgoingPaymentSent => Self::Success,
+            WiseTransferStatus::Cancelled => Self::Cancelled,
+            WiseTransferStatus::FundsRefunded
+            | WiseTransferStatus::BouncedBack
+            | WiseTransferStatus::ChargedBack => Self::Failed,
+        }
+    }
+}
+
+impl TryFrom<&types::PayoutsRouterData<api::PoCreate>> for WiseTransferRequest {
+    type Error = Error;
+    fn try_from(item: &types::PayoutsRouterData<api::PoCreate>) -> Result<Self, Self::Error> {
+        let target_account = item
+            .request
+            .recipient_id
+            .as_ref()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "recipient_id",
+            })?
+            .parse::<i64>()
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Self {
+            target_account,
+            quote_uuid: item.request.quote_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "quote_id",
+                },
+            )?,
+            customer_transaction_id: uuid::Uuid::new_v4().to_string(),
+            details: WiseTransferDetails {
+                reference: item.request.description.clone(),
+            },
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseTransferResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, WiseTransferResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.id.to_string(),
+                status: storage_enums::PayoutStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Fund Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WiseFundType {
+    Balance,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WiseFundRequest {
+    #[serde(rename = "type")]
+    fund_type: WiseFundType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseFundResponse {
+    status: WiseFundStatus,
+    error_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WiseFundStatus {
+    Completed,
+    Rejected,
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for WiseFundRequest {
+    type Error = Error;
+    fn try_from(_item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fund_type: WiseFundType::Balance,
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseFundResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, WiseFundResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_payout_id = item.data.request.connector_payout_id.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            },
+        )?;
+        let response = match item.response.status {
+            // A funded transfer is still on its way to the recipient, which is settled by
+            // syncing the payout
+            WiseFundStatus::Completed => Ok(types::PayoutsResponseData {
+                connector_payout_id,
+                status: storage_enums::PayoutStatus::Pending,
+            }),
+            WiseFundStatus::Rejected => Err(types::ErrorResponse {
+                code: item
+                    .response
+                    .error_code
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .error_code
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code: item.http_code,
+                unified_code: None,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+// Error Response Types
+#[derive(Debug, Deserialize)]
+pub struct WiseErrorResponse {
+    pub errors: Option<Vec<WiseErrorDetails>>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseErrorDetails {
+    pub code: String,
+    pub message: Option<String>,
+    pub path: Option<String>,
+}
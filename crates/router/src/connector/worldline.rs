@@ -9,11 +9,12 @@ use storage_models::enums;
 use time::{format_description, OffsetDateTime};
 use transformers as worldline;
 
-use super::utils::RefundsRequestData;
+use super::utils::{self as conn_utils, RefundsRequestData};
 use crate::{
     configs::settings::Connectors,
     consts,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     headers, logger,
     services::{self, ConnectorIntegration},
     types::{
@@ -21,7 +22,7 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse,
     },
-    utils::{self, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -125,6 +126,12 @@ impl ConnectorCommon for Worldline {
     }
 }
 
+impl api::ConnectorSpecifications for Worldline {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[api::enums::PaymentMethod::Card])
+    }
+}
+
 impl api::ConnectorAccessToken for Worldline {}
 
 impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
@@ -651,27 +658,192 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Worldline {}
+impl api::PayoutCreate for Worldline {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::PayoutFulfill for Worldline {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::PayoutCancel for Worldline {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::PayoutSync for Worldline {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::RefundCredit for Worldline {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Worldline
+{
+}
+
+impl api::ConnectorMandateRevoke for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Worldline
+{
+}
+
+impl api::Dispute for Worldline {}
+impl api::SubmitEvidence for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldline
+{
+}
+
+fn is_endpoint_verification(request: &api::IncomingWebhookRequestDetails<'_>) -> bool {
+    request.method == actix_web::http::Method::GET
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldline {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(api::WebhookSourceVerificationScheme::HmacSha256)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            conn_utils::get_header_key_value(headers::X_GCS_SIGNATURE, request.headers)?;
+        consts::BASE64_ENGINE
+            .decode(signature.as_bytes())
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    async fn verify_webhook_source(
+        &self,
+        db: &dyn StorageInterface,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        // Endpoint verification requests carry no body and are not signed
+        if is_endpoint_verification(request) {
+            return Ok(false);
+        }
+        let algorithm = self
+            .get_webhook_source_verification_scheme(request)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?
+            .get_algorithm();
+        let signature = self
+            .get_webhook_source_verification_signature(request)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let secret = self
+            .get_webhook_source_verification_merchant_secret(db, merchant_id)
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = self
+            .get_webhook_source_verification_message(request, merchant_id, &secret)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        algorithm
+            .verify_signature(&secret, &signature, &message)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: worldline::WebhookBody = request
+            .body
+            .parse_struct("WorldlineWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        webhook.get_reference_id()
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        if is_endpoint_verification(request) {
+            return Ok(api::IncomingWebhookEvent::EndpointVerification);
+        }
+        let webhook: worldline::WebhookBody = request
+            .body
+            .parse_struct("WorldlineWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        api::IncomingWebhookEvent::try_from(webhook.event_type)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: worldline::WebhookBody = request
+            .body
+            .parse_struct("WorldlineWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        let resource = match (webhook.payment, webhook.refund) {
+            (_, Some(refund)) => {
+                utils::Encode::<worldline::RefundResponse>::encode_to_value(&refund)
+            }
+            (Some(payment), None) => utils::Encode::<worldline::Payment>::encode_to_value(&payment),
+            (None, None) => {
+                return Err(errors::ConnectorError::WebhookResourceObjectNotFound.into())
+            }
+        };
+        resource.change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
+    }
+
+    fn get_webhook_api_response(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_secret: &[u8],
+    ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
+    {
+        if is_endpoint_verification(request) {
+            // The verification value is echoed back to confirm the endpoint
+            let verification = conn_utils::get_header_key_value(
+                headers::X_GCS_WEBHOOKS_ENDPOINT_VERIFICATION,
+                request.headers,
+            )?;
+            Ok(services::api::ApplicationResponse::TextPlain(
+                verification.to_string(),
+            ))
+        } else {
+            Ok(services::api::ApplicationResponse::StatusOk)
+        }
     }
 }
 
@@ -284,7 +284,7 @@ impl TryFrom<&types::ConnectorAuthType> for AuthType {
     }
 }
 
-#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentStatus {
     Captured,
@@ -324,11 +324,11 @@ impl ForeignFrom<(PaymentStatus, enums::CaptureMethod)> for enums::AttemptStatus
 /// capture_method is not part of response from connector.
 /// This is used to decide payment status while converting connector response to RouterData.
 /// To keep this try_from logic generic in case of AUTHORIZE, SYNC and CAPTURE flows capture_method will be set from RouterData request.
-#[derive(Default, Debug, Clone, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Payment {
     id: String,
     status: PaymentStatus,
-    #[serde(skip_deserializing)]
+    #[serde(skip)]
     pub capture_method: enums::CaptureMethod,
 }
 
@@ -349,6 +349,9 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, Payment, T, types::PaymentsRespo
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -377,6 +380,9 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentResponse, T, types::Payme
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..item.data
         })
@@ -410,7 +416,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for WorldlineRefundRequest {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum RefundStatus {
     Cancelled,
@@ -430,7 +436,7 @@ impl From<RefundStatus> for enums::RefundStatus {
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct RefundResponse {
     id: String,
     status: RefundStatus,
@@ -486,3 +492,56 @@ pub struct ErrorResponse {
     pub error_id: Option<String>,
     pub errors: Vec<Error>,
 }
+
+#[derive(Debug, Deserialize)]
+pub enum WebhookEvent {
+    #[serde(rename = "payment.captured")]
+    PaymentCaptured,
+    #[serde(rename = "payment.paid")]
+    PaymentPaid,
+    #[serde(rename = "payment.rejected")]
+    PaymentRejected,
+    #[serde(rename = "payment.rejected_capture")]
+    PaymentRejectedCapture,
+    #[serde(rename = "refund.refunded")]
+    RefundRefunded,
+    #[serde(rename = "refund.rejected")]
+    RefundRejected,
+    #[serde(rename = "refund.cancelled")]
+    RefundCancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: WebhookEvent,
+    pub payment: Option<Payment>,
+    pub refund: Option<RefundResponse>,
+}
+
+impl WebhookBody {
+    pub fn get_reference_id(&self) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+        match (&self.payment, &self.refund) {
+            (_, Some(refund)) => Ok(refund.id.clone()),
+            (Some(payment), None) => Ok(payment.id.clone()),
+            (None, None) => Err(errors::ConnectorError::WebhookReferenceIdNotFound.into()),
+        }
+    }
+}
+
+impl TryFrom<WebhookEvent> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+        match event {
+            WebhookEvent::PaymentCaptured => Ok(Self::PaymentIntentCaptureSuccess),
+            WebhookEvent::PaymentPaid => Ok(Self::PaymentIntentSuccess),
+            WebhookEvent::PaymentRejected => Ok(Self::PaymentIntentFailure),
+            WebhookEvent::PaymentRejectedCapture => Ok(Self::PaymentIntentCaptureFailure),
+            WebhookEvent::RefundRefunded => Ok(Self::RefundSuccess),
+            WebhookEvent::RefundRejected | WebhookEvent::RefundCancelled => Ok(Self::RefundFailure),
+            WebhookEvent::Unknown => Err(errors::ConnectorError::WebhookEventTypeNotFound.into()),
+        }
+    }
+}
@@ -61,6 +61,10 @@ impl ConnectorCommon for Worldpay {
         connectors.worldpay.base_url.as_ref()
     }
 
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        Some("worldpay")
+    }
+
     fn get_auth_header(
         &self,
         auth_type: &types::ConnectorAuthType,
@@ -84,10 +88,20 @@ impl ConnectorCommon for Worldpay {
             code: response.error_name,
             message: response.message,
             reason: response.validation_errors.map(|e| e.to_string()),
+            unified_code: None,
         })
     }
 }
 
+impl api::ConnectorSpecifications for Worldpay {
+    fn supported_payment_methods(&self) -> Option<&'static [api::enums::PaymentMethod]> {
+        Some(&[
+            api::enums::PaymentMethod::Card,
+            api::enums::PaymentMethod::Wallet,
+        ])
+    }
+}
+
 impl api::Payment for Worldpay {}
 
 impl api::PreVerify for Worldpay {}
@@ -163,6 +177,9 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
                         redirection_data: None,
                         mandate_reference: None,
                         connector_metadata: None,
+                        network_txn_id: None,
+                        avs_result: None,
+                        cvv_result: None,
                     }),
                     ..data.clone()
                 })
@@ -258,6 +275,9 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                network_txn_id: None,
+                avs_result: None,
+                cvv_result: None,
             }),
             ..data.clone()
         })
@@ -314,6 +334,9 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
                         redirection_data: None,
                         mandate_reference: None,
                         connector_metadata: None,
+                        network_txn_id: None,
+                        avs_result: None,
+                        cvv_result: None,
                     }),
                     ..data.clone()
                 })
@@ -588,6 +611,65 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Worldpay {}
+impl api::PayoutCreate for Worldpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::PayoutFulfill for Worldpay {}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::PayoutCancel for Worldpay {}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::PayoutSync for Worldpay {}
+
+impl ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::RefundCredit for Worldpay {}
+
+impl ConnectorIntegration<api::Credit, types::CreditsData, types::RefundsResponseData>
+    for Worldpay
+{
+}
+
+impl api::ConnectorMandateRevoke for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Worldpay
+{
+}
+
+impl api::Dispute for Worldpay {}
+impl api::SubmitEvidence for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldpay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldpay {
     fn get_webhook_object_reference_id(
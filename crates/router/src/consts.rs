@@ -23,4 +23,13 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+/// Validity of the JWTs issued to dashboard users (in seconds)
+pub(crate) const JWT_TOKEN_TIME_IN_SECS: i64 = 60 * 60 * 24 * 2;
+/// Minimum length of the passwords of dashboard users
+pub(crate) const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Time before an authorization expires at which the merchant's authorization expiry policy is
+/// applied, if the policy does not specify it (in seconds)
+pub(crate) const DEFAULT_AUTHORIZATION_EXPIRY_NOTICE_PERIOD_IN_SECS: i64 = 3600;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
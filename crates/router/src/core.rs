@@ -1,11 +1,32 @@
 pub mod admin;
+pub mod analytics;
 pub mod api_keys;
+pub mod audit_logs;
+pub mod authentication;
+pub mod blocklist;
+pub mod card_verification;
+pub mod cards_info;
 pub mod configs;
+pub mod connector_health;
+pub mod connector_payloads;
 pub mod customers;
+pub mod disputes;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
+pub mod duplicate_payment;
 pub mod errors;
+pub mod forex;
+pub mod fraud_check;
 pub mod mandate;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod test_mode;
+pub mod token_migration;
+pub mod user;
+pub mod user_roles;
 pub mod utils;
 pub mod webhooks;
@@ -1,13 +1,18 @@
 use common_utils::ext_traits::ValueExt;
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use storage_models::{enums, merchant_account};
 use uuid::Uuid;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        api_keys, audit_logs,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        test_mode,
+    },
     db::StorageInterface,
     pii::Secret,
+    scheduler::utils as pt_utils,
     services::api as service_api,
     types::{
         self, api,
@@ -17,6 +22,9 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+const DEFAULT_API_KEY_NAME: &str = "Default API key";
+const DEFAULT_BUSINESS_PROFILE_NAME: &str = "default";
+
 #[inline]
 pub fn create_merchant_api_key() -> String {
     format!(
@@ -32,8 +40,6 @@ pub async fn create_merchant_account(
 ) -> RouterResponse<api::MerchantAccountResponse> {
     let publishable_key = Some(format!("pk_{}", create_merchant_api_key()));
 
-    let api_key = Some(create_merchant_api_key().into());
-
     let merchant_details = Some(
         utils::Encode::<api::MerchantDetails>::encode_to_value(&req.merchant_details)
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
@@ -58,10 +64,26 @@ pub async fn create_merchant_account(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    let authorization_expiry_policy =
+        encode_authorization_expiry_policy(req.authorization_expiry_policy.as_ref())?;
+    let sca_exemption_policy = encode_sca_exemption_policy(req.sca_exemption_policy.as_ref())?;
+    let credit_policy = encode_credit_policy(req.credit_policy.as_ref())?;
+    let fraud_check_policy = encode_fraud_check_policy(req.fraud_check_policy.as_ref())?;
+    let velocity_policy = encode_velocity_policy(req.velocity_policy.as_ref())?;
+    let duplicate_payment_policy =
+        encode_duplicate_payment_policy(req.duplicate_payment_policy.as_ref())?;
+    let card_verification_policy =
+        encode_card_verification_policy(req.card_verification_policy.as_ref())?;
+    test_mode::validate_test_mode(req.test_mode)?;
+
+    let (organization_id, is_new_organization) =
+        get_organization_id(db, req.organization_id).await?;
+
     let merchant_account = storage::MerchantAccountNew {
         merchant_id: req.merchant_id,
         merchant_name: req.merchant_name,
-        api_key,
+        // Only the hash of the API key of the merchant is stored, with the API keys of the merchant
+        api_key: None,
         merchant_details,
         return_url: req.return_url.map(|a| a.to_string()),
         webhook_details,
@@ -79,6 +101,16 @@ pub async fn create_merchant_account(
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        routing_rules: None,
+        authorization_expiry_policy,
+        sca_exemption_policy,
+        credit_policy,
+        fraud_check_policy,
+        velocity_policy,
+        duplicate_payment_policy,
+        card_verification_policy,
+        test_mode: req.test_mode,
+        organization_id: Some(organization_id.clone()),
     };
 
     let merchant_account = db
@@ -88,9 +120,32 @@ pub async fn create_merchant_account(
             error.to_duplicate_response(errors::ApiErrorResponse::DuplicateMerchantAccount)
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
-        merchant_account.foreign_into(),
-    ))
+    if is_new_organization {
+        db.insert_organization(storage::OrganizationNew {
+            org_id: organization_id,
+            org_name: merchant_account.merchant_name.clone(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create an organization for the merchant account")?;
+    }
+
+    let api_key: api::CreateApiKeyResponse = api_keys::generate_api_key(
+        db,
+        merchant_account.merchant_id.clone(),
+        DEFAULT_API_KEY_NAME.to_string(),
+        None,
+        None,
+        enums::RoleName::Admin,
+    )
+    .await?
+    .foreign_into();
+
+    // The API key is returned only once, when the merchant account is created
+    let mut merchant_account: api::MerchantAccountResponse = merchant_account.foreign_into();
+    merchant_account.api_key = Some(api_key.api_key);
+
+    Ok(service_api::ApplicationResponse::Json(merchant_account))
 }
 
 pub async fn get_merchant_account(
@@ -136,6 +191,8 @@ pub async fn merchant_account_update(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    test_mode::validate_test_mode(req.test_mode)?;
+
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
         merchant_name: req.merchant_name,
 
@@ -169,6 +226,20 @@ pub async fn merchant_account_update(
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        authorization_expiry_policy: encode_authorization_expiry_policy(
+            req.authorization_expiry_policy.as_ref(),
+        )?,
+        sca_exemption_policy: encode_sca_exemption_policy(req.sca_exemption_policy.as_ref())?,
+        credit_policy: encode_credit_policy(req.credit_policy.as_ref())?,
+        fraud_check_policy: encode_fraud_check_policy(req.fraud_check_policy.as_ref())?,
+        velocity_policy: encode_velocity_policy(req.velocity_policy.as_ref())?,
+        duplicate_payment_policy: encode_duplicate_payment_policy(
+            req.duplicate_payment_policy.as_ref(),
+        )?,
+        card_verification_policy: encode_card_verification_policy(
+            req.card_verification_policy.as_ref(),
+        )?,
+        test_mode: req.test_mode,
         api_key: None,
         publishable_key: None,
     };
@@ -180,28 +251,246 @@ pub async fn merchant_account_update(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })?;
 
+    audit_logs::record_resource_change(
+        db,
+        merchant_id,
+        enums::AuditResourceType::MerchantAccount,
+        merchant_id,
+        None,
+        None,
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(
         response.foreign_into(),
     ))
 }
 
+/// Closes the merchant account. The account is left in place as a tombstone, and the personal data
+/// held for the merchant is redacted by the process tracker afterwards.
 pub async fn merchant_account_delete(
     db: &dyn StorageInterface,
     merchant_id: String,
 ) -> RouterResponse<api::DeleteMerchantAccountResponse> {
-    let is_deleted = db
-        .delete_merchant_account_by_merchant_id(&merchant_id)
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
         .await
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })?;
+
+    db.update_merchant(
+        merchant_account,
+        storage::MerchantAccountUpdate::SoftDelete {
+            deleted_at: common_utils::date_time::now(),
+        },
+    )
+    .await
+    .map_err(|error| {
+        error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+    })?;
+
+    add_merchant_account_redaction_task(db, &merchant_id)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while adding merchant account redaction task to process tracker",
+        )?;
+
+    audit_logs::record_resource_change(
+        db,
+        &merchant_id,
+        enums::AuditResourceType::MerchantAccount,
+        &merchant_id,
+        Some(String::from("active")),
+        Some(String::from("deleted")),
+    )
+    .await;
+
     let response = api::DeleteMerchantAccountResponse {
         merchant_id,
-        deleted: is_deleted,
+        deleted: true,
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Identifies the closed merchant account whose data the merchant account redaction workflow
+/// redacts.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantAccountRedactionTrackingData {
+    pub merchant_id: String,
+}
+
+async fn add_merchant_account_redaction_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = MerchantAccountRedactionTrackingData {
+        merchant_id: merchant_id.to_string(),
+    };
+    let runner = "MERCHANT_ACCOUNT_REDACTION_WORKFLOW";
+    let task = "MERCHANT_ACCOUNT_REDACTION";
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, merchant_id, merchant_id);
+    let mut process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            common_utils::date_time::now(),
+        )?;
+    process_tracker_entry.tag = vec![String::from("REDACTION"), String::from("MERCHANT")];
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+fn encode_authorization_expiry_policy(
+    authorization_expiry_policy: Option<&api::AuthorizationExpiryPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(notice_period_in_secs) =
+        authorization_expiry_policy.and_then(|policy| policy.notice_period_in_secs)
+    {
+        utils::when(notice_period_in_secs <= 0, || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "authorization_expiry_policy.notice_period_in_secs",
+            })
+        })?;
+    }
+
+    authorization_expiry_policy
+        .map(utils::Encode::<api::AuthorizationExpiryPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "authorization_expiry_policy",
+        })
+}
+
+fn encode_sca_exemption_policy(
+    sca_exemption_policy: Option<&api::ScaExemptionPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(policy) = sca_exemption_policy {
+        utils::when(
+            policy
+                .low_value_threshold
+                .map_or(false, |threshold| threshold <= 0),
+            || {
+                Err(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "sca_exemption_policy.low_value_threshold",
+                })
+            },
+        )?;
+        utils::when(
+            policy
+                .transaction_risk_analysis_threshold
+                .map_or(false, |threshold| threshold <= 0),
+            || {
+                Err(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "sca_exemption_policy.transaction_risk_analysis_threshold",
+                })
+            },
+        )?;
+    }
+
+    sca_exemption_policy
+        .map(utils::Encode::<api::ScaExemptionPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "sca_exemption_policy",
+        })
+}
+
+fn encode_credit_policy(
+    credit_policy: Option<&api::CreditPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(max_amount) = credit_policy.and_then(|policy| policy.max_amount) {
+        utils::when(max_amount <= 0, || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "credit_policy.max_amount",
+            })
+        })?;
+    }
+
+    credit_policy
+        .map(utils::Encode::<api::CreditPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "credit_policy",
+        })
+}
+
+fn encode_fraud_check_policy(
+    fraud_check_policy: Option<&api::FraudCheckPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    fraud_check_policy
+        .map(utils::Encode::<api::FraudCheckPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "fraud_check_policy",
+        })
+}
+
+fn encode_velocity_policy(
+    velocity_policy: Option<&api::VelocityPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    velocity_policy
+        .map(utils::Encode::<api::VelocityPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "velocity_policy",
+        })
+}
+
+fn encode_duplicate_payment_policy(
+    duplicate_payment_policy: Option<&api::DuplicatePaymentPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(policy) = duplicate_payment_policy {
+        utils::when(policy.window_in_secs == 0, || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "duplicate_payment_policy.window_in_secs",
+            })
+        })?;
+    }
+
+    duplicate_payment_policy
+        .map(utils::Encode::<api::DuplicatePaymentPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "duplicate_payment_policy",
+        })
+}
+
+fn encode_card_verification_policy(
+    card_verification_policy: Option<&api::CardVerificationPolicy>,
+) -> RouterResult<Option<serde_json::Value>> {
+    card_verification_policy
+        .map(utils::Encode::<api::CardVerificationPolicy>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_verification_policy",
+        })
+}
+
+/// Validates the organization the merchant account is to be created under. If none is provided,
+/// a new organization is created for the merchant account, once the merchant account is created.
+async fn get_organization_id(
+    db: &dyn StorageInterface,
+    organization_id: Option<String>,
+) -> RouterResult<(String, bool)> {
+    match organization_id {
+        Some(organization_id) => db
+            .find_organization_by_org_id(&organization_id)
+            .await
+            .map(|organization| (organization.org_id, false))
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::OrganizationNotFound)
+            }),
+        None => Ok((utils::generate_id(consts::ID_LENGTH, "org"), true)),
+    }
+}
+
 async fn get_parent_merchant(
     db: &dyn StorageInterface,
     sub_merchants_enabled: Option<bool>,
@@ -282,6 +571,18 @@ pub async fn create_payment_connector(
             expected_format: "auth_type and api_key".to_string(),
         })?;
 
+    let connector_webhook_details = req
+        .connector_webhook_details
+        .as_ref()
+        .map(|webhook_details| {
+            utils::Encode::<api::MerchantConnectorWebhookDetails>::encode_to_value(webhook_details)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while encoding to serde_json::Value, MerchantConnectorWebhookDetails",
+        )?;
+
     let merchant_connector_account = storage::MerchantConnectorAccountNew {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
@@ -292,6 +593,7 @@ pub async fn create_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        connector_webhook_details,
     };
 
     let mca = store
@@ -301,6 +603,16 @@ pub async fn create_payment_connector(
             error.to_duplicate_response(errors::ApiErrorResponse::DuplicateMerchantConnectorAccount)
         })?;
 
+    audit_logs::record_resource_change(
+        store,
+        merchant_id,
+        enums::AuditResourceType::MerchantConnectorAccount,
+        &mca.merchant_connector_id,
+        None,
+        Some(get_connector_account_status(mca.disabled)),
+    )
+    .await;
+
     response.merchant_connector_id = Some(mca.merchant_connector_id);
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -360,6 +672,61 @@ pub async fn list_payment_connectors(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Re-encrypts the credentials of all connectors of the merchant with the current key of the
+/// secrets manager, so that keys which have been rotated out can be retired.
+pub async fn rotate_payment_connectors_key(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantConnectorKeyRotationResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(&merchant_id, true)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching merchant connector accounts")?;
+
+    let mut merchant_connector_ids = Vec::with_capacity(merchant_connector_accounts.len());
+    for mca in merchant_connector_accounts {
+        let merchant_connector_id = mca.merchant_connector_id.clone();
+        let connector_account_details = Secret::new(mca.connector_account_details.clone());
+        db.update_merchant_connector_account(
+            mca,
+            storage::MerchantConnectorAccountUpdate::Update {
+                merchant_id: None,
+                connector_type: None,
+                connector_name: None,
+                connector_account_details: Some(connector_account_details),
+                test_mode: None,
+                disabled: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                metadata: None,
+                connector_webhook_details: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while re-encrypting MerchantConnectorAccount: id: {merchant_connector_id}"
+            )
+        })?;
+        merchant_connector_ids.push(merchant_connector_id);
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantConnectorKeyRotationResponse {
+            merchant_id,
+            merchant_connector_ids,
+        },
+    ))
+}
+
 pub async fn update_payment_connector(
     db: &dyn StorageInterface,
     merchant_id: &str,
@@ -392,6 +759,19 @@ pub async fn update_payment_connector(
             .collect::<Vec<serde_json::Value>>()
     });
 
+    let connector_webhook_details = req
+        .connector_webhook_details
+        .as_ref()
+        .map(|webhook_details| {
+            utils::Encode::<api::MerchantConnectorWebhookDetails>::encode_to_value(webhook_details)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while encoding to serde_json::Value, MerchantConnectorWebhookDetails",
+        )?;
+
+    let previous_status = get_connector_account_status(mca.disabled);
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
@@ -402,6 +782,7 @@ pub async fn update_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        connector_webhook_details,
     };
 
     let updated_mca = db
@@ -412,6 +793,16 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
+    audit_logs::record_resource_change(
+        db,
+        merchant_id,
+        enums::AuditResourceType::MerchantConnectorAccount,
+        merchant_connector_id,
+        Some(previous_status),
+        Some(get_connector_account_status(updated_mca.disabled)),
+    )
+    .await;
+
     let updated_pm_enabled = updated_mca.payment_methods_enabled.map(|pm| {
         pm.into_iter()
             .flat_map(|pm_value| {
@@ -423,6 +814,17 @@ pub async fn update_payment_connector(
             .collect::<Vec<api_models::admin::PaymentMethodsEnabled>>()
     });
 
+    let connector_webhook_details = updated_mca
+        .connector_webhook_details
+        .map(|webhook_details| {
+            ValueExt::<api::MerchantConnectorWebhookDetails>::parse_value(
+                webhook_details,
+                "MerchantConnectorWebhookDetails",
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
     let response = api::PaymentConnectorCreate {
         connector_type: updated_mca.connector_type.foreign_into(),
         connector_name: updated_mca.connector_name,
@@ -432,6 +834,7 @@ pub async fn update_payment_connector(
         disabled: updated_mca.disabled,
         payment_methods_enabled: updated_pm_enabled,
         metadata: updated_mca.metadata,
+        connector_webhook_details,
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -457,6 +860,17 @@ pub async fn delete_payment_connector(
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
+
+    audit_logs::record_resource_change(
+        db,
+        &merchant_id,
+        enums::AuditResourceType::MerchantConnectorAccount,
+        &merchant_connector_id,
+        None,
+        Some(String::from("deleted")),
+    )
+    .await;
+
     let response = api::DeleteMcaResponse {
         merchant_id,
         merchant_connector_id,
@@ -465,6 +879,15 @@ pub async fn delete_payment_connector(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Status of a merchant connector account, as recorded in the audit log
+fn get_connector_account_status(disabled: Option<bool>) -> String {
+    if disabled.unwrap_or(false) {
+        String::from("disabled")
+    } else {
+        String::from("enabled")
+    }
+}
+
 pub async fn kv_for_merchant(
     db: &dyn StorageInterface,
     merchant_id: String,
@@ -542,3 +965,265 @@ pub async fn check_merchant_account_kv_status(
         },
     ))
 }
+
+pub async fn create_organization(
+    db: &dyn StorageInterface,
+    req: api::OrganizationCreateRequest,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = db
+        .insert_organization(storage::OrganizationNew {
+            org_id: utils::generate_id(consts::ID_LENGTH, "org"),
+            org_name: req.org_name,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create organization")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::OrganizationResponse {
+            org_id: organization.org_id,
+            org_name: organization.org_name,
+            merchant_ids: Vec::new(),
+            created: organization.created_at,
+        },
+    ))
+}
+
+pub async fn retrieve_organization(
+    db: &dyn StorageInterface,
+    org_id: &str,
+) -> RouterResponse<api::OrganizationResponse> {
+    let organization = db
+        .find_organization_by_org_id(org_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::OrganizationNotFound)
+        })?;
+
+    let merchant_ids = db
+        .list_merchant_accounts_by_organization_id(org_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant accounts of the organization")?
+        .into_iter()
+        .map(|merchant_account| merchant_account.merchant_id)
+        .collect();
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::OrganizationResponse {
+            org_id: organization.org_id,
+            org_name: organization.org_name,
+            merchant_ids,
+            created: organization.created_at,
+        },
+    ))
+}
+
+// Business Profile API - A merchant account can have any number of business profiles, such as one
+//                        per country or label of its business
+
+pub async fn create_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+    req: api::BusinessProfileCreate,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    validate_merchant_id(db, merchant_id.clone()).await?;
+
+    let profile_name = req.profile_name.clone().unwrap_or_else(|| {
+        let name = [
+            req.business_country.as_deref(),
+            req.business_label.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("_");
+        if name.is_empty() {
+            DEFAULT_BUSINESS_PROFILE_NAME.to_string()
+        } else {
+            name
+        }
+    });
+
+    let business_profile = storage::BusinessProfileNew {
+        profile_id: utils::generate_id(consts::ID_LENGTH, "pro"),
+        merchant_id,
+        profile_name,
+        business_country: validate_business_country(req.business_country)?,
+        business_label: req.business_label,
+        webhook_details: encode_business_profile_webhook_details(req.webhook_details.as_ref())?,
+        routing_algorithm: validate_business_profile_routing_algorithm(req.routing_algorithm)?,
+    };
+
+    let business_profile = db
+        .insert_business_profile(business_profile)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicateBusinessProfile)
+        })?;
+
+    audit_logs::record_resource_change(
+        db,
+        &business_profile.merchant_id,
+        enums::AuditResourceType::BusinessProfile,
+        &business_profile.profile_id,
+        None,
+        None,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        business_profile.foreign_into(),
+    ))
+}
+
+pub async fn list_business_profiles(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResponse<Vec<api::BusinessProfileResponse>> {
+    validate_merchant_id(db, merchant_id).await?;
+
+    let business_profiles = db
+        .list_business_profiles_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the business profiles of the merchant account")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(service_api::ApplicationResponse::Json(business_profiles))
+}
+
+pub async fn retrieve_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(merchant_id, profile_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        business_profile.foreign_into(),
+    ))
+}
+
+pub async fn update_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+    req: api::BusinessProfileCreate,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    let business_profile_update = storage::BusinessProfileUpdate::Update {
+        profile_name: req.profile_name,
+        business_country: validate_business_country(req.business_country)?,
+        business_label: req.business_label,
+        webhook_details: encode_business_profile_webhook_details(req.webhook_details.as_ref())?,
+        routing_algorithm: validate_business_profile_routing_algorithm(req.routing_algorithm)?,
+    };
+
+    let business_profile = db
+        .update_business_profile_by_merchant_id_profile_id(
+            merchant_id,
+            profile_id,
+            business_profile_update,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.to_duplicate_response(errors::ApiErrorResponse::DuplicateBusinessProfile)
+            } else {
+                error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+            }
+        })?;
+
+    audit_logs::record_resource_change(
+        db,
+        merchant_id,
+        enums::AuditResourceType::BusinessProfile,
+        profile_id,
+        None,
+        None,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        business_profile.foreign_into(),
+    ))
+}
+
+pub async fn delete_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<api::DeleteBusinessProfileResponse> {
+    let business_profile = db
+        .delete_business_profile_by_merchant_id_profile_id(merchant_id, profile_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+        })?;
+
+    audit_logs::record_resource_change(
+        db,
+        merchant_id,
+        enums::AuditResourceType::BusinessProfile,
+        profile_id,
+        None,
+        Some(String::from("deleted")),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::DeleteBusinessProfileResponse {
+            merchant_id: business_profile.merchant_id,
+            profile_id: business_profile.profile_id,
+            deleted: true,
+        },
+    ))
+}
+
+fn validate_business_country(business_country: Option<String>) -> RouterResult<Option<String>> {
+    if let Some(ref country) = business_country {
+        utils::when(
+            country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase()),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "business_country",
+                })
+                .attach_printable("The business country must be an ISO 3166-1 alpha-2 code"))
+            },
+        )?;
+    }
+    Ok(business_country)
+}
+
+fn encode_business_profile_webhook_details(
+    webhook_details: Option<&api::WebhookDetails>,
+) -> RouterResult<Option<serde_json::Value>> {
+    webhook_details
+        .map(utils::Encode::<api::WebhookDetails>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "webhook_details",
+        })
+}
+
+fn validate_business_profile_routing_algorithm(
+    routing_algorithm: Option<serde_json::Value>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(ref routing_algorithm) = routing_algorithm {
+        let _: api::RoutingAlgorithm = routing_algorithm
+            .clone()
+            .parse_value("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            })
+            .attach_printable("Invalid routing algorithm given")?;
+    }
+    Ok(routing_algorithm)
+}
@@ -0,0 +1,449 @@
+//! Analytics of the payments of a merchant, such as the success rate, volume and latency of the
+//! payment attempts, by time bucket and by connector, payment method, currency or status.
+//!
+//! When ClickHouse is enabled, payment attempts are recorded there as they are processed, and the
+//! analytics are computed there instead of the database.
+
+use base64::Engine;
+use error_stack::{report, IntoReport, ResultExt};
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
+use serde::Serialize;
+
+use crate::{
+    configs::settings::ClickhouseSettings,
+    consts,
+    core::errors::{self, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::storage::{self, enums as storage_enums},
+    utils,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::RouterResponse,
+    types::{api, storage::PaymentAnalyticsRow, transformers::ForeignFrom},
+    utils::BytesExt,
+};
+
+/// Maximum number of time buckets the payments can be aggregated in at once
+#[cfg(feature = "olap")]
+const MAX_TIME_BUCKETS: i64 = 1000;
+
+/// Row of the payment attempts table of ClickHouse
+#[derive(Debug, Serialize)]
+struct ClickhousePaymentAttempt {
+    attempt_id: String,
+    payment_id: String,
+    merchant_id: String,
+    status: storage_enums::AttemptStatus,
+    amount: i64,
+    currency: Option<storage_enums::Currency>,
+    connector: Option<String>,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    modified_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, serde::Deserialize)]
+struct ClickhouseQueryResponse {
+    data: Vec<PaymentAnalyticsRow>,
+}
+
+#[cfg(feature = "olap")]
+fn get_bucket_size(granularity: api::AnalyticsGranularity) -> time::Duration {
+    match granularity {
+        api::AnalyticsGranularity::Hour => time::Duration::HOUR,
+        api::AnalyticsGranularity::Day => time::Duration::DAY,
+        api::AnalyticsGranularity::Week => time::Duration::WEEK,
+        api::AnalyticsGranularity::Month => time::Duration::days(30),
+    }
+}
+
+#[cfg(feature = "olap")]
+fn validate_analytics_request(
+    req: &api::PaymentAnalyticsRequest,
+    end_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let duration = end_time - req.time_range.start_time;
+    utils::when(duration <= time::Duration::ZERO, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "time_range.start_time should be before time_range.end_time".to_string(),
+        }))
+    })?;
+
+    let time_buckets = req.granularity.map_or(1, |granularity| {
+        (duration.whole_seconds() / get_bucket_size(granularity).whole_seconds()) + 1
+    });
+    utils::when(time_buckets > MAX_TIME_BUCKETS, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "time_range spans more than {MAX_TIME_BUCKETS} time buckets of the granularity"
+            ),
+        }))
+    })
+}
+
+/// Aggregates the payment attempts of the merchant made in the time range, by time bucket and
+/// group.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn get_payment_analytics(
+    state: &AppState,
+    merchant: storage::MerchantAccount,
+    req: api::PaymentAnalyticsRequest,
+) -> RouterResponse<api::PaymentAnalyticsResponse> {
+    let end_time = req
+        .time_range
+        .end_time
+        .unwrap_or_else(common_utils::date_time::now);
+    validate_analytics_request(&req, end_time)?;
+
+    let rows = if state.conf.clickhouse.enabled {
+        query_clickhouse(state, &merchant.merchant_id, &req, end_time).await?
+    } else {
+        state
+            .store
+            .get_payment_analytics(&merchant.merchant_id, &req, end_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to aggregate the payment attempts")?
+    };
+
+    let query_data = rows
+        .into_iter()
+        .map(|row| api::PaymentAnalyticsBucket {
+            time_bucket: row.time_bucket,
+            connector: row.connector,
+            payment_method: row.payment_method,
+            currency: row.currency,
+            status: row.status,
+            payment_count: row.payment_count,
+            success_count: row.success_count,
+            success_rate: get_success_rate(row.success_count, row.payment_count),
+            total_amount: row.total_amount,
+            avg_latency_ms: row.avg_latency_ms,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentAnalyticsResponse { query_data },
+    ))
+}
+
+#[cfg(feature = "olap")]
+#[allow(clippy::as_conversions)]
+fn get_success_rate(success_count: i64, payment_count: i64) -> f64 {
+    if payment_count == 0 {
+        return 0.0;
+    }
+    (success_count as f64 * 100.0) / payment_count as f64
+}
+
+fn get_auth_header(settings: &ClickhouseSettings) -> Option<String> {
+    (!settings.username.is_empty()).then(|| {
+        let credentials =
+            consts::BASE64_ENGINE.encode(format!("{}:{}", settings.username, settings.password));
+        format!("Basic {credentials}")
+    })
+}
+
+/// Builds the query aggregating the payment attempts in ClickHouse. The values of the filters are
+/// all written by the router from enums, the merchant and the time range are passed as parameters.
+/// Columns are qualified with the table, as ClickHouse resolves names to the aliases of the query
+/// first, which are named after the grouped columns.
+#[cfg(feature = "olap")]
+fn build_clickhouse_query(database: &str, req: &api::PaymentAnalyticsRequest) -> String {
+    let time_bucket = match req.granularity {
+        Some(api::AnalyticsGranularity::Hour) => "toStartOfHour(attempts.created_at)",
+        Some(api::AnalyticsGranularity::Day) => "toStartOfDay(attempts.created_at)",
+        Some(api::AnalyticsGranularity::Week) => "toStartOfWeek(attempts.created_at, 1)",
+        Some(api::AnalyticsGranularity::Month) => "toStartOfMonth(attempts.created_at)",
+        None => "CAST(NULL AS Nullable(DateTime))",
+    };
+    let dimension = |dimension: api::PaymentAnalyticsDimension, column: &str| {
+        if req.group_by.contains(&dimension) {
+            column.to_string()
+        } else {
+            "CAST(NULL AS Nullable(String))".to_string()
+        }
+    };
+    let in_filter = |column: &str, values: Vec<String>| {
+        if values.is_empty() {
+            String::new()
+        } else {
+            let values = values
+                .iter()
+                .map(|value| format!("'{value}'"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" AND {column} IN ({values})")
+        }
+    };
+
+    let filters = &req.filters;
+    let filters = [
+        in_filter(
+            "attempts.connector",
+            filters.connector.iter().map(ToString::to_string).collect(),
+        ),
+        in_filter(
+            "attempts.payment_method",
+            filters
+                .payment_method
+                .iter()
+                .map(|payment_method| {
+                    storage_enums::PaymentMethod::foreign_from(*payment_method).to_string()
+                })
+                .collect(),
+        ),
+        in_filter(
+            "attempts.currency",
+            filters
+                .currency
+                .iter()
+                .map(|currency| storage_enums::Currency::foreign_from(*currency).to_string())
+                .collect(),
+        ),
+        in_filter(
+            "attempts.status",
+            filters
+                .status
+                .iter()
+                .map(|status| storage_enums::AttemptStatus::foreign_from(*status).to_string())
+                .collect(),
+        ),
+    ]
+    .concat();
+
+    let success = storage::quote_statuses(&storage::SUCCESS_ATTEMPT_STATUSES);
+    let completed = storage::quote_statuses(&storage::COMPLETED_ATTEMPT_STATUSES);
+    format!(
+        "SELECT {time_bucket} AS time_bucket, \
+            {} AS connector, {} AS payment_method, {} AS currency, {} AS status, \
+            count() AS payment_count, \
+            countIf(attempts.status IN ({success})) AS success_count, \
+            sumIf(attempts.amount, attempts.status IN ({success})) AS total_amount, \
+            if(countIf(attempts.status IN ({completed})) = 0, NULL, \
+                avgIf(dateDiff('millisecond', attempts.created_at, attempts.modified_at), \
+                    attempts.status IN ({completed}))) AS avg_latency_ms \
+        FROM {database}.payment_attempts AS attempts FINAL \
+        WHERE attempts.merchant_id = {{merchant_id:String}} \
+            AND attempts.created_at >= fromUnixTimestamp64Milli({{start_time:Int64}}) \
+            AND attempts.created_at < fromUnixTimestamp64Milli({{end_time:Int64}}){filters} \
+        GROUP BY time_bucket, connector, payment_method, currency, status \
+        ORDER BY time_bucket, connector, payment_method, currency, status \
+        FORMAT JSON",
+        dimension(
+            api::PaymentAnalyticsDimension::Connector,
+            "attempts.connector"
+        ),
+        dimension(
+            api::PaymentAnalyticsDimension::PaymentMethod,
+            "attempts.payment_method"
+        ),
+        dimension(
+            api::PaymentAnalyticsDimension::Currency,
+            "attempts.currency"
+        ),
+        dimension(api::PaymentAnalyticsDimension::Status, "attempts.status"),
+    )
+}
+
+#[cfg(feature = "olap")]
+fn get_unix_timestamp_millis(date_time: time::PrimitiveDateTime) -> String {
+    (date_time.assume_utc().unix_timestamp_nanos() / 1_000_000).to_string()
+}
+
+#[cfg(feature = "olap")]
+async fn query_clickhouse(
+    state: &AppState,
+    merchant_id: &str,
+    req: &api::PaymentAnalyticsRequest,
+    end_time: time::PrimitiveDateTime,
+) -> RouterResult<Vec<PaymentAnalyticsRow>> {
+    let settings = &state.conf.clickhouse;
+    let url = url::Url::parse_with_params(
+        &settings.base_url,
+        &[
+            ("param_merchant_id", merchant_id.to_string()),
+            (
+                "param_start_time",
+                get_unix_timestamp_millis(req.time_range.start_time),
+            ),
+            ("param_end_time", get_unix_timestamp_millis(end_time)),
+            ("date_time_output_format", "iso".to_string()),
+            ("output_format_json_quote_64bit_integers", "0".to_string()),
+        ],
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid ClickHouse base URL")?;
+
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url.as_str())
+        .header(headers::CONTENT_TYPE, "text/plain")
+        .body(Some(build_clickhouse_query(&settings.database, req)));
+    if let Some(auth_header) = get_auth_header(settings) {
+        request = request.header(headers::AUTHORIZATION, &auth_header);
+    }
+
+    let response = services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call ClickHouse")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "ClickHouse responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    let query_response: ClickhouseQueryResponse = response
+        .response
+        .parse_struct("ClickhouseQueryResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the query response of ClickHouse")?;
+
+    Ok(query_response.data)
+}
+
+/// Records the payment attempt in ClickHouse in the background, when enabled. Failures are only
+/// logged, the attempt is recorded again the next time the payment is processed.
+pub fn record_payment_attempt(state: &AppState, payment_attempt: &storage::PaymentAttempt) {
+    if !state.conf.clickhouse.enabled {
+        return;
+    }
+
+    let row = ClickhousePaymentAttempt {
+        attempt_id: payment_attempt.attempt_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        status: payment_attempt.status,
+        amount: payment_attempt.amount,
+        currency: payment_attempt.currency,
+        connector: payment_attempt.connector.clone(),
+        payment_method: payment_attempt.payment_method,
+        created_at: payment_attempt.created_at,
+        modified_at: payment_attempt.modified_at,
+    };
+
+    let state = state.clone();
+    let insert_row = async move {
+        if let Err(error) = insert_payment_attempt(&state, row).await {
+            logger::error!(?error, "Failed to record the payment attempt in ClickHouse");
+        }
+    };
+
+    match actix::Arbiter::try_current() {
+        Some(arbiter) => {
+            arbiter.spawn(insert_row);
+        }
+        None => {
+            tokio::spawn(insert_row);
+        }
+    }
+}
+
+async fn insert_payment_attempt(
+    state: &AppState,
+    row: ClickhousePaymentAttempt,
+) -> RouterResult<()> {
+    let settings = &state.conf.clickhouse;
+    let query = format!(
+        "INSERT INTO {}.payment_attempts FORMAT JSONEachRow",
+        settings.database
+    );
+    let url = url::Url::parse_with_params(
+        &settings.base_url,
+        &[
+            ("query", query.as_str()),
+            ("date_time_input_format", "best_effort"),
+        ],
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid ClickHouse base URL")?;
+    let request_body = utils::Encode::<ClickhousePaymentAttempt>::encode_to_string_of_json(&row)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payment attempt row")?;
+
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url.as_str())
+        .header(headers::CONTENT_TYPE, "application/json")
+        .body(Some(request_body));
+    if let Some(auth_header) = get_auth_header(settings) {
+        request = request.header(headers::AUTHORIZATION, &auth_header);
+    }
+
+    services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call ClickHouse")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "ClickHouse responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    use super::*;
+
+    fn analytics_request(
+        granularity: Option<api::AnalyticsGranularity>,
+        days: i64,
+    ) -> (api::PaymentAnalyticsRequest, time::PrimitiveDateTime) {
+        let end_time = common_utils::date_time::now();
+        let req = api::PaymentAnalyticsRequest {
+            time_range: api::AnalyticsTimeRange {
+                start_time: end_time - time::Duration::days(days),
+                end_time: Some(end_time),
+            },
+            granularity,
+            group_by: vec![api::PaymentAnalyticsDimension::Connector],
+            filters: api::PaymentAnalyticsFilters::default(),
+        };
+        (req, end_time)
+    }
+
+    #[test]
+    fn test_analytics_time_range() {
+        let (req, end_time) = analytics_request(Some(api::AnalyticsGranularity::Day), 30);
+        assert!(validate_analytics_request(&req, end_time).is_ok());
+
+        let (req, end_time) = analytics_request(Some(api::AnalyticsGranularity::Hour), 60);
+        assert!(validate_analytics_request(&req, end_time).is_err());
+
+        let (req, end_time) = analytics_request(None, 0);
+        assert!(validate_analytics_request(&req, end_time).is_err());
+    }
+
+    #[test]
+    fn test_success_rate() {
+        assert_eq!(get_success_rate(0, 0), 0.0);
+        assert_eq!(get_success_rate(3, 4), 75.0);
+    }
+
+    #[test]
+    fn test_clickhouse_query_filters() {
+        let (mut req, _) = analytics_request(None, 1);
+        req.filters.currency = vec![api_models::enums::Currency::USD];
+        let query = build_clickhouse_query("hyperswitch", &req);
+
+        assert!(query.contains("attempts.connector AS connector"));
+        assert!(query.contains("CAST(NULL AS Nullable(String)) AS currency"));
+        assert!(query.contains(" AND attempts.currency IN ('USD')"));
+        assert!(query.contains("FROM hyperswitch.payment_attempts AS attempts FINAL"));
+    }
+}
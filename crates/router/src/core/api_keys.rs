@@ -1,11 +1,12 @@
 use common_utils::{date_time, errors::CustomResult, fp_utils};
 use error_stack::{report, IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
     db::StorageInterface,
     services::ApplicationResponse,
     types::{api, storage, transformers::ForeignInto},
@@ -17,6 +18,10 @@ use crate::{
 pub struct PlaintextApiKey(Secret<String>);
 pub struct HashedApiKey(String);
 
+/// The time an API key was last used is updated at most once in this interval, so that
+/// authenticating a request does not always need a write to the database.
+const LAST_USED_UPDATE_INTERVAL_IN_SECS: i64 = 60;
+
 impl PlaintextApiKey {
     const HASH_KEY_LEN: usize = 32;
 
@@ -93,25 +98,56 @@ impl PlaintextApiKey {
     }
 }
 
+impl From<&str> for PlaintextApiKey {
+    fn from(api_key: &str) -> Self {
+        Self(api_key.to_owned().into())
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn create_api_key(
     store: &dyn StorageInterface,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    let (api_key, plaintext_api_key) = generate_api_key(
+        store,
+        merchant_id,
+        api_key.name,
+        api_key.description,
+        api_key.expiration.into(),
+        api_key.role.foreign_into(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        (api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+/// Generates a new API key for the merchant, of which only the hash is stored.
+pub(crate) async fn generate_api_key(
+    store: &dyn StorageInterface,
+    merchant_id: String,
+    name: String,
+    description: Option<String>,
+    expires_at: Option<PrimitiveDateTime>,
+    role: storage::enums::RoleName,
+) -> RouterResult<(storage::ApiKey, PlaintextApiKey)> {
     let hash_key = PlaintextApiKey::new_hash_key();
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
         key_id: PlaintextApiKey::new_key_id(),
         merchant_id,
-        name: api_key.name,
-        description: api_key.description,
+        name,
+        description,
         hash_key: Secret::from(hex::encode(hash_key)),
         hashed_api_key: plaintext_api_key.keyed_hash(&hash_key).into(),
         prefix: plaintext_api_key.prefix(),
         created_at: date_time::now(),
-        expires_at: api_key.expiration.into(),
+        expires_at,
         last_used: None,
+        role,
     };
 
     let api_key = store
@@ -120,9 +156,81 @@ pub async fn create_api_key(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
 
-    Ok(ApplicationResponse::Json(
-        (api_key, plaintext_api_key).foreign_into(),
-    ))
+    Ok((api_key, plaintext_api_key))
+}
+
+/// Finds the stored API key matching the API key provided in a request.
+///
+/// API keys are issued as the key ID followed by the plaintext key, separated by a hyphen. Keys
+/// that are not in this format are not looked up, as they may be the plaintext API keys stored
+/// with merchant accounts created before API keys were hashed. Expired keys are rejected, and the
+/// time the key was last used is recorded.
+#[instrument(skip_all)]
+pub async fn find_api_key_by_plaintext(
+    store: &dyn StorageInterface,
+    api_key: &str,
+) -> RouterResult<Option<storage::ApiKey>> {
+    let (key_id, plaintext_api_key) = match api_key.split_once('-') {
+        Some((key_id, plaintext_api_key)) => (key_id, PlaintextApiKey::from(plaintext_api_key)),
+        None => return Ok(None),
+    };
+
+    let api_key = match store
+        .find_api_key_optional(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+    {
+        Some(api_key) => api_key,
+        None => return Ok(None),
+    };
+
+    let hash_key = get_hash_key(&api_key)?;
+    let hashed_api_key = HashedApiKey(api_key.hashed_api_key.get_hash().to_owned());
+    plaintext_api_key
+        .verify_hash(&hash_key, &hashed_api_key)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+    let now = date_time::now();
+    fp_utils::when(is_expired(api_key.expires_at, now), || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable_lazy(|| format!("API key {key_id} has expired"))
+    })?;
+
+    if should_update_last_used(api_key.last_used, now) {
+        let last_used_update = storage::ApiKeyUpdate::LastUsedUpdate { last_used: now };
+        match store
+            .update_api_key(api_key.key_id.clone(), last_used_update)
+            .await
+        {
+            Ok(api_key) => return Ok(Some(api_key)),
+            // Failing to record the usage of the key must not fail the request
+            Err(error) => logger::error!(?error, "Failed to update last used time of API key"),
+        }
+    }
+
+    Ok(Some(api_key))
+}
+
+fn get_hash_key(api_key: &storage::ApiKey) -> RouterResult<[u8; PlaintextApiKey::HASH_KEY_LEN]> {
+    let hash_key = hex::decode(api_key.hash_key.peek())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode API key hash key from hexadecimal string")?;
+
+    <[u8; PlaintextApiKey::HASH_KEY_LEN]>::try_from(hash_key)
+        .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("API key hash key has an invalid length")
+}
+
+fn is_expired(expires_at: Option<PrimitiveDateTime>, now: PrimitiveDateTime) -> bool {
+    expires_at.map_or(false, |expires_at| now >= expires_at)
+}
+
+fn should_update_last_used(last_used: Option<PrimitiveDateTime>, now: PrimitiveDateTime) -> bool {
+    last_used.map_or(true, |last_used| {
+        now - last_used >= time::Duration::seconds(LAST_USED_UPDATE_INTERVAL_IN_SECS)
+    })
 }
 
 #[instrument(skip_all)]
@@ -216,4 +324,19 @@ mod tests {
             .verify_hash(&hash_key, &hashed_api_key)
             .unwrap();
     }
+
+    #[test]
+    fn test_api_key_expiry_and_last_used_update() {
+        let now = date_time::now();
+        let a_minute_ago = now - time::Duration::minutes(1);
+        let a_second_ago = now - time::Duration::seconds(1);
+
+        assert!(!is_expired(None, now));
+        assert!(is_expired(Some(a_minute_ago), now));
+        assert!(!is_expired(Some(now + time::Duration::minutes(1)), now));
+
+        assert!(should_update_last_used(None, now));
+        assert!(should_update_last_used(Some(a_minute_ago), now));
+        assert!(!should_update_last_used(Some(a_second_ago), now));
+    }
 }
@@ -0,0 +1,121 @@
+use std::{cell::RefCell, future::Future};
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::generate_id,
+};
+
+tokio::task_local! {
+    static AUDIT_CONTEXT: RefCell<AuditContext>;
+}
+
+/// The API call being served, which the changes recorded in the audit log are attributed to.
+#[derive(Clone, Debug)]
+pub struct AuditContext {
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub http_method: String,
+    pub route: String,
+}
+
+impl AuditContext {
+    /// Calls are attributed to the system until the caller is authenticated, as some routes, such
+    /// as those receiving webhooks from connectors, are not called on behalf of anyone.
+    pub fn new(http_method: &str, route: &str) -> Self {
+        Self {
+            actor_type: storage_enums::AuditActorType::System,
+            actor_id: None,
+            http_method: http_method.to_owned(),
+            route: route.to_owned(),
+        }
+    }
+}
+
+pub async fn with_audit_context<F: Future>(context: AuditContext, future: F) -> F::Output {
+    AUDIT_CONTEXT.scope(RefCell::new(context), future).await
+}
+
+/// Sets the caller the API call being served was authenticated as. Has no effect outside of an
+/// API call.
+pub fn set_actor(actor_type: storage_enums::AuditActorType, actor_id: Option<String>) {
+    AUDIT_CONTEXT
+        .try_with(|context| {
+            let mut context = context.borrow_mut();
+            context.actor_type = actor_type;
+            context.actor_id = actor_id;
+        })
+        .ok();
+}
+
+/// Records a change made to a resource of the merchant by the API call being served. Changes made
+/// outside of API calls, such as by the scheduler, are not recorded. Failing to record the change
+/// is logged, and does not fail the API call.
+#[instrument(skip(db))]
+pub async fn record_resource_change(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    resource_type: storage_enums::AuditResourceType,
+    resource_id: &str,
+    previous_status: Option<String>,
+    new_status: Option<String>,
+) {
+    let context = match AUDIT_CONTEXT.try_with(|context| context.borrow().clone()) {
+        Ok(context) => context,
+        Err(_) => return,
+    };
+
+    // Retrieving a resource may update it, such as when a payment is synced with the connector,
+    // in which case only the retrievals that changed the status are recorded
+    if context.http_method == "GET" && previous_status == new_status {
+        return;
+    }
+
+    let audit_log = storage::AuditLogNew {
+        audit_log_id: generate_id(consts::ID_LENGTH, "audit"),
+        merchant_id: merchant_id.to_owned(),
+        actor_type: context.actor_type,
+        actor_id: context.actor_id,
+        http_method: Some(context.http_method),
+        route: Some(context.route),
+        resource_type,
+        resource_id: resource_id.to_owned(),
+        previous_status,
+        new_status,
+    };
+
+    if let Err(error) = db.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to record the change in the audit log");
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn list_audit_logs(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::AuditLogListConstraints,
+) -> RouterResponse<Vec<api::AuditLogResponse>> {
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing audit logs")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(audit_logs))
+}
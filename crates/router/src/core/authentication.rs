@@ -0,0 +1,432 @@
+//! 3DS authentication of card payments through the external 3DS server, decoupled from the
+//! connectors. The payment reaches the connector only once the cardholder is authenticated, with
+//! the proof of the authentication passed along for the connector to authorize it as such.
+
+pub mod three_ds_server;
+
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use self::three_ds_server::TransStatus;
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payments::{self, helpers, PaymentData},
+};
+use crate::{
+    configs::settings::ThreeDsServerSettings,
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalAuthenticationStatus {
+    ChallengePending,
+    Succeeded,
+    Failed,
+}
+
+/// The external authentication of a payment, stored on its attempt. The authentication value is
+/// not stored, it is fetched from the 3DS server when the payment is authorized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalThreeDsAuthentication {
+    pub three_ds_server_trans_id: String,
+    pub message_version: String,
+    pub status: ExternalAuthenticationStatus,
+    pub trans_status_reason: Option<String>,
+}
+
+enum AuthenticationOutcome {
+    Authenticated(types::ExternalAuthenticationData),
+    Challenge(services::RedirectForm),
+    /// The cardholder is yet to complete the challenge
+    ChallengePending,
+    Failed {
+        trans_status: TransStatus,
+        trans_status_reason: Option<String>,
+    },
+}
+
+/// Rejects the payments asking for external authentication when the 3DS server is not set up.
+pub fn validate_external_authentication<F: Clone>(
+    settings: &ThreeDsServerSettings,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    if payment_data.request_external_three_ds_authentication && !settings.enabled {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "External 3DS authentication is not enabled".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn should_authenticate_externally<F: Clone>(payment_data: &PaymentData<F>) -> bool {
+    payment_data.request_external_three_ds_authentication
+        && payment_data.payment_attempt.authentication_type
+            == Some(storage_enums::AuthenticationType::ThreeDs)
+        && payment_data.amount != api::Amount::Zero
+        && matches!(
+            payment_data.payment_method_data,
+            Some(api::PaymentMethodData::Card(_))
+        )
+}
+
+pub fn get_external_authentication(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<ExternalThreeDsAuthentication>> {
+    payment_attempt
+        .external_three_ds_authentication
+        .clone()
+        .map(|authentication| authentication.parse_value("ExternalThreeDsAuthentication"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the external 3DS authentication of the payment")
+}
+
+/// Authenticates the cardholder through the external 3DS server when the payment asks for it, or
+/// completes the challenge of an authentication already started. Returns whether the payment can
+/// go on to be authorized at the connector.
+pub async fn authenticate_externally<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let (authentication, outcome) =
+        match get_external_authentication(&payment_data.payment_attempt)? {
+            Some(authentication)
+                if authentication.status == ExternalAuthenticationStatus::ChallengePending =>
+            {
+                let response =
+                    three_ds_server::get_results(state, &authentication.three_ds_server_trans_id)
+                        .await?;
+                let outcome = get_outcome(&authentication.message_version, response)?;
+                (authentication, outcome)
+            }
+            _ if should_authenticate_externally(payment_data) => {
+                match start_authentication(state, merchant_account, payment_data).await? {
+                    Some(started) => started,
+                    None => return Ok(true),
+                }
+            }
+            _ => return Ok(true),
+        };
+
+    let storage_scheme = merchant_account.storage_scheme;
+    let (status, trans_status_reason, should_authorize) = match outcome {
+        AuthenticationOutcome::Authenticated(authentication_data) => {
+            payment_data.external_authentication_data = Some(authentication_data);
+            (ExternalAuthenticationStatus::Succeeded, None, true)
+        }
+        AuthenticationOutcome::Challenge(redirect_form) => {
+            let authentication_data =
+                Encode::<services::RedirectForm>::encode_to_value(&redirect_form)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed to encode the challenge of the external authentication",
+                    )?;
+            let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
+                connector_transaction_id: None,
+                authentication_data: Some(authentication_data),
+                encoded_data: None,
+                connector_name: payment_data.payment_attempt.connector.clone(),
+            };
+            payment_data.connector_response = state
+                .store
+                .update_connector_response(
+                    payment_data.connector_response.clone(),
+                    connector_response_update,
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+            (ExternalAuthenticationStatus::ChallengePending, None, false)
+        }
+        AuthenticationOutcome::ChallengePending => return Ok(false),
+        AuthenticationOutcome::Failed {
+            trans_status,
+            trans_status_reason,
+        } => {
+            helpers::fail_payment_before_connector(
+                state,
+                storage_scheme,
+                payment_data,
+                storage_enums::AttemptStatus::AuthenticationFailed,
+                Some(trans_status.to_string()),
+                "Cardholder could not be authenticated".to_string(),
+                storage_enums::UnifiedCode::AuthenticationFailed,
+            )
+            .await?;
+            (
+                ExternalAuthenticationStatus::Failed,
+                trans_status_reason,
+                false,
+            )
+        }
+    };
+
+    let external_three_ds_authentication =
+        Encode::<ExternalThreeDsAuthentication>::encode_to_value(&ExternalThreeDsAuthentication {
+            status,
+            trans_status_reason,
+            ..authentication
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the external 3DS authentication of the payment")?;
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ExternalAuthenticationUpdate {
+                external_three_ds_authentication,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(should_authorize)
+}
+
+/// Authorizes the payment once the cardholder completes the challenge at the ACS, which posts the
+/// challenge response to the notification URL of the authentication. The result of the challenge
+/// is fetched from the 3DS server rather than taken from the post.
+pub async fn complete_external_authentication(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::RedirectionResponse> {
+    let request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        confirm: Some(true),
+        ..Default::default()
+    };
+    let response = payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account.clone(),
+        payments::operations::PaymentConfirm,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await?;
+
+    let payments_response = match response {
+        services::ApplicationResponse::Json(payments_response) => payments_response,
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get the response in json"))
+        }
+    };
+    let connector = payments_response.connector.clone().unwrap_or_default();
+    let redirection_response = helpers::get_handle_response_url(
+        payment_id,
+        &merchant_account,
+        payments_response,
+        connector,
+    )
+    .attach_printable("No redirection response")?;
+
+    Ok(services::ApplicationResponse::JsonForRedirection(
+        redirection_response,
+    ))
+}
+
+/// Starts the authentication of the payment at the 3DS server. Returns None when the card is not
+/// enrolled in 3DS2, leaving the authentication to the connector.
+async fn start_authentication<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<(ExternalThreeDsAuthentication, AuthenticationOutcome)>> {
+    let card = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => card,
+        _ => return Ok(None),
+    };
+    let browser_info: types::BrowserInformation = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "browser_info",
+        })?
+        .parse_value("BrowserInformation")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+
+    let versioning = three_ds_server::get_version(state, &card.card_number).await?;
+    let message_version = match versioning.message_version {
+        Some(message_version) => message_version,
+        None => {
+            logger::info!(
+                "Card is not enrolled in 3DS2, leaving the authentication to the connector"
+            );
+            return Ok(None);
+        }
+    };
+
+    let payment_attempt = &payment_data.payment_attempt;
+    let details = three_ds_server::AuthenticationDetails {
+        three_ds_server_trans_id: versioning.three_ds_server_trans_id.clone(),
+        message_version: message_version.clone(),
+        card,
+        amount: payment_data.amount.into(),
+        currency: payment_data.currency.foreign_into(),
+        browser_info,
+        notification_url: format!(
+            "{}/payments/{}/{}/authentication/complete",
+            state.conf.server.base_url, payment_attempt.payment_id, merchant_account.merchant_id
+        ),
+    };
+    let response = three_ds_server::authenticate(state, details).await?;
+    let outcome = get_outcome(&message_version, response)?;
+
+    Ok(Some((
+        ExternalThreeDsAuthentication {
+            three_ds_server_trans_id: versioning.three_ds_server_trans_id,
+            message_version,
+            status: ExternalAuthenticationStatus::ChallengePending,
+            trans_status_reason: None,
+        },
+        outcome,
+    )))
+}
+
+fn get_outcome(
+    message_version: &str,
+    response: three_ds_server::AuthenticationResponse,
+) -> RouterResult<AuthenticationOutcome> {
+    match response.trans_status {
+        TransStatus::Y | TransStatus::A => {
+            let cavv = response.authentication_value.ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("3DS server did not give the authentication value")
+            })?;
+            Ok(AuthenticationOutcome::Authenticated(
+                types::ExternalAuthenticationData {
+                    cavv,
+                    eci: response.eci,
+                    ds_trans_id: response.ds_trans_id,
+                    message_version: message_version.to_string(),
+                    trans_status: response.trans_status.to_string(),
+                },
+            ))
+        }
+        TransStatus::C => match (response.acs_url, response.challenge_request) {
+            (Some(acs_url), Some(challenge_request)) => {
+                Ok(AuthenticationOutcome::Challenge(services::RedirectForm {
+                    endpoint: acs_url,
+                    method: services::Method::Post,
+                    form_fields: std::collections::HashMap::from([(
+                        "creq".to_string(),
+                        challenge_request,
+                    )]),
+                }))
+            }
+            // Results of challenges are not final until the cardholder completes them
+            _ => Ok(AuthenticationOutcome::ChallengePending),
+        },
+        trans_status @ (TransStatus::N
+        | TransStatus::U
+        | TransStatus::R
+        | TransStatus::D
+        | TransStatus::I) => Ok(AuthenticationOutcome::Failed {
+            trans_status,
+            trans_status_reason: response.trans_status_reason,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use masking::PeekInterface;
+
+    use super::*;
+
+    fn response(trans_status: TransStatus) -> three_ds_server::AuthenticationResponse {
+        three_ds_server::AuthenticationResponse {
+            trans_status,
+            trans_status_reason: None,
+            authentication_value: None,
+            eci: None,
+            ds_trans_id: None,
+            acs_url: None,
+            challenge_request: None,
+        }
+    }
+
+    #[test]
+    fn test_frictionless_outcome() {
+        let outcome = get_outcome(
+            "2.2.0",
+            three_ds_server::AuthenticationResponse {
+                authentication_value: Some(masking::Secret::new(
+                    "AJkBBkhgQQAAAE4gSEJydQAAAAA=".to_string(),
+                )),
+                eci: Some("05".to_string()),
+                ..response(TransStatus::Y)
+            },
+        )
+        .unwrap();
+
+        let data = match outcome {
+            AuthenticationOutcome::Authenticated(data) => Some(data),
+            _ => None,
+        }
+        .unwrap();
+        assert_eq!(data.cavv.peek(), "AJkBBkhgQQAAAE4gSEJydQAAAAA=");
+        assert_eq!(data.eci.as_deref(), Some("05"));
+        assert_eq!(data.message_version, "2.2.0");
+        assert!(get_outcome("2.2.0", response(TransStatus::A)).is_err());
+    }
+
+    #[test]
+    fn test_challenge_outcome() {
+        let outcome = get_outcome(
+            "2.2.0",
+            three_ds_server::AuthenticationResponse {
+                acs_url: Some("https://acs.example.com/challenge".to_string()),
+                challenge_request: Some("eyJtZXNzYWdlVHlwZSI6IkNSZXEifQ".to_string()),
+                ..response(TransStatus::C)
+            },
+        )
+        .unwrap();
+
+        let redirect_form = match outcome {
+            AuthenticationOutcome::Challenge(redirect_form) => Some(redirect_form),
+            _ => None,
+        }
+        .unwrap();
+        assert_eq!(redirect_form.endpoint, "https://acs.example.com/challenge");
+        assert_eq!(
+            redirect_form.form_fields.get("creq").map(String::as_str),
+            Some("eyJtZXNzYWdlVHlwZSI6IkNSZXEifQ")
+        );
+        assert!(matches!(
+            get_outcome("2.2.0", response(TransStatus::C)).unwrap(),
+            AuthenticationOutcome::ChallengePending
+        ));
+    }
+
+    #[test]
+    fn test_failed_outcome() {
+        for trans_status in [TransStatus::N, TransStatus::R, TransStatus::U] {
+            assert!(matches!(
+                get_outcome("2.2.0", response(trans_status)).unwrap(),
+                AuthenticationOutcome::Failed { .. }
+            ));
+        }
+    }
+}
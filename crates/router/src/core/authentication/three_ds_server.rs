@@ -0,0 +1,331 @@
+//! Client of the external 3DS server, which runs EMV 3DS authentications with the directory
+//! servers of the card networks on behalf of the 3DS requestor.
+
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::{format_description, OffsetDateTime};
+
+use crate::{
+    configs::settings::ThreeDsServerSettings,
+    core::{
+        errors::{self, RouterResult},
+        forex,
+    },
+    headers, pii,
+    routes::AppState,
+    services, types,
+    types::api::{self, enums as api_enums},
+    utils::{self, BytesExt},
+};
+
+/// Browser based authentications
+const DEVICE_CHANNEL_BROWSER: &str = "02";
+/// Authentications of payments, as opposed to the verification of cards
+const MESSAGE_CATEGORY_PAYMENT: &str = "01";
+/// The 3DS method of the ACS is not run before the authentication
+const THREE_DS_METHOD_NOT_COMPLETED: &str = "U";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VersioningRequest {
+    acct_number: Secret<String, pii::CardNumber>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VersioningResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    /// Highest protocol version supported by both the 3DS server and the ACS of the issuer, absent
+    /// when the card is not enrolled in 3DS2
+    #[serde(rename = "messageVersion")]
+    pub message_version: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthenticationRequest {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    message_version: String,
+    message_category: &'static str,
+    device_channel: &'static str,
+    #[serde(rename = "threeDSCompInd")]
+    three_ds_comp_ind: &'static str,
+    #[serde(rename = "threeDSRequestorID")]
+    three_ds_requestor_id: String,
+    #[serde(rename = "threeDSRequestorName")]
+    three_ds_requestor_name: String,
+    #[serde(rename = "notificationURL")]
+    notification_url: String,
+    acct_number: Secret<String, pii::CardNumber>,
+    card_expiry_date: Secret<String>,
+    cardholder_name: Secret<String>,
+    purchase_amount: String,
+    purchase_currency: &'static str,
+    purchase_exponent: String,
+    purchase_date: String,
+    browser_accept_header: String,
+    #[serde(rename = "browserIP")]
+    browser_ip: Option<String>,
+    browser_java_enabled: bool,
+    browser_javascript_enabled: bool,
+    browser_language: String,
+    browser_color_depth: String,
+    browser_screen_height: String,
+    browser_screen_width: String,
+    #[serde(rename = "browserTZ")]
+    browser_tz: String,
+    browser_user_agent: String,
+}
+
+/// Status of the authentication, as given by the ACS of the issuer
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, strum::Display)]
+pub enum TransStatus {
+    /// Authenticated
+    Y,
+    /// Not authenticated
+    N,
+    /// Authentication could not be performed
+    U,
+    /// Authentication attempted, with the proof of it given by the issuer
+    A,
+    /// The cardholder has to be challenged
+    C,
+    /// Rejected by the issuer
+    R,
+    /// The cardholder has to be challenged outside of the browser
+    D,
+    /// Informational only
+    I,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthenticationResponse {
+    #[serde(rename = "transStatus")]
+    pub trans_status: TransStatus,
+    #[serde(rename = "transStatusReason")]
+    pub trans_status_reason: Option<String>,
+    #[serde(rename = "authenticationValue")]
+    pub authentication_value: Option<Secret<String>>,
+    pub eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
+    /// URL of the ACS the cardholder is sent to when challenged
+    #[serde(rename = "acsURL")]
+    pub acs_url: Option<String>,
+    /// Challenge request to post to the ACS, encoded in base64url
+    #[serde(rename = "base64EncodedChallengeRequest")]
+    pub challenge_request: Option<String>,
+}
+
+/// Looks up the 3DS2 protocol version supported by the issuer of the card, which also starts the
+/// transaction at the 3DS server.
+pub async fn get_version(
+    state: &AppState,
+    card_number: &Secret<String, pii::CardNumber>,
+) -> RouterResult<VersioningResponse> {
+    let request_body = VersioningRequest {
+        acct_number: card_number.clone(),
+    };
+    let response = send_request(
+        state,
+        services::Method::Post,
+        "3ds/versioning",
+        Some(encode_request_body(&request_body)?),
+    )
+    .await?;
+
+    response
+        .parse_struct("VersioningResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the versioning response of the 3DS server")
+}
+
+pub struct AuthenticationDetails<'a> {
+    pub three_ds_server_trans_id: String,
+    pub message_version: String,
+    pub card: &'a api::Card,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub browser_info: types::BrowserInformation,
+    pub notification_url: String,
+}
+
+/// Sends the authentication request of the payment to the ACS of the issuer, through the
+/// directory server of the card network.
+pub async fn authenticate(
+    state: &AppState,
+    details: AuthenticationDetails<'_>,
+) -> RouterResult<AuthenticationResponse> {
+    let settings = &state.conf.three_ds_server;
+    let request_body = build_authentication_request(settings, details)?;
+    let response = send_request(
+        state,
+        services::Method::Post,
+        "3ds/authentication",
+        Some(encode_request_body(&request_body)?),
+    )
+    .await?;
+
+    response
+        .parse_struct("AuthenticationResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the authentication response of the 3DS server")
+}
+
+/// Fetches the final result of a challenged authentication, which the ACS reports to the 3DS
+/// server once the cardholder completes the challenge.
+pub async fn get_results(
+    state: &AppState,
+    three_ds_server_trans_id: &str,
+) -> RouterResult<AuthenticationResponse> {
+    let response = send_request(
+        state,
+        services::Method::Get,
+        &format!("3ds/results/{three_ds_server_trans_id}"),
+        None,
+    )
+    .await?;
+
+    response
+        .parse_struct("AuthenticationResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the authentication results of the 3DS server")
+}
+
+fn build_authentication_request(
+    settings: &ThreeDsServerSettings,
+    details: AuthenticationDetails<'_>,
+) -> RouterResult<AuthenticationRequest> {
+    let browser_info = details.browser_info;
+    let purchase_date_format =
+        format_description::parse("[year][month][day][hour][minute][second]")
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let purchase_date = OffsetDateTime::now_utc()
+        .format(&purchase_date_format)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format the purchase date")?;
+
+    Ok(AuthenticationRequest {
+        three_ds_server_trans_id: details.three_ds_server_trans_id,
+        message_version: details.message_version,
+        message_category: MESSAGE_CATEGORY_PAYMENT,
+        device_channel: DEVICE_CHANNEL_BROWSER,
+        three_ds_comp_ind: THREE_DS_METHOD_NOT_COMPLETED,
+        three_ds_requestor_id: settings.requestor_id.clone(),
+        three_ds_requestor_name: settings.requestor_name.clone(),
+        notification_url: details.notification_url,
+        acct_number: details.card.card_number.clone(),
+        card_expiry_date: get_card_expiry_date(details.card),
+        cardholder_name: details.card.card_holder_name.clone(),
+        purchase_amount: details.amount.to_string(),
+        purchase_currency: details.currency.iso_4217_numeric_code(),
+        purchase_exponent: forex::currency_exponent(details.currency).to_string(),
+        purchase_date,
+        browser_accept_header: browser_info.accept_header,
+        browser_ip: browser_info
+            .ip_address
+            .map(|ip_address| ip_address.to_string()),
+        browser_java_enabled: browser_info.java_enabled,
+        browser_javascript_enabled: browser_info.java_script_enabled,
+        browser_language: browser_info.language,
+        browser_color_depth: browser_info.color_depth.to_string(),
+        browser_screen_height: browser_info.screen_height.to_string(),
+        browser_screen_width: browser_info.screen_width.to_string(),
+        browser_tz: browser_info.time_zone.to_string(),
+        browser_user_agent: browser_info.user_agent,
+    })
+}
+
+/// Expiry date of the card in the YYMM format
+fn get_card_expiry_date(card: &api::Card) -> Secret<String> {
+    let year = card.card_exp_year.peek();
+    let year = year.get(year.len().saturating_sub(2)..).unwrap_or(year);
+    Secret::new(format!("{year}{:0>2}", card.card_exp_month.peek()))
+}
+
+fn encode_request_body<T: Serialize>(request_body: &T) -> RouterResult<String> {
+    utils::Encode::<T>::encode_to_string_of_json(request_body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the request to the 3DS server")
+}
+
+async fn send_request(
+    state: &AppState,
+    method: services::Method,
+    path: &str,
+    body: Option<String>,
+) -> RouterResult<bytes::Bytes> {
+    let settings = &state.conf.three_ds_server;
+    let url = format!("{}{path}", settings.base_url);
+    let request = services::RequestBuilder::new()
+        .method(method)
+        .url(&url)
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(headers::X_API_KEY, &settings.api_key)
+        .body(body)
+        .build();
+
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the 3DS server")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "3DS server responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    Ok(response.response)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn card(card_exp_month: &str, card_exp_year: &str) -> api::Card {
+        api::Card {
+            card_number: Secret::new("4000000000001091".to_string()),
+            card_exp_month: Secret::new(card_exp_month.to_string()),
+            card_exp_year: Secret::new(card_exp_year.to_string()),
+            card_holder_name: Secret::new("John Doe".to_string()),
+            card_cvc: Secret::new("123".to_string()),
+            card_issuer: None,
+            card_network: None,
+            card_issuing_country: None,
+            card_type: None,
+        }
+    }
+
+    #[test]
+    fn test_card_expiry_date() {
+        assert_eq!(get_card_expiry_date(&card("03", "2030")).peek(), "3003");
+        assert_eq!(get_card_expiry_date(&card("3", "30")).peek(), "3003");
+        assert_eq!(get_card_expiry_date(&card("12", "2031")).peek(), "3112");
+    }
+
+    #[test]
+    fn test_authentication_response_deserialization() {
+        let response: AuthenticationResponse = serde_json::from_str(
+            r#"{
+                "transStatus": "C",
+                "acsURL": "https://acs.example.com/challenge",
+                "base64EncodedChallengeRequest": "eyJtZXNzYWdlVHlwZSI6IkNSZXEifQ"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.trans_status, TransStatus::C);
+        assert_eq!(
+            response.acs_url.as_deref(),
+            Some("https://acs.example.com/challenge")
+        );
+        assert!(response.authentication_value.is_none());
+    }
+}
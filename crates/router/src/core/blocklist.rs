@@ -0,0 +1,392 @@
+//! Blocklists and velocity rules of merchants, checked when payments are confirmed.
+//!
+//! Merchants block payments on card numbers, card BINs, customer emails and IP addresses. Card
+//! numbers are blocked on their fingerprint, an HMAC of the card number, so that they are not
+//! stored. The velocity policy of a merchant limits the payments a card attempts in an hour.
+//! Payments which are blocked or go over the limit are declined without reaching the connector.
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payments::{helpers, PaymentData},
+};
+use crate::{
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::{
+        self,
+        crypto::{self, SignMessage},
+    },
+};
+
+const DEFAULT_LIST_LIMIT: i64 = 10;
+const MAX_LIST_LIMIT: i64 = 100;
+const CARD_BIN_LENGTH: usize = 6;
+const VELOCITY_WINDOW_IN_SECS: u64 = 60 * 60;
+
+#[instrument(skip(state))]
+pub async fn add_entry_to_blocklist(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::BlocklistRequest,
+) -> RouterResponse<api::BlocklistResponse> {
+    let (fingerprint_id, data_kind) = get_fingerprint(state, &req)?;
+    let blocklist_entry = state
+        .store
+        .insert_blocklist_entry(storage::BlocklistNew {
+            merchant_id: merchant_account.merchant_id,
+            fingerprint_id,
+            data_kind: data_kind.foreign_into(),
+            created_at: None,
+        })
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicateBlocklistEntry)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        blocklist_entry.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn remove_entry_from_blocklist(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::BlocklistRequest,
+) -> RouterResponse<api::BlocklistResponse> {
+    let (fingerprint_id, _) = get_fingerprint(state, &req)?;
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+            &merchant_account.merchant_id,
+            &fingerprint_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BlocklistEntryNotFound)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        blocklist_entry.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_blocklist_entries(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::BlocklistListConstraints,
+) -> RouterResponse<Vec<api::BlocklistResponse>> {
+    let limit = constraints.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+    utils::when(!(1..=MAX_LIST_LIMIT).contains(&limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {MAX_LIST_LIMIT}"),
+        }))
+    })?;
+    let offset = constraints.offset.unwrap_or(0);
+    utils::when(offset < 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset must not be negative".to_string(),
+        }))
+    })?;
+
+    let blocklist_entries = state
+        .store
+        .list_blocklist_entries_by_merchant_id_data_kind(
+            &merchant_account.merchant_id,
+            constraints.data_kind.foreign_into(),
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing blocklist entries")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(blocklist_entries))
+}
+
+/// Checks the payment against the blocklist and velocity policy of the merchant, failing it when
+/// it is blocked or goes over the limit of the policy. Returns whether the payment can go on to
+/// the connector.
+pub async fn screen_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let card_fingerprint = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => Some((
+            generate_card_fingerprint(state, card.card_number.peek())?,
+            get_card_bin(card.card_number.peek()),
+        )),
+        _ => None,
+    };
+
+    let fingerprint_ids = get_fingerprint_ids(
+        card_fingerprint.as_ref(),
+        payment_data
+            .email
+            .as_ref()
+            .map(|email| email.peek().as_str()),
+        get_ip_address(&payment_data.payment_attempt)?,
+    );
+
+    let storage_scheme = merchant_account.storage_scheme;
+    if !fingerprint_ids.is_empty() {
+        let blocklist_entries = state
+            .store
+            .find_blocklist_entries_by_merchant_id_fingerprint_ids(
+                &merchant_account.merchant_id,
+                fingerprint_ids,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding blocklist entries of the payment")?;
+
+        if let Some(blocklist_entry) = blocklist_entries.first() {
+            logger::info!(
+                payment_id = %payment_data.payment_intent.payment_id,
+                data_kind = %blocklist_entry.data_kind,
+                "Payment is blocked"
+            );
+            helpers::fail_payment_before_connector(
+                state,
+                storage_scheme,
+                payment_data,
+                storage_enums::AttemptStatus::RouterDeclined,
+                None,
+                format!(
+                    "Payment was blocked by the blocklist of the merchant on its {}",
+                    blocklist_entry.data_kind
+                ),
+                storage_enums::UnifiedCode::TransactionNotAllowed,
+            )
+            .await?;
+            return Ok(false);
+        }
+    }
+
+    let velocity_policy: Option<api::VelocityPolicy> = merchant_account
+        .velocity_policy
+        .clone()
+        .map(|policy| policy.parse_value("VelocityPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the velocity policy of the merchant")?;
+    let (velocity_policy, (card_fingerprint, _)) = match velocity_policy.zip(card_fingerprint) {
+        Some(policy_and_card) => policy_and_card,
+        None => return Ok(true),
+    };
+
+    let key = format!(
+        "velocity_card_{}_{card_fingerprint}",
+        merchant_account.merchant_id
+    );
+    let attempts = state
+        .store
+        .count_in_fixed_window(&key, VELOCITY_WINDOW_IN_SECS)
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to count the payment attempts of the card");
+        })
+        .ok();
+
+    if is_over_velocity_limit(&velocity_policy, attempts) {
+        helpers::fail_payment_before_connector(
+            state,
+            storage_scheme,
+            payment_data,
+            storage_enums::AttemptStatus::RouterDeclined,
+            None,
+            "The card attempted more payments in the last hour than allowed by the merchant"
+                .to_string(),
+            storage_enums::UnifiedCode::TransactionNotAllowed,
+        )
+        .await?;
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+fn get_fingerprint(
+    state: &AppState,
+    req: &api::BlocklistRequest,
+) -> RouterResult<(String, api_enums::BlocklistDataKind)> {
+    match req {
+        api::BlocklistRequest::CardNumber(card_number) => Ok((
+            generate_card_fingerprint(state, card_number.peek())?,
+            api_enums::BlocklistDataKind::CardFingerprint,
+        )),
+        api::BlocklistRequest::CardFingerprint(fingerprint) => Ok((
+            fingerprint.clone(),
+            api_enums::BlocklistDataKind::CardFingerprint,
+        )),
+        api::BlocklistRequest::CardBin(card_bin) => {
+            validate_card_bin(card_bin)?;
+            Ok((card_bin.clone(), api_enums::BlocklistDataKind::CardBin))
+        }
+        api::BlocklistRequest::Email(email) => Ok((
+            email.peek().to_lowercase(),
+            api_enums::BlocklistDataKind::Email,
+        )),
+        api::BlocklistRequest::IpAddress(ip_address) => Ok((
+            ip_address.to_string(),
+            api_enums::BlocklistDataKind::IpAddress,
+        )),
+    }
+}
+
+/// Identifiers of the payment looked up in the blocklist: the fingerprint and BIN of the card, the
+/// email of the customer and the IP address of the client
+fn get_fingerprint_ids(
+    card_fingerprint: Option<&(String, Option<String>)>,
+    email: Option<&str>,
+    ip_address: Option<String>,
+) -> Vec<String> {
+    let mut fingerprint_ids = Vec::new();
+    if let Some((fingerprint, card_bin)) = card_fingerprint {
+        fingerprint_ids.push(fingerprint.clone());
+        fingerprint_ids.extend(card_bin.clone());
+    }
+    fingerprint_ids.extend(email.map(str::to_lowercase));
+    fingerprint_ids.extend(ip_address);
+    fingerprint_ids
+}
+
+/// The attempts of a card are not limited while they cannot be counted, as when redis is
+/// unavailable, rather than failing every card payment
+fn is_over_velocity_limit(velocity_policy: &api::VelocityPolicy, attempts: Option<u64>) -> bool {
+    attempts.map_or(false, |attempts| {
+        attempts > u64::from(velocity_policy.max_attempts_per_card_per_hour)
+    })
+}
+
+fn get_card_bin(card_number: &str) -> Option<String> {
+    card_number.get(..CARD_BIN_LENGTH).map(ToOwned::to_owned)
+}
+
+fn validate_card_bin(card_bin: &str) -> RouterResult<()> {
+    utils::when(
+        card_bin.len() != CARD_BIN_LENGTH || !card_bin.chars().all(|c| c.is_ascii_digit()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "card_bin",
+            }))
+        },
+    )
+}
+
+pub(crate) fn generate_card_fingerprint(
+    state: &AppState,
+    card_number: &str,
+) -> RouterResult<String> {
+    fingerprint_card_number(
+        state.conf.secrets.card_fingerprint_secret.as_bytes(),
+        card_number,
+    )
+}
+
+/// HMAC of the digits of the card number, so that the same card is fingerprinted alike however
+/// its number is formatted
+fn fingerprint_card_number(secret: &[u8], card_number: &str) -> RouterResult<String> {
+    let card_number: String = card_number.chars().filter(char::is_ascii_digit).collect();
+    crypto::HmacSha256
+        .sign_message(secret, card_number.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fingerprint the card")
+}
+
+fn get_ip_address(payment_attempt: &storage::PaymentAttempt) -> RouterResult<Option<String>> {
+    let browser_info: Option<types::BrowserInformation> = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+
+    Ok(browser_info
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_card_fingerprint() {
+        let fingerprint = fingerprint_card_number(b"secret", "4242424242424242").unwrap();
+
+        assert_eq!(fingerprint.len(), 64);
+        assert!(!fingerprint.contains("4242424242424242"));
+        assert_eq!(
+            fingerprint_card_number(b"secret", "4242 4242 4242 4242").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            fingerprint_card_number(b"another_secret", "4242424242424242").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            fingerprint_card_number(b"secret", "4000056655665556").unwrap(),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn test_card_bin_validation() {
+        assert!(validate_card_bin("424242").is_ok());
+        assert!(validate_card_bin("42424").is_err());
+        assert!(validate_card_bin("4242424").is_err());
+        assert!(validate_card_bin("42424a").is_err());
+
+        assert_eq!(get_card_bin("4242424242424242"), Some("424242".to_string()));
+        assert_eq!(get_card_bin("4242"), None);
+    }
+
+    #[test]
+    fn test_fingerprint_ids() {
+        let card_fingerprint = ("fingerprint".to_string(), Some("424242".to_string()));
+
+        assert_eq!(
+            get_fingerprint_ids(
+                Some(&card_fingerprint),
+                Some("John@Example.com"),
+                Some("127.0.0.1".to_string())
+            ),
+            vec!["fingerprint", "424242", "john@example.com", "127.0.0.1"]
+        );
+        assert!(get_fingerprint_ids(None, None, None).is_empty());
+    }
+
+    #[test]
+    fn test_velocity_limit() {
+        let velocity_policy = api::VelocityPolicy {
+            max_attempts_per_card_per_hour: 3,
+        };
+
+        assert!(!is_over_velocity_limit(&velocity_policy, Some(3)));
+        assert!(is_over_velocity_limit(&velocity_policy, Some(4)));
+        // Attempts which could not be counted are let through
+        assert!(!is_over_velocity_limit(&velocity_policy, None));
+    }
+}
@@ -0,0 +1,82 @@
+//! Enforcement of the card verification policy of merchants on authorized payments. Connectors
+//! normalize the results of the address (AVS) and security code (CVV) verification of the card by
+//! its issuer, which are stored on the payment attempt. Payments authorized with a result the
+//! merchant declines are voided right after their authorization.
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+
+use super::{
+    errors::{self, RouterResult},
+    payments::{helpers, PaymentData},
+};
+use crate::{
+    logger,
+    routes::AppState,
+    types::{
+        api::{self, enums as api_enums},
+        storage,
+        transformers::ForeignInto,
+    },
+};
+
+pub fn get_card_verification_policy(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::CardVerificationPolicy>> {
+    merchant_account
+        .card_verification_policy
+        .clone()
+        .map(|policy| policy.parse_value("CardVerificationPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the card verification policy of the merchant")
+}
+
+/// Voids the payment when its card verification results are declined by the policy of the
+/// merchant.
+pub async fn enforce_policy_after_authorization<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let policy = match get_card_verification_policy(merchant_account)? {
+        Some(policy) => policy,
+        None => return Ok(()),
+    };
+
+    let payment_attempt = &payment_data.payment_attempt;
+    let avs_result: Option<api_enums::AvsResult> =
+        payment_attempt.avs_result.map(ForeignInto::foreign_into);
+    let cvv_result: Option<api_enums::CvvResult> =
+        payment_attempt.cvv_result.map(ForeignInto::foreign_into);
+    let cancellation_reason = if avs_result.map_or(false, |avs_result| {
+        policy.void_on_avs_results.contains(&avs_result)
+    }) {
+        "avs_declined"
+    } else if cvv_result.map_or(false, |cvv_result| {
+        policy.void_on_cvv_results.contains(&cvv_result)
+    }) {
+        "cvv_declined"
+    } else {
+        return Ok(());
+    };
+
+    logger::info!(
+        payment_id = %payment_attempt.payment_id,
+        ?avs_result,
+        ?cvv_result,
+        "Payment is declined by the card verification policy of the merchant"
+    );
+    if helpers::void_authorized_payment(
+        state,
+        merchant_account,
+        payment_attempt,
+        cancellation_reason,
+    )
+    .await?
+    {
+        helpers::reload_payment(state, merchant_account, payment_data).await?;
+    }
+
+    Ok(())
+}
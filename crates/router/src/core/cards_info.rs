@@ -0,0 +1,427 @@
+//! Identification of cards from their BIN, the leading digits of the card number.
+//!
+//! The network of a card is identified from the ranges of card numbers assigned to each network.
+//! Its issuer, issuing country and funding type are looked up in the BIN table embedded in the
+//! application and, when the external lookup is enabled, with the BIN lookup API for the details
+//! the table does not have. Details provided by the merchant always take precedence over the ones
+//! identified from the BIN.
+
+use std::collections::HashMap;
+
+use error_stack::{report, ResultExt};
+use once_cell::sync::Lazy;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::CardBinLookupSettings,
+    core::errors::{self, RouterResult},
+    headers, logger,
+    pii::PeekInterface,
+    routes::AppState,
+    services,
+    types::api::{self, enums as api_enums},
+    utils::BytesExt,
+};
+
+const MIN_BIN_LENGTH: usize = 6;
+const MAX_BIN_LENGTH: usize = 8;
+
+/// Ranges of card number prefixes assigned to the card networks, given as the first and the last
+/// prefix of each range. When the ranges of several networks contain a card number, the range
+/// with the longest prefixes decides the network.
+const CARD_NETWORK_RANGES: &[(&str, &str, api_enums::CardNetwork)] = &[
+    ("4", "4", api_enums::CardNetwork::Visa),
+    ("51", "55", api_enums::CardNetwork::Mastercard),
+    ("2221", "2720", api_enums::CardNetwork::Mastercard),
+    ("34", "34", api_enums::CardNetwork::AmericanExpress),
+    ("37", "37", api_enums::CardNetwork::AmericanExpress),
+    ("300", "305", api_enums::CardNetwork::DinersClub),
+    ("3095", "3095", api_enums::CardNetwork::DinersClub),
+    ("36", "36", api_enums::CardNetwork::DinersClub),
+    ("38", "39", api_enums::CardNetwork::DinersClub),
+    ("6011", "6011", api_enums::CardNetwork::Discover),
+    ("644", "649", api_enums::CardNetwork::Discover),
+    ("65", "65", api_enums::CardNetwork::Discover),
+    ("622126", "622925", api_enums::CardNetwork::Discover),
+    ("3528", "3589", api_enums::CardNetwork::JCB),
+    ("62", "62", api_enums::CardNetwork::UnionPay),
+    ("5018", "5018", api_enums::CardNetwork::Maestro),
+    ("5020", "5020", api_enums::CardNetwork::Maestro),
+    ("5038", "5038", api_enums::CardNetwork::Maestro),
+    ("5893", "5893", api_enums::CardNetwork::Maestro),
+    ("6304", "6304", api_enums::CardNetwork::Maestro),
+    ("6759", "6759", api_enums::CardNetwork::Maestro),
+    ("6761", "6763", api_enums::CardNetwork::Maestro),
+    ("60", "60", api_enums::CardNetwork::RuPay),
+    ("508", "508", api_enums::CardNetwork::RuPay),
+    ("6521", "6522", api_enums::CardNetwork::RuPay),
+    ("81", "82", api_enums::CardNetwork::RuPay),
+];
+
+static BIN_TABLE: Lazy<HashMap<String, CardInfo>> =
+    Lazy::new(|| parse_bin_table(include_str!("cards_info/bin_table.csv")));
+
+/// Details of a card identified from its BIN
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CardInfo {
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub card_issuer: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub card_type: Option<api_enums::CardType>,
+}
+
+impl CardInfo {
+    /// Fills the details missing from `self` with the ones of `other`.
+    fn or(self, other: Self) -> Self {
+        Self {
+            card_network: self.card_network.or(other.card_network),
+            card_issuer: self.card_issuer.or(other.card_issuer),
+            card_issuing_country: self.card_issuing_country.or(other.card_issuing_country),
+            card_type: self.card_type.or(other.card_type),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.card_network.is_some()
+            && self.card_issuer.is_some()
+            && self.card_issuing_country.is_some()
+            && self.card_type.is_some()
+    }
+}
+
+impl From<&api::Card> for CardInfo {
+    fn from(card: &api::Card) -> Self {
+        Self {
+            card_network: card.card_network.clone(),
+            card_issuer: card.card_issuer.clone(),
+            card_issuing_country: card.card_issuing_country.clone(),
+            card_type: card.card_type,
+        }
+    }
+}
+
+/// Response of the BIN lookup API
+#[derive(Debug, serde::Deserialize)]
+struct BinLookupResponse {
+    scheme: Option<String>,
+    #[serde(rename = "type")]
+    funding_type: Option<String>,
+    prepaid: Option<bool>,
+    country: Option<BinLookupCountry>,
+    bank: Option<BinLookupBank>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BinLookupCountry {
+    alpha2: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BinLookupBank {
+    name: Option<String>,
+}
+
+impl From<BinLookupResponse> for CardInfo {
+    fn from(response: BinLookupResponse) -> Self {
+        let card_type = match response.prepaid {
+            Some(true) => Some(api_enums::CardType::Prepaid),
+            _ => response.funding_type.as_deref().and_then(parse_card_type),
+        };
+
+        Self {
+            card_network: response.scheme.as_deref().and_then(parse_card_network),
+            card_issuer: response.bank.and_then(|bank| bank.name),
+            card_issuing_country: response.country.and_then(|country| country.alpha2),
+            card_type,
+        }
+    }
+}
+
+/// Identifies a card from its number. Nothing is returned for numbers too short to contain a BIN.
+#[instrument(skip_all)]
+pub async fn get_card_info(state: &AppState, card_number: &str) -> Option<CardInfo> {
+    let card_number: String = card_number
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect();
+    let bin = card_number
+        .get(..MIN_BIN_LENGTH)
+        .filter(|bin| bin.bytes().all(|byte| byte.is_ascii_digit()))?;
+
+    let mut card_info = find_in_bin_table(&card_number)
+        .unwrap_or_default()
+        .or(CardInfo {
+            card_network: get_card_network(&card_number),
+            ..CardInfo::default()
+        });
+
+    let lookup_settings = &state.conf.card_bin_lookup;
+    if lookup_settings.external_lookup_enabled && !card_info.is_complete() {
+        // Only the BIN leaves the application, never the rest of the card number
+        match lookup_bin(state, lookup_settings, bin).await {
+            Ok(looked_up_card_info) => card_info = card_info.or(looked_up_card_info),
+            Err(error) => logger::warn!(?error, "Failed to look up the card BIN"),
+        }
+    }
+
+    Some(card_info)
+}
+
+/// Fills the details of a card missing from the payment method data with the ones identified from
+/// its BIN. The network chosen by the client for a co-badged card replaces the one of the card.
+/// Payment method data other than cards is returned as is.
+pub async fn populate_card_info(
+    state: &AppState,
+    payment_method_data: Option<api::PaymentMethodData>,
+    preferred_card_network: Option<api_enums::CardNetwork>,
+) -> Option<api::PaymentMethodData> {
+    match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => {
+            let requested_card_info = CardInfo {
+                card_network: preferred_card_network.or_else(|| card.card_network.clone()),
+                ..CardInfo::from(&card)
+            };
+            if requested_card_info.is_complete() {
+                return Some(api::PaymentMethodData::Card(card));
+            }
+
+            let card_info = match get_card_info(state, card.card_number.peek()).await {
+                Some(card_info) => requested_card_info.or(card_info),
+                None => requested_card_info,
+            };
+
+            Some(api::PaymentMethodData::Card(api::Card {
+                card_network: card_info.card_network,
+                card_issuer: card_info.card_issuer,
+                card_issuing_country: card_info.card_issuing_country,
+                card_type: card_info.card_type,
+                ..card
+            }))
+        }
+        payment_method_data => payment_method_data,
+    }
+}
+
+fn find_in_bin_table(card_number: &str) -> Option<CardInfo> {
+    (MIN_BIN_LENGTH..=MAX_BIN_LENGTH)
+        .rev()
+        .find_map(|length| card_number.get(..length).and_then(|bin| BIN_TABLE.get(bin)))
+        .cloned()
+}
+
+fn get_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    CARD_NETWORK_RANGES
+        .iter()
+        .filter(|(first_prefix, last_prefix, _)| {
+            card_number
+                .get(..first_prefix.len())
+                .map_or(false, |prefix| {
+                    *first_prefix <= prefix && prefix <= *last_prefix
+                })
+        })
+        .max_by_key(|(first_prefix, _, _)| first_prefix.len())
+        .map(|(_, _, card_network)| card_network.clone())
+}
+
+async fn lookup_bin(
+    state: &AppState,
+    lookup_settings: &CardBinLookupSettings,
+    bin: &str,
+) -> RouterResult<CardInfo> {
+    let url = format!("{}{bin}", lookup_settings.base_url);
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&url)
+        .header(headers::ACCEPT_VERSION, "3");
+    if !lookup_settings.api_key.is_empty() {
+        request = request.header(headers::AUTHORIZATION, &lookup_settings.api_key);
+    }
+
+    let response = services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the BIN lookup API")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "BIN lookup API responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    let response: BinLookupResponse = response
+        .response
+        .parse_struct("BinLookupResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the BIN lookup API response")?;
+
+    Ok(CardInfo::from(response))
+}
+
+/// Parses the BIN table, skipping comments, the header and malformed lines.
+fn parse_bin_table(table: &str) -> HashMap<String, CardInfo> {
+    table
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            match fields.as_slice() {
+                [bin, card_network, card_issuer, card_issuing_country, card_type]
+                    if is_valid_bin(bin) =>
+                {
+                    let card_info = CardInfo {
+                        card_network: parse_card_network(card_network),
+                        card_issuer: Some(card_issuer.to_string())
+                            .filter(|value| !value.is_empty()),
+                        card_issuing_country: Some(card_issuing_country.to_string())
+                            .filter(|value| !value.is_empty()),
+                        card_type: parse_card_type(card_type),
+                    };
+                    Some((bin.to_string(), card_info))
+                }
+                _ => {
+                    logger::error!(%line, "Skipping malformed line of the BIN table");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn is_valid_bin(bin: &str) -> bool {
+    (MIN_BIN_LENGTH..=MAX_BIN_LENGTH).contains(&bin.len())
+        && bin.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Parses the name of a card network, ignoring case, spaces and punctuation.
+fn parse_card_network(name: &str) -> Option<api_enums::CardNetwork> {
+    let name: String = name
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    match name.as_str() {
+        "visa" => Some(api_enums::CardNetwork::Visa),
+        "mastercard" => Some(api_enums::CardNetwork::Mastercard),
+        "amex" | "americanexpress" => Some(api_enums::CardNetwork::AmericanExpress),
+        "jcb" => Some(api_enums::CardNetwork::JCB),
+        "diners" | "dinersclub" => Some(api_enums::CardNetwork::DinersClub),
+        "discover" => Some(api_enums::CardNetwork::Discover),
+        "cartesbancaires" => Some(api_enums::CardNetwork::CartesBancaires),
+        "unionpay" | "chinaunionpay" => Some(api_enums::CardNetwork::UnionPay),
+        "interac" => Some(api_enums::CardNetwork::Interac),
+        "rupay" => Some(api_enums::CardNetwork::RuPay),
+        "maestro" => Some(api_enums::CardNetwork::Maestro),
+        _ => None,
+    }
+}
+
+fn parse_card_type(name: &str) -> Option<api_enums::CardType> {
+    name.trim().to_ascii_lowercase().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_embedded_bin_table_is_valid() {
+        let table = include_str!("cards_info/bin_table.csv");
+        let entries = table
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+
+        // Every line but the header must have been parsed
+        assert_eq!(BIN_TABLE.len(), entries - 1);
+        assert!(BIN_TABLE
+            .values()
+            .all(|card_info| card_info.card_network.is_some() && card_info.card_type.is_some()));
+    }
+
+    #[test]
+    fn test_longest_bin_is_preferred() {
+        let card_info = find_in_bin_table("4000000760000002").unwrap();
+        assert_eq!(card_info.card_issuing_country.as_deref(), Some("BR"));
+
+        let card_info = find_in_bin_table("4242424242424242").unwrap();
+        assert_eq!(card_info.card_issuing_country.as_deref(), Some("US"));
+        assert_eq!(card_info.card_type, Some(api_enums::CardType::Credit));
+
+        assert!(find_in_bin_table("4917610000000000").is_none());
+    }
+
+    #[test]
+    fn test_card_network_is_identified_from_ranges() {
+        let cases = [
+            ("4917610000000000", Some(api_enums::CardNetwork::Visa)),
+            ("2720990000000000", Some(api_enums::CardNetwork::Mastercard)),
+            ("5105105105105100", Some(api_enums::CardNetwork::Mastercard)),
+            (
+                "340000000000009",
+                Some(api_enums::CardNetwork::AmericanExpress),
+            ),
+            ("6011000990139424", Some(api_enums::CardNetwork::Discover)),
+            ("6070000000000000", Some(api_enums::CardNetwork::RuPay)),
+            ("6521000000000000", Some(api_enums::CardNetwork::RuPay)),
+            ("6500000000000002", Some(api_enums::CardNetwork::Discover)),
+            ("6200000000000005", Some(api_enums::CardNetwork::UnionPay)),
+            ("6759649826438453", Some(api_enums::CardNetwork::Maestro)),
+            ("3566002020360505", Some(api_enums::CardNetwork::JCB)),
+            ("9900000000000000", None),
+        ];
+
+        for (card_number, card_network) in cases {
+            assert_eq!(get_card_network(card_number), card_network, "{card_number}");
+        }
+    }
+
+    #[test]
+    fn test_bin_lookup_response_is_converted() {
+        let response: BinLookupResponse = serde_json::from_str(
+            r#"{
+                "scheme": "visa",
+                "type": "debit",
+                "prepaid": true,
+                "country": { "alpha2": "DK" },
+                "bank": { "name": "Jyske Bank" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CardInfo::from(response),
+            CardInfo {
+                card_network: Some(api_enums::CardNetwork::Visa),
+                card_issuer: Some("Jyske Bank".to_string()),
+                card_issuing_country: Some("DK".to_string()),
+                card_type: Some(api_enums::CardType::Prepaid),
+            }
+        );
+    }
+
+    #[test]
+    fn test_requested_details_take_precedence() {
+        let requested = CardInfo {
+            card_network: Some(api_enums::CardNetwork::CartesBancaires),
+            ..CardInfo::default()
+        };
+        let identified = CardInfo {
+            card_network: Some(api_enums::CardNetwork::Visa),
+            card_issuing_country: Some("FR".to_string()),
+            ..CardInfo::default()
+        };
+
+        let card_info = requested.or(identified);
+        assert_eq!(
+            card_info.card_network,
+            Some(api_enums::CardNetwork::CartesBancaires)
+        );
+        assert_eq!(card_info.card_issuing_country.as_deref(), Some("FR"));
+        assert!(!card_info.is_complete());
+    }
+}
@@ -0,0 +1,280 @@
+//! Health of connectors, computed from a rolling window of their most recent calls.
+//!
+//! Every application instance tracks the calls it makes. When the error rate or the average
+//! latency of a connector crosses the configured thresholds, the circuit of the connector is
+//! opened and the routing engine falls back to the other connectors of the merchant. Once the
+//! circuit has been open for the configured duration, it is half opened and the connector is
+//! called again: the circuit is closed on the first successful call and opened again on a failure.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use api_models::health_check::{CircuitState, ConnectorHealthResponse};
+use once_cell::sync::Lazy;
+use router_env::{instrument, opentelemetry::KeyValue, tracing};
+
+use crate::{
+    configs::settings::ConnectorHealthSettings,
+    core::errors::RouterResponse,
+    logger,
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+};
+
+static CONNECTOR_HEALTH: Lazy<ConnectorHealthRegistry> =
+    Lazy::new(ConnectorHealthRegistry::default);
+
+#[derive(Clone, Copy, Debug)]
+struct ConnectorCall {
+    failed: bool,
+    latency: Duration,
+}
+
+#[derive(Debug, Default)]
+struct ConnectorHealth {
+    calls: VecDeque<ConnectorCall>,
+    circuit_state: CircuitState,
+    opened_at: Option<Instant>,
+}
+
+impl ConnectorHealth {
+    #[allow(clippy::as_conversions)]
+    fn error_rate(&self) -> f64 {
+        if self.calls.is_empty() {
+            return 0.0;
+        }
+        let failed_calls = self.calls.iter().filter(|call| call.failed).count();
+        failed_calls as f64 / self.calls.len() as f64
+    }
+
+    fn average_latency(&self) -> Duration {
+        let total_latency: Duration = self.calls.iter().map(|call| call.latency).sum();
+        u32::try_from(self.calls.len())
+            .ok()
+            .and_then(|calls| total_latency.checked_div(calls))
+            .unwrap_or_default()
+    }
+
+    fn is_degraded(&self, settings: &ConnectorHealthSettings) -> bool {
+        self.calls.len() >= settings.min_calls
+            && (self.error_rate() >= settings.error_rate_threshold
+                || self.average_latency()
+                    >= Duration::from_millis(settings.latency_threshold_in_ms))
+    }
+
+    fn open_circuit(&mut self, connector: &str) {
+        logger::warn!(
+            connector,
+            error_rate = self.error_rate(),
+            average_latency_ms = self.average_latency().as_millis(),
+            "Opening the circuit of a degraded connector"
+        );
+        metrics::CONNECTOR_CIRCUIT_OPENED.add(
+            &metrics::CONTEXT,
+            1,
+            &[KeyValue::new("connector", connector.to_string())],
+        );
+        self.circuit_state = CircuitState::Open;
+        self.opened_at = Some(Instant::now());
+    }
+
+    fn close_circuit(&mut self, connector: &str) {
+        logger::info!(connector, "Closing the circuit of a recovered connector");
+        // The calls made before the circuit was opened would open it again right away
+        self.calls.clear();
+        self.circuit_state = CircuitState::Closed;
+        self.opened_at = None;
+    }
+}
+
+/// Health of the connectors called by this application instance.
+#[derive(Debug, Default)]
+pub struct ConnectorHealthRegistry {
+    connectors: Mutex<HashMap<String, ConnectorHealth>>,
+}
+
+impl ConnectorHealthRegistry {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ConnectorHealth>> {
+        self.connectors
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn record_call(
+        &self,
+        settings: &ConnectorHealthSettings,
+        connector: &str,
+        failed: bool,
+        latency: Duration,
+    ) {
+        let mut connectors = self.lock();
+        let health = connectors.entry(connector.to_string()).or_default();
+
+        health.calls.push_back(ConnectorCall { failed, latency });
+        while health.calls.len() > settings.window_size {
+            health.calls.pop_front();
+        }
+
+        match health.circuit_state {
+            CircuitState::HalfOpen if failed => health.open_circuit(connector),
+            CircuitState::HalfOpen => health.close_circuit(connector),
+            CircuitState::Closed if health.is_degraded(settings) => health.open_circuit(connector),
+            CircuitState::Closed | CircuitState::Open => (),
+        }
+    }
+
+    /// Whether payments can be routed to the connector. A circuit which has been open for long
+    /// enough is half opened, so that the connector is called again.
+    pub fn is_available(&self, settings: &ConnectorHealthSettings, connector: &str) -> bool {
+        let mut connectors = self.lock();
+        let health = match connectors.get_mut(connector) {
+            Some(health) => health,
+            None => return true,
+        };
+
+        match health.circuit_state {
+            CircuitState::Closed | CircuitState::HalfOpen => true,
+            CircuitState::Open => {
+                let open_duration = Duration::from_secs(settings.open_duration_in_secs);
+                let should_half_open = health
+                    .opened_at
+                    .map_or(true, |opened_at| opened_at.elapsed() >= open_duration);
+                if should_half_open {
+                    health.circuit_state = CircuitState::HalfOpen;
+                }
+                should_half_open
+            }
+        }
+    }
+
+    pub fn get_health(&self) -> Vec<ConnectorHealthResponse> {
+        let mut connectors_health: Vec<_> = self
+            .lock()
+            .iter()
+            .map(|(connector, health)| ConnectorHealthResponse {
+                connector: connector.clone(),
+                circuit_state: health.circuit_state,
+                calls: health.calls.len(),
+                error_rate: health.error_rate(),
+                average_latency_ms: u64::try_from(health.average_latency().as_millis())
+                    .unwrap_or(u64::MAX),
+            })
+            .collect();
+        connectors_health.sort_by(|a, b| a.connector.cmp(&b.connector));
+        connectors_health
+    }
+}
+
+/// Records the outcome of a call made to a connector. Network failures and 5xx responses count as
+/// failed calls, while other error responses, such as declines, don't.
+pub fn record_connector_call(
+    settings: &ConnectorHealthSettings,
+    connector: &str,
+    failed: bool,
+    latency: Duration,
+) {
+    CONNECTOR_HEALTH.record_call(settings, connector, failed, latency)
+}
+
+/// Removes the connectors whose circuit is open from the connectors given by the routing rules,
+/// when the circuit breaker is enabled. If every connector is degraded, the connectors are
+/// returned as they are, so that payments are still attempted.
+pub fn filter_available_connectors(
+    settings: &ConnectorHealthSettings,
+    connectors: Vec<String>,
+) -> Vec<String> {
+    if !settings.circuit_breaker_enabled {
+        return connectors;
+    }
+
+    let available_connectors: Vec<_> = connectors
+        .iter()
+        .filter(|connector| CONNECTOR_HEALTH.is_available(settings, connector))
+        .cloned()
+        .collect();
+
+    if available_connectors.is_empty() {
+        logger::warn!(
+            ?connectors,
+            "All the connectors given by the routing rules are degraded"
+        );
+        connectors
+    } else {
+        available_connectors
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_connectors_health(
+    _state: &AppState,
+) -> RouterResponse<Vec<ConnectorHealthResponse>> {
+    Ok(ApplicationResponse::Json(CONNECTOR_HEALTH.get_health()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_settings(open_duration_in_secs: u64) -> ConnectorHealthSettings {
+        ConnectorHealthSettings {
+            circuit_breaker_enabled: true,
+            window_size: 10,
+            min_calls: 4,
+            error_rate_threshold: 0.5,
+            latency_threshold_in_ms: 1000,
+            open_duration_in_secs,
+        }
+    }
+
+    #[test]
+    fn test_circuit_opens_on_error_rate() {
+        let settings = get_settings(60);
+        let registry = ConnectorHealthRegistry::default();
+        let latency = Duration::from_millis(100);
+
+        registry.record_call(&settings, "stripe", true, latency);
+        registry.record_call(&settings, "stripe", true, latency);
+        registry.record_call(&settings, "stripe", false, latency);
+        // Not enough calls have been made to judge the health of the connector
+        assert!(registry.is_available(&settings, "stripe"));
+
+        registry.record_call(&settings, "stripe", false, latency);
+        assert!(!registry.is_available(&settings, "stripe"));
+        assert!(registry.is_available(&settings, "adyen"));
+    }
+
+    #[test]
+    fn test_circuit_opens_on_latency() {
+        let settings = get_settings(60);
+        let registry = ConnectorHealthRegistry::default();
+
+        for _ in 0..4 {
+            registry.record_call(&settings, "stripe", false, Duration::from_secs(2));
+        }
+        assert!(!registry.is_available(&settings, "stripe"));
+    }
+
+    #[test]
+    fn test_half_open_circuit() {
+        let settings = get_settings(0);
+        let registry = ConnectorHealthRegistry::default();
+        let latency = Duration::from_millis(100);
+
+        for _ in 0..4 {
+            registry.record_call(&settings, "stripe", true, latency);
+        }
+        // The circuit is half opened right away as it is configured to stay open for no time
+        assert!(registry.is_available(&settings, "stripe"));
+        registry.record_call(&settings, "stripe", true, latency);
+        assert_eq!(registry.get_health()[0].circuit_state, CircuitState::Open);
+
+        assert!(registry.is_available(&settings, "stripe"));
+        registry.record_call(&settings, "stripe", false, latency);
+        let health = &registry.get_health()[0];
+        assert_eq!(health.circuit_state, CircuitState::Closed);
+        assert_eq!(health.calls, 0);
+    }
+}
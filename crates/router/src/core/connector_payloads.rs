@@ -0,0 +1,439 @@
+use common_utils::pii;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, utils::StorageErrorExt, CustomResult, RouterResponse},
+    db::StorageInterface,
+    logger,
+    services::{
+        api::{request::ContentType, Request},
+        ApplicationResponse,
+    },
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Fragments of the keys of the values that are masked wherever they appear in a key, once it is
+/// lowercased and stripped of anything but letters and digits, so that `card[exp_month]`,
+/// `expiryMonth` and `expiry_month` are all matched.
+const SENSITIVE_KEY_FRAGMENTS: &[&str] = &[
+    "cardnumber",
+    "cardcode",
+    "cvc",
+    "cvv",
+    "cvn",
+    "securitycode",
+    "expmonth",
+    "expyear",
+    "expirymonth",
+    "expiryyear",
+    "expirydate",
+    "expiration",
+    "cryptogram",
+    "token",
+    "password",
+    "secret",
+    "apikey",
+    "privatekey",
+    "transactionkey",
+    "signature",
+    "authorization",
+    "iban",
+    "accountnumber",
+    "routingnumber",
+    "sortcode",
+];
+
+/// Keys of the values that are masked only when they are the whole key, as they are too short to
+/// be matched as fragments without masking unrelated values.
+const SENSITIVE_KEYS: &[&str] = &["number", "pan", "expiry", "key"];
+
+fn get_capture_config_key(merchant_id: &str) -> String {
+    format!("capture_connector_payloads_{merchant_id}")
+}
+
+/// Whether the merchant opted in to the capture of the payloads exchanged with the connectors.
+/// The capture is disabled when the configuration can't be read, as it is only a debugging aid.
+pub async fn is_capture_enabled(db: &dyn StorageInterface, merchant_id: &str) -> bool {
+    db.find_config_by_key_cached(&get_capture_config_key(merchant_id))
+        .await
+        .map(|config| config.config == "true")
+        .unwrap_or(false)
+}
+
+/// Masks the request sent to the connector, leaving out its headers, which carry the credentials
+/// of the merchant, and the query of its URL.
+pub fn mask_request(request: &Request, flow: &str) -> serde_json::Value {
+    let url = request
+        .url
+        .split_once('?')
+        .map_or(request.url.as_str(), |(path, _)| path);
+    let body = request
+        .payload
+        .as_ref()
+        .map(|payload| match request.content_type {
+            Some(ContentType::FormUrlEncoded) => mask_form(payload.peek()),
+            Some(ContentType::Json) | None => mask_body(payload.peek()),
+        });
+
+    serde_json::json!({
+        "flow": flow,
+        "method": request.method.to_string(),
+        "url": url,
+        "body": body,
+    })
+}
+
+pub fn mask_response(
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) -> serde_json::Value {
+    match response {
+        Ok(Ok(response)) | Ok(Err(response)) => serde_json::json!({
+            "status_code": response.status_code,
+            "body": mask_body(&String::from_utf8_lossy(&response.response)),
+        }),
+        Err(error) => serde_json::json!({ "error": error.current_context().to_string() }),
+    }
+}
+
+/// Stores the masked payloads of the last call made to the connector for the attempt. Calls made
+/// for attempts which have no connector response, such as those of payouts, are not recorded.
+/// Failing to record the payloads is logged, and does not fail the call.
+#[instrument(skip_all)]
+pub async fn record_connector_payloads(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_id: &str,
+    attempt_id: &str,
+    connector_request_payload: serde_json::Value,
+    connector_response_payload: serde_json::Value,
+) {
+    let connector_response = match db
+        .find_connector_response_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_id,
+            attempt_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+    {
+        Ok(connector_response) => connector_response,
+        Err(error) => {
+            logger::debug!(?error, "No connector response to record the payloads in");
+            return;
+        }
+    };
+
+    if let Err(error) = db
+        .update_connector_response(
+            connector_response,
+            storage::ConnectorResponseUpdate::PayloadUpdate {
+                connector_request_payload,
+                connector_response_payload,
+            },
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to record the payloads of the connector call"
+        );
+    }
+}
+
+/// Masks a body which is JSON when it can be parsed as such, and plain text otherwise.
+fn mask_body(body: &str) -> serde_json::Value {
+    serde_json::from_str(body)
+        .map(mask_value)
+        .unwrap_or_else(|_| serde_json::Value::String(mask_text(body)))
+}
+
+fn mask_form(body: &str) -> serde_json::Value {
+    match serde_urlencoded::from_str::<Vec<(String, String)>>(body) {
+        Ok(fields) => serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_key(&key) {
+                        mask_string(value)
+                    } else {
+                        mask_card_number(value)
+                    };
+                    (key, serde_json::Value::String(value))
+                })
+                .collect(),
+        ),
+        Err(_) => serde_json::Value::String(mask_text(body)),
+    }
+}
+
+fn mask_value(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(fields) => serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_key(&key) {
+                        mask_sensitive_value(value)
+                    } else {
+                        mask_value(value)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(mask_value).collect())
+        }
+        serde_json::Value::String(value) => serde_json::Value::String(mask_card_number(value)),
+        serde_json::Value::Number(number) if is_card_number(&number.to_string()) => {
+            serde_json::Value::String(mask_string(number.to_string()))
+        }
+        value => value,
+    }
+}
+
+/// Masks the value of a sensitive key as a whole, whatever its type, so that no part of an object
+/// holding sensitive data, such as a wallet token, is kept.
+fn mask_sensitive_value(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Null => serde_json::Value::Null,
+        serde_json::Value::String(value) => serde_json::Value::String(mask_string(value)),
+        value => serde_json::Value::String(mask_string(value.to_string())),
+    }
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key: String = key
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|character| character.to_ascii_lowercase())
+        .collect();
+
+    SENSITIVE_KEYS.contains(&key.as_str())
+        || SENSITIVE_KEY_FRAGMENTS
+            .iter()
+            .any(|fragment| key.contains(fragment))
+}
+
+/// Card numbers keep their BIN, as it identifies the issuer of the card, which helps investigating
+/// declines. Other values are masked entirely.
+fn mask_string(value: String) -> String {
+    if is_card_number(&value) {
+        let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+        format!("{:?}", Secret::<_, pii::CardNumber>::new(digits))
+    } else {
+        format!("{:?}", Secret::<_>::new(value))
+    }
+}
+
+fn mask_card_number(value: String) -> String {
+    if is_card_number(&value) {
+        mask_string(value)
+    } else {
+        value
+    }
+}
+
+/// Whether the value looks like a card number, with its digits optionally grouped by spaces or
+/// dashes. The Luhn checksum of the digits is verified, so that identifiers and timestamps of the
+/// same length are mostly left as they are.
+fn is_card_number(value: &str) -> bool {
+    if !value
+        .chars()
+        .all(|character| character.is_ascii_digit() || character == ' ' || character == '-')
+    {
+        return false;
+    }
+
+    let digits: Vec<u32> = value
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .collect();
+    let checksum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(position, &digit)| match (position % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+
+    (13..=19).contains(&digits.len()) && checksum % 10 == 0
+}
+
+/// Masks every run of three digits or more of a body which couldn't be parsed, such as an XML
+/// body, as the card details in it can't be told apart from the other values.
+fn mask_text(text: &str) -> String {
+    fn push_digits(masked: &mut String, digits: &str) {
+        if digits.len() >= 3 {
+            masked.push_str(&"*".repeat(digits.len()));
+        } else {
+            masked.push_str(digits);
+        }
+    }
+
+    let mut masked = String::with_capacity(text.len());
+    let mut digits = String::new();
+    for character in text.chars() {
+        if character.is_ascii_digit() {
+            digits.push(character);
+        } else {
+            push_digits(&mut masked, &digits);
+            digits.clear();
+            masked.push(character);
+        }
+    }
+    push_digits(&mut masked, &digits);
+    masked
+}
+
+#[instrument(skip(db))]
+pub async fn toggle_capture(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+    capture_enabled: bool,
+) -> RouterResponse<api::ToggleConnectorPayloadsResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let key = get_capture_config_key(&merchant_id);
+    let config = capture_enabled.to_string();
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_cached(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the capture of the connector payloads")?;
+
+    Ok(ApplicationResponse::Json(
+        api::ToggleConnectorPayloadsResponse {
+            merchant_id,
+            capture_enabled,
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_capture_status(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::ToggleConnectorPayloadsResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let capture_enabled = is_capture_enabled(db, &merchant_id).await;
+    Ok(ApplicationResponse::Json(
+        api::ToggleConnectorPayloadsResponse {
+            merchant_id,
+            capture_enabled,
+        },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_connector_payloads(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+    payment_id: String,
+) -> RouterResponse<Vec<api::ConnectorPayloadsResponse>> {
+    let connector_payloads = db
+        .find_connector_responses_by_merchant_id_payment_id(&merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while retrieving the connector payloads")?
+        .into_iter()
+        .map(|connector_response| api::ConnectorPayloadsResponse {
+            payment_id: connector_response.payment_id,
+            merchant_id: connector_response.merchant_id,
+            attempt_id: connector_response.attempt_id,
+            connector: connector_response.connector_name,
+            request: connector_response.connector_request_payload,
+            response: connector_response.connector_response_payload,
+            modified_at: connector_response.modified_at,
+        })
+        .collect::<Vec<_>>();
+
+    if connector_payloads.is_empty() {
+        Err(errors::ApiErrorResponse::PaymentNotFound)?
+    }
+
+    Ok(ApplicationResponse::Json(connector_payloads))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_mask_body_masks_sensitive_keys() {
+        let masked = mask_body(
+            r#"{"amount":6540,"source":{"number":"4242424242424242","expiry_month":"10","cvv":"123"},"reference":"pay_123"}"#,
+        );
+        assert_eq!(masked["amount"], 6540);
+        assert_eq!(masked["reference"], "pay_123");
+        assert_eq!(masked["source"]["number"], "424242**********");
+        assert_ne!(masked["source"]["expiry_month"], "10");
+        assert_ne!(masked["source"]["cvv"], "123");
+    }
+
+    #[test]
+    fn test_mask_body_masks_card_numbers_under_any_key() {
+        let masked = mask_body(r#"{"metadata":["4242 4242 4242 4242"]}"#);
+        assert_eq!(masked["metadata"][0], "424242**********");
+    }
+
+    #[test]
+    fn test_is_card_number() {
+        assert!(is_card_number("4242424242424242"));
+        assert!(is_card_number("4242-4242-4242-4242"));
+        assert!(!is_card_number("4242424242424241"));
+        assert!(!is_card_number("1681200000000"));
+        assert!(!is_card_number("pay_4242424242424242"));
+    }
+
+    #[test]
+    fn test_mask_form() {
+        let masked = mask_form("amount=6540&card%5Bnumber%5D=4242424242424242&card%5Bcvc%5D=123");
+        assert_eq!(masked["amount"], "6540");
+        assert_eq!(masked["card[number]"], "424242**********");
+        assert_ne!(masked["card[cvc]"], "123");
+    }
+
+    #[test]
+    fn test_mask_text() {
+        assert_eq!(
+            mask_text(
+                "<card><number>4242424242424242</number><cvc>123</cvc><month>10</month></card>"
+            ),
+            "<card><number>****************</number><cvc>***</cvc><month>10</month></card>"
+        );
+    }
+}
@@ -5,7 +5,8 @@ use storage_models::errors as storage_errors;
 
 use crate::{
     core::{
-        errors::{self, RouterResponse, StorageErrorExt},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        mandate,
         payment_methods::cards,
     },
     db::StorageInterface,
@@ -15,7 +16,9 @@ use crate::{
     types::{
         api::customers::{self, CustomerRequestExt},
         storage::{self, enums},
+        transformers::ForeignInto,
     },
+    utils,
 };
 
 pub const REDACTED: &str = "Redacted";
@@ -116,24 +119,53 @@ pub async fn delete_customer(
 ) -> RouterResponse<customers::CustomerDeleteResponse> {
     let db = &state.store;
 
-    db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
         .await
         .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
+    redact_customer(state, &merchant_account, customer).await?;
+
+    let response = customers::CustomerDeleteResponse {
+        customer_id: req.customer_id,
+        customer_deleted: true,
+        address_deleted: true,
+        payment_methods_deleted: true,
+    };
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Revokes the mandates of the customer, removes their saved payment methods from the locker and
+/// redacts their details, leaving the customer in place as a tombstone.
+#[instrument(skip_all)]
+pub async fn redact_customer(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer: storage::Customer,
+) -> RouterResult<()> {
+    let db = &state.store;
+
     let customer_mandates = db
-        .find_mandate_by_merchant_id_customer_id(&merchant_account.merchant_id, &req.customer_id)
+        .find_mandate_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            &customer.customer_id,
+        )
         .await
         .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
-    for mandate in customer_mandates.into_iter() {
-        if mandate.mandate_status == enums::MandateStatus::Active {
-            Err(errors::ApiErrorResponse::MandateActive)?
+    // Mandates are revoked before the payment methods they were set up with are removed, so that
+    // the customer cannot be charged through them anymore
+    for customer_mandate in customer_mandates.into_iter() {
+        if customer_mandate.can_transition_to(enums::MandateStatus::Revoked) {
+            mandate::revoke_mandate_with_connector(state, merchant_account, customer_mandate)
+                .await
+                .attach_printable("Failed while revoking the mandates of the customer")?;
         }
     }
 
     match db
         .find_payment_method_by_customer_id_merchant_id_list(
-            &req.customer_id,
+            &customer.customer_id,
             &merchant_account.merchant_id,
         )
         .await
@@ -179,7 +211,7 @@ pub async fn delete_customer(
 
     match db
         .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
+            &customer.customer_id,
             &merchant_account.merchant_id,
             update_address,
         )
@@ -195,6 +227,18 @@ pub async fn delete_customer(
         },
     }?;
 
+    if customer.default_payment_method_id.is_some() {
+        db.update_customer_by_customer_id_merchant_id(
+            customer.customer_id.clone(),
+            merchant_account.merchant_id.clone(),
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
+    }
+
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(REDACTED.to_string()),
         email: Some(REDACTED.to_string().into()),
@@ -204,20 +248,14 @@ pub async fn delete_customer(
         metadata: None,
     };
     db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
+        customer.customer_id,
+        merchant_account.merchant_id.clone(),
         updated_customer,
     )
     .await
     .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = customers::CustomerDeleteResponse {
-        customer_id: req.customer_id,
-        customer_deleted: true,
-        address_deleted: true,
-        payment_methods_deleted: true,
-    };
-    Ok(services::ApplicationResponse::Json(response))
+    Ok(())
 }
 
 #[instrument(skip(db))]
@@ -289,3 +327,47 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+#[instrument(skip(db))]
+pub async fn set_default_payment_method(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerPaymentMethodId,
+) -> RouterResponse<customers::CustomerDefaultPaymentMethodResponse> {
+    db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let payment_method = db
+        .find_payment_method(&req.payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    // Payment methods of other customers are reported as not found, so as to not reveal them
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id
+            || payment_method.customer_id != req.customer_id,
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    let customer = db
+        .update_customer_by_customer_id_merchant_id(
+            req.customer_id,
+            merchant_account.merchant_id,
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: Some(payment_method.payment_method_id.clone()),
+            },
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerDefaultPaymentMethodResponse {
+            customer_id: customer.customer_id,
+            default_payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method.foreign_into(),
+        },
+    ))
+}
@@ -0,0 +1,182 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt};
+use crate::{
+    core::{payments, utils as core_utils},
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<api_models::disputes::DisputeResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })
+        })?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_disputes_list(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api_models::disputes::DisputeListConstraints,
+) -> RouterResponse<Vec<api_models::disputes::DisputeResponse>> {
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?;
+    let disputes_list = disputes
+        .into_iter()
+        .map(api_models::disputes::DisputeResponse::foreign_from)
+        .collect();
+    Ok(services::ApplicationResponse::Json(disputes_list))
+}
+
+#[instrument(skip(state))]
+pub async fn submit_evidence(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: disputes::SubmitEvidenceRequest,
+) -> RouterResponse<api_models::disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute_id = req.dispute_id.clone();
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.clone(),
+            })
+        })?;
+
+    utils::when(
+        dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened,
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "Evidence cannot be submitted for a dispute with status {}",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let submit_evidence_request_data = types::SubmitEvidenceRequestData {
+        dispute_id: dispute.dispute_id.clone(),
+        connector_dispute_id: dispute.connector_dispute_id.clone(),
+        access_activity_log: req.access_activity_log,
+        billing_address: req.billing_address,
+        cancellation_policy_disclosure: req.cancellation_policy_disclosure,
+        cancellation_rebuttal: req.cancellation_rebuttal,
+        customer_email_address: req.customer_email_address,
+        customer_name: req.customer_name,
+        customer_purchase_ip: req.customer_purchase_ip,
+        product_description: req.product_description,
+        refund_policy_disclosure: req.refund_policy_disclosure,
+        refund_refusal_explanation: req.refund_refusal_explanation,
+        service_date: req.service_date,
+        shipping_address: req.shipping_address,
+        shipping_carrier: req.shipping_carrier,
+        shipping_date: req.shipping_date,
+        shipping_tracking_number: req.shipping_tracking_number,
+        uncategorized_text: req.uncategorized_text,
+    };
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_submit_evidence_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute,
+        submit_evidence_request_data,
+    )
+    .await?;
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+    .attach_printable("Failed while calling submit evidence connector api")?;
+
+    let submit_evidence_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: dispute.connector.clone(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    let update_dispute = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: submit_evidence_response.dispute_status,
+        connector_status: submit_evidence_response.connector_status,
+    };
+    let updated_dispute = db
+        .update_dispute(dispute.clone(), update_dispute)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })
+        })
+        .attach_printable_lazy(|| {
+            format!(
+                "Unable to update dispute with dispute_id: {}",
+                dispute.dispute_id
+            )
+        })?;
+    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
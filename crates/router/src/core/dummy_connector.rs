@@ -0,0 +1,570 @@
+//! In-process mock processor backing the `dummy` connector.
+//!
+//! The outcome of a payment is scripted through the card number used to create it, so that
+//! integration tests, and merchants in test mode, can exercise the payment core deterministically
+//! without reaching out to a connector sandbox. Payments of merchants in test mode can also be
+//! scripted through the amount.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use masking::{PeekInterface, Secret};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{consts, utils};
+
+/// Card number which results in a successful payment. Any card number not listed below behaves
+/// the same way.
+pub const SUCCESS_CARD_NUMBER: &str = "4111111111111111";
+/// Card number which results in a soft decline that can be retried with another connector.
+pub const SOFT_DECLINE_CARD_NUMBER: &str = "4000000000000002";
+/// Card number which requires the customer to complete a 3DS challenge.
+pub const THREE_DS_CARD_NUMBER: &str = "4000000000003220";
+/// Card number for which the processor does not respond before the request times out.
+pub const TIMEOUT_CARD_NUMBER: &str = "4000000000009995";
+/// Card number which results in a hard decline for insufficient funds.
+pub const INSUFFICIENT_FUNDS_CARD_NUMBER: &str = "4000000000000341";
+
+/// Amounts which script the outcome of payments of merchants in test mode made with any other card
+/// number, in the lowest denomination of the currency. They are odd amounts past ten thousand in
+/// the main denomination, so that they do not collide with ordinary prices.
+pub const SOFT_DECLINE_AMOUNT: i64 = 1_000_001;
+pub const INSUFFICIENT_FUNDS_AMOUNT: i64 = 1_000_003;
+pub const THREE_DS_AMOUNT: i64 = 1_000_005;
+pub const TIMEOUT_AMOUNT: i64 = 1_000_007;
+
+/// Longest time a payment is held for to simulate a timeout, whatever the timeout of the client
+const MAX_TIMEOUT_DELAY: Duration = Duration::from_secs(consts::REQUEST_TIME_OUT + 1);
+
+static PAYMENTS: Lazy<Mutex<HashMap<String, DummyConnectorPaymentData>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static REFUNDS: Lazy<Mutex<HashMap<String, DummyConnectorRefundResponse>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DummyConnectorOutcome {
+    Success,
+    SoftDecline,
+    InsufficientFunds,
+    ThreeDs,
+    Timeout,
+}
+
+impl DummyConnectorOutcome {
+    pub fn from_card_number(card_number: &str) -> Self {
+        match card_number {
+            SOFT_DECLINE_CARD_NUMBER => Self::SoftDecline,
+            INSUFFICIENT_FUNDS_CARD_NUMBER => Self::InsufficientFunds,
+            THREE_DS_CARD_NUMBER => Self::ThreeDs,
+            TIMEOUT_CARD_NUMBER => Self::Timeout,
+            _ => Self::Success,
+        }
+    }
+
+    pub fn from_amount(amount: i64) -> Self {
+        match amount {
+            SOFT_DECLINE_AMOUNT => Self::SoftDecline,
+            INSUFFICIENT_FUNDS_AMOUNT => Self::InsufficientFunds,
+            THREE_DS_AMOUNT => Self::ThreeDs,
+            TIMEOUT_AMOUNT => Self::Timeout,
+            _ => Self::Success,
+        }
+    }
+
+    /// The outcome scripted by the card number, or, in test mode, by the amount when the card
+    /// number does not script one.
+    pub fn from_payment(card_number: &str, amount: i64, test_mode: bool) -> Self {
+        match Self::from_card_number(card_number) {
+            Self::Success if test_mode => Self::from_amount(amount),
+            outcome => outcome,
+        }
+    }
+}
+
+/// A payment simulating a timeout is held just past the timeout of the client, so that the client
+/// gives up first
+fn get_timeout_delay(client_timeout: Duration) -> Duration {
+    (client_timeout + Duration::from_secs(1)).min(MAX_TIMEOUT_DELAY)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorPaymentStatus {
+    Succeeded,
+    Authorized,
+    Processing,
+    Failed,
+    Voided,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorRefundStatus {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorCard {
+    pub number: Secret<String, common_utils::pii::CardNumber>,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cvc: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorPaymentRequest {
+    pub amount: i64,
+    pub currency: String,
+    pub card: DummyConnectorCard,
+    pub captured: bool,
+    pub return_url: Option<String>,
+    /// Whether the payment is simulated for a merchant in test mode
+    #[serde(default)]
+    pub test_mode: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorNextAction {
+    pub redirect_to_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorPaymentResponse {
+    pub id: String,
+    pub status: DummyConnectorPaymentStatus,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub currency: String,
+    pub next_action: Option<DummyConnectorNextAction>,
+}
+
+#[derive(Debug, Clone)]
+struct DummyConnectorPaymentData {
+    response: DummyConnectorPaymentResponse,
+    captured: bool,
+    return_url: Option<String>,
+    amount_refunded: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount_to_capture: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorRefundRequest {
+    pub payment_id: String,
+    pub amount: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorRefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorRefundStatus,
+    pub amount: i64,
+    pub currency: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorErrorResponse {
+    pub error: DummyConnectorErrorDetails,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorErrorDetails {
+    pub code: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum DummyConnectorError {
+    SoftDecline,
+    InsufficientFunds,
+    PaymentNotFound,
+    RefundNotFound,
+    InvalidStatus(DummyConnectorPaymentStatus),
+    InvalidAmount,
+}
+
+impl DummyConnectorError {
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::SoftDecline | Self::InsufficientFunds => 402,
+            Self::PaymentNotFound | Self::RefundNotFound => 404,
+            Self::InvalidStatus(_) | Self::InvalidAmount => 400,
+        }
+    }
+
+    pub fn to_response(&self) -> DummyConnectorErrorResponse {
+        let (code, message) = match self {
+            Self::SoftDecline => (
+                "card_declined_temporarily",
+                "The card was declined, try again later".to_string(),
+            ),
+            Self::InsufficientFunds => (
+                "insufficient_funds",
+                "The card has insufficient funds to complete the payment".to_string(),
+            ),
+            Self::PaymentNotFound => ("payment_not_found", "No such payment".to_string()),
+            Self::RefundNotFound => ("refund_not_found", "No such refund".to_string()),
+            Self::InvalidStatus(status) => (
+                "invalid_payment_status",
+                format!("Operation not allowed for a payment in status {status:?}"),
+            ),
+            Self::InvalidAmount => (
+                "invalid_amount",
+                "Amount exceeds the amount available on the payment".to_string(),
+            ),
+        };
+        DummyConnectorErrorResponse {
+            error: DummyConnectorErrorDetails { code, message },
+        }
+    }
+}
+
+type DummyConnectorResult<T> = Result<T, DummyConnectorError>;
+
+pub async fn payment(
+    base_url: &str,
+    client_timeout: Duration,
+    req: DummyConnectorPaymentRequest,
+) -> DummyConnectorResult<DummyConnectorPaymentResponse> {
+    let outcome =
+        DummyConnectorOutcome::from_payment(req.card.number.peek(), req.amount, req.test_mode);
+    let payment_id = utils::generate_id(consts::ID_LENGTH, "dummy_pay");
+    let (status, next_action) = match outcome {
+        DummyConnectorOutcome::SoftDecline => return Err(DummyConnectorError::SoftDecline),
+        DummyConnectorOutcome::InsufficientFunds => {
+            return Err(DummyConnectorError::InsufficientFunds)
+        }
+        DummyConnectorOutcome::Timeout => {
+            tokio::time::sleep(get_timeout_delay(client_timeout)).await;
+            (DummyConnectorPaymentStatus::Failed, None)
+        }
+        DummyConnectorOutcome::ThreeDs => (
+            DummyConnectorPaymentStatus::Processing,
+            Some(DummyConnectorNextAction {
+                redirect_to_url: format!("{base_url}authorize/{payment_id}"),
+            }),
+        ),
+        DummyConnectorOutcome::Success if req.captured => {
+            (DummyConnectorPaymentStatus::Succeeded, None)
+        }
+        DummyConnectorOutcome::Success => (DummyConnectorPaymentStatus::Authorized, None),
+    };
+    let amount_captured = if status == DummyConnectorPaymentStatus::Succeeded {
+        req.amount
+    } else {
+        0
+    };
+    let response = DummyConnectorPaymentResponse {
+        id: payment_id.clone(),
+        status,
+        amount: req.amount,
+        amount_captured,
+        currency: req.currency,
+        next_action,
+    };
+    insert_payment(
+        payment_id,
+        DummyConnectorPaymentData {
+            response: response.clone(),
+            captured: req.captured,
+            return_url: req.return_url,
+            amount_refunded: 0,
+        },
+    );
+    Ok(response)
+}
+
+pub fn payment_sync(payment_id: &str) -> DummyConnectorResult<DummyConnectorPaymentResponse> {
+    get_payment(payment_id).map(|payment| payment.response)
+}
+
+pub fn payment_capture(
+    payment_id: &str,
+    req: DummyConnectorCaptureRequest,
+) -> DummyConnectorResult<DummyConnectorPaymentResponse> {
+    update_payment(payment_id, |payment| {
+        let status = payment.response.status;
+        if status != DummyConnectorPaymentStatus::Authorized {
+            return Err(DummyConnectorError::InvalidStatus(status));
+        }
+        let amount_to_capture = req.amount_to_capture.unwrap_or(payment.response.amount);
+        if amount_to_capture > payment.response.amount {
+            return Err(DummyConnectorError::InvalidAmount);
+        }
+        payment.response.status = DummyConnectorPaymentStatus::Succeeded;
+        payment.response.amount_captured = amount_to_capture;
+        Ok(())
+    })
+}
+
+pub fn payment_void(payment_id: &str) -> DummyConnectorResult<DummyConnectorPaymentResponse> {
+    update_payment(payment_id, |payment| {
+        let status = payment.response.status;
+        if status != DummyConnectorPaymentStatus::Authorized {
+            return Err(DummyConnectorError::InvalidStatus(status));
+        }
+        payment.response.status = DummyConnectorPaymentStatus::Voided;
+        Ok(())
+    })
+}
+
+/// Completes the 3DS challenge of a payment and returns the URL the customer should be sent back
+/// to.
+pub fn payment_complete(payment_id: &str, confirm: bool) -> DummyConnectorResult<Option<String>> {
+    let mut return_url = None;
+    update_payment(payment_id, |payment| {
+        let status = payment.response.status;
+        if status != DummyConnectorPaymentStatus::Processing {
+            return Err(DummyConnectorError::InvalidStatus(status));
+        }
+        payment.response.next_action = None;
+        payment.response.status = match (confirm, payment.captured) {
+            (false, _) => DummyConnectorPaymentStatus::Failed,
+            (true, true) => {
+                payment.response.amount_captured = payment.response.amount;
+                DummyConnectorPaymentStatus::Succeeded
+            }
+            (true, false) => DummyConnectorPaymentStatus::Authorized,
+        };
+        return_url = payment.return_url.clone();
+        Ok(())
+    })?;
+    Ok(return_url)
+}
+
+pub fn refund(
+    req: DummyConnectorRefundRequest,
+) -> DummyConnectorResult<DummyConnectorRefundResponse> {
+    let mut currency = String::new();
+    update_payment(&req.payment_id, |payment| {
+        let status = payment.response.status;
+        if status != DummyConnectorPaymentStatus::Succeeded {
+            return Err(DummyConnectorError::InvalidStatus(status));
+        }
+        if payment.amount_refunded + req.amount > payment.response.amount_captured {
+            return Err(DummyConnectorError::InvalidAmount);
+        }
+        payment.amount_refunded += req.amount;
+        currency = payment.response.currency.clone();
+        Ok(())
+    })?;
+
+    let refund_id = utils::generate_id(consts::ID_LENGTH, "dummy_ref");
+    let response = DummyConnectorRefundResponse {
+        id: refund_id.clone(),
+        payment_id: req.payment_id,
+        status: DummyConnectorRefundStatus::Succeeded,
+        amount: req.amount,
+        currency,
+    };
+    REFUNDS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(refund_id, response.clone());
+    Ok(response)
+}
+
+pub fn refund_sync(refund_id: &str) -> DummyConnectorResult<DummyConnectorRefundResponse> {
+    REFUNDS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(refund_id)
+        .cloned()
+        .ok_or(DummyConnectorError::RefundNotFound)
+}
+
+fn insert_payment(payment_id: String, payment: DummyConnectorPaymentData) {
+    PAYMENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(payment_id, payment);
+}
+
+fn get_payment(payment_id: &str) -> DummyConnectorResult<DummyConnectorPaymentData> {
+    PAYMENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(payment_id)
+        .cloned()
+        .ok_or(DummyConnectorError::PaymentNotFound)
+}
+
+fn update_payment<F>(
+    payment_id: &str,
+    update: F,
+) -> DummyConnectorResult<DummyConnectorPaymentResponse>
+where
+    F: FnOnce(&mut DummyConnectorPaymentData) -> DummyConnectorResult<()>,
+{
+    let mut payments = PAYMENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let payment = payments
+        .get_mut(payment_id)
+        .ok_or(DummyConnectorError::PaymentNotFound)?;
+    update(payment)?;
+    Ok(payment.response.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const CLIENT_TIMEOUT: Duration = Duration::from_secs(1);
+
+    fn payment_request(card_number: &str, captured: bool) -> DummyConnectorPaymentRequest {
+        DummyConnectorPaymentRequest {
+            amount: 100,
+            currency: "USD".to_string(),
+            card: DummyConnectorCard {
+                number: Secret::new(card_number.to_string()),
+                expiry_month: Secret::new("12".to_string()),
+                expiry_year: Secret::new("2030".to_string()),
+                cvc: Secret::new("123".to_string()),
+            },
+            captured,
+            return_url: Some("https://example.com/return".to_string()),
+            test_mode: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manual_capture_and_refund() {
+        let payment = payment(
+            "http://localhost/",
+            CLIENT_TIMEOUT,
+            payment_request(SUCCESS_CARD_NUMBER, false),
+        )
+        .await
+        .unwrap();
+        assert_eq!(payment.status, DummyConnectorPaymentStatus::Authorized);
+
+        let captured = payment_capture(
+            &payment.id,
+            DummyConnectorCaptureRequest {
+                amount_to_capture: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(captured.status, DummyConnectorPaymentStatus::Succeeded);
+        assert_eq!(captured.amount_captured, 100);
+
+        let refund = refund(DummyConnectorRefundRequest {
+            payment_id: payment.id.clone(),
+            amount: 100,
+        })
+        .unwrap();
+        assert_eq!(refund_sync(&refund.id).unwrap().amount, 100);
+        assert!(matches!(
+            super::refund(DummyConnectorRefundRequest {
+                payment_id: payment.id,
+                amount: 1,
+            }),
+            Err(DummyConnectorError::InvalidAmount)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_soft_decline() {
+        let result = payment(
+            "http://localhost/",
+            CLIENT_TIMEOUT,
+            payment_request(SOFT_DECLINE_CARD_NUMBER, true),
+        )
+        .await;
+        assert!(matches!(result, Err(DummyConnectorError::SoftDecline)));
+    }
+
+    #[tokio::test]
+    async fn test_outcome_scripted_by_card_number() {
+        for test_mode in [false, true] {
+            let mut request = payment_request(INSUFFICIENT_FUNDS_CARD_NUMBER, true);
+            request.test_mode = test_mode;
+            let result = payment("http://localhost/", CLIENT_TIMEOUT, request).await;
+            assert!(matches!(
+                result,
+                Err(DummyConnectorError::InsufficientFunds)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_outcome_scripted_by_amount_in_test_mode() {
+        let mut request = payment_request(SUCCESS_CARD_NUMBER, true);
+        request.amount = INSUFFICIENT_FUNDS_AMOUNT;
+        request.test_mode = true;
+        let result = payment("http://localhost/", CLIENT_TIMEOUT, request).await;
+        assert!(matches!(
+            result,
+            Err(DummyConnectorError::InsufficientFunds)
+        ));
+
+        // The card number takes precedence over the amount
+        let mut request = payment_request(SOFT_DECLINE_CARD_NUMBER, true);
+        request.amount = THREE_DS_AMOUNT;
+        request.test_mode = true;
+        let result = payment("http://localhost/", CLIENT_TIMEOUT, request).await;
+        assert!(matches!(result, Err(DummyConnectorError::SoftDecline)));
+    }
+
+    #[tokio::test]
+    async fn test_outcome_not_scripted_by_amount_outside_test_mode() {
+        let mut request = payment_request(SUCCESS_CARD_NUMBER, true);
+        request.amount = INSUFFICIENT_FUNDS_AMOUNT;
+        let payment = payment("http://localhost/", CLIENT_TIMEOUT, request)
+            .await
+            .unwrap();
+        assert_eq!(payment.status, DummyConnectorPaymentStatus::Succeeded);
+
+        // Ordinary prices never script an outcome, even in test mode
+        let mut request = payment_request(SUCCESS_CARD_NUMBER, true);
+        request.amount = 4995;
+        request.test_mode = true;
+        let payment = super::payment("http://localhost/", CLIENT_TIMEOUT, request)
+            .await
+            .unwrap();
+        assert_eq!(payment.status, DummyConnectorPaymentStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_timeout_delay() {
+        assert_eq!(
+            get_timeout_delay(Duration::from_secs(5)),
+            Duration::from_secs(6)
+        );
+        assert_eq!(
+            get_timeout_delay(Duration::from_secs(600)),
+            MAX_TIMEOUT_DELAY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_three_ds_redirect() {
+        let payment = payment(
+            "http://localhost/",
+            CLIENT_TIMEOUT,
+            payment_request(THREE_DS_CARD_NUMBER, true),
+        )
+        .await
+        .unwrap();
+        assert_eq!(payment.status, DummyConnectorPaymentStatus::Processing);
+        assert_eq!(
+            payment.next_action.unwrap().redirect_to_url,
+            format!("http://localhost/authorize/{}", payment.id)
+        );
+
+        let return_url = payment_complete(&payment.id, true).unwrap();
+        assert_eq!(return_url.as_deref(), Some("https://example.com/return"));
+        assert_eq!(
+            payment_sync(&payment.id).unwrap().status,
+            DummyConnectorPaymentStatus::Succeeded
+        );
+    }
+}
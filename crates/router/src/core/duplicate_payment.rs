@@ -0,0 +1,185 @@
+//! Detection of payments likely duplicating an earlier payment of the merchant, confirmed with the
+//! same card for the same amount within the window of the duplicate payment policy of the
+//! merchant. The latest payment of each card and amount is recorded in redis for the window.
+//! Duplicates are flagged on the payment attempt or declined without reaching the connector,
+//! unless the client confirms them with `allow_duplicate`.
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+
+use super::{
+    blocklist,
+    errors::{self, RouterResult, StorageErrorExt},
+    payments::{helpers, PaymentData},
+};
+use crate::{
+    logger,
+    routes::AppState,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub fn get_duplicate_payment_policy(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::DuplicatePaymentPolicy>> {
+    merchant_account
+        .duplicate_payment_policy
+        .clone()
+        .map(|policy| policy.parse_value("DuplicatePaymentPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the duplicate payment policy of the merchant")
+}
+
+/// Checks whether the payment is likely a duplicate of an earlier payment, flagging it on the
+/// payment attempt and failing it when the policy of the merchant blocks duplicates. Returns
+/// whether the payment can go on to the connector.
+pub async fn screen_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let policy = match get_duplicate_payment_policy(merchant_account)? {
+        Some(policy) => policy,
+        None => return Ok(true),
+    };
+    let card_fingerprint = match &payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => {
+            blocklist::generate_card_fingerprint(state, card.card_number.peek())?
+        }
+        _ => return Ok(true),
+    };
+
+    let key = format!(
+        "duplicate_payment_{}_{card_fingerprint}_{}_{}",
+        merchant_account.merchant_id, payment_data.payment_attempt.amount, payment_data.currency
+    );
+    let payment_id = payment_data.payment_intent.payment_id.clone();
+
+    // Payments confirmed as duplicates by the client are recorded in place of the earlier
+    // payment, so that their own duplicates are detected
+    if payment_data.allow_duplicate {
+        if let Err(error) = state
+            .store
+            .record_payment(&key, &payment_id, policy.window_in_secs)
+            .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to record the payment for duplicate detection"
+            );
+        }
+        return Ok(true);
+    }
+
+    // Duplicates are not detected while redis is unavailable, rather than failing every card
+    // payment
+    let earlier_payment_id = match state
+        .store
+        .record_payment_unless_recorded(&key, &payment_id, policy.window_in_secs)
+        .await
+    {
+        Ok(Some(earlier_payment_id)) => earlier_payment_id,
+        Ok(None) => return Ok(true),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to record the payment for duplicate detection"
+            );
+            return Ok(true);
+        }
+    };
+
+    let storage_scheme = merchant_account.storage_scheme;
+    if !is_duplicated_by(
+        state,
+        &earlier_payment_id,
+        &merchant_account.merchant_id,
+        storage_scheme,
+    )
+    .await?
+    {
+        if let Err(error) = state
+            .store
+            .record_payment(&key, &payment_id, policy.window_in_secs)
+            .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to record the payment for duplicate detection"
+            );
+        }
+        return Ok(true);
+    }
+
+    logger::info!(
+        %payment_id,
+        duplicate_of = %earlier_payment_id,
+        action = %policy.action,
+        "Payment is likely a duplicate"
+    );
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::DuplicatePaymentUpdate {
+                duplicate_of: earlier_payment_id.clone(),
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    match policy.action {
+        api_enums::DuplicatePaymentAction::Warn => Ok(true),
+        api_enums::DuplicatePaymentAction::Block => {
+            helpers::fail_payment_before_connector(
+                state,
+                storage_scheme,
+                payment_data,
+                storage_enums::AttemptStatus::RouterDeclined,
+                None,
+                format!("Payment is likely a duplicate of payment {earlier_payment_id}"),
+                storage_enums::UnifiedCode::DuplicateTransaction,
+            )
+            .await?;
+            Ok(false)
+        }
+    }
+}
+
+/// Whether the earlier payment still stands, so that a payment with its card and amount duplicates
+/// it. Payments which failed, were cancelled or never went through are retried rather than
+/// duplicated.
+async fn is_duplicated_by(
+    state: &AppState,
+    earlier_payment_id: &str,
+    merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<bool> {
+    let earlier_payment = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            earlier_payment_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await;
+
+    match earlier_payment {
+        Ok(payment_intent) => Ok(!matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::Failed
+                | storage_enums::IntentStatus::Cancelled
+                | storage_enums::IntentStatus::RequiresPaymentMethod
+                | storage_enums::IntentStatus::RequiresConfirmation
+        )),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding the earlier payment of the card and amount"),
+    }
+}
@@ -73,6 +73,10 @@ pub enum StorageError {
     DeserializationFailed,
     #[error("Received Error RedisError: {0}")]
     ERedisError(error_stack::Report<RedisError>),
+    #[error("Failed to encrypt data before storing it")]
+    EncryptionError,
+    #[error("Failed to decrypt stored data")]
+    DecryptionError,
 }
 
 impl From<error_stack::Report<RedisError>> for StorageError {
@@ -196,6 +200,8 @@ pub enum ApiClientError {
     UrlEncodingFailed,
     #[error("Failed to send request to connector {0}")]
     RequestNotSent(String),
+    #[error("Connection to connector failed before a response was received {0}")]
+    ConnectionClosed(String),
     #[error("Failed to decode response")]
     ResponseDecodingFailed,
 
@@ -346,6 +352,8 @@ pub enum ProcessTrackerError {
     EParsingError(error_stack::Report<ParsingError>),
     #[error("Validation Error Received: {0}")]
     EValidationError(error_stack::Report<ValidationError>),
+    #[error("Received Error WebhooksFlowError: {0}")]
+    EWebhooksFlowError(error_stack::Report<WebhooksFlowError>),
 }
 
 macro_rules! error_to_process_tracker_error {
@@ -391,6 +399,11 @@ error_to_process_tracker_error!(
     ProcessTrackerError::EValidationError(error_stack::Report<ValidationError>)
 );
 
+error_to_process_tracker_error!(
+    error_stack::Report<WebhooksFlowError>,
+    ProcessTrackerError::EWebhooksFlowError(error_stack::Report<WebhooksFlowError>)
+);
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
@@ -403,6 +416,10 @@ pub enum WebhooksFlowError {
     PaymentsCoreFailed,
     #[error("Refunds core flow failed")]
     RefundsCoreFailed,
+    #[error("Dispute core flow failed")]
+    DisputeCoreFailed,
+    #[error("Mandate core flow failed")]
+    MandateCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
@@ -413,6 +430,22 @@ pub enum WebhooksFlowError {
     NotReceivedByMerchant,
     #[error("Resource not found")]
     ResourceNotFound,
+    #[error("Webhook source verification failed")]
+    WebhookSourceVerificationFailed,
+    #[error("Outgoing webhook body encoding failed")]
+    OutgoingWebhookEncodingFailed,
+    #[error("Outgoing webhook signing failed")]
+    OutgoingWebhookSigningFailed,
+    #[error("Failed to record outgoing webhook delivery attempt")]
+    DeliveryAttemptRecordingFailed,
+    #[error("Failed to schedule outgoing webhook retry")]
+    RetrySchedulingFailed,
+    #[error("Incoming webhook resource object encoding failed")]
+    IncomingWebhookEncodingFailed,
+    #[error("Failed to schedule incoming webhook processing")]
+    IncomingWebhookSchedulingFailed,
+    #[error("Unsupported flow type received in incoming webhooks")]
+    UnsupportedFlow,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -83,6 +83,12 @@ pub enum ApiErrorResponse {
     GenericUnauthorized { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_19", message = "{message}")]
     NotSupported { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "Access forbidden, the API key or user is not permitted to perform this operation")]
+    AccessForbidden,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_21", message = "Invalid email or password")]
+    InvalidCredentials,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_22", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: u64 },
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -106,6 +112,12 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Verification failed while processing with connector. Retry operation")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Payout failed while processing with connector. Retry payout")]
+    PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Dispute operation failed while processing with connector. Retry operation")]
+    DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_10", message = "Mandate revocation failed while processing with connector. Retry operation")]
+    MandateRevokeFailed { data: Option<serde_json::Value> },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
@@ -119,10 +131,36 @@ pub enum ApiErrorResponse {
     DuplicateMerchantConnectorAccount,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment method with the specified details already exists in our records")]
     DuplicatePaymentMethod,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The user already has a role for the merchant account")]
+    DuplicateUserRole,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A user with the specified email already exists in our records")]
+    DuplicateUser,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A business profile with the specified name already exists for the merchant account")]
+    DuplicateBusinessProfile,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The data is already blocked for the merchant account")]
+    DuplicateBlocklistEntry,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund batch does not exist in our records")]
+    RefundBatchNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Credit does not exist in our records")]
+    CreditNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Reconciliation report does not exist in our records")]
+    ReconReportNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Blocklist entry does not exist in our records")]
+    BlocklistEntryNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dispute does not exist in our records")]
+    DisputeNotFound { dispute_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
+    EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Routing configuration does not exist in our records")]
+    RoutingConfigNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
     CustomerNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Config key does not exist in our records.")]
@@ -141,6 +179,12 @@ pub enum ApiErrorResponse {
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "User role does not exist in our records")]
+    UserRoleNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Organization does not exist in our records")]
+    OrganizationNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Business profile does not exist in our records")]
+    BusinessProfileNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
@@ -149,6 +193,10 @@ pub enum ApiErrorResponse {
     MandateValidationFailed { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The dispute is not in a state that allows this operation: {reason}")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The mandate is not in a state that allows this operation: {reason}")]
+    MandateStatusValidationFailed { reason: String },
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
     SuccessfulPaymentNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "The connector provided in the request is incorrect or not available")]
@@ -193,7 +241,10 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::Unauthorized
             | Self::InvalidEphemeralKey
             | Self::InvalidJwtToken
-            | Self::GenericUnauthorized { .. } => StatusCode::UNAUTHORIZED, // 401
+            | Self::GenericUnauthorized { .. }
+            | Self::InvalidCredentials => StatusCode::UNAUTHORIZED, // 401
+            Self::AccessForbidden => StatusCode::FORBIDDEN, // 403
+            Self::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS, // 429
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -216,13 +267,28 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::CardExpired { .. }
             | Self::RefundFailed { .. }
             | Self::RefundNotPossible { .. }
+            | Self::PayoutFailed { .. }
+            | Self::DisputeFailed { .. }
+            | Self::MandateRevokeFailed { .. }
             | Self::VerificationFailed { .. }
             | Self::PaymentUnexpectedState { .. }
-            | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            | Self::MandateValidationFailed { .. }
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::MandateStatusValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
+            | Self::RefundBatchNotFound
+            | Self::CreditNotFound
+            | Self::ReconReportNotFound
+            | Self::BlocklistEntryNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::EventNotFound
+            | Self::RoutingConfigNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
             | Self::CustomerRedacted
@@ -239,24 +305,35 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::ConfigNotFound
             | Self::AddressNotFound
             | Self::NotSupported { .. }
-            | Self::ApiKeyNotFound => StatusCode::BAD_REQUEST, // 400
+            | Self::ApiKeyNotFound
+            | Self::UserRoleNotFound
+            | Self::OrganizationNotFound
+            | Self::BusinessProfileNotFound => StatusCode::BAD_REQUEST, // 400
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
-            | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
-            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
-            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
+            | Self::DuplicateMandate
+            | Self::DuplicateUserRole
+            | Self::DuplicateUser
+            | Self::DuplicateBusinessProfile
+            | Self::DuplicateBlocklistEntry => StatusCode::BAD_REQUEST, // 400
+            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,  // 503
+            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,           // 400
+            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,     // 501
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response
             .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .insert_header((header::VIA, "Juspay_Router"))
-            .body(self.to_string())
+            .insert_header((header::VIA, "Juspay_Router"));
+        if let Self::TooManyRequests { retry_after } = self {
+            response.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -340,6 +417,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::GenericUnauthorized { message } => {
                 AER::Unauthorized(ApiError::new("IR", 18, message.to_string(), None))
             }
+            Self::AccessForbidden => AER::ForbiddenPrivateResource(ApiError::new("IR", 20, "Access forbidden, the API key or user is not permitted to perform this operation", None)),
+            Self::InvalidCredentials => AER::Unauthorized(ApiError::new("IR", 21, "Invalid email or password", None)),
+            Self::TooManyRequests { retry_after } => AER::TooManyRequests(ApiError::new("IR", 22, format!("Too many requests, retry after {retry_after} seconds"), None), *retry_after),
             Self::ExternalConnectorError {
                 code,
                 message,
@@ -362,6 +442,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::VerificationFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 7, "Verification failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::PayoutFailed { data } => AER::BadRequest(ApiError::new("CE", 8, "Payout failed while processing with connector. Retry payout", Some(Extra { data: data.clone(), ..Default::default()}))),
+            Self::DisputeFailed { data } => AER::BadRequest(ApiError::new("CE", 9, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()}))),
+            Self::MandateRevokeFailed { data } => AER::BadRequest(ApiError::new("CE", 10, "Mandate revocation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()}))),
             Self::InternalServerError => {
                 AER::InternalServerError(ApiError::new("HE", 0, "Something went wrong", None))
             }
@@ -372,12 +455,43 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 AER::BadRequest(ApiError::new("HE", 1, "The merchant connector account with the specified details already exists in our records", None))
             }
             Self::DuplicatePaymentMethod => AER::BadRequest(ApiError::new("HE", 1, "The payment method with the specified details already exists in our records", None)),
+            Self::DuplicateUserRole => AER::BadRequest(ApiError::new("HE", 1, "The user already has a role for the merchant account", None)),
+            Self::DuplicateUser => AER::BadRequest(ApiError::new("HE", 1, "A user with the specified email already exists in our records", None)),
+            Self::DuplicateBusinessProfile => AER::BadRequest(ApiError::new("HE", 1, "A business profile with the specified name already exists for the merchant account", None)),
+            Self::DuplicateBlocklistEntry => AER::BadRequest(ApiError::new("HE", 1, "The data is already blocked for the merchant account", None)),
             Self::DuplicatePayment { payment_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, format!("The payment with the specified payment_id '{payment_id}' already exists in our records"), None))
             }
+            Self::DuplicatePayout { payout_id } => {
+                AER::BadRequest(ApiError::new("HE", 1, format!("The payout with the specified payout_id '{payout_id}' already exists in our records"), None))
+            }
             Self::RefundNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Refund does not exist in our records.", None))
             }
+            Self::RefundBatchNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Refund batch does not exist in our records", None))
+            }
+            Self::CreditNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Credit does not exist in our records", None))
+            }
+            Self::ReconReportNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Reconciliation report does not exist in our records", None))
+            }
+            Self::BlocklistEntryNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Blocklist entry does not exist in our records", None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
+            }
+            Self::DisputeNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Dispute does not exist in our records", None))
+            }
+            Self::EventNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
+            }
+            Self::RoutingConfigNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Routing configuration does not exist in our records", None))
+            }
             Self::CustomerNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Customer does not exist in our records", None))
             }
@@ -410,6 +524,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 AER::BadRequest(ApiError::new("HE", 3, "Mandate Validation Failed", Some(Extra { reason: Some(reason.clone()), ..Default::default() })))
             }
             Self::PaymentNotSucceeded => AER::BadRequest(ApiError::new("HE", 3, "The payment has not succeeded yet. Please pass a successful payment to initiate refund", None)),
+            Self::DisputeStatusValidationFailed { reason } => {
+                AER::BadRequest(ApiError::new("HE", 3, format!("The dispute is not in a state that allows this operation: {reason}"), None))
+            }
+            Self::MandateStatusValidationFailed { reason } => {
+                AER::BadRequest(ApiError::new("HE", 3, format!("The mandate is not in a state that allows this operation: {reason}"), None))
+            }
             Self::SuccessfulPaymentNotFound => {
                 AER::NotFound(ApiError::new("HE", 4, "Successful payment not found for the given payment id", None))
             }
@@ -422,6 +542,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::ApiKeyNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "API Key does not exist in our records", None))
             }
+            Self::UserRoleNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "User role does not exist in our records", None))
+            }
+            Self::OrganizationNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Organization does not exist in our records", None))
+            }
+            Self::BusinessProfileNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Business profile does not exist in our records", None))
+            }
             Self::NotSupported { message } => {
                 AER::BadRequest(ApiError::new("HE", 3, "Payment method type not supported", Some(Extra {reason: Some(message.to_owned()), ..Default::default()})))
             }
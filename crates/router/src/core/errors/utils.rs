@@ -50,6 +50,12 @@ pub trait ConnectorErrorExt {
     fn to_payment_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
     #[track_caller]
     fn to_verify_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    #[track_caller]
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    #[track_caller]
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    #[track_caller]
+    fn to_mandate_revoke_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
 }
 
 impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
@@ -133,6 +139,90 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
         };
         self.change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data })
     }
+
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let error = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let data = match std::str::from_utf8(bytes) {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                };
+                errors::ApiErrorResponse::PayoutFailed { data }
+            }
+            errors::ConnectorError::MissingRequiredField { field_name } => {
+                errors::ApiErrorResponse::MissingRequiredField { field_name }
+            }
+            errors::ConnectorError::NotImplemented(reason) => {
+                errors::ApiErrorResponse::NotImplemented {
+                    message: errors::api_error_response::NotImplementedMessage::Reason(
+                        reason.to_string(),
+                    ),
+                }
+            }
+            _ => errors::ApiErrorResponse::PayoutFailed { data: None },
+        };
+        self.change_context(error)
+    }
+
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let error = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let data = match std::str::from_utf8(bytes) {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                };
+                errors::ApiErrorResponse::DisputeFailed { data }
+            }
+            errors::ConnectorError::MissingRequiredField { field_name } => {
+                errors::ApiErrorResponse::MissingRequiredField { field_name }
+            }
+            errors::ConnectorError::NotImplemented(reason) => {
+                errors::ApiErrorResponse::NotImplemented {
+                    message: errors::api_error_response::NotImplementedMessage::Reason(
+                        reason.to_string(),
+                    ),
+                }
+            }
+            _ => errors::ApiErrorResponse::DisputeFailed { data: None },
+        };
+        self.change_context(error)
+    }
+
+    fn to_mandate_revoke_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let error = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let data = match std::str::from_utf8(bytes) {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                };
+                errors::ApiErrorResponse::MandateRevokeFailed { data }
+            }
+            errors::ConnectorError::NotImplemented(reason) => {
+                errors::ApiErrorResponse::NotImplemented {
+                    message: errors::api_error_response::NotImplementedMessage::Reason(
+                        reason.to_string(),
+                    ),
+                }
+            }
+            _ => errors::ApiErrorResponse::MandateRevokeFailed { data: None },
+        };
+        self.change_context(error)
+    }
 }
 
 pub trait RedisErrorExt {
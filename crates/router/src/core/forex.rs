@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use api_models::forex::{ForexProvider, ForexQuoteRequest, ForexQuoteResponse};
+use common_utils::ext_traits::Encode;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::ForexSettings,
+    core::errors::{self, RouterResponse, RouterResult},
+    headers,
+    routes::AppState,
+    services,
+    types::{api::enums as api_enums, storage},
+    utils::BytesExt,
+};
+
+/// Source of exchange rates between two currencies.
+#[async_trait::async_trait]
+pub trait ForexRateProvider: Send + Sync {
+    fn provider(&self) -> ForexProvider;
+
+    /// Returns the number of units of `to_currency` one unit of `from_currency` is worth.
+    async fn get_rate(
+        &self,
+        state: &AppState,
+        from_currency: api_enums::Currency,
+        to_currency: api_enums::Currency,
+    ) -> RouterResult<f64>;
+}
+
+/// Provides rates from the table in the application configuration, where each currency is
+/// valued against a common base currency.
+pub struct FixedRateProvider<'a> {
+    rates: &'a HashMap<api_enums::Currency, f64>,
+}
+
+#[async_trait::async_trait]
+impl ForexRateProvider for FixedRateProvider<'_> {
+    fn provider(&self) -> ForexProvider {
+        ForexProvider::Fixed
+    }
+
+    async fn get_rate(
+        &self,
+        _state: &AppState,
+        from_currency: api_enums::Currency,
+        to_currency: api_enums::Currency,
+    ) -> RouterResult<f64> {
+        let from_value = self.rates.get(&from_currency);
+        let to_value = self.rates.get(&to_currency);
+        match (from_value, to_value) {
+            (Some(from_value), Some(to_value)) if *from_value > 0.0 => Ok(to_value / from_value),
+            _ => Err(rate_unavailable(from_currency, to_currency)),
+        }
+    }
+}
+
+/// Provides rates fetched from an external exchange rates API.
+pub struct ExternalRateProvider<'a> {
+    base_url: &'a str,
+    api_key: &'a str,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExternalRatesResponse {
+    rates: HashMap<api_enums::Currency, f64>,
+}
+
+#[async_trait::async_trait]
+impl ForexRateProvider for ExternalRateProvider<'_> {
+    fn provider(&self) -> ForexProvider {
+        ForexProvider::External
+    }
+
+    async fn get_rate(
+        &self,
+        state: &AppState,
+        from_currency: api_enums::Currency,
+        to_currency: api_enums::Currency,
+    ) -> RouterResult<f64> {
+        let url = format!(
+            "{}latest?base={from_currency}&symbols={to_currency}",
+            self.base_url
+        );
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&url)
+            .headers(vec![(
+                headers::AUTHORIZATION.to_string(),
+                self.api_key.to_string(),
+            )])
+            .build();
+
+        let response = services::call_connector_api(state, request)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to call the exchange rates API")?
+            .map_err(|error| {
+                report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                    "Exchange rates API responded with status code {}",
+                    error.status_code
+                ))
+            })?;
+
+        let response: ExternalRatesResponse = response
+            .response
+            .parse_struct("ExternalRatesResponse")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize the exchange rates API response")?;
+
+        response
+            .rates
+            .get(&to_currency)
+            .copied()
+            .ok_or_else(|| rate_unavailable(from_currency, to_currency))
+    }
+}
+
+pub fn get_rate_provider(config: &ForexSettings) -> Box<dyn ForexRateProvider + '_> {
+    match config.provider {
+        ForexProvider::Fixed => Box::new(FixedRateProvider {
+            rates: &config.fixed_rates,
+        }),
+        ForexProvider::External => Box::new(ExternalRateProvider {
+            base_url: &config.base_url,
+            api_key: &config.api_key,
+        }),
+    }
+}
+
+fn rate_unavailable(
+    from_currency: api_enums::Currency,
+    to_currency: api_enums::Currency,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::NotSupported {
+        message: format!("Exchange rate from {from_currency} to {to_currency}"),
+    })
+}
+
+/// Number of decimal places the minor unit of the currency represents.
+pub(crate) fn currency_exponent(currency: api_enums::Currency) -> i32 {
+    match currency {
+        api_enums::Currency::JPY | api_enums::Currency::KRW => 0,
+        api_enums::Currency::BHD
+        | api_enums::Currency::JOD
+        | api_enums::Currency::KWD
+        | api_enums::Currency::OMR => 3,
+        _ => 2,
+    }
+}
+
+/// Converts an amount in the lowest denomination of `from_currency` into the lowest
+/// denomination of `to_currency`, rounding to the nearest unit.
+pub fn convert_amount(
+    amount: i64,
+    from_currency: api_enums::Currency,
+    to_currency: api_enums::Currency,
+    rate: f64,
+) -> RouterResult<i64> {
+    let exponent_difference = currency_exponent(to_currency) - currency_exponent(from_currency);
+    #[allow(clippy::as_conversions)]
+    let converted = (amount as f64 * rate * 10_f64.powi(exponent_difference)).round();
+    // Bounds are checked before the cast, which would otherwise saturate silently
+    #[allow(clippy::as_conversions)]
+    let in_range = converted.is_finite() && converted >= 0.0 && converted < i64::MAX as f64;
+    if in_range {
+        #[allow(clippy::as_conversions)]
+        Ok(converted as i64)
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount"
+        }))
+        .attach_printable("Converted amount is out of range")
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn get_quote(
+    state: &AppState,
+    amount: i64,
+    from_currency: api_enums::Currency,
+    to_currency: api_enums::Currency,
+) -> RouterResult<ForexQuoteResponse> {
+    let provider = get_rate_provider(&state.conf.forex);
+    let rate = if from_currency == to_currency {
+        1.0
+    } else {
+        provider.get_rate(state, from_currency, to_currency).await?
+    };
+
+    Ok(ForexQuoteResponse {
+        from_currency,
+        to_currency,
+        rate: rate.to_string(),
+        amount,
+        converted_amount: convert_amount(amount, from_currency, to_currency, rate)?,
+        provider: provider.provider(),
+        quoted_at: common_utils::date_time::now(),
+    })
+}
+
+pub async fn retrieve_forex_quote(
+    state: &AppState,
+    _merchant_account: storage::MerchantAccount,
+    req: ForexQuoteRequest,
+) -> RouterResponse<ForexQuoteResponse> {
+    let quote = get_quote(state, req.amount, req.from_currency, req.to_currency).await?;
+    Ok(services::ApplicationResponse::Json(quote))
+}
+
+/// Quotes the conversion of the payment amount into the settlement currency and encodes it to
+/// be stored with the payment intent.
+pub async fn make_payment_forex_quote(
+    state: &AppState,
+    amount: i64,
+    currency: api_enums::Currency,
+    settlement_currency: Option<api_enums::Currency>,
+) -> RouterResult<Option<serde_json::Value>> {
+    let settlement_currency = match settlement_currency {
+        Some(settlement_currency) if settlement_currency != currency => settlement_currency,
+        _ => return Ok(None),
+    };
+
+    let quote = get_quote(state, amount, currency, settlement_currency).await?;
+    Encode::<ForexQuoteResponse>::encode_to_value(&quote)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode forex quote")
+        .map(Some)
+}
+
+pub fn get_payment_forex_quote(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<ForexQuoteResponse>> {
+    payment_intent
+        .forex_quote
+        .clone()
+        .map(|forex_quote| {
+            serde_json::from_value(forex_quote)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not decode forex quote")
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_convert_amount_across_exponents() {
+        let converted = convert_amount(
+            6540,
+            api_enums::Currency::EUR,
+            api_enums::Currency::USD,
+            1.0821,
+        )
+        .unwrap();
+        assert_eq!(converted, 7077);
+
+        let converted = convert_amount(
+            1000,
+            api_enums::Currency::USD,
+            api_enums::Currency::JPY,
+            149.5,
+        )
+        .unwrap();
+        assert_eq!(converted, 1495);
+
+        let converted = convert_amount(
+            1000,
+            api_enums::Currency::USD,
+            api_enums::Currency::KWD,
+            0.308,
+        )
+        .unwrap();
+        assert_eq!(converted, 3080);
+    }
+}
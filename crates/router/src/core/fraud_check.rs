@@ -0,0 +1,366 @@
+//! Screening of payments by the fraud check connector of the merchant, before or after they are
+//! authorized. The decision of the connector is stored on the payment attempt. Fraudulent payments
+//! are failed before they reach the payment connector, or have their authorization voided, while
+//! the decision on payments held for a review is received later through the webhooks of the fraud
+//! check connector.
+
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::{report, ResultExt};
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payments::{self, helpers, PaymentData},
+    utils,
+};
+use crate::{
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const FRAUD_CANCELLATION_REASON: &str = "fraud_suspected";
+
+pub fn get_fraud_check_policy(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::FraudCheckPolicy>> {
+    merchant_account
+        .fraud_check_policy
+        .clone()
+        .map(|policy| policy.parse_value("FraudCheckPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the fraud check policy of the merchant")
+}
+
+pub fn get_fraud_check(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<api::FraudCheckDetails>> {
+    payment_attempt
+        .fraud_check
+        .clone()
+        .map(|fraud_check| fraud_check.parse_value("FraudCheckDetails"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the fraud check of the payment")
+}
+
+/// Screens the payment with the fraud check connector of the merchant before it is authorized.
+/// Returns whether the payment can go on to be authorized at the connector.
+pub async fn screen_before_authorization<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let policy = match get_fraud_check_policy(merchant_account)? {
+        Some(policy) if policy.check_type == api_enums::FraudCheckType::PreAuth => policy,
+        _ => return Ok(true),
+    };
+    // Payments confirmed again, once the cardholder completes an authentication, were screened
+    // when they were first confirmed
+    if let Some(fraud_check) = get_fraud_check(&payment_data.payment_attempt)? {
+        return Ok(fraud_check.status != api_enums::FraudCheckStatus::Fraud);
+    }
+
+    let connector =
+        api::FraudCheckConnectorData::get_connector_by_name(&policy.connector.to_string())?;
+    let router_data = utils::construct_fraud_check_router_data::<api::FrmPreAuth, _>(
+        state,
+        &policy.connector.to_string(),
+        merchant_account,
+        payment_data,
+    )
+    .await?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::FrmPreAuth,
+        types::FraudCheckData,
+        types::FraudCheckResponseData,
+    > = connector.connector.get_connector_integration();
+    let decision = match get_decision(state, connector_integration, &router_data).await {
+        Some(decision) => decision,
+        None => return Ok(true),
+    };
+
+    let storage_scheme = merchant_account.storage_scheme;
+    let fraud_check = api::FraudCheckDetails {
+        frm_connector: policy.connector,
+        check_type: api_enums::FraudCheckType::PreAuth,
+        status: decision.status,
+        frm_reference_id: decision.frm_reference_id,
+        reason: decision.reason,
+    };
+    payment_data.payment_attempt = update_fraud_check(
+        state,
+        storage_scheme,
+        payment_data.payment_attempt.clone(),
+        &fraud_check,
+    )
+    .await?;
+
+    match fraud_check.status {
+        api_enums::FraudCheckStatus::Fraud => {
+            helpers::fail_payment_before_connector(
+                state,
+                storage_scheme,
+                payment_data,
+                storage_enums::AttemptStatus::RouterDeclined,
+                None,
+                fraud_check.reason.unwrap_or_else(|| {
+                    "Payment was declined by the fraud check of the merchant".to_string()
+                }),
+                storage_enums::UnifiedCode::FraudSuspected,
+            )
+            .await?;
+            Ok(false)
+        }
+        api_enums::FraudCheckStatus::ThreeDsRequired => {
+            if payment_data.payment_attempt.authentication_type
+                != Some(storage_enums::AuthenticationType::ThreeDs)
+            {
+                payment_data.payment_attempt = state
+                    .store
+                    .update_payment_attempt(
+                        payment_data.payment_attempt.clone(),
+                        storage::PaymentAttemptUpdate::AuthenticationTypeUpdate {
+                            authentication_type: storage_enums::AuthenticationType::ThreeDs,
+                        },
+                        storage_scheme,
+                    )
+                    .await
+                    .map_err(|error| {
+                        error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                    })?;
+            }
+            Ok(true)
+        }
+        api_enums::FraudCheckStatus::Legit
+        | api_enums::FraudCheckStatus::ManualReview
+        | api_enums::FraudCheckStatus::Pending => Ok(true),
+    }
+}
+
+/// Sends the payment for a review by the fraud check connector of the merchant once it is
+/// authorized, voiding the authorization right away when the connector finds it fraudulent.
+pub async fn screen_after_authorization<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let policy = match get_fraud_check_policy(merchant_account)? {
+        Some(policy) if policy.check_type == api_enums::FraudCheckType::PostAuth => policy,
+        _ => return Ok(()),
+    };
+    if !matches!(
+        payment_data.payment_attempt.status,
+        storage_enums::AttemptStatus::Authorized | storage_enums::AttemptStatus::Charged
+    ) || get_fraud_check(&payment_data.payment_attempt)?.is_some()
+    {
+        return Ok(());
+    }
+
+    let connector =
+        api::FraudCheckConnectorData::get_connector_by_name(&policy.connector.to_string())?;
+    let router_data = utils::construct_fraud_check_router_data::<api::FrmPostAuth, _>(
+        state,
+        &policy.connector.to_string(),
+        merchant_account,
+        payment_data,
+    )
+    .await?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::FrmPostAuth,
+        types::FraudCheckData,
+        types::FraudCheckResponseData,
+    > = connector.connector.get_connector_integration();
+    let decision = match get_decision(state, connector_integration, &router_data).await {
+        Some(decision) => decision,
+        None => return Ok(()),
+    };
+
+    let fraud_check = api::FraudCheckDetails {
+        frm_connector: policy.connector,
+        check_type: api_enums::FraudCheckType::PostAuth,
+        status: decision.status,
+        frm_reference_id: decision.frm_reference_id,
+        reason: decision.reason,
+    };
+    payment_data.payment_attempt = update_fraud_check(
+        state,
+        merchant_account.storage_scheme,
+        payment_data.payment_attempt.clone(),
+        &fraud_check,
+    )
+    .await?;
+
+    if fraud_check.status == api_enums::FraudCheckStatus::Fraud
+        && helpers::void_authorized_payment(
+            state,
+            merchant_account,
+            &payment_data.payment_attempt,
+            FRAUD_CANCELLATION_REASON,
+        )
+        .await?
+    {
+        helpers::reload_payment(state, merchant_account, payment_data).await?;
+    }
+
+    Ok(())
+}
+
+/// Records the decision of a fraud check connector on a payment it held for a review. The source
+/// of the webhook has to be verified, as fraudulent payments are voided on the decision.
+pub async fn frm_webhooks_core(
+    state: &AppState,
+    req: &actix_web::HttpRequest,
+    merchant_account: storage::MerchantAccount,
+    connector_name: &str,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let connector = api::FraudCheckConnectorData::get_connector_by_name(connector_name)?;
+    let request_details = api::IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri(),
+        headers: req.headers(),
+        body: &body,
+    };
+
+    let source_verified = connector
+        .connector
+        .verify_webhook_source(db, &request_details, &merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("There was an issue in incoming webhook source verification")?;
+    if !source_verified {
+        return Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "The source of the webhook could not be verified".to_string(),
+        }));
+    }
+
+    let event_type = connector
+        .connector
+        .get_webhook_event_type(&request_details)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find event type in incoming webhook body")?;
+    if !matches!(
+        api::WebhookFlow::from(event_type),
+        api::WebhookFlow::FraudCheck
+    ) {
+        return Ok(services::ApplicationResponse::StatusOk);
+    }
+
+    let attempt_id = connector
+        .connector
+        .get_webhook_object_reference_id(&request_details)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find object reference id in incoming webhook body")?;
+    let decision: types::FraudCheckResponseData = connector
+        .connector
+        .get_webhook_resource_object(&request_details)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find resource object in incoming webhook body")?
+        .parse_value("FraudCheckResponseData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &attempt_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    let previous_fraud_check = get_fraud_check(&payment_attempt)?;
+    let check_type = match previous_fraud_check {
+        Some(ref fraud_check) => fraud_check.check_type,
+        None => get_fraud_check_policy(&merchant_account)?
+            .map(|policy| policy.check_type)
+            .unwrap_or_default(),
+    };
+    let fraud_check = api::FraudCheckDetails {
+        frm_connector: connector.connector_name,
+        check_type,
+        status: decision.status,
+        frm_reference_id: decision
+            .frm_reference_id
+            .or_else(|| previous_fraud_check.and_then(|fraud_check| fraud_check.frm_reference_id)),
+        reason: decision.reason,
+    };
+    let payment_attempt =
+        update_fraud_check(state, storage_scheme, payment_attempt, &fraud_check).await?;
+
+    if fraud_check.status == api_enums::FraudCheckStatus::Fraud {
+        helpers::void_authorized_payment(
+            state,
+            &merchant_account,
+            &payment_attempt,
+            FRAUD_CANCELLATION_REASON,
+        )
+        .await?;
+    }
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Calls the fraud check connector. A fraud check connector which can't be reached doesn't block
+/// payments, they go on without a decision.
+async fn get_decision<T>(
+    state: &AppState,
+    connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        T,
+        types::FraudCheckData,
+        types::FraudCheckResponseData,
+    >,
+    router_data: &types::FraudCheckRouterData<T>,
+) -> Option<types::FraudCheckResponseData>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    match response.map(|router_data| router_data.response) {
+        Ok(Ok(decision)) => Some(decision),
+        Ok(Err(error_response)) => {
+            logger::error!(fraud_check_error_response=?error_response);
+            None
+        }
+        Err(error) => {
+            logger::error!(fraud_check_error=?error);
+            None
+        }
+    }
+}
+
+async fn update_fraud_check(
+    state: &AppState,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_attempt: storage::PaymentAttempt,
+    fraud_check: &api::FraudCheckDetails,
+) -> RouterResult<storage::PaymentAttempt> {
+    let fraud_check = Encode::<api::FraudCheckDetails>::encode_to_value(fraud_check)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the fraud check of the payment")?;
+    state
+        .store
+        .update_payment_attempt(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::FraudCheckUpdate { fraud_check },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+}
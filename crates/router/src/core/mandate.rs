@@ -4,14 +4,16 @@ use storage_models::enums as storage_enums;
 
 use super::payments::helpers;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+        payments, utils as core_utils,
+    },
     routes::AppState,
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         storage,
@@ -35,23 +37,20 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
-    let mandate = db
-        .update_mandate_by_merchant_id_mandate_id(
-            &merchant_account.merchant_id,
-            &req.mandate_id,
-            storage::MandateUpdate::StatusUpdate {
-                mandate_status: storage::enums::MandateStatus::Revoked,
-            },
-        )
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
+    let mandate = revoke_mandate_with_connector(state, &merchant_account, mandate).await?;
+
     Ok(services::ApplicationResponse::Json(
         mandates::MandateRevokedResponse {
             mandate_id: mandate.mandate_id,
@@ -60,6 +59,78 @@ pub async fn revoke_mandate(
     ))
 }
 
+/// Revokes the mandate at the connector it was set up with, and records the resulting status.
+pub async fn revoke_mandate_with_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) -> errors::RouterResult<storage::Mandate> {
+    if !mandate.can_transition_to(storage_enums::MandateStatus::Revoked) {
+        Err(errors::ApiErrorResponse::MandateStatusValidationFailed {
+            reason: format!(
+                "mandate with status {} cannot be revoked",
+                mandate.mandate_status
+            ),
+        })?
+    }
+
+    // Mandates without a connector reference are only known to us, so there is nothing
+    // to cancel at the connector
+    let mandate_status = match mandate.connector_mandate_id.clone() {
+        Some(connector_mandate_id) => {
+            let connector_data = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &mandate.connector,
+                api::GetToken::Connector,
+            )?;
+            let connector_integration: services::BoxedConnectorIntegration<
+                '_,
+                api::MandateRevoke,
+                types::MandateRevokeRequestData,
+                types::MandateRevokeResponseData,
+            > = connector_data.connector.get_connector_integration();
+            let router_data = core_utils::construct_mandate_revoke_router_data(
+                state,
+                merchant_account,
+                &mandate,
+                connector_mandate_id,
+            )
+            .await?;
+            let response = services::execute_connector_processing_step(
+                state,
+                connector_integration,
+                &router_data,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await
+            .map_err(|error| error.to_mandate_revoke_failed_response())
+            .attach_printable("Failed while calling mandate revoke connector api")?;
+
+            response
+                .response
+                .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                    code: err.code,
+                    message: err.message,
+                    connector: mandate.connector.clone(),
+                    status_code: err.status_code,
+                    reason: err.reason,
+                })?
+                .mandate_status
+        }
+        None => storage_enums::MandateStatus::Revoked,
+    };
+
+    state
+        .store
+        .update_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::StatusUpdate { mandate_status },
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))
+}
+
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: &AppState,
@@ -139,6 +210,15 @@ where
             resp.payment_method_id = Some(mandate.payment_method_id);
         }
         None => {
+            let (mandate_reference, network_txn_id) = match resp.response.as_ref().ok() {
+                Some(types::PaymentsResponseData::TransactionResponse {
+                    mandate_reference,
+                    network_txn_id,
+                    ..
+                }) => (mandate_reference.clone(), network_txn_id.clone()),
+                _ => (None, None),
+            };
+
             if resp.request.get_setup_mandate_details().is_some() {
                 let payment_method_id = helpers::call_payment_method(
                     state,
@@ -151,13 +231,8 @@ where
                 .payment_method_id;
 
                 resp.payment_method_id = Some(payment_method_id.clone());
-                let mandate_reference = match resp.response.as_ref().ok() {
-                    Some(types::PaymentsResponseData::TransactionResponse {
-                        mandate_reference,
-                        ..
-                    }) => mandate_reference.clone(),
-                    _ => None,
-                };
+                save_network_transaction_id(state, &payment_method_id, network_txn_id.clone())
+                    .await;
 
                 if let Some(new_mandate_data) = helpers::generate_mandate(
                     resp.merchant_id.clone(),
@@ -166,12 +241,14 @@ where
                     maybe_customer,
                     payment_method_id,
                     mandate_reference,
+                    network_txn_id,
                 ) {
                     logger::error!("{:?}", new_mandate_data);
                     resp.request
                         .set_mandate_id(api_models::payments::MandateIds {
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
+                            network_transaction_id: new_mandate_data.network_transaction_id.clone(),
                         });
                     state
                         .store
@@ -183,15 +260,20 @@ where
                             )
                         })?;
                 };
-            } else if resp.request.get_setup_future_usage().is_some() {
-                helpers::call_payment_method(
+            } else if let Some(setup_future_usage) = resp.request.get_setup_future_usage() {
+                let payment_method_id = helpers::call_payment_method(
                     state,
                     merchant_account,
                     Some(&resp.request.get_payment_method_data()),
                     Some(resp.payment_method),
                     maybe_customer,
                 )
-                .await?;
+                .await?
+                .payment_method_id;
+
+                if setup_future_usage == storage_enums::FutureUsage::OffSession {
+                    save_network_transaction_id(state, &payment_method_id, network_txn_id).await;
+                }
             }
         }
     }
@@ -199,6 +281,40 @@ where
     Ok(resp)
 }
 
+/// Records the network transaction id of the customer-initiated transaction a card is saved with
+/// on the saved payment method, so that off-session payments on it can be made as
+/// merchant-initiated transactions at any connector. Failing to record it does not fail the
+/// payment, which has already been made.
+async fn save_network_transaction_id(
+    state: &AppState,
+    payment_method_id: &str,
+    network_txn_id: Option<String>,
+) {
+    if network_txn_id.is_none() {
+        return;
+    }
+
+    let db = &*state.store;
+    let result = match db.find_payment_method(payment_method_id).await {
+        Ok(payment_method) => {
+            db.update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::NetworkTransactionIdUpdate {
+                    network_transaction_id: network_txn_id,
+                },
+            )
+            .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to save the network transaction id of the payment method"
+        );
+    }
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<storage_models::enums::FutureUsage>;
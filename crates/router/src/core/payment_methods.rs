@@ -1,3 +1,5 @@
 pub mod cards;
+#[cfg(feature = "internal_locker")]
+pub mod internal_locker;
 pub mod transformers;
 pub mod vault;
@@ -17,6 +17,7 @@ use router_env::{instrument, tracing};
 use crate::{
     configs::settings,
     core::{
+        cards_info,
         errors::{self, StorageErrorExt},
         payment_methods::{transformers as payment_methods, vault},
         payments::helpers,
@@ -25,11 +26,11 @@ use crate::{
     pii::prelude::*,
     routes, services,
     types::{
-        api::{self, CreatePaymentMethodExt},
+        api::{self, ConnectorSpecifications, CreatePaymentMethodExt},
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::{self, BytesExt, ConnectorResponseExt, OptionExt},
+    utils::{self, OptionExt},
 };
 
 #[instrument(skip_all)]
@@ -39,6 +40,7 @@ pub async fn create_payment_method(
     customer_id: &str,
     payment_method_id: &str,
     merchant_id: &str,
+    card_info: Option<&cards_info::CardInfo>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::StorageError> {
     let response = db
         .insert_payment_method(storage::PaymentMethodNew {
@@ -48,7 +50,16 @@ pub async fn create_payment_method(
             payment_method: req.payment_method.foreign_into(),
             payment_method_type: req.payment_method_type.map(ForeignInto::foreign_into),
             payment_method_issuer: req.payment_method_issuer.clone(),
-            scheme: req.card_network.clone(),
+            scheme: req.card_network.clone().or_else(|| {
+                card_info
+                    .and_then(|card_info| card_info.card_network.as_ref())
+                    .map(ToString::to_string)
+            }),
+            issuer_name: card_info.and_then(|card_info| card_info.card_issuer.clone()),
+            issuer_country: card_info.and_then(|card_info| card_info.card_issuing_country.clone()),
+            card_type: card_info
+                .and_then(|card_info| card_info.card_type)
+                .map(|card_type| card_type.to_string()),
             metadata: req.metadata.clone(),
             ..storage::PaymentMethodNew::default()
         })
@@ -79,6 +90,7 @@ pub async fn add_payment_method(
                 &customer_id,
                 &payment_method_id,
                 merchant_id,
+                None,
             )
             .await
             .map_err(|error| {
@@ -155,58 +167,103 @@ pub async fn add_card(
         .get_required_value("locker_id")
         .change_context(errors::VaultError::SaveCardFailed)?;
 
-    let request = payment_methods::mk_add_card_request(
-        locker,
-        &card,
-        &customer_id,
-        &req,
-        &locker_id,
-        merchant_id,
-    )?;
-
     let response = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
-            .await
-            .change_context(errors::VaultError::SaveCardFailed)?;
-
-        let response: payment_methods::AddCardResponse = match response {
-            Ok(card) => card
-                .response
-                .parse_struct("AddCardResponse")
-                .change_context(errors::VaultError::ResponseDeserializationFailed),
-            Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
-                err.response
-            ))),
-        }?;
-        response
+        add_card_to_locker(state, &card, &customer_id, &req, &locker_id, merchant_id).await?
     } else {
         let card_id = generate_id(consts::ID_LENGTH, "card");
         mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
     };
 
+    let card_info = cards_info::get_card_info(state, card.card_number.peek()).await;
+
     if let Some(false) = response.duplicate {
-        create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-            .await
-            .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+        create_payment_method(
+            db,
+            &req,
+            &customer_id,
+            &response.card_id,
+            merchant_id,
+            card_info.as_ref(),
+        )
+        .await
+        .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
     } else {
         match db.find_payment_method(&response.card_id).await {
             Ok(_) => (),
             Err(err) => {
                 if err.current_context().is_db_not_found() {
-                    create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-                        .await
-                        .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+                    create_payment_method(
+                        db,
+                        &req,
+                        &customer_id,
+                        &response.card_id,
+                        merchant_id,
+                        card_info.as_ref(),
+                    )
+                    .await
+                    .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
                 } else {
                     Err(errors::VaultError::PaymentMethodCreationFailed)?;
                 }
             }
         }
     }
-    let payment_method_resp =
-        payment_methods::mk_add_card_response(card, response, req, merchant_id);
+    let payment_method_resp = payment_methods::mk_add_card_response(
+        card,
+        response,
+        req,
+        merchant_id,
+        card_info.unwrap_or_default(),
+    );
     Ok(payment_method_resp)
 }
 
+#[cfg(not(feature = "internal_locker"))]
+async fn add_card_to_locker(
+    state: &routes::AppState,
+    card: &api::CardDetail,
+    customer_id: &str,
+    req: &api::CreatePaymentMethod,
+    locker_id: &str,
+    merchant_id: &str,
+) -> errors::CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    use crate::utils::BytesExt;
+
+    let request = payment_methods::mk_add_card_request(
+        &state.conf.locker,
+        card,
+        customer_id,
+        req,
+        locker_id,
+        merchant_id,
+    )?;
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+    match response {
+        Ok(card) => card
+            .response
+            .parse_struct("AddCardResponse")
+            .change_context(errors::VaultError::ResponseDeserializationFailed),
+        Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
+            err.response
+        ))),
+    }
+}
+
+#[cfg(feature = "internal_locker")]
+async fn add_card_to_locker(
+    state: &routes::AppState,
+    card: &api::CardDetail,
+    customer_id: &str,
+    _req: &api::CreatePaymentMethod,
+    _locker_id: &str,
+    merchant_id: &str,
+) -> errors::CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    super::internal_locker::add_card(state, card, customer_id, merchant_id).await
+}
+
 #[instrument(skip_all)]
 pub async fn mock_add_card(
     db: &dyn db::StorageInterface,
@@ -307,16 +364,8 @@ pub async fn get_card_from_legacy_locker<'a>(
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::GetCardResponse> {
     let locker = &state.conf.locker;
-    let request = payment_methods::mk_get_card_request(locker, locker_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making get card request failed")?;
     let get_card_result = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while executing call_connector_api for get_card");
-
-        response.get_response_inner("AddCardResponse")?
+        get_card_from_locker(state, locker_id, card_id).await?
     } else {
         let (get_card_response, _) = mock_get_card(&*state.store, card_id)
             .await
@@ -335,25 +384,76 @@ pub async fn delete_card<'a>(
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
     let locker = &state.conf.locker;
-    let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making Delete card request Failed")?;
-
-    let card_delete_failure_message = "Failed while deleting card from card_locker";
     let delete_card_resp = if !locker.mock_locker {
-        services::call_connector_api(state, request)
-            .await
-            .get_response_inner("DeleteCardResponse")?
+        delete_card_from_locker(state, merchant_id, card_id).await?
     } else {
         mock_delete_card(&*state.store, card_id)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(card_delete_failure_message)?
+            .attach_printable("Failed while deleting card from card_locker")?
     };
 
     Ok(delete_card_resp)
 }
 
+#[cfg(not(feature = "internal_locker"))]
+async fn get_card_from_locker(
+    state: &routes::AppState,
+    locker_id: &str,
+    card_id: &str,
+) -> errors::RouterResult<payment_methods::GetCardResponse> {
+    use crate::utils::ConnectorResponseExt;
+
+    let request = payment_methods::mk_get_card_request(&state.conf.locker, locker_id, card_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making get card request failed")?;
+    services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while executing call_connector_api for get_card")
+        .get_response_inner("AddCardResponse")
+}
+
+#[cfg(feature = "internal_locker")]
+async fn get_card_from_locker(
+    state: &routes::AppState,
+    _locker_id: &str,
+    card_id: &str,
+) -> errors::RouterResult<payment_methods::GetCardResponse> {
+    super::internal_locker::get_card(state, card_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching card from internal locker")
+}
+
+#[cfg(not(feature = "internal_locker"))]
+async fn delete_card_from_locker(
+    state: &routes::AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
+    use crate::utils::ConnectorResponseExt;
+
+    let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making Delete card request Failed")?;
+    services::call_connector_api(state, request)
+        .await
+        .get_response_inner("DeleteCardResponse")
+}
+
+#[cfg(feature = "internal_locker")]
+async fn delete_card_from_locker(
+    state: &routes::AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
+    super::internal_locker::delete_card(state, merchant_id, card_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while deleting card from internal locker")
+}
+
 pub fn get_banks(
     state: &routes::AppState,
     pm_type: api_enums::PaymentMethodType,
@@ -363,7 +463,11 @@ pub fn get_banks(
 
     if matches!(
         pm_type,
-        api_enums::PaymentMethodType::Giropay | api_enums::PaymentMethodType::Sofort
+        api_enums::PaymentMethodType::Giropay
+            | api_enums::PaymentMethodType::Sofort
+            | api_enums::PaymentMethodType::Blik
+            | api_enums::PaymentMethodType::Trustly
+            | api_enums::PaymentMethodType::Przelewy24
     ) {
         Ok(vec![BankCodeResponse {
             bank_name: vec![],
@@ -495,6 +599,7 @@ pub async fn list_payment_methods(
             address.as_ref(),
             mca.connector_name,
             pm_config_mapping,
+            &state.conf.connectors,
         )
         .await?;
     }
@@ -701,7 +806,11 @@ async fn filter_payment_methods(
     address: Option<&storage::Address>,
     connector: String,
     config: &settings::ConnectorFilters,
+    connectors: &settings::Connectors,
 ) -> errors::CustomResult<(), errors::ApiErrorResponse> {
+    let connector_data =
+        api::ConnectorData::get_connector_by_name(connectors, &connector, api::GetToken::Connector)
+            .ok();
     for payment_method in payment_methods.into_iter() {
         let parse_result = serde_json::from_value::<PaymentMethodsEnabled>(payment_method);
         if let Ok(payment_methods_enabled) = parse_result {
@@ -765,6 +874,21 @@ async fn filter_payment_methods(
                             .map(|value| value.foreign_into()),
                     );
 
+                    let filter6 = connector_data.as_ref().map_or(true, |connector_data| {
+                        filter_pm_based_on_specifications(
+                            *connector_data.connector,
+                            payment_method,
+                            &address.and_then(|inner| inner.country.clone()),
+                            payment_attempt
+                                .and_then(|value| value.currency)
+                                .map(|value| value.foreign_into()),
+                            payment_attempt
+                                .and_then(|value| value.capture_method)
+                                .map(|value| value.foreign_into()),
+                            req,
+                        )
+                    });
+
                     let connector = connector.clone();
 
                     let response_pm_type = ResponsePaymentMethodIntermediate::new(
@@ -773,7 +897,7 @@ async fn filter_payment_methods(
                         payment_method,
                     );
 
-                    if filter && filter2 && filter3 && filter4 && filter5 {
+                    if filter && filter2 && filter3 && filter4 && filter5 && filter6 {
                         resp.push(response_pm_type);
                     }
                 }
@@ -809,6 +933,57 @@ fn filter_pm_based_on_config<'a>(
         .unwrap_or(true)
 }
 
+/// Checks whether the connector is capable of processing the payment method in the given country
+/// and currency with the given capture method, as declared in its specifications. When the payment
+/// does not specify a country or currency, the connector is considered eligible if it supports any
+/// of the countries or currencies the request asks for.
+fn filter_pm_based_on_specifications(
+    connector: &(dyn api::Connector + Sync),
+    payment_method: api_enums::PaymentMethod,
+    country: &Option<String>,
+    currency: Option<api_enums::Currency>,
+    capture_method: Option<api_enums::CaptureMethod>,
+    req: &api::ListPaymentMethodRequest,
+) -> bool {
+    let payment_method_condition = connector
+        .supported_payment_methods()
+        .map_or(true, |supported| supported.contains(&payment_method));
+
+    let is_supported_country = |supported: &[&str], country: &str| {
+        supported
+            .iter()
+            .any(|supported_country| supported_country.eq_ignore_ascii_case(country))
+    };
+    let country_condition = match (connector.supported_countries(), country) {
+        (Some(supported), Some(country)) => is_supported_country(supported, country),
+        (Some(supported), None) => req.accepted_countries.as_ref().map_or(true, |countries| {
+            countries
+                .iter()
+                .any(|country| is_supported_country(supported, country))
+        }),
+        (None, _) => true,
+    };
+
+    let currency_condition = match (connector.supported_currencies(), currency) {
+        (Some(supported), Some(currency)) => supported.contains(&currency),
+        (Some(supported), None) => req.accepted_currencies.as_ref().map_or(true, |currencies| {
+            currencies
+                .iter()
+                .any(|currency| supported.contains(currency))
+        }),
+        (None, _) => true,
+    };
+
+    let capture_method_condition = connector
+        .supported_capture_methods()
+        .zip(capture_method)
+        .map_or(true, |(supported, capture_method)| {
+            supported.contains(&capture_method)
+        });
+
+    payment_method_condition && country_condition && currency_condition && capture_method_condition
+}
+
 fn card_network_filter(
     country: &Option<String>,
     currency: Option<api_enums::Currency>,
@@ -1086,6 +1261,12 @@ pub async fn list_customer_payment_method(
 ) -> errors::RouterResponse<api::ListCustomerPaymentMethodsResponse> {
     let db = &*state.store;
 
+    let default_payment_method_id = db
+        .find_customer_by_customer_id_merchant_id(customer_id, &merchant_account.merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?
+        .default_payment_method_id;
+
     let resp = db
         .find_payment_method_by_customer_id_merchant_id_list(
             customer_id,
@@ -1114,8 +1295,11 @@ pub async fn list_customer_payment_method(
             None
         };
         //Need validation for enabled payment method ,querying MCA
+        let default_payment_method_set =
+            default_payment_method_id.as_ref() == Some(&pm.payment_method_id);
         let pma = api::CustomerPaymentMethod {
             payment_token: payment_token.to_string(),
+            payment_method_id: pm.payment_method_id,
             customer_id: pm.customer_id,
             payment_method: pm.payment_method.foreign_into(),
             payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
@@ -1129,6 +1313,7 @@ pub async fn list_customer_payment_method(
             installment_payment_enabled: false,
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             created: Some(pm.created_at),
+            default_payment_method_set,
         };
         customer_pms.push(pma);
     }
@@ -1392,6 +1577,29 @@ pub async fn delete_payment_method(
             error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
 
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(&pm.customer_id, &merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the customer of the payment method")?;
+    if customer.default_payment_method_id.as_ref() == Some(&pm.payment_method_id) {
+        state
+            .store
+            .update_customer_by_customer_id_merchant_id(
+                customer.customer_id,
+                customer.merchant_id,
+                storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                    default_payment_method_id: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed while unsetting the default payment method of the customer",
+            )?;
+    }
+
     if pm.payment_method == enums::PaymentMethod::Card {
         let response = delete_card(state, &pm.merchant_id, &payment_method_id).await?;
         if response.status == "success" {
@@ -0,0 +1,284 @@
+//! Card locker backed by the application database, exposing the same interface as the external
+//! locker.
+//!
+//! Card details are encrypted with a data encryption key (DEK) generated for each merchant. The
+//! DEKs are stored wrapped by the key encryption key (KEK) configured for the locker, which is
+//! KMS encrypted when the `kms` feature is enabled.
+
+use common_utils::{
+    consts,
+    crypto::{HmacSha256, SignMessage},
+    generate_id,
+    pii::CardNumber,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::transformers as payment_methods,
+    },
+    routes,
+    services::encryption,
+    types::{api, storage},
+};
+
+const DATA_ENCRYPTION_KEY_LENGTH: usize = 32;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct LockerCardData {
+    card_number: Secret<String, CardNumber>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    name_on_card: Option<Secret<String>>,
+}
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: &routes::AppState,
+    card: &api::CardDetail,
+    customer_id: &str,
+    merchant_id: &str,
+) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    let db = &*state.store;
+    let key_encryption_key = get_key_encryption_key(state).await?;
+    let data_encryption_key =
+        get_or_create_data_encryption_key(state, merchant_id, &key_encryption_key).await?;
+
+    let card_number = card.card_number.peek().as_bytes();
+    let card_fingerprint = get_fingerprint(&data_encryption_key, card_number)?;
+
+    match db
+        .find_locker_card_by_merchant_id_customer_id_card_fingerprint(
+            merchant_id,
+            customer_id,
+            &card_fingerprint,
+        )
+        .await
+    {
+        Ok(locker_card) => {
+            return get_add_card_response(locker_card, &data_encryption_key, Some(true));
+        }
+        Err(err) if err.current_context().is_db_not_found() => (),
+        Err(err) => Err(err).change_context(errors::VaultError::SaveCardFailed)?,
+    }
+
+    let card_data = LockerCardData {
+        card_number: card.card_number.clone(),
+        card_exp_month: card.card_exp_month.clone(),
+        card_exp_year: card.card_exp_year.clone(),
+        name_on_card: card.card_holder_name.clone(),
+    };
+    let card_data = serde_json::to_string(&card_data)
+        .into_report()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+    let locker_card = storage::LockerCardNew {
+        card_id: generate_id(consts::ID_LENGTH, "card"),
+        external_id: uuid::Uuid::new_v4().to_string(),
+        merchant_id: merchant_id.to_string(),
+        customer_id: Some(customer_id.to_string()),
+        card_fingerprint,
+        card_global_fingerprint: get_fingerprint(&key_encryption_key, card_number)?,
+        card_data: encryption::encrypt(&card_data, &data_encryption_key)
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to encrypt card details")?,
+        nickname: None,
+    };
+
+    let locker_card = db
+        .insert_locker_card(locker_card)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+    get_add_card_response(locker_card, &data_encryption_key, Some(false))
+}
+
+#[instrument(skip_all)]
+pub async fn get_card(
+    state: &routes::AppState,
+    card_id: &str,
+) -> CustomResult<payment_methods::GetCardResponse, errors::VaultError> {
+    let locker_card = state
+        .store
+        .find_locker_card_by_card_id(card_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+
+    let key_encryption_key = get_key_encryption_key(state).await?;
+    let data_encryption_key =
+        get_or_create_data_encryption_key(state, &locker_card.merchant_id, &key_encryption_key)
+            .await?;
+
+    Ok(payment_methods::GetCardResponse {
+        card: get_add_card_response(locker_card, &data_encryption_key, None)?,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: &routes::AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::DeleteCardResponse, errors::VaultError> {
+    let locker_card = state
+        .store
+        .delete_locker_card_by_merchant_id_card_id(merchant_id, card_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+
+    Ok(payment_methods::DeleteCardResponse {
+        card_id: Some(locker_card.card_id),
+        external_id: Some(locker_card.external_id),
+        card_isin: None,
+        status: "SUCCESS".to_string(),
+    })
+}
+
+async fn get_key_encryption_key(
+    state: &routes::AppState,
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    let key_encryption_key = encryption::KeyHandler::get_kms_decrypted_key(
+        &state.conf.jwekey,
+        state.conf.locker.internal_locker_kek.clone(),
+    )
+    .await
+    .change_context(errors::VaultError::FetchCardFailed)
+    .attach_printable("Failed to decrypt the key encryption key of the internal locker")?;
+
+    hex::decode(key_encryption_key)
+        .into_report()
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("The key encryption key of the internal locker is not hex encoded")
+}
+
+/// Fetches the data encryption key of the merchant, generating one when the merchant does not
+/// have any cards stored yet.
+async fn get_or_create_data_encryption_key(
+    state: &routes::AppState,
+    merchant_id: &str,
+    key_encryption_key: &[u8],
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    let db = &*state.store;
+    let merchant_key_store = match db.find_merchant_key_store_by_merchant_id(merchant_id).await {
+        Ok(merchant_key_store) => merchant_key_store,
+        Err(err) if err.current_context().is_db_not_found() => {
+            let data_encryption_key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+            let merchant_key_store = storage::MerchantKeyStoreNew {
+                merchant_id: merchant_id.to_string(),
+                key: wrap_key(&data_encryption_key, key_encryption_key)?,
+            };
+
+            match db.insert_merchant_key_store(merchant_key_store).await {
+                Ok(_) => return Ok(data_encryption_key.to_vec()),
+                // A key has been generated concurrently for the same merchant
+                Err(err) if err.current_context().is_db_unique_violation() => db
+                    .find_merchant_key_store_by_merchant_id(merchant_id)
+                    .await
+                    .change_context(errors::VaultError::FetchCardFailed)?,
+                Err(err) => Err(err).change_context(errors::VaultError::SaveCardFailed)?,
+            }
+        }
+        Err(err) => Err(err).change_context(errors::VaultError::FetchCardFailed)?,
+    };
+
+    unwrap_key(merchant_key_store.key, key_encryption_key)
+}
+
+fn wrap_key(
+    data_encryption_key: &[u8],
+    key_encryption_key: &[u8],
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    encryption::encrypt(&hex::encode(data_encryption_key), key_encryption_key)
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to wrap the data encryption key")
+}
+
+fn unwrap_key(
+    wrapped_key: Vec<u8>,
+    key_encryption_key: &[u8],
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    let data_encryption_key = encryption::decrypt(wrapped_key, key_encryption_key)
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to unwrap the data encryption key")?;
+
+    hex::decode(data_encryption_key)
+        .into_report()
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("The unwrapped data encryption key is not hex encoded")
+}
+
+fn get_fingerprint(key: &[u8], card_number: &[u8]) -> CustomResult<String, errors::VaultError> {
+    HmacSha256
+        .sign_message(key, card_number)
+        .map(hex::encode)
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to calculate the card fingerprint")
+}
+
+fn get_add_card_response(
+    locker_card: storage::LockerCard,
+    data_encryption_key: &[u8],
+    duplicate: Option<bool>,
+) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    let card_data = encryption::decrypt(locker_card.card_data, data_encryption_key)
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to decrypt card details")?;
+    let card_data: LockerCardData = serde_json::from_str(&card_data)
+        .into_report()
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    Ok(payment_methods::AddCardResponse {
+        card_id: locker_card.card_id,
+        external_id: locker_card.external_id,
+        card_fingerprint: locker_card.card_fingerprint.into(),
+        card_global_fingerprint: locker_card.card_global_fingerprint.into(),
+        merchant_id: Some(locker_card.merchant_id),
+        card_number: Some(card_data.card_number),
+        card_exp_year: Some(card_data.card_exp_year),
+        card_exp_month: Some(card_data.card_exp_month),
+        name_on_card: card_data.name_on_card,
+        nickname: locker_card.nickname,
+        customer_id: locker_card.customer_id,
+        duplicate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_data_encryption_key_round_trip() {
+        let key_encryption_key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+        let data_encryption_key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+
+        let wrapped_key = wrap_key(&data_encryption_key, &key_encryption_key).unwrap();
+        assert_ne!(wrapped_key, data_encryption_key.to_vec());
+
+        let unwrapped_key = unwrap_key(wrapped_key.clone(), &key_encryption_key).unwrap();
+        assert_eq!(unwrapped_key, data_encryption_key.to_vec());
+
+        let other_key_encryption_key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+        assert!(unwrap_key(wrapped_key, &other_key_encryption_key).is_err());
+    }
+
+    #[test]
+    fn test_card_fingerprint_depends_on_key() {
+        let card_number = b"4111111111111111";
+        let key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+        let other_key: [u8; DATA_ENCRYPTION_KEY_LENGTH] = rand::random();
+
+        let fingerprint = get_fingerprint(&key, card_number).unwrap();
+        assert_eq!(fingerprint, get_fingerprint(&key, card_number).unwrap());
+        assert_ne!(
+            fingerprint,
+            get_fingerprint(&other_key, card_number).unwrap()
+        );
+        assert_eq!(fingerprint.len(), 64);
+    }
+}
@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     configs::settings::Locker,
-    core::errors::{self, CustomResult},
+    core::{
+        cards_info,
+        errors::{self, CustomResult},
+    },
     headers,
     pii::{self, prelude::*, Secret},
     services::api as services,
@@ -101,18 +104,25 @@ pub fn mk_add_card_response(
     response: AddCardResponse,
     req: api::CreatePaymentMethod,
     merchant_id: &str,
+    card_info: cards_info::CardInfo,
 ) -> api::PaymentMethodResponse {
     let mut card_number = card.card_number.peek().to_owned();
     let card = api::CardDetailFromLocker {
-        scheme: None,
+        scheme: req.card_network.clone().or_else(|| {
+            card_info
+                .card_network
+                .map(|card_network| card_network.to_string())
+        }),
         last4_digits: Some(card_number.split_off(card_number.len() - 4)),
-        issuer_country: None, // [#256] bin mapping
+        issuer_country: card_info.card_issuing_country,
+        card_issuer: card_info.card_issuer,
+        card_type: card_info.card_type.map(|card_type| card_type.to_string()),
         card_number: Some(card.card_number),
         expiry_month: Some(card.card_exp_month),
         expiry_year: Some(card.card_exp_year),
         card_token: Some(response.external_id.into()), // [#256]
         card_fingerprint: Some(response.card_fingerprint),
-        card_holder_name: None,
+        card_holder_name: card.card_holder_name,
     };
     api::PaymentMethodResponse {
         merchant_id: merchant_id.to_owned(),
@@ -182,13 +192,15 @@ pub fn get_card_detail(
     let card_detail = api::CardDetailFromLocker {
         scheme: pm.scheme.clone(),
         issuer_country: pm.issuer_country.clone(),
+        card_issuer: pm.issuer_name.clone(),
+        card_type: pm.card_type.clone(),
         last4_digits: Some(last4_digits.split_off(last4_digits.len() - 4)),
         card_number: Some(card_number),
         expiry_month: response.card_exp_month,
         expiry_year: response.card_exp_year,
         card_token: Some(response.external_id.into()), //TODO ?
         card_fingerprint: Some(response.card_fingerprint),
-        card_holder_name: None,
+        card_holder_name: pm.cardholder_name.clone(),
     };
     Ok(card_detail)
 }
@@ -87,6 +87,8 @@ impl Vaultable for api::Card {
             card_cvc: value2.card_security_code.unwrap_or_default().into(),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         };
 
         let supp_data = SupplementaryVaultData {
@@ -1,15 +1,20 @@
 pub mod access_token;
+pub mod click_to_pay;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
+pub mod routing;
+pub mod sca_exemptions;
+pub mod search;
 pub mod transformers;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
 
-use common_utils::ext_traits::AsyncExt;
-use error_stack::{IntoReport, ResultExt};
+use common_utils::ext_traits::{AsyncExt, ValueExt};
+use error_stack::{report, IntoReport, ResultExt};
 use futures::future::join_all;
-use router_env::{instrument, tracing};
+use router_env::{instrument, opentelemetry::KeyValue, tracing};
 use time;
 
 pub use self::operations::{
@@ -22,17 +27,22 @@ use self::{
 };
 use crate::{
     core::{
+        analytics, audit_logs, authentication, blocklist, card_verification, cards_info,
+        connector_health, duplicate_payment,
         errors::{self, RouterResponse, RouterResult},
+        fraud_check,
         payment_methods::vault,
+        test_mode,
     },
     db::StorageInterface,
     logger, pii,
-    routes::AppState,
+    routes::{metrics, AppState},
     scheduler::utils as pt_utils,
     services,
     types::{
         self, api,
         storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
     },
     utils::OptionExt,
 };
@@ -80,6 +90,10 @@ where
         )
         .await?;
 
+    // The payment intent is created along with its trackers when the payment is created
+    let previous_status = (format!("{operation:?}") != "PaymentCreate")
+        .then(|| payment_data.payment_intent.status.to_string());
+
     let (operation, customer) = operation
         .to_domain()?
         .get_or_create_customer_details(
@@ -97,7 +111,13 @@ where
         .make_pm_data(state, &mut payment_data, validate_result.storage_scheme)
         .await?;
 
-    payment_data.payment_method_data = payment_method_data;
+    // Cards are identified before routing, so that the rules can be evaluated against their BIN
+    payment_data.payment_method_data = cards_info::populate_card_info(
+        state,
+        payment_method_data,
+        payment_data.card_network.clone(),
+    )
+    .await;
 
     let connector_details = operation
         .to_domain()?
@@ -110,15 +130,31 @@ where
         .await?;
 
     let connector = match should_call_connector(&operation, &payment_data) {
-        true => Some(
-            route_connector(
+        true => {
+            let connector_call_type = route_connector(
                 state,
                 &merchant_account,
                 &mut payment_data,
                 connector_details,
             )
-            .await?,
-        ),
+            .await?;
+
+            // Only authorizations are validated, the later operations on a payment are left to the
+            // connector which authorized it
+            Some(if format!("{operation:?}") == "PaymentConfirm" {
+                let connector_call_type =
+                    validate_connector_specifications(&mut payment_data, connector_call_type)?;
+                authentication::validate_external_authentication(
+                    &state.conf.three_ds_server,
+                    &payment_data,
+                )?;
+                payment_data.sca_exemption =
+                    sca_exemptions::get_sca_exemption(&merchant_account, &payment_data)?;
+                connector_call_type
+            } else {
+                connector_call_type
+            })
+        }
         false => None,
     };
 
@@ -133,6 +169,31 @@ where
         )
         .await?;
 
+    // Payments reach the connector only once they pass the blocklist, velocity and duplicate
+    // payment policies of the merchant, are screened by its fraud check connector and, when
+    // authenticated through the external 3DS server, once the cardholder is authenticated
+    let connector = match connector {
+        Some(connector) if format!("{operation:?}") == "PaymentConfirm" => {
+            (blocklist::screen_payment(state, &merchant_account, &mut payment_data).await?
+                && duplicate_payment::screen_payment(state, &merchant_account, &mut payment_data)
+                    .await?
+                && fraud_check::screen_before_authorization(
+                    state,
+                    &merchant_account,
+                    &mut payment_data,
+                )
+                .await?
+                && authentication::authenticate_externally(
+                    state,
+                    &merchant_account,
+                    &mut payment_data,
+                )
+                .await?)
+                .then_some(connector)
+        }
+        connector => connector,
+    };
+
     operation
         .to_domain()?
         .add_task_to_process_tracker(state, &payment_data.payment_attempt)
@@ -153,6 +214,19 @@ where
                 )
                 .await?
             }
+            api::ConnectorCallType::Failover(connectors) => {
+                retry::call_connector_service_with_failover(
+                    state,
+                    &merchant_account,
+                    &validate_result.payment_id,
+                    connectors,
+                    &operation,
+                    payment_data,
+                    &customer,
+                    call_connector_action,
+                )
+                .await?
+            }
             api::ConnectorCallType::Multiple(connectors) => {
                 call_multiple_connectors_service(
                     state,
@@ -193,8 +267,63 @@ where
                 .await?
             }
         };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+
+        metrics::PAYMENT_ATTEMPT_OUTCOME.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                KeyValue::new(
+                    "connector",
+                    payment_data
+                        .payment_attempt
+                        .connector
+                        .clone()
+                        .unwrap_or_default(),
+                ),
+                KeyValue::new(
+                    "payment_method",
+                    payment_data
+                        .payment_attempt
+                        .payment_method
+                        .map(|payment_method| payment_method.to_string())
+                        .unwrap_or_default(),
+                ),
+                KeyValue::new("status", payment_data.payment_attempt.status.to_string()),
+            ],
+        );
+
+        // Payments voided on their card verification results aren't sent for a fraud review
+        if format!("{operation:?}") == "PaymentConfirm" {
+            card_verification::enforce_policy_after_authorization(
+                state,
+                &merchant_account,
+                &mut payment_data,
+            )
+            .await?;
+            fraud_check::screen_after_authorization(state, &merchant_account, &mut payment_data)
+                .await?;
+        }
+
+        // The payment method is kept until the customer returns from authentication, as some
+        // connectors need it again to complete the payment
+        if payment_data.payment_attempt.status
+            != storage_enums::AttemptStatus::AuthenticationPending
+        {
+            vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
+                .await
+        }
     }
+
+    audit_logs::record_resource_change(
+        &*state.store,
+        &merchant_account.merchant_id,
+        storage_enums::AuditResourceType::Payment,
+        &payment_data.payment_intent.payment_id,
+        previous_status,
+        Some(payment_data.payment_intent.status.to_string()),
+    )
+    .await;
+
     Ok((payment_data, req, customer))
 }
 
@@ -233,6 +362,28 @@ where
     )
     .await?;
 
+    analytics::record_payment_attempt(state, &payment_data.payment_attempt);
+    search::index_payment(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .or_else(|| payment_data.email.clone()),
+    );
+    #[cfg(feature = "kafka_events")]
+    {
+        services::kafka::log_event(
+            state,
+            &services::kafka::KafkaPaymentIntent::from(&payment_data.payment_intent),
+        );
+        services::kafka::log_event(
+            state,
+            &services::kafka::KafkaPaymentAttempt::from(&payment_data.payment_attempt),
+        );
+    }
+
     Res::generate_response(
         Some(req),
         payment_data,
@@ -331,7 +482,7 @@ pub async fn call_connector_service<F, Op, Req>(
     payment_id: &api::PaymentIdType,
     connector: api::ConnectorData,
     _operation: &Op,
-    payment_data: PaymentData<F>,
+    mut payment_data: PaymentData<F>,
     customer: &Option<storage::Customer>,
     call_connector_action: CallConnectorAction,
 ) -> RouterResult<PaymentData<F>>
@@ -349,16 +500,78 @@ where
     // To perform router related operation for PaymentResponse
     PaymentResponse: Operation<F, Req>,
 {
-    let db = &*state.store;
-
     let stime_connector = Instant::now();
+    let is_connector_triggered = matches!(call_connector_action, CallConnectorAction::Trigger);
+
+    let mut router_data_res = call_connector(
+        state,
+        merchant_account,
+        &connector,
+        &payment_data,
+        customer,
+        call_connector_action,
+    )
+    .await;
 
+    if is_connector_triggered {
+        router_data_res = sca_exemptions::challenge_soft_decline(
+            state,
+            merchant_account,
+            &connector,
+            &mut payment_data,
+            customer,
+            router_data_res,
+        )
+        .await;
+    }
+
+    let response = router_data_res
+        .async_and_then(|router_data| async {
+            update_trackers_with_connector_response(
+                state,
+                merchant_account,
+                payment_id,
+                payment_data,
+                router_data,
+            )
+            .await
+        })
+        .await?;
+
+    let etime_connector = Instant::now();
+    let duration_connector = etime_connector.saturating_duration_since(stime_connector);
+    tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
+
+    Ok(response)
+}
+
+/// Constructs the router data for the connector and executes the connector flow, without updating
+/// any of the trackers with the connector response.
+#[instrument(skip_all)]
+pub async fn call_connector<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    call_connector_action: CallConnectorAction,
+) -> RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>
+where
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+{
     let mut router_data = payment_data
         .construct_router_data(state, connector.connector.id(), merchant_account)
         .await?;
 
     let add_access_token_result = router_data
-        .add_access_token(state, &connector, merchant_account)
+        .add_access_token(state, connector, merchant_account)
         .await?;
 
     access_token::update_router_data_with_access_token_result(
@@ -367,13 +580,13 @@ where
         &call_connector_action,
     );
 
-    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+    if !(add_access_token_result.connector_supports_access_token
         && router_data.access_token.is_none())
     {
         router_data
             .decide_flows(
                 state,
-                &connector,
+                connector,
                 customer,
                 call_connector_action,
                 merchant_account,
@@ -381,30 +594,33 @@ where
             .await
     } else {
         Ok(router_data)
-    };
-
-    let response = router_data_res
-        .async_and_then(|response| async {
-            let operation = helpers::response_operation::<F, Req>();
-            let payment_data = operation
-                .to_post_update_tracker()?
-                .update_tracker(
-                    db,
-                    payment_id,
-                    payment_data,
-                    response,
-                    merchant_account.storage_scheme,
-                )
-                .await?;
-            Ok(payment_data)
-        })
-        .await?;
+    }
+}
 
-    let etime_connector = Instant::now();
-    let duration_connector = etime_connector.saturating_duration_since(stime_connector);
-    tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
+pub async fn update_trackers_with_connector_response<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone,
 
-    Ok(response)
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let operation = helpers::response_operation::<F, Req>();
+    operation
+        .to_post_update_tracker()?
+        .update_tracker(
+            &*state.store,
+            payment_id,
+            payment_data,
+            router_data,
+            merchant_account.storage_scheme,
+        )
+        .await
 }
 
 pub async fn call_multiple_connectors_service<F, Op, Req>(
@@ -514,6 +730,55 @@ where
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
+    pub payer_document: Option<api_models::payments::PayerDocument>,
+    pub installments: Option<api_models::payments::InstallmentsData>,
+    pub multiple_capture_data: Option<MultipleCaptureData>,
+    /// The network the client chose to process the card on
+    pub card_network: Option<api_models::enums::CardNetwork>,
+    /// Exemption from strong customer authentication requested for the authorization
+    pub sca_exemption: Option<api_models::enums::ScaExemptionType>,
+    /// Whether the client asked for the payment to be authenticated through the external 3DS server
+    pub request_external_three_ds_authentication: bool,
+    pub external_authentication_data: Option<types::ExternalAuthenticationData>,
+    /// Whether the client confirmed the payment even if it is likely a duplicate
+    pub allow_duplicate: bool,
+    /// Network transaction id of the customer-initiated transaction the saved card of an
+    /// off-session payment was stored with
+    pub network_transaction_id: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MultipleCaptureData {
+    pub previous_captures: Vec<storage::Capture>,
+    pub current_capture: storage::Capture,
+}
+
+impl MultipleCaptureData {
+    /// Amount already captured or awaiting capture at the connector, excluding the current capture
+    pub fn get_previously_captured_amount(&self) -> i64 {
+        get_captured_amount(&self.previous_captures)
+    }
+
+    pub fn get_all_captures(&self) -> Vec<storage::Capture> {
+        self.previous_captures
+            .iter()
+            .chain(std::iter::once(&self.current_capture))
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn get_captured_amount(captures: &[storage::Capture]) -> i64 {
+    captures
+        .iter()
+        .filter(|capture| {
+            matches!(
+                capture.status,
+                storage_enums::CaptureStatus::Charged | storage_enums::CaptureStatus::Pending
+            )
+        })
+        .map(|capture| capture.amount)
+        .sum()
 }
 
 #[derive(Debug, Default)]
@@ -611,7 +876,14 @@ pub async fn list_payments(
 ) -> RouterResponse<api::PaymentListResponse> {
     helpers::validate_payment_list_request(&constraints)?;
     let merchant_id = &merchant.merchant_id;
-    let payment_intent =
+    let limit = constraints.limit;
+    let backwards = constraints.ending_before.is_some();
+    // One payment more than asked for is fetched, to tell whether the list has more payments
+    let constraints = api::PaymentListConstraints {
+        limit: limit + 1,
+        ..constraints
+    };
+    let mut payment_intents =
         helpers::filter_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
             .await
             .map_err(|err| {
@@ -621,18 +893,112 @@ pub async fn list_payments(
                 )
             })?;
 
-    let data: Vec<api::PaymentsResponse> = payment_intent
+    let has_more = payment_intents.len() > usize::try_from(limit).unwrap_or_default();
+    if has_more {
+        // When fetching backwards, the extra payment is the farthest from the cursor
+        if backwards {
+            payment_intents.remove(0);
+        } else {
+            payment_intents.pop();
+        }
+    }
+
+    let data: Vec<api::PaymentsResponse> = payment_intents
         .into_iter()
-        .map(types::transformers::ForeignInto::foreign_into)
+        .map(ForeignInto::foreign_into)
         .collect();
     Ok(services::ApplicationResponse::Json(
         api::PaymentListResponse {
+            size: data.len(),
+            has_more,
+            data,
+        },
+    ))
+}
+
+/// Lists every attempt of the payment, along with the calls to other connectors which failed before
+/// each attempt was moved to its connector.
+#[instrument(skip(db))]
+pub async fn list_payment_attempts(
+    db: &dyn StorageInterface,
+    merchant: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::PaymentAttemptListResponse> {
+    let payment_attempts = db
+        .list_payment_attempts_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant.merchant_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            errors::StorageErrorExt::to_not_found_response(
+                error,
+                errors::ApiErrorResponse::PaymentNotFound,
+            )
+        })?;
+
+    if payment_attempts.is_empty() {
+        Err(errors::ApiErrorResponse::PaymentNotFound)?
+    }
+
+    let data = payment_attempts
+        .into_iter()
+        .map(get_payment_attempt_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentAttemptListResponse {
+            payment_id,
             size: data.len(),
             data,
         },
     ))
 }
 
+fn get_payment_attempt_response(
+    payment_attempt: storage::PaymentAttempt,
+) -> RouterResult<api::PaymentAttemptResponse> {
+    let connector_attempts = payment_attempt
+        .connector_attempts
+        .map(|connector_attempts| {
+            connector_attempts.parse_value::<Vec<retry::ConnectorAttempt>>("ConnectorAttempt")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector attempts of the payment attempt")?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|connector_attempt| api::ConnectorAttemptResponse {
+            connector: connector_attempt.connector,
+            status_code: connector_attempt.status_code,
+            error_code: connector_attempt.error_code,
+            error_message: connector_attempt.error_message,
+            latency_in_millis: connector_attempt.latency_in_millis,
+            attempted_at: connector_attempt.attempted_at,
+        })
+        .collect();
+
+    Ok(api::PaymentAttemptResponse {
+        attempt_id: payment_attempt.attempt_id,
+        status: payment_attempt.status.foreign_into(),
+        amount: payment_attempt.amount,
+        currency: payment_attempt.currency.map(ForeignInto::foreign_into),
+        connector: payment_attempt.connector,
+        connector_transaction_id: payment_attempt.connector_transaction_id,
+        payment_method: payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+        error_code: payment_attempt.error_code,
+        error_message: payment_attempt.error_message,
+        unified_code: payment_attempt.unified_code.map(ForeignInto::foreign_into),
+        latency_in_millis: payment_attempt.connector_latency_in_millis,
+        connector_attempts,
+        created_at: payment_attempt.created_at,
+        modified_at: payment_attempt.modified_at,
+    })
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -667,6 +1033,147 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Identifies the payment whose authorization is captured by the scheduled capture workflow.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+}
+
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_on: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = ScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = "SCHEDULED_CAPTURE_WORKFLOW";
+    let task = "SCHEDULED_CAPTURE";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let mut process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            capture_on,
+        )?;
+    process_tracker_entry.tag = vec![String::from("CAPTURE"), String::from("PAYMENT")];
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Identifies the payment whose authorization the authorization expiry workflow acts on.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+}
+
+pub async fn add_authorization_expiry_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = AuthorizationExpiryTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = "AUTHORIZATION_EXPIRY_WORKFLOW";
+    let task = "AUTHORIZATION_EXPIRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let mut process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+    process_tracker_entry.tag = vec![String::from("AUTHORIZATION"), String::from("PAYMENT")];
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Validates a payment against the specifications of the connectors it is routed to. Connectors
+/// which can't process the payment are skipped when failing over between connectors, and the
+/// payment is rejected when none of them can process it.
+fn validate_connector_specifications<F>(
+    payment_data: &mut PaymentData<F>,
+    connector_call_type: api::ConnectorCallType,
+) -> RouterResult<api::ConnectorCallType>
+where
+    F: Send + Clone,
+{
+    match connector_call_type {
+        api::ConnectorCallType::Single(connector) => {
+            helpers::validate_connector_specifications(
+                *connector.connector,
+                &connector.connector_name.to_string(),
+                payment_data,
+            )?;
+            Ok(api::ConnectorCallType::Single(connector))
+        }
+
+        api::ConnectorCallType::Failover(connectors) => {
+            let mut first_error = None;
+            let mut supported_connectors = Vec::with_capacity(connectors.len());
+            for connector in connectors {
+                match helpers::validate_connector_specifications(
+                    *connector.connector,
+                    &connector.connector_name.to_string(),
+                    payment_data,
+                ) {
+                    Ok(()) => supported_connectors.push(connector),
+                    Err(error) => {
+                        first_error.get_or_insert(error);
+                    }
+                }
+            }
+
+            let first_connector = match supported_connectors.first() {
+                Some(connector) => connector.connector_name.to_string(),
+                None => {
+                    return Err(first_error.unwrap_or_else(|| {
+                        report!(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("No connector to fail over to")
+                    }))
+                }
+            };
+            payment_data.payment_attempt.connector = Some(first_connector);
+
+            if supported_connectors.len() > 1 {
+                Ok(api::ConnectorCallType::Failover(supported_connectors))
+            } else {
+                supported_connectors
+                    .pop()
+                    .map(api::ConnectorCallType::Single)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("No connector to fail over to")
+            }
+        }
+
+        call_type @ (api::ConnectorCallType::Multiple(_) | api::ConnectorCallType::Routing) => {
+            Ok(call_type)
+        }
+    }
+}
+
 pub async fn route_connector<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
@@ -676,6 +1183,19 @@ pub async fn route_connector<F>(
 where
     F: Send + Clone,
 {
+    // The payments of merchants in test mode are simulated by the dummy connector, whichever
+    // connector they would otherwise be routed to
+    if !matches!(connector_call_type, api::ConnectorCallType::Multiple(_)) {
+        if let Some(connector) = test_mode::get_test_mode_connector(
+            state,
+            merchant_account,
+            &payment_data.payment_attempt,
+        )? {
+            payment_data.payment_attempt.connector = Some(connector.connector_name.to_string());
+            return Ok(api::ConnectorCallType::Single(connector));
+        }
+    }
+
     match connector_call_type {
         api::ConnectorCallType::Single(connector) => {
             payment_data.payment_attempt.connector = Some(connector.connector_name.to_string());
@@ -684,30 +1204,96 @@ where
         }
 
         api::ConnectorCallType::Routing => {
-            let routing_algorithm: api::RoutingAlgorithm = merchant_account
-                .routing_algorithm
-                .clone()
+            // The payments made for a business profile are routed with the routing algorithm of
+            // the profile, when the profile has one
+            let profile_routing_algorithm = match payment_data.payment_intent.profile_id {
+                Some(ref profile_id) => {
+                    state
+                        .store
+                        .find_business_profile_by_merchant_id_profile_id(
+                            &merchant_account.merchant_id,
+                            profile_id,
+                        )
+                        .await
+                        .map_err(|err| {
+                            errors::StorageErrorExt::to_not_found_response(
+                                err,
+                                errors::ApiErrorResponse::BusinessProfileNotFound,
+                            )
+                        })?
+                        .routing_algorithm
+                }
+                None => None,
+            };
+
+            let routing_algorithm: api::RoutingAlgorithm = profile_routing_algorithm
+                .or_else(|| merchant_account.routing_algorithm.clone())
                 .parse_value("RoutingAlgorithm")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Could not decode merchant routing rules")?;
 
-            let connector_name = match routing_algorithm {
-                api::RoutingAlgorithm::Single(conn) => conn.to_string(),
+            let connector_names = match routing_algorithm {
+                api::RoutingAlgorithm::Single(conn) => vec![conn.to_string()],
+                api::RoutingAlgorithm::RuleBased(config) => routing::get_connectors_for_payment(
+                    &*state.store,
+                    &merchant_account.merchant_id,
+                    &config,
+                    &routing::RoutingInput::from(&*payment_data),
+                )
+                .await
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            };
+            let connector_names = connector_health::filter_available_connectors(
+                &state.conf.connector_health,
+                connector_names,
+            );
+
+            // Only the first connector is called unless failover to the next connectors is enabled
+            let max_connector_attempts = if state.conf.payment_retries.enabled {
+                state.conf.payment_retries.max_connector_attempts
+            } else {
+                1
             };
 
-            let connector_data = api::ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
-                &connector_name,
-                api::GetToken::Connector,
-            )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Routing algorithm gave invalid connector")?;
-
-            payment_data.payment_attempt.connector = Some(connector_name);
-
-            Ok(api::ConnectorCallType::Single(connector_data))
+            let mut connectors = connector_names
+                .iter()
+                .take(max_connector_attempts)
+                .map(|connector_name| {
+                    api::ConnectorData::get_connector_by_name(
+                        &state.conf.connectors,
+                        connector_name,
+                        api::GetToken::Connector,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing algorithm gave invalid connector")
+                })
+                .collect::<RouterResult<Vec<_>>>()?;
+
+            payment_data.payment_attempt.connector = Some(
+                connector_names
+                    .first()
+                    .cloned()
+                    .get_required_value("connector")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing algorithm did not give any connector")?,
+            );
+
+            if connectors.len() > 1 {
+                Ok(api::ConnectorCallType::Failover(connectors))
+            } else {
+                connectors
+                    .pop()
+                    .map(api::ConnectorCallType::Single)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Routing algorithm did not give any connector")
+            }
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        call_type @ (api::ConnectorCallType::Multiple(_) | api::ConnectorCallType::Failover(_)) => {
+            Ok(call_type)
+        }
     }
 }
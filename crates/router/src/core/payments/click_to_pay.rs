@@ -0,0 +1,170 @@
+//! Click to Pay checkouts, in which the customer picks a card saved at the Secure Remote Commerce
+//! (SRC) system of the card network. The SDK returns a correlation ID of the checkout, with which
+//! the network token of the card and a cryptogram for the payment are retrieved from the SRC
+//! system before the payment is authorized at the connector.
+
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings::{ClickToPaySettings, SrcSystemSettings},
+    core::errors::{self, RouterResult},
+    headers, pii,
+    routes::AppState,
+    services, types,
+    types::api::{self, enums as api_enums},
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PayloadRequest {
+    src_correlation_id: String,
+    srci_transaction_id: String,
+    src_initiator_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PayloadResponse {
+    token: SrcToken,
+    dynamic_data: SrcDynamicData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SrcToken {
+    payment_token: Secret<String, pii::CardNumber>,
+    token_expiration_month: Secret<String>,
+    token_expiration_year: Secret<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SrcDynamicData {
+    dynamic_data_value: Secret<String>,
+    eci: Option<String>,
+}
+
+fn get_src_system(
+    settings: &ClickToPaySettings,
+    card_network: api_enums::CardNetwork,
+) -> Option<&SrcSystemSettings> {
+    let src_system = match card_network {
+        api_enums::CardNetwork::Visa => &settings.visa,
+        api_enums::CardNetwork::Mastercard => &settings.mastercard,
+        _ => return None,
+    };
+
+    (!src_system.base_url.is_empty()).then_some(src_system)
+}
+
+/// Retrieves the payment credentials of the Click to Pay checkout the payment is made with, from
+/// the SRC system of the card network. Returns None for every other payment method.
+pub async fn get_click_to_pay_token_data(
+    state: &AppState,
+    payment_method_data: &api::PaymentMethodData,
+    payment_id: &str,
+) -> RouterResult<Option<types::ClickToPayTokenData>> {
+    let wallet_data = match payment_method_data {
+        api::PaymentMethodData::Wallet(api::WalletData::ClickToPay(wallet_data)) => wallet_data,
+        _ => return Ok(None),
+    };
+
+    let settings = &state.conf.click_to_pay;
+    let src_system = get_src_system(settings, wallet_data.card_network).ok_or_else(|| {
+        errors::ApiErrorResponse::NotSupported {
+            message: format!("Click to Pay checkouts with {}", wallet_data.card_network),
+        }
+    })?;
+
+    let request_body = PayloadRequest {
+        src_correlation_id: wallet_data.src_correlation_id.clone(),
+        srci_transaction_id: payment_id.to_string(),
+        src_initiator_id: settings.src_initiator_id.clone(),
+    };
+    let request_body = utils::Encode::<PayloadRequest>::encode_to_string_of_json(&request_body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payload request to the SRC system")?;
+
+    let url = format!("{}payloads", src_system.base_url);
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&url)
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(headers::X_API_KEY, &src_system.api_key)
+        .body(Some(request_body))
+        .build();
+
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the SRC system")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payment_method_data.wallet.click_to_pay.src_correlation_id",
+            })
+            .attach_printable(format!(
+                "SRC system of {} responded with status code {}",
+                wallet_data.card_network, error.status_code
+            ))
+        })?;
+
+    let payload: PayloadResponse = response
+        .response
+        .parse_struct("PayloadResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the payload of the SRC system")?;
+
+    Ok(Some(types::ClickToPayTokenData {
+        network_token: payload.token.payment_token,
+        token_exp_month: payload.token.token_expiration_month,
+        token_exp_year: get_four_digit_year(&payload.token.token_expiration_year),
+        cryptogram: payload.dynamic_data.dynamic_data_value,
+        eci: payload.dynamic_data.eci,
+        card_network: wallet_data.card_network,
+    }))
+}
+
+/// SRC systems may give the expiry year of the token in two digits
+fn get_four_digit_year(year: &Secret<String>) -> Secret<String> {
+    let year = year.peek();
+    match year.len() {
+        2 => Secret::new(format!("20{year}")),
+        _ => Secret::new(year.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_four_digit_year() {
+        assert_eq!(
+            get_four_digit_year(&Secret::new("28".to_string())).peek(),
+            "2028"
+        );
+        assert_eq!(
+            get_four_digit_year(&Secret::new("2028".to_string())).peek(),
+            "2028"
+        );
+    }
+
+    #[test]
+    fn test_src_system_of_unconfigured_network() {
+        let settings = ClickToPaySettings {
+            src_initiator_id: "initiator".to_string(),
+            visa: SrcSystemSettings {
+                base_url: "https://src.visa.example.com/".to_string(),
+                api_key: String::new(),
+            },
+            mastercard: SrcSystemSettings::default(),
+        };
+
+        assert!(get_src_system(&settings, api_enums::CardNetwork::Visa).is_some());
+        assert!(get_src_system(&settings, api_enums::CardNetwork::Mastercard).is_none());
+        assert!(get_src_system(&settings, api_enums::CardNetwork::AmericanExpress).is_none());
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
-        payments::{self, access_token, transformers, PaymentData},
+        payments::{self, access_token, click_to_pay, helpers, transformers, PaymentData},
     },
     routes::AppState,
     scheduler::metrics,
@@ -33,13 +33,25 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        transformers::construct_payment_router_data::<api::Authorize, types::PaymentsAuthorizeData>(
-            state,
-            self.clone(),
+        let mut router_data = transformers::construct_payment_router_data::<
+            api::Authorize,
+            types::PaymentsAuthorizeData,
+        >(state, self.clone(), connector_id, merchant_account)
+        .await?;
+
+        router_data.request.apple_pay_decrypted_data = helpers::get_apple_pay_decrypted_data(
+            &state.conf.applepay_decrypt_keys,
             connector_id,
-            merchant_account,
+            &router_data.request.payment_method_data,
+        )?;
+        router_data.request.click_to_pay_token_data = click_to_pay::get_click_to_pay_token_data(
+            state,
+            &router_data.request.payment_method_data,
+            &router_data.payment_id,
         )
-        .await
+        .await?;
+
+        Ok(router_data)
     }
 }
 
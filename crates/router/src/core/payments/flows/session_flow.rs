@@ -66,20 +66,9 @@ impl Feature<api::Session, types::PaymentsSessionData> for types::PaymentsSessio
 
 fn create_gpay_session_token(
     router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
 ) -> RouterResult<types::PaymentsSessionRouterData> {
-    let connector_metadata = router_data.connector_meta_data.clone();
-
-    let gpay_data = connector_metadata
-        .clone()
-        .parse_value::<payment_types::GpaySessionTokenData>("GpaySessionTokenData")
-        .change_context(errors::ConnectorError::NoConnectorMetaData)
-        .attach_printable(format!(
-            "cannot parse gpay metadata from the given value {connector_metadata:?}"
-        ))
-        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "connector_metadata".to_string(),
-            expected_format: "gpay_metadata_format".to_string(),
-        })?;
+    let gpay_data = get_gpay_metadata(router_data.connector_meta_data.clone(), connector)?;
 
     let session_data = router_data.request.clone();
     let transaction_info = payment_types::GpayTransactionInfo {
@@ -93,8 +82,8 @@ fn create_gpay_session_token(
         response: Ok(types::PaymentsResponseData::SessionResponse {
             session_token: payment_types::SessionToken::GooglePay(Box::new(
                 payment_types::GpaySessionTokenResponse {
-                    merchant_info: gpay_data.data.merchant_info,
-                    allowed_payment_methods: gpay_data.data.allowed_payment_methods,
+                    merchant_info: gpay_data.merchant_info,
+                    allowed_payment_methods: gpay_data.allowed_payment_methods,
                     transaction_info,
                 },
             )),
@@ -105,6 +94,105 @@ fn create_gpay_session_token(
     Ok(response_router_data)
 }
 
+fn create_click_to_pay_session_token(
+    router_data: &types::PaymentsSessionRouterData,
+    state: &routes::AppState,
+) -> RouterResult<types::PaymentsSessionRouterData> {
+    let click_to_pay_data = router_data
+        .connector_meta_data
+        .clone()
+        .parse_value::<payment_types::ClickToPaySessionTokenData>("ClickToPaySessionTokenData")
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "click_to_pay_metadata_format".to_string(),
+        })?
+        .data;
+
+    let response_router_data = types::PaymentsSessionRouterData {
+        response: Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: payment_types::SessionToken::ClickToPay(Box::new(
+                payment_types::ClickToPaySessionTokenResponse {
+                    src_initiator_id: state.conf.click_to_pay.src_initiator_id.clone(),
+                    dpa_id: click_to_pay_data.dpa_id,
+                    dpa_name: click_to_pay_data.dpa_name,
+                    srci_transaction_id: router_data.payment_id.clone(),
+                    card_brands: click_to_pay_data.card_brands.unwrap_or_else(|| {
+                        vec![
+                            api::enums::CardNetwork::Visa,
+                            api::enums::CardNetwork::Mastercard,
+                        ]
+                    }),
+                    transaction_amount: router_data.request.amount,
+                    transaction_currency_code: router_data.request.currency.to_string(),
+                },
+            )),
+        }),
+        ..router_data.clone()
+    };
+
+    Ok(response_router_data)
+}
+
+/// The Google Pay configuration is either given in full in the connector metadata, or built from
+/// the merchant's identifier at the connector and the connector's Google Pay gateway
+fn get_gpay_metadata(
+    connector_metadata: Option<serde_json::Value>,
+    connector: &api::ConnectorData,
+) -> RouterResult<payment_types::GpayMetaData> {
+    if let Ok(gpay_data) = connector_metadata
+        .clone()
+        .parse_value::<payment_types::GpaySessionTokenData>("GpaySessionTokenData")
+    {
+        return Ok(gpay_data.data);
+    }
+
+    let gateway_data = connector_metadata
+        .clone()
+        .parse_value::<payment_types::GpayGatewaySessionTokenData>("GpayGatewaySessionTokenData")
+        .change_context(errors::ConnectorError::NoConnectorMetaData)
+        .attach_printable(format!(
+            "cannot parse gpay metadata from the given value {connector_metadata:?}"
+        ))
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "gpay_metadata_format".to_string(),
+        })?
+        .data;
+
+    let gateway = connector.connector.gpay_gateway().ok_or_else(|| {
+        errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Google Pay gateway tokens with connector {}",
+                connector.connector_name
+            ),
+        }
+    })?;
+
+    Ok(payment_types::GpayMetaData {
+        merchant_info: gateway_data.merchant_info,
+        allowed_payment_methods: vec![payment_types::GpayAllowedPaymentMethods {
+            payment_method_type: "CARD".to_string(),
+            parameters: payment_types::GpayAllowedMethodsParameters {
+                allowed_auth_methods: gateway_data
+                    .allowed_auth_methods
+                    .unwrap_or_else(|| vec!["PAN_ONLY".to_string(), "CRYPTOGRAM_3DS".to_string()]),
+                allowed_card_networks: gateway_data.allowed_card_networks.unwrap_or_else(|| {
+                    ["AMEX", "DISCOVER", "MASTERCARD", "VISA"]
+                        .map(ToString::to_string)
+                        .to_vec()
+                }),
+            },
+            tokenization_specification: payment_types::GpayTokenizationSpecification {
+                token_specification_type: "PAYMENT_GATEWAY".to_string(),
+                parameters: payment_types::GpayTokenParameters {
+                    gateway: gateway.to_string(),
+                    gateway_merchant_id: gateway_data.gateway_merchant_id,
+                },
+            },
+        }],
+    })
+}
+
 impl types::PaymentsSessionRouterData {
     pub async fn decide_flow<'a, 'b>(
         &'b self,
@@ -115,7 +203,8 @@ impl types::PaymentsSessionRouterData {
         call_connector_action: payments::CallConnectorAction,
     ) -> RouterResult<Self> {
         match connector.get_token {
-            api::GetToken::Metadata => create_gpay_session_token(self),
+            api::GetToken::Metadata => create_gpay_session_token(self, connector),
+            api::GetToken::ClickToPayMetadata => create_click_to_pay_session_token(self, state),
             api::GetToken::Connector => {
                 let connector_integration: services::BoxedConnectorIntegration<
                     '_,
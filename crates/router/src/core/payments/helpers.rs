@@ -1,10 +1,13 @@
 use std::borrow::Cow;
 
+use base64::Engine;
 use common_utils::{ext_traits::AsyncExt, fp_utils};
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
+use futures::future::BoxFuture;
 use masking::ExposeOptionInterface;
 use router_env::{instrument, tracing};
+use strum::EnumMessage;
 use uuid::Uuid;
 
 use super::{
@@ -12,25 +15,27 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::Server,
+    configs::settings::{self, Server},
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payment_methods::{cards, vault},
     },
     db::StorageInterface,
+    logger,
     routes::AppState,
     scheduler::{metrics, workflows::payment_sync},
     services,
     types::{
+        self,
         api::{self, enums as api_enums, CustomerAcceptanceExt, MandateValidationFieldsExt},
         storage::{self, enums as storage_enums, ephemeral_key},
-        transformers::ForeignInto,
+        transformers::{ForeignFrom, ForeignInto},
     },
     utils::{
         self,
-        crypto::{self, SignMessage},
-        OptionExt,
+        crypto::{self, DecodeMessage, SignMessage},
+        ByteSliceExt, Encode, OptionExt, ValueExt,
     },
 };
 
@@ -193,6 +198,226 @@ pub async fn get_token_for_recurring_mandate(
     Ok((Some(token), Some(payment_method.payment_method)))
 }
 
+/// Resolves the recurring details of an off-session payment into the mandate or the saved payment
+/// method the payment is charged with. Payments on a mandate are routed to the connector the mandate
+/// was set up with, and the payments are made without 3DS unless requested otherwise, as the
+/// customer isn't present to authenticate them.
+pub async fn resolve_recurring_details(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    req: &mut api::PaymentsRequest,
+) -> RouterResult<()> {
+    let recurring_details = match req.recurring_details.clone() {
+        Some(recurring_details) => recurring_details,
+        None => return Ok(()),
+    };
+
+    utils::when(req.off_session != Some(true), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "recurring_details can only be provided for off-session payments, with \
+                      off_session set to true"
+                .into()
+        }))
+    })?;
+    utils::when(
+        req.mandate_id.is_some()
+            || req.mandate_data.is_some()
+            || req.payment_token.is_some()
+            || req.payment_method_id.is_some()
+            || req.payment_method_data.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "recurring_details can't be provided along with mandate_id, \
+                          mandate_data, payment_token, payment_method_id or payment_method_data"
+                    .into()
+            }))
+        },
+    )?;
+
+    let db = &*state.store;
+    match recurring_details {
+        api::RecurringDetails::MandateId(mandate_id) => {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &mandate_id)
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+                })?;
+            if req.connector.is_none() {
+                let connector = mandate
+                    .connector
+                    .parse::<api_enums::Connector>()
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Invalid connector in the mandate")?;
+                req.connector = Some(vec![connector]);
+            }
+            req.mandate_id = Some(mandate_id);
+        }
+        api::RecurringDetails::PaymentMethodId(payment_method_id) => {
+            req.customer_id.as_ref().get_required_value("customer_id")?;
+            // Charged like payments given the payment_method_id, see `resolve_payment_method_id`
+            req.payment_method_id = Some(payment_method_id);
+        }
+    }
+
+    req.authentication_type
+        .get_or_insert(api_enums::AuthenticationType::NoThreeDs);
+
+    Ok(())
+}
+
+/// Resolves the saved payment method a payment is charged with into a token of its card in the
+/// temporary locker, as for the payment methods listed to the customer. The payment is then routed
+/// like a payment with the card details, to any connector of the merchant rather than only the one
+/// which processed the card first.
+pub async fn resolve_payment_method_id(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    req: &mut api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+) -> RouterResult<()> {
+    let payment_method_id = match req.payment_method_id.clone() {
+        Some(payment_method_id) => payment_method_id,
+        None => return Ok(()),
+    };
+
+    // Clients only know the payment methods the customer saved through the merchant, which are
+    // charged with their payment_token instead
+    utils::when(matches!(auth_flow, services::AuthFlow::Client), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "payment_method_id can only be provided with the API key of the merchant"
+                .into()
+        }))
+    })?;
+
+    utils::when(
+        req.mandate_id.is_some()
+            || req.mandate_data.is_some()
+            || req.payment_token.is_some()
+            || req.payment_method_data.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment_method_id can't be provided along with mandate_id, \
+                          mandate_data, payment_token or payment_method_data"
+                    .into()
+            }))
+        },
+    )?;
+
+    let payment_method = state
+        .store
+        .find_payment_method(&payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+    let payment_intent_customer_id =
+        get_payment_intent_customer_id(&*state.store, merchant_account, req).await?;
+    validate_payment_method_customer(
+        &payment_method.customer_id,
+        payment_intent_customer_id.as_deref(),
+        req.customer_id.as_deref(),
+    )?;
+    utils::when(
+        payment_method.payment_method != storage_enums::PaymentMethod::Card,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only cards can be charged with payment_method_id".into()
+            }))
+        },
+    )?;
+
+    let token = Uuid::new_v4().to_string();
+    let locker_id = merchant_account
+        .locker_id
+        .to_owned()
+        .get_required_value("locker_id")?;
+    cards::get_lookup_key_from_locker(state, &token, &payment_method, &locker_id).await?;
+    req.payment_token = Some(token);
+    req.payment_method = Some(payment_method.payment_method.foreign_into());
+
+    // The customer isn't present to authenticate off-session payments
+    if req.off_session == Some(true) {
+        req.authentication_type
+            .get_or_insert(api_enums::AuthenticationType::NoThreeDs);
+    }
+
+    Ok(())
+}
+
+/// The customer of the payment being confirmed or updated, if the payment was already created
+async fn get_payment_intent_customer_id(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    req: &api::PaymentsRequest,
+) -> RouterResult<Option<String>> {
+    let payment_id = match req.payment_id.as_ref() {
+        Some(api::PaymentIdType::PaymentIntentId(payment_id)) => payment_id,
+        _ => return Ok(None),
+    };
+
+    match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => Ok(payment_intent.customer_id),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    }
+}
+
+/// A saved payment method can only be charged for the customer it belongs to: the customer of the
+/// payment, or the customer of the request when the payment has none yet.
+fn validate_payment_method_customer(
+    payment_method_customer_id: &str,
+    payment_intent_customer_id: Option<&str>,
+    request_customer_id: Option<&str>,
+) -> RouterResult<()> {
+    let customer_id = payment_intent_customer_id
+        .or(request_customer_id)
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "customer_id",
+        })
+        .into_report()?;
+    utils::when(customer_id != payment_method_customer_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "customer_id must match the customer_id of the payment method".into()
+        }))
+    })
+}
+
+/// The network transaction id of the customer-initiated transaction the card of an off-session
+/// payment on a saved payment method was stored with. The payment is made as a merchant-initiated
+/// transaction referring to it, which issuers authorize without the CVC of the card.
+pub async fn get_network_transaction_id(
+    db: &dyn StorageInterface,
+    payment_method_id: Option<&String>,
+    off_session: Option<bool>,
+) -> RouterResult<Option<String>> {
+    let payment_method_id = match payment_method_id {
+        Some(payment_method_id) if off_session == Some(true) => payment_method_id,
+        _ => return Ok(None),
+    };
+
+    let payment_method = db
+        .find_payment_method(payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    Ok(payment_method.network_transaction_id)
+}
+
 #[instrument(skip_all)]
 /// Check weather the merchant id in the request
 /// and merchant id in the merchant account are same.
@@ -247,6 +472,139 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+/// Computes how the payment amount is split between the platform and the connected account, in
+/// the form stored on the payment intent.
+pub fn make_split_payments_breakdown(
+    split_payments: &api_models::payments::SplitPaymentsRequest,
+    amount: i64,
+) -> RouterResult<serde_json::Value> {
+    utils::when(
+        split_payments.application_fee < 0 || split_payments.application_fee > amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "split_payments.application_fee"
+            })
+            .attach_printable("Application fee must be between zero and the payment amount"))
+        },
+    )?;
+
+    let breakdown = api_models::payments::SplitPaymentsBreakdown {
+        application_fee: split_payments.application_fee,
+        destination_account_id: split_payments.destination_account_id.clone(),
+        destination_amount: amount - split_payments.application_fee,
+    };
+
+    Encode::<api_models::payments::SplitPaymentsBreakdown>::encode_to_value(&breakdown)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode split payments breakdown")
+}
+
+pub fn get_split_payments_breakdown(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<api_models::payments::SplitPaymentsBreakdown>> {
+    payment_intent
+        .split_payments
+        .clone()
+        .map(|split_payments| {
+            split_payments
+                .parse_value("SplitPaymentsBreakdown")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not decode split payments breakdown")
+        })
+        .transpose()
+}
+
+/// Characters card networks reject in statement descriptors
+const STATEMENT_DESCRIPTOR_FORBIDDEN_CHARACTERS: &str = "<>\\'\"*";
+
+/// The maximum length of the statement descriptor shown by the card network. The descriptor of
+/// cards on other or unknown networks is limited to the shortest common length.
+fn get_statement_descriptor_max_length(card_network: Option<&api_enums::CardNetwork>) -> usize {
+    match card_network {
+        Some(api_enums::CardNetwork::Visa) => 25,
+        Some(api_enums::CardNetwork::AmericanExpress) => 20,
+        _ => 22,
+    }
+}
+
+/// The statement descriptor shown to the customer, with the suffix appended to the name of the
+/// payment.
+pub fn get_statement_descriptor(
+    statement_descriptor_name: Option<&str>,
+    statement_descriptor_suffix: Option<&str>,
+) -> Option<String> {
+    match (statement_descriptor_name, statement_descriptor_suffix) {
+        (Some(name), Some(suffix)) => Some(format!("{name}* {suffix}")),
+        (Some(descriptor), None) | (None, Some(descriptor)) => Some(descriptor.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// Validates the statement descriptor of the payment against the rules of card networks, its
+/// length depending on the network the card is processed on.
+pub fn validate_statement_descriptor(
+    statement_descriptor_name: Option<&str>,
+    statement_descriptor_suffix: Option<&str>,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> RouterResult<()> {
+    for (field_name, descriptor) in [
+        ("statement_descriptor", statement_descriptor_name),
+        ("statement_descriptor_suffix", statement_descriptor_suffix),
+    ] {
+        let is_valid = descriptor.map_or(true, |descriptor| {
+            descriptor.chars().any(|c| c.is_ascii_alphabetic())
+                && descriptor.chars().all(|c| {
+                    c.is_ascii()
+                        && !c.is_ascii_control()
+                        && !STATEMENT_DESCRIPTOR_FORBIDDEN_CHARACTERS.contains(c)
+                })
+        });
+        utils::when(!is_valid, || {
+            Err(
+                report!(errors::ApiErrorResponse::InvalidDataValue { field_name })
+                    .attach_printable(
+                        "Statement descriptor must contain a letter and no special characters",
+                    ),
+            )
+        })?;
+    }
+
+    let max_length = get_statement_descriptor_max_length(card_network);
+    let descriptor_length =
+        get_statement_descriptor(statement_descriptor_name, statement_descriptor_suffix)
+            .map_or(0, |descriptor| descriptor.len());
+    utils::when(descriptor_length > max_length, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The statement descriptor, with its suffix, can be at most {max_length} characters long for the card network"
+            ),
+        }))
+    })
+}
+
+pub fn encode_connector_metadata(
+    connector_metadata: &api_models::payments::ConnectorMetadata,
+) -> RouterResult<serde_json::Value> {
+    Encode::<api_models::payments::ConnectorMetadata>::encode_to_value(connector_metadata)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode connector metadata")
+}
+
+pub fn get_connector_metadata(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<api_models::payments::ConnectorMetadata>> {
+    payment_intent
+        .connector_metadata
+        .clone()
+        .map(|connector_metadata| {
+            connector_metadata
+                .parse_value("ConnectorMetadata")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not decode connector metadata")
+        })
+        .transpose()
+}
+
 pub fn validate_mandate(
     req: impl Into<api::MandateValidationFields>,
 ) -> RouterResult<Option<api::MandateTxnType>> {
@@ -453,13 +811,35 @@ where
     Op: std::fmt::Debug,
 {
     if check_if_operation_confirm(operation) {
+        if let (Some(storage_enums::CaptureMethod::Scheduled), Some(capture_on)) =
+            (payment_attempt.capture_method, payment_attempt.capture_on)
+        {
+            super::add_scheduled_capture_task(&*state.store, payment_attempt, capture_on)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding scheduled capture task to process tracker",
+                )?;
+        }
+
         let connector_name = payment_attempt
             .connector
             .clone()
             .ok_or(errors::ApiErrorResponse::InternalServerError)?;
 
+        if matches!(
+            payment_attempt.capture_method,
+            Some(
+                storage_enums::CaptureMethod::Manual | storage_enums::CaptureMethod::ManualMultiple
+            )
+        ) {
+            schedule_authorization_expiry_task(state, payment_attempt, &connector_name).await?;
+        }
+
         let schedule_time = payment_sync::get_sync_process_schedule_time(
             &*state.store,
+            &state.conf.payment_sync,
             &connector_name,
             &payment_attempt.merchant_id,
             0,
@@ -485,6 +865,79 @@ where
     }
 }
 
+async fn schedule_authorization_expiry_task(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_name: &str,
+) -> RouterResult<()> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&payment_attempt.merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let schedule_time = get_authorization_expiry_policy(&merchant_account)?.and_then(|policy| {
+        get_authorization_expiry_schedule_time(
+            &state.conf.connector_capabilities,
+            connector_name,
+            &policy,
+            common_utils::date_time::now(),
+        )
+    });
+
+    match schedule_time {
+        Some(schedule_time) => {
+            super::add_authorization_expiry_task(&*state.store, payment_attempt, schedule_time)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding authorization expiry task to process tracker",
+                )
+        }
+        None => Ok(()),
+    }
+}
+
+pub fn get_authorization_expiry_policy(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::AuthorizationExpiryPolicy>> {
+    merchant_account
+        .authorization_expiry_policy
+        .clone()
+        .map(|policy| policy.parse_value("AuthorizationExpiryPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the authorization expiry policy of the merchant")
+}
+
+/// Returns the time at which the authorization expiry policy is applied to an authorization made
+/// at `authorized_at`, if the connector's authorization validity window is known.
+pub fn get_authorization_expiry_schedule_time(
+    connector_capabilities: &settings::ConnectorCapabilities,
+    connector_name: &str,
+    policy: &api::AuthorizationExpiryPolicy,
+    authorized_at: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    let authorization_validity_in_secs = connector_capabilities
+        .0
+        .get(connector_name)?
+        .authorization_validity_in_secs?;
+    let notice_period_in_secs = policy
+        .notice_period_in_secs
+        .unwrap_or(consts::DEFAULT_AUTHORIZATION_EXPIRY_NOTICE_PERIOD_IN_SECS);
+
+    Some(
+        authorized_at.saturating_add(time::Duration::seconds(
+            authorization_validity_in_secs
+                .saturating_sub(notice_period_in_secs)
+                .max(0),
+        )),
+    )
+}
+
 pub fn response_operation<'a, F, R>() -> BoxedOperation<'a, F, R>
 where
     F: Send + Clone,
@@ -738,7 +1191,11 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                         Some(storage_enums::PaymentMethod::Wallet);
                     // TODO: Remove redundant update from wallets.
                     match wallet_data {
-                        api_models::payments::WalletData::PaypalRedirect(_) => pm,
+                        api_models::payments::WalletData::PaypalRedirect(_)
+                        | api_models::payments::WalletData::PaytmRedirect(_)
+                        | api_models::payments::WalletData::AliPayRedirect(_)
+                        | api_models::payments::WalletData::WeChatPayRedirect(_)
+                        | api_models::payments::WalletData::WeChatPayQr(_) => pm,
                         _ => {
                             let updated_pm = api::PaymentMethodData::Wallet(wallet_data);
                             vault::Vault::store_payment_method_data_in_locker(
@@ -775,6 +1232,11 @@ pub async fn make_pm_data<'a, F: Clone, R>(
         }
         (pm @ Some(api::PaymentMethodData::PayLater(_)), _) => Ok(pm.to_owned()),
         (pm @ Some(api::PaymentMethodData::BankRedirect(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::BankTransfer(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::Voucher(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::Upi(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::BankDebit(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethodData::Crypto(_)), _) => Ok(pm.to_owned()),
         (pm_opt @ Some(pm @ api::PaymentMethodData::Wallet(_)), _) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
@@ -809,6 +1271,122 @@ pub(crate) fn validate_capture_method(
     )
 }
 
+#[instrument(skip_all)]
+pub(crate) fn validate_scheduled_capture(
+    capture_method: Option<api_enums::CaptureMethod>,
+    capture_on: Option<time::PrimitiveDateTime>,
+) -> RouterResult<()> {
+    if capture_method != Some(api_enums::CaptureMethod::Scheduled) {
+        return Ok(());
+    }
+
+    let capture_on = capture_on.get_required_value("capture_on")?;
+    utils::when(capture_on <= common_utils::date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_on must be a time in the future".to_string()
+        }))
+    })
+}
+
+/// Validates a payment against the capabilities declared by the connector it is routed to, so that
+/// the payment is rejected with the reason before it reaches the connector.
+pub(crate) fn validate_connector_specifications<F, C>(
+    connector: &C,
+    connector_name: &str,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()>
+where
+    F: Clone,
+    C: api::ConnectorSpecifications + ?Sized,
+{
+    let not_supported =
+        |message: String| Err(report!(errors::ApiErrorResponse::NotSupported { message }));
+
+    if let (Some(supported), Some(payment_method)) = (
+        connector.supported_payment_methods(),
+        payment_data.payment_attempt.payment_method,
+    ) {
+        let payment_method: api_enums::PaymentMethod = payment_method.foreign_into();
+        if !supported.contains(&payment_method) {
+            return not_supported(format!(
+                "Payment method {payment_method} is not supported by {connector_name}, the supported payment methods are {}",
+                join_values(supported)
+            ));
+        }
+    }
+
+    if let Some(supported) = connector.supported_currencies() {
+        let currency: api_enums::Currency = payment_data.currency.foreign_into();
+        if !supported.contains(&currency) {
+            return not_supported(format!(
+                "Currency {currency} is not supported by {connector_name}, the supported currencies are {}",
+                join_values(supported)
+            ));
+        }
+    }
+
+    let billing_country = payment_data
+        .address
+        .billing
+        .as_ref()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country.as_ref());
+    if let (Some(supported), Some(country)) = (connector.supported_countries(), billing_country) {
+        if !supported
+            .iter()
+            .any(|supported_country| supported_country.eq_ignore_ascii_case(country))
+        {
+            return not_supported(format!(
+                "Billing country {country} is not supported by {connector_name}, the supported countries are {}",
+                join_values(supported)
+            ));
+        }
+    }
+
+    if let (Some(supported), Some(capture_method)) = (
+        connector.supported_capture_methods(),
+        payment_data.payment_attempt.capture_method,
+    ) {
+        let capture_method: api_enums::CaptureMethod = capture_method.foreign_into();
+        if !supported.contains(&capture_method) {
+            return not_supported(format!(
+                "Capture method {capture_method} is not supported by {connector_name}, the supported capture methods are {}",
+                join_values(supported)
+            ));
+        }
+    }
+
+    let is_mandate_payment =
+        payment_data.setup_mandate.is_some() || payment_data.mandate_id.is_some();
+    if is_mandate_payment && !connector.supports_mandates() {
+        return not_supported(format!("Mandates are not supported by {connector_name}"));
+    }
+
+    if payment_data.amount == api::Amount::Zero && !connector.supports_zero_amount_authorization() {
+        return not_supported(format!(
+            "Zero amount authorizations are not supported by {connector_name}"
+        ));
+    }
+
+    if payment_data.request_external_three_ds_authentication
+        && !connector.supports_external_authentication()
+    {
+        return not_supported(format!(
+            "External 3DS authentication is not supported by {connector_name}"
+        ));
+    }
+
+    Ok(())
+}
+
+fn join_values<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResult<()> {
     utils::when(
@@ -839,6 +1417,38 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+pub(crate) fn make_new_capture(
+    payment_attempt: &storage::PaymentAttempt,
+    amount: i64,
+    currency: storage_enums::Currency,
+    capture_sequence: i16,
+) -> RouterResult<storage::CaptureNew> {
+    let connector = payment_attempt
+        .connector
+        .clone()
+        .get_required_value("connector")?;
+    let now = common_utils::date_time::now();
+    Ok(storage::CaptureNew {
+        capture_id: format!(
+            "{}_capture_{}",
+            payment_attempt.attempt_id, capture_sequence
+        ),
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        status: storage_enums::CaptureStatus::Started,
+        amount,
+        currency,
+        connector,
+        error_message: None,
+        error_code: None,
+        created_at: Some(now),
+        modified_at: Some(now),
+        authorized_attempt_id: payment_attempt.attempt_id.clone(),
+        connector_capture_id: None,
+        capture_sequence,
+    })
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -863,9 +1473,62 @@ pub(crate) fn validate_payment_method_fields_present(
         },
     )?;
 
+    utils::when(
+        req.card_network.is_some()
+            && req.payment_method.map_or(false, |payment_method| {
+                payment_method != api_enums::PaymentMethod::Card
+            }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "card_network can only be provided for card payments".to_string(),
+            })
+        },
+    )?;
+
     Ok(())
 }
 
+/// Returns the network the client chose to process the card on, which is the network of the card in
+/// the payment method data unless another one is provided for a co-badged card.
+pub fn get_preferred_card_network(req: &api::PaymentsRequest) -> Option<api_enums::CardNetwork> {
+    req.card_network
+        .clone()
+        .or_else(|| match req.payment_method_data {
+            Some(api::PaymentMethodData::Card(ref card)) => card.card_network.clone(),
+            _ => None,
+        })
+}
+
+/// Returns the amount a payment is to be authorized for, which is the amount of its payment intent
+/// when the request confirming or updating the payment doesn't change it. Zero amounts are processed
+/// through the verify flow. Payments which don't exist yet are left to the operation to reject.
+pub async fn get_authorization_amount(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    req: &api::PaymentsRequest,
+) -> RouterResult<Option<api::Amount>> {
+    match (req.amount, req.payment_id.as_ref()) {
+        (Some(amount), _) => Ok(Some(amount)),
+        (None, Some(api::PaymentIdType::PaymentIntentId(payment_id))) => {
+            match db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    payment_id,
+                    &merchant_account.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_intent) => Ok(Some(payment_intent.amount.into())),
+                Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                Err(error) => {
+                    Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                }
+            }
+        }
+        (None, _) => Ok(None),
+    }
+}
+
 pub fn check_force_psync_precondition(
     status: &storage_enums::AttemptStatus,
     connector_transaction_id: &Option<String>,
@@ -911,6 +1574,21 @@ pub(super) fn validate_payment_list_request(
             message: "limit should be in between 1 and 100".to_string(),
         })
     })?;
+    utils::when(
+        req.starting_after.is_some() && req.ending_before.is_some(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "only one of starting_after and ending_before can be used".to_string(),
+            })
+        },
+    )?;
+    if let (Some(amount_gte), Some(amount_lte)) = (req.amount_gte, req.amount_lte) {
+        utils::when(amount_gte > amount_lte, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount.gte cannot be greater than amount.lte".to_string(),
+            })
+        })?;
+    }
     Ok(())
 }
 
@@ -1100,6 +1778,138 @@ pub fn check_if_operation_confirm<Op: std::fmt::Debug>(operations: Op) -> bool {
     format!("{operations:?}") == "PaymentConfirm"
 }
 
+/// Fails a payment declined before it reaches the connector, and removes its payment method
+/// from the temporary locker as the payment can't be completed
+pub async fn fail_payment_before_connector<F: Clone>(
+    state: &AppState,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_data: &mut PaymentData<F>,
+    status: storage_enums::AttemptStatus,
+    error_code: Option<String>,
+    error_message: String,
+    unified_code: storage_enums::UnifiedCode,
+) -> RouterResult<()> {
+    let unified_message = api_enums::UnifiedCode::foreign_from(unified_code)
+        .get_message()
+        .map(ToOwned::to_owned);
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status,
+                error_code,
+                error_message: Some(error_message),
+                unified_code: Some(unified_code),
+                unified_message,
+                connector_latency_in_millis: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    payment_data.payment_intent = state
+        .store
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await;
+    Ok(())
+}
+
+/// Voids the authorization of a payment declined by the checks made once it is authorized.
+/// Payments already captured are left for the merchant to refund. Returns whether the payment was
+/// voided.
+pub async fn void_authorized_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    cancellation_reason: &str,
+) -> RouterResult<bool> {
+    if payment_attempt.status != storage_enums::AttemptStatus::Authorized {
+        logger::warn!(
+            payment_id = %payment_attempt.payment_id,
+            %cancellation_reason,
+            "Payment is not voided as it is {}",
+            payment_attempt.status
+        );
+        return Ok(false);
+    }
+
+    void_payment(
+        state,
+        merchant_account.clone(),
+        payment_attempt.payment_id.clone(),
+        cancellation_reason.to_string(),
+    )
+    .await?;
+    Ok(true)
+}
+
+// The payment is voided from within the payment flow which authorized it, so the future of the
+// void is boxed to keep the payment flow from containing itself
+fn void_payment(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_id: String,
+    cancellation_reason: String,
+) -> BoxFuture<'_, services::RouterResponse<api::PaymentsResponse>> {
+    Box::pin(super::payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state,
+        merchant_account,
+        super::PaymentCancel,
+        api::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some(cancellation_reason),
+        },
+        services::AuthFlow::Merchant,
+        super::CallConnectorAction::Trigger,
+    ))
+}
+
+/// Reloads the payment intent and attempt of the payment data, once they are updated outside of
+/// the payment flow
+pub async fn reload_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let storage_scheme = merchant_account.storage_scheme;
+    payment_data.payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_data.payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    payment_data.payment_attempt = state
+        .store
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &payment_data.payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    Ok(())
+}
+
 pub fn generate_mandate(
     merchant_id: String,
     connector: String,
@@ -1107,6 +1917,7 @@ pub fn generate_mandate(
     customer: &Option<storage::Customer>,
     payment_method_id: String,
     connector_mandate_id: Option<String>,
+    network_transaction_id: Option<String>,
 ) -> Option<storage::MandateNew> {
     match (setup_mandate_details, customer) {
         (Some(data), Some(cus)) => {
@@ -1123,6 +1934,7 @@ pub fn generate_mandate(
                 .set_connector(connector)
                 .set_mandate_status(storage_enums::MandateStatus::Active)
                 .set_connector_mandate_id(connector_mandate_id)
+                .set_network_transaction_id(network_transaction_id)
                 .set_customer_ip_address(
                     data.customer_acceptance
                         .get_ip_address()
@@ -1234,6 +2046,84 @@ pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> String {
     cs.split('_').take(2).collect::<Vec<&str>>().join("_")
 }
 
+/// Connectors which accept Apple Pay only as a decrypted token (PAN/DPAN and cryptogram)
+fn connector_requires_decrypted_apple_pay(connector_name: &str) -> bool {
+    matches!(connector_name, "cybersource" | "dlocal")
+}
+
+pub fn get_apple_pay_decrypted_data(
+    apple_pay_decrypt_keys: &settings::ApplePayDecryptConfig,
+    connector_name: &str,
+    payment_method_data: &api::PaymentMethodData,
+) -> RouterResult<Option<types::ApplePayDecryptedData>> {
+    match payment_method_data {
+        api::PaymentMethodData::Wallet(api_models::payments::WalletData::ApplePay(wallet_data))
+            if connector_requires_decrypted_apple_pay(connector_name) =>
+        {
+            decrypt_apple_pay_token(apple_pay_decrypt_keys, wallet_data).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Decrypts the payment data of an Apple Pay token with the merchant's payment processing key.
+/// The signature of the token is not verified here.
+pub fn decrypt_apple_pay_token(
+    apple_pay_decrypt_keys: &settings::ApplePayDecryptConfig,
+    wallet_data: &api_models::payments::ApplePayWalletData,
+) -> RouterResult<types::ApplePayDecryptedData> {
+    if apple_pay_decrypt_keys.apple_pay_ppc_key.is_empty() {
+        Err(errors::ApiErrorResponse::NotSupported {
+            message: "Apple Pay without a configured payment processing certificate".to_string(),
+        })?
+    }
+
+    let encrypted_data: types::ApplePayEncryptedData = consts::BASE64_ENGINE
+        .decode(&wallet_data.payment_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_data",
+        })?
+        .parse_struct("ApplePayEncryptedData")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_data",
+        })?;
+
+    if encrypted_data.version != "EC_v1" {
+        Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Apple Pay token version {}", encrypted_data.version),
+        })?
+    }
+
+    let ephemeral_public_key = consts::BASE64_ENGINE
+        .decode(&encrypted_data.header.ephemeral_public_key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_data.header.ephemeralPublicKey",
+        })?;
+    let data = consts::BASE64_ENGINE
+        .decode(&encrypted_data.data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_data.data",
+        })?;
+
+    let decrypted_data = crypto::ApplePayEcV1 {
+        ephemeral_public_key: &ephemeral_public_key,
+        merchant_identifier: &apple_pay_decrypt_keys.apple_pay_merchant_identifier,
+    }
+    .decode_message(apple_pay_decrypt_keys.apple_pay_ppc_key.as_bytes(), &data)
+    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+        field_name: "payment_data",
+    })
+    .attach_printable("Failed to decrypt Apple Pay token")?;
+
+    decrypted_data
+        .parse_struct("ApplePayDecryptedData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse decrypted Apple Pay token")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1244,4 +2134,91 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    #[test]
+    fn test_validate_payment_method_customer() {
+        assert!(validate_payment_method_customer("cus_1", None, Some("cus_1")).is_ok());
+        assert!(validate_payment_method_customer("cus_1", Some("cus_1"), None).is_ok());
+        // The customer of the payment takes precedence over the one of the request
+        assert!(validate_payment_method_customer("cus_1", Some("cus_2"), Some("cus_1")).is_err());
+        assert!(validate_payment_method_customer("cus_1", None, Some("cus_2")).is_err());
+        assert!(validate_payment_method_customer("cus_1", None, None).is_err());
+    }
+
+    #[test]
+    fn test_split_payments_breakdown() {
+        let split_payments = api_models::payments::SplitPaymentsRequest {
+            application_fee: 150,
+            destination_account_id: "acct_123".to_string(),
+        };
+
+        let breakdown = make_split_payments_breakdown(&split_payments, 1000).unwrap();
+        assert_eq!(breakdown["destination_amount"], 850);
+        assert!(make_split_payments_breakdown(&split_payments, 100).is_err());
+    }
+
+    #[test]
+    fn test_validate_statement_descriptor() {
+        assert_eq!(
+            get_statement_descriptor(Some("HYPERSWITCH"), Some("SHOES")),
+            Some("HYPERSWITCH* SHOES".to_string())
+        );
+        assert!(validate_statement_descriptor(Some("HYPERSWITCH"), Some("SHOES"), None).is_ok());
+        assert!(validate_statement_descriptor(Some("12345"), None, None).is_err());
+        assert!(validate_statement_descriptor(Some("<HYPERSWITCH>"), None, None).is_err());
+
+        // 25 characters fit on Visa but not on other networks
+        let suffix = Some("RUNNING SHOE");
+        assert!(validate_statement_descriptor(
+            Some("HYPERSWITCH"),
+            suffix,
+            Some(&api_enums::CardNetwork::Visa)
+        )
+        .is_ok());
+        assert!(validate_statement_descriptor(
+            Some("HYPERSWITCH"),
+            suffix,
+            Some(&api_enums::CardNetwork::Mastercard)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_authorization_expiry_schedule_time() {
+        let connector_capabilities = settings::ConnectorCapabilities(
+            [(
+                "stripe".to_string(),
+                settings::ConnectorCapability {
+                    authorization_validity_in_secs: Some(604800),
+                    ..Default::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+        );
+        let policy = api::AuthorizationExpiryPolicy {
+            action: api_enums::AuthorizationExpiryAction::Void,
+            notice_period_in_secs: Some(3600),
+        };
+        let authorized_at = common_utils::date_time::now();
+
+        assert_eq!(
+            get_authorization_expiry_schedule_time(
+                &connector_capabilities,
+                "stripe",
+                &policy,
+                authorized_at
+            ),
+            Some(authorized_at.saturating_add(time::Duration::seconds(604800 - 3600)))
+        );
+        assert_eq!(
+            get_authorization_expiry_schedule_time(
+                &connector_capabilities,
+                "adyen",
+                &policy,
+                authorized_at
+            ),
+            None
+        );
+    }
 }
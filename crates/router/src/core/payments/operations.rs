@@ -11,7 +11,7 @@ pub mod payment_update;
 
 use async_trait::async_trait;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 pub use self::{
     payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
@@ -209,7 +209,15 @@ where
         BoxedOperation<'a, F, api::PaymentsRetrieveRequest>,
         Option<api::PaymentMethodData>,
     )> {
-        helpers::make_pm_data(Box::new(self), state, payment_data).await
+        // A token is only set when the customer returns from authentication, a payment method
+        // that can no longer be retrieved should not fail the sync itself
+        match helpers::make_pm_data(Box::new(self), state, payment_data).await {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                logger::warn!(payment_method_retrieval_error=?error);
+                Ok((Box::new(self), None))
+            }
+        }
     }
 }
 
@@ -129,6 +129,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
+                    payer_document: None,
+                    installments: None,
+                    multiple_capture_data: None,
+                    card_network: None,
+                    sca_exemption: None,
+                    request_external_three_ds_authentication: false,
+                    external_authentication_data: None,
+                    allow_duplicate: false,
+                    network_transaction_id: None,
                 },
                 None,
             )),
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
@@ -86,6 +86,54 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         amount = payment_attempt.amount.into();
 
+        let multiple_capture_data = if capture_method == enums::CaptureMethod::ManualMultiple {
+            let amount_to_capture = request
+                .amount_to_capture
+                .get_required_value("amount_to_capture")?;
+
+            let previous_captures = db
+                .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    merchant_id,
+                    &payment_id,
+                    &payment_attempt.attempt_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching previous captures")?;
+
+            helpers::validate_amount_to_capture(
+                payment_attempt.amount - payments::get_captured_amount(&previous_captures),
+                Some(amount_to_capture),
+            )?;
+
+            let capture_sequence = i16::try_from(previous_captures.len() + 1)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Exceeded the maximum number of captures for the payment")?;
+
+            let current_capture = db
+                .insert_capture(
+                    helpers::make_new_capture(
+                        &payment_attempt,
+                        amount_to_capture,
+                        currency,
+                        capture_sequence,
+                    )?,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while inserting capture")?;
+
+            Some(payments::MultipleCaptureData {
+                previous_captures,
+                current_capture,
+            })
+        } else {
+            None
+        };
+
         let connector_response = db
             .find_connector_response_by_payment_id_merchant_id_attempt_id(
                 &payment_attempt.payment_id,
@@ -139,6 +187,15 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
+                payer_document: None,
+                installments: None,
+                multiple_capture_data,
+                card_network: None,
+                sca_exemption: None,
+                request_external_three_ds_authentication: false,
+                external_authentication_data: None,
+                allow_duplicate: false,
+                network_transaction_id: None,
             },
             None,
         ))
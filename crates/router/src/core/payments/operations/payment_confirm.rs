@@ -117,6 +117,16 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         )?;
 
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
+        payment_attempt.payment_method_id = request
+            .payment_method_id
+            .clone()
+            .or(payment_attempt.payment_method_id);
+        let network_transaction_id = helpers::get_network_transaction_id(
+            db,
+            payment_attempt.payment_method_id.as_ref(),
+            request.off_session.or(payment_intent.off_session),
+        )
+        .await?;
         payment_attempt.browser_info = browser_info;
         payment_attempt.payment_method_type = request
             .payment_method_type
@@ -130,6 +140,32 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
 
+        if let Some(ref split_payments) = request.split_payments {
+            payment_intent.split_payments = Some(helpers::make_split_payments_breakdown(
+                split_payments,
+                payment_attempt.amount,
+            )?);
+        }
+
+        if let Some(ref connector_metadata) = request.connector_metadata {
+            payment_intent.connector_metadata =
+                Some(helpers::encode_connector_metadata(connector_metadata)?);
+        }
+
+        payment_intent.statement_descriptor_name = request
+            .statement_descriptor
+            .clone()
+            .or(payment_intent.statement_descriptor_name);
+        payment_intent.statement_descriptor_suffix = request
+            .statement_descriptor_suffix
+            .clone()
+            .or(payment_intent.statement_descriptor_suffix);
+        helpers::validate_statement_descriptor(
+            payment_intent.statement_descriptor_name.as_deref(),
+            payment_intent.statement_descriptor_suffix.as_deref(),
+            helpers::get_preferred_card_network(request).as_ref(),
+        )?;
+
         helpers::validate_customer_id_mandatory_cases(
             request.shipping.is_some(),
             request.billing.is_some(),
@@ -196,6 +232,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                payer_document: request.payer_document.clone(),
+                installments: request.installments.clone(),
+                multiple_capture_data: None,
+                card_network: helpers::get_preferred_card_network(request),
+                sca_exemption: None,
+                request_external_three_ds_authentication: request
+                    .request_external_three_ds_authentication
+                    .unwrap_or(false),
+                external_authentication_data: None,
+                allow_duplicate: request.allow_duplicate.unwrap_or(false),
+                network_transaction_id,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -358,6 +405,17 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let customer_id = customer.map(|c| c.customer_id);
         let return_url = payment_data.payment_intent.return_url.clone();
         let setup_future_usage = payment_data.payment_intent.setup_future_usage;
+        let split_payments = payment_data.payment_intent.split_payments.clone();
+        let forex_quote = payment_data.payment_intent.forex_quote.clone();
+        let connector_metadata = payment_data.payment_intent.connector_metadata.clone();
+        let statement_descriptor_name = payment_data
+            .payment_intent
+            .statement_descriptor_name
+            .clone();
+        let statement_descriptor_suffix = payment_data
+            .payment_intent
+            .statement_descriptor_suffix
+            .clone();
 
         payment_data.payment_intent = db
             .update_payment_intent(
@@ -371,6 +429,11 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     shipping_address_id: shipping_address,
                     billing_address_id: billing_address,
                     return_url,
+                    split_payments,
+                    forex_quote,
+                    connector_metadata,
+                    statement_descriptor_name,
+                    statement_descriptor_suffix,
                 },
                 storage_scheme,
             )
@@ -9,9 +9,11 @@ use uuid::Uuid;
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    configs::settings,
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        forex,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -87,6 +89,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         )
         .await?;
 
+        if let Some(ref profile_id) = request.profile_id {
+            db.find_business_profile_by_merchant_id_profile_id(merchant_id, profile_id)
+                .await
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound)
+                })?;
+        }
+
         let browser_info = request
             .browser_info
             .clone()
@@ -117,6 +127,21 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 })
             })?;
 
+        let network_transaction_id = helpers::get_network_transaction_id(
+            db,
+            payment_attempt.payment_method_id.as_ref(),
+            request.off_session,
+        )
+        .await?;
+
+        let forex_quote = forex::make_payment_forex_quote(
+            state,
+            amount.into(),
+            currency.foreign_into(),
+            request.settlement_currency,
+        )
+        .await?;
+
         payment_intent = db
             .insert_payment_intent(
                 Self::make_payment_intent(
@@ -126,6 +151,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     request,
                     shipping_address.clone().map(|x| x.address_id),
                     billing_address.clone().map(|x| x.address_id),
+                    forex_quote,
+                    &state.conf.scheduled_capture,
                 )?,
                 storage_scheme,
             )
@@ -158,6 +185,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
                     mandate_id: mandate_obj.mandate_id,
                     connector_mandate_id: mandate_obj.connector_mandate_id,
+                    network_transaction_id: mandate_obj.network_transaction_id,
                 }))
             })
             .await
@@ -192,6 +220,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                payer_document: request.payer_document.clone(),
+                installments: request.installments.clone(),
+                multiple_capture_data: None,
+                card_network: helpers::get_preferred_card_network(request),
+                sca_exemption: None,
+                request_external_three_ds_authentication: request
+                    .request_external_three_ds_authentication
+                    .unwrap_or(false),
+                external_authentication_data: None,
+                allow_duplicate: request.allow_duplicate.unwrap_or(false),
+                network_transaction_id,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -298,6 +337,15 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let payment_token = payment_data.token.clone();
         let connector = payment_data.payment_attempt.connector.clone();
+        let additional_pm_data = payment_data
+            .payment_method_data
+            .as_ref()
+            .map(api_models::payments::AdditionalPaymentData::from)
+            .as_ref()
+            .map(Encode::<api_models::payments::AdditionalPaymentData>::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode additional pm data")?;
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -305,6 +353,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                 storage::PaymentAttemptUpdate::UpdateTrackers {
                     payment_token,
                     connector,
+                    payment_method_data: additional_pm_data,
                 },
                 storage_scheme,
             )
@@ -374,6 +423,14 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_scheduled_capture(request.capture_method, request.capture_on)?;
+
+        helpers::validate_statement_descriptor(
+            request.statement_descriptor.as_deref(),
+            request.statement_descriptor_suffix.as_deref(),
+            helpers::get_preferred_card_network(request).as_ref(),
+        )?;
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type = helpers::validate_mandate(request)?;
@@ -456,6 +513,7 @@ impl PaymentCreate {
             browser_info,
             payment_experience: request.payment_experience.map(ForeignInto::foreign_into),
             payment_method_type: request.payment_method_type.map(ForeignInto::foreign_into),
+            payment_method_id: request.payment_method_id.clone(),
             payment_method_data: additional_pm_data,
             connector,
             ..storage::PaymentAttemptNew::default()
@@ -470,6 +528,8 @@ impl PaymentCreate {
         request: &api::PaymentsRequest,
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
+        forex_quote: Option<serde_json::Value>,
+        scheduled_capture_settings: &settings::ScheduledCaptureSettings,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status =
@@ -484,6 +544,27 @@ impl PaymentCreate {
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Encoding Metadata to value failed")?;
+        let split_payments = request
+            .split_payments
+            .as_ref()
+            .map(|split_payments| {
+                helpers::make_split_payments_breakdown(split_payments, amount.into())
+            })
+            .transpose()?;
+        let connector_metadata = request
+            .connector_metadata
+            .as_ref()
+            .map(helpers::encode_connector_metadata)
+            .transpose()?;
+        let capture_on = match request.capture_method {
+            Some(api_models::enums::CaptureMethod::Scheduled) => request.capture_on,
+            _ => None,
+        };
+        let capture_expires_at = capture_on.map(|capture_on| {
+            capture_on.saturating_add(time::Duration::seconds(
+                scheduled_capture_settings.expiry_in_secs,
+            ))
+        });
         Ok(storage::PaymentIntentNew {
             payment_id: payment_id.to_string(),
             merchant_id: merchant_id.to_string(),
@@ -503,6 +584,12 @@ impl PaymentCreate {
             statement_descriptor_name: request.statement_descriptor.clone(),
             statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
             metadata,
+            split_payments,
+            forex_quote,
+            capture_on,
+            capture_expires_at,
+            profile_id: request.profile_id.clone(),
+            connector_metadata,
             ..storage::PaymentIntentNew::default()
         })
     }
@@ -521,6 +608,8 @@ impl PaymentCreate {
             connector_transaction_id: None,
             authentication_data: None,
             encoded_data: None,
+            connector_request_payload: None,
+            connector_response_payload: None,
         }
     }
 }
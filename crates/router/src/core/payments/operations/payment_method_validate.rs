@@ -151,6 +151,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
+                payer_document: None,
+                installments: None,
+                multiple_capture_data: None,
+                card_network: None,
+                sca_exemption: None,
+                request_external_three_ds_authentication: false,
+                external_authentication_data: None,
+                allow_duplicate: false,
+                network_transaction_id: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -1,20 +1,21 @@
 use async_trait::async_trait;
 use common_utils::fp_utils;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_derive;
+use strum::EnumMessage;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{self, PaymentData},
     },
     db::StorageInterface,
     services::RedirectForm,
     types::{
         self, api,
         storage::{self, enums},
-        transformers::ForeignInto,
+        transformers::{ForeignFrom, ForeignInto},
     },
     utils,
 };
@@ -154,14 +155,28 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         let router_response = router_data.response.clone();
         let connector = router_data.connector.clone();
 
-        payment_data = payment_response_update_tracker(
-            db,
-            payment_id,
-            payment_data,
-            router_data,
-            storage_scheme,
-        )
-        .await?;
+        payment_data = match payment_data.multiple_capture_data.take() {
+            Some(multiple_capture_data) => {
+                multiple_capture_update_tracker(
+                    db,
+                    payment_data,
+                    multiple_capture_data,
+                    router_data,
+                    storage_scheme,
+                )
+                .await?
+            }
+            None => {
+                payment_response_update_tracker(
+                    db,
+                    payment_id,
+                    payment_data,
+                    router_data,
+                    storage_scheme,
+                )
+                .await?
+            }
+        };
 
         router_response.map_err(|error_response| {
             errors::ApiErrorResponse::ExternalConnectorError {
@@ -269,6 +284,9 @@ async fn payment_response_update_tracker<F: Clone, T>(
     router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    let connector_latency_in_millis = router_data
+        .connector_latency
+        .and_then(|latency| i64::try_from(latency.as_millis()).ok());
     let (payment_attempt_update, connector_response_update) = match router_data.response.clone() {
         Err(err) => (
             Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -276,6 +294,13 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 status: storage::enums::AttemptStatus::Failure,
                 error_message: Some(err.message),
                 error_code: Some(err.code),
+                unified_code: err.unified_code,
+                unified_message: err.unified_code.and_then(|unified_code| {
+                    api::enums::UnifiedCode::foreign_from(unified_code)
+                        .get_message()
+                        .map(ToOwned::to_owned)
+                }),
+                connector_latency_in_millis,
             }),
             Some(storage::ConnectorResponseUpdate::ErrorUpdate {
                 connector_name: Some(router_data.connector.clone()),
@@ -286,6 +311,9 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 resource_id,
                 redirection_data,
                 connector_metadata,
+                network_txn_id,
+                avs_result,
+                cvv_result,
                 ..
             } => {
                 let connector_transaction_id = match resource_id {
@@ -307,13 +335,18 @@ async fn payment_response_update_tracker<F: Clone, T>(
                     status: router_data.status,
                     connector: Some(router_data.connector),
                     connector_transaction_id: connector_transaction_id.clone(),
-                    authentication_type: None,
+                    // A challenge may have been asked for after the payment was soft declined
+                    authentication_type: payment_data.payment_attempt.authentication_type,
                     payment_method_id: Some(router_data.payment_method_id),
                     mandate_id: payment_data
                         .mandate_id
                         .clone()
                         .map(|mandate| mandate.mandate_id),
                     connector_metadata,
+                    connector_latency_in_millis,
+                    network_transaction_id: network_txn_id,
+                    avs_result,
+                    cvv_result,
                 };
 
                 let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
@@ -391,3 +424,86 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     Ok(payment_data)
 }
+
+/// Updates the capture made on a payment with `manual_multiple` capture method. The connector
+/// transaction ID of the authorization is left untouched, since connectors return a separate
+/// reference for every capture.
+async fn multiple_capture_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    multiple_capture_data: payments::MultipleCaptureData,
+    router_data: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let capture_update = match router_data.response {
+        Err(ref err) => storage::CaptureUpdate::ErrorUpdate {
+            status: enums::CaptureStatus::Failed,
+            error_code: Some(err.code.clone()),
+            error_message: Some(err.message.clone()),
+        },
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            ref resource_id, ..
+        }) => storage::CaptureUpdate::ResponseUpdate {
+            status: router_data.status.foreign_into(),
+            connector_capture_id: resource_id.get_connector_transaction_id().ok(),
+        },
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected connector response for capture")?,
+    };
+
+    let current_capture = db
+        .update_capture_with_capture_id(
+            multiple_capture_data.current_capture,
+            capture_update,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating capture")?;
+
+    let multiple_capture_data = payments::MultipleCaptureData {
+        previous_captures: multiple_capture_data.previous_captures,
+        current_capture,
+    };
+
+    // A failed capture does not affect the authorization, the remaining amount can still be captured
+    if router_data.response.is_ok() {
+        let amount_captured =
+            payments::get_captured_amount(&multiple_capture_data.get_all_captures());
+        let status = if amount_captured >= payment_data.payment_attempt.amount {
+            enums::AttemptStatus::Charged
+        } else {
+            enums::AttemptStatus::PartialCharged
+        };
+
+        payment_data.payment_attempt = db
+            .update_payment_attempt(
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::StatusUpdate { status },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        payment_data.payment_intent = db
+            .update_payment_intent(
+                payment_data.payment_intent,
+                storage::PaymentIntentUpdate::ResponseUpdate {
+                    status: status.foreign_into(),
+                    amount_captured: Some(amount_captured),
+                    return_url: None,
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+    }
+
+    payment_data.multiple_capture_data = Some(multiple_capture_data);
+    Ok(payment_data)
+}
@@ -158,6 +158,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
+                payer_document: None,
+                installments: None,
+                multiple_capture_data: None,
+                card_network: None,
+                sca_exemption: None,
+                request_external_three_ds_authentication: false,
+                external_authentication_data: None,
+                allow_duplicate: false,
+                network_transaction_id: None,
             },
             Some(customer_details),
         ))
@@ -328,41 +337,14 @@ where
 
         // Parse the payment methods enabled to check if the merchant has enabled googlepay ( wallet ) using that connector.
         // A single connector can support creating session token from metadata as well as by calling the connector.
-        let session_token_from_metadata_connectors = connector_accounts
-            .iter()
-            .filter(|connector_account| {
-                connector_account
-                    .payment_methods_enabled
-                    .clone()
-                    .unwrap_or_default()
-                    .iter()
-                    .any(|payment_method| {
-                        let parsed_payment_method_result: Result<
-                            PaymentMethodsEnabled,
-                            error_stack::Report<errors::ParsingError>,
-                        > = payment_method.clone().parse_value("payment_method");
-
-                        match parsed_payment_method_result {
-                            Ok(parsed_payment_method) => parsed_payment_method
-                                .payment_method_types
-                                .map(|payment_method_types| {
-                                    payment_method_types.iter().any(|payment_method_type| {
-                                        matches!(
-                                            payment_method_type.payment_method_type,
-                                            api_models::enums::PaymentMethodType::GooglePay
-                                        )
-                                    })
-                                })
-                                .unwrap_or(false),
-                            Err(parsing_error) => {
-                                logger::debug!(session_token_parsing_error=?parsing_error);
-                                false
-                            }
-                        }
-                    })
-            })
-            .map(|filtered_connector| filtered_connector.connector_name.clone())
-            .collect::<HashSet<String>>();
+        let session_token_from_metadata_connectors = get_connectors_with_payment_method_type(
+            &connector_accounts,
+            api_enums::PaymentMethodType::GooglePay,
+        );
+        let click_to_pay_connectors = get_connectors_with_payment_method_type(
+            &connector_accounts,
+            api_enums::PaymentMethodType::ClickToPay,
+        );
 
         let given_wallets = request.wallets.clone();
 
@@ -371,7 +353,28 @@ where
             let mut connectors_data = Vec::with_capacity(supported_connectors.len());
             for wallet in given_wallets {
                 let (connector_name, connector_type) = match wallet {
-                    api_enums::SupportedWallets::Gpay => ("adyen", api::GetToken::Metadata),
+                    // Google Pay session tokens are created from the metadata of every connector
+                    // the merchant has enabled Google Pay on
+                    api_enums::SupportedWallets::Gpay => {
+                        for connector_name in &session_token_from_metadata_connectors {
+                            connectors_data.push(api::ConnectorData::get_connector_by_name(
+                                connectors,
+                                connector_name,
+                                api::GetToken::Metadata,
+                            )?);
+                        }
+                        continue;
+                    }
+                    api_enums::SupportedWallets::ClickToPay => {
+                        for connector_name in &click_to_pay_connectors {
+                            connectors_data.push(api::ConnectorData::get_connector_by_name(
+                                connectors,
+                                connector_name,
+                                api::GetToken::ClickToPayMetadata,
+                            )?);
+                        }
+                        continue;
+                    }
                     api_enums::SupportedWallets::ApplePay => ("applepay", api::GetToken::Connector),
                     api_enums::SupportedWallets::Paypal => ("braintree", api::GetToken::Connector),
                     api_enums::SupportedWallets::Klarna => ("klarna", api::GetToken::Connector),
@@ -392,7 +395,9 @@ where
         } else {
             // Create connectors for all enabled wallets
             let mut connectors_data = Vec::with_capacity(
-                normal_connector_names.len() + session_token_from_metadata_connectors.len(),
+                normal_connector_names.len()
+                    + session_token_from_metadata_connectors.len()
+                    + click_to_pay_connectors.len(),
             );
 
             for connector_name in normal_connector_names {
@@ -412,9 +417,60 @@ where
                 )?;
                 connectors_data.push(connector_data);
             }
+
+            for connector_name in click_to_pay_connectors {
+                let connector_data = api::ConnectorData::get_connector_by_name(
+                    connectors,
+                    &connector_name,
+                    api::GetToken::ClickToPayMetadata,
+                )?;
+                connectors_data.push(connector_data);
+            }
             connectors_data
         };
 
         Ok(api::ConnectorCallType::Multiple(connectors_data))
     }
 }
+
+/// Connectors of the merchant on which the given payment method type is enabled
+fn get_connectors_with_payment_method_type(
+    connector_accounts: &[storage::MerchantConnectorAccount],
+    payment_method_type: api_enums::PaymentMethodType,
+) -> HashSet<String> {
+    connector_accounts
+        .iter()
+        .filter(|connector_account| {
+            connector_account
+                .payment_methods_enabled
+                .clone()
+                .unwrap_or_default()
+                .iter()
+                .any(|payment_method| {
+                    let parsed_payment_method_result: Result<
+                        PaymentMethodsEnabled,
+                        error_stack::Report<errors::ParsingError>,
+                    > = payment_method.clone().parse_value("payment_method");
+
+                    match parsed_payment_method_result {
+                        Ok(parsed_payment_method) => parsed_payment_method
+                            .payment_method_types
+                            .map(|payment_method_types| {
+                                payment_method_types
+                                    .iter()
+                                    .any(|enabled_payment_method_type| {
+                                        enabled_payment_method_type.payment_method_type
+                                            == payment_method_type
+                                    })
+                            })
+                            .unwrap_or(false),
+                        Err(parsing_error) => {
+                            logger::debug!(session_token_parsing_error=?parsing_error);
+                            false
+                        }
+                    }
+                })
+        })
+        .map(|filtered_connector| filtered_connector.connector_name.clone())
+        .collect()
+}
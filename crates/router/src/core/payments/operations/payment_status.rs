@@ -245,6 +245,13 @@ async fn get_tracker_for_sync<
 
     let contains_encoded_data = connector_response.encoded_data.is_some();
 
+    // Connectors completing the payment after a challenge need the payment method again
+    let token = request
+        .param
+        .as_ref()
+        .filter(|_| payment_attempt.status == enums::AttemptStatus::AuthenticationPending)
+        .and(payment_attempt.payment_token.clone());
+
     Ok((
         Box::new(operation),
         PaymentData {
@@ -256,7 +263,7 @@ async fn get_tracker_for_sync<
             email: None,
             mandate_id: None,
             setup_mandate: None,
-            token: None,
+            token,
             address: PaymentAddress {
                 shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
                 billing: billing_address.as_ref().map(|a| a.foreign_into()),
@@ -274,6 +281,15 @@ async fn get_tracker_for_sync<
             refunds,
             sessions_token: vec![],
             card_cvc: None,
+            payer_document: None,
+            installments: None,
+            multiple_capture_data: None,
+            card_network: None,
+            sca_exemption: None,
+            request_external_three_ds_authentication: false,
+            external_authentication_data: None,
+            allow_duplicate: false,
+            network_transaction_id: None,
         },
         None,
     ))
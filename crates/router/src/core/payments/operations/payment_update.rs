@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        forex,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -104,11 +105,64 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         };
 
         payment_attempt.payment_method = payment_method_type.or(payment_attempt.payment_method);
+        payment_attempt.payment_method_id = request
+            .payment_method_id
+            .clone()
+            .or(payment_attempt.payment_method_id);
+        let network_transaction_id = helpers::get_network_transaction_id(
+            db,
+            payment_attempt.payment_method_id.as_ref(),
+            request.off_session.or(payment_intent.off_session),
+        )
+        .await?;
 
         let amount = request
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        // The breakdown is recomputed so that it stays in line with an updated amount
+        let split_payments = match request.split_payments {
+            Some(ref split_payments) => Some(split_payments.clone()),
+            None => helpers::get_split_payments_breakdown(&payment_intent)?
+                .map(api_models::payments::SplitPaymentsRequest::from),
+        };
+        payment_intent.split_payments = split_payments
+            .map(|split_payments| {
+                helpers::make_split_payments_breakdown(&split_payments, amount.into())
+            })
+            .transpose()?;
+
+        // The quote is renewed so that it reflects an updated amount or currency
+        let settlement_currency = request
+            .settlement_currency
+            .or(forex::get_payment_forex_quote(&payment_intent)?.map(|quote| quote.to_currency));
+        payment_intent.forex_quote = forex::make_payment_forex_quote(
+            state,
+            amount.into(),
+            currency.foreign_into(),
+            settlement_currency,
+        )
+        .await?;
+
+        if let Some(ref connector_metadata) = request.connector_metadata {
+            payment_intent.connector_metadata =
+                Some(helpers::encode_connector_metadata(connector_metadata)?);
+        }
+
+        payment_intent.statement_descriptor_name = request
+            .statement_descriptor
+            .clone()
+            .or(payment_intent.statement_descriptor_name);
+        payment_intent.statement_descriptor_suffix = request
+            .statement_descriptor_suffix
+            .clone()
+            .or(payment_intent.statement_descriptor_suffix);
+        helpers::validate_statement_descriptor(
+            payment_intent.statement_descriptor_name.as_deref(),
+            payment_intent.statement_descriptor_suffix.as_deref(),
+            helpers::get_preferred_card_network(request).as_ref(),
+        )?;
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.shipping.is_some(),
@@ -179,6 +233,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
                     mandate_id: mandate_obj.mandate_id,
                     connector_mandate_id: mandate_obj.connector_mandate_id,
+                    network_transaction_id: mandate_obj.network_transaction_id,
                 }))
             })
             .await
@@ -233,6 +288,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                payer_document: request.payer_document.clone(),
+                installments: request.installments.clone(),
+                multiple_capture_data: None,
+                card_network: helpers::get_preferred_card_network(request),
+                sca_exemption: None,
+                request_external_three_ds_authentication: request
+                    .request_external_three_ds_authentication
+                    .unwrap_or(false),
+                external_authentication_data: None,
+                allow_duplicate: request.allow_duplicate.unwrap_or(false),
+                network_transaction_id,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -388,6 +454,17 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let return_url = payment_data.payment_intent.return_url.clone();
         let setup_future_usage = payment_data.payment_intent.setup_future_usage;
+        let split_payments = payment_data.payment_intent.split_payments.clone();
+        let forex_quote = payment_data.payment_intent.forex_quote.clone();
+        let connector_metadata = payment_data.payment_intent.connector_metadata.clone();
+        let statement_descriptor_name = payment_data
+            .payment_intent
+            .statement_descriptor_name
+            .clone();
+        let statement_descriptor_suffix = payment_data
+            .payment_intent
+            .statement_descriptor_suffix
+            .clone();
 
         payment_data.payment_intent = db
             .update_payment_intent(
@@ -401,6 +478,11 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     shipping_address_id: shipping_address,
                     billing_address_id: billing_address,
                     return_url,
+                    split_payments,
+                    forex_quote,
+                    connector_metadata,
+                    statement_descriptor_name,
+                    statement_descriptor_suffix,
                 },
                 storage_scheme,
             )
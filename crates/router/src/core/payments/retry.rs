@@ -0,0 +1,464 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::Operation,
+    CallConnectorAction, PaymentData, PaymentResponse,
+};
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api, storage, storage::enums as storage_enums},
+    utils::Encode,
+};
+
+/// A connector call which failed with a retriable error, after which the payment was moved to the
+/// next connector. These are recorded on the payment attempt in the order they were made.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorAttempt {
+    pub connector: String,
+    pub status_code: Option<u16>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    /// Time taken by the connector to respond, unknown when the call failed without a response
+    #[serde(default)]
+    pub latency_in_millis: Option<u64>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub attempted_at: time::PrimitiveDateTime,
+}
+
+/// How a connector call failed, as far as the payment at the connector is concerned
+#[derive(Debug)]
+enum RetriableFailure {
+    /// The request never reached the connector, or the connector declined it outright
+    NotProcessed(ConnectorAttempt),
+    /// The connector may have authorized the payment before the call failed, so the payment must be
+    /// synced before it can be moved to another connector
+    InDoubt(ConnectorAttempt),
+}
+
+/// Calls the connectors in the given order, moving on to the next connector when the call fails
+/// with a retriable error. A payment the failed connector may have authorized is only moved on once
+/// a sync confirms it was not, so that the customer is never charged twice. The trackers are only
+/// updated with the response of the last call.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn call_connector_service_with_failover<F, Op, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    connectors: Vec<api::ConnectorData>,
+    operation: &Op,
+    mut payment_data: PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    call_connector_action: CallConnectorAction,
+) -> RouterResult<PaymentData<F>>
+where
+    Op: Debug + Sync,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let mut connectors = connectors.into_iter();
+    let first_connector = connectors
+        .next()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("No connector given for failover")?;
+
+    // Failover only makes sense when the connector is actually called
+    if !matches!(call_connector_action, CallConnectorAction::Trigger) {
+        return super::call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            first_connector,
+            operation,
+            payment_data,
+            customer,
+            call_connector_action,
+        )
+        .await;
+    }
+
+    let mut connector = first_connector;
+    let mut connector_attempts = Vec::new();
+    let router_data_res = loop {
+        let router_data_res = super::call_connector(
+            state,
+            merchant_account,
+            &connector,
+            &payment_data,
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await;
+        let router_data_res = super::sca_exemptions::challenge_soft_decline(
+            state,
+            merchant_account,
+            &connector,
+            &mut payment_data,
+            customer,
+            router_data_res,
+        )
+        .await;
+
+        let next_connector = match connectors.next() {
+            Some(next_connector) => next_connector,
+            None => break router_data_res,
+        };
+
+        let connector_attempt = match get_retriable_failure(
+            &state.conf.payment_retries,
+            &connector,
+            &router_data_res,
+        ) {
+            Some(RetriableFailure::NotProcessed(connector_attempt)) => connector_attempt,
+            Some(RetriableFailure::InDoubt(connector_attempt)) => {
+                if !is_unprocessed_at_connector(
+                    state,
+                    merchant_account,
+                    &connector,
+                    &payment_data,
+                    customer,
+                )
+                .await
+                {
+                    logger::warn!(
+                        failed_connector = %connector_attempt.connector,
+                        "Not retrying authorization, the connector may have processed the payment"
+                    );
+                    break router_data_res;
+                }
+                connector_attempt
+            }
+            None => break router_data_res,
+        };
+
+        logger::info!(
+            failed_connector = %connector_attempt.connector,
+            next_connector = %next_connector.connector_name,
+            "Retrying authorization on the next connector"
+        );
+        connector_attempts.push(connector_attempt);
+        connector = next_connector;
+    };
+
+    let payment_data =
+        record_connector_attempts(state, merchant_account, payment_data, connector_attempts)
+            .await?;
+
+    super::update_trackers_with_connector_response(
+        state,
+        merchant_account,
+        payment_id,
+        payment_data,
+        router_data_res?,
+    )
+    .await
+}
+
+/// Returns the details of the failed call if the connector responded with an error on which the
+/// payment can be retried on another connector.
+fn get_retriable_failure<F, Req>(
+    retry_config: &settings::PaymentRetries,
+    connector: &api::ConnectorData,
+    router_data_res: &RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>,
+) -> Option<RetriableFailure> {
+    let (is_in_doubt, status_code, error_code, error_message, latency) = match router_data_res {
+        // Network failures never reach the connector's response handling
+        Err(error) => match error.downcast_ref::<errors::ApiClientError>() {
+            Some(client_error) => (
+                is_in_doubt_client_error(client_error),
+                None,
+                None,
+                Some(error.current_context().to_string()),
+                None,
+            ),
+            None => return None,
+        },
+        Ok(router_data) => match router_data.response {
+            Err(ref error_response) => (
+                is_in_doubt_error_response(retry_config, error_response)?,
+                Some(error_response.status_code),
+                Some(error_response.code.clone()),
+                Some(error_response.message.clone()),
+                router_data.connector_latency,
+            ),
+            Ok(_) => return None,
+        },
+    };
+
+    let connector_attempt = ConnectorAttempt {
+        connector: connector.connector_name.to_string(),
+        status_code,
+        error_code,
+        error_message,
+        latency_in_millis: latency.and_then(|latency| u64::try_from(latency.as_millis()).ok()),
+        attempted_at: common_utils::date_time::now(),
+    };
+    Some(if is_in_doubt {
+        RetriableFailure::InDoubt(connector_attempt)
+    } else {
+        RetriableFailure::NotProcessed(connector_attempt)
+    })
+}
+
+/// Only failures to build the request or to connect guarantee that the connector never received
+/// it. Timeouts, dropped connections and unreadable responses may follow an authorization.
+fn is_in_doubt_client_error(client_error: &errors::ApiClientError) -> bool {
+    !matches!(
+        client_error,
+        errors::ApiClientError::HeaderMapConstructionFailed
+            | errors::ApiClientError::InvalidProxyConfiguration
+            | errors::ApiClientError::ClientConstructionFailed
+            | errors::ApiClientError::CertificateDecodeFailed
+            | errors::ApiClientError::UrlEncodingFailed
+            | errors::ApiClientError::RequestNotSent(_)
+    )
+}
+
+/// Returns whether the payment may have been processed by the connector, if the error response is
+/// retriable. Error codes configured as retriable are declines, while server errors leave the
+/// outcome of the payment unknown.
+fn is_in_doubt_error_response(
+    retry_config: &settings::PaymentRetries,
+    error_response: &types::ErrorResponse,
+) -> Option<bool> {
+    let is_retriable_error_code = retry_config
+        .retriable_error_codes
+        .as_ref()
+        .map_or(false, |error_codes| {
+            error_codes.contains(&error_response.code)
+        });
+    if is_retriable_error_code {
+        Some(false)
+    } else if error_response.status_code >= 500 {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Syncs the payment with the connector the call failed at, returning whether the connector
+/// confirms that it did not authorize the payment. Any other outcome, including a failed sync, is
+/// treated as a possible authorization.
+async fn is_unprocessed_at_connector<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+    customer: &Option<storage::Customer>,
+) -> bool {
+    let sync_data = get_sync_payment_data(payment_data);
+    let sync_res = super::call_connector::<api::PSync, types::PaymentsSyncData>(
+        state,
+        merchant_account,
+        connector,
+        &sync_data,
+        customer,
+        CallConnectorAction::Trigger,
+    )
+    .await;
+
+    match sync_res {
+        Ok(router_data) => is_unprocessed_sync_response(router_data.status, &router_data.response),
+        Err(error) => {
+            logger::warn!(?error, connector = %connector.connector_name, "Failed to sync payment before retrying");
+            false
+        }
+    }
+}
+
+fn is_unprocessed_sync_response(
+    status: storage_enums::AttemptStatus,
+    response: &Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> bool {
+    match response {
+        Ok(_) => matches!(
+            status,
+            storage_enums::AttemptStatus::Failure
+                | storage_enums::AttemptStatus::AuthorizationFailed
+                | storage_enums::AttemptStatus::Voided
+        ),
+        // The connector has no payment for the attempt
+        Err(error_response) => error_response.status_code == 404,
+    }
+}
+
+fn get_sync_payment_data<F: Clone>(payment_data: &PaymentData<F>) -> PaymentData<api::PSync> {
+    let payment_data = payment_data.clone();
+    PaymentData {
+        flow: PhantomData,
+        payment_intent: payment_data.payment_intent,
+        payment_attempt: payment_data.payment_attempt,
+        connector_response: payment_data.connector_response,
+        amount: payment_data.amount,
+        mandate_id: payment_data.mandate_id,
+        currency: payment_data.currency,
+        setup_mandate: payment_data.setup_mandate,
+        address: payment_data.address,
+        token: payment_data.token,
+        confirm: payment_data.confirm,
+        force_sync: Some(true),
+        payment_method_data: payment_data.payment_method_data,
+        refunds: payment_data.refunds,
+        sessions_token: payment_data.sessions_token,
+        card_cvc: payment_data.card_cvc,
+        email: payment_data.email,
+        payer_document: payment_data.payer_document,
+        installments: payment_data.installments,
+        multiple_capture_data: payment_data.multiple_capture_data,
+        card_network: payment_data.card_network,
+        sca_exemption: payment_data.sca_exemption,
+        request_external_three_ds_authentication: payment_data
+            .request_external_three_ds_authentication,
+        external_authentication_data: payment_data.external_authentication_data,
+        allow_duplicate: payment_data.allow_duplicate,
+        network_transaction_id: payment_data.network_transaction_id,
+    }
+}
+
+async fn record_connector_attempts<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    connector_attempts: Vec<ConnectorAttempt>,
+) -> RouterResult<PaymentData<F>> {
+    if connector_attempts.is_empty() {
+        return Ok(payment_data);
+    }
+
+    let connector_attempts = Encode::<Vec<ConnectorAttempt>>::encode_to_value(&connector_attempts)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode connector attempts")?;
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::ConnectorAttemptsUpdate {
+                connector_attempts: Some(connector_attempts),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector attempts on the payment attempt")?;
+
+    Ok(payment_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_response(code: &str, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "error".to_string(),
+            reason: None,
+            status_code,
+            unified_code: None,
+        }
+    }
+
+    #[test]
+    fn test_retriable_error_responses() {
+        let retry_config = settings::PaymentRetries {
+            enabled: true,
+            max_connector_attempts: 3,
+            retriable_error_codes: Some(["processor_unavailable".to_string()].into()),
+        };
+
+        assert_eq!(
+            is_in_doubt_error_response(&retry_config, &error_response("internal_error", 503)),
+            Some(true)
+        );
+        assert_eq!(
+            is_in_doubt_error_response(&retry_config, &error_response("gateway_timeout", 504)),
+            Some(true)
+        );
+        assert_eq!(
+            is_in_doubt_error_response(
+                &retry_config,
+                &error_response("processor_unavailable", 402)
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            is_in_doubt_error_response(&retry_config, &error_response("insufficient_funds", 402)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_in_doubt_client_errors() {
+        assert!(!is_in_doubt_client_error(
+            &errors::ApiClientError::RequestNotSent("connection refused".to_string())
+        ));
+        assert!(!is_in_doubt_client_error(
+            &errors::ApiClientError::ClientConstructionFailed
+        ));
+        assert!(is_in_doubt_client_error(
+            &errors::ApiClientError::RequestTimeoutReceived
+        ));
+        assert!(is_in_doubt_client_error(
+            &errors::ApiClientError::ConnectionClosed("connection reset".to_string())
+        ));
+        assert!(is_in_doubt_client_error(
+            &errors::ApiClientError::GatewayTimeoutReceived
+        ));
+        assert!(is_in_doubt_client_error(
+            &errors::ApiClientError::ResponseDecodingFailed
+        ));
+    }
+
+    #[test]
+    fn test_unprocessed_sync_responses() {
+        let transaction_response = Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::NoResponseId,
+            redirection_data: None,
+            mandate_reference: None,
+            connector_metadata: None,
+            network_txn_id: None,
+            avs_result: None,
+            cvv_result: None,
+        });
+
+        assert!(is_unprocessed_sync_response(
+            storage_enums::AttemptStatus::Failure,
+            &transaction_response
+        ));
+        assert!(!is_unprocessed_sync_response(
+            storage_enums::AttemptStatus::Authorized,
+            &transaction_response
+        ));
+        assert!(!is_unprocessed_sync_response(
+            storage_enums::AttemptStatus::Pending,
+            &transaction_response
+        ));
+        assert!(is_unprocessed_sync_response(
+            storage_enums::AttemptStatus::Pending,
+            &Err(error_response("resource_missing", 404))
+        ));
+        assert!(!is_unprocessed_sync_response(
+            storage_enums::AttemptStatus::Pending,
+            &Err(error_response("internal_error", 500))
+        ));
+    }
+}
@@ -0,0 +1,362 @@
+use super::PaymentData;
+use crate::{
+    db::StorageInterface,
+    logger,
+    types::{
+        api::{self, enums as api_enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// A stride coprime with 100, used to spread consecutive payments across the percentage buckets
+/// of a volume split so that connectors are interleaved rather than picked in long runs.
+const VOLUME_SPLIT_STRIDE: u64 = 37;
+
+/// The attributes of a payment that routing rules are evaluated against
+#[derive(Clone, Debug, Default)]
+pub struct RoutingInput {
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    pub currency: Option<api_enums::Currency>,
+    pub amount: i64,
+    pub card_bin_country: Option<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub card_type: Option<api_enums::CardType>,
+}
+
+impl<F: Clone> From<&PaymentData<F>> for RoutingInput {
+    fn from(payment_data: &PaymentData<F>) -> Self {
+        let (card_bin_country, card_network, card_type) = match payment_data.payment_method_data {
+            Some(api::PaymentMethodData::Card(ref card)) => (
+                card.card_issuing_country.clone(),
+                card.card_network.clone(),
+                card.card_type,
+            ),
+            _ => (None, None, None),
+        };
+
+        Self {
+            payment_method: payment_data
+                .payment_attempt
+                .payment_method
+                .map(|payment_method| payment_method.foreign_into()),
+            currency: Some(payment_data.currency.foreign_into()),
+            amount: payment_data.amount.into(),
+            card_bin_country,
+            card_network,
+            card_type,
+        }
+    }
+}
+
+/// Returns the connectors of the first rule matching the payment, falling back to the default
+/// connectors of the configuration. The connectors are ordered by priority.
+///
+/// When the matching rule splits traffic by volume, a per-rule counter in Redis is used as the
+/// seed for the split, so the configured percentages hold across all instances of the router.
+pub async fn get_connectors_for_payment(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    config: &api::RoutingConfig,
+    input: &RoutingInput,
+) -> Vec<api_enums::RoutableConnectors> {
+    let (rule_index, rule) = match find_matching_rule(config, input) {
+        Some(matching_rule) => matching_rule,
+        None => return config.default_connectors.clone(),
+    };
+
+    let volume_split = match rule.volume_split {
+        Some(ref volume_split) if !volume_split.is_empty() => volume_split,
+        _ => return rule.connectors.clone(),
+    };
+
+    let counter_key = format!(
+        "routing_split_{merchant_id}_{}_{rule_index}",
+        config.routing_id
+    );
+    let seed = match db.increment_key(&counter_key).await {
+        Ok(counter) => counter.unsigned_abs(),
+        Err(error) => {
+            // Losing the counter only affects how evenly traffic is split, so routing continues
+            logger::error!(?error, %counter_key, "Failed to increment volume split counter");
+            u64::from(common_utils::date_time::now().nanosecond())
+        }
+    };
+
+    order_connectors_by_volume_split(volume_split, &rule.connectors, seed)
+}
+
+fn find_matching_rule<'a>(
+    config: &'a api::RoutingConfig,
+    input: &RoutingInput,
+) -> Option<(usize, &'a api::RoutingRule)> {
+    config
+        .rules
+        .iter()
+        .enumerate()
+        .find(|(_, rule)| conditions_match(&rule.conditions, input))
+}
+
+/// Picks a connector from the split for the given seed. Every 100 consecutive seeds pick each
+/// connector exactly as many times as its percentage.
+pub fn select_connector_by_volume(
+    volume_split: &[api::ConnectorVolumeSplit],
+    seed: u64,
+) -> Option<api_enums::RoutableConnectors> {
+    let bucket = seed.wrapping_mul(VOLUME_SPLIT_STRIDE) % 100;
+    let mut cumulative_percentage = 0;
+
+    volume_split
+        .iter()
+        .find(|split| {
+            cumulative_percentage += u64::from(split.percentage);
+            bucket < cumulative_percentage
+        })
+        .map(|split| split.connector)
+}
+
+/// Places the connector picked by the split first, followed by the rule's connectors and then the
+/// remaining connectors of the split, which are used for failover.
+fn order_connectors_by_volume_split(
+    volume_split: &[api::ConnectorVolumeSplit],
+    connectors: &[api_enums::RoutableConnectors],
+    seed: u64,
+) -> Vec<api_enums::RoutableConnectors> {
+    let mut ordered_connectors: Vec<api_enums::RoutableConnectors> =
+        select_connector_by_volume(volume_split, seed)
+            .into_iter()
+            .collect();
+
+    for connector in connectors
+        .iter()
+        .copied()
+        .chain(volume_split.iter().map(|split| split.connector))
+    {
+        if !ordered_connectors.contains(&connector) {
+            ordered_connectors.push(connector);
+        }
+    }
+
+    ordered_connectors
+}
+
+fn conditions_match(conditions: &api::RoutingConditions, input: &RoutingInput) -> bool {
+    let payment_method_matches =
+        conditions
+            .payment_methods
+            .as_ref()
+            .map_or(true, |payment_methods| {
+                input.payment_method.map_or(false, |payment_method| {
+                    payment_methods.contains(&payment_method)
+                })
+            });
+
+    let currency_matches = conditions.currencies.as_ref().map_or(true, |currencies| {
+        input
+            .currency
+            .map_or(false, |currency| currencies.contains(&currency))
+    });
+
+    let amount_matches = conditions.amount.as_ref().map_or(true, |band| {
+        band.min.map_or(true, |min| input.amount >= min)
+            && band.max.map_or(true, |max| input.amount <= max)
+    });
+
+    let card_bin_country_matches =
+        conditions
+            .card_bin_countries
+            .as_ref()
+            .map_or(true, |countries| {
+                input
+                    .card_bin_country
+                    .as_ref()
+                    .map_or(false, |card_country| {
+                        countries
+                            .iter()
+                            .any(|country| country.eq_ignore_ascii_case(card_country))
+                    })
+            });
+
+    let card_network_matches = conditions
+        .card_networks
+        .as_ref()
+        .map_or(true, |card_networks| {
+            input
+                .card_network
+                .as_ref()
+                .map_or(false, |card_network| card_networks.contains(card_network))
+        });
+
+    let card_type_matches = conditions.card_types.as_ref().map_or(true, |card_types| {
+        input
+            .card_type
+            .map_or(false, |card_type| card_types.contains(&card_type))
+    });
+
+    payment_method_matches
+        && currency_matches
+        && amount_matches
+        && card_bin_country_matches
+        && card_network_matches
+        && card_type_matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routing_config() -> api::RoutingConfig {
+        api::RoutingConfig {
+            routing_id: "routing_test".to_string(),
+            name: "test".to_string(),
+            rules: vec![
+                api::RoutingRule {
+                    conditions: api::RoutingConditions {
+                        payment_methods: Some(vec![api_enums::PaymentMethod::Card]),
+                        card_bin_countries: Some(vec!["DE".to_string(), "FR".to_string()]),
+                        ..Default::default()
+                    },
+                    connectors: vec![api_enums::RoutableConnectors::Adyen],
+                    volume_split: None,
+                },
+                api::RoutingRule {
+                    conditions: api::RoutingConditions {
+                        currencies: Some(vec![api_enums::Currency::USD]),
+                        amount: Some(api::AmountBand {
+                            min: Some(1000),
+                            max: None,
+                        }),
+                        ..Default::default()
+                    },
+                    connectors: vec![
+                        api_enums::RoutableConnectors::Checkout,
+                        api_enums::RoutableConnectors::Stripe,
+                    ],
+                    volume_split: None,
+                },
+            ],
+            default_connectors: vec![api_enums::RoutableConnectors::Stripe],
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_decides_connectors() {
+        let config = routing_config();
+        let input = RoutingInput {
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            currency: Some(api_enums::Currency::USD),
+            amount: 5000,
+            card_bin_country: Some("de".to_string()),
+            card_network: Some(api_enums::CardNetwork::Visa),
+            card_type: Some(api_enums::CardType::Credit),
+        };
+
+        let (rule_index, rule) = find_matching_rule(&config, &input).unwrap();
+        assert_eq!(rule_index, 0);
+        assert_eq!(rule.connectors, [api_enums::RoutableConnectors::Adyen]);
+    }
+
+    #[test]
+    fn test_amount_band_is_evaluated() {
+        let config = routing_config();
+        let input = RoutingInput {
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            currency: Some(api_enums::Currency::USD),
+            amount: 5000,
+            card_bin_country: None,
+            card_network: None,
+            card_type: None,
+        };
+        assert_eq!(
+            find_matching_rule(&config, &input).map(|(rule_index, _)| rule_index),
+            Some(1)
+        );
+
+        let input = RoutingInput {
+            amount: 500,
+            ..input
+        };
+        assert!(find_matching_rule(&config, &input).is_none());
+    }
+
+    #[test]
+    fn test_card_conditions_require_card_info() {
+        let mut config = routing_config();
+        config.rules[0].conditions = api::RoutingConditions {
+            card_networks: Some(vec![api_enums::CardNetwork::Mastercard]),
+            card_types: Some(vec![api_enums::CardType::Debit]),
+            ..Default::default()
+        };
+        let input = RoutingInput {
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            card_network: Some(api_enums::CardNetwork::Mastercard),
+            card_type: Some(api_enums::CardType::Debit),
+            ..Default::default()
+        };
+        assert_eq!(
+            find_matching_rule(&config, &input).map(|(rule_index, _)| rule_index),
+            Some(0)
+        );
+
+        let input = RoutingInput {
+            card_type: None,
+            ..input
+        };
+        assert!(find_matching_rule(&config, &input).is_none());
+    }
+
+    fn volume_split() -> Vec<api::ConnectorVolumeSplit> {
+        vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                percentage: 70,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                percentage: 30,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_volume_split_is_exact_over_hundred_payments() {
+        let volume_split = volume_split();
+        let stripe_count = (1000..1100)
+            .filter_map(|seed| select_connector_by_volume(&volume_split, seed))
+            .filter(|connector| *connector == api_enums::RoutableConnectors::Stripe)
+            .count();
+
+        assert_eq!(stripe_count, 70);
+    }
+
+    #[test]
+    fn test_volume_split_is_deterministic() {
+        let volume_split = volume_split();
+
+        assert_eq!(
+            select_connector_by_volume(&volume_split, 42),
+            select_connector_by_volume(&volume_split, 42)
+        );
+    }
+
+    #[test]
+    fn test_volume_split_keeps_failover_connectors() {
+        let volume_split = volume_split();
+        let connectors = [api_enums::RoutableConnectors::Checkout];
+        let seed = (0..100)
+            .find(|seed| {
+                select_connector_by_volume(&volume_split, *seed)
+                    == Some(api_enums::RoutableConnectors::Adyen)
+            })
+            .unwrap();
+
+        assert_eq!(
+            order_connectors_by_volume_split(&volume_split, &connectors, seed),
+            [
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Stripe
+            ]
+        );
+    }
+}
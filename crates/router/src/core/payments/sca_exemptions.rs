@@ -0,0 +1,222 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+
+use super::{
+    flows::{ConstructFlowSpecificData, Feature},
+    CallConnectorAction, PaymentData,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api, storage, storage::enums as storage_enums},
+};
+
+/// Countries where strong customer authentication is mandated for the cards issued there: the
+/// European Economic Area and the United Kingdom.
+const SCA_MANDATED_COUNTRIES: [&str; 31] = [
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GB", "GR", "HR", "HU", "IE",
+    "IS", "IT", "LI", "LT", "LU", "LV", "MT", "NL", "NO", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+pub fn get_sca_exemption_policy(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::ScaExemptionPolicy>> {
+    merchant_account
+        .sca_exemption_policy
+        .clone()
+        .map(|policy| policy.parse_value("ScaExemptionPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the SCA exemption policy of the merchant")
+}
+
+/// Returns the exemption to request from the issuer of the card the payment is made with, as per
+/// the SCA exemption policy of the merchant.
+pub fn get_sca_exemption<F: Clone>(
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<api::enums::ScaExemptionType>> {
+    let policy = match get_sca_exemption_policy(merchant_account)? {
+        Some(policy) => policy,
+        None => return Ok(None),
+    };
+
+    let card_issuing_country = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => card.card_issuing_country.as_deref(),
+        _ => return Ok(None),
+    };
+
+    // Merchant-initiated transactions are out of the scope of SCA, and mandates can only be set up
+    // with the customer authenticated
+    let is_customer_authentication_required = payment_data.payment_attempt.authentication_type
+        == Some(storage_enums::AuthenticationType::ThreeDs)
+        || payment_data.mandate_id.is_some()
+        || payment_data.setup_mandate.is_some();
+    if is_customer_authentication_required {
+        return Ok(None);
+    }
+
+    Ok(decide_sca_exemption(
+        &policy,
+        payment_data.amount.into(),
+        card_issuing_country,
+    ))
+}
+
+/// Low value exemptions are preferred over transaction risk analysis ones, as they don't count
+/// towards the fraud rate of the acquirer. Delegated authentication is the last resort.
+fn decide_sca_exemption(
+    policy: &api::ScaExemptionPolicy,
+    amount: i64,
+    card_issuing_country: Option<&str>,
+) -> Option<api::enums::ScaExemptionType> {
+    let is_sca_mandated =
+        card_issuing_country.map_or(false, |country| SCA_MANDATED_COUNTRIES.contains(&country));
+    if !is_sca_mandated || amount <= 0 {
+        return None;
+    }
+
+    let is_within =
+        |threshold: Option<i64>| threshold.map_or(false, |threshold| amount <= threshold);
+    if is_within(policy.low_value_threshold) {
+        Some(api::enums::ScaExemptionType::LowValue)
+    } else if is_within(policy.transaction_risk_analysis_threshold) {
+        Some(api::enums::ScaExemptionType::TransactionRiskAnalysis)
+    } else if policy.delegated_authentication.unwrap_or(false) {
+        Some(api::enums::ScaExemptionType::DelegatedAuthentication)
+    } else {
+        None
+    }
+}
+
+fn is_soft_decline<F, Req>(
+    router_data_res: &RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>,
+) -> bool {
+    match router_data_res {
+        Ok(router_data) => matches!(
+            router_data.response,
+            Err(ref error_response)
+                if error_response.unified_code
+                    == Some(storage_enums::UnifiedCode::AuthenticationRequired)
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Drops the exemption requested for the payment in favour of a 3DS challenge. Returns false when
+/// no exemption was requested, or the policy of the merchant leaves soft declines as they are.
+fn request_challenge<F: Clone>(
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    if payment_data.sca_exemption.is_none() {
+        return Ok(false);
+    }
+
+    let challenge_on_soft_decline = get_sca_exemption_policy(merchant_account)?
+        .and_then(|policy| policy.challenge_on_soft_decline)
+        .unwrap_or(true);
+    if challenge_on_soft_decline {
+        payment_data.sca_exemption = None;
+        payment_data.payment_attempt.authentication_type =
+            Some(storage_enums::AuthenticationType::ThreeDs);
+    }
+
+    Ok(challenge_on_soft_decline)
+}
+
+/// Submits the payment to the connector again with a 3DS challenge, when the issuer declined the
+/// exemption requested for it and asked for the customer to be authenticated.
+pub async fn challenge_soft_decline<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &mut PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    router_data_res: RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>,
+) -> RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>
+where
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+{
+    if !is_soft_decline(&router_data_res) || !request_challenge(merchant_account, payment_data)? {
+        return router_data_res;
+    }
+
+    logger::info!(
+        connector = %connector.connector_name,
+        "Submitting the soft declined payment again with a 3DS challenge"
+    );
+    super::call_connector(
+        state,
+        merchant_account,
+        connector,
+        payment_data,
+        customer,
+        CallConnectorAction::Trigger,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> api::ScaExemptionPolicy {
+        api::ScaExemptionPolicy {
+            low_value_threshold: Some(3000),
+            transaction_risk_analysis_threshold: Some(10000),
+            delegated_authentication: Some(true),
+            challenge_on_soft_decline: None,
+        }
+    }
+
+    #[test]
+    fn test_exemption_by_amount() {
+        let policy = policy();
+
+        assert_eq!(
+            decide_sca_exemption(&policy, 3000, Some("DE")),
+            Some(api::enums::ScaExemptionType::LowValue)
+        );
+        assert_eq!(
+            decide_sca_exemption(&policy, 3001, Some("DE")),
+            Some(api::enums::ScaExemptionType::TransactionRiskAnalysis)
+        );
+        assert_eq!(
+            decide_sca_exemption(&policy, 10001, Some("DE")),
+            Some(api::enums::ScaExemptionType::DelegatedAuthentication)
+        );
+        assert_eq!(
+            decide_sca_exemption(
+                &api::ScaExemptionPolicy {
+                    delegated_authentication: None,
+                    ..policy
+                },
+                10001,
+                Some("DE")
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_no_exemption_outside_sca_countries() {
+        let policy = policy();
+
+        assert_eq!(decide_sca_exemption(&policy, 1000, Some("US")), None);
+        assert_eq!(decide_sca_exemption(&policy, 1000, None), None);
+        assert_eq!(
+            decide_sca_exemption(&policy, 1000, Some("GB")),
+            Some(api::enums::ScaExemptionType::LowValue)
+        );
+    }
+}
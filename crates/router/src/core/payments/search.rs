@@ -0,0 +1,365 @@
+//! Search over the payments of a merchant, by the email of the customer, the last four digits of
+//! the card, the identifier of the attempt at the connector or the metadata of the payment.
+//!
+//! When OpenSearch is enabled, payments are indexed in the cluster as they are processed, and
+//! searched there instead of the database. The document of a payment describes its latest attempt.
+
+use base64::Engine;
+use error_stack::{report, ResultExt};
+#[cfg(feature = "olap")]
+use futures::future::try_join_all;
+#[cfg(feature = "olap")]
+use masking::PeekInterface;
+use masking::Secret;
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
+#[cfg(feature = "olap")]
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::{
+    configs::settings::OpenSearchSettings,
+    consts,
+    core::errors::{self, RouterResult},
+    headers, logger, pii,
+    routes::AppState,
+    services,
+    types::storage::{self, enums as storage_enums},
+    utils,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::RouterResponse,
+    types::{api, transformers::ForeignInto},
+    utils::BytesExt,
+};
+
+#[cfg(feature = "olap")]
+const MAX_SEARCH_LIMIT: i64 = 100;
+
+/// Document of a payment in the OpenSearch index
+#[derive(Debug, Serialize)]
+struct PaymentSearchDocument {
+    payment_id: String,
+    merchant_id: String,
+    customer_id: Option<String>,
+    customer_email: Option<Secret<String, pii::Email>>,
+    card_last4: Option<String>,
+    connector_transaction_id: Option<String>,
+    metadata: Option<serde_json::Value>,
+    status: storage_enums::IntentStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    hits: SearchHits,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize)]
+struct SearchHits {
+    hits: Vec<SearchHit>,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize)]
+struct SearchHit {
+    #[serde(rename = "_source")]
+    source: SearchHitSource,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize)]
+struct SearchHitSource {
+    payment_id: String,
+}
+
+#[cfg(feature = "olap")]
+fn validate_search_request(req: &api::PaymentSearchRequest) -> RouterResult<()> {
+    let has_criteria = req.customer_email.is_some()
+        || req.card_last4.is_some()
+        || req.connector_transaction_id.is_some()
+        || req
+            .metadata
+            .as_ref()
+            .map_or(false, |metadata| !metadata.is_empty());
+    utils::when(!has_criteria, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "At least one of customer_email, card_last4, connector_transaction_id or \
+                      metadata must be given"
+                .to_string(),
+        }))
+    })?;
+
+    utils::when(!(1..=MAX_SEARCH_LIMIT).contains(&req.limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {MAX_SEARCH_LIMIT}"),
+        }))
+    })
+}
+
+fn get_auth_header(settings: &OpenSearchSettings) -> Option<String> {
+    (!settings.username.is_empty()).then(|| {
+        let credentials =
+            consts::BASE64_ENGINE.encode(format!("{}:{}", settings.username, settings.password));
+        format!("Basic {credentials}")
+    })
+}
+
+#[cfg(feature = "olap")]
+fn build_search_query(merchant_id: &str, req: &api::PaymentSearchRequest) -> serde_json::Value {
+    let mut filters = vec![serde_json::json!({ "term": { "merchant_id.keyword": merchant_id } })];
+    if let Some(customer_email) = &req.customer_email {
+        filters.push(serde_json::json!({
+            "term": { "customer_email.keyword": customer_email.peek() }
+        }));
+    }
+    if let Some(card_last4) = &req.card_last4 {
+        filters.push(serde_json::json!({ "term": { "card_last4.keyword": card_last4 } }));
+    }
+    if let Some(connector_transaction_id) = &req.connector_transaction_id {
+        filters.push(serde_json::json!({
+            "term": { "connector_transaction_id.keyword": connector_transaction_id }
+        }));
+    }
+    for (key, value) in req.metadata.iter().flatten() {
+        filters.push(serde_json::json!({ "match": { format!("metadata.{key}"): value } }));
+    }
+
+    serde_json::json!({
+        "query": { "bool": { "filter": filters } },
+        "sort": [{ "created_at": "desc" }],
+        "size": req.limit,
+        "_source": ["payment_id"],
+    })
+}
+
+/// Searches the payments of the merchant, the most recent first. Every given criterion must match.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn search_payments(
+    state: &AppState,
+    merchant: storage::MerchantAccount,
+    req: api::PaymentSearchRequest,
+) -> RouterResponse<api::PaymentSearchResponse> {
+    validate_search_request(&req)?;
+
+    let payment_intents = if state.conf.opensearch.enabled {
+        let payment_ids = search_payment_ids(state, &merchant.merchant_id, &req).await?;
+        try_join_all(payment_ids.iter().map(|payment_id| {
+            state.store.find_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                &merchant.merchant_id,
+                merchant.storage_scheme,
+            )
+        }))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payments found in OpenSearch")?
+    } else {
+        state
+            .store
+            .search_payment_intents(&merchant.merchant_id, &req, merchant.storage_scheme)
+            .await
+            .map_err(|error| {
+                errors::StorageErrorExt::to_not_found_response(
+                    error,
+                    errors::ApiErrorResponse::PaymentNotFound,
+                )
+            })?
+    };
+
+    let data: Vec<api::PaymentsResponse> = payment_intents
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentSearchResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+async fn search_payment_ids(
+    state: &AppState,
+    merchant_id: &str,
+    req: &api::PaymentSearchRequest,
+) -> RouterResult<Vec<String>> {
+    let settings = &state.conf.opensearch;
+    let request_body = build_search_query(merchant_id, req).to_string();
+
+    let url = format!("{}{}/_search", settings.base_url, settings.payment_index);
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&url)
+        .header(headers::CONTENT_TYPE, "application/json")
+        .body(Some(request_body));
+    if let Some(auth_header) = get_auth_header(settings) {
+        request = request.header(headers::AUTHORIZATION, &auth_header);
+    }
+
+    let response = services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call OpenSearch")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "OpenSearch responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    let search_response: SearchResponse = response
+        .response
+        .parse_struct("SearchResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the search response of OpenSearch")?;
+
+    Ok(search_response
+        .hits
+        .hits
+        .into_iter()
+        .map(|hit| hit.source.payment_id)
+        .collect())
+}
+
+/// Indexes the payment in OpenSearch in the background, when enabled. Failures are only logged, the
+/// document is brought up to date the next time the payment is processed.
+pub fn index_payment(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    customer_email: Option<Secret<String, pii::Email>>,
+) {
+    if !state.conf.opensearch.enabled {
+        return;
+    }
+
+    let document = PaymentSearchDocument {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        customer_email,
+        card_last4: payment_attempt
+            .payment_method_data
+            .as_ref()
+            .and_then(|data| data.pointer("/card/last4"))
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string),
+        connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+        metadata: payment_intent.metadata.clone(),
+        status: payment_intent.status,
+        created_at: payment_intent.created_at,
+    };
+
+    let state = state.clone();
+    let index_document = async move {
+        if let Err(error) = put_document(&state, document).await {
+            logger::error!(?error, "Failed to index the payment in OpenSearch");
+        }
+    };
+
+    match actix::Arbiter::try_current() {
+        Some(arbiter) => {
+            arbiter.spawn(index_document);
+        }
+        None => {
+            tokio::spawn(index_document);
+        }
+    }
+}
+
+async fn put_document(state: &AppState, document: PaymentSearchDocument) -> RouterResult<()> {
+    let settings = &state.conf.opensearch;
+    let request_body = utils::Encode::<PaymentSearchDocument>::encode_to_string_of_json(&document)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payment search document")?;
+
+    let url = format!(
+        "{}{}/_doc/{}_{}",
+        settings.base_url, settings.payment_index, document.merchant_id, document.payment_id
+    );
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Put)
+        .url(&url)
+        .header(headers::CONTENT_TYPE, "application/json")
+        .body(Some(request_body));
+    if let Some(auth_header) = get_auth_header(settings) {
+        request = request.header(headers::AUTHORIZATION, &auth_header);
+    }
+
+    services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call OpenSearch")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "OpenSearch responded with status code {}",
+                error.status_code
+            ))
+        })?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn search_request() -> api::PaymentSearchRequest {
+        api::PaymentSearchRequest {
+            customer_email: None,
+            card_last4: None,
+            connector_transaction_id: None,
+            metadata: None,
+            limit: 10,
+        }
+    }
+
+    #[test]
+    fn test_search_request_without_criteria() {
+        assert!(validate_search_request(&search_request()).is_err());
+
+        let req = api::PaymentSearchRequest {
+            metadata: Some(HashMap::new()),
+            ..search_request()
+        };
+        assert!(validate_search_request(&req).is_err());
+
+        let req = api::PaymentSearchRequest {
+            card_last4: Some("4242".to_string()),
+            ..search_request()
+        };
+        assert!(validate_search_request(&req).is_ok());
+    }
+
+    #[test]
+    fn test_search_query_filters() {
+        let req = api::PaymentSearchRequest {
+            card_last4: Some("4242".to_string()),
+            metadata: Some(HashMap::from([(
+                "order_id".to_string(),
+                "ord_1042".to_string(),
+            )])),
+            ..search_request()
+        };
+        let query = build_search_query("merchant_1", &req);
+
+        assert_eq!(
+            query["query"]["bool"]["filter"],
+            serde_json::json!([
+                { "term": { "merchant_id.keyword": "merchant_1" } },
+                { "term": { "card_last4.keyword": "4242" } },
+                { "match": { "metadata.order_id": "ord_1042" } },
+            ])
+        );
+        assert_eq!(query["size"], 10);
+    }
+}
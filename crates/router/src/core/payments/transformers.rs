@@ -8,7 +8,9 @@ use crate::{
     configs::settings::Server,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        forex, fraud_check,
         payments::{self, helpers},
+        test_mode,
     },
     routes::AppState,
     services::{self, RedirectForm},
@@ -34,16 +36,16 @@ where
     error_stack::Report<errors::ApiErrorResponse>: From<<T as TryFrom<PaymentData<F>>>::Error>,
 {
     let (merchant_connector_account, payment_method, router_data);
-    let db = &*state.store;
-    merchant_connector_account = db
-        .find_merchant_connector_account_by_merchant_id_connector(
-            &merchant_account.merchant_id,
-            connector_id,
-        )
-        .await
-        .map_err(|error| {
-            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
-        })?;
+    merchant_connector_account = test_mode::find_merchant_connector_account(
+        &*state.store,
+        merchant_account,
+        connector_id,
+        &payment_data.payment_attempt,
+    )
+    .await
+    .map_err(|error| {
+        error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+    })?;
 
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .connector_account_details
@@ -67,6 +69,9 @@ where
             redirection_data: None,
             mandate_reference: None,
             connector_metadata: None,
+            network_txn_id: None,
+            avs_result: None,
+            cvv_result: None,
         });
 
     let router_return_url = Some(helpers::create_redirect_url(
@@ -88,6 +93,7 @@ where
         return_url: payment_data.payment_intent.return_url.clone(),
         router_return_url,
         payment_method_id: payment_data.payment_attempt.payment_method_id.clone(),
+        connector_latency: None,
         address: payment_data.address.clone(),
         auth_type: payment_data
             .payment_attempt
@@ -138,6 +144,7 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.multiple_capture_data,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -227,6 +234,7 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    multiple_capture_data: Option<payments::MultipleCaptureData>,
     payment_method_data: Option<api::PaymentMethodData>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -249,6 +257,18 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let captures_response = multiple_capture_data.map(|multiple_capture_data| {
+        multiple_capture_data
+            .get_all_captures()
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
+
+    let split_payments = helpers::get_split_payments_breakdown(&payment_intent)?;
+    let forex_quote = forex::get_payment_forex_quote(&payment_intent)?;
+    let fraud_check = fraud_check::get_fraud_check(&payment_attempt)?;
+    let connector_metadata = helpers::get_connector_metadata(&payment_intent)?;
 
     Ok(match payment_request {
         Some(_request) => {
@@ -260,13 +280,32 @@ where
             } else {
                 let mut next_action_response = None;
                 if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
+                    // Connectors of QR based payment methods keep the QR code as the connector
+                    // metadata of the attempt
+                    let qr_code_information: Option<api::QrCodeInformation> = payment_attempt
+                        .connector_metadata
+                        .clone()
+                        .and_then(|metadata| metadata.parse_value("QrCodeInformation").ok());
+                    // Bank debits await the verification of the bank account with micro-deposits,
+                    // on the hosted verification page of the connector
+                    let next_action_type = if qr_code_information.is_some() {
+                        api::NextActionType::DisplayQrCode
+                    } else if payment_attempt.payment_method
+                        == Some(enums::PaymentMethod::BankDebit)
+                    {
+                        api::NextActionType::VerifyWithMicrodeposits
+                    } else {
+                        api::NextActionType::RedirectToUrl
+                    };
+                    // A QR code may come without a page of the connector to redirect to
+                    let redirect_to_url =
+                        (qr_code_information.is_none() || redirection_data.is_some()).then(|| {
+                            helpers::create_startpay_url(server, &payment_attempt, &payment_intent)
+                        });
                     next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
-                            server,
-                            &payment_attempt,
-                            &payment_intent,
-                        )),
+                        next_action_type,
+                        redirect_to_url,
+                        qr_code_information,
                     })
                 }
                 let mut response: api::PaymentsResponse = Default::default();
@@ -301,6 +340,7 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_captures(captures_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -314,6 +354,10 @@ where
                         .set_payment_token(payment_attempt.payment_token)
                         .set_error_message(payment_attempt.error_message)
                         .set_error_code(payment_attempt.error_code)
+                        .set_unified_code(
+                            payment_attempt.unified_code.map(ForeignInto::foreign_into),
+                        )
+                        .set_unified_message(payment_attempt.unified_message)
                         .set_shipping(address.shipping)
                         .set_billing(address.billing)
                         .set_next_action(next_action_response)
@@ -347,6 +391,14 @@ where
                                 .map(ForeignInto::foreign_into),
                         )
                         .set_metadata(payment_intent.metadata)
+                        .set_split_payments(split_payments)
+                        .set_forex_quote(forex_quote)
+                        .set_profile_id(payment_intent.profile_id)
+                        .set_fraud_check(fraud_check)
+                        .set_duplicate_of(payment_attempt.duplicate_of)
+                        .set_avs_result(payment_attempt.avs_result.map(ForeignInto::foreign_into))
+                        .set_cvv_result(payment_attempt.cvv_result.map(ForeignInto::foreign_into))
+                        .set_connector_metadata(connector_metadata)
                         .to_owned(),
                 )
             }
@@ -364,6 +416,7 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            captures: captures_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -372,6 +425,8 @@ where
                 .map(ForeignInto::foreign_into),
             error_message: payment_attempt.error_message,
             error_code: payment_attempt.error_code,
+            unified_code: payment_attempt.unified_code.map(ForeignInto::foreign_into),
+            unified_message: payment_attempt.unified_message,
             payment_method_data: payment_method_data.map(api::PaymentMethodDataResponse::from),
             email: customer
                 .as_ref()
@@ -388,6 +443,14 @@ where
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
             metadata: payment_intent.metadata,
+            split_payments,
+            forex_quote,
+            profile_id: payment_intent.profile_id,
+            fraud_check,
+            duplicate_of: payment_attempt.duplicate_of,
+            avs_result: payment_attempt.avs_result.map(ForeignInto::foreign_into),
+            cvv_result: payment_attempt.cvv_result.map(ForeignInto::foreign_into),
+            connector_metadata,
             ..Default::default()
         }),
     })
@@ -406,6 +469,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
                 field_name: "browser_info",
             })?;
 
+        let split_payments = helpers::get_split_payments_breakdown(&payment_data.payment_intent)?;
+        let connector_metadata = helpers::get_connector_metadata(&payment_data.payment_intent)?;
+
         let parsed_metadata: Option<api_models::payments::Metadata> = payment_data
             .payment_intent
             .metadata
@@ -428,7 +494,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
                 .get_required_value("payment_method_data")?,
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             mandate_id: payment_data.mandate_id.clone(),
-            off_session: payment_data.mandate_id.as_ref().map(|_| true),
+            off_session: (payment_data.mandate_id.is_some()
+                || payment_data.network_transaction_id.is_some())
+            .then_some(true),
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
@@ -441,6 +509,16 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             payment_experience: payment_data.payment_attempt.payment_experience,
             order_details,
             payment_method_type: payment_data.payment_attempt.payment_method_type,
+            payer_document: payment_data.payer_document,
+            installments: payment_data.installments,
+            split_payments,
+            apple_pay_decrypted_data: None,
+            card_network: payment_data.card_network,
+            sca_exemption: payment_data.sca_exemption,
+            external_authentication_data: payment_data.external_authentication_data,
+            click_to_pay_token_data: None,
+            connector_metadata,
+            network_transaction_id: payment_data.network_transaction_id,
         })
     }
 }
@@ -458,6 +536,8 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSyncData {
             },
             encoded_data: payment_data.connector_response.encoded_data,
             capture_method: payment_data.payment_attempt.capture_method,
+            payment_method_data: payment_data.payment_method_data,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
@@ -474,6 +554,12 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            multiple_capture_data: payment_data.multiple_capture_data.map(|data| {
+                types::MultipleCaptureRequestData {
+                    capture_sequence: data.current_capture.capture_sequence,
+                    capture_reference: data.current_capture.capture_id,
+                }
+            }),
         })
     }
 }
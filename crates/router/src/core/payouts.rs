@@ -0,0 +1,327 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, access_token},
+        utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, payouts},
+        storage::{self, enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })?;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+    let connector = get_connector_data(state, &merchant_account, req.connector)?;
+
+    let payout_new = storage::PayoutsNew {
+        payout_id: payout_id.clone(),
+        merchant_id: merchant_id.to_string(),
+        customer_id: req.customer_id.clone(),
+        connector: connector.connector_name.to_string(),
+        payout_type: req.payout_method_data.get_payout_type().foreign_into(),
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        status: enums::PayoutStatus::Pending,
+        description: req.description.clone(),
+        metadata: req.metadata.clone(),
+        ..storage::PayoutsNew::default()
+    };
+
+    let payout = db.insert_payout(payout_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+    })?;
+
+    let payout = trigger_payout_to_gateway::<api::PoCreate>(
+        state,
+        &connector,
+        &merchant_account,
+        &payout,
+        Some(&req),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutCreateResponse::foreign_from(payout),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_payout_to_gateway<F>(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payouts,
+    req: Option<&payouts::PayoutCreateRequest>,
+) -> RouterResult<storage::Payouts>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let mut router_data = core_utils::construct_payout_router_data(
+        state,
+        &connector.connector_name.to_string(),
+        merchant_account,
+        payout,
+        req,
+    )
+    .await?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, connector, merchant_account, &router_data).await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    logger::debug!(payout_router_data=?router_data);
+
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    connector_integration
+        .execute_pretasks(&mut router_data, state)
+        .await
+        .map_err(|error| error.to_payout_failed_response())?;
+
+    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+        && router_data.access_token.is_none())
+    {
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_payout_failed_response())?
+    } else {
+        router_data
+    };
+
+    let payout_update = match router_data_res.response {
+        Err(err) => storage::PayoutsUpdate::ErrorUpdate {
+            status: enums::PayoutStatus::Failed,
+            error_code: Some(err.code),
+            error_message: Some(err.message),
+        },
+        Ok(response) => storage::PayoutsUpdate::ResponseUpdate {
+            status: response.status,
+            connector_payout_id: Some(response.connector_payout_id),
+        },
+    };
+
+    state
+        .store
+        .update_payout(payout.to_owned(), payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating payout: payout_id: {}",
+                payout.payout_id
+            )
+        })
+}
+
+fn get_connector_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: Option<api::enums::Connector>,
+) -> RouterResult<api::ConnectorData> {
+    let connector_name = match connector {
+        Some(connector) => connector.to_string(),
+        None => {
+            let routing_algorithm: api::RoutingAlgorithm = merchant_account
+                .routing_algorithm
+                .clone()
+                .get_required_value("routing_algorithm")?
+                .parse_value("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not decode merchant routing rules")?;
+
+            match routing_algorithm {
+                api::RoutingAlgorithm::Single(connector) => connector.to_string(),
+                // Routing rules are evaluated against payment attributes, so payouts are sent
+                // through the configuration's default connectors
+                api::RoutingAlgorithm::RuleBased(config) => config
+                    .default_connectors
+                    .first()
+                    .get_required_value("connector")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing rules did not give any connector")?
+                    .to_string(),
+            }
+        }
+    };
+
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+    .attach_printable("Unable to find the connector for the payout")
+}
+
+// ********************************************** PAYOUT CONFIRM **********************************************
+
+/// Confirms a payout that the connector is holding for review, releasing the funds.
+#[instrument(skip_all)]
+pub async fn payouts_confirm_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let (payout, connector) = get_payout_under_review(state, &merchant_account, &payout_id).await?;
+
+    let payout = trigger_payout_to_gateway::<api::PoFulfill>(
+        state,
+        &connector,
+        &merchant_account,
+        &payout,
+        None,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutCreateResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+/// Declines a payout that the connector is holding for review.
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let (payout, connector) = get_payout_under_review(state, &merchant_account, &payout_id).await?;
+
+    let payout = trigger_payout_to_gateway::<api::PoCancel>(
+        state,
+        &connector,
+        &merchant_account,
+        &payout,
+        None,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutCreateResponse::foreign_from(payout),
+    ))
+}
+
+async fn get_payout_under_review(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<(storage::Payouts, api::ConnectorData)> {
+    let payout = state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    utils::when(
+        payout.status != enums::PayoutStatus::Pending || payout.connector_payout_id.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payout is in {} status and is not awaiting review",
+                    payout.status
+                ),
+            }))
+        },
+    )?;
+
+    let connector = get_payout_connector(state, &payout)?;
+
+    Ok((payout, connector))
+}
+
+fn get_payout_connector(
+    state: &AppState,
+    payout: &storage::Payouts,
+) -> RouterResult<api::ConnectorData> {
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to find the connector the payout was created with")
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutRetrieveRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let payout = state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, &req.payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    // Only payouts still in flight at the connector have a status worth syncing
+    let payout = if req.force_sync
+        && payout.status == enums::PayoutStatus::Pending
+        && payout.connector_payout_id.is_some()
+    {
+        let connector = get_payout_connector(state, &payout)?;
+        trigger_payout_to_gateway::<api::PoSync>(
+            state,
+            &connector,
+            &merchant_account,
+            &payout,
+            None,
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutCreateResponse::foreign_from(payout),
+    ))
+}
@@ -0,0 +1,1018 @@
+//! Reconciliation of the settlement reports of connectors with the payments and refunds they
+//! settle.
+//!
+//! A settlement report is either uploaded by the merchant, or downloaded from the SFTP server or
+//! reporting API configured for the connector. Its payment and refund records are stored and then
+//! matched in the background, by the process tracker, to the payment attempts and refunds having
+//! their connector transaction ids. Records disagreeing with their payment or refund on the status,
+//! currency or amount are flagged, as are those whose payment or refund is not found.
+
+use std::str::FromStr;
+
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::{ReconApiSettings, ReconConnectorSettings, SettlementReportFormat},
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    headers, logger,
+    routes::AppState,
+    scheduler::utils as process_tracker_utils,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+const RECON_REPORT_RUNNER: &str = "RECON_REPORT_WORKFLOW";
+const RECON_REPORT_TASK: &str = "RECONCILE_REPORT";
+const DEFAULT_LIST_LIMIT: i64 = 10;
+const MAX_LIST_LIMIT: i64 = 100;
+/// Number of records matched between two reads of the pending records of a report
+const MATCH_BATCH_SIZE: i64 = 500;
+#[cfg(feature = "recon_sftp")]
+const SFTP_TIMEOUT_IN_MILLIS: u32 = 30_000;
+
+/// The report reconciled by a task. The day of the settlements is given for the reports to be
+/// downloaded by the task.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReconTrackingData {
+    pub merchant_id: String,
+    pub report_id: String,
+    pub report_date: Option<String>,
+}
+
+/// A payment or refund record read from a settlement report
+#[derive(Debug, PartialEq)]
+struct SettlementRecord {
+    line_number: i32,
+    record_type: storage_enums::ReconRecordType,
+    connector_transaction_id: String,
+    amount: i64,
+    currency: storage_enums::Currency,
+    settlement_status: Option<String>,
+}
+
+fn get_report_format<'a>(state: &'a AppState, connector: &str) -> &'a SettlementReportFormat {
+    static DEFAULT_FORMAT: once_cell::sync::Lazy<SettlementReportFormat> =
+        once_cell::sync::Lazy::new(SettlementReportFormat::default);
+
+    state
+        .conf
+        .recon
+        .connectors
+        .get(connector)
+        .map_or(&*DEFAULT_FORMAT, |connector| &connector.format)
+}
+
+/// Downloads the settlement report of a day from the SFTP server or reporting API of the
+/// connector, and reconciles it in the background.
+#[instrument(skip(state))]
+pub async fn fetch_recon_report(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::ReconReportFetchRequest,
+) -> RouterResponse<api::ReconReportResponse> {
+    let connector = req.connector.to_string();
+    let connector_settings = state
+        .conf
+        .recon
+        .connectors
+        .get(&connector)
+        .filter(|settings| settings.source != api_enums::ReconReportSource::Csv)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Settlement reports of {connector} cannot be downloaded, they can only be \
+                     uploaded"
+                ),
+            })
+        })?;
+
+    time::Date::parse(
+        &req.report_date,
+        &time::format_description::well_known::Iso8601::DATE,
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "report_date must be in the YYYY-MM-DD format".to_string(),
+    })?;
+
+    let report = storage::ReconReportNew {
+        report_id: utils::generate_id(consts::ID_LENGTH, "recon"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector,
+        source: connector_settings.source.foreign_into(),
+        status: storage_enums::ReconReportStatus::Pending,
+        file_name: Some(get_report_location(connector_settings, &req.report_date)?),
+        total_records: 0,
+    };
+    let report = state
+        .store
+        .insert_recon_report(report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the recon report")?;
+
+    schedule_reconciliation(state, &report, Some(req.report_date)).await?;
+
+    Ok(services::ApplicationResponse::Json(report.foreign_into()))
+}
+
+/// Reads a settlement report uploaded as a CSV file, and reconciles it in the background.
+#[instrument(skip(state, content))]
+pub async fn upload_recon_report(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    connector: api_enums::Connector,
+    file_name: Option<String>,
+    content: String,
+) -> RouterResponse<api::ReconReportResponse> {
+    let connector = connector.to_string();
+    let records = parse_settlement_report(
+        &content,
+        get_report_format(state, &connector),
+        state.conf.recon.max_records_per_report,
+    )?;
+
+    let report = storage::ReconReportNew {
+        report_id: utils::generate_id(consts::ID_LENGTH, "recon"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector,
+        source: storage_enums::ReconReportSource::Csv,
+        status: storage_enums::ReconReportStatus::Pending,
+        file_name,
+        total_records: get_total_records(&records)?,
+    };
+    let report = state
+        .store
+        .insert_recon_report(report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the recon report")?;
+
+    insert_records(state, &report, records).await?;
+    schedule_reconciliation(state, &report, None).await?;
+
+    Ok(services::ApplicationResponse::Json(report.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn list_recon_reports(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::ReconReportListConstraints,
+) -> RouterResponse<Vec<api::ReconReportResponse>> {
+    let (limit, offset) = get_limit_and_offset(constraints.limit, constraints.offset)?;
+    let reports = state
+        .store
+        .list_recon_reports_by_merchant_id(&merchant_account.merchant_id, limit, offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing recon reports")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(reports))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_recon_report(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    report_id: String,
+) -> RouterResponse<api::ReconReportResponse> {
+    let report = find_recon_report(state, &merchant_account.merchant_id, &report_id).await?;
+    Ok(services::ApplicationResponse::Json(report.foreign_into()))
+}
+
+#[instrument(skip(state))]
+pub async fn list_recon_records(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    report_id: String,
+    constraints: api::ReconRecordListConstraints,
+) -> RouterResponse<Vec<api::ReconRecordResponse>> {
+    let (limit, offset) = get_limit_and_offset(constraints.limit, constraints.offset)?;
+    let report = find_recon_report(state, &merchant_account.merchant_id, &report_id).await?;
+    let records = state
+        .store
+        .list_recon_records_by_report_id(
+            &report.merchant_id,
+            &report.report_id,
+            constraints.recon_status.map(ForeignInto::foreign_into),
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing recon records")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(records))
+}
+
+async fn find_recon_report(
+    state: &AppState,
+    merchant_id: &str,
+    report_id: &str,
+) -> RouterResult<storage::ReconReport> {
+    state
+        .store
+        .find_recon_report_by_merchant_id_report_id(merchant_id, report_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ReconReportNotFound))
+}
+
+fn get_limit_and_offset(limit: Option<i64>, offset: Option<i64>) -> RouterResult<(i64, i64)> {
+    let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT);
+    utils::when(!(1..=MAX_LIST_LIMIT).contains(&limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {MAX_LIST_LIMIT}"),
+        }))
+    })?;
+
+    let offset = offset.unwrap_or(0);
+    utils::when(offset < 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset must not be negative".to_string(),
+        }))
+    })?;
+
+    Ok((limit, offset))
+}
+
+fn get_total_records(records: &[SettlementRecord]) -> RouterResult<i32> {
+    i32::try_from(records.len())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+async fn insert_records(
+    state: &AppState,
+    report: &storage::ReconReport,
+    records: Vec<SettlementRecord>,
+) -> RouterResult<()> {
+    let records = records
+        .into_iter()
+        .map(|record| storage::ReconRecordNew {
+            report_id: report.report_id.clone(),
+            merchant_id: report.merchant_id.clone(),
+            line_number: record.line_number,
+            record_type: record.record_type,
+            connector_transaction_id: record.connector_transaction_id,
+            amount: record.amount,
+            currency: record.currency.to_string(),
+            settlement_status: record.settlement_status,
+            recon_status: storage_enums::ReconStatus::Pending,
+        })
+        .collect();
+
+    state
+        .store
+        .insert_recon_records(records)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting the records of the recon report {}",
+                report.report_id
+            )
+        })?;
+
+    Ok(())
+}
+
+async fn schedule_reconciliation(
+    state: &AppState,
+    report: &storage::ReconReport,
+    report_date: Option<String>,
+) -> RouterResult<()> {
+    let process_tracker_id = process_tracker_utils::get_process_tracker_id(
+        RECON_REPORT_RUNNER,
+        RECON_REPORT_TASK,
+        &report.report_id,
+        &report.merchant_id,
+    );
+    let tracking_data = ReconTrackingData {
+        merchant_id: report.merchant_id.clone(),
+        report_id: report.report_id.clone(),
+        report_date,
+    };
+    let mut process_tracker_entry =
+        <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            RECON_REPORT_TASK,
+            RECON_REPORT_RUNNER,
+            tracking_data,
+            common_utils::date_time::now(),
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    process_tracker_entry.tag = vec![String::from("RECON")];
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: report_id: {}",
+                report.report_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Reconciles a settlement report, downloading it first when it is not uploaded. A report that
+/// cannot be downloaded or read is marked as failed. The task can be run again after being
+/// interrupted, as only the records that are yet to be matched are matched.
+#[instrument(skip(state))]
+pub async fn reconcile_report(
+    state: &AppState,
+    tracking_data: ReconTrackingData,
+) -> RouterResult<storage::ReconReport> {
+    let db = &*state.store;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let mut report = find_recon_report(
+        state,
+        &merchant_account.merchant_id,
+        &tracking_data.report_id,
+    )
+    .await?;
+
+    if report.status == storage_enums::ReconReportStatus::Pending {
+        let total_records = if report.source == storage_enums::ReconReportSource::Csv {
+            report.total_records
+        } else {
+            let records = match download_and_parse_report(state, &report, &tracking_data).await {
+                Ok(records) => records,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        report_id = %report.report_id,
+                        "Failed to download the settlement report"
+                    );
+                    return db
+                        .update_recon_report(
+                            report,
+                            storage::ReconReportUpdate::FailureUpdate {
+                                error_message: match error.current_context() {
+                                    errors::ApiErrorResponse::InvalidRequestData { message }
+                                    | errors::ApiErrorResponse::NotSupported { message } => {
+                                        message.clone()
+                                    }
+                                    _ => {
+                                        "The settlement report could not be downloaded".to_string()
+                                    }
+                                },
+                            },
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError);
+                }
+            };
+            let total_records = get_total_records(&records)?;
+            insert_records(state, &report, records).await?;
+            total_records
+        };
+
+        report = db
+            .update_recon_report(
+                report,
+                storage::ReconReportUpdate::ProcessingUpdate { total_records },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+
+    loop {
+        let records = db
+            .list_recon_records_by_report_id(
+                &report.merchant_id,
+                &report.report_id,
+                Some(storage_enums::ReconStatus::Pending),
+                MATCH_BATCH_SIZE,
+                0,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        if records.is_empty() {
+            break;
+        }
+
+        for record in records {
+            let record_update =
+                match_record(state, &merchant_account, &report.connector, &record).await?;
+            db.update_recon_record(record, record_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        }
+    }
+
+    let counts = db
+        .get_recon_record_status_counts(&report.merchant_id, &report.report_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let count = |statuses: &[storage_enums::ReconStatus]| -> RouterResult<i32> {
+        counts
+            .iter()
+            .filter(|(status, _)| statuses.contains(status))
+            .map(|(_, count)| count)
+            .sum::<i64>()
+            .try_into()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    };
+
+    db.update_recon_report(
+        report,
+        storage::ReconReportUpdate::ResultUpdate {
+            matched_records: count(&[storage_enums::ReconStatus::Matched])?,
+            mismatched_records: count(&[
+                storage_enums::ReconStatus::AmountMismatch,
+                storage_enums::ReconStatus::CurrencyMismatch,
+                storage_enums::ReconStatus::StatusMismatch,
+            ])?,
+            missing_records: count(&[storage_enums::ReconStatus::NotFound])?,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+async fn download_and_parse_report(
+    state: &AppState,
+    report: &storage::ReconReport,
+    tracking_data: &ReconTrackingData,
+) -> RouterResult<Vec<SettlementRecord>> {
+    let connector_settings = state
+        .conf
+        .recon
+        .connectors
+        .get(&report.connector)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "No settlement report source is configured for {}",
+                    report.connector
+                ),
+            })
+        })?;
+    let report_date = tracking_data
+        .report_date
+        .as_deref()
+        .get_required_value("report_date")?;
+
+    let content = match connector_settings.source {
+        api_enums::ReconReportSource::Sftp => {
+            let sftp_settings = connector_settings
+                .sftp
+                .as_ref()
+                .get_required_value("sftp")?;
+            download_from_sftp(
+                sftp_settings,
+                get_report_location(connector_settings, report_date)?,
+            )
+            .await?
+        }
+        api_enums::ReconReportSource::Api => {
+            let api_settings = connector_settings.api.as_ref().get_required_value("api")?;
+            download_from_api(
+                state,
+                api_settings,
+                get_report_location(connector_settings, report_date)?,
+            )
+            .await?
+        }
+        api_enums::ReconReportSource::Csv => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Settlement reports of the CSV source are uploaded")?
+        }
+    };
+
+    utils::when(content.len() > state.conf.recon.max_file_size, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The settlement report is larger than {} bytes",
+                state.conf.recon.max_file_size
+            ),
+        }))
+    })?;
+
+    parse_settlement_report(
+        &content,
+        &connector_settings.format,
+        state.conf.recon.max_records_per_report,
+    )
+}
+
+/// The path or URL of the settlement report of a day
+fn get_report_location(
+    connector_settings: &ReconConnectorSettings,
+    report_date: &str,
+) -> RouterResult<String> {
+    let template = match connector_settings.source {
+        api_enums::ReconReportSource::Sftp => connector_settings
+            .sftp
+            .as_ref()
+            .map(|sftp| sftp.path.as_str()),
+        api_enums::ReconReportSource::Api => {
+            connector_settings.api.as_ref().map(|api| api.url.as_str())
+        }
+        api_enums::ReconReportSource::Csv => None,
+    }
+    .get_required_value("report_location")?;
+
+    Ok(template.replace("{date}", report_date))
+}
+
+#[cfg(feature = "recon_sftp")]
+async fn download_from_sftp(
+    sftp_settings: &crate::configs::settings::ReconSftpSettings,
+    path: String,
+) -> RouterResult<String> {
+    use std::io::Read;
+
+    let sftp_settings = sftp_settings.clone();
+    tokio::task::spawn_blocking(move || -> std::io::Result<String> {
+        let tcp = std::net::TcpStream::connect((sftp_settings.host.as_str(), sftp_settings.port))?;
+        let mut session = ssh2::Session::new()?;
+        session.set_timeout(SFTP_TIMEOUT_IN_MILLIS);
+        session.set_tcp_stream(tcp);
+        session.handshake()?;
+        session.userauth_password(&sftp_settings.username, &sftp_settings.password)?;
+
+        let mut content = String::new();
+        session
+            .sftp()?
+            .open(std::path::Path::new(&path))?
+            .read_to_string(&mut content)?;
+        Ok(content)
+    })
+    .await
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)?
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "The settlement report could not be downloaded from the SFTP server".to_string(),
+    })
+}
+
+#[cfg(not(feature = "recon_sftp"))]
+async fn download_from_sftp(
+    _sftp_settings: &crate::configs::settings::ReconSftpSettings,
+    _path: String,
+) -> RouterResult<String> {
+    Err(report!(errors::ApiErrorResponse::NotSupported {
+        message: "Downloading settlement reports over SFTP requires the recon_sftp feature"
+            .to_string(),
+    }))
+}
+
+async fn download_from_api(
+    state: &AppState,
+    api_settings: &ReconApiSettings,
+    url: String,
+) -> RouterResult<String> {
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&url);
+    if !api_settings.api_key.is_empty() {
+        request = request.header(
+            headers::AUTHORIZATION,
+            &format!("Bearer {}", api_settings.api_key),
+        );
+    }
+
+    let response = services::call_connector_api(state, request.build())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the reporting API")?
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The reporting API responded with status code {}",
+                    error.status_code
+                ),
+            })
+        })?;
+
+    String::from_utf8(response.response.to_vec())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The settlement report is not UTF-8 encoded".to_string(),
+        })
+}
+
+/// Matches a record to the payment attempt or refund having its connector transaction id
+async fn match_record(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &str,
+    record: &storage::ReconRecord,
+) -> RouterResult<storage::ReconRecordUpdate> {
+    let db = &*state.store;
+    let not_found = storage::ReconRecordUpdate::MatchUpdate {
+        recon_status: storage_enums::ReconStatus::NotFound,
+        payment_id: None,
+        attempt_id: None,
+        refund_id: None,
+        expected_amount: None,
+        expected_currency: None,
+        mismatch_reason: None,
+    };
+
+    match record.record_type {
+        storage_enums::ReconRecordType::Payment => {
+            let payment_attempt = match db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    &merchant_account.merchant_id,
+                    &record.connector_transaction_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_attempt) if payment_attempt.connector.as_deref() == Some(connector) => {
+                    payment_attempt
+                }
+                Ok(_) => return Ok(not_found),
+                Err(error) if error.current_context().is_db_not_found() => return Ok(not_found),
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while finding the payment attempt of a record")?,
+            };
+
+            let expected_amount = payment_attempt
+                .amount_to_capture
+                .unwrap_or(payment_attempt.amount);
+            let (recon_status, mismatch_reason) = compare_record(
+                record,
+                matches!(
+                    payment_attempt.status,
+                    storage_enums::AttemptStatus::Charged
+                        | storage_enums::AttemptStatus::PartialCharged
+                ),
+                &payment_attempt.status.to_string(),
+                payment_attempt.currency,
+                expected_amount,
+            );
+
+            Ok(storage::ReconRecordUpdate::MatchUpdate {
+                recon_status,
+                payment_id: Some(payment_attempt.payment_id),
+                attempt_id: Some(payment_attempt.attempt_id),
+                refund_id: None,
+                expected_amount: Some(expected_amount),
+                expected_currency: payment_attempt
+                    .currency
+                    .map(|currency| currency.to_string()),
+                mismatch_reason,
+            })
+        }
+        storage_enums::ReconRecordType::Refund => {
+            let refund = match db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    &merchant_account.merchant_id,
+                    &record.connector_transaction_id,
+                    connector,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(refund) => refund,
+                Err(error) if error.current_context().is_db_not_found() => return Ok(not_found),
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while finding the refund of a record")?,
+            };
+
+            let (recon_status, mismatch_reason) = compare_record(
+                record,
+                refund.refund_status == storage_enums::RefundStatus::Success,
+                &refund.refund_status.to_string(),
+                Some(refund.currency),
+                refund.refund_amount,
+            );
+
+            Ok(storage::ReconRecordUpdate::MatchUpdate {
+                recon_status,
+                payment_id: Some(refund.payment_id),
+                attempt_id: Some(refund.attempt_id),
+                refund_id: Some(refund.refund_id),
+                expected_amount: Some(refund.refund_amount),
+                expected_currency: Some(refund.currency.to_string()),
+                mismatch_reason,
+            })
+        }
+    }
+}
+
+/// Compares a record with the payment attempt or refund it settles, the status first, then the
+/// currency and the amount
+fn compare_record(
+    record: &storage::ReconRecord,
+    is_successful: bool,
+    status: &str,
+    currency: Option<storage_enums::Currency>,
+    amount: i64,
+) -> (storage_enums::ReconStatus, Option<String>) {
+    let record_type = record.record_type;
+    if !is_successful {
+        (
+            storage_enums::ReconStatus::StatusMismatch,
+            Some(format!(
+                "The {record_type} was settled by the connector, but its status is {status}"
+            )),
+        )
+    } else if currency.map(|currency| currency.to_string()).as_deref()
+        != Some(record.currency.as_str())
+    {
+        (
+            storage_enums::ReconStatus::CurrencyMismatch,
+            Some(format!(
+                "{} was settled, but the {record_type} is in {}",
+                record.currency,
+                currency.map_or("no currency".to_string(), |currency| currency.to_string())
+            )),
+        )
+    } else if record.amount != amount {
+        (
+            storage_enums::ReconStatus::AmountMismatch,
+            Some(format!(
+                "{} was settled, but the amount of the {record_type} is {amount}",
+                record.amount
+            )),
+        )
+    } else {
+        (storage_enums::ReconStatus::Matched, None)
+    }
+}
+
+/// Reads the payment and refund records of a CSV settlement report. Its first line names the
+/// columns, and the records of the other types are skipped. Values may be quoted, but cannot span
+/// several lines.
+fn parse_settlement_report(
+    content: &str,
+    format: &SettlementReportFormat,
+    max_records: usize,
+) -> RouterResult<Vec<SettlementRecord>> {
+    let invalid_report =
+        |message: String| report!(errors::ApiErrorResponse::InvalidRequestData { message });
+
+    let mut lines = content
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines
+        .next()
+        .ok_or_else(|| invalid_report("The settlement report is empty".to_string()))?;
+    let columns = split_line(header, format.delimiter)
+        .ok_or_else(|| invalid_report("Line 1: unterminated quoted value".to_string()))?;
+
+    let column_index = |column: &str| {
+        columns
+            .iter()
+            .position(|name| name == column)
+            .ok_or_else(|| invalid_report(format!("The column '{column}' is missing")))
+    };
+    let transaction_id_index = column_index(&format.transaction_id_column)?;
+    let record_type_index = column_index(&format.record_type_column)?;
+    let amount_index = column_index(&format.amount_column)?;
+    let currency_index = column_index(&format.currency_column)?;
+    let status_index = if format.status_column.is_empty() {
+        None
+    } else {
+        Some(column_index(&format.status_column)?)
+    };
+
+    let mut records = Vec::new();
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let values = split_line(line, format.delimiter).ok_or_else(|| {
+            invalid_report(format!("Line {line_number}: unterminated quoted value"))
+        })?;
+        utils::when(values.len() != columns.len(), || {
+            Err(invalid_report(format!(
+                "Line {line_number}: expected {} values, found {}",
+                columns.len(),
+                values.len()
+            )))
+        })?;
+
+        let record_type = &values[record_type_index];
+        let record_type = if format.payment_types.contains(record_type) {
+            storage_enums::ReconRecordType::Payment
+        } else if format.refund_types.contains(record_type) {
+            storage_enums::ReconRecordType::Refund
+        } else {
+            continue;
+        };
+
+        utils::when(records.len() == max_records, || {
+            Err(invalid_report(format!(
+                "The settlement report has more than {max_records} records"
+            )))
+        })?;
+
+        let connector_transaction_id = values[transaction_id_index].clone();
+        utils::when(connector_transaction_id.is_empty(), || {
+            Err(invalid_report(format!(
+                "Line {line_number}: missing connector transaction id"
+            )))
+        })?;
+        let currency = storage_enums::Currency::from_str(&values[currency_index].to_uppercase())
+            .map_err(|_| {
+                invalid_report(format!(
+                    "Line {line_number}: invalid currency '{}'",
+                    values[currency_index]
+                ))
+            })?;
+        let amount = parse_amount(
+            &values[amount_index],
+            currency,
+            format.amount_in_minor_units,
+        )
+        .ok_or_else(|| {
+            invalid_report(format!(
+                "Line {line_number}: invalid amount '{}'",
+                values[amount_index]
+            ))
+        })?;
+
+        records.push(SettlementRecord {
+            line_number: i32::try_from(line_number)
+                .map_err(|_| invalid_report("The settlement report is too long".to_string()))?,
+            record_type,
+            connector_transaction_id,
+            amount,
+            currency,
+            settlement_status: status_index
+                .map(|index| values[index].clone())
+                .filter(|status| !status.is_empty()),
+        });
+    }
+
+    Ok(records)
+}
+
+/// Splits a line of a CSV file into its values, unquoting the quoted ones. None when a quoted
+/// value is not terminated.
+fn split_line(line: &str, delimiter: char) -> Option<Vec<String>> {
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut is_quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            char if char == delimiter && !is_quoted => {
+                values.push(value.trim().to_string());
+                value.clear();
+            }
+            char => value.push(char),
+        }
+    }
+
+    (!is_quoted).then(|| {
+        values.push(value.trim().to_string());
+        values
+    })
+}
+
+/// Parses an amount into the lowest denomination of the currency. Reports may show the amounts of
+/// refunds as negative, so the sign is ignored.
+fn parse_amount(
+    value: &str,
+    currency: storage_enums::Currency,
+    in_minor_units: bool,
+) -> Option<i64> {
+    let value = value.trim_start_matches(['-', '+']);
+    if in_minor_units {
+        return value.parse::<i64>().ok();
+    }
+
+    let decimal_places = match currency {
+        storage_enums::Currency::JPY | storage_enums::Currency::KRW => 0,
+        storage_enums::Currency::BHD
+        | storage_enums::Currency::JOD
+        | storage_enums::Currency::KWD
+        | storage_enums::Currency::OMR => 3,
+        _ => 2,
+    };
+    let (units, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let all_digits = |part: &str| part.chars().all(|char| char.is_ascii_digit());
+    if units.is_empty() || !all_digits(units) || !all_digits(fraction) {
+        return None;
+    }
+    // Trailing zeros beyond the decimal places of the currency are allowed, other digits would be
+    // lost
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimal_places {
+        return None;
+    }
+
+    let units = units.parse::<i64>().ok()?;
+    let fraction = format!("{fraction:0<decimal_places$}");
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<i64>().ok()?
+    };
+    units
+        .checked_mul(10_i64.pow(u32::try_from(decimal_places).ok()?))?
+        .checked_add(fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_split_line() {
+        assert_eq!(
+            split_line(r#"pay_1, "1,000.00" ,"say ""hi""""#, ',').unwrap(),
+            vec!["pay_1", "1,000.00", r#"say "hi""#]
+        );
+        assert!(split_line(r#"pay_1,"unterminated"#, ',').is_none());
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        let usd = storage_enums::Currency::USD;
+        assert_eq!(parse_amount("1050", usd, true), Some(1050));
+        assert_eq!(parse_amount("-1050", usd, true), Some(1050));
+        assert_eq!(parse_amount("10.5", usd, false), Some(1050));
+        assert_eq!(parse_amount("10.500", usd, false), Some(1050));
+        assert_eq!(parse_amount("10", usd, false), Some(1000));
+        assert_eq!(parse_amount("10.505", usd, false), None);
+        assert_eq!(parse_amount("1,000.00", usd, false), None);
+        assert_eq!(
+            parse_amount("1000", storage_enums::Currency::JPY, false),
+            Some(1000)
+        );
+        assert_eq!(
+            parse_amount("1.234", storage_enums::Currency::KWD, false),
+            Some(1234)
+        );
+    }
+
+    #[test]
+    fn test_parse_settlement_report() {
+        let format = SettlementReportFormat {
+            status_column: String::new(),
+            amount_in_minor_units: false,
+            ..SettlementReportFormat::default()
+        };
+        let content = "\u{feff}type,connector_transaction_id,amount,currency\n\
+                       payment,pi_1,10.50,usd\n\
+                       fee,fee_1,0.30,USD\n\
+                       \n\
+                       refund,re_1,-5.00,USD\n";
+        let records = parse_settlement_report(content, &format, 10).unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                SettlementRecord {
+                    line_number: 2,
+                    record_type: storage_enums::ReconRecordType::Payment,
+                    connector_transaction_id: "pi_1".to_string(),
+                    amount: 1050,
+                    currency: storage_enums::Currency::USD,
+                    settlement_status: None,
+                },
+                SettlementRecord {
+                    line_number: 5,
+                    record_type: storage_enums::ReconRecordType::Refund,
+                    connector_transaction_id: "re_1".to_string(),
+                    amount: 500,
+                    currency: storage_enums::Currency::USD,
+                    settlement_status: None,
+                },
+            ]
+        );
+        assert!(parse_settlement_report(content, &format, 1).is_err());
+        assert!(parse_settlement_report("type,amount\npayment,1", &format, 10).is_err());
+    }
+}
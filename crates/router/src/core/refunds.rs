@@ -4,23 +4,25 @@ use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use crate::{
+    configs::settings,
     consts,
     core::{
+        audit_logs,
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token},
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::AppState,
-    scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
+    scheduler::{process_data, utils as process_tracker_utils},
     services,
     types::{
         self,
-        api::{self, refunds},
+        api::{self, refunds, ConnectorSpecifications},
         storage::{self, enums, ProcessTrackerExt},
-        transformers::{ForeignFrom, ForeignInto},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryInto},
     },
-    utils::{self, OptionExt},
+    utils::{self, OptionExt, ValueExt},
 };
 
 // ********************************************** REFUND EXECUTE **********************************************
@@ -188,6 +190,8 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    #[cfg(feature = "kafka_events")]
+    services::kafka::log_event(state, &services::kafka::KafkaRefund::from(&response));
     Ok(response)
 }
 
@@ -258,6 +262,16 @@ pub async fn refund_retrieve_core(
     )
     .await?;
 
+    audit_logs::record_resource_change(
+        db,
+        merchant_id,
+        enums::AuditResourceType::Refund,
+        &response.refund_id,
+        Some(refund.refund_status.to_string()),
+        Some(response.refund_status.to_string()),
+    )
+    .await;
+
     Ok(response)
 }
 
@@ -353,6 +367,8 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    #[cfg(feature = "kafka_events")]
+    services::kafka::log_event(state, &services::kafka::KafkaRefund::from(&response));
     Ok(response)
 }
 
@@ -386,6 +402,16 @@ pub async fn refund_update_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| format!("Unable to update refund with refund_id: {refund_id}"))?;
 
+    audit_logs::record_resource_change(
+        db,
+        &merchant_account.merchant_id,
+        enums::AuditResourceType::Refund,
+        refund_id,
+        Some(response.refund_status.to_string()),
+        Some(response.refund_status.to_string()),
+    )
+    .await;
+
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
@@ -456,19 +482,29 @@ pub async fn validate_and_create_refund(
                 .attach_printable("Failed to fetch refund")?;
             currency = payment_attempt.currency.get_required_value("currency")?;
 
-            //[#249]: Add Connector Based Validation here.
-            validator::validate_payment_order_age(
-                &payment_intent.created_at,
-                state.conf.refund.max_age,
-            )
-            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-                field_name: "created_at".to_string(),
-                expected_format: format!(
-                    "created_at not older than {} days",
-                    state.conf.refund.max_age,
-                ),
+            let connector = payment_attempt.connector.clone().ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("connector not populated in payment attempt.")
             })?;
 
+            // Payments can't be refunded after the refund window of their connector, when it is
+            // shorter than the configured maximum age
+            let refund_max_age = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &connector,
+                api::GetToken::Connector,
+            )?
+            .connector
+            .refund_window_days()
+            .map_or(state.conf.refund.max_age, |refund_window_days| {
+                refund_window_days.min(state.conf.refund.max_age)
+            });
+            validator::validate_payment_order_age(&payment_intent.created_at, refund_max_age)
+                .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "created_at".to_string(),
+                    expected_format: format!("created_at not older than {refund_max_age} days"),
+                })?;
+
             validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
                 .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
@@ -478,11 +514,6 @@ pub async fn validate_and_create_refund(
             )
             .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
 
-            let connector = payment_attempt.connector.clone().ok_or_else(|| {
-                report!(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("connector not populated in payment attempt.")
-            })?;
-
             refund_create_req = storage::RefundNew::default()
                 .set_refund_id(refund_id.to_string())
                 .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -502,6 +533,7 @@ pub async fn validate_and_create_refund(
                 .set_description(req.reason.clone())
                 .set_attempt_id(payment_attempt.attempt_id.clone())
                 .set_refund_reason(req.reason)
+                .set_refund_reason_code(req.reason_code.map(ForeignInto::foreign_into))
                 .to_owned();
 
             refund = db
@@ -510,7 +542,7 @@ pub async fn validate_and_create_refund(
                 .map_err(|error| {
                     error.to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
                 })?;
-            schedule_refund_execution(
+            let refund = schedule_refund_execution(
                 state,
                 refund,
                 refund_type,
@@ -518,7 +550,19 @@ pub async fn validate_and_create_refund(
                 payment_attempt,
                 payment_intent,
             )
-            .await?
+            .await?;
+
+            audit_logs::record_resource_change(
+                db,
+                &merchant_account.merchant_id,
+                enums::AuditResourceType::Refund,
+                &refund.refund_id,
+                None,
+                Some(refund.refund_status.to_string()),
+            )
+            .await;
+
+            refund
         }
     };
 
@@ -569,6 +613,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             amount: refund.refund_amount,
             currency: refund.currency.to_string(),
             reason: refund.description,
+            reason_code: refund.refund_reason_code.map(ForeignInto::foreign_into),
             status: refund.refund_status.foreign_into(),
             metadata: refund.metadata,
             error_message: refund.refund_error_message,
@@ -579,6 +624,488 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
     }
 }
 
+// ********************************************** BULK REFUNDS **********************************************
+
+const REFUND_BULK_RUNNER: &str = "REFUND_BULK_WORKFLOW";
+const REFUND_BULK_TASK: &str = "INITIATE_REFUNDS";
+const REFUND_BULK_CSV_COLUMNS: [&str; 5] =
+    ["payment_id", "refund_id", "amount", "reason", "refund_type"];
+
+/// The refunds of a batch and the outcome of those initiated so far. Kept as the tracking data of
+/// the task initiating them, so that the task resumes after the last refund it initiated.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RefundBulkTrackingData {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub refunds: Vec<refunds::RefundRequest>,
+    pub results: Vec<refunds::RefundBulkItem>,
+}
+
+#[instrument(skip_all)]
+pub async fn refund_bulk_create_core(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    req: refunds::RefundBulkRequest,
+) -> RouterResponse<refunds::RefundBulkResponse> {
+    let db = &*state.store;
+    let max_refunds_per_batch = state.conf.refund.max_refunds_per_batch;
+
+    utils::when(
+        req.refunds.is_empty() || req.refunds.len() > max_refunds_per_batch,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("A batch must have between 1 and {max_refunds_per_batch} refunds"),
+            }))
+        },
+    )?;
+
+    // The refund ids are assigned upfront, so that a refund the task initiated before being
+    // interrupted is found again instead of being initiated a second time
+    let refunds = req
+        .refunds
+        .into_iter()
+        .map(|refund| {
+            let refund_id = core_utils::get_or_generate_id("refund_id", &refund.refund_id, "ref")?;
+            Ok(refunds::RefundRequest {
+                refund_id: Some(refund_id),
+                ..refund
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let mut refund_ids = std::collections::HashSet::new();
+    utils::when(
+        !refunds
+            .iter()
+            .all(|refund| refund_ids.insert(refund.refund_id.clone())),
+        || {
+            Err(report!(errors::ApiErrorResponse::DuplicateRefundRequest)
+                .attach_printable("refund_id repeated within the batch"))
+        },
+    )?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "batch");
+    let process_tracker_id = process_tracker_utils::get_process_tracker_id(
+        REFUND_BULK_RUNNER,
+        REFUND_BULK_TASK,
+        &batch_id,
+        &merchant_account.merchant_id,
+    );
+    let tracking_data = RefundBulkTrackingData {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        refunds,
+        results: Vec::new(),
+    };
+    let mut process_tracker_entry =
+        <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            REFUND_BULK_TASK,
+            REFUND_BULK_RUNNER,
+            tracking_data,
+            common_utils::date_time::now(),
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    process_tracker_entry.tag = vec![String::from("REFUND"), String::from("BULK")];
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting task in process_tracker: batch_id: {batch_id}")
+        })?;
+
+    get_refund_bulk_response(process).map(services::ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn refund_bulk_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<refunds::RefundBulkResponse> {
+    let process_tracker_id = process_tracker_utils::get_process_tracker_id(
+        REFUND_BULK_RUNNER,
+        REFUND_BULK_TASK,
+        &batch_id,
+        &merchant_account.merchant_id,
+    );
+    let db = &*state.store;
+    let process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .ok_or(errors::ApiErrorResponse::RefundBatchNotFound)?;
+
+    get_refund_bulk_response(process).map(services::ApplicationResponse::Json)
+}
+
+fn get_refund_bulk_response(
+    process: storage::ProcessTracker,
+) -> RouterResult<refunds::RefundBulkResponse> {
+    let tracking_data: RefundBulkTrackingData = process
+        .tracking_data
+        .parse_value("RefundBulkTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let total_count = tracking_data.refunds.len();
+    let processed_count = tracking_data.results.len();
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::Processing => refunds::RefundBulkStatus::Pending,
+        enums::ProcessTrackerStatus::ProcessStarted => refunds::RefundBulkStatus::Processing,
+        enums::ProcessTrackerStatus::Finish if processed_count == total_count => {
+            refunds::RefundBulkStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => refunds::RefundBulkStatus::Failed,
+    };
+    let pending_refunds = tracking_data
+        .refunds
+        .into_iter()
+        .skip(processed_count)
+        .map(|refund| refunds::RefundBulkItem {
+            refund_id: refund.refund_id.unwrap_or_default(),
+            payment_id: refund.payment_id,
+            status: None,
+            error_code: None,
+            error_message: None,
+        });
+
+    Ok(refunds::RefundBulkResponse {
+        batch_id: tracking_data.batch_id,
+        status,
+        total_count,
+        processed_count,
+        refunds: tracking_data
+            .results
+            .into_iter()
+            .chain(pending_refunds)
+            .collect(),
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    })
+}
+
+/// Initiates a refund of a batch. A refund that cannot be initiated is recorded with the error,
+/// the rest of the batch is initiated regardless.
+#[instrument(skip_all)]
+pub async fn initiate_refund_of_batch(
+    state: &AppState,
+    merchant_account: &storage::merchant_account::MerchantAccount,
+    req: refunds::RefundRequest,
+) -> refunds::RefundBulkItem {
+    let refund_id = req.refund_id.clone().unwrap_or_default();
+    let payment_id = req.payment_id.clone();
+
+    let refund = refund_create_core(state, merchant_account.clone(), req)
+        .await
+        .and_then(|response| match response {
+            services::ApplicationResponse::Json(refund) => Ok(refund),
+            _ => Err(report!(errors::ApiErrorResponse::InternalServerError)),
+        });
+
+    match refund {
+        Ok(refund) => refunds::RefundBulkItem {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            status: Some(refund.status),
+            error_code: refund.error_code,
+            error_message: refund.error_message,
+        },
+        Err(error) => {
+            logger::error!(?error, %refund_id, "Failed to initiate a refund of a batch");
+            refunds::RefundBulkItem {
+                refund_id,
+                payment_id,
+                status: None,
+                error_code: Some(error.current_context().error_code()),
+                error_message: Some(error.current_context().error_message()),
+            }
+        }
+    }
+}
+
+/// Reads the refunds of a batch from a CSV file. Its first line names the columns, out of
+/// `payment_id`, `refund_id`, `amount`, `reason` and `refund_type`, of which only `payment_id` is
+/// required. Values cannot be quoted, and so cannot contain commas.
+pub fn parse_refund_bulk_csv(csv: &str) -> RouterResult<refunds::RefundBulkRequest> {
+    let invalid_csv =
+        |message: String| report!(errors::ApiErrorResponse::InvalidRequestData { message });
+
+    let mut lines = csv
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines
+        .next()
+        .ok_or_else(|| invalid_csv("The CSV file is empty".to_string()))?;
+    let columns = header.split(',').map(str::trim).collect::<Vec<_>>();
+
+    for (index, column) in columns.iter().enumerate() {
+        utils::when(
+            !REFUND_BULK_CSV_COLUMNS.contains(column)
+                || columns.iter().take(index).any(|other| other == column),
+            || {
+                Err(invalid_csv(format!(
+                    "Unknown or repeated column '{column}'"
+                )))
+            },
+        )?;
+    }
+    utils::when(!columns.contains(&"payment_id"), || {
+        Err(invalid_csv(
+            "The column 'payment_id' is missing".to_string(),
+        ))
+    })?;
+
+    let refunds = lines
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            utils::when(line.contains('"'), || {
+                Err(invalid_csv(format!(
+                    "Line {line_number}: quoted values are not supported"
+                )))
+            })?;
+            let values = line.split(',').map(str::trim).collect::<Vec<_>>();
+            utils::when(values.len() != columns.len(), || {
+                Err(invalid_csv(format!(
+                    "Line {line_number}: expected {} values, found {}",
+                    columns.len(),
+                    values.len()
+                )))
+            })?;
+
+            let mut refund = refunds::RefundRequest::default();
+            for (column, value) in columns
+                .iter()
+                .zip(values)
+                .filter(|(_, value)| !value.is_empty())
+            {
+                match *column {
+                    "payment_id" => refund.payment_id = value.to_string(),
+                    "refund_id" => refund.refund_id = Some(value.to_string()),
+                    "amount" => {
+                        refund.amount = Some(value.parse().map_err(|_| {
+                            invalid_csv(format!("Line {line_number}: invalid amount '{value}'"))
+                        })?)
+                    }
+                    "reason" => refund.reason = Some(value.to_string()),
+                    "refund_type" => {
+                        refund.refund_type = Some(match value {
+                            "instant" => refunds::RefundType::Instant,
+                            "scheduled" => refunds::RefundType::Scheduled,
+                            _ => Err(invalid_csv(format!(
+                                "Line {line_number}: invalid refund_type '{value}'"
+                            )))?,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+            utils::when(refund.payment_id.is_empty(), || {
+                Err(invalid_csv(format!(
+                    "Line {line_number}: payment_id is missing"
+                )))
+            })?;
+
+            Ok(refund)
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(refunds::RefundBulkRequest { refunds })
+}
+
+// ********************************************** CREDITS **********************************************
+
+#[instrument(skip_all)]
+pub async fn credit_create_core(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    req: refunds::CreditRequest,
+) -> RouterResponse<refunds::CreditResponse> {
+    let db = &*state.store;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })?;
+
+    let credit_policy: Option<api::CreditPolicy> = merchant_account
+        .credit_policy
+        .clone()
+        .map(|policy| policy.parse_value("CreditPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the credit policy of the merchant")?;
+    let credit_policy = credit_policy
+        .filter(|policy| policy.enabled)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Credits are not enabled for the merchant".to_string(),
+            })
+        })?;
+
+    if let Some(max_amount) = credit_policy.max_amount {
+        utils::when(req.amount > max_amount, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("The amount of a credit cannot be greater than {max_amount}"),
+            }))
+        })?;
+    }
+
+    let connector_id = req.connector.to_string();
+    let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_id,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    utils::when(!connector.connector.supports_unreferenced_refunds(), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Credits through {connector_id}"),
+        }))
+    })?;
+
+    let credit_id = core_utils::get_or_generate_id("credit_id", &req.credit_id, "credit")?;
+    let credit_new = storage::CreditNew {
+        credit_id: credit_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: req.customer_id.clone(),
+        connector: connector_id,
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        status: enums::RefundStatus::Pending,
+        reason: req.reason.clone(),
+        metadata: req.metadata.clone(),
+        ..storage::CreditNew::default()
+    };
+
+    let credit = db.insert_credit(credit_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
+    })?;
+
+    let credit =
+        trigger_credit_to_gateway(state, &connector, &merchant_account, &credit, &req).await?;
+
+    Ok(services::ApplicationResponse::Json(credit.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_credit_to_gateway(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    merchant_account: &storage::merchant_account::MerchantAccount,
+    credit: &storage::Credit,
+    req: &refunds::CreditRequest,
+) -> RouterResult<storage::Credit> {
+    let mut router_data = core_utils::construct_credit_router_data(
+        state,
+        &connector.connector_name.to_string(),
+        merchant_account,
+        credit,
+        Some(req),
+    )
+    .await?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, connector, merchant_account, &router_data).await?;
+
+    logger::debug!(credit_router_data=?router_data);
+
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+        && router_data.access_token.is_none())
+    {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::Credit,
+            types::CreditsData,
+            types::RefundsResponseData,
+        > = connector.connector.get_connector_integration();
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_refund_failed_response())?
+    } else {
+        router_data
+    };
+
+    let credit_update = match router_data_res.response {
+        Err(err) => storage::CreditUpdate::ErrorUpdate {
+            status: enums::RefundStatus::Failure,
+            error_code: Some(err.code),
+            error_message: Some(err.message),
+        },
+        Ok(response) => storage::CreditUpdate::ResponseUpdate {
+            status: response.refund_status,
+            connector_credit_id: Some(response.connector_refund_id),
+        },
+    };
+
+    state
+        .store
+        .update_credit(credit.to_owned(), credit_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating credit: credit_id: {}",
+                credit.credit_id
+            )
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn credit_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    credit_id: String,
+) -> RouterResponse<refunds::CreditResponse> {
+    let credit = state
+        .store
+        .find_credit_by_merchant_id_credit_id(&merchant_account.merchant_id, &credit_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CreditNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(credit.foreign_into()))
+}
+
+impl ForeignFrom<storage::Credit> for api::CreditResponse {
+    fn foreign_from(credit: storage::Credit) -> Self {
+        Self {
+            credit_id: credit.credit_id,
+            amount: credit.amount,
+            currency: credit.currency.to_string(),
+            connector: credit.connector,
+            customer_id: credit.customer_id,
+            reason: credit.reason,
+            status: credit.status.foreign_into(),
+            metadata: credit.metadata,
+            error_message: credit.error_message,
+            error_code: credit.error_code,
+            created_at: credit.created_at,
+            updated_at: credit.modified_at,
+        }
+    }
+}
+
 // ********************************************** PROCESS TRACKER **********************************************
 
 #[instrument(skip_all)]
@@ -616,14 +1143,28 @@ pub async fn schedule_refund_execution(
                             Ok(refund)
                         }
                         api_models::refunds::RefundType::Instant => {
-                            trigger_refund_to_gateway(
+                            let updated_refund = trigger_refund_to_gateway(
                                 state,
                                 &refund,
                                 merchant_account,
                                 payment_attempt,
                                 payment_intent,
                             )
-                            .await
+                            .await?;
+
+                            // The refund has been accepted by the connector by now, so failing to
+                            // schedule its sync doesn't fail the request
+                            if updated_refund.sent_to_gateway
+                                && updated_refund.refund_status == enums::RefundStatus::Pending
+                            {
+                                if let Err(error) =
+                                    add_refund_sync_task(db, &updated_refund, runner).await
+                                {
+                                    logger::error!(?error, "Failed to schedule refund sync");
+                                }
+                            }
+
+                            Ok(updated_refund)
                         }
                     }
                 }
@@ -676,7 +1217,7 @@ pub async fn sync_refund_with_gateway_workflow(
 
     let response = refund_retrieve_core(
         state,
-        merchant_account,
+        merchant_account.clone(),
         refund_core.refund_internal_reference_id,
     )
     .await?;
@@ -687,6 +1228,13 @@ pub async fn sync_refund_with_gateway_workflow(
     ];
     match response.refund_status {
         status if terminal_status.contains(&status) => {
+            // The merchant isn't told otherwise about refunds that settle after being created
+            if let Err(error) =
+                trigger_refund_outgoing_webhook(state, merchant_account, &response).await
+            {
+                logger::error!(?error, "Failed to trigger outgoing webhook for refund");
+            }
+
             let id = refund_tracker.id.clone();
             refund_tracker
                 .clone()
@@ -694,8 +1242,9 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            payment_sync::retry_sync_task(
+            retry_refund_sync_task(
                 &*state.store,
+                &state.conf.refund,
                 response.connector,
                 response.merchant_id,
                 refund_tracker.to_owned(),
@@ -707,6 +1256,32 @@ pub async fn sync_refund_with_gateway_workflow(
     Ok(())
 }
 
+#[instrument(skip_all)]
+async fn trigger_refund_outgoing_webhook(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    refund: &storage::Refund,
+) -> errors::CustomResult<(), errors::WebhooksFlowError> {
+    let event_type: enums::EventType = refund
+        .refund_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+    let refund_response: api_models::refunds::RefundResponse = refund.clone().foreign_into();
+
+    webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        event_type,
+        enums::EventClass::Refunds,
+        Some(refund.payment_id.clone()),
+        refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund_response),
+    )
+    .await
+}
+
 #[instrument(skip_all)]
 pub async fn start_refund_workflow(
     state: &AppState,
@@ -904,8 +1479,27 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
+/// Returns the time at which a pending refund should be synced next, if the syncs are not
+/// exhausted yet. The delay doubles with every sync.
+pub fn get_refund_sync_schedule_time(
+    refund_settings: &settings::Refund,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    if retry_count + 1 >= refund_settings.sync_max_attempts {
+        return None;
+    }
+
+    let multiplier = 2_i32.checked_pow(u32::try_from(retry_count).ok()?)?;
+    let delay = refund_settings
+        .sync_base_delay_in_secs
+        .checked_mul(multiplier)?;
+
+    Some(common_utils::date_time::now().saturating_add(time::Duration::seconds(delay.into())))
+}
+
 pub async fn get_refund_sync_process_schedule_time(
     db: &dyn db::StorageInterface,
+    refund_settings: &settings::Refund,
     connector: &str,
     merchant_id: &str,
     retry_count: i32,
@@ -918,28 +1512,35 @@ pub async fn get_refund_sync_process_schedule_time(
         )
         .await;
 
-    let mapping = match redis_mapping {
-        Ok(x) => x,
+    match redis_mapping {
+        Ok(mapping) => {
+            let time_delta =
+                process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
+
+            Ok(process_tracker_utils::get_time_from_delta(time_delta))
+        }
         Err(err) => {
-            logger::error!("Error: while getting connector mapping: {}", err);
-            process_data::ConnectorPTMapping::default()
+            logger::info!("Redis Mapping Error: {}", err);
+            Ok(get_refund_sync_schedule_time(refund_settings, retry_count))
         }
-    };
-
-    let time_delta =
-        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
-
-    Ok(process_tracker_utils::get_time_from_delta(time_delta))
+    }
 }
 
 pub async fn retry_refund_sync_task(
     db: &dyn db::StorageInterface,
+    refund_settings: &settings::Refund,
     connector: String,
     merchant_id: String,
     pt: storage::ProcessTracker,
 ) -> Result<(), errors::ProcessTrackerError> {
-    let schedule_time =
-        get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
+    let schedule_time = get_refund_sync_process_schedule_time(
+        db,
+        refund_settings,
+        &connector,
+        &merchant_id,
+        pt.retry_count,
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => pt.retry(db, s_time).await,
@@ -949,3 +1550,41 @@ pub async fn retry_refund_sync_task(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_refund_bulk_csv() {
+        let csv = "payment_id,amount,reason,refund_type\n\
+                   pay_1,1000,Product recalled,instant\n\
+                   \n\
+                   pay_2,,,\n";
+        let requests = parse_refund_bulk_csv(csv).unwrap().refunds;
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].payment_id, "pay_1");
+        assert_eq!(requests[0].amount, Some(1000));
+        assert_eq!(requests[0].reason.as_deref(), Some("Product recalled"));
+        assert!(matches!(
+            requests[0].refund_type,
+            Some(refunds::RefundType::Instant)
+        ));
+        assert_eq!(requests[1].payment_id, "pay_2");
+        assert_eq!(requests[1].amount, None);
+        assert_eq!(requests[1].reason, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_refund_bulk_csv() {
+        assert!(parse_refund_bulk_csv("").is_err());
+        assert!(parse_refund_bulk_csv("amount\n1000\n").is_err());
+        assert!(parse_refund_bulk_csv("payment_id,currency\npay_1,USD\n").is_err());
+        assert!(parse_refund_bulk_csv("payment_id,amount\npay_1,ten\n").is_err());
+        assert!(parse_refund_bulk_csv("payment_id,reason\npay_1,\"Late, damaged\"\n").is_err());
+        assert!(parse_refund_bulk_csv("payment_id,amount\npay_1\n").is_err());
+    }
+}
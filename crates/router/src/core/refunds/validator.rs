@@ -166,9 +166,6 @@ pub fn validate_for_valid_refunds(
                 matches!(
                     (connector, payment_method_type),
                     (
-                        api_models::enums::Connector::Braintree,
-                        storage_models::enums::PaymentMethodType::Paypal,
-                    ) | (
                         api_models::enums::Connector::Klarna,
                         storage_models::enums::PaymentMethodType::Klarna
                     )
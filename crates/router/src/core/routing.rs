@@ -0,0 +1,218 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        audit_logs,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils::{self, Encode, ValueExt},
+};
+
+#[instrument(skip(state))]
+pub async fn create_routing_config(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::RoutingConfigCreateRequest,
+) -> RouterResponse<api::RoutingConfigResponse> {
+    validate_routing_config_request(&req)?;
+
+    let mut routing_configs = get_routing_configs(&merchant_account)?;
+    let routing_config = api::RoutingConfig {
+        routing_id: utils::generate_id(consts::ID_LENGTH, "routing"),
+        name: req.name,
+        rules: req.rules,
+        default_connectors: req.default_connectors,
+        created_at: common_utils::date_time::now(),
+    };
+    routing_configs.push(routing_config.clone());
+
+    let routing_rules = Encode::<Vec<api::RoutingConfig>>::encode_to_value(&routing_configs)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode routing configurations")?;
+
+    state
+        .store
+        .update_specific_fields_in_merchant(
+            &merchant_account.merchant_id,
+            storage::MerchantAccountUpdate::RoutingUpdate {
+                routing_algorithm: None,
+                routing_rules: Some(routing_rules),
+            },
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    audit_logs::record_resource_change(
+        &*state.store,
+        &merchant_account.merchant_id,
+        storage_enums::AuditResourceType::RoutingConfig,
+        &routing_config.routing_id,
+        None,
+        Some(String::from("inactive")),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        api::RoutingConfigResponse::foreign_from((routing_config, false)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_routing_configs(
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<Vec<api::RoutingConfigResponse>> {
+    let active_routing_id = get_active_routing_id(&merchant_account);
+    let response = get_routing_configs(&merchant_account)?
+        .into_iter()
+        .map(|routing_config| {
+            let is_active = active_routing_id.as_ref() == Some(&routing_config.routing_id);
+            api::RoutingConfigResponse::foreign_from((routing_config, is_active))
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state))]
+pub async fn activate_routing_config(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    routing_id: String,
+) -> RouterResponse<api::RoutingConfigResponse> {
+    let routing_config = get_routing_configs(&merchant_account)?
+        .into_iter()
+        .find(|routing_config| routing_config.routing_id == routing_id)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::RoutingConfigNotFound))?;
+
+    let routing_algorithm = Encode::<api::RoutingAlgorithm>::encode_to_value(
+        &api::RoutingAlgorithm::RuleBased(routing_config.clone()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode routing algorithm")?;
+
+    state
+        .store
+        .update_specific_fields_in_merchant(
+            &merchant_account.merchant_id,
+            storage::MerchantAccountUpdate::RoutingUpdate {
+                routing_algorithm: Some(routing_algorithm),
+                routing_rules: None,
+            },
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let previous_status = if get_active_routing_id(&merchant_account) == Some(routing_id) {
+        "active"
+    } else {
+        "inactive"
+    };
+    audit_logs::record_resource_change(
+        &*state.store,
+        &merchant_account.merchant_id,
+        storage_enums::AuditResourceType::RoutingConfig,
+        &routing_config.routing_id,
+        Some(String::from(previous_status)),
+        Some(String::from("active")),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        api::RoutingConfigResponse::foreign_from((routing_config, true)),
+    ))
+}
+
+fn validate_routing_config_request(req: &api::RoutingConfigCreateRequest) -> RouterResult<()> {
+    utils::when(req.default_connectors.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "default_connectors"
+        })
+        .attach_printable("At least one default connector must be provided"))
+    })?;
+
+    let has_rule_without_connector = req.rules.iter().any(|rule| {
+        rule.connectors.is_empty()
+            && rule
+                .volume_split
+                .as_ref()
+                .map_or(true, |volume_split| volume_split.is_empty())
+    });
+    utils::when(has_rule_without_connector, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rules.connectors"
+        })
+        .attach_printable("Every routing rule must have at least one connector"))
+    })?;
+
+    let has_invalid_split = req.rules.iter().any(|rule| {
+        rule.volume_split.as_ref().map_or(false, |volume_split| {
+            volume_split
+                .iter()
+                .map(|split| u32::from(split.percentage))
+                .sum::<u32>()
+                != 100
+        })
+    });
+    utils::when(has_invalid_split, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rules.volume_split"
+        })
+        .attach_printable("Volume split percentages of a routing rule must add up to 100"))
+    })?;
+
+    let has_invalid_band = req.rules.iter().any(|rule| {
+        rule.conditions
+            .amount
+            .as_ref()
+            .and_then(|band| band.min.zip(band.max))
+            .map_or(false, |(min, max)| min > max)
+    });
+    utils::when(has_invalid_band, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rules.conditions.amount"
+        })
+        .attach_printable("Minimum amount of an amount band exceeds its maximum amount"))
+    })
+}
+
+fn get_routing_configs(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Vec<api::RoutingConfig>> {
+    merchant_account
+        .routing_rules
+        .clone()
+        .map(|routing_rules| {
+            routing_rules
+                .parse_value("Vec<RoutingConfig>")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not decode merchant routing configurations")
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+fn get_active_routing_id(merchant_account: &storage::MerchantAccount) -> Option<String> {
+    let routing_algorithm: api::RoutingAlgorithm = merchant_account
+        .routing_algorithm
+        .clone()?
+        .parse_value("RoutingAlgorithm")
+        .ok()?;
+
+    match routing_algorithm {
+        api::RoutingAlgorithm::RuleBased(routing_config) => Some(routing_config.routing_id),
+        api::RoutingAlgorithm::Single(_) => None,
+    }
+}
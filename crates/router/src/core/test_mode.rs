@@ -0,0 +1,218 @@
+//! Test mode of merchant accounts. The payments of merchants in test mode are simulated by the
+//! in-process dummy connector in place of the connectors of the merchant, so that merchants can
+//! integrate without the sandbox credentials of a connector. The outcome of a simulated payment is
+//! scripted with the test card numbers and amounts of the dummy connector.
+
+use error_stack::{report, ResultExt};
+
+use super::errors::{self, CustomResult, RouterResult};
+use crate::{
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// The connector simulating the payments of merchants in test mode
+const TEST_MODE_CONNECTOR: &str = "dummy";
+
+pub fn validate_test_mode(test_mode: Option<bool>) -> RouterResult<()> {
+    utils::when(
+        test_mode == Some(true) && !cfg!(feature = "dummy_connector"),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Test mode is not available on this server".to_string(),
+            }))
+        },
+    )
+}
+
+/// The connector a payment of a merchant in test mode is simulated with, in place of the
+/// connectors it would be routed to. Payments which were already processed by another connector,
+/// before the merchant moved to test mode, are left with that connector.
+pub fn get_test_mode_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<api::ConnectorData>> {
+    if !is_simulated_by_test_mode(
+        merchant_account.test_mode,
+        payment_attempt.connector_transaction_id.as_deref(),
+    ) {
+        return Ok(None);
+    }
+
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        TEST_MODE_CONNECTOR,
+        api::GetToken::Connector,
+    )
+    .map(Some)
+    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "Test mode is not available on this server".to_string(),
+    })
+}
+
+fn is_simulated_by_test_mode(test_mode: bool, connector_transaction_id: Option<&str>) -> bool {
+    test_mode && connector_transaction_id.is_none()
+}
+
+/// Finds the connector account of the merchant for the connector. Merchants in test mode do not
+/// need an account for the dummy connector, one is made up for them. The payments the dummy
+/// connector processed keep the made up account once the merchant leaves test mode, so that they
+/// can still be synced and refunded.
+pub async fn find_merchant_connector_account(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    connector_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+    if connector_id != TEST_MODE_CONNECTOR {
+        return db
+            .find_merchant_connector_account_by_merchant_id_connector(
+                &merchant_account.merchant_id,
+                connector_id,
+            )
+            .await;
+    }
+    if merchant_account.test_mode {
+        return Ok(get_test_mode_connector_account(merchant_account));
+    }
+
+    match db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+    {
+        Err(error)
+            if error.current_context().is_db_not_found()
+                && is_processed_by_test_mode_connector(
+                    payment_attempt.connector.as_deref(),
+                    payment_attempt.connector_transaction_id.as_deref(),
+                ) =>
+        {
+            Ok(get_test_mode_connector_account(merchant_account))
+        }
+        merchant_connector_account => merchant_connector_account,
+    }
+}
+
+fn is_processed_by_test_mode_connector(
+    connector: Option<&str>,
+    connector_transaction_id: Option<&str>,
+) -> bool {
+    connector == Some(TEST_MODE_CONNECTOR) && connector_transaction_id.is_some()
+}
+
+fn get_test_mode_connector_account(
+    merchant_account: &storage::MerchantAccount,
+) -> storage::MerchantConnectorAccount {
+    storage::MerchantConnectorAccount {
+        id: 0,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector_name: TEST_MODE_CONNECTOR.to_string(),
+        connector_account_details: serde_json::json!({
+            "auth_type": "HeaderKey",
+            "api_key": "test_mode",
+        }),
+        test_mode: Some(true),
+        disabled: Some(false),
+        merchant_connector_id: format!("{}_test_mode", merchant_account.merchant_id),
+        payment_methods_enabled: None,
+        connector_type: enums::ConnectorType::PaymentProcessor,
+        metadata: Some(serde_json::json!({ "test_mode": true })),
+        connector_webhook_details: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payments_simulated_in_test_mode() {
+        assert!(is_simulated_by_test_mode(true, None));
+        assert!(!is_simulated_by_test_mode(false, None));
+        // Payments processed before the merchant moved to test mode stay with their connector
+        assert!(!is_simulated_by_test_mode(true, Some("pi_123")));
+    }
+
+    #[test]
+    fn test_payments_processed_by_test_mode_connector() {
+        assert!(is_processed_by_test_mode_connector(
+            Some(TEST_MODE_CONNECTOR),
+            Some("dummy_pay_123")
+        ));
+        assert!(!is_processed_by_test_mode_connector(
+            Some(TEST_MODE_CONNECTOR),
+            None
+        ));
+        assert!(!is_processed_by_test_mode_connector(
+            Some("stripe"),
+            Some("pi_123")
+        ));
+    }
+
+    #[cfg(feature = "dummy_connector")]
+    #[test]
+    fn test_outcomes_of_test_card_numbers() {
+        use crate::core::dummy_connector::{self as dummy, DummyConnectorOutcome};
+
+        let outcomes = [
+            (dummy::SUCCESS_CARD_NUMBER, DummyConnectorOutcome::Success),
+            (
+                dummy::SOFT_DECLINE_CARD_NUMBER,
+                DummyConnectorOutcome::SoftDecline,
+            ),
+            (
+                dummy::INSUFFICIENT_FUNDS_CARD_NUMBER,
+                DummyConnectorOutcome::InsufficientFunds,
+            ),
+            (dummy::THREE_DS_CARD_NUMBER, DummyConnectorOutcome::ThreeDs),
+            (dummy::TIMEOUT_CARD_NUMBER, DummyConnectorOutcome::Timeout),
+            ("5555555555554444", DummyConnectorOutcome::Success),
+        ];
+        for (card_number, outcome) in outcomes {
+            assert_eq!(
+                DummyConnectorOutcome::from_card_number(card_number),
+                outcome
+            );
+        }
+    }
+
+    #[cfg(feature = "dummy_connector")]
+    #[test]
+    fn test_outcomes_of_test_amounts_only_in_test_mode() {
+        use crate::core::dummy_connector::{self as dummy, DummyConnectorOutcome};
+
+        assert_eq!(
+            DummyConnectorOutcome::from_payment(
+                dummy::SUCCESS_CARD_NUMBER,
+                dummy::TIMEOUT_AMOUNT,
+                true
+            ),
+            DummyConnectorOutcome::Timeout
+        );
+        assert_eq!(
+            DummyConnectorOutcome::from_payment(
+                dummy::SUCCESS_CARD_NUMBER,
+                dummy::TIMEOUT_AMOUNT,
+                false
+            ),
+            DummyConnectorOutcome::Success
+        );
+        assert_eq!(
+            DummyConnectorOutcome::from_payment(
+                dummy::THREE_DS_CARD_NUMBER,
+                dummy::TIMEOUT_AMOUNT,
+                true
+            ),
+            DummyConnectorOutcome::ThreeDs
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! Migration of the cards of the customers of a merchant from the processor the merchant used
+//! before. The cards exported by that processor are saved in the locker as payment methods of the
+//! customers, with the network transaction id of the payment they were stored with, so that they
+//! can be charged off-session at any connector. Mandates can also be created at the connector of
+//! the previous processor, to keep charging the cards with the tokens it issued for them.
+
+use common_utils::pii;
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payment_methods::cards,
+};
+use crate::{
+    consts, logger,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+/// Cards are imported before the request is answered, so exports are imported in chunks
+const MAX_CARDS_PER_MIGRATION: usize = 100;
+
+/// A card exported by the previous processor of the merchant
+struct ExportedCard {
+    customer_id: String,
+    customer_name: Option<String>,
+    customer_email: Option<Secret<String, pii::Email>>,
+    connector_token: String,
+    card: api::CardDetail,
+    network_transaction_id: Option<String>,
+}
+
+fn get_exported_cards(export: api::TokenExport) -> (api_enums::Connector, Vec<ExportedCard>) {
+    match export {
+        api::TokenExport::Stripe(customers) => {
+            let cards = customers
+                .into_iter()
+                .flat_map(|customer| {
+                    let api::StripeCustomerExport {
+                        id,
+                        email,
+                        name,
+                        cards,
+                    } = customer;
+                    cards.into_iter().map(move |card| ExportedCard {
+                        customer_id: id.clone(),
+                        customer_name: name.clone(),
+                        customer_email: email.clone(),
+                        connector_token: card.id,
+                        card: api::CardDetail {
+                            card_number: card.number,
+                            card_exp_month: Secret::new(format!("{:02}", card.exp_month)),
+                            card_exp_year: Secret::new(card.exp_year.to_string()),
+                            card_holder_name: card.name,
+                        },
+                        network_transaction_id: card.network_transaction_id,
+                    })
+                })
+                .collect();
+            (api_enums::Connector::Stripe, cards)
+        }
+        api::TokenExport::Adyen(recurring_details) => {
+            let cards = recurring_details
+                .into_iter()
+                .map(|recurring_detail| ExportedCard {
+                    customer_id: recurring_detail.shopper_reference,
+                    customer_name: None,
+                    customer_email: recurring_detail.shopper_email,
+                    connector_token: recurring_detail.recurring_detail_reference,
+                    card: api::CardDetail {
+                        card_number: recurring_detail.card_number,
+                        card_exp_month: recurring_detail.expiry_month,
+                        card_exp_year: recurring_detail.expiry_year,
+                        card_holder_name: recurring_detail.holder_name,
+                    },
+                    network_transaction_id: recurring_detail.network_tx_reference,
+                })
+                .collect();
+            (api_enums::Connector::Adyen, cards)
+        }
+    }
+}
+
+/// Imports the cards exported by the previous processor of the merchant. A card that cannot be
+/// imported is reported with the error, the rest of the export is imported regardless. Exports can
+/// be imported again, as cards already in the locker are not saved a second time and the mandates
+/// of the cards imported before are kept.
+#[instrument(skip_all)]
+pub async fn migrate_tokens(
+    state: &AppState,
+    merchant_id: String,
+    req: api::TokenMigrationRequest,
+) -> RouterResponse<api::TokenMigrationResponse> {
+    let db = &*state.store;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let (connector, cards) = get_exported_cards(req.export);
+    utils::when(
+        cards.is_empty() || cards.len() > MAX_CARDS_PER_MIGRATION,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "An export must have between 1 and {MAX_CARDS_PER_MIGRATION} cards"
+                ),
+            }))
+        },
+    )?;
+
+    // Mandates are charged at the connector they were set up with
+    if req.create_mandates {
+        db.find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_id,
+            &connector.to_string(),
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+    }
+
+    let mut migrated_cards = Vec::with_capacity(cards.len());
+    for card in cards {
+        let customer_id = card.customer_id.clone();
+        let connector_token = card.connector_token.clone();
+        let migrated_card = match import_card(
+            state,
+            &merchant_account,
+            connector,
+            card,
+            req.create_mandates,
+        )
+        .await
+        {
+            Ok((payment_method_id, mandate_id)) => api::TokenMigrationCardResponse {
+                customer_id,
+                connector_token,
+                payment_method_id: Some(payment_method_id),
+                mandate_id,
+                error_code: None,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::error!(?error, %customer_id, "Failed to import a card of the export");
+                api::TokenMigrationCardResponse {
+                    customer_id,
+                    connector_token,
+                    payment_method_id: None,
+                    mandate_id: None,
+                    error_code: Some(error.current_context().error_code()),
+                    error_message: Some(error.current_context().error_message()),
+                }
+            }
+        };
+        migrated_cards.push(migrated_card);
+    }
+
+    let failed_count = migrated_cards
+        .iter()
+        .filter(|card| card.payment_method_id.is_none())
+        .count();
+    Ok(services::ApplicationResponse::Json(
+        api::TokenMigrationResponse {
+            merchant_id,
+            migrated_count: migrated_cards.len() - failed_count,
+            failed_count,
+            cards: migrated_cards,
+        },
+    ))
+}
+
+/// Saves the card as a payment method of its customer, creating the customer if needed, along
+/// with its mandate at the connector of the previous processor. Returns the identifiers of the
+/// payment method and the mandate.
+async fn import_card(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: api_enums::Connector,
+    card: ExportedCard,
+    create_mandate: bool,
+) -> RouterResult<(String, Option<String>)> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let customer = db
+        .find_customer_optional_by_customer_id_merchant_id(&card.customer_id, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding the customer of the card")?;
+    if customer.is_none() {
+        db.insert_customer(storage::CustomerNew {
+            customer_id: card.customer_id.clone(),
+            merchant_id: merchant_id.clone(),
+            name: card.customer_name,
+            email: card.customer_email,
+            ..storage::CustomerNew::default()
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the customer of the card")?;
+    }
+
+    let payment_method_request = api::CreatePaymentMethod {
+        payment_method: api_enums::PaymentMethod::Card,
+        payment_method_type: None,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        card: Some(card.card),
+        metadata: Some(serde_json::json!({
+            "migrated_from": connector,
+            "connector_token": card.connector_token,
+        })),
+        customer_id: Some(card.customer_id.clone()),
+        card_network: None,
+    };
+    let payment_method_id =
+        match cards::add_payment_method(state, payment_method_request, merchant_account).await? {
+            services::ApplicationResponse::Json(payment_method) => payment_method.payment_method_id,
+            _ => {
+                return Err(report!(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unexpected response when saving the card"))
+            }
+        };
+
+    if card.network_transaction_id.is_some() {
+        let payment_method = db
+            .find_payment_method(&payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while finding the saved card")?;
+        db.update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::NetworkTransactionIdUpdate {
+                network_transaction_id: card.network_transaction_id.clone(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to save the network transaction id of the card")?;
+    }
+
+    if !create_mandate {
+        return Ok((payment_method_id, None));
+    }
+
+    match db
+        .find_mandate_by_merchant_id_connector_mandate_id(merchant_id, &card.connector_token)
+        .await
+    {
+        Ok(mandate) => {
+            let mandate_id = get_reimported_mandate_id(&mandate, &payment_method_id)?;
+            return Ok((payment_method_id, Some(mandate_id)));
+        }
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while finding the mandate of the card"));
+        }
+    }
+
+    let mandate_id = utils::generate_id(consts::ID_LENGTH, "man");
+    let mut new_mandate = storage::MandateNew::default();
+    new_mandate
+        .set_mandate_id(mandate_id.clone())
+        .set_customer_id(card.customer_id)
+        .set_merchant_id(merchant_id.clone())
+        .set_payment_method_id(payment_method_id.clone())
+        .set_connector(connector.to_string())
+        .set_mandate_status(storage_enums::MandateStatus::Active)
+        .set_mandate_type(storage_enums::MandateType::MultiUse)
+        .set_connector_mandate_id(Some(card.connector_token))
+        .set_network_transaction_id(card.network_transaction_id);
+    db.insert_mandate(new_mandate)
+        .await
+        .map_err(|error| error.to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate))?;
+
+    Ok((payment_method_id, Some(mandate_id)))
+}
+
+/// The mandate created when the card was imported before is kept, unless the token of the previous
+/// processor is the connector mandate id of another payment method
+fn get_reimported_mandate_id(
+    mandate: &storage::Mandate,
+    payment_method_id: &str,
+) -> RouterResult<String> {
+    utils::when(mandate.payment_method_id != payment_method_id, || {
+        Err(report!(errors::ApiErrorResponse::DuplicateMandate))
+    })?;
+    Ok(mandate.mandate_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::PeekInterface;
+
+    use super::*;
+
+    fn mandate(payment_method_id: &str) -> storage::Mandate {
+        storage::Mandate {
+            id: 1,
+            mandate_id: "man_123".to_string(),
+            customer_id: "cus_NffrFeUfNV2Hib".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: payment_method_id.to_string(),
+            mandate_status: storage_enums::MandateStatus::Active,
+            mandate_type: storage_enums::MandateType::MultiUse,
+            customer_accepted_at: None,
+            customer_ip_address: None,
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at: common_utils::date_time::now(),
+            mandate_amount: None,
+            mandate_currency: None,
+            amount_captured: None,
+            connector: "stripe".to_string(),
+            connector_mandate_id: Some("pm_1MqLiJLkdIwHu7ixUEgbFdYF".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_reimported_mandate() {
+        // Importing the card again keeps its mandate
+        assert_eq!(
+            get_reimported_mandate_id(&mandate("pm_123"), "pm_123").unwrap(),
+            "man_123"
+        );
+        // The token of the previous processor is already the mandate of another payment method
+        assert!(get_reimported_mandate_id(&mandate("pm_456"), "pm_123").is_err());
+    }
+
+    #[test]
+    fn test_exported_cards() {
+        let export = api::TokenExport::Stripe(vec![api::StripeCustomerExport {
+            id: "cus_NffrFeUfNV2Hib".to_string(),
+            email: None,
+            name: Some("John Test".to_string()),
+            cards: vec![api::StripeCardExport {
+                id: "pm_1MqLiJLkdIwHu7ixUEgbFdYF".to_string(),
+                number: Secret::new("4242424242424242".to_string()),
+                exp_month: 8,
+                exp_year: 2026,
+                name: None,
+                network_transaction_id: Some("MCC0IX5QG0306".to_string()),
+            }],
+        }]);
+
+        let (connector, cards) = get_exported_cards(export);
+        assert_eq!(connector, api_enums::Connector::Stripe);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].customer_id, "cus_NffrFeUfNV2Hib");
+        assert_eq!(cards[0].connector_token, "pm_1MqLiJLkdIwHu7ixUEgbFdYF");
+        assert_eq!(cards[0].card.card_exp_month.peek(), "08");
+        assert_eq!(cards[0].card.card_exp_year.peek(), "2026");
+    }
+}
@@ -0,0 +1,243 @@
+use std::num::NonZeroU32;
+
+use common_utils::{generate_id, pii};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use ring::pbkdf2;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::AppState,
+    services::{
+        authentication::{AuthToken, UserFromToken},
+        ApplicationResponse,
+    },
+    types::{api, storage, transformers::ForeignInto},
+};
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+const PASSWORD_SALT_LENGTH: usize = 16;
+const PASSWORD_HASH_LENGTH: usize = 32;
+
+#[instrument(skip_all)]
+pub async fn signup(
+    state: &AppState,
+    req: api::SignUpRequest,
+) -> RouterResponse<api::DashboardEntryResponse> {
+    let db = &*state.store;
+
+    if req.password.peek().chars().count() < consts::MIN_PASSWORD_LENGTH {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The password must be at least {} characters long",
+                consts::MIN_PASSWORD_LENGTH
+            ),
+        }))?;
+    }
+
+    let email = normalize_email(&req.email);
+    match db.find_user_by_email(&email).await {
+        Ok(_) => Err(report!(errors::ApiErrorResponse::DuplicateUser))?,
+        Err(err) if err.current_context().is_db_not_found() => (),
+        Err(err) => Err(err).change_context(errors::ApiErrorResponse::InternalServerError)?,
+    }
+
+    let merchant_account = admin::create_merchant_account(
+        db,
+        api::CreateMerchantAccount {
+            merchant_id: generate_id(consts::ID_LENGTH, "merchant"),
+            merchant_name: req.merchant_name,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let merchant_id = match merchant_account {
+        ApplicationResponse::Json(merchant_account) => merchant_account.merchant_id,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response when creating the merchant account"))?,
+    };
+
+    let user = storage::UserNew {
+        user_id: generate_id(consts::ID_LENGTH, "user"),
+        email: Secret::new(email),
+        name: req.name,
+        password: hash_password(&req.password)?,
+    };
+    let user = db
+        .insert_user(user)
+        .await
+        .map_err(|err| err.to_duplicate_response(errors::ApiErrorResponse::DuplicateUser))?;
+
+    let user_role = db
+        .insert_user_role(storage::UserRoleNew {
+            user_id: user.user_id.clone(),
+            merchant_id,
+            role: storage::enums::RoleName::Admin,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to assign the admin role to the user")?;
+
+    get_dashboard_entry_response(state, user, user_role)
+}
+
+#[instrument(skip_all)]
+pub async fn login(
+    state: &AppState,
+    req: api::LoginRequest,
+) -> RouterResponse<api::DashboardEntryResponse> {
+    let db = &*state.store;
+
+    let user = db
+        .find_user_by_email(&normalize_email(&req.email))
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::InvalidCredentials))?;
+
+    if !verify_password(&req.password, &user.password)? {
+        Err(report!(errors::ApiErrorResponse::InvalidCredentials))?;
+    }
+
+    let user_role = db
+        .list_user_roles_by_user_id(&user.user_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the roles of the user")?
+        .into_iter()
+        .find(|user_role| {
+            req.merchant_id
+                .as_ref()
+                .map_or(true, |merchant_id| &user_role.merchant_id == merchant_id)
+        })
+        .ok_or(errors::ApiErrorResponse::AccessForbidden)
+        .into_report()
+        .attach_printable("The user does not have a role for the merchant account")?;
+
+    get_dashboard_entry_response(state, user, user_role)
+}
+
+#[instrument(skip_all)]
+pub async fn refresh_token(
+    state: &AppState,
+    user_from_token: UserFromToken,
+) -> RouterResponse<api::DashboardEntryResponse> {
+    let db = &*state.store;
+
+    let user = db
+        .find_user_by_user_id(&user_from_token.user_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken))?;
+
+    // The role of the user may have been removed since the token was issued
+    let user_role = db
+        .find_user_role_by_user_id_merchant_id(&user.user_id, &user_from_token.merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::AccessForbidden))?;
+
+    get_dashboard_entry_response(state, user, user_role)
+}
+
+fn get_dashboard_entry_response(
+    state: &AppState,
+    user: storage::User,
+    user_role: storage::UserRole,
+) -> RouterResponse<api::DashboardEntryResponse> {
+    let token = AuthToken::new_token(
+        user.user_id.clone(),
+        user_role.merchant_id.clone(),
+        &state.conf,
+    )?;
+
+    Ok(ApplicationResponse::Json(api::DashboardEntryResponse {
+        token: Secret::new(token),
+        user_id: user.user_id,
+        merchant_id: user_role.merchant_id,
+        name: user.name,
+        email: user.email,
+        role: user_role.role.foreign_into(),
+    }))
+}
+
+fn normalize_email(email: &Secret<String, pii::Email>) -> String {
+    email.peek().trim().to_lowercase()
+}
+
+/// Hashes the password with PBKDF2-HMAC-SHA256 and a random salt. The hash is stored along with
+/// the number of iterations and the salt, as `{iterations}${salt}${hash}`.
+fn hash_password(password: &Secret<String>) -> RouterResult<Secret<String>> {
+    let iterations = NonZeroU32::new(PBKDF2_ITERATIONS)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()?;
+    let salt: [u8; PASSWORD_SALT_LENGTH] = rand::random();
+    let mut hash = [0u8; PASSWORD_HASH_LENGTH];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &salt,
+        password.peek().as_bytes(),
+        &mut hash,
+    );
+
+    Ok(Secret::new(format!(
+        "{iterations}${}${}",
+        hex::encode(salt),
+        hex::encode(hash)
+    )))
+}
+
+fn verify_password(password: &Secret<String>, stored_hash: &Secret<String>) -> RouterResult<bool> {
+    let mut parts = stored_hash.peek().splitn(3, '$');
+    let (iterations, salt, hash) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(iterations), Some(salt), Some(hash)) => (iterations, salt, hash),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("The stored password hash is malformed"))?,
+    };
+
+    let iterations: NonZeroU32 = iterations
+        .parse()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The stored password hash has an invalid number of iterations")?;
+    let salt = hex::decode(salt)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The salt of the stored password hash is not hex encoded")?;
+    let hash = hex::decode(hash)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The stored password hash is not hex encoded")?;
+
+    Ok(pbkdf2::verify(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &salt,
+        password.peek().as_bytes(),
+        &hash,
+    )
+    .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_password_hash_verification() {
+        let password = Secret::new("correct horse battery staple".to_string());
+        let hash = hash_password(&password).unwrap();
+
+        assert!(!hash.peek().contains(password.peek()));
+        assert!(verify_password(&password, &hash).unwrap());
+        assert!(!verify_password(&Secret::new("incorrect".to_string()), &hash).unwrap());
+
+        // Each hash is computed with a different salt
+        let other_hash = hash_password(&password).unwrap();
+        assert_ne!(hash.peek(), other_hash.peek());
+        assert!(verify_password(&password, &other_hash).unwrap());
+    }
+}
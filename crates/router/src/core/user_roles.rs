@@ -0,0 +1,106 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{api, storage, transformers::ForeignInto},
+};
+
+#[instrument(skip_all)]
+pub async fn create_user_role(
+    store: &dyn StorageInterface,
+    merchant_id: String,
+    req: api::CreateUserRoleRequest,
+) -> RouterResponse<api::UserRoleResponse> {
+    store
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|err| {
+            err.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let user_role = storage::UserRoleNew {
+        user_id: req.user_id,
+        merchant_id,
+        role: req.role.foreign_into(),
+    };
+
+    let user_role = store
+        .insert_user_role(user_role)
+        .await
+        .map_err(|err| err.to_duplicate_response(errors::ApiErrorResponse::DuplicateUserRole))?;
+
+    Ok(ApplicationResponse::Json(user_role.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_user_role(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    user_id: &str,
+) -> RouterResponse<api::UserRoleResponse> {
+    let user_role = store
+        .find_user_role_by_user_id_merchant_id(user_id, merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::UserRoleNotFound))?;
+
+    Ok(ApplicationResponse::Json(user_role.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_user_role(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    user_id: &str,
+    req: api::UpdateUserRoleRequest,
+) -> RouterResponse<api::UserRoleResponse> {
+    let user_role = store
+        .update_user_role_by_user_id_merchant_id(
+            user_id,
+            merchant_id,
+            storage::UserRoleUpdate::RoleUpdate {
+                role: req.role.foreign_into(),
+            },
+        )
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::UserRoleNotFound))?;
+
+    Ok(ApplicationResponse::Json(user_role.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_user_role(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    user_id: &str,
+) -> RouterResponse<api::DeleteUserRoleResponse> {
+    let user_role = store
+        .delete_user_role_by_user_id_merchant_id(user_id, merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::UserRoleNotFound))?;
+
+    Ok(ApplicationResponse::Json(api::DeleteUserRoleResponse {
+        user_id: user_role.user_id,
+        merchant_id: user_role.merchant_id,
+        deleted: true,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_user_roles(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResponse<Vec<api::UserRoleResponse>> {
+    let user_roles = store
+        .list_user_roles_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant user roles")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(ApplicationResponse::Json(user_roles))
+}
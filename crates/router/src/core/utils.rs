@@ -3,13 +3,16 @@ use std::marker::PhantomData;
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
-use super::payments::PaymentAddress;
+use super::payments::{PaymentAddress, PaymentData};
 use crate::{
     consts,
-    core::errors::{self, RouterResult},
+    core::{
+        errors::{self, RouterResult},
+        test_mode,
+    },
     routes::AppState,
     types::{
-        self,
+        self, api,
         storage::{self, enums},
     },
     utils::{generate_id, OptionExt, ValueExt},
@@ -26,14 +29,14 @@ pub async fn construct_refund_router_data<'a, F>(
     payment_attempt: &storage::PaymentAttempt,
     refund: &'a storage::Refund,
 ) -> RouterResult<types::RefundsRouterData<F>> {
-    let db = &*state.store;
-    let merchant_connector_account = db
-        .find_merchant_connector_account_by_merchant_id_connector(
-            &merchant_account.merchant_id,
-            connector_id,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_connector_account = test_mode::find_merchant_connector_account(
+        &*state.store,
+        merchant_account,
+        connector_id,
+        payment_attempt,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .connector_account_details
@@ -61,6 +64,7 @@ pub async fn construct_refund_router_data<'a, F>(
         return_url: payment_intent.return_url.clone(),
         router_return_url: None,
         payment_method_id: payment_attempt.payment_method_id.clone(),
+        connector_latency: None,
         // Does refund need shipping/billing address ?
         address: PaymentAddress::default(),
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
@@ -74,6 +78,8 @@ pub async fn construct_refund_router_data<'a, F>(
             amount,
             connector_metadata: payment_attempt.connector_metadata.clone(),
             reason: refund.refund_reason.clone(),
+            reason_code: refund.refund_reason_code,
+            metadata: refund.metadata.clone(),
             connector_refund_id: refund.connector_refund_id.clone(),
         },
 
@@ -89,6 +95,336 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<'a, F>(
+    state: &'a AppState,
+    connector_id: &str,
+    merchant_account: &storage::MerchantAccount,
+    payout: &'a storage::Payouts,
+    request: Option<&'a api::PayoutCreateRequest>,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = match payout.payout_type {
+        enums::PayoutType::Card => enums::PaymentMethod::Card,
+        enums::PayoutType::Bank => enums::PaymentMethod::BankTransfer,
+    };
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payout.payout_id.clone(),
+        attempt_id: payout.payout_id.clone(),
+        status: enums::AttemptStatus::Pending,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        connector_latency: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            payout_method_data: request.map(|request| request.payout_method_data.clone()),
+            connector_payout_id: payout.connector_payout_id.clone(),
+            quote_id: None,
+            recipient_id: None,
+            customer_id: payout.customer_id.clone(),
+            email: request.and_then(|request| request.email.clone()),
+            description: payout.description.clone(),
+        },
+        response: Ok(types::PayoutsResponseData {
+            connector_payout_id: payout.connector_payout_id.clone().unwrap_or_default(),
+            status: payout.status,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_credit_router_data(
+    state: &AppState,
+    connector_id: &str,
+    merchant_account: &storage::MerchantAccount,
+    credit: &storage::Credit,
+    request: Option<&api::CreditRequest>,
+) -> RouterResult<types::RefundCreditRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: credit.credit_id.clone(),
+        attempt_id: credit.credit_id.clone(),
+        status: enums::AttemptStatus::Pending,
+        payment_method: enums::PaymentMethod::Card,
+        connector_auth_type: auth_type,
+        description: credit.reason.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        connector_latency: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        request: types::CreditsData {
+            credit_id: credit.credit_id.clone(),
+            amount: credit.amount,
+            currency: credit.currency,
+            payment_method_data: request.map(|request| request.payment_method_data.clone()),
+            customer_id: credit.customer_id.clone(),
+            reason: credit.reason.clone(),
+        },
+        response: Ok(types::RefundsResponseData {
+            connector_refund_id: credit.connector_credit_id.clone().unwrap_or_default(),
+            refund_status: credit.status,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_mandate_revoke_router_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+    connector_mandate_id: String,
+) -> RouterResult<types::MandateRevokeRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &mandate.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: mandate.connector.clone(),
+        payment_id: mandate
+            .previous_attempt_id
+            .clone()
+            .unwrap_or_else(|| mandate.mandate_id.clone()),
+        attempt_id: mandate
+            .previous_attempt_id
+            .clone()
+            .unwrap_or_else(|| mandate.mandate_id.clone()),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        connector_latency: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id.clone(),
+            connector_mandate_id,
+            customer_id: mandate.customer_id.clone(),
+        },
+        // Connectors which do not integrate the revoke flow leave this untouched,
+        // so the mandate is only revoked on our end
+        response: Ok(types::MandateRevokeResponseData {
+            mandate_status: enums::MandateStatus::Revoked,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_submit_evidence_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    submit_evidence_request_data: types::SubmitEvidenceRequestData,
+) -> RouterResult<types::SubmitEvidenceRouterData> {
+    let db = &*state.store;
+    let connector_id = &dispute.connector;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_id.to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        connector_latency: None,
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: None,
+        amount_captured: payment_intent.amount_captured,
+        request: submit_evidence_request_data,
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_fraud_check_router_data<F, T: Clone>(
+    state: &AppState,
+    connector_id: &str,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<T>,
+) -> RouterResult<types::FraudCheckRouterData<F>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_intent = &payment_data.payment_intent;
+    let payment_attempt = &payment_data.payment_attempt;
+    let browser_info: Option<types::BrowserInformation> = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?;
+    let parsed_metadata: Option<api_models::payments::Metadata> = payment_intent
+        .metadata
+        .clone()
+        .and_then(|metadata| metadata.parse_value("metadata").ok());
+    let order_details = parsed_metadata.and_then(|metadata| metadata.order_details);
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method: payment_attempt.payment_method.unwrap_or_default(),
+        connector_auth_type: auth_type,
+        description: payment_intent.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        connector_latency: None,
+        address: payment_data.address.clone(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request: types::FraudCheckData {
+            amount: payment_data.amount.into(),
+            currency: payment_data.currency,
+            payment_method_data: payment_data.payment_method_data.clone(),
+            customer_id: payment_intent.customer_id.clone(),
+            email: payment_data.email.clone(),
+            browser_info,
+            order_details,
+            payment_connector: payment_attempt.connector.clone(),
+            authorization_status: Some(payment_attempt.status),
+            connector_transaction_id: payment_attempt.connector_transaction_id.clone(),
+            error_code: payment_attempt.error_code.clone(),
+        },
+        response: Ok(types::FraudCheckResponseData {
+            frm_reference_id: None,
+            status: api_models::enums::FraudCheckStatus::Pending,
+            reason: None,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -1,36 +1,70 @@
 pub mod transformers;
 pub mod utils;
 
+use std::str::FromStr;
+
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
+    configs::settings,
     consts,
     core::{
-        errors::{self, CustomResult, RouterResponse},
-        payments, refunds,
+        errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+        fraud_check, payments, refunds,
     },
     db::StorageInterface,
-    logger,
+    headers, logger,
     routes::AppState,
     services,
     types::{
-        api,
+        api::{self, mandates::MandateResponseExt},
         storage::{self, enums},
         transformers::{ForeignInto, ForeignTryInto},
     },
-    utils::{generate_id, Encode, OptionExt, ValueExt},
+    utils::{crypto, crypto::SignMessage, generate_id, Encode, OptionExt, ValueExt},
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_MS: u64 = 5000;
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub event_id: String,
+    pub merchant_id: String,
+}
+
+/// Everything needed to run the flows of an incoming webhook once its source has been verified
+/// and its body parsed, so that they can be run outside of the API call.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct IncomingWebhookTrackingData {
+    pub merchant_id: String,
+    pub connector_name: String,
+    pub event_type: api_models::webhooks::IncomingWebhookEvent,
+    pub source_verified: bool,
+    pub object_reference_id: String,
+    pub resource_object: serde_json::Value,
+    pub dispute_details: Option<api::DisputePayload>,
+}
+
+impl IncomingWebhookTrackingData {
+    /// Webhooks sharing this key refer to the same object at the connector, and are processed
+    /// in the order in which they were received.
+    pub fn get_ordering_key(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.merchant_id, self.connector_name, self.object_reference_id
+        )
+    }
+}
+
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let consume_or_trigger_flow = if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
@@ -65,18 +99,31 @@ async fn payments_incoming_webhook_flow(
                 .get_required_value("payment_id")
                 .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
 
-            let event_type: enums::EventType = payments_response
-                .status
-                .foreign_try_into()
-                .into_report()
-                .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+            // Captures and expired authorizations leave the payment in a status shared with other
+            // events, so they are told apart by the incoming event
+            let event_type = match event_type {
+                api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureSuccess => {
+                    enums::EventType::PaymentCaptureSucceeded
+                }
+                api_models::webhooks::IncomingWebhookEvent::PaymentIntentCaptureFailure => {
+                    enums::EventType::PaymentCaptureFailed
+                }
+                api_models::webhooks::IncomingWebhookEvent::PaymentIntentAuthorizationExpired => {
+                    enums::EventType::PaymentAuthorizationExpired
+                }
+                _ => payments_response
+                    .status
+                    .foreign_try_into()
+                    .into_report()
+                    .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?,
+            };
 
             create_event_and_trigger_outgoing_webhook(
                 state,
                 merchant_account,
                 event_type,
                 enums::EventClass::Payments,
-                None,
+                Some(payment_id.clone()),
                 payment_id,
                 enums::EventObjectType::PaymentDetails,
                 api::OutgoingWebhookContent::PaymentDetails(payments_response),
@@ -113,7 +160,10 @@ async fn refunds_incoming_webhook_flow(
         .attach_printable_lazy(|| "Failed fetching the refund")?;
     let refund_id = refund.refund_id.to_owned();
     //if source verified then update refund status else trigger refund sync
-    let updated_refund = if source_verified {
+    //updates of refunds don't carry their status, so the refund is always synced for them
+    let updated_refund = if source_verified
+        && event_type != api_models::webhooks::IncomingWebhookEvent::RefundUpdated
+    {
         let refund_update = storage::RefundUpdate::StatusUpdate {
             connector_refund_id: None,
             sent_to_gateway: true,
@@ -122,7 +172,7 @@ async fn refunds_incoming_webhook_flow(
                 .into_report()
                 .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?,
         };
-        state
+        let updated_refund = state
             .store
             .update_refund(
                 refund.to_owned(),
@@ -136,7 +186,10 @@ async fn refunds_incoming_webhook_flow(
                     "Failed while updating refund: refund_id: {}",
                     refund_id.to_owned()
                 )
-            })?
+            })?;
+        #[cfg(feature = "kafka_events")]
+        services::kafka::log_event(&state, &services::kafka::KafkaRefund::from(&updated_refund));
+        updated_refund
     } else {
         refunds::refund_retrieve_core(&state, merchant_account.clone(), refund_id.to_owned())
             .await
@@ -153,13 +206,14 @@ async fn refunds_incoming_webhook_flow(
         .foreign_try_into()
         .into_report()
         .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+    let payment_id = updated_refund.payment_id.clone();
     let refund_response: api_models::refunds::RefundResponse = updated_refund.foreign_into();
     create_event_and_trigger_outgoing_webhook(
         state,
         merchant_account,
         event_type,
         enums::EventClass::Refunds,
-        None,
+        Some(payment_id),
         refund_id,
         enums::EventObjectType::RefundDetails,
         api::OutgoingWebhookContent::RefundDetails(refund_response),
@@ -168,9 +222,196 @@ async fn refunds_incoming_webhook_flow(
     Ok(())
 }
 
+#[instrument(skip_all)]
+async fn get_or_update_dispute_object(
+    state: AppState,
+    option_dispute: Option<storage::Dispute>,
+    dispute_details: api::DisputePayload,
+    merchant_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+    connector_name: &str,
+) -> CustomResult<storage::Dispute, errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let dispute_status: enums::DisputeStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+    match option_dispute {
+        None => {
+            let dispute_id = generate_id(consts::ID_LENGTH, "dp");
+            let new_dispute = storage::DisputeNew {
+                dispute_id,
+                amount: dispute_details.amount,
+                currency: dispute_details.currency,
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                payment_id: payment_attempt.payment_id.to_owned(),
+                attempt_id: payment_attempt.attempt_id.to_owned(),
+                merchant_id: merchant_id.to_owned(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                dispute_created_at: dispute_details.created_at,
+                updated_at: dispute_details.updated_at,
+                connector: connector_name.to_owned(),
+            };
+            db.insert_dispute(new_dispute)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)
+        }
+        Some(dispute) => {
+            logger::info!("Dispute Already exists, Updating the dispute details");
+            let update_dispute = storage::DisputeUpdate::Update {
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                connector_status: dispute_details.connector_status,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                updated_at: dispute_details.updated_at,
+            };
+            db.update_dispute(dispute, update_dispute)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)
+        }
+    }
+}
+
+#[instrument(skip_all)]
+async fn disputes_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    dispute_details: Option<api::DisputePayload>,
+    connector_name: &str,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Disputes can't be synced with the connector, so only verified webhooks are trusted
+    if !source_verified {
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?
+    }
+
+    let db = &*state.store;
+    let dispute_details = dispute_details
+        .get_required_value("dispute_details")
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable_lazy(|| "Failed fetching the payment attempt")?;
+    let option_dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            &dispute_details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+    let dispute_object = get_or_update_dispute_object(
+        state.clone(),
+        option_dispute,
+        dispute_details,
+        &merchant_account.merchant_id,
+        &payment_attempt,
+        event_type,
+        connector_name,
+    )
+    .await?;
+    let dispute_id = dispute_object.dispute_id.to_owned();
+    let event_type: enums::EventType = dispute_object
+        .dispute_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+    let payment_id = dispute_object.payment_id.to_owned();
+    let dispute_response: api_models::disputes::DisputeResponse = dispute_object.foreign_into();
+    create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Disputes,
+        Some(payment_id),
+        dispute_id,
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+    )
+    .await?;
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn mandates_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // The status of a mandate is only read from webhooks whose source could be verified
+    if !source_verified {
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?
+    }
+
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_connector_mandate_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable_lazy(|| "Failed fetching the mandate")?;
+    let mandate_status: enums::MandateStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::MandateCoreFailed)?;
+    let mandate_id = mandate.mandate_id.to_owned();
+    let updated_mandate = db
+        .update_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &mandate_id,
+            storage::MandateUpdate::StatusUpdate { mandate_status },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::MandateCoreFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed while updating mandate: mandate_id: {mandate_id}")
+        })?;
+    let event_type: enums::EventType = updated_mandate
+        .mandate_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::MandateCoreFailed)?;
+    let mandate_response =
+        api::mandates::MandateResponse::from_db_mandate(&state, updated_mandate, &merchant_account)
+            .await
+            .change_context(errors::WebhooksFlowError::MandateCoreFailed)?;
+    create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Mandates,
+        None,
+        mandate_id,
+        enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+    )
+    .await?;
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook(
+pub async fn create_event_and_trigger_outgoing_webhook(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     event_type: enums::EventType,
@@ -180,14 +421,28 @@ async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let new_event = storage::EventNew {
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.merchant_id.clone(),
         event_id: generate_id(consts::ID_LENGTH, "evt"),
+        event_type: event_type.foreign_into(),
+        content,
+        timestamp: common_utils::date_time::now(),
+    };
+    // The body of the webhook is stored with the event, so that the event can be replayed as it
+    // was first sent
+    let request_body = Encode::<api::OutgoingWebhook>::encode_to_value(&outgoing_webhook)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
+    let new_event = storage::EventNew {
+        event_id: outgoing_webhook.event_id,
         event_type,
         event_class,
         is_webhook_notified: false,
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        content: Some(request_body.clone()),
     };
 
     let event = state
@@ -197,38 +452,108 @@ async fn create_event_and_trigger_outgoing_webhook(
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
     if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-            .into_report()?;
-
-        let outgoing_webhook = api::OutgoingWebhook {
-            merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
-            event_type: event.event_type.foreign_into(),
-            content,
-            timestamp: event.created_at,
-        };
-
-        arbiter.spawn(async move {
+        let trigger_webhook = async move {
             let result =
-                trigger_webhook_to_merchant(merchant_account, outgoing_webhook, state.store).await;
+                trigger_webhook_to_merchant(state, merchant_account, event.event_id, request_body)
+                    .await;
 
             if let Err(e) = result {
                 logger::error!(?e);
             }
-        });
+        };
+
+        // Workflows run by the scheduler are outside of an actix system, and deliver the webhook
+        // before moving on
+        match actix::Arbiter::try_current() {
+            Some(arbiter) => {
+                arbiter.spawn(trigger_webhook);
+            }
+            None => trigger_webhook.await,
+        }
     }
 
     Ok(())
 }
 
 async fn trigger_webhook_to_merchant(
+    state: AppState,
     merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
+    event_id: String,
+    request_body: serde_json::Value,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_details_json = merchant_account
-        .webhook_details
+    let delivery_attempt =
+        deliver_webhook_to_merchant(&state, &merchant_account, &event_id, request_body, 1).await?;
+
+    if delivery_attempt.delivery_status == enums::WebhookDeliveryStatus::Failed {
+        add_outgoing_webhook_retry_task(
+            &*state.store,
+            &state.conf.webhooks,
+            &event_id,
+            &merchant_account.merchant_id,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Returns the webhook details of the business profile of the payment the event is about, if the
+/// payment was made for a business profile with webhook details of its own.
+async fn get_profile_webhook_details(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_id: &str,
+) -> CustomResult<Option<serde_json::Value>, errors::WebhooksFlowError> {
+    let db = &*state.store;
+
+    let event = db
+        .find_event_by_event_id(event_id)
+        .await
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+        .attach_printable("Failed to fetch the webhook event")?;
+    let payment_id = match event.intent_reference_id {
+        Some(payment_id) => payment_id,
+        None => return Ok(None),
+    };
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+        .attach_printable("Failed to fetch the payment the webhook event is about")?;
+    let profile_id = match payment_intent.profile_id {
+        Some(profile_id) => profile_id,
+        None => return Ok(None),
+    };
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(&merchant_account.merchant_id, &profile_id)
+        .await
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+        .attach_printable("Failed to fetch the business profile of the payment")?;
+
+    Ok(business_profile.webhook_details)
+}
+
+/// Sends the webhook body to the merchant's endpoint and records the outcome as a delivery attempt.
+/// The body is signed with the merchant's `payment_response_hash_key` when one is configured.
+/// Events about payments made for a business profile are sent with the webhook details of the
+/// profile, when it has any.
+#[instrument(skip_all)]
+pub async fn deliver_webhook_to_merchant(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_id: &str,
+    request_body: serde_json::Value,
+    attempt_number: i32,
+) -> CustomResult<storage::WebhookDeliveryAttempt, errors::WebhooksFlowError> {
+    let webhook_details_json = get_profile_webhook_details(state, merchant_account, event_id)
+        .await?
+        .or_else(|| merchant_account.webhook_details.clone())
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -243,30 +568,398 @@ async fn trigger_webhook_to_merchant(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let response = reqwest::Client::new()
+    let body = request_body.to_string();
+
+    let mut request = reqwest::Client::new()
         .post(&webhook_url)
         .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
-        ))
-        .send()
-        .await;
-
-    match response {
-        Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e)
-                .into_report()
-                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
-        }
+        ));
+
+    if let Some(secret) = merchant_account.payment_response_hash_key.as_ref() {
+        let signature = crypto::HmacSha512
+            .sign_message(secret.as_bytes(), body.as_bytes())
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+        request = request.header(headers::X_WEBHOOK_SIGNATURE, hex::encode(signature));
+    }
+
+    let response = request.body(body).send().await;
+
+    let (delivery_status, response_status_code, error_message) = match response {
+        Err(e) => (
+            enums::WebhookDeliveryStatus::Failed,
+            e.status().map(|status| i32::from(status.as_u16())),
+            Some(e.to_string()),
+        ),
         Ok(res) => {
-            if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
+            let status_code = Some(i32::from(res.status().as_u16()));
+            if res.status().is_success() {
+                (enums::WebhookDeliveryStatus::Delivered, status_code, None)
+            } else {
+                (
+                    enums::WebhookDeliveryStatus::Failed,
+                    status_code,
+                    Some(errors::WebhooksFlowError::NotReceivedByMerchant.to_string()),
+                )
             }
         }
+    };
+
+    let delivery_attempt = state
+        .store
+        .insert_webhook_delivery_attempt(storage::WebhookDeliveryAttemptNew {
+            event_id: event_id.to_owned(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            attempt_number,
+            delivery_status,
+            request_body,
+            response_status_code,
+            error_message,
+        })
+        .await
+        .change_context(errors::WebhooksFlowError::DeliveryAttemptRecordingFailed)?;
+
+    if delivery_status == enums::WebhookDeliveryStatus::Delivered {
+        state
+            .store
+            .update_event(
+                event_id,
+                storage::EventUpdate::UpdateWebhookNotified {
+                    is_webhook_notified: Some(true),
+                },
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::DeliveryAttemptRecordingFailed)?;
+    }
+
+    Ok(delivery_attempt)
+}
+
+/// Returns the time at which the next delivery should be attempted, if the retries are not
+/// exhausted yet. The delay doubles with every failed attempt.
+pub fn get_outgoing_webhook_retry_schedule_time(
+    webhooks_settings: &settings::WebhooksSettings,
+    attempt_number: i32,
+) -> Option<time::PrimitiveDateTime> {
+    if attempt_number >= webhooks_settings.outgoing_max_delivery_attempts {
+        return None;
+    }
+
+    let multiplier = 2_i32.checked_pow(u32::try_from(attempt_number - 1).ok()?)?;
+    let delay = webhooks_settings
+        .outgoing_retry_base_delay_in_secs
+        .checked_mul(multiplier)?;
+
+    Some(common_utils::date_time::now().saturating_add(time::Duration::seconds(delay.into())))
+}
+
+#[instrument(skip_all)]
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    webhooks_settings: &settings::WebhooksSettings,
+    event_id: &str,
+    merchant_id: &str,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let schedule_time = match get_outgoing_webhook_retry_schedule_time(webhooks_settings, 1) {
+        Some(schedule_time) => schedule_time,
+        None => return Ok(()),
+    };
+
+    let tracking_data = OutgoingWebhookTrackingData {
+        event_id: event_id.to_owned(),
+        merchant_id: merchant_id.to_owned(),
+    };
+    let current_time = common_utils::date_time::now();
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!("{runner}_{task}_{event_id}"),
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: Encode::<OutgoingWebhookTrackingData>::encode_to_value(&tracking_data)
+            .change_context(errors::WebhooksFlowError::RetrySchedulingFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::RetrySchedulingFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting outgoing webhook retry task: event_id: {event_id}")
+        })?;
+
+    Ok(())
+}
+
+#[instrument(skip(state))]
+pub async fn retry_outgoing_webhook_delivery(
+    state: &AppState,
+    event_id: String,
+) -> RouterResponse<api::WebhookDeliveryAttemptResponse> {
+    let db = &*state.store;
+    let last_delivery_attempt = db
+        .find_webhook_delivery_attempts_by_event_id(&event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching webhook delivery attempts")?
+        .into_iter()
+        .next()
+        .ok_or(errors::ApiErrorResponse::EventNotFound)
+        .into_report()?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&last_delivery_attempt.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let delivery_attempt = deliver_webhook_to_merchant(
+        state,
+        &merchant_account,
+        &event_id,
+        last_delivery_attempt.request_body,
+        last_delivery_attempt.attempt_number + 1,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while retrying outgoing webhook delivery")?;
+
+    Ok(services::ApplicationResponse::Json(
+        delivery_attempt.foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_events(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::EventListConstraints,
+) -> RouterResponse<Vec<api::EventResponse>> {
+    let events = state
+        .store
+        .list_events_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing events")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(events))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<api::EventRetrieveResponse> {
+    let db = &*state.store;
+    let event = db
+        .find_event_by_merchant_id_event_id(&merchant_account.merchant_id, &event_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    let delivery_attempts = db
+        .find_webhook_delivery_attempts_by_event_id(&event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching webhook delivery attempts")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::EventRetrieveResponse {
+            content: event.content.clone(),
+            event: event.foreign_into(),
+            delivery_attempts,
+        },
+    ))
+}
+
+/// Sends the stored body of the event to the merchant's webhook endpoint again. Unlike the
+/// retries made by the scheduler, events can be replayed even after they have been delivered.
+#[instrument(skip(state))]
+pub async fn replay_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<api::WebhookDeliveryAttemptResponse> {
+    let db = &*state.store;
+    let event = db
+        .find_event_by_merchant_id_event_id(&merchant_account.merchant_id, &event_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    let request_body = event
+        .content
+        .get_required_value("content")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The body of the event was not stored")?;
+
+    let attempt_number = db
+        .find_webhook_delivery_attempts_by_event_id(&event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching webhook delivery attempts")?
+        .first()
+        .map_or(1, |last_delivery_attempt| {
+            last_delivery_attempt.attempt_number + 1
+        });
+
+    let delivery_attempt = deliver_webhook_to_merchant(
+        state,
+        &merchant_account,
+        &event_id,
+        request_body,
+        attempt_number,
+    )
+    .await
+    .map_err(|err| match err.current_context() {
+        errors::WebhooksFlowError::MerchantWebhookDetailsNotFound
+        | errors::WebhooksFlowError::MerchantWebhookURLNotConfigured => {
+            err.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The webhook URL of the merchant is not configured".to_string(),
+            })
+        }
+        _ => err
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while replaying the event"),
+    })?;
+
+    Ok(services::ApplicationResponse::Json(
+        delivery_attempt.foreign_into(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn process_incoming_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_data: IncomingWebhookTrackingData,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let webhook_details = api::IncomingWebhookDetails {
+        object_reference_id: webhook_data.object_reference_id,
+        resource_object: Encode::<serde_json::Value>::encode_to_vec(&webhook_data.resource_object)
+            .change_context(errors::WebhooksFlowError::IncomingWebhookEncodingFailed)?,
+    };
+
+    let flow_type: api::WebhookFlow = webhook_data.event_type.to_owned().into();
+    match flow_type {
+        api::WebhookFlow::Payment => payments_incoming_webhook_flow(
+            state,
+            merchant_account,
+            webhook_details,
+            webhook_data.source_verified,
+            webhook_data.event_type,
+        )
+        .await
+        .attach_printable("Incoming webhook flow for payments failed"),
+
+        api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
+            state,
+            merchant_account,
+            webhook_details,
+            &webhook_data.connector_name,
+            webhook_data.source_verified,
+            webhook_data.event_type,
+        )
+        .await
+        .attach_printable("Incoming webhook flow for refunds failed"),
+
+        api::WebhookFlow::Dispute => disputes_incoming_webhook_flow(
+            state,
+            merchant_account,
+            webhook_details,
+            webhook_data.source_verified,
+            webhook_data.dispute_details,
+            &webhook_data.connector_name,
+            webhook_data.event_type,
+        )
+        .await
+        .attach_printable("Incoming webhook flow for disputes failed"),
+
+        api::WebhookFlow::Mandate => mandates_incoming_webhook_flow(
+            state,
+            merchant_account,
+            webhook_details,
+            webhook_data.source_verified,
+            webhook_data.event_type,
+        )
+        .await
+        .attach_printable("Incoming webhook flow for mandates failed"),
+
+        api::WebhookFlow::ReturnResponse => Ok(()),
+
+        // The decisions of fraud check connectors are handled as they are received, by
+        // `fraud_check::frm_webhooks_core`
+        api::WebhookFlow::FraudCheck | api::WebhookFlow::Subscription => {
+            Err(errors::WebhooksFlowError::UnsupportedFlow).into_report()
+        }
     }
+}
+
+/// Returns the time at which processing of an incoming webhook should be attempted again, if the
+/// retries are not exhausted yet. The delay doubles with every failed attempt.
+pub fn get_incoming_webhook_retry_schedule_time(
+    webhooks_settings: &settings::WebhooksSettings,
+    attempt_number: i32,
+) -> Option<time::PrimitiveDateTime> {
+    if attempt_number >= webhooks_settings.incoming_max_process_attempts {
+        return None;
+    }
+
+    let multiplier = 2_i32.checked_pow(u32::try_from(attempt_number - 1).ok()?)?;
+    let delay = webhooks_settings
+        .incoming_retry_base_delay_in_secs
+        .checked_mul(multiplier)?;
+
+    Some(common_utils::date_time::now().saturating_add(time::Duration::seconds(delay.into())))
+}
+
+#[instrument(skip_all)]
+async fn add_incoming_webhook_task(
+    db: &dyn StorageInterface,
+    webhook_data: &IncomingWebhookTrackingData,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let current_time = common_utils::date_time::now();
+    let runner = "INCOMING_WEBHOOK_WORKFLOW";
+    let task = "INCOMING_WEBHOOK";
+    let process_tracker_id = format!("{runner}_{task}_{}", generate_id(consts::ID_LENGTH, "whin"));
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: process_tracker_id.clone(),
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK"), webhook_data.get_ordering_key()],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data: Encode::<IncomingWebhookTrackingData>::encode_to_value(webhook_data)
+            .change_context(errors::WebhooksFlowError::IncomingWebhookSchedulingFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::IncomingWebhookSchedulingFailed)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting incoming webhook task: process_id: {process_tracker_id}"
+            )
+        })?;
 
     Ok(())
 }
@@ -279,6 +972,11 @@ pub async fn webhooks_core(
     connector_name: &str,
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
+    if api::enums::FrmConnectors::from_str(connector_name).is_ok() {
+        return fraud_check::frm_webhooks_core(state, req, merchant_account, connector_name, body)
+            .await;
+    }
+
     let connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
         connector_name,
@@ -291,6 +989,7 @@ pub async fn webhooks_core(
 
     let mut request_details = api::IncomingWebhookRequestDetails {
         method: req.method().clone(),
+        uri: req.uri(),
         headers: req.headers(),
         body: &body,
     };
@@ -322,6 +1021,12 @@ pub async fn webhooks_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not find event type in incoming webhook body")?;
 
+    let response_secret = connector
+        .get_webhook_api_response_merchant_secret(&*state.store, &merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not get the secret for the incoming webhook api response")?;
+
     let process_webhook_further = utils::lookup_webhook_event(
         &*state.store,
         connector_name,
@@ -341,38 +1046,47 @@ pub async fn webhooks_core(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Could not find resource object in incoming webhook body")?;
 
-        let webhook_details = api::IncomingWebhookDetails {
-            object_reference_id: object_ref_id,
-            resource_object: Encode::<serde_json::Value>::encode_to_vec(&event_object)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "There was an issue when encoding the incoming webhook body to bytes",
-                )?,
-        };
-
         let flow_type: api::WebhookFlow = event_type.to_owned().into();
         match flow_type {
-            api::WebhookFlow::Payment => payments_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                webhook_details,
-                source_verified,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            api::WebhookFlow::Payment
+            | api::WebhookFlow::Refund
+            | api::WebhookFlow::Dispute
+            | api::WebhookFlow::Mandate => {
+                // Disputes can't be synced with the connector, so their details are only read
+                // from webhooks whose source could be verified
+                let dispute_details = match flow_type {
+                    api::WebhookFlow::Dispute if source_verified => Some(
+                        connector
+                            .get_dispute_details(&request_details)
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable(
+                                "Could not find dispute details in incoming webhook body",
+                            )?,
+                    ),
+                    _ => None,
+                };
 
-            api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                webhook_details,
-                connector_name,
-                source_verified,
-                event_type,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+                let webhook_data = IncomingWebhookTrackingData {
+                    merchant_id: merchant_account.merchant_id.clone(),
+                    connector_name: connector_name.to_owned(),
+                    event_type,
+                    source_verified,
+                    object_reference_id: object_ref_id,
+                    resource_object: event_object,
+                    dispute_details,
+                };
+
+                if state.conf.webhooks.incoming_async_enabled {
+                    add_incoming_webhook_task(&*state.store, &webhook_data)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to schedule incoming webhook processing")?;
+                } else {
+                    process_incoming_webhook(state.clone(), merchant_account, webhook_data)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                }
+            }
 
             api::WebhookFlow::ReturnResponse => {}
 
@@ -383,7 +1097,7 @@ pub async fn webhooks_core(
     }
 
     let response = connector
-        .get_webhook_api_response(&request_details)
+        .get_webhook_api_response(&request_details, &response_secret)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not get incoming webhook api response from connector")?;
 
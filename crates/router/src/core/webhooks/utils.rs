@@ -1,10 +1,18 @@
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+
 use crate::{
+    core::errors::{self, CustomResult},
     db::{get_and_deserialize_key, StorageInterface},
     types::api,
+    utils::ValueExt,
 };
 
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::PaymentIntentCaptureSuccess,
+    ])
 }
 
 pub async fn lookup_webhook_event(
@@ -22,3 +30,34 @@ pub async fn lookup_webhook_event(
 
     webhook_config.contains(event)
 }
+
+/// Fetches the secret the webhooks of a connector are verified with, as configured with the
+/// merchant connector account. Secrets set up before they were kept there are still looked up by
+/// the key they were stored with, in redis or else in the configs.
+pub async fn get_webhook_source_verification_secret(
+    db: &dyn StorageInterface,
+    connector_id: &str,
+    merchant_id: &str,
+) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(merchant_id, connector_id)
+        .await
+        .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+    if let Some(webhook_details) = merchant_connector_account.connector_webhook_details {
+        let webhook_details: api::MerchantConnectorWebhookDetails = webhook_details
+            .parse_value("MerchantConnectorWebhookDetails")
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+        return Ok(webhook_details.merchant_secret.expose().into_bytes());
+    }
+
+    let key = format!("whsec_verification_{connector_id}_{merchant_id}");
+    match db.get_key(&key).await {
+        Ok(secret) if !secret.is_empty() => Ok(secret),
+        _ => db
+            .find_config_by_key(&key)
+            .await
+            .map(|config| config.config.into_bytes())
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound),
+    }
+}
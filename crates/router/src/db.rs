@@ -1,22 +1,39 @@
 pub mod address;
+pub mod analytics;
 pub mod api_keys;
+pub mod audit_log;
+pub mod blocklist;
+pub mod business_profile;
 pub mod cache;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod credits;
 pub mod customers;
+pub mod dispute;
+pub mod duplicate_payment;
 pub mod ephemeral_key;
 pub mod events;
+pub mod locker_card;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
+pub mod rate_limit;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod user;
+pub mod user_role;
+pub mod webhook_delivery_attempt;
 
 use std::sync::Arc;
 
@@ -37,24 +54,41 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + analytics::AnalyticsInterface
     + api_keys::ApiKeyInterface
+    + audit_log::AuditLogInterface
+    + blocklist::BlocklistInterface
+    + business_profile::BusinessProfileInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
+    + credits::CreditInterface
     + customers::CustomerInterface
+    + dispute::DisputeInterface
+    + duplicate_payment::DuplicatePaymentInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + locker_card::LockerCardInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface
+    + merchant_key_store::MerchantKeyStoreInterface
+    + organization::OrganizationInterface
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutsInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + rate_limit::RateLimitInterface
+    + recon::ReconInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + user::UserInterface
+    + user_role::UserRoleInterface
+    + webhook_delivery_attempt::WebhookDeliveryAttemptInterface
     + 'static
 {
     async fn close(&mut self) {}
@@ -81,6 +115,7 @@ pub struct MockDb {
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
+    captures: Arc<Mutex<Vec<storage::Capture>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
 
@@ -95,6 +130,7 @@ impl MockDb {
             refunds: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
+            captures: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
     }
@@ -0,0 +1,57 @@
+#[cfg(feature = "olap")]
+use error_stack::IntoReport;
+#[cfg(feature = "olap")]
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+#[cfg(feature = "olap")]
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::{
+        api,
+        storage::{self, PaymentAnalyticsDbExt},
+    },
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsInterface {
+    #[cfg(feature = "olap")]
+    async fn get_payment_analytics(
+        &self,
+        merchant_id: &str,
+        req: &api::PaymentAnalyticsRequest,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentAnalyticsRow>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsInterface for Store {
+    #[cfg(feature = "olap")]
+    async fn get_payment_analytics(
+        &self,
+        merchant_id: &str,
+        req: &api::PaymentAnalyticsRequest,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentAnalyticsRow>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await?;
+        storage::PaymentAnalyticsRow::aggregate(&conn, merchant_id, req, end_time)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsInterface for MockDb {
+    #[cfg(feature = "olap")]
+    async fn get_payment_analytics(
+        &self,
+        _merchant_id: &str,
+        _req: &api::PaymentAnalyticsRequest,
+        _end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentAnalyticsRow>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
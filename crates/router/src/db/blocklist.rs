@@ -0,0 +1,135 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait BlocklistInterface {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &str,
+        data_kind: enums::BlocklistDataKind,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &str,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for Store {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        blocklist_entry
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Blocklist::delete_by_merchant_id_fingerprint_id(&conn, merchant_id, fingerprint_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &str,
+        data_kind: enums::BlocklistDataKind,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Blocklist::list_by_merchant_id_data_kind(
+            &conn,
+            merchant_id,
+            data_kind,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &str,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Blocklist::find_by_merchant_id_fingerprint_ids(&conn, merchant_id, fingerprint_ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for MockDb {
+    async fn insert_blocklist_entry(
+        &self,
+        _blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
+        &self,
+        _merchant_id: &str,
+        _fingerprint_id: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        _merchant_id: &str,
+        _data_kind: enums::BlocklistDataKind,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        _merchant_id: &str,
+        _fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
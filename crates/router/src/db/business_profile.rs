@@ -0,0 +1,155 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait BusinessProfileInterface {
+    async fn insert_business_profile(
+        &self,
+        business_profile: storage::BusinessProfileNew,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError>;
+
+    async fn find_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError>;
+
+    async fn list_business_profiles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BusinessProfile>, errors::StorageError>;
+
+    async fn update_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        business_profile: storage::BusinessProfileUpdate,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError>;
+
+    async fn delete_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BusinessProfileInterface for Store {
+    async fn insert_business_profile(
+        &self,
+        business_profile: storage::BusinessProfileNew,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        business_profile
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::BusinessProfile::find_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_business_profiles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BusinessProfile>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::BusinessProfile::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        business_profile: storage::BusinessProfileUpdate,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::BusinessProfile::update_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            business_profile,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_business_profile_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::BusinessProfile::delete_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BusinessProfileInterface for MockDb {
+    async fn insert_business_profile(
+        &self,
+        _business_profile: storage::BusinessProfileNew,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_business_profile_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &str,
+        _profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_business_profiles_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::BusinessProfile>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_business_profile_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &str,
+        _profile_id: &str,
+        _business_profile: storage::BusinessProfileUpdate,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_business_profile_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &str,
+        _profile_id: &str,
+    ) -> CustomResult<storage::BusinessProfile, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,139 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update_with_capture_id(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Capture::find_all_by_merchant_id_payment_id_authorized_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            authorized_attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+        let current_time = common_utils::date_time::now();
+        let capture = storage::Capture {
+            capture_id: capture.capture_id,
+            payment_id: capture.payment_id,
+            merchant_id: capture.merchant_id,
+            status: capture.status,
+            amount: capture.amount,
+            currency: capture.currency,
+            connector: capture.connector,
+            error_message: capture.error_message,
+            error_code: capture.error_code,
+            created_at: capture.created_at.unwrap_or(current_time),
+            modified_at: capture.modified_at.unwrap_or(current_time),
+            authorized_attempt_id: capture.authorized_attempt_id,
+            connector_capture_id: capture.connector_capture_id,
+            capture_sequence: capture.capture_sequence,
+        };
+        captures.push(capture.clone());
+        Ok(capture)
+    }
+
+    async fn update_capture_with_capture_id(
+        &self,
+        _this: storage::Capture,
+        _capture: storage::CaptureUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let captures = self.captures.lock().await;
+        Ok(captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -23,6 +23,12 @@ pub trait ConnectorResponseInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::ConnectorResponse, errors::StorageError>;
 
+    async fn find_connector_responses_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorResponse>, errors::StorageError>;
+
     async fn update_connector_response(
         &self,
         this: storage::ConnectorResponse,
@@ -65,6 +71,22 @@ impl ConnectorResponseInterface for Store {
         .into_report()
     }
 
+    async fn find_connector_responses_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorResponse>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ConnectorResponse::find_all_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn update_connector_response(
         &self,
         this: storage::ConnectorResponse,
@@ -99,6 +121,8 @@ impl ConnectorResponseInterface for MockDb {
             connector_transaction_id: new.connector_transaction_id,
             authentication_data: new.authentication_data,
             encoded_data: new.encoded_data,
+            connector_request_payload: new.connector_request_payload,
+            connector_response_payload: new.connector_response_payload,
         };
         connector_response.push(response.clone());
         Ok(response)
@@ -115,6 +139,19 @@ impl ConnectorResponseInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_connector_responses_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorResponse>, errors::StorageError> {
+        let connector_response = self.connector_response.lock().await;
+        Ok(connector_response
+            .iter()
+            .filter(|item| item.merchant_id == merchant_id && item.payment_id == payment_id)
+            .cloned()
+            .collect())
+    }
+
     // safety: interface only used for testing
     #[allow(clippy::unwrap_used)]
     async fn update_connector_response(
@@ -0,0 +1,92 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CreditInterface {
+    async fn insert_credit(
+        &self,
+        credit: storage::CreditNew,
+    ) -> CustomResult<storage::Credit, errors::StorageError>;
+
+    async fn update_credit(
+        &self,
+        this: storage::Credit,
+        credit: storage::CreditUpdate,
+    ) -> CustomResult<storage::Credit, errors::StorageError>;
+
+    async fn find_credit_by_merchant_id_credit_id(
+        &self,
+        merchant_id: &str,
+        credit_id: &str,
+    ) -> CustomResult<storage::Credit, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CreditInterface for Store {
+    async fn insert_credit(
+        &self,
+        credit: storage::CreditNew,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        credit.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn update_credit(
+        &self,
+        this: storage::Credit,
+        credit: storage::CreditUpdate,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, credit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_credit_by_merchant_id_credit_id(
+        &self,
+        merchant_id: &str,
+        credit_id: &str,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Credit::find_by_merchant_id_credit_id(&conn, merchant_id, credit_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CreditInterface for MockDb {
+    async fn insert_credit(
+        &self,
+        _credit: storage::CreditNew,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_credit(
+        &self,
+        _this: storage::Credit,
+        _credit: storage::CreditUpdate,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_credit_by_merchant_id_credit_id(
+        &self,
+        _merchant_id: &str,
+        _credit_id: &str,
+    ) -> CustomResult<storage::Credit, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -41,6 +41,12 @@ pub trait CustomerInterface {
         &self,
         customer_data: storage::CustomerNew,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
+
+    /// Lists all the customers of the merchant, including the redacted ones
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -127,6 +133,17 @@ impl CustomerInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Customer::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -184,6 +201,7 @@ impl CustomerInterface for MockDb {
             description: customer_data.description,
             created_at: common_utils::date_time::now(),
             metadata: customer_data.metadata,
+            default_payment_method_id: None,
         };
         customers.push(customer.clone());
         Ok(customer)
@@ -197,4 +215,17 @@ impl CustomerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_customers_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+
+        Ok(customers
+            .iter()
+            .filter(|customer| customer.merchant_id == merchant_id)
+            .cloned()
+            .collect())
+    }
 }
@@ -0,0 +1,97 @@
+use redis_interface::errors::RedisError;
+
+use super::{MockDb, Store};
+use crate::core::errors::CustomResult;
+
+/// Records the payment at the key for the given number of seconds, unless another payment is
+/// recorded there. Returns the other payment, if any.
+const RECORD_UNLESS_RECORDED_SCRIPT: &str = r#"
+local recorded = redis.call('GET', KEYS[1])
+if recorded and recorded ~= ARGV[1] then
+    return recorded
+end
+redis.call('SET', KEYS[1], ARGV[1], 'EX', tonumber(ARGV[2]))
+return false
+"#;
+
+#[async_trait::async_trait]
+pub trait DuplicatePaymentInterface {
+    /// Records the payment at the key of its card and amount for the window, unless another
+    /// payment is recorded there. Returns the payment recorded earlier in the window, if any.
+    async fn record_payment_unless_recorded(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<Option<String>, RedisError>;
+
+    /// Records the payment at the key of its card and amount for the window, in place of the
+    /// payment recorded earlier.
+    async fn record_payment(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<(), RedisError>;
+}
+
+async fn record_unless_recorded(
+    redis: &redis_interface::RedisConnectionPool,
+    key: &str,
+    payment_id: &str,
+    window_in_secs: u32,
+) -> CustomResult<Option<String>, RedisError> {
+    redis
+        .evaluate_redis_script(
+            RECORD_UNLESS_RECORDED_SCRIPT,
+            vec![key.to_string()],
+            vec![payment_id.to_string(), window_in_secs.to_string()],
+        )
+        .await
+}
+
+#[async_trait::async_trait]
+impl DuplicatePaymentInterface for Store {
+    async fn record_payment_unless_recorded(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<Option<String>, RedisError> {
+        record_unless_recorded(&*self.redis_conn()?, key, payment_id, window_in_secs).await
+    }
+
+    async fn record_payment(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<(), RedisError> {
+        self.redis_conn()?
+            .set_key_with_expiry(key, payment_id, window_in_secs.into())
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DuplicatePaymentInterface for MockDb {
+    async fn record_payment_unless_recorded(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<Option<String>, RedisError> {
+        record_unless_recorded(&self.redis, key, payment_id, window_in_secs).await
+    }
+
+    async fn record_payment(
+        &self,
+        key: &str,
+        payment_id: &str,
+        window_in_secs: u32,
+    ) -> CustomResult<(), RedisError> {
+        self.redis
+            .set_key_with_expiry(key, payment_id, window_in_secs.into())
+            .await
+    }
+}
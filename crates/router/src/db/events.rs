@@ -4,7 +4,7 @@ use super::{MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, EventDbExt},
 };
 
 #[async_trait::async_trait]
@@ -13,6 +13,29 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::webhooks::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        event_id: &str,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,6 +47,53 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await?;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::webhooks::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Event::filter_by_constraints(&conn, merchant_id, event_constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_event(
+        &self,
+        event_id: &str,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Event::update(&conn, event_id, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -35,4 +105,39 @@ impl EventInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        _merchant_id: &str,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_events_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _event_constraints: api_models::webhooks::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_event(
+        &self,
+        _event_id: &str,
+        _event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,128 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait LockerCardInterface {
+    async fn insert_locker_card(
+        &self,
+        locker_card: storage::LockerCardNew,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError>;
+
+    async fn find_locker_card_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError>;
+
+    async fn find_locker_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError>;
+
+    async fn delete_locker_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LockerCardInterface for Store {
+    async fn insert_locker_card(
+        &self,
+        locker_card: storage::LockerCardNew,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        locker_card
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_locker_card_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::LockerCard::find_by_card_id(&conn, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_locker_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::LockerCard::find_by_merchant_id_customer_id_card_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_locker_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::LockerCard::delete_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl LockerCardInterface for MockDb {
+    async fn insert_locker_card(
+        &self,
+        _locker_card: storage::LockerCardNew,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_locker_card_by_card_id(
+        &self,
+        _card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_locker_card_by_merchant_id_customer_id_card_fingerprint(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _card_fingerprint: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_locker_card_by_merchant_id_card_id(
+        &self,
+        _merchant_id: &str,
+        _card_id: &str,
+    ) -> CustomResult<storage::LockerCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -15,6 +15,12 @@ pub trait MandateInterface {
         mandate_id: &str,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        merchant_id: &str,
+        connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
@@ -48,6 +54,22 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        merchant_id: &str,
+        connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Mandate::find_by_merchant_id_connector_mandate_id(
+            &conn,
+            merchant_id,
+            connector_mandate_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
@@ -97,6 +119,15 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_mandate_by_merchant_id_connector_mandate_id(
+        &self,
+        _merchant_id: &str,
+        _connector_mandate_id: &str,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         _merchant_id: &str,
@@ -20,6 +20,12 @@ pub trait MerchantAccountInterface {
         merchant_id: &str,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError>;
 
+    /// Finds the tombstone of a merchant account that has been closed
+    async fn find_deleted_merchant_account_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantAccount, errors::StorageError>;
+
     async fn update_merchant(
         &self,
         this: storage::MerchantAccount,
@@ -46,6 +52,11 @@ pub trait MerchantAccountInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -85,6 +96,17 @@ impl MerchantAccountInterface for Store {
         }
     }
 
+    async fn find_deleted_merchant_account_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantAccount::find_deleted_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn update_merchant(
         &self,
         this: storage::MerchantAccount,
@@ -182,6 +204,17 @@ impl MerchantAccountInterface for Store {
             super::cache::redact_cache(self, merchant_id, delete_func).await
         }
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantAccount::find_by_organization_id(&conn, organization_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -209,6 +242,18 @@ impl MerchantAccountInterface for MockDb {
             merchant_details: merchant_account.merchant_details,
             webhook_details: merchant_account.webhook_details,
             routing_algorithm: merchant_account.routing_algorithm,
+            routing_rules: merchant_account.routing_rules,
+            authorization_expiry_policy: merchant_account.authorization_expiry_policy,
+            sca_exemption_policy: merchant_account.sca_exemption_policy,
+            credit_policy: merchant_account.credit_policy,
+            fraud_check_policy: merchant_account.fraud_check_policy,
+            velocity_policy: merchant_account.velocity_policy,
+            duplicate_payment_policy: merchant_account.duplicate_payment_policy,
+            card_verification_policy: merchant_account.card_verification_policy,
+            test_mode: merchant_account.test_mode.unwrap_or_default(),
+            organization_id: merchant_account.organization_id,
+            is_deleted: false,
+            deleted_at: None,
             sub_merchants_enabled: merchant_account.sub_merchants_enabled,
             parent_merchant_id: merchant_account.parent_merchant_id,
             publishable_key: merchant_account.publishable_key,
@@ -228,7 +273,7 @@ impl MerchantAccountInterface for MockDb {
         let accounts = self.merchant_accounts.lock().await;
         let account = accounts
             .iter()
-            .find(|account| account.merchant_id == merchant_id);
+            .find(|account| account.merchant_id == merchant_id && !account.is_deleted);
 
         match account {
             Some(account) => Ok(account.clone()),
@@ -237,6 +282,20 @@ impl MerchantAccountInterface for MockDb {
         }
     }
 
+    async fn find_deleted_merchant_account_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+
+        accounts
+            .iter()
+            .find(|account| account.merchant_id == merchant_id && account.is_deleted)
+            .cloned()
+            .ok_or_else(|| Report::from(storage_models::errors::DatabaseError::NotFound).into())
+            .into_report()
+    }
+
     async fn update_merchant(
         &self,
         _this: storage::MerchantAccount,
@@ -285,4 +344,19 @@ impl MerchantAccountInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_merchant_accounts_by_organization_id(
+        &self,
+        organization_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+
+        Ok(accounts
+            .iter()
+            .filter(|account| {
+                account.organization_id.as_deref() == Some(organization_id) && !account.is_deleted
+            })
+            .cloned()
+            .collect())
+    }
 }
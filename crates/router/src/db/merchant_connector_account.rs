@@ -1,6 +1,9 @@
-use common_utils::ext_traits::{ByteSliceExt, Encode};
+use common_utils::{
+    ext_traits::{ByteSliceExt, Encode},
+    secrets_manager,
+};
 use error_stack::{IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface, Secret};
 
 use super::{MockDb, Store};
 use crate::{
@@ -134,6 +137,70 @@ pub trait MerchantConnectorAccountInterface {
     ) -> CustomResult<bool, errors::StorageError>;
 }
 
+/// Connector account details as stored when they are encrypted with the secrets manager
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+struct EncryptedConnectorAccountDetails {
+    encrypted: secrets_manager::Envelope,
+}
+
+impl Store {
+    async fn encrypt_connector_account_details(
+        &self,
+        connector_account_details: Secret<serde_json::Value>,
+    ) -> CustomResult<Secret<serde_json::Value>, errors::StorageError> {
+        let secrets_manager = match self.secrets_manager.as_deref() {
+            Some(secrets_manager) => secrets_manager,
+            None => return Ok(connector_account_details),
+        };
+
+        let secret = serde_json::to_vec(connector_account_details.peek())
+            .into_report()
+            .change_context(errors::StorageError::SerializationFailed)?;
+        let encrypted = secrets_manager::encrypt(secrets_manager, &secret)
+            .await
+            .change_context(errors::StorageError::EncryptionError)
+            .attach_printable("Failed to encrypt connector account details")?;
+
+        serde_json::to_value(EncryptedConnectorAccountDetails { encrypted })
+            .map(Secret::new)
+            .into_report()
+            .change_context(errors::StorageError::SerializationFailed)
+    }
+
+    async fn decrypt_merchant_connector_account(
+        &self,
+        mut merchant_connector_account: storage::MerchantConnectorAccount,
+    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        // Details stored before encryption was enabled are kept in plaintext
+        let encrypted_details =
+            match <EncryptedConnectorAccountDetails as serde::Deserialize>::deserialize(
+                &merchant_connector_account.connector_account_details,
+            ) {
+                Ok(encrypted_details) => encrypted_details,
+                Err(_) => return Ok(merchant_connector_account),
+            };
+
+        let secrets_manager = self
+            .secrets_manager
+            .as_deref()
+            .ok_or(errors::StorageError::DecryptionError)
+            .into_report()
+            .attach_printable(
+                "Connector account details are encrypted, but no secrets manager is configured",
+            )?;
+        let secret = secrets_manager::decrypt(secrets_manager, &encrypted_details.encrypted)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+            .attach_printable("Failed to decrypt connector account details")?;
+
+        merchant_connector_account.connector_account_details = serde_json::from_slice(&secret)
+            .into_report()
+            .change_context(errors::StorageError::DeserializationFailed)?;
+        Ok(merchant_connector_account)
+    }
+}
+
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for Store {
     async fn find_merchant_connector_account_by_merchant_id_connector(
@@ -142,14 +209,18 @@ impl MerchantConnectorAccountInterface for Store {
         connector: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
-            &conn,
-            merchant_id,
-            connector,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        let merchant_connector_account =
+            storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+                &conn,
+                merchant_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -168,23 +239,35 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
         };
+        // The cache holds the connector account details as stored, so that they are never
+        // cached in plaintext
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            find_call().await
-        }
+        let merchant_connector_account = find_call().await?;
 
         #[cfg(feature = "accounts_cache")]
-        {
-            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await
-        }
+        let merchant_connector_account =
+            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await?;
+
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn insert_merchant_connector_account(
         &self,
-        t: storage::MerchantConnectorAccountNew,
+        mut t: storage::MerchantConnectorAccountNew,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        if let Some(connector_account_details) = t.connector_account_details.take() {
+            t.connector_account_details = Some(
+                self.encrypt_connector_account_details(connector_account_details)
+                    .await?,
+            );
+        }
+
         let conn = pg_connection(&self.master_pool).await?;
-        t.insert(&conn).await.map_err(Into::into).into_report()
+        let merchant_connector_account = t.insert(&conn).await.map_err(Into::into).into_report()?;
+
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn find_merchant_connector_account_by_merchant_id_and_disabled_list(
@@ -193,10 +276,24 @@ impl MerchantConnectorAccountInterface for Store {
         get_disabled: bool,
     ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id, get_disabled)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let merchant_connector_accounts = storage::MerchantConnectorAccount::find_by_merchant_id(
+            &conn,
+            merchant_id,
+            get_disabled,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+
+        let mut decrypted_merchant_connector_accounts =
+            Vec::with_capacity(merchant_connector_accounts.len());
+        for merchant_connector_account in merchant_connector_accounts {
+            decrypted_merchant_connector_accounts.push(
+                self.decrypt_merchant_connector_account(merchant_connector_account)
+                    .await?,
+            );
+        }
+        Ok(decrypted_merchant_connector_accounts)
     }
 
     async fn update_merchant_connector_account(
@@ -204,6 +301,41 @@ impl MerchantConnectorAccountInterface for Store {
         this: storage::MerchantConnectorAccount,
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        let merchant_connector_account = match merchant_connector_account {
+            storage::MerchantConnectorAccountUpdate::Update {
+                merchant_id,
+                connector_type,
+                connector_name,
+                connector_account_details,
+                test_mode,
+                disabled,
+                merchant_connector_id,
+                payment_methods_enabled,
+                metadata,
+                connector_webhook_details,
+            } => {
+                let connector_account_details = match connector_account_details {
+                    Some(connector_account_details) => Some(
+                        self.encrypt_connector_account_details(connector_account_details)
+                            .await?,
+                    ),
+                    None => None,
+                };
+                storage::MerchantConnectorAccountUpdate::Update {
+                    merchant_id,
+                    connector_type,
+                    connector_name,
+                    connector_account_details,
+                    test_mode,
+                    disabled,
+                    merchant_connector_id,
+                    payment_methods_enabled,
+                    metadata,
+                    connector_webhook_details,
+                }
+            }
+        };
+
         let _merchant_connector_id = this.merchant_connector_id.clone();
         let update_call = || async {
             let conn = pg_connection(&self.master_pool).await?;
@@ -214,14 +346,14 @@ impl MerchantConnectorAccountInterface for Store {
         };
 
         #[cfg(feature = "accounts_cache")]
-        {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await
-        }
+        let updated_merchant_connector_account =
+            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await?;
 
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            update_call().await
-        }
+        let updated_merchant_connector_account = update_call().await?;
+
+        self.decrypt_merchant_connector_account(updated_merchant_connector_account)
+            .await
     }
 
     async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -287,6 +419,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             merchant_connector_id: t.merchant_connector_id,
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
+            connector_webhook_details: t.connector_webhook_details,
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
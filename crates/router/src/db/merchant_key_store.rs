@@ -0,0 +1,66 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantKeyStoreInterface {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for Store {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        merchant_key_store
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::MerchantKeyStore::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for MockDb {
+    async fn insert_merchant_key_store(
+        &self,
+        _merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
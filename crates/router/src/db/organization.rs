@@ -0,0 +1,66 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OrganizationInterface {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+
+    async fn find_organization_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for Store {
+    async fn insert_organization(
+        &self,
+        organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        organization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_organization_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Organization::find_by_org_id(&conn, org_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationInterface for MockDb {
+    async fn insert_organization(
+        &self,
+        _organization: storage::OrganizationNew,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_organization_by_org_id(
+        &self,
+        _org_id: &str,
+    ) -> CustomResult<storage::Organization, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -26,6 +26,13 @@ pub trait PaymentAttemptInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
 
+    async fn list_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         connector_transaction_id: &str,
@@ -109,6 +116,19 @@ mod storage {
                 .into_report()
         }
 
+        async fn list_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            PaymentAttempt::find_all_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
             &self,
             connector_transaction_id: &str,
@@ -245,6 +265,16 @@ impl PaymentAttemptInterface for MockDb {
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             payment_method_data: payment_attempt.payment_method_data,
+            connector_attempts: payment_attempt.connector_attempts,
+            unified_code: payment_attempt.unified_code,
+            unified_message: payment_attempt.unified_message,
+            connector_latency_in_millis: payment_attempt.connector_latency_in_millis,
+            network_transaction_id: payment_attempt.network_transaction_id,
+            external_three_ds_authentication: payment_attempt.external_three_ds_authentication,
+            fraud_check: payment_attempt.fraud_check,
+            duplicate_of: payment_attempt.duplicate_of,
+            avs_result: payment_attempt.avs_result,
+            cvv_result: payment_attempt.cvv_result,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -280,6 +310,24 @@ impl PaymentAttemptInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         _connector_transaction_id: &str,
@@ -385,6 +433,18 @@ mod storage {
                         payment_experience: payment_attempt.payment_experience.clone(),
                         payment_method_type: payment_attempt.payment_method_type.clone(),
                         payment_method_data: payment_attempt.payment_method_data.clone(),
+                        connector_attempts: payment_attempt.connector_attempts.clone(),
+                        unified_code: payment_attempt.unified_code,
+                        unified_message: payment_attempt.unified_message.clone(),
+                        connector_latency_in_millis: payment_attempt.connector_latency_in_millis,
+                        network_transaction_id: payment_attempt.network_transaction_id.clone(),
+                        external_three_ds_authentication: payment_attempt
+                            .external_three_ds_authentication
+                            .clone(),
+                        fraud_check: payment_attempt.fraud_check.clone(),
+                        duplicate_of: payment_attempt.duplicate_of.clone(),
+                        avs_result: payment_attempt.avs_result,
+                        cvv_result: payment_attempt.cvv_result,
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -556,6 +616,37 @@ mod storage {
             }
         }
 
+        async fn list_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    PaymentAttempt::find_all_by_payment_id_merchant_id(
+                        &conn,
+                        payment_id,
+                        merchant_id,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                // A payment has a single attempt in redis, see the lookup of
+                // `find_payment_attempt_by_payment_id_merchant_id`
+                enums::MerchantStorageScheme::RedisKv => self
+                    .find_payment_attempt_by_payment_id_merchant_id(
+                        payment_id,
+                        merchant_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .map(|payment_attempt| vec![payment_attempt]),
+            }
+        }
+
         async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
             &self,
             connector_transaction_id: &str,
@@ -35,6 +35,14 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn search_payment_intents(
+        &self,
+        merchant_id: &str,
+        req: &api::PaymentSearchRequest,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -92,6 +100,12 @@ mod storage {
                         setup_future_usage: new.setup_future_usage,
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
+                        split_payments: new.split_payments.clone(),
+                        forex_quote: new.forex_quote.clone(),
+                        capture_on: new.capture_on,
+                        capture_expires_at: new.capture_expires_at,
+                        profile_id: new.profile_id.clone(),
+                        connector_metadata: new.connector_metadata.clone(),
                     };
 
                     match self
@@ -232,6 +246,26 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
             }
         }
+
+        #[cfg(feature = "olap")]
+        async fn search_payment_intents(
+            &self,
+            merchant_id: &str,
+            req: &api::PaymentSearchRequest,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.replica_pool).await?;
+                    PaymentIntent::search(&conn, merchant_id, req)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+
+                enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
+            }
+        }
     }
 }
 
@@ -299,6 +333,20 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        #[cfg(feature = "olap")]
+        async fn search_payment_intents(
+            &self,
+            merchant_id: &str,
+            req: &api::PaymentSearchRequest,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(&self.replica_pool).await?;
+            PaymentIntent::search(&conn, merchant_id, req)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -315,6 +363,17 @@ impl PaymentIntentInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "olap")]
+    async fn search_payment_intents(
+        &self,
+        _merchant_id: &str,
+        _req: &api::PaymentSearchRequest,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_intent(
         &self,
@@ -347,6 +406,12 @@ impl PaymentIntentInterface for MockDb {
             setup_future_usage: new.setup_future_usage,
             off_session: new.off_session,
             client_secret: new.client_secret,
+            split_payments: new.split_payments,
+            forex_quote: new.forex_quote,
+            capture_on: new.capture_on,
+            capture_expires_at: new.capture_expires_at,
+            profile_id: new.profile_id,
+            connector_metadata: new.connector_metadata,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
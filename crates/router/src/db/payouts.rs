@@ -0,0 +1,92 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutsInterface {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutsNew,
+    ) -> CustomResult<storage::Payouts, errors::StorageError>;
+
+    async fn update_payout(
+        &self,
+        this: storage::Payouts,
+        payout: storage::PayoutsUpdate,
+    ) -> CustomResult<storage::Payouts, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payouts, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutsInterface for Store {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutsNew,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        payout.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payouts,
+        payout: storage::PayoutsUpdate,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, payout)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Payouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutsInterface for MockDb {
+    async fn insert_payout(
+        &self,
+        _payout: storage::PayoutsNew,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout(
+        &self,
+        _this: storage::Payouts,
+        _payout: storage::PayoutsUpdate,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &str,
+        _payout_id: &str,
+    ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -50,6 +50,11 @@ pub trait ProcessTrackerInterface {
         status: enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_unfinished_processes_by_tag(
+        &self,
+        tag: &str,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +102,17 @@ impl ProcessTrackerInterface for Store {
         .into_report()
     }
 
+    async fn find_unfinished_processes_by_tag(
+        &self,
+        tag: &str,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ProcessTracker::find_unfinished_processes_by_tag(&conn, tag)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -182,6 +198,26 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_unfinished_processes_by_tag(
+        &self,
+        tag: &str,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let mut processes: Vec<storage::ProcessTracker> = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.tag.iter().any(|process_tag| process_tag == tag)
+                    && process.status != enums::ProcessTrackerStatus::Finish
+            })
+            .cloned()
+            .collect();
+        processes.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        Ok(processes)
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
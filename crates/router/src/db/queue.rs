@@ -41,6 +41,8 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    async fn increment_key(&self, key: &str) -> CustomResult<i64, RedisError>;
 }
 
 #[async_trait::async_trait]
@@ -131,6 +133,10 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+
+    async fn increment_key(&self, key: &str) -> CustomResult<i64, RedisError> {
+        self.redis_conn()?.increment_key(key).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -186,4 +192,8 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.redis.get_key(key).await
     }
+
+    async fn increment_key(&self, key: &str) -> CustomResult<i64, RedisError> {
+        self.redis.increment_key(key).await
+    }
 }
@@ -0,0 +1,140 @@
+use error_stack::report;
+use redis_interface::errors::RedisError;
+
+use super::{MockDb, Store};
+use crate::core::errors::CustomResult;
+
+/// Takes a token from the bucket, refilling it for the time elapsed since it was last updated.
+/// The time of the redis server is used so that the instances of the application share the same
+/// clock. Returns whether a token was taken, and otherwise the time in milliseconds after which a
+/// token is available.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local burst = tonumber(ARGV[1])
+local requests_per_second = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now_ms = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'updated_at')
+local tokens = tonumber(bucket[1]) or burst
+local updated_at = tonumber(bucket[2]) or now_ms
+tokens = math.min(burst, tokens + math.max(0, now_ms - updated_at) * requests_per_second / 1000)
+
+local allowed = 0
+local retry_after_ms = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+else
+    retry_after_ms = math.ceil((1 - tokens) * 1000 / requests_per_second)
+end
+
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'updated_at', now_ms)
+redis.call('PEXPIRE', KEYS[1], math.ceil(burst * 1000 / requests_per_second) + 1000)
+return { allowed, retry_after_ms }
+"#;
+
+/// Counts a request in the fixed window stored at the key, which expires with the window. Returns
+/// the number of requests counted in the window so far.
+const FIXED_WINDOW_SCRIPT: &str = r#"
+local count = redis.call('INCR', KEYS[1])
+if count == 1 then
+    redis.call('EXPIRE', KEYS[1], tonumber(ARGV[1]))
+end
+return count
+"#;
+
+#[async_trait::async_trait]
+pub trait RateLimitInterface {
+    /// Takes a token from the token bucket stored at the key. Returns `None` if a token was
+    /// taken, or the number of milliseconds after which a token is available if the bucket is
+    /// empty.
+    async fn take_rate_limit_token(
+        &self,
+        key: &str,
+        burst: u32,
+        requests_per_second: u32,
+    ) -> CustomResult<Option<u64>, RedisError>;
+
+    /// Counts a request in the window of the given length stored at the key, returning the number
+    /// of requests counted in the window, this one included.
+    async fn count_in_fixed_window(
+        &self,
+        key: &str,
+        window_in_secs: u64,
+    ) -> CustomResult<u64, RedisError>;
+}
+
+async fn take_token(
+    redis: &redis_interface::RedisConnectionPool,
+    key: &str,
+    burst: u32,
+    requests_per_second: u32,
+) -> CustomResult<Option<u64>, RedisError> {
+    let reply: Vec<u64> = redis
+        .evaluate_redis_script(
+            TOKEN_BUCKET_SCRIPT,
+            vec![key.to_string()],
+            vec![burst.to_string(), requests_per_second.to_string()],
+        )
+        .await?;
+
+    match reply.as_slice() {
+        [allowed, retry_after_ms] => Ok((*allowed == 0).then_some(*retry_after_ms)),
+        _ => Err(report!(RedisError::ScriptExecutionFailed)
+            .attach_printable("Unexpected reply from the token bucket script")),
+    }
+}
+
+async fn count_in_window(
+    redis: &redis_interface::RedisConnectionPool,
+    key: &str,
+    window_in_secs: u64,
+) -> CustomResult<u64, RedisError> {
+    redis
+        .evaluate_redis_script(
+            FIXED_WINDOW_SCRIPT,
+            vec![key.to_string()],
+            vec![window_in_secs.to_string()],
+        )
+        .await
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for Store {
+    async fn take_rate_limit_token(
+        &self,
+        key: &str,
+        burst: u32,
+        requests_per_second: u32,
+    ) -> CustomResult<Option<u64>, RedisError> {
+        take_token(&*self.redis_conn()?, key, burst, requests_per_second).await
+    }
+
+    async fn count_in_fixed_window(
+        &self,
+        key: &str,
+        window_in_secs: u64,
+    ) -> CustomResult<u64, RedisError> {
+        count_in_window(&*self.redis_conn()?, key, window_in_secs).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for MockDb {
+    async fn take_rate_limit_token(
+        &self,
+        key: &str,
+        burst: u32,
+        requests_per_second: u32,
+    ) -> CustomResult<Option<u64>, RedisError> {
+        take_token(&self.redis, key, burst, requests_per_second).await
+    }
+
+    async fn count_in_fixed_window(
+        &self,
+        key: &str,
+        window_in_secs: u64,
+    ) -> CustomResult<u64, RedisError> {
+        count_in_window(&self.redis, key, window_in_secs).await
+    }
+}
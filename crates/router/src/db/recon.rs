@@ -0,0 +1,243 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums as storage_enums, ReconRecordDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait ReconInterface {
+    async fn insert_recon_report(
+        &self,
+        report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError>;
+
+    async fn insert_recon_records(
+        &self,
+        records: Vec<storage::ReconRecordNew>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError>;
+
+    async fn update_recon_record(
+        &self,
+        this: storage::ReconRecord,
+        record: storage::ReconRecordUpdate,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError>;
+
+    async fn list_recon_records_by_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        recon_status: Option<storage_enums::ReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError>;
+
+    async fn get_recon_record_status_counts(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<(storage_enums::ReconStatus, i64)>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for Store {
+    async fn insert_recon_report(
+        &self,
+        report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        report.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, report)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ReconReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ReconReport::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_recon_records(
+        &self,
+        records: Vec<storage::ReconRecordNew>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ReconRecordNew::insert_batch(&conn, records)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_recon_record(
+        &self,
+        this: storage::ReconRecord,
+        record: storage::ReconRecordUpdate,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, record)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_recon_records_by_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        recon_status: Option<storage_enums::ReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ReconRecord::filter_by_report_id(
+            &conn,
+            merchant_id,
+            report_id,
+            recon_status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn get_recon_record_status_counts(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<(storage_enums::ReconStatus, i64)>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::ReconRecord::get_status_counts(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for MockDb {
+    async fn insert_recon_report(
+        &self,
+        _report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_recon_report(
+        &self,
+        _this: storage::ReconReport,
+        _report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_recon_records(
+        &self,
+        _records: Vec<storage::ReconRecordNew>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_recon_record(
+        &self,
+        _this: storage::ReconRecord,
+        _record: storage::ReconRecordUpdate,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_records_by_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+        _recon_status: Option<storage_enums::ReconStatus>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_recon_record_status_counts(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<Vec<(storage_enums::ReconStatus, i64)>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -318,6 +318,7 @@ mod storage {
                         updated_at: new.created_at.unwrap_or_else(date_time::now),
                         description: new.description.clone(),
                         refund_reason: new.refund_reason.clone(),
+                        refund_reason_code: new.refund_reason_code,
                     };
 
                     let field = format!(
@@ -695,6 +696,7 @@ impl RefundInterface for MockDb {
             updated_at: current_time,
             description: new.description,
             refund_reason: new.refund_reason.clone(),
+            refund_reason_code: new.refund_reason_code,
         };
         refunds.push(refund.clone());
         Ok(refund)
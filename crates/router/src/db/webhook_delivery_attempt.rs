@@ -0,0 +1,66 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryAttemptInterface {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError>;
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for Store {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        delivery_attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::WebhookDeliveryAttempt::find_all_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryAttemptInterface for MockDb {
+    async fn insert_webhook_delivery_attempt(
+        &self,
+        _delivery_attempt: storage::WebhookDeliveryAttemptNew,
+    ) -> CustomResult<storage::WebhookDeliveryAttempt, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_delivery_attempts_by_event_id(
+        &self,
+        _event_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookDeliveryAttempt>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,121 @@
+//! GraphQL API over the payments, refunds, customers and disputes of a merchant.
+//!
+//! The queries and mutations are resolved by the same core operations as the REST API. Every
+//! resolver authenticates the API key the request was made with, so that each operation of a
+//! request is permitted by the role of the API key on its own.
+
+mod mutation;
+mod query;
+mod types;
+
+use actix_web::{http::header::HeaderMap, web, HttpRequest, ResponseError, Scope};
+use async_graphql::{Context, EmptySubscription, ErrorExtensions, Schema};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use router_env::{instrument, tracing, Flow};
+
+pub use self::{mutation::Mutation, query::Query};
+use crate::{
+    core::{audit_logs, errors},
+    logger,
+    routes::AppState,
+    services::{
+        authentication::{self as auth, AuthenticateAndFetch},
+        authorization::Permission,
+        rate_limiter, ApplicationResponse,
+    },
+    types::storage,
+};
+
+pub type GraphqlSchema = Schema<Query, Mutation, EmptySubscription>;
+
+pub struct Graphql;
+
+impl Graphql {
+    pub fn server(state: AppState) -> Scope {
+        let schema = Schema::build(Query, Mutation, EmptySubscription).finish();
+
+        web::scope("/graphql")
+            .app_data(web::Data::new(state))
+            .app_data(web::Data::new(schema))
+            .service(web::resource("").route(web::post().to(graphql_request)))
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::GraphqlRequest))]
+pub async fn graphql_request(
+    state: web::Data<AppState>,
+    schema: web::Data<GraphqlSchema>,
+    req: HttpRequest,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let context = RequestContext {
+        state: state.get_ref().clone(),
+        headers: req.headers().clone(),
+    };
+    let audit_context = audit_logs::AuditContext::new(req.method().as_str(), req.path());
+
+    audit_logs::with_audit_context(
+        audit_context,
+        schema.execute(request.into_inner().data(context)),
+    )
+    .await
+    .into()
+}
+
+/// Data of the request being served, shared by the resolvers of its queries and mutations.
+pub(crate) struct RequestContext {
+    state: AppState,
+    headers: HeaderMap,
+}
+
+impl RequestContext {
+    fn get<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a Self> {
+        ctx.data::<Self>()
+    }
+
+    /// Authenticates the merchant with the API key of the request, and checks the rate limits of
+    /// the merchant, as is done for the REST API.
+    async fn authenticate(
+        &self,
+        permission: Permission,
+    ) -> async_graphql::Result<storage::MerchantAccount> {
+        let merchant_account = auth::ApiKeyAuth(permission)
+            .authenticate_and_fetch(&self.headers, &self.state)
+            .await
+            .map_err(to_graphql_error)?;
+
+        rate_limiter::check_merchant_rate_limit(
+            &*self.state.store,
+            &self.state.conf.rate_limit,
+            &merchant_account.merchant_id,
+            "/graphql",
+        )
+        .await
+        .map_err(to_graphql_error)?;
+
+        Ok(merchant_account)
+    }
+}
+
+/// Returns the object of the response of a core operation. The operations served over GraphQL
+/// only respond with JSON objects.
+fn get_json_response<T>(response: errors::RouterResponse<T>) -> async_graphql::Result<T> {
+    match response.map_err(to_graphql_error)? {
+        ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(async_graphql::Error::new(
+            "The response of the operation can't be served over GraphQL",
+        )),
+    }
+}
+
+/// Converts an API error into a GraphQL error, keeping the error code and the HTTP status code of
+/// the REST API in the extensions of the error.
+fn to_graphql_error(error: error_stack::Report<errors::ApiErrorResponse>) -> async_graphql::Error {
+    logger::error!(?error);
+    let error = error.current_context();
+
+    async_graphql::Error::new(error.error_message()).extend_with(|_, extensions| {
+        extensions.set("code", error.error_code());
+        extensions.set("status_code", error.status_code().as_u16());
+    })
+}
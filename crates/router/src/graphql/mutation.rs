@@ -0,0 +1,203 @@
+use async_graphql::{Context, Json, Object};
+use error_stack::report;
+use serde::de::DeserializeOwned;
+
+use super::{
+    get_json_response, to_graphql_error,
+    types::{Customer, CustomerInput, Dispute, Payment, Refund, RefundInput},
+    RequestContext,
+};
+use crate::{
+    core::{
+        customers, disputes,
+        errors::{self, api_error_response::NotImplementedMessage},
+        payments, refunds,
+    },
+    routes::payments::authorize_verify_select,
+    services::{api, authorization::Permission},
+    types::api::{
+        self as api_types, disputes as dispute_types, enums as api_enums, payments as payment_types,
+    },
+};
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    /// Creates a payment. The input takes the same fields as the request of the REST API, as the
+    /// payment method data it carries can't be described as a GraphQL input object.
+    async fn create_payment(
+        &self,
+        ctx: &Context<'_>,
+        input: Json<serde_json::Value>,
+    ) -> async_graphql::Result<Payment> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentWrite).await?;
+        let request: payment_types::PaymentsRequest = parse_json_input(input, "payment request")?;
+
+        get_json_response(
+            authorize_verify_select(
+                payments::PaymentCreate,
+                &context.state,
+                merchant_account,
+                request,
+                api::AuthFlow::Merchant,
+            )
+            .await,
+        )
+        .map(Payment::from)
+    }
+
+    /// Confirms a payment, updating it with the fields of the input when given.
+    async fn confirm_payment(
+        &self,
+        ctx: &Context<'_>,
+        payment_id: String,
+        input: Option<Json<serde_json::Value>>,
+    ) -> async_graphql::Result<Payment> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentWrite).await?;
+        let mut request: payment_types::PaymentsRequest = match input {
+            Some(input) => parse_json_input(input, "payment request")?,
+            None => payment_types::PaymentsRequest::default(),
+        };
+
+        if let Some(api_enums::CaptureMethod::Scheduled) = request.capture_method {
+            return Err(to_graphql_error(report!(
+                errors::ApiErrorResponse::NotImplemented {
+                    message: NotImplementedMessage::Default,
+                }
+            )));
+        }
+        request.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
+        request.confirm = Some(true);
+
+        get_json_response(
+            authorize_verify_select(
+                payments::PaymentConfirm,
+                &context.state,
+                merchant_account,
+                request,
+                api::AuthFlow::Merchant,
+            )
+            .await,
+        )
+        .map(Payment::from)
+    }
+
+    /// Captures the funds of an authorized payment, all of them unless an amount is given.
+    async fn capture_payment(
+        &self,
+        ctx: &Context<'_>,
+        payment_id: String,
+        amount_to_capture: Option<i64>,
+    ) -> async_graphql::Result<Payment> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentWrite).await?;
+        let request = payment_types::PaymentsCaptureRequest {
+            payment_id: Some(payment_id),
+            amount_to_capture,
+            ..Default::default()
+        };
+
+        get_json_response(
+            payments::payments_core::<api_types::Capture, payment_types::PaymentsResponse, _, _, _>(
+                &context.state,
+                merchant_account,
+                payments::PaymentCapture,
+                request,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await,
+        )
+        .map(Payment::from)
+    }
+
+    /// Cancels a payment which hasn't been captured.
+    async fn cancel_payment(
+        &self,
+        ctx: &Context<'_>,
+        payment_id: String,
+        cancellation_reason: Option<String>,
+    ) -> async_graphql::Result<Payment> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentWrite).await?;
+        let request = payment_types::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason,
+        };
+
+        get_json_response(
+            payments::payments_core::<api_types::Void, payment_types::PaymentsResponse, _, _, _>(
+                &context.state,
+                merchant_account,
+                payments::PaymentCancel,
+                request,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await,
+        )
+        .map(Payment::from)
+    }
+
+    /// Refunds a payment, in full unless an amount is given.
+    async fn create_refund(
+        &self,
+        ctx: &Context<'_>,
+        input: RefundInput,
+    ) -> async_graphql::Result<Refund> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::RefundWrite).await?;
+
+        get_json_response(
+            refunds::refund_create_core(&context.state, merchant_account, input.into()).await,
+        )
+        .map(Refund::from)
+    }
+
+    /// Creates a customer.
+    async fn create_customer(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerInput,
+    ) -> async_graphql::Result<Customer> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::CustomerWrite).await?;
+
+        get_json_response(
+            customers::create_customer(&*context.state.store, merchant_account, input.into()).await,
+        )
+        .map(Customer::from)
+    }
+
+    /// Submits evidence to the connector to challenge a dispute. The input takes the same fields
+    /// as the request of the REST API.
+    async fn submit_dispute_evidence(
+        &self,
+        ctx: &Context<'_>,
+        input: Json<serde_json::Value>,
+    ) -> async_graphql::Result<Dispute> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::DisputeWrite).await?;
+        let request: dispute_types::SubmitEvidenceRequest =
+            parse_json_input(input, "dispute evidence")?;
+
+        get_json_response(
+            disputes::submit_evidence(&context.state, merchant_account, request).await,
+        )
+        .map(Dispute::from)
+    }
+}
+
+fn parse_json_input<T: DeserializeOwned>(
+    input: Json<serde_json::Value>,
+    input_name: &str,
+) -> async_graphql::Result<T> {
+    serde_json::from_value(input.0).map_err(|error| {
+        to_graphql_error(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid {input_name}: {error}"),
+        }))
+    })
+}
@@ -0,0 +1,194 @@
+use api_models::{customers as customer_types, disputes as dispute_types, refunds as refund_types};
+use async_graphql::{Context, Object};
+
+use super::{
+    get_json_response,
+    types::{Customer, Dispute, Payment, Refund},
+    RequestContext,
+};
+use crate::{
+    core::{customers, disputes, payments, refunds},
+    services::{api, authorization::Permission},
+    types::api::{self as api_types, payments as payment_types},
+};
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// Retrieves a payment. The status of the payment is synced with the connector when
+    /// `forceSync` is set.
+    async fn payment(
+        &self,
+        ctx: &Context<'_>,
+        payment_id: String,
+        #[graphql(default)] force_sync: bool,
+    ) -> async_graphql::Result<Payment> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentRead).await?;
+        let request = payment_types::PaymentsRetrieveRequest {
+            resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id),
+            merchant_id: None,
+            force_sync,
+            param: None,
+            connector: None,
+        };
+
+        get_json_response(
+            payments::payments_core::<api_types::PSync, payment_types::PaymentsResponse, _, _, _>(
+                &context.state,
+                merchant_account,
+                payments::PaymentStatus,
+                request,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await,
+        )
+        .map(Payment::from)
+    }
+
+    /// Lists the payments of the merchant, most recent first.
+    async fn payments(
+        &self,
+        ctx: &Context<'_>,
+        customer_id: Option<String>,
+        starting_after: Option<String>,
+        ending_before: Option<String>,
+        #[graphql(default = 10)] limit: i64,
+    ) -> async_graphql::Result<Vec<Payment>> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::PaymentRead).await?;
+        let constraints = payment_types::PaymentListConstraints {
+            customer_id,
+            starting_after,
+            ending_before,
+            limit,
+            created: None,
+            created_lt: None,
+            created_gt: None,
+            created_lte: None,
+            created_gte: None,
+            profile_id: None,
+            status: None,
+            connector: None,
+            payment_method: None,
+            currency: None,
+            amount_gte: None,
+            amount_lte: None,
+            sort_by: payment_types::PaymentListSortBy::default(),
+            sort_order: api_models::enums::SortOrder::default(),
+        };
+
+        let payments = get_json_response(
+            payments::list_payments(&*context.state.store, merchant_account, constraints).await,
+        )?;
+        Ok(payments.data.into_iter().map(Payment::from).collect())
+    }
+
+    /// Retrieves a refund.
+    async fn refund(&self, ctx: &Context<'_>, refund_id: String) -> async_graphql::Result<Refund> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::RefundRead).await?;
+
+        get_json_response(
+            refunds::refund_response_wrapper(
+                &context.state,
+                merchant_account,
+                refund_id,
+                refunds::refund_retrieve_core,
+            )
+            .await,
+        )
+        .map(Refund::from)
+    }
+
+    /// Lists the refunds of the merchant, or of one of its payments.
+    async fn refunds(
+        &self,
+        ctx: &Context<'_>,
+        payment_id: Option<String>,
+        limit: Option<i64>,
+    ) -> async_graphql::Result<Vec<Refund>> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::RefundRead).await?;
+        let request = refund_types::RefundListRequest {
+            payment_id,
+            reason_code: None,
+            limit,
+            created: None,
+            created_lt: None,
+            created_gt: None,
+            created_lte: None,
+            created_gte: None,
+        };
+
+        let refunds = get_json_response(
+            refunds::refund_list(&*context.state.store, merchant_account, request).await,
+        )?;
+        Ok(refunds.data.into_iter().map(Refund::from).collect())
+    }
+
+    /// Retrieves a customer.
+    async fn customer(
+        &self,
+        ctx: &Context<'_>,
+        customer_id: String,
+    ) -> async_graphql::Result<Customer> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::CustomerRead).await?;
+
+        get_json_response(
+            customers::retrieve_customer(
+                &*context.state.store,
+                merchant_account,
+                customer_types::CustomerId { customer_id },
+            )
+            .await,
+        )
+        .map(Customer::from)
+    }
+
+    /// Retrieves a dispute.
+    async fn dispute(
+        &self,
+        ctx: &Context<'_>,
+        dispute_id: String,
+    ) -> async_graphql::Result<Dispute> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::DisputeRead).await?;
+
+        get_json_response(
+            disputes::retrieve_dispute(&context.state, merchant_account, dispute_id).await,
+        )
+        .map(Dispute::from)
+    }
+
+    /// Lists the disputes of the merchant, optionally only those raised through a connector.
+    async fn disputes(
+        &self,
+        ctx: &Context<'_>,
+        connector: Option<String>,
+        limit: Option<i64>,
+    ) -> async_graphql::Result<Vec<Dispute>> {
+        let context = RequestContext::get(ctx)?;
+        let merchant_account = context.authenticate(Permission::DisputeRead).await?;
+        let constraints = dispute_types::DisputeListConstraints {
+            limit,
+            dispute_status: None,
+            dispute_stage: None,
+            reason: None,
+            connector,
+            received_time: None,
+            received_time_lt: None,
+            received_time_gt: None,
+            received_time_lte: None,
+            received_time_gte: None,
+        };
+
+        let disputes = get_json_response(
+            disputes::retrieve_disputes_list(&context.state, merchant_account, constraints).await,
+        )?;
+        Ok(disputes.into_iter().map(Dispute::from).collect())
+    }
+}
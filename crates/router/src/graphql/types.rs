@@ -0,0 +1,234 @@
+use api_models::{customers, disputes, payments, refunds};
+use async_graphql::{InputObject, Json, SimpleObject};
+use masking::{PeekInterface, Secret};
+use time::PrimitiveDateTime;
+
+#[derive(Debug, SimpleObject)]
+pub struct Payment {
+    pub payment_id: Option<String>,
+    pub merchant_id: Option<String>,
+    pub status: String,
+    pub amount: i64,
+    pub amount_capturable: Option<i64>,
+    pub amount_received: Option<i64>,
+    pub currency: String,
+    pub connector: Option<String>,
+    pub client_secret: Option<String>,
+    pub customer_id: Option<String>,
+    pub description: Option<String>,
+    pub capture_method: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub return_url: Option<String>,
+    pub next_action: Option<Json<payments::NextAction>>,
+    pub cancellation_reason: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub metadata: Option<Json<serde_json::Value>>,
+    pub refunds: Vec<Refund>,
+    pub created: Option<PrimitiveDateTime>,
+}
+
+impl From<payments::PaymentsResponse> for Payment {
+    fn from(payment: payments::PaymentsResponse) -> Self {
+        Self {
+            payment_id: payment.payment_id,
+            merchant_id: payment.merchant_id,
+            status: payment.status.to_string(),
+            amount: payment.amount,
+            amount_capturable: payment.amount_capturable,
+            amount_received: payment.amount_received,
+            currency: payment.currency,
+            connector: payment.connector,
+            client_secret: payment
+                .client_secret
+                .map(|client_secret| client_secret.peek().clone()),
+            customer_id: payment.customer_id,
+            description: payment.description,
+            capture_method: payment
+                .capture_method
+                .map(|capture_method| capture_method.to_string()),
+            payment_method: payment
+                .payment_method
+                .map(|payment_method| payment_method.to_string()),
+            payment_method_type: payment
+                .payment_method_type
+                .map(|payment_method_type| payment_method_type.to_string()),
+            return_url: payment.return_url,
+            next_action: payment.next_action.map(Json),
+            cancellation_reason: payment.cancellation_reason,
+            error_code: payment.error_code,
+            error_message: payment.error_message,
+            metadata: payment.metadata.map(Json),
+            refunds: payment
+                .refunds
+                .unwrap_or_default()
+                .into_iter()
+                .map(Refund::from)
+                .collect(),
+            created: payment.created,
+        }
+    }
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct Refund {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub status: String,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub metadata: Option<Json<serde_json::Value>>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+impl From<refunds::RefundResponse> for Refund {
+    fn from(refund: refunds::RefundResponse) -> Self {
+        Self {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            amount: refund.amount,
+            currency: refund.currency,
+            reason: refund.reason,
+            status: refund.status.to_string(),
+            error_code: refund.error_code,
+            error_message: refund.error_message,
+            metadata: refund.metadata.map(Json),
+            created_at: refund.created_at,
+            updated_at: refund.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct Customer {
+    pub customer_id: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub phone_country_code: Option<String>,
+    pub description: Option<String>,
+    pub default_payment_method_id: Option<String>,
+    pub metadata: Option<Json<serde_json::Value>>,
+    pub created_at: PrimitiveDateTime,
+}
+
+impl From<customers::CustomerResponse> for Customer {
+    fn from(customer: customers::CustomerResponse) -> Self {
+        Self {
+            customer_id: customer.customer_id,
+            name: customer.name,
+            email: customer.email.map(|email| email.peek().clone()),
+            phone: customer.phone.map(|phone| phone.peek().clone()),
+            phone_country_code: customer.phone_country_code,
+            description: customer.description,
+            default_payment_method_id: customer.default_payment_method_id,
+            metadata: customer.metadata.map(Json),
+            created_at: customer.created_at,
+        }
+    }
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct Dispute {
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: String,
+    pub dispute_status: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub received_at: PrimitiveDateTime,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+impl From<disputes::DisputeResponse> for Dispute {
+    fn from(dispute: disputes::DisputeResponse) -> Self {
+        Self {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_stage: dispute.dispute_stage.to_string(),
+            dispute_status: dispute.dispute_status.to_string(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            received_at: dispute.received_at,
+            created_at: dispute.created_at,
+            updated_at: dispute.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, InputObject)]
+pub struct RefundInput {
+    pub payment_id: String,
+    /// Generated when not provided.
+    pub refund_id: Option<String>,
+    /// Defaults to the amount of the payment which hasn't been refunded yet.
+    pub amount: Option<i64>,
+    pub reason: Option<String>,
+    pub metadata: Option<Json<serde_json::Value>>,
+}
+
+impl From<RefundInput> for refunds::RefundRequest {
+    fn from(input: RefundInput) -> Self {
+        Self {
+            refund_id: input.refund_id,
+            payment_id: input.payment_id,
+            merchant_id: None,
+            amount: input.amount,
+            reason: input.reason,
+            reason_code: None,
+            refund_type: None,
+            metadata: input.metadata.map(|metadata| metadata.0),
+        }
+    }
+}
+
+#[derive(Debug, InputObject)]
+pub struct CustomerInput {
+    /// Generated when not provided.
+    pub customer_id: Option<String>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub phone_country_code: Option<String>,
+    pub description: Option<String>,
+    pub address: Option<Json<serde_json::Value>>,
+    pub metadata: Option<Json<serde_json::Value>>,
+}
+
+impl From<CustomerInput> for customers::CustomerRequest {
+    fn from(input: CustomerInput) -> Self {
+        Self {
+            customer_id: input
+                .customer_id
+                .unwrap_or_else(customers::generate_customer_id),
+            name: input.name,
+            email: input.email.map(Secret::new),
+            phone: input.phone.map(Secret::new),
+            phone_country_code: input.phone_country_code,
+            description: input.description,
+            address: input.address.map(|address| Secret::new(address.0)),
+            metadata: input.metadata.map(|metadata| metadata.0),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! gRPC API of the payment and refund operations, for internal services calling the application at
+//! a high rate. It is served on its own port, and calls the same core operations as the REST API.
+
+mod transformers;
+
+#[allow(
+    clippy::derive_partial_eq_without_eq,
+    clippy::use_self,
+    rust_2018_idioms,
+    unused_qualifications
+)]
+pub mod proto {
+    tonic::include_proto!("hyperswitch.payments.v1");
+}
+
+use std::{future::Future, net::ToSocketAddrs};
+
+use actix_web::{http::header::HeaderMap, ResponseError};
+use http::StatusCode;
+use tonic::{metadata::MetadataValue, Code, Request, Response, Status};
+
+use self::proto::payments_server::{Payments, PaymentsServer};
+use crate::{
+    core::{
+        audit_logs,
+        errors::{self, ApplicationError, ApplicationResult, RouterResponse},
+        payments, refunds,
+    },
+    logger,
+    routes::{payments::authorize_verify_select, AppState},
+    services::{
+        api,
+        authentication::{self as auth, AuthenticateAndFetch},
+        authorization::Permission,
+        rate_limiter, ApplicationResponse,
+    },
+    types::{
+        api::{self as api_types, payments as payment_types},
+        storage,
+    },
+};
+
+/// Starts serving the gRPC API in the background, on the address given by the configuration.
+pub fn start_grpc_server(state: AppState) -> ApplicationResult<()> {
+    let server = &state.conf.grpc_server;
+    let address = (server.host.as_str(), server.port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| {
+            ApplicationError::InvalidConfigurationValueError(
+                "gRPC server host must resolve to an address".into(),
+            )
+        })?;
+
+    logger::info!(%address, "Starting the gRPC server");
+    tokio::spawn(async move {
+        if let Err(error) = tonic::transport::Server::builder()
+            .add_service(PaymentsServer::new(PaymentsService { state }))
+            .serve(address)
+            .await
+        {
+            logger::error!(?error, "The gRPC server stopped");
+        }
+    });
+
+    Ok(())
+}
+
+pub struct PaymentsService {
+    state: AppState,
+}
+
+impl PaymentsService {
+    /// Authenticates the merchant with the API key in the metadata of the request and checks the
+    /// rate limits, as is done for the REST API, before calling the core operation.
+    async fn serve<'a, Req, Res, T, F, Fut>(
+        &'a self,
+        method: &str,
+        request: Request<Req>,
+        permission: Permission,
+        func: F,
+    ) -> Result<Response<Res>, Status>
+    where
+        F: FnOnce(&'a AppState, storage::MerchantAccount, Req) -> Fut,
+        Fut: Future<Output = RouterResponse<T>>,
+        Res: From<T>,
+    {
+        let path = format!("/hyperswitch.payments.v1.Payments/{method}");
+        let audit_context = audit_logs::AuditContext::new("POST", &path);
+
+        audit_logs::with_audit_context(audit_context, async {
+            let headers = HeaderMap::from(request.metadata().clone().into_headers());
+            let rate_limit = &self.state.conf.rate_limit;
+
+            if let Ok(api_key) = auth::get_api_key(&headers) {
+                rate_limiter::check_api_key_rate_limit(&*self.state.store, rate_limit, api_key)
+                    .await
+                    .map_err(to_grpc_status)?;
+            }
+            let merchant_account = auth::ApiKeyAuth(permission)
+                .authenticate_and_fetch(&headers, &self.state)
+                .await
+                .map_err(to_grpc_status)?;
+            rate_limiter::check_merchant_rate_limit(
+                &*self.state.store,
+                rate_limit,
+                &merchant_account.merchant_id,
+                &path,
+            )
+            .await
+            .map_err(to_grpc_status)?;
+
+            match func(&self.state, merchant_account, request.into_inner())
+                .await
+                .map_err(to_grpc_status)?
+            {
+                ApplicationResponse::Json(response) => Ok(Response::new(Res::from(response))),
+                _ => Err(Status::internal(
+                    "The response of the operation can't be served over gRPC",
+                )),
+            }
+        })
+        .await
+    }
+}
+
+#[tonic::async_trait]
+impl Payments for PaymentsService {
+    async fn create_payment(
+        &self,
+        request: Request<proto::PaymentCreateRequest>,
+    ) -> Result<Response<proto::PaymentResponse>, Status> {
+        self.serve(
+            "CreatePayment",
+            request,
+            Permission::PaymentWrite,
+            |state, merchant_account, request| async move {
+                authorize_verify_select(
+                    payments::PaymentCreate,
+                    state,
+                    merchant_account,
+                    payment_types::PaymentsRequest::try_from(request)?,
+                    api::AuthFlow::Merchant,
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    async fn confirm_payment(
+        &self,
+        request: Request<proto::PaymentConfirmRequest>,
+    ) -> Result<Response<proto::PaymentResponse>, Status> {
+        self.serve(
+            "ConfirmPayment",
+            request,
+            Permission::PaymentWrite,
+            |state, merchant_account, request| async move {
+                authorize_verify_select(
+                    payments::PaymentConfirm,
+                    state,
+                    merchant_account,
+                    payment_types::PaymentsRequest::try_from(request)?,
+                    api::AuthFlow::Merchant,
+                )
+                .await
+            },
+        )
+        .await
+    }
+
+    async fn sync_payment(
+        &self,
+        request: Request<proto::PaymentSyncRequest>,
+    ) -> Result<Response<proto::PaymentResponse>, Status> {
+        self.serve(
+            "SyncPayment",
+            request,
+            Permission::PaymentRead,
+            |state, merchant_account, request| {
+                payments::payments_core::<api_types::PSync, payment_types::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account,
+                    payments::PaymentStatus,
+                    request.into(),
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                )
+            },
+        )
+        .await
+    }
+
+    async fn create_refund(
+        &self,
+        request: Request<proto::RefundCreateRequest>,
+    ) -> Result<Response<proto::RefundResponse>, Status> {
+        self.serve(
+            "CreateRefund",
+            request,
+            Permission::RefundWrite,
+            |state, merchant_account, request| {
+                refunds::refund_create_core(state, merchant_account, request.into())
+            },
+        )
+        .await
+    }
+
+    async fn sync_refund(
+        &self,
+        request: Request<proto::RefundSyncRequest>,
+    ) -> Result<Response<proto::RefundResponse>, Status> {
+        self.serve(
+            "SyncRefund",
+            request,
+            Permission::RefundRead,
+            |state, merchant_account, request| {
+                refunds::refund_response_wrapper(
+                    state,
+                    merchant_account,
+                    request.refund_id,
+                    refunds::refund_retrieve_core,
+                )
+            },
+        )
+        .await
+    }
+}
+
+/// Converts an API error into a gRPC status, with the code closest to the HTTP status code of the
+/// error. The error code of the REST API is sent in the `x-error-code` metadata entry.
+fn to_grpc_status(error: error_stack::Report<errors::ApiErrorResponse>) -> Status {
+    logger::error!(?error);
+    let error = error.current_context();
+
+    let code = match error.status_code() {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::NOT_IMPLEMENTED => Code::Unimplemented,
+        status_code if status_code.is_server_error() => Code::Internal,
+        _ => Code::Unknown,
+    };
+
+    let mut status = Status::new(code, error.error_message());
+    if let Ok(error_code) = MetadataValue::try_from(error.error_code()) {
+        status.metadata_mut().insert("x-error-code", error_code);
+    }
+    status
+}
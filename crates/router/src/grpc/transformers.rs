@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use api_models::refunds as refund_types;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+use super::proto;
+use crate::{
+    core::errors,
+    types::api::{enums as api_enums, payments as payment_types},
+};
+
+impl From<proto::Card> for payment_types::Card {
+    fn from(card: proto::Card) -> Self {
+        Self {
+            card_number: Secret::new(card.card_number),
+            card_exp_month: Secret::new(card.card_exp_month),
+            card_exp_year: Secret::new(card.card_exp_year),
+            card_holder_name: Secret::new(card.card_holder_name),
+            card_cvc: Secret::new(card.card_cvc),
+            ..Default::default()
+        }
+    }
+}
+
+fn get_payment_method_data(
+    card: Option<proto::Card>,
+) -> (
+    Option<api_enums::PaymentMethod>,
+    Option<payment_types::PaymentMethodData>,
+) {
+    match card {
+        Some(card) => (
+            Some(api_enums::PaymentMethod::Card),
+            Some(payment_types::PaymentMethodData::Card(card.into())),
+        ),
+        None => (None, None),
+    }
+}
+
+fn parse_return_url(
+    return_url: Option<String>,
+) -> Result<Option<url::Url>, error_stack::Report<errors::ApiErrorResponse>> {
+    return_url
+        .map(|return_url| url::Url::parse(&return_url))
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "return_url",
+        })
+}
+
+impl TryFrom<proto::PaymentCreateRequest> for payment_types::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(request: proto::PaymentCreateRequest) -> Result<Self, Self::Error> {
+        let currency = api_enums::Currency::from_str(&request.currency)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "currency",
+            })?;
+        let capture_method = request
+            .capture_method
+            .map(|capture_method| api_enums::CaptureMethod::from_str(&capture_method))
+            .transpose()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "capture_method",
+            })?;
+        let (payment_method, payment_method_data) = get_payment_method_data(request.card);
+
+        Ok(Self {
+            payment_id: request
+                .payment_id
+                .map(payment_types::PaymentIdType::PaymentIntentId),
+            amount: Some(request.amount.into()),
+            currency: Some(currency),
+            capture_method,
+            confirm: Some(request.confirm),
+            customer_id: request.customer_id,
+            email: request.email.map(Secret::new),
+            description: request.description,
+            return_url: parse_return_url(request.return_url)?,
+            payment_method,
+            payment_method_data,
+            ..Default::default()
+        })
+    }
+}
+
+impl TryFrom<proto::PaymentConfirmRequest> for payment_types::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(request: proto::PaymentConfirmRequest) -> Result<Self, Self::Error> {
+        let (payment_method, payment_method_data) = get_payment_method_data(request.card);
+
+        Ok(Self {
+            payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+                request.payment_id,
+            )),
+            confirm: Some(true),
+            return_url: parse_return_url(request.return_url)?,
+            payment_method,
+            payment_method_data,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::PaymentSyncRequest> for payment_types::PaymentsRetrieveRequest {
+    fn from(request: proto::PaymentSyncRequest) -> Self {
+        Self {
+            resource_id: payment_types::PaymentIdType::PaymentIntentId(request.payment_id),
+            merchant_id: None,
+            force_sync: request.force_sync,
+            param: None,
+            connector: None,
+        }
+    }
+}
+
+impl From<payment_types::PaymentsResponse> for proto::PaymentResponse {
+    fn from(payment: payment_types::PaymentsResponse) -> Self {
+        Self {
+            payment_id: payment.payment_id.unwrap_or_default(),
+            merchant_id: payment.merchant_id.unwrap_or_default(),
+            status: payment.status.to_string(),
+            amount: payment.amount,
+            amount_capturable: payment.amount_capturable,
+            amount_received: payment.amount_received,
+            currency: payment.currency,
+            connector: payment.connector,
+            client_secret: payment
+                .client_secret
+                .map(|client_secret| client_secret.peek().clone()),
+            customer_id: payment.customer_id,
+            capture_method: payment
+                .capture_method
+                .map(|capture_method| capture_method.to_string()),
+            redirect_to_url: payment
+                .next_action
+                .and_then(|next_action| next_action.redirect_to_url),
+            error_code: payment.error_code,
+            error_message: payment.error_message,
+        }
+    }
+}
+
+impl From<proto::RefundCreateRequest> for refund_types::RefundRequest {
+    fn from(request: proto::RefundCreateRequest) -> Self {
+        Self {
+            refund_id: request.refund_id,
+            payment_id: request.payment_id,
+            merchant_id: None,
+            amount: request.amount,
+            reason: request.reason,
+            reason_code: None,
+            refund_type: None,
+            metadata: None,
+        }
+    }
+}
+
+impl From<refund_types::RefundResponse> for proto::RefundResponse {
+    fn from(refund: refund_types::RefundResponse) -> Self {
+        Self {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            amount: refund.amount,
+            currency: refund.currency,
+            status: refund.status.to_string(),
+            reason: refund.reason,
+            error_code: refund.error_code,
+            error_message: refund.error_message,
+        }
+    }
+}
@@ -11,6 +11,10 @@ pub mod core;
 pub mod cors;
 pub mod db;
 pub mod env;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub(crate) mod macros;
 pub mod routes;
 pub mod scheduler;
@@ -47,13 +51,39 @@ pub mod headers {
     pub const X_ROUTER: &str = "X-router";
     pub const AUTHORIZATION: &str = "Authorization";
     pub const ACCEPT: &str = "Accept";
-    pub const X_API_VERSION: &str = "X-ApiVersion";
+    pub const X_API_VERSION: &str = "X-Api-Version";
+    pub const USER_AGENT: &str = "User-Agent";
     pub const DATE: &str = "Date";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
     pub const X_LOGIN: &str = "X-Login";
     pub const X_TRANS_KEY: &str = "X-Trans-Key";
     pub const X_VERSION: &str = "X-Version";
     pub const X_DATE: &str = "X-Date";
+    pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature";
+    pub const X_IDEMPOTENCY_KEY: &str = "X-Idempotency-Key";
+    pub const X_RAZORPAY_SIGNATURE: &str = "X-Razorpay-Signature";
+    pub const OPENPAYU_SIGNATURE: &str = "OpenPayu-Signature";
+    pub const SIGNATURE: &str = "Signature";
+    pub const BRAINTREE_VERSION: &str = "Braintree-Version";
+    pub const X_AFFIRM_SIGNATURE: &str = "X-Affirm-Signature";
+    pub const X_GCS_SIGNATURE: &str = "X-GCS-Signature";
+    pub const X_GCS_WEBHOOKS_ENDPOINT_VERIFICATION: &str = "X-GCS-Webhooks-Endpoint-Verification";
+    pub const X_ANET_SIGNATURE: &str = "X-ANET-Signature";
+    pub const X_AVANGATE_AUTHENTICATION: &str = "X-Avangate-Authentication";
+    pub const CLIENT_ID: &str = "Client-Id";
+    pub const REQUEST_TIME: &str = "Request-Time";
+    pub const ACCEPT_VERSION: &str = "Accept-Version";
+    pub const PREFER: &str = "Prefer";
+    pub const PAYPAL_REQUEST_ID: &str = "PayPal-Request-Id";
+    pub const PAYPAL_AUTH_ALGO: &str = "PayPal-Auth-Algo";
+    pub const PAYPAL_TRANSMISSION_ID: &str = "PayPal-Transmission-Id";
+    pub const PAYPAL_TRANSMISSION_TIME: &str = "PayPal-Transmission-Time";
+    pub const PAYPAL_TRANSMISSION_SIG: &str = "PayPal-Transmission-Sig";
+    pub const X_CRYPTOPAY_SIGNATURE: &str = "X-Cryptopay-Signature";
+    pub const SIGNIFYD_SEC_HMAC_SHA256: &str = "SIGNIFYD-SEC-HMAC-SHA256";
+    pub const X_RISKIFIED_SHOP_DOMAIN: &str = "X-RISKIFIED-SHOP-DOMAIN";
+    pub const X_RISKIFIED_HMAC_SHA256: &str = "X-RISKIFIED-HMAC-SHA256";
+    pub const GOCARDLESS_VERSION: &str = "GoCardless-Version";
 }
 
 pub mod pii {
@@ -76,15 +106,19 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit);
+    let mut server_app = get_application_builder(&state, request_body_limit);
 
     #[cfg(feature = "openapi")]
     {
-        use utoipa::OpenApi;
-        server_app = server_app.service(
-            utoipa_swagger_ui::SwaggerUi::new("/docs/{_:.*}")
-                .url("/docs/openapi.json", openapi::ApiDoc::openapi()),
-        );
+        server_app = server_app
+            .route(
+                "/docs/openapi.json",
+                actix_web::web::get().to(openapi::openapi_spec),
+            )
+            .service(
+                utoipa_swagger_ui::SwaggerUi::new("/docs/{_:.*}")
+                    .config(utoipa_swagger_ui::Config::from("/docs/openapi.json")),
+            );
     }
 
     #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -104,6 +138,7 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::Forex::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()));
     }
 
@@ -111,13 +146,33 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::Organization::server(state.clone()))
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
+            .service(routes::Events::server(state.clone()))
+            .service(routes::AuditLogs::server(state.clone()))
+            .service(routes::Analytics::server(state.clone()))
+            .service(routes::Recon::server(state.clone()))
+            .service(routes::Blocklist::server(state.clone()))
+            .service(routes::Routing::server(state.clone()))
+            .service(routes::UserRoles::server(state.clone()))
+            .service(routes::User::server(state.clone()));
+    }
+
+    #[cfg(feature = "dummy_connector")]
+    {
+        server_app = server_app.service(routes::DummyConnector::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
     {
         server_app = server_app.service(routes::StripeApis::server(state.clone()));
     }
+
+    #[cfg(feature = "graphql")]
+    {
+        server_app = server_app.service(routes::Graphql::server(state.clone()));
+    }
     server_app = server_app.service(routes::Health::server(state));
     server_app
 }
@@ -134,6 +189,8 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server
     let state = routes::AppState::new(conf).await;
     // Cloning to close connections before shutdown
     let app_state = state.clone();
+    #[cfg(feature = "grpc")]
+    grpc::start_grpc_server(state.clone())?;
     let request_body_limit = server.request_body_limit;
     let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
         .bind((server.host.as_str(), server.port))?
@@ -145,6 +202,7 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server
 }
 
 pub fn get_application_builder(
+    state: &AppState,
     request_body_limit: usize,
 ) -> actix_web::App<
     impl ServiceFactory<
@@ -163,6 +221,7 @@ pub fn get_application_builder(
 
     actix_web::App::new()
         .app_data(json_cfg)
+        .wrap(middleware::RateLimiter::new(state))
         .wrap(middleware::RequestId)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
         .wrap(ErrorHandlers::new().handler(
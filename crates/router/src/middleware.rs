@@ -59,3 +59,105 @@ where
         })
     }
 }
+
+/// Middleware to limit the rate of the requests made with each API key, before the request is
+/// authenticated. The limits on the requests made on behalf of each merchant are checked once
+/// the request is authenticated.
+pub struct RateLimiter {
+    store: Box<dyn crate::db::StorageInterface>,
+    settings: std::rc::Rc<crate::configs::settings::RateLimitSettings>,
+}
+
+impl RateLimiter {
+    pub fn new(state: &crate::routes::AppState) -> Self {
+        Self {
+            store: state.store.clone(),
+            settings: std::rc::Rc::new(state.conf.rate_limit.clone()),
+        }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimiter
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RateLimiterMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimiterMiddleware {
+            service: std::rc::Rc::new(service),
+            store: self.store.clone(),
+            settings: self.settings.clone(),
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: std::rc::Rc<S>,
+    store: Box<dyn crate::db::StorageInterface>,
+    settings: std::rc::Rc<crate::configs::settings::RateLimitSettings>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use common_utils::errors::ReportSwitchExt;
+
+        let service = self.service.clone();
+        let store = self.store.clone();
+        let settings = self.settings.clone();
+
+        Box::pin(async move {
+            let api_key = req
+                .headers()
+                .get("api-key")
+                .and_then(|api_key| api_key.to_str().ok())
+                .map(ToOwned::to_owned);
+
+            if let Some(api_key) = api_key {
+                let rate_limit_out: common_utils::errors::CustomResult<
+                    (),
+                    api_models::errors::types::ApiErrorResponse,
+                > = crate::services::rate_limiter::check_api_key_rate_limit(
+                    &*store, &settings, &api_key,
+                )
+                .await
+                .switch();
+
+                if let Err(error) = rate_limit_out {
+                    let response = crate::services::api::log_and_return_error_response(error);
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+            }
+
+            service
+                .call(req)
+                .await
+                .map(actix_web::dev::ServiceResponse::map_into_left_body)
+        })
+    }
+}
@@ -44,6 +44,17 @@ the request Authorization header.
 | Production    | Unique identifier for your account. Used to authenticate API requests from your app's client  |
 
 Never share your secret api keys. Keep them guarded and secure.
+
+## Versioning
+
+Payments are returned in the schema of the version of the API given in the `X-Api-Version`
+request header, or of the latest version when the header is absent. The version a response is in
+is returned in the same header.
+
+| Version       |  Changes                                                                                      |
+|---------------|-----------------------------------------------------------------------------------------------|
+| 2023-05-15    | Adds captures, unified error codes, split payments, forex quotes, profiles, fraud checks, duplicate detection, card verification results and connector metadata to payments. Adds the `verify_with_microdeposits` next action |
+| 2023-01-30    | The original schema                                                                           |
 "#,
     ),
     servers(
@@ -51,9 +62,22 @@ Never share your secret api keys. Keep them guarded and secure.
     ),
     tags(
         (name = "Merchant Account", description = "Create and manage merchant accounts"),
+        (name = "Organization", description = "Create and manage organizations of merchant accounts"),
+        (name = "Business Profile", description = "Create and manage business profiles of merchant accounts"),
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to cards and bank accounts"),
+        (name = "Disputes", description = "Manage disputes raised against payments and submit evidence"),
+        (name = "Audit Logs", description = "Review the changes made to payments, refunds and configuration by API calls"),
+        (name = "Analytics", description = "Aggregate the success rate, volume and latency of payments for dashboards"),
+        (name = "Reconciliation", description = "Reconcile the settlement reports of connectors with payments and refunds"),
+        (name = "Blocklist", description = "Block payments on cards, card BINs, customer emails and IP addresses"),
+        (name = "Events", description = "Inspect and replay the events sent to the webhook endpoint of merchants"),
+        (name = "Routing", description = "Create and activate rules for routing payments to connectors"),
+        (name = "User Roles", description = "Assign roles to the dashboard users of merchant accounts"),
+        (name = "User", description = "Sign up and log in to the dashboard"),
+        (name = "Forex", description = "Quote exchange rates between currencies"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
@@ -64,6 +88,43 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_retrieve,
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
+        crate::routes::refunds::refunds_bulk_create,
+        crate::routes::refunds::refunds_bulk_create_csv,
+        crate::routes::refunds::refunds_bulk_retrieve,
+        crate::routes::refunds::refunds_credit_create,
+        crate::routes::refunds::refunds_credit_retrieve,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_confirm,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::retrieve_disputes_list,
+        crate::routes::disputes::submit_dispute_evidence,
+        crate::routes::audit_logs::audit_logs_list,
+        crate::routes::analytics::payment_analytics,
+        crate::routes::recon::recon_report_fetch,
+        crate::routes::recon::recon_report_upload,
+        crate::routes::recon::recon_report_list,
+        crate::routes::recon::recon_report_retrieve,
+        crate::routes::recon::recon_record_list,
+        crate::routes::blocklist::add_entry_to_blocklist,
+        crate::routes::blocklist::remove_entry_from_blocklist,
+        crate::routes::blocklist::list_blocklist_entries,
+        crate::routes::events::events_list,
+        crate::routes::events::event_retrieve,
+        crate::routes::events::event_replay,
+        crate::routes::routing::routing_config_create,
+        crate::routes::routing::routing_config_list,
+        crate::routes::routing::routing_config_activate,
+        crate::routes::user_roles::user_role_create,
+        crate::routes::user_roles::user_role_retrieve,
+        crate::routes::user_roles::user_role_update,
+        crate::routes::user_roles::user_role_delete,
+        crate::routes::user_roles::user_role_list,
+        crate::routes::user::user_signup,
+        crate::routes::user::user_login,
+        crate::routes::user::user_refresh_token,
+        crate::routes::forex::retrieve_forex_quote,
         crate::routes::admin::merchant_account_create,
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
@@ -73,8 +134,18 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::admin::payment_connector_list,
         crate::routes::admin::payment_connector_update,
         crate::routes::admin::payment_connector_delete,
+        crate::routes::admin::payment_connector_rotate_key,
+        crate::routes::admin::organization_create,
+        crate::routes::admin::organization_retrieve,
+        crate::routes::admin::business_profile_create,
+        crate::routes::admin::business_profile_retrieve,
+        crate::routes::admin::business_profile_update,
+        crate::routes::admin::business_profile_delete,
+        crate::routes::admin::business_profiles_list,
+        crate::routes::admin::merchant_account_token_migration,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::mandates::delete_mandate,
         crate::routes::payments::payments_create,
        // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
@@ -85,6 +156,8 @@ Never share your secret api keys. Keep them guarded and secure.
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
         crate::routes::payments::payments_list,
+        crate::routes::payments::payments_search,
+        crate::routes::payments::payment_attempts_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
         crate::routes::payment_methods::list_customer_payment_method_api,
@@ -95,6 +168,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_set_default_payment_method,
         // crate::routes::api_keys::api_key_create,
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
@@ -107,9 +181,21 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::refunds::RefundResponse,
         crate::types::api::refunds::RefundStatus,
         crate::types::api::refunds::RefundUpdateRequest,
+        crate::types::api::refunds::RefundBulkRequest,
+        crate::types::api::refunds::RefundBulkResponse,
+        crate::types::api::refunds::RefundBulkStatus,
+        crate::types::api::refunds::RefundBulkItem,
+        crate::types::api::refunds::CreditRequest,
+        crate::types::api::refunds::CreditResponse,
         crate::types::api::admin::CreateMerchantAccount,
         crate::types::api::admin::DeleteMerchantAccountResponse,
         crate::types::api::admin::DeleteMcaResponse,
+        crate::types::api::admin::MerchantConnectorKeyRotationResponse,
+        crate::types::api::admin::OrganizationCreateRequest,
+        crate::types::api::admin::OrganizationResponse,
+        crate::types::api::admin::BusinessProfileCreate,
+        crate::types::api::admin::BusinessProfileResponse,
+        crate::types::api::admin::DeleteBusinessProfileResponse,
         crate::types::api::customers::CustomerRequest,
         crate::types::api::customers::CustomerDeleteResponse,
         crate::types::api::payment_methods::CreatePaymentMethod,
@@ -122,7 +208,9 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::payment_methods::UpdatePaymentMethod,
         crate::types::api::payment_methods::CardDetailFromLocker,
         crate::types::api::payment_methods::CardDetail,
+        api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::customers::CustomerResponse,
+        api_models::customers::CustomerDefaultPaymentMethodResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::RoutingAlgorithm,
@@ -131,7 +219,33 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
+        api_models::enums::AttemptStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureStatus,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::enums::RefundReason,
+        api_models::enums::SortOrder,
+        api_models::enums::RoleName,
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+        api_models::enums::UnifiedCode,
+        api_models::enums::EventType,
+        api_models::enums::AuditActorType,
+        api_models::enums::AuditResourceType,
+        api_models::enums::ReconReportSource,
+        api_models::enums::ReconReportStatus,
+        api_models::enums::FrmConnectors,
+        api_models::enums::FraudCheckType,
+        api_models::enums::FraudCheckStatus,
+        api_models::enums::ReconRecordType,
+        api_models::enums::ReconStatus,
+        api_models::enums::BlocklistDataKind,
+        api_models::enums::DuplicatePaymentAction,
+        api_models::enums::AvsResult,
+        api_models::enums::CvvResult,
+        api_models::enums::EventObjectType,
+        api_models::enums::WebhookDeliveryStatus,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
@@ -142,19 +256,48 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
+        api_models::enums::CardType,
+        api_models::enums::BankAccountType,
+        api_models::enums::PayerDocumentType,
+        api_models::enums::AuthorizationExpiryAction,
+        api_models::enums::ScaExemptionType,
         api_models::admin::PaymentConnectorCreate,
+        api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::PaymentMethodsEnabled,
+        api_models::admin::AuthorizationExpiryPolicy,
+        api_models::admin::ScaExemptionPolicy,
+        api_models::admin::CreditPolicy,
+        api_models::admin::FraudCheckPolicy,
+        api_models::admin::VelocityPolicy,
+        api_models::admin::DuplicatePaymentPolicy,
+        api_models::admin::CardVerificationPolicy,
         api_models::payments::AddressDetails,
         api_models::payments::Address,
+        api_models::payments::PayerDocument,
+        api_models::payments::InstallmentsData,
+        api_models::payments::SplitPaymentsRequest,
+        api_models::payments::SplitPaymentsBreakdown,
+        api_models::payments::ConnectorMetadata,
+        api_models::payments::AdyenConnectorMetadata,
+        api_models::payments::DlocalConnectorMetadata,
+        api_models::payments::FraudCheckDetails,
         api_models::payments::BankRedirectData,
         api_models::payments::BankRedirectBilling,
+        api_models::payments::BankTransferData,
+        api_models::payments::VoucherData,
+        api_models::payments::UpiData,
+        api_models::payments::BankDebitData,
+        api_models::payments::BankDebitBilling,
+        api_models::payments::CryptoData,
         api_models::payments::OrderDetails,
         api_models::payments::NextActionType,
+        api_models::payments::QrCodeInformation,
         api_models::payments::Metadata,
         api_models::payments::WalletData,
         api_models::payments::NextAction,
         api_models::payments::PayLaterData,
         api_models::payments::MandateData,
+        api_models::payments::RecurringDetails,
         api_models::payments::PhoneDetails,
         api_models::payments::PaymentMethodData,
         api_models::payments::MandateType,
@@ -170,6 +313,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::CaptureResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::SessionToken,
@@ -179,6 +323,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GpayWalletData,
         api_models::payments::PayPalWalletData,
         api_models::payments::PaypalRedirection,
+        api_models::payments::PaytmRedirection,
+        api_models::payments::AliPayRedirection,
+        api_models::payments::WeChatPayRedirection,
+        api_models::payments::WeChatPayQr,
+        api_models::payments::ClickToPayWalletData,
         api_models::payments::GpayMerchantInfo,
         api_models::payments::GpayAllowedPaymentMethods,
         api_models::payments::GpayAllowedMethodsParameters,
@@ -189,6 +338,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::KlarnaSessionTokenResponse,
         api_models::payments::PaypalSessionTokenResponse,
         api_models::payments::ApplepaySessionTokenResponse,
+        api_models::payments::ClickToPaySessionTokenResponse,
         api_models::payments::GpayTokenizationData,
         api_models::payments::GpayPaymentMethodInfo,
         api_models::payments::ApplePayWalletData,
@@ -196,8 +346,59 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCancelRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
+        api_models::payments::PaymentSearchRequest,
+        api_models::payments::PaymentSearchResponse,
+        api_models::payments::PaymentListSortBy,
+        api_models::payments::PaymentAttemptListResponse,
+        api_models::payments::PaymentAttemptResponse,
+        api_models::payments::ConnectorAttemptResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::CardPayout,
+        api_models::payouts::BankPayout,
+        api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeListConstraints,
+        api_models::disputes::SubmitEvidenceRequest,
+        api_models::audit_logs::AuditLogListConstraints,
+        api_models::audit_logs::AuditLogResponse,
+        api_models::recon::ReconReportFetchRequest,
+        api_models::recon::ReconReportUploadParams,
+        api_models::recon::ReconReportListConstraints,
+        api_models::recon::ReconReportResponse,
+        api_models::recon::ReconRecordListConstraints,
+        api_models::recon::ReconRecordResponse,
+        api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::BlocklistListConstraints,
+        api_models::blocklist::BlocklistResponse,
+        api_models::token_migration::TokenMigrationRequest,
+        api_models::token_migration::TokenExport,
+        api_models::token_migration::StripeCustomerExport,
+        api_models::token_migration::StripeCardExport,
+        api_models::token_migration::AdyenRecurringDetailExport,
+        api_models::token_migration::TokenMigrationResponse,
+        api_models::token_migration::TokenMigrationCardResponse,
+        api_models::analytics::AnalyticsTimeRange,
+        api_models::analytics::AnalyticsGranularity,
+        api_models::analytics::PaymentAnalyticsDimension,
+        api_models::analytics::PaymentAnalyticsFilters,
+        api_models::analytics::PaymentAnalyticsRequest,
+        api_models::analytics::PaymentAnalyticsBucket,
+        api_models::analytics::PaymentAnalyticsResponse,
+        api_models::webhooks::EventListConstraints,
+        api_models::webhooks::EventResponse,
+        api_models::webhooks::EventRetrieveResponse,
+        api_models::webhooks::WebhookDeliveryAttemptResponse,
+        api_models::forex::ForexQuoteResponse,
+        api_models::forex::ForexProvider,
+        api_models::routing::RoutingConfigCreateRequest,
+        api_models::routing::RoutingConfigResponse,
+        api_models::routing::RoutingRule,
+        api_models::routing::RoutingConditions,
+        api_models::routing::AmountBand,
+        api_models::routing::ConnectorVolumeSplit,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -210,9 +411,16 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
         crate::types::api::api_keys::RevokeApiKeyResponse,
-        crate::types::api::api_keys::UpdateApiKeyRequest
+        crate::types::api::api_keys::UpdateApiKeyRequest,
+        crate::types::api::user_roles::CreateUserRoleRequest,
+        crate::types::api::user_roles::UpdateUserRoleRequest,
+        crate::types::api::user_roles::UserRoleResponse,
+        crate::types::api::user_roles::DeleteUserRoleResponse,
+        crate::types::api::user::SignUpRequest,
+        crate::types::api::user::LoginRequest,
+        crate::types::api::user::DashboardEntryResponse
     )),
-    modifiers(&SecurityAddon)
+    modifiers(&SecurityAddon, &EnumValuesAddon)
 )]
 pub struct ApiDoc;
 
@@ -256,7 +464,217 @@ impl utoipa::Modify for SecurityAddon {
                          to a single customer object for a short period of time."
                     ))),
                 ),
+                (
+                    "jwt",
+                    SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                        "Authorization",
+                        "JWTs are issued to dashboard users when they log in, and are sent as \
+                         bearer tokens."
+                    ))),
+                ),
             ]);
         }
     }
 }
+
+/// Lists the values of the enums in the descriptions of their schemas, along with what each value
+/// means, as OpenAPI has no way of documenting the values of an enum on their own.
+struct EnumValuesAddon;
+
+impl utoipa::Modify for EnumValuesAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        use api_models::enums;
+
+        if let Some(components) = openapi.components.as_mut() {
+            describe_enum_values::<enums::IntentStatus>(components, "IntentStatus");
+            describe_enum_values::<enums::AttemptStatus>(components, "AttemptStatus");
+            describe_enum_values::<enums::CaptureMethod>(components, "CaptureMethod");
+            describe_enum_values::<enums::CaptureStatus>(components, "CaptureStatus");
+            describe_enum_values::<enums::AuthenticationType>(components, "AuthenticationType");
+            describe_enum_values::<enums::FutureUsage>(components, "FutureUsage");
+            describe_enum_values::<enums::MandateStatus>(components, "MandateStatus");
+            describe_enum_values::<enums::PayoutStatus>(components, "PayoutStatus");
+            describe_enum_values::<enums::DisputeStage>(components, "DisputeStage");
+            describe_enum_values::<enums::DisputeStatus>(components, "DisputeStatus");
+            describe_enum_values::<enums::UnifiedCode>(components, "UnifiedCode");
+            describe_enum_values::<enums::ConnectorType>(components, "ConnectorType");
+            describe_enum_values::<enums::RoleName>(components, "RoleName");
+            describe_enum_values::<enums::WebhookDeliveryStatus>(
+                components,
+                "WebhookDeliveryStatus",
+            );
+            describe_enum_values::<enums::AuthorizationExpiryAction>(
+                components,
+                "AuthorizationExpiryAction",
+            );
+            describe_enum_values::<enums::ScaExemptionType>(components, "ScaExemptionType");
+            describe_enum_values::<api_models::refunds::RefundStatus>(components, "RefundStatus");
+        }
+    }
+}
+
+fn describe_enum_values<E>(components: &mut utoipa::openapi::Components, schema_name: &str)
+where
+    E: strum::IntoEnumIterator + strum::EnumMessage + std::fmt::Display,
+{
+    use utoipa::openapi::{RefOr, Schema};
+
+    let values = E::iter()
+        .filter_map(|value| {
+            value
+                .get_documentation()
+                .map(|documentation| format!("- `{value}`: {documentation}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if let Some(RefOr::T(Schema::Object(schema))) = components.schemas.get_mut(schema_name) {
+        schema.description = Some(match schema.description.take() {
+            Some(description) => format!("{description}\n\n{values}"),
+            None => values,
+        });
+    }
+}
+
+/// Generates the OpenAPI 3.1 document of the API, which is served at `/docs/openapi.json` and
+/// from which the client SDKs are generated.
+pub fn generate_openapi_spec() -> serde_json::Result<serde_json::Value> {
+    use utoipa::OpenApi;
+
+    // utoipa generates OpenAPI 3.0 documents, which differ from OpenAPI 3.1 in how nullable
+    // schemas are described
+    let mut openapi = serde_json::to_value(ApiDoc::openapi())?;
+    if let Some(openapi) = openapi.as_object_mut() {
+        openapi.insert("openapi".to_string(), "3.1.0".into());
+    }
+    convert_nullable_schemas(&mut openapi);
+
+    Ok(openapi)
+}
+
+pub async fn openapi_spec() -> actix_web::HttpResponse {
+    match generate_openapi_spec() {
+        Ok(openapi) => actix_web::HttpResponse::Ok().json(openapi),
+        Err(error) => {
+            crate::logger::error!(?error, "Failed to generate the OpenAPI specification");
+            actix_web::HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+/// Replaces the `nullable` keyword of OpenAPI 3.0 schemas with the `null` type of JSON Schema, as
+/// the keyword was removed in OpenAPI 3.1.
+fn convert_nullable_schemas(value: &mut serde_json::Value) {
+    use serde_json::{json, Value};
+
+    match value {
+        Value::Object(object) => {
+            if object.remove("nullable") == Some(Value::Bool(true)) {
+                match object.get_mut("type") {
+                    Some(Value::String(schema_type)) => {
+                        let schema_type = json!([schema_type, "null"]);
+                        object.insert("type".to_string(), schema_type);
+                        if let Some(Value::Array(values)) = object.get_mut("enum") {
+                            values.push(Value::Null);
+                        }
+                    }
+                    // References and compositions of schemas can't be given a type
+                    _ => {
+                        let schema = Value::Object(std::mem::take(object));
+                        object.insert("oneOf".to_string(), json!([schema, { "type": "null" }]));
+                    }
+                }
+            }
+            object.values_mut().for_each(convert_nullable_schemas);
+        }
+        Value::Array(values) => values.iter_mut().for_each(convert_nullable_schemas),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn test_nullable_schemas_conversion() {
+        let mut schema = json!({
+            "properties": {
+                "description": { "type": "string", "nullable": true },
+                "status": { "type": "string", "enum": ["active"], "nullable": true },
+                "billing": {
+                    "allOf": [{ "$ref": "#/components/schemas/Address" }],
+                    "nullable": true
+                },
+                "amount": { "type": "integer" }
+            }
+        });
+        convert_nullable_schemas(&mut schema);
+
+        assert_eq!(
+            schema,
+            json!({
+                "properties": {
+                    "description": { "type": ["string", "null"] },
+                    "status": { "type": ["string", "null"], "enum": ["active", null] },
+                    "billing": {
+                        "oneOf": [
+                            { "allOf": [{ "$ref": "#/components/schemas/Address" }] },
+                            { "type": "null" }
+                        ]
+                    },
+                    "amount": { "type": "integer" }
+                }
+            })
+        );
+    }
+
+    fn collect_references<'a>(value: &'a Value, references: &mut HashSet<&'a str>) {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    references.insert(reference);
+                }
+                object
+                    .values()
+                    .for_each(|value| collect_references(value, references));
+            }
+            Value::Array(values) => values
+                .iter()
+                .for_each(|value| collect_references(value, references)),
+            _ => (),
+        }
+    }
+
+    #[test]
+    fn test_openapi_spec_references_resolve() {
+        let openapi = generate_openapi_spec().unwrap();
+        assert_eq!(openapi["openapi"], "3.1.0");
+
+        let mut references = HashSet::new();
+        collect_references(&openapi, &mut references);
+        let schemas = openapi["components"]["schemas"].as_object().unwrap();
+        let unresolved: Vec<_> = references
+            .into_iter()
+            .filter(|reference| {
+                reference
+                    .strip_prefix("#/components/schemas/")
+                    .map_or(true, |schema| !schemas.contains_key(schema))
+            })
+            .collect();
+        assert!(
+            unresolved.is_empty(),
+            "Unresolved references: {unresolved:?}"
+        );
+
+        assert!(schemas["IntentStatus"]["description"]
+            .as_str()
+            .unwrap()
+            .contains("- `requires_capture`: "));
+    }
+}
@@ -1,21 +1,38 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics;
 pub mod api_keys;
 pub mod app;
+pub mod audit_logs;
+pub mod blocklist;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod ephemeral_key;
+pub mod events;
+pub mod forex;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod user;
+pub mod user_roles;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    Analytics, ApiKeys, AppState, AuditLogs, Blocklist, Configs, Customers, Disputes,
+    DummyConnector, EphemeralKey, Events, Forex, Health, Mandates, MerchantAccount,
+    MerchantConnectorAccount, Organization, PaymentMethods, Payments, Payouts, Recon, Refunds,
+    Routing, User, UserRoles, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
+#[cfg(feature = "graphql")]
+pub use super::graphql::Graphql;
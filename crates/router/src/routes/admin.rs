@@ -3,9 +3,9 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::admin::*,
+    core::{admin::*, connector_payloads, token_migration},
     services::{api, authentication as auth},
-    types::api::admin,
+    types::api::{self as api_types, admin},
 };
 
 /// Merchant Account - Create
@@ -110,7 +110,7 @@ pub async fn update_merchant_account(
 
 /// Merchant Account - Delete
 ///
-/// To delete a merchant account
+/// To close a merchant account. The account can no longer be used once closed, and the personal data of its customers is redacted shortly after: mandates are revoked, saved cards are removed from the locker and customer details are redacted. The records of the account are kept for auditing.
 #[utoipa::path(
     delete,
     path = "/accounts/{account_id}",
@@ -255,6 +255,41 @@ pub async fn payment_connector_list(
     .await
 }
 
+/// Payment Connector - Rotate Key
+///
+/// Re-encrypt the credentials of all Payment Connectors of the merchant with the current key of the secrets manager
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/rotate_key",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Payment Connector credentials re-encrypted", body = MerchantConnectorKeyRotationResponse),
+        (status = 404, description = "Merchant Account does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Rotate the key of Merchant Connectors",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentConnectorsKeyRotate))]
+pub async fn payment_connector_rotate_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_payment_connectors_key(&*state.store, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 /// Payment Connector - Update
 ///
 /// To update an existing Payment Connector. Helpful in enabling / disabling different payment methods and other settings for the connector etc.
@@ -381,3 +416,363 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+
+/// Merchant Account - Toggle Connector Payloads Capture
+///
+/// Toggle the capture of the masked payloads exchanged with the connectors for the Merchant
+/// Account, to investigate the payments of the merchant
+#[instrument(skip_all)]
+pub async fn merchant_account_toggle_connector_payloads(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::ToggleConnectorPayloadsRequest>,
+) -> HttpResponse {
+    let payload = json_payload.into_inner();
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (merchant_id, payload),
+        |state, _, (merchant_id, payload)| {
+            connector_payloads::toggle_capture(&*state.store, merchant_id, payload.capture_enabled)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Connector Payloads Capture Status
+///
+/// Whether the payloads exchanged with the connectors are captured for the Merchant Account
+#[instrument(skip_all)]
+pub async fn merchant_account_connector_payloads_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| {
+            connector_payloads::retrieve_capture_status(&*state.store, merchant_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Retrieve Connector Payloads
+///
+/// Retrieve the masked payloads last exchanged with the connector for each attempt of a payment
+/// of the Merchant Account
+#[instrument(skip_all)]
+pub async fn connector_payloads_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, payment_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (merchant_id, payment_id),
+        |state, _, (merchant_id, payment_id)| {
+            connector_payloads::retrieve_connector_payloads(&*state.store, merchant_id, payment_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - Create
+///
+/// Create a new organization, under which merchant accounts can be created
+#[utoipa::path(
+    post,
+    path = "/organization",
+    request_body = OrganizationCreateRequest,
+    responses(
+        (status = 200, description = "Organization Created", body = OrganizationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Organization",
+    operation_id = "Create an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationCreate))]
+pub async fn organization_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<admin::OrganizationCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| create_organization(&*state.store, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Organization - Retrieve
+///
+/// Retrieve an organization along with the merchant accounts under it
+#[utoipa::path(
+    get,
+    path = "/organization/{org_id}",
+    params(("org_id" = String, Path, description = "The unique identifier for the organization")),
+    responses(
+        (status = 200, description = "Organization Retrieved", body = OrganizationResponse),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organization",
+    operation_id = "Retrieve an Organization",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationRetrieve))]
+pub async fn organization_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let org_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        org_id,
+        |state, _, org_id| retrieve_organization(&*state.store, &org_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Business Profile - Create
+///
+/// Create a new business profile for the merchant account
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/business_profile",
+    request_body = BusinessProfileCreate,
+    params(("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Business Profile Created", body = BusinessProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Create a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
+pub async fn business_profile_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::BusinessProfileCreate>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| create_business_profile(&*state.store, merchant_id.clone(), req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Business Profile - Retrieve
+///
+/// Retrieve a business profile of the merchant account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Business Profile Retrieved", body = BusinessProfileResponse),
+        (status = 404, description = "Business Profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileRetrieve))]
+pub async fn business_profile_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, profile_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| retrieve_business_profile(&*state.store, &merchant_id, &profile_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Business Profile - Update
+///
+/// Update a business profile of the merchant account
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    request_body = BusinessProfileCreate,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Business Profile Updated", body = BusinessProfileResponse),
+        (status = 404, description = "Business Profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Update a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileUpdate))]
+pub async fn business_profile_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<admin::BusinessProfileCreate>,
+) -> HttpResponse {
+    let (merchant_id, profile_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| update_business_profile(&*state.store, &merchant_id, &profile_id, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Business Profile - Delete
+///
+/// Delete a business profile of the merchant account
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Business Profile Deleted", body = DeleteBusinessProfileResponse),
+        (status = 404, description = "Business Profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete a Business Profile",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileDelete))]
+pub async fn business_profile_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, profile_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| delete_business_profile(&*state.store, &merchant_id, &profile_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Business Profile - List
+///
+/// List the business profiles of the merchant account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/business_profile",
+    params(("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Business Profiles Retrieved", body = Vec<BusinessProfileResponse>),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "List Business Profiles",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileList))]
+pub async fn business_profiles_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| list_business_profiles(&*state.store, &merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// Merchant Account - Migrate Tokens
+///
+/// Import the cards of the customers of the merchant account exported by the processor the merchant migrates from, saving them as payment methods of the customers and optionally as mandates at the connector of that processor
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/token_migration",
+    params(("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = TokenMigrationRequest,
+        example = json!({
+            "create_mandates": true,
+            "export": {
+                "source": "adyen",
+                "data": [
+                    {
+                        "shopperReference": "shopper_123",
+                        "recurringDetailReference": "8415718415172200",
+                        "cardNumber": "4111111111111111",
+                        "expiryMonth": "03",
+                        "expiryYear": "2030",
+                        "holderName": "John Test",
+                        "networkTxReference": "MCC0IX5QG0306"
+                    }
+                ]
+            }
+        })
+    ),
+    responses(
+        (status = 200, description = "Cards imported, each with its own outcome", body = TokenMigrationResponse),
+        (status = 400, description = "Invalid data or too many cards in the export"),
+        (status = 404, description = "Merchant account or connector account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Migrate Tokens",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TokenMigration))]
+pub async fn merchant_account_token_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::TokenMigrationRequest>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| token_migration::migrate_tokens(state, merchant_id.clone(), req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -0,0 +1,42 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::analytics,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Analytics - Payments
+///
+/// Aggregate the payment attempts of the merchant made in a time range, such as their number,
+/// success rate, volume and latency, by time bucket and by connector, payment method, currency or
+/// status.
+#[utoipa::path(
+    post,
+    path = "/analytics/payments",
+    request_body = PaymentAnalyticsRequest,
+    responses(
+        (status = 200, description = "Payment analytics computed", body = PaymentAnalyticsResponse),
+        (status = 400, description = "Invalid time range")
+    ),
+    tag = "Analytics",
+    operation_id = "Get Payment Analytics",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentAnalytics))]
+pub async fn payment_analytics(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::PaymentAnalyticsRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        analytics::get_payment_analytics,
+        &auth::ApiKeyOrJWTAuth(Permission::AnalyticsRead),
+    )
+    .await
+}
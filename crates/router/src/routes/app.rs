@@ -1,12 +1,17 @@
 use actix_web::{web, Scope};
 
-use super::health::*;
+#[cfg(feature = "dummy_connector")]
+use super::dummy_connector::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{
+    admin::*, analytics::*, api_keys::*, audit_logs::*, blocklist::*, disputes::*, events::*,
+    recon::*, routing::*, user::*, user_roles::*,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, forex::*, payment_methods::*, webhooks::*};
+use super::{health::*, metrics::metrics_export};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
@@ -18,6 +23,9 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    /// Producer the lifecycle events are published to Kafka with, when enabled
+    #[cfg(feature = "kafka_events")]
+    pub event_producer: Option<crate::services::kafka::KafkaProducer>,
 }
 
 pub trait AppStateInfo {
@@ -48,10 +56,19 @@ impl AppState {
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
 
+        #[cfg(feature = "kafka_events")]
+        #[allow(clippy::expect_used)]
+        let event_producer = conf.kafka.enabled.then(|| {
+            crate::services::kafka::KafkaProducer::create(&conf.kafka)
+                .expect("Failed to create the Kafka producer")
+        });
+
         Self {
             flow_name: String::from("default"),
             store,
             conf,
+            #[cfg(feature = "kafka_events")]
+            event_producer,
         }
     }
 
@@ -68,6 +85,8 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/connectors").route(web::get().to(connector_health)))
+            .service(web::resource("/metrics").route(web::get().to(metrics_export)))
     }
 }
 
@@ -80,7 +99,13 @@ impl Payments {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/list").route(web::get().to(payments_list)));
+            route = route
+                .service(web::resource("/list").route(web::get().to(payments_list)))
+                .service(web::resource("/search").route(web::post().to(payments_search)))
+                .service(
+                    web::resource("/{payment_id}/attempts")
+                        .route(web::get().to(payment_attempts_list)),
+                );
         }
         #[cfg(feature = "oltp")]
         {
@@ -110,7 +135,12 @@ impl Payments {
                 )
                 .service(
                     web::resource("/{payment_id}/{merchant_id}/response/{connector}")
-                        .route(web::get().to(payments_redirect_response)),
+                        .route(web::get().to(payments_redirect_response))
+                        .route(web::post().to(payments_redirect_response_with_form)),
+                )
+                .service(
+                    web::resource("/{payment_id}/{merchant_id}/authentication/complete")
+                        .route(web::post().to(payments_complete_external_authentication)),
                 );
         }
         route
@@ -145,6 +175,10 @@ impl Customers {
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(customers_set_default_payment_method)),
                 );
         }
         route
@@ -166,6 +200,16 @@ impl Refunds {
         {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
+                .service(web::resource("/bulk").route(web::post().to(refunds_bulk_create)))
+                .service(web::resource("/bulk/csv").route(web::post().to(refunds_bulk_create_csv)))
+                .service(
+                    web::resource("/bulk/{batch_id}").route(web::get().to(refunds_bulk_retrieve)),
+                )
+                .service(web::resource("/credits").route(web::post().to(refunds_credit_create)))
+                .service(
+                    web::resource("/credits/{credit_id}")
+                        .route(web::get().to(refunds_credit_retrieve)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -191,16 +235,129 @@ impl Payouts {
         #[cfg(feature = "oltp")]
         {
             route = route
-                .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
+                .service(web::resource("").route(web::post().to(payouts_create)))
                 .service(web::resource("/update").route(web::post().to(payouts_update)))
                 .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(web::resource("/{payout_id}").route(web::get().to(payouts_retrieve)))
+                .service(
+                    web::resource("/{payout_id}/confirm").route(web::post().to(payouts_confirm)),
+                )
+                .service(
+                    web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)),
+                );
         }
         route
     }
 }
 
+pub struct Disputes;
+
+#[cfg(feature = "olap")]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
+            .service(web::resource("/evidence").route(web::post().to(submit_dispute_evidence)))
+            .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
+    }
+}
+
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(events_list)))
+            .service(web::resource("/{event_id}").route(web::get().to(event_retrieve)))
+            .service(web::resource("/{event_id}/replay").route(web::post().to(event_replay)))
+    }
+}
+
+pub struct AuditLogs;
+
+#[cfg(feature = "olap")]
+impl AuditLogs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_logs_list)))
+    }
+}
+
+pub struct Recon;
+
+#[cfg(feature = "olap")]
+impl Recon {
+    pub fn server(state: AppState) -> Scope {
+        // Uploaded reports are read as text, whose size is limited by the payload config rather
+        // than the JSON config of the application
+        let max_file_size = state.conf.recon.max_file_size;
+        web::scope("/recon/reports")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(recon_report_fetch))
+                    .route(web::get().to(recon_report_list)),
+            )
+            .service(
+                web::resource("/{connector}/csv")
+                    .app_data(web::PayloadConfig::new(max_file_size))
+                    .route(web::post().to(recon_report_upload)),
+            )
+            .service(web::resource("/{report_id}").route(web::get().to(recon_report_retrieve)))
+            .service(web::resource("/{report_id}/records").route(web::get().to(recon_record_list)))
+    }
+}
+
+pub struct Blocklist;
+
+#[cfg(feature = "olap")]
+impl Blocklist {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/blocklist")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(add_entry_to_blocklist))
+                    .route(web::delete().to(remove_entry_from_blocklist))
+                    .route(web::get().to(list_blocklist_entries)),
+            )
+    }
+}
+
+pub struct Analytics;
+
+#[cfg(feature = "olap")]
+impl Analytics {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/analytics")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/payments").route(web::post().to(payment_analytics)))
+    }
+}
+
+pub struct Routing;
+
+#[cfg(feature = "olap")]
+impl Routing {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/routing")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(routing_config_create))
+                    .route(web::get().to(routing_config_list)),
+            )
+            .service(
+                web::resource("/{routing_id}/activate")
+                    .route(web::post().to(routing_config_activate)),
+            )
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
@@ -235,6 +392,30 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/connector_payloads")
+                    .route(web::post().to(merchant_account_toggle_connector_payloads))
+                    .route(web::get().to(merchant_account_connector_payloads_status)),
+            )
+            .service(
+                web::resource("/{id}/payments/{payment_id}/connector_payloads")
+                    .route(web::get().to(connector_payloads_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/token_migration")
+                    .route(web::post().to(merchant_account_token_migration)),
+            )
+            .service(
+                web::resource("/{id}/business_profile")
+                    .route(web::post().to(business_profile_create))
+                    .route(web::get().to(business_profiles_list)),
+            )
+            .service(
+                web::resource("/{id}/business_profile/{profile_id}")
+                    .route(web::get().to(business_profile_retrieve))
+                    .route(web::post().to(business_profile_update))
+                    .route(web::delete().to(business_profile_delete)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -244,6 +425,18 @@ impl MerchantAccount {
     }
 }
 
+pub struct Organization;
+
+#[cfg(feature = "olap")]
+impl Organization {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organization")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(organization_create)))
+            .service(web::resource("/{org_id}").route(web::get().to(organization_retrieve)))
+    }
+}
+
 pub struct MerchantConnectorAccount;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -261,6 +454,10 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(payment_connector_create))
                         .route(web::get().to(payment_connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/rotate_key")
+                        .route(web::post().to(payment_connector_rotate_key)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(payment_connector_retrieve))
@@ -296,17 +493,66 @@ pub struct Mandates;
 impl Mandates {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/mandates").app_data(web::Data::new(state));
+        let mut mandate_resource = web::resource("/{id}");
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/{id}").route(web::get().to(get_mandate)));
+            mandate_resource = mandate_resource.route(web::get().to(get_mandate));
         }
         #[cfg(feature = "oltp")]
         {
             route =
                 route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            mandate_resource = mandate_resource.route(web::delete().to(delete_mandate));
         }
-        route
+        route.service(mandate_resource)
+    }
+}
+
+pub struct Forex;
+
+#[cfg(feature = "oltp")]
+impl Forex {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/forex")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/quote").route(web::get().to(retrieve_forex_quote)))
+    }
+}
+
+pub struct DummyConnector;
+
+#[cfg(feature = "dummy_connector")]
+impl DummyConnector {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/dummy-connector")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/payments").route(web::post().to(dummy_connector_payment)))
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(dummy_connector_payment_sync)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_payment_capture)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/void")
+                    .route(web::post().to(dummy_connector_payment_void)),
+            )
+            .service(web::resource("/refunds").route(web::post().to(dummy_connector_refund)))
+            .service(
+                web::resource("/refunds/{refund_id}")
+                    .route(web::get().to(dummy_connector_refund_sync)),
+            )
+            .service(
+                web::resource("/authorize/{payment_id}")
+                    .route(web::get().to(dummy_connector_authorize)),
+            )
+            .service(
+                web::resource("/complete/{payment_id}")
+                    .route(web::get().to(dummy_connector_complete)),
+            )
     }
 }
 
@@ -317,9 +563,14 @@ impl Webhooks {
     pub fn server(config: AppState) -> Scope {
         web::scope("/webhooks")
             .app_data(web::Data::new(config))
+            .service(
+                web::resource("/events/{event_id}/retry")
+                    .route(web::post().to(retry_outgoing_webhook)),
+            )
             .service(
                 web::resource("/{merchant_id}/{connector}")
                     .route(web::post().to(receive_incoming_webhook))
+                    .route(web::get().to(receive_incoming_webhook))
                     .route(web::get().to(receive_incoming_webhook)),
             )
     }
@@ -357,3 +608,34 @@ impl ApiKeys {
             )
     }
 }
+
+pub struct UserRoles;
+
+#[cfg(feature = "olap")]
+impl UserRoles {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/user_roles/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(user_role_create)))
+            .service(web::resource("/list").route(web::get().to(user_role_list)))
+            .service(
+                web::resource("/{user_id}")
+                    .route(web::get().to(user_role_retrieve))
+                    .route(web::post().to(user_role_update))
+                    .route(web::delete().to(user_role_delete)),
+            )
+    }
+}
+
+pub struct User;
+
+#[cfg(feature = "olap")]
+impl User {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/user")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/signup").route(web::post().to(user_signup)))
+            .service(web::resource("/login").route(web::post().to(user_login)))
+            .service(web::resource("/refresh").route(web::post().to(user_refresh_token)))
+    }
+}
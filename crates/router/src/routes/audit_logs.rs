@@ -0,0 +1,48 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::audit_logs,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Audit Logs - List
+///
+/// List the changes made to the payments, refunds and configuration of the merchant account by API
+/// calls, latest first, along with the API key or dashboard user that made each change.
+#[utoipa::path(
+    get,
+    path = "/audit_logs",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of audit log entries to include in the response"),
+        ("resource_type" = Option<AuditResourceType>, Query, description = "The type of the resources that were changed"),
+        ("resource_id" = Option<String>, Query, description = "The identifier of the resource that was changed"),
+        ("actor_id" = Option<String>, Query, description = "The identifier of the API key or dashboard user that made the changes"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the time at which the change was made"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the time at which the change was made"),
+    ),
+    responses(
+        (status = 200, description = "Audit logs listed", body = Vec<AuditLogResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Audit Logs",
+    operation_id = "List Audit Logs",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn audit_logs_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_types::AuditLogListConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        audit_logs::list_audit_logs,
+        &auth::ApiKeyOrJWTAuth(Permission::AuditLogRead),
+    )
+    .await
+}
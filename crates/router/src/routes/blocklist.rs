@@ -0,0 +1,107 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::blocklist,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api as api_types,
+};
+
+/// Blocklist - Add
+///
+/// Block payments on a card, card BIN, customer email or IP address. Payments confirmed with blocked
+/// data are declined without reaching the connector.
+#[utoipa::path(
+    post,
+    path = "/blocklist",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Data blocked", body = BlocklistResponse),
+        (status = 400, description = "The data is already blocked")
+    ),
+    tag = "Blocklist",
+    operation_id = "Block Data",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AddToBlocklist))]
+pub async fn add_entry_to_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::BlocklistRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        blocklist::add_entry_to_blocklist,
+        &auth::ApiKeyAuth(Permission::BlocklistWrite),
+    )
+    .await
+}
+
+/// Blocklist - Remove
+///
+/// Stop blocking payments on a card, card BIN, customer email or IP address.
+#[utoipa::path(
+    delete,
+    path = "/blocklist",
+    request_body = BlocklistRequest,
+    responses(
+        (status = 200, description = "Data unblocked", body = BlocklistResponse),
+        (status = 404, description = "Blocklist entry does not exist in our records")
+    ),
+    tag = "Blocklist",
+    operation_id = "Unblock Data",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFromBlocklist))]
+pub async fn remove_entry_from_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::BlocklistRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        blocklist::remove_entry_from_blocklist,
+        &auth::ApiKeyAuth(Permission::BlocklistWrite),
+    )
+    .await
+}
+
+/// Blocklist - List
+///
+/// List the data of a kind blocked by the merchant account, latest first.
+#[utoipa::path(
+    get,
+    path = "/blocklist",
+    params(
+        ("data_kind" = BlocklistDataKind, Query, description = "The kind of the blocked data"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of entries to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of entries to skip"),
+    ),
+    responses(
+        (status = 200, description = "Blocklist entries listed", body = Vec<BlocklistResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Blocklist",
+    operation_id = "List Blocked Data",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ListBlocklist))]
+pub async fn list_blocklist_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_types::BlocklistListConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        blocklist::list_blocklist_entries,
+        &auth::ApiKeyOrJWTAuth(Permission::BlocklistRead),
+    )
+    .await
+}
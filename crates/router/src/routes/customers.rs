@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::customers::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::customers,
 };
 
@@ -14,9 +14,27 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/customers",
-    request_body = CustomerRequest,
+    request_body(
+        content = CustomerRequest,
+        example = json!({
+            "customer_id": "cus_y3oqhf46pyzuxjbcn2giaqnb44",
+            "name": "John Doe",
+            "email": "guest@example.com",
+            "phone": "999999999",
+            "phone_country_code": "+65",
+            "description": "First customer"
+        })
+    ),
     responses(
-        (status = 200, description = "Customer Created", body = CustomerResponse),
+        (status = 200, description = "Customer Created", body = CustomerResponse, example = json!({
+            "customer_id": "cus_y3oqhf46pyzuxjbcn2giaqnb44",
+            "name": "John Doe",
+            "email": "guest@example.com",
+            "phone": "999999999",
+            "phone_country_code": "+65",
+            "description": "First customer",
+            "created_at": "2023-05-18T11:20:15.332Z"
+        })),
         (status = 400, description = "Invalid data")
     ),
     tag = "Customers",
@@ -34,7 +52,7 @@ pub async fn customers_create(
         &req,
         json_payload.into_inner(),
         |state, merchant_account, req| create_customer(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -65,11 +83,17 @@ pub async fn customers_retrieve(
     })
     .into_inner();
 
-    let auth =
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let auth = match auth::is_ephemeral_auth(
+        req.headers(),
+        &*state.store,
+        &payload.customer_id,
+        Permission::CustomerRead,
+    )
+    .await
+    {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     api::server_wrap(
         state.get_ref(),
@@ -111,14 +135,14 @@ pub async fn customers_update(
         &req,
         json_payload.into_inner(),
         |state, merchant_account, req| update_customer(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
 
 /// Delete Customer
 ///
-/// Delete a customer record.
+/// Delete a customer record. The customer's mandates are revoked, saved payment methods are removed from the locker and the customer's details are redacted.
 #[utoipa::path(
     delete,
     path = "/customers/{customer_id}",
@@ -146,7 +170,48 @@ pub async fn customers_delete(
         &req,
         payload,
         delete_customer,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
+    )
+    .await
+}
+
+/// Set Default Payment Method
+///
+/// Set a payment method saved for the customer as the one used by default for the customer.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Default Payment Method set", body = CustomerDefaultPaymentMethodResponse),
+        (status = 404, description = "Customer or Payment Method was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Set the Default Payment Method for a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsSetDefault))]
+pub async fn customers_set_default_payment_method(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (customer_id, payment_method_id) = path.into_inner();
+    let payload = customers::CustomerPaymentMethodId {
+        customer_id,
+        payment_method_id,
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            set_default_payment_method(&*state.store, merchant_account, req)
+        },
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -168,7 +233,7 @@ pub async fn get_customer_mandates(
         |state, merchant_account, req| {
             crate::core::mandate::get_customer_mandates(state, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateRead),
     )
     .await
 }
@@ -0,0 +1,110 @@
+use actix_web::{http::header, web, HttpResponse};
+use router_env::{instrument, tracing};
+
+use super::app::AppState;
+use crate::{
+    core::dummy_connector::{self as dummy, DummyConnectorError},
+    services,
+};
+
+fn error_response(error: DummyConnectorError) -> HttpResponse {
+    match actix_web::http::StatusCode::from_u16(error.status_code()) {
+        Ok(status_code) => HttpResponse::build(status_code).json(error.to_response()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
+
+fn json_response<T: serde::Serialize>(result: Result<T, DummyConnectorError>) -> HttpResponse {
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(error) => error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_payment(
+    state: web::Data<AppState>,
+    json_payload: web::Json<dummy::DummyConnectorPaymentRequest>,
+) -> HttpResponse {
+    json_response(
+        dummy::payment(
+            &state.conf.connectors.dummy.base_url,
+            services::api::get_timeout(&state.conf.connector_request, "dummy"),
+            json_payload.into_inner(),
+        )
+        .await,
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_payment_sync(path: web::Path<String>) -> HttpResponse {
+    json_response(dummy::payment_sync(&path.into_inner()))
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_payment_capture(
+    path: web::Path<String>,
+    json_payload: web::Json<dummy::DummyConnectorCaptureRequest>,
+) -> HttpResponse {
+    json_response(dummy::payment_capture(
+        &path.into_inner(),
+        json_payload.into_inner(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_payment_void(path: web::Path<String>) -> HttpResponse {
+    json_response(dummy::payment_void(&path.into_inner()))
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_refund(
+    json_payload: web::Json<dummy::DummyConnectorRefundRequest>,
+) -> HttpResponse {
+    json_response(dummy::refund(json_payload.into_inner()))
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_refund_sync(path: web::Path<String>) -> HttpResponse {
+    json_response(dummy::refund_sync(&path.into_inner()))
+}
+
+/// Challenge page shown to the customer for payments made with the 3DS test card.
+#[instrument(skip_all)]
+pub async fn dummy_connector_authorize(path: web::Path<String>) -> HttpResponse {
+    let payment_id = path.into_inner();
+    let page = maud::html! {
+        (maud::DOCTYPE)
+        html {
+            head { title { "Dummy Connector 3DS" } }
+            body {
+                h3 { "Authenticate payment " (payment_id) }
+                a href=(format!("../complete/{payment_id}?confirm=true")) { "Complete" }
+                " "
+                a href=(format!("../complete/{payment_id}?confirm=false")) { "Fail" }
+            }
+        }
+    };
+    HttpResponse::Ok()
+        .content_type(mime::TEXT_HTML_UTF_8)
+        .body(page.into_string())
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DummyConnectorCompleteQuery {
+    confirm: bool,
+}
+
+#[instrument(skip_all)]
+pub async fn dummy_connector_complete(
+    path: web::Path<String>,
+    query: web::Query<DummyConnectorCompleteQuery>,
+) -> HttpResponse {
+    match dummy::payment_complete(&path.into_inner(), query.confirm) {
+        Ok(Some(return_url)) => HttpResponse::Found()
+            .insert_header((header::LOCATION, return_url))
+            .finish(),
+        Ok(None) => HttpResponse::Ok().body("Authentication completed"),
+        Err(error) => error_response(error),
+    }
+}
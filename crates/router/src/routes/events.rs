@@ -0,0 +1,115 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::webhooks,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api::webhooks as webhook_types,
+};
+
+/// Events - List
+///
+/// List the events raised for the merchant account, latest first.
+#[utoipa::path(
+    get,
+    path = "/events",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("object_id" = Option<String>, Query, description = "The identifier of the payment, refund or dispute the events are about"),
+        ("event_type" = Option<EventType>, Query, description = "The type of the events"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the event creation time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the event creation time"),
+    ),
+    responses(
+        (status = 200, description = "Events listed", body = Vec<EventResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Events",
+    operation_id = "List Events",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventList))]
+pub async fn events_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<webhook_types::EventListConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        webhooks::list_events,
+        &auth::ApiKeyOrJWTAuth(Permission::EventRead),
+    )
+    .await
+}
+
+/// Events - Retrieve
+///
+/// Retrieve an event along with the body of the webhook sent for it and the attempts made to
+/// deliver it.
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "The identifier of the event")
+    ),
+    responses(
+        (status = 200, description = "Event retrieved", body = EventRetrieveResponse),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Retrieve an Event",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventRetrieve))]
+pub async fn event_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        webhooks::retrieve_event,
+        &auth::ApiKeyOrJWTAuth(Permission::EventRead),
+    )
+    .await
+}
+
+/// Events - Replay
+///
+/// Send the webhook of an event to the webhook endpoint of the merchant again, as it was first
+/// sent.
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/replay",
+    params(
+        ("event_id" = String, Path, description = "The identifier of the event")
+    ),
+    responses(
+        (status = 200, description = "Event replayed", body = WebhookDeliveryAttemptResponse),
+        (status = 400, description = "The webhook URL of the merchant is not configured"),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Replay an Event",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+pub async fn event_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        webhooks::replay_event,
+        &auth::ApiKeyOrJWTAuth(Permission::EventWrite),
+    )
+    .await
+}
@@ -0,0 +1,44 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::forex::ForexQuoteRequest;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::forex,
+    services::{api, authentication as auth, authorization::Permission},
+};
+
+/// Forex - Quote
+///
+/// Quote the conversion of an amount from one currency to another, using the exchange rates of the configured provider
+#[utoipa::path(
+    get,
+    path = "/forex/quote",
+    params(
+        ("from_currency" = Currency, Query, description = "The currency the amount is presented in"),
+        ("to_currency" = Currency, Query, description = "The currency the amount is to be settled in"),
+        ("amount" = i64, Query, description = "The amount to be converted, in the lowest denomination of the currency it is presented in"),
+    ),
+    responses(
+        (status = 200, description = "The quote was created successfully", body = ForexQuoteResponse),
+        (status = 400, description = "No exchange rate is available for the currencies")
+    ),
+    tag = "Forex",
+    operation_id = "Retrieve a Forex Quote",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ForexQuote))]
+pub async fn retrieve_forex_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ForexQuoteRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        forex::retrieve_forex_quote,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+    )
+    .await
+}
@@ -1,6 +1,12 @@
-use router_env::{instrument, logger, tracing};
+use actix_web::{web, HttpRequest};
+use router_env::{instrument, logger, tracing, Flow};
 
-use crate::routes::metrics;
+use super::app::AppState;
+use crate::{
+    core::connector_health as connector_health_core,
+    routes::metrics,
+    services::{api, authentication as auth},
+};
 
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -11,3 +17,19 @@ pub async fn health() -> impl actix_web::Responder {
     logger::info!("Health was called");
     actix_web::HttpResponse::Ok().body("health is good")
 }
+
+/// Health of the connectors called by this instance, along with the state of their circuits.
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealth))]
+pub async fn connector_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl actix_web::Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| connector_health_core::get_connectors_health(state),
+        &auth::NoAuth,
+    )
+    .await
+}
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::mandate,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::mandates,
 };
 
@@ -40,7 +40,7 @@ pub async fn get_mandate(
         &req,
         mandate_id,
         mandate::get_mandate,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateRead),
     )
     .await
 }
@@ -76,10 +76,45 @@ pub async fn revoke_mandate(
         state.get_ref(),
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
-        &auth::ApiKeyAuth,
+        |state, merchant_account, req| mandate::revoke_mandate(state, merchant_account, req),
+        &auth::ApiKeyAuth(Permission::MandateWrite),
+    )
+    .await
+}
+
+/// Mandates - Delete Mandate
+///
+/// Revoke a mandate and cancel it at the connector
+#[utoipa::path(
+    delete,
+    path = "/mandates/{mandate_id}",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for mandate")
+    ),
+    responses(
+        (status = 200, description = "The mandate was revoked successfully", body = MandateRevokedResponse),
+        (status = 400, description = "Mandate does not exist in our records or cannot be revoked in its current status")
+    ),
+    tag = "Mandates",
+    operation_id = "Delete a Mandate",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesRevoke))]
+// #[delete("/{id}")]
+pub async fn delete_mandate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let mandate_id = mandates::MandateId {
+        mandate_id: path.into_inner(),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        mandate_id,
+        |state, merchant_account, req| mandate::revoke_mandate(state, merchant_account, req),
+        &auth::ApiKeyAuth(Permission::MandateWrite),
     )
     .await
 }
@@ -1,8 +1,13 @@
+use actix_web::{HttpResponse, Responder};
 use once_cell::sync::Lazy;
-use router_env::opentelemetry::{
-    global,
-    metrics::{Counter, Meter},
-    Context,
+use router_env::{
+    logger,
+    opentelemetry::{
+        global,
+        metrics::{Counter, Histogram, Meter},
+        Context,
+    },
+    prometheus::{self, Encoder},
 };
 
 pub static CONTEXT: Lazy<Context> = Lazy::new(Context::current);
@@ -13,3 +18,56 @@ pub(crate) static HEALTH_METRIC: Lazy<Counter<u64>> =
 
 pub(crate) static KV_MISS: Lazy<Counter<u64>> =
     Lazy::new(|| GLOBAL_METER.u64_counter("KV_MISS").init());
+
+// API requests served, by route, HTTP method and response status code
+pub(crate) static REQUESTS_RECEIVED: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("REQUESTS_RECEIVED").init());
+
+// Time taken to serve API requests in seconds, by route, HTTP method and response status code
+pub(crate) static REQUEST_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| GLOBAL_METER.f64_histogram("REQUEST_TIME").init());
+
+// Requests made to connectors, by connector, flow and outcome
+pub(crate) static CONNECTOR_CALL_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CONNECTOR_CALL_COUNT").init());
+
+// Time taken by connectors to respond in seconds, by connector, flow and outcome
+pub(crate) static CONNECTOR_REQUEST_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| GLOBAL_METER.f64_histogram("CONNECTOR_REQUEST_TIME").init());
+
+// Requests to connectors sent again after a failure, by connector
+pub(crate) static CONNECTOR_REQUEST_RETRY_COUNT: Lazy<Counter<u64>> = Lazy::new(|| {
+    GLOBAL_METER
+        .u64_counter("CONNECTOR_REQUEST_RETRY_COUNT")
+        .init()
+});
+
+// Circuits opened for degraded connectors, by connector
+pub(crate) static CONNECTOR_CIRCUIT_OPENED: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CONNECTOR_CIRCUIT_OPENED").init());
+
+// Payment attempts processed by connectors, by connector, payment method and the resulting
+// status of the attempt, from which success and decline rates are derived
+pub(crate) static PAYMENT_ATTEMPT_OUTCOME: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("PAYMENT_ATTEMPT_OUTCOME").init());
+
+/// Serves the metrics of the application in the Prometheus text format, when metrics are
+/// exported to Prometheus.
+pub async fn metrics_export() -> impl Responder {
+    let registry = match router_env::prometheus_registry() {
+        Some(registry) => registry,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    let encoder = prometheus::TextEncoder::new();
+    let mut buffer = Vec::new();
+    match encoder.encode(&registry.gather(), &mut buffer) {
+        Ok(()) => HttpResponse::Ok()
+            .content_type(encoder.format_type())
+            .body(buffer),
+        Err(error) => {
+            logger::error!(?error, "Failed to encode metrics");
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
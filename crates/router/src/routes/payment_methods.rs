@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::payment_methods::cards,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::payment_methods::{self, PaymentMethodId},
 };
 
@@ -36,7 +36,7 @@ pub async fn create_payment_method_api(
         |state, merchant_account, req| async move {
             cards::add_payment_method(state, req, &merchant_account).await
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -73,7 +73,11 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let payload = json_payload.into_inner();
 
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -121,7 +125,13 @@ pub async fn list_customer_payment_method_api(
 ) -> HttpResponse {
     let customer_id = customer_id.into_inner().0;
 
-    let auth_type = match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await
+    let auth_type = match auth::is_ephemeral_auth(
+        req.headers(),
+        &*state.store,
+        &customer_id,
+        Permission::CustomerRead,
+    )
+    .await
     {
         Ok(auth_type) => auth_type,
         Err(err) => return api::log_and_return_error_response(err),
@@ -172,7 +182,7 @@ pub async fn payment_method_retrieve_api(
         &req,
         payload,
         |state, merchant_account, pm| cards::retrieve_payment_method(state, pm, merchant_account),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
     )
     .await
 }
@@ -216,7 +226,7 @@ pub async fn payment_method_update_api(
                 &payment_method_id,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
@@ -252,7 +262,7 @@ pub async fn payment_method_delete_api(
         &req,
         pm,
         cards::delete_payment_method,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
     )
     .await
 }
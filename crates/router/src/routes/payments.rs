@@ -4,8 +4,8 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     self as app,
-    core::{errors::http_not_implemented, payments},
-    services::{api, authentication as auth},
+    core::{authentication, errors::http_not_implemented, payments},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::{self as api_types, enums as api_enums, payments as payment_types},
 };
 
@@ -15,9 +15,48 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/payments",
-    request_body=PaymentsRequest,
+    request_body(
+        content = PaymentsRequest,
+        example = json!({
+            "amount": 6540,
+            "currency": "USD",
+            "confirm": true,
+            "capture_method": "automatic",
+            "customer_id": "cus_y3oqhf46pyzuxjbcn2giaqnb44",
+            "email": "guest@example.com",
+            "description": "Its my first payment request",
+            "return_url": "https://hyperswitch.io",
+            "payment_method": "card",
+            "payment_method_data": {
+                "card": {
+                    "card_number": "4242424242424242",
+                    "card_exp_month": "10",
+                    "card_exp_year": "25",
+                    "card_holder_name": "John Doe",
+                    "card_cvc": "123"
+                }
+            }
+        })
+    ),
     responses(
-        (status = 200, description = "Payment created", body = PaymentsResponse),
+        (status = 200, description = "Payment created", body = PaymentsResponse, example = json!({
+            "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+            "merchant_id": "merchant_1668273825",
+            "status": "succeeded",
+            "amount": 6540,
+            "amount_capturable": 0,
+            "amount_received": 6540,
+            "connector": "stripe",
+            "client_secret": "pay_mbabizu24mvu3mela5njyhpit4_secret_el9ksDkiB8hi6j9N78yo",
+            "created": "2023-05-18T11:23:04.213Z",
+            "currency": "USD",
+            "customer_id": "cus_y3oqhf46pyzuxjbcn2giaqnb44",
+            "description": "Its my first payment request",
+            "capture_method": "automatic",
+            "payment_method": "card",
+            "return_url": "https://hyperswitch.io/",
+            "authentication_type": "no_three_ds"
+        })),
         (status = 400, description = "Missing Mandatory fields")
     ),
     tag = "Payments",
@@ -33,11 +72,7 @@ pub async fn payments_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
@@ -50,7 +85,7 @@ pub async fn payments_create(
                 api::AuthFlow::Merchant,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
     )
     .await
 }
@@ -137,12 +172,13 @@ pub async fn payments_retrieve(
         param: None,
         connector: None,
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
@@ -197,12 +233,13 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
@@ -256,13 +293,16 @@ pub async fn payments_confirm(
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
@@ -311,7 +351,7 @@ pub async fn payments_capture(
         ..json_payload.into_inner()
     };
 
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         capture_payload,
@@ -325,7 +365,7 @@ pub async fn payments_capture(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
     )
     .await
 }
@@ -428,6 +468,65 @@ pub async fn payments_redirect_response(
     .await
 }
 
+/// Payments - Redirect response for form posts
+///
+/// Used when the customer is sent back with a form post, as done by 3DS2 access control servers
+#[instrument(skip_all)]
+pub async fn payments_redirect_response_with_form(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String, String)>,
+    body: web::Bytes,
+) -> impl Responder {
+    let (payment_id, merchant_id, connector) = path.into_inner();
+    let param_string = String::from_utf8_lossy(&body).into_owned();
+
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: Some(merchant_id.clone()),
+        force_sync: true,
+        param: Some(param_string),
+        connector: Some(connector),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::handle_payments_redirect_response::<api_types::PSync>(
+                state,
+                merchant_account,
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
+/// Payments - Complete external authentication
+///
+/// Notification URL of the authentications made through the external 3DS server, to which the
+/// access control server of the issuer posts the challenge response
+#[instrument(skip_all)]
+pub async fn payments_complete_external_authentication(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (payment_id, merchant_id) = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            authentication::complete_external_authentication(state, merchant_account, payment_id)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
 /// Payments - Cancel
 ///
 /// A Payment could can be cancelled when it is in one of these statuses: requires_payment_method, requires_capture, requires_confirmation, requires_customer_action
@@ -458,7 +557,7 @@ pub async fn payments_cancel(
     let payment_id = path.into_inner();
     payload.payment_id = payment_id;
 
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
@@ -472,7 +571,7 @@ pub async fn payments_cancel(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
     )
     .await
 }
@@ -492,7 +591,15 @@ pub async fn payments_cancel(
         ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the payment created time"),
         ("created_gt" = PrimitiveDateTime, Query, description = "Time greater than the payment created time"),
         ("created_lte" = PrimitiveDateTime, Query, description = "Time less than or equals to the payment created time"),
-        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the payment created time")
+        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the payment created time"),
+        ("status" = IntentStatus, Query, description = "The status of the payments"),
+        ("connector" = Connector, Query, description = "The connector any attempt of the payments was made through"),
+        ("payment_method" = PaymentMethod, Query, description = "The payment method any attempt of the payments was made with"),
+        ("currency" = Currency, Query, description = "The currency of the payments"),
+        ("amount_gte" = i64, Query, description = "Amount greater than or equals to the payment amount"),
+        ("amount_lte" = i64, Query, description = "Amount less than or equals to the payment amount"),
+        ("sort_by" = PaymentListSortBy, Query, description = "The field the payments are sorted by, created_at by default"),
+        ("sort_order" = SortOrder, Query, description = "The order the payments are sorted in, desc by default")
     ),
     responses(
         (status = 200, description = "Received payment list"),
@@ -500,7 +607,7 @@ pub async fn payments_cancel(
     ),
     tag = "Payments",
     operation_id = "List all Payments",
-    security(("api_key" = []))
+    security(("api_key" = []), ("jwt" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(feature = "olap")]
@@ -511,23 +618,96 @@ pub async fn payments_list(
     payload: web::Query<payment_types::PaymentListConstraints>,
 ) -> impl Responder {
     let payload = payload.into_inner();
-    api::server_wrap(
+    api::versioned_server_wrap(
         state.get_ref(),
         &req,
         payload,
         |state, merchant_account, req| {
             payments::list_payments(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyOrJWTAuth(Permission::PaymentRead),
+    )
+    .await
+}
+
+/// Payments - Search
+///
+/// To search the payments by the email of the customer, the last four digits of the card, the
+/// identifier of an attempt at the connector or the metadata of the payments
+#[utoipa::path(
+    post,
+    path = "/payments/search",
+    request_body = PaymentSearchRequest,
+    responses(
+        (status = 200, description = "Payments found", body = PaymentSearchResponse),
+        (status = 400, description = "Invalid search criteria")
+    ),
+    tag = "Payments",
+    operation_id = "Search Payments",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSearch))]
+#[cfg(feature = "olap")]
+pub async fn payments_search(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentSearchRequest>,
+) -> impl Responder {
+    api::versioned_server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payments::search::search_payments(state, merchant_account, req)
+        },
+        &auth::ApiKeyOrJWTAuth(Permission::PaymentRead),
     )
     .await
 }
 
-async fn authorize_verify_select<Op>(
+/// Payments - List Attempts
+///
+/// To list every attempt of a payment, including the attempts which failed and the calls to other
+/// connectors which failed before an attempt was moved to its connector
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/attempts",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Received the attempts of the payment", body = PaymentAttemptListResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "List the Attempts of a Payment",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentAttemptsList))]
+#[cfg(feature = "olap")]
+pub async fn payment_attempts_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            payments::list_payment_attempts(&*state.store, merchant_account, payment_id)
+        },
+        &auth::ApiKeyOrJWTAuth(Permission::PaymentRead),
+    )
+    .await
+}
+
+pub(crate) async fn authorize_verify_select<Op>(
     operation: Op,
     state: &app::AppState,
     merchant_account: storage_models::merchant_account::MerchantAccount,
-    req: api_models::payments::PaymentsRequest,
+    mut req: api_models::payments::PaymentsRequest,
     auth_flow: api::AuthFlow,
 ) -> app::core::errors::RouterResponse<api_models::payments::PaymentsResponse>
 where
@@ -543,7 +723,13 @@ where
     // the operation are flow agnostic, and the flow is only required in the post_update_tracker
     // Thus the flow can be generated just before calling the connector instead of explicitly passing it here.
 
-    match req.amount.as_ref() {
+    payments::helpers::resolve_recurring_details(state, &merchant_account, &mut req).await?;
+    payments::helpers::resolve_payment_method_id(state, &merchant_account, &mut req, auth_flow)
+        .await?;
+    let amount =
+        payments::helpers::get_authorization_amount(&*state.store, &merchant_account, &req).await?;
+
+    match amount {
         Some(api_types::Amount::Value(_)) | None => payments::payments_core::<
             api_types::Authorize,
             payment_types::PaymentsResponse,
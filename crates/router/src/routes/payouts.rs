@@ -1,19 +1,86 @@
 use actix_web::{
     body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
+    web, HttpRequest, HttpResponse, Responder,
 };
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts::*,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api::payouts,
+};
+
+/// Payouts - Create
+///
+/// To disburse funds to a card or a bank account through a connector that supports payouts
+#[utoipa::path(
+    post,
+    path = "/payouts",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutCreateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
-// #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    http_response("create")
+// #[post("")]
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts_create_core,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
+    )
+    .await
 }
 
+/// Payouts - Retrieve
+///
+/// To retrieve the properties of a Payout, including its current status
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout"),
+        ("force_sync" = Option<bool>, Query, description = "Sync the status of a pending payout with the connector")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    http_response("retrieve")
+// #[get("/{payout_id}")]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<payouts::PayoutRetrieveBody>,
+) -> HttpResponse {
+    let payload = payouts::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: query_payload.force_sync.unwrap_or(false),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        payouts_retrieve_core,
+        &auth::ApiKeyAuth(Permission::PayoutRead),
+    )
+    .await
 }
 
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
@@ -28,10 +95,74 @@ pub async fn payouts_reverse() -> impl Responder {
     http_response("reverse")
 }
 
+/// Payouts - Confirm
+///
+/// To confirm a payout that the connector is holding for review, releasing the funds
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/confirm",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout confirmed", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not awaiting review"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Confirm a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsConfirm))]
+// #[post("/{payout_id}/confirm")]
+pub async fn payouts_confirm(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_confirm_core,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
+    )
+    .await
+}
+
+/// Payouts - Cancel
+///
+/// To decline a payout that the connector is holding for review
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutCreateResponse),
+        (status = 400, description = "Payout is not awaiting review"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    http_response("cancel")
+// #[post("/{payout_id}/cancel")]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_cancel_core,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
+    )
+    .await
 }
 
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
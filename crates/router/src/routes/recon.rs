@@ -0,0 +1,197 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::recon,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api::{self as api_types, enums as api_enums},
+};
+
+/// Reconciliation - Fetch Report
+///
+/// Download the settlement report of a day from the SFTP server or reporting API of a connector.
+/// The report is reconciled in the background, its status shows when it is done.
+#[utoipa::path(
+    post,
+    path = "/recon/reports",
+    request_body = ReconReportFetchRequest,
+    responses(
+        (status = 200, description = "Settlement report scheduled for reconciliation", body = ReconReportResponse),
+        (status = 400, description = "Settlement reports of the connector cannot be downloaded")
+    ),
+    tag = "Reconciliation",
+    operation_id = "Fetch a Settlement Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportFetch))]
+pub async fn recon_report_fetch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::ReconReportFetchRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        recon::fetch_recon_report,
+        &auth::ApiKeyAuth(Permission::ReconWrite),
+    )
+    .await
+}
+
+/// Reconciliation - Upload Report
+///
+/// Upload the settlement report of a connector as a CSV file, whose first line names the columns.
+/// The report is reconciled in the background, its status shows when it is done.
+#[utoipa::path(
+    post,
+    path = "/recon/reports/{connector}/csv",
+    params(
+        ("connector" = Connector, Path, description = "The connector which settled the transactions of the report"),
+        ("file_name" = Option<String>, Query, description = "Name of the uploaded file, kept for reference"),
+    ),
+    request_body(content = String, content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Settlement report scheduled for reconciliation", body = ReconReportResponse),
+        (status = 400, description = "Invalid settlement report")
+    ),
+    tag = "Reconciliation",
+    operation_id = "Upload a Settlement Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportUpload))]
+pub async fn recon_report_upload(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::Connector>,
+    query_payload: web::Query<api_types::ReconReportUploadParams>,
+    body: String,
+) -> impl Responder {
+    let connector = path.into_inner();
+    let file_name = query_payload.into_inner().file_name;
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        body,
+        |state, merchant_account, content| {
+            recon::upload_recon_report(
+                state,
+                merchant_account,
+                connector,
+                file_name.clone(),
+                content,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::ReconWrite),
+    )
+    .await
+}
+
+/// Reconciliation - List Reports
+///
+/// List the settlement reports of the merchant account, latest first.
+#[utoipa::path(
+    get,
+    path = "/recon/reports",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of reports to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of reports to skip"),
+    ),
+    responses(
+        (status = 200, description = "Settlement reports listed", body = Vec<ReconReportResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Reconciliation",
+    operation_id = "List Settlement Reports",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportList))]
+pub async fn recon_report_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_types::ReconReportListConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        recon::list_recon_reports,
+        &auth::ApiKeyOrJWTAuth(Permission::ReconRead),
+    )
+    .await
+}
+
+/// Reconciliation - Retrieve Report
+///
+/// Retrieve a settlement report, along with the outcome of its reconciliation.
+#[utoipa::path(
+    get,
+    path = "/recon/reports/{report_id}",
+    params(
+        ("report_id" = String, Path, description = "The identifier of the settlement report")
+    ),
+    responses(
+        (status = 200, description = "Settlement report retrieved", body = ReconReportResponse),
+        (status = 404, description = "Settlement report does not exist in our records")
+    ),
+    tag = "Reconciliation",
+    operation_id = "Retrieve a Settlement Report",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportRetrieve))]
+pub async fn recon_report_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        recon::retrieve_recon_report,
+        &auth::ApiKeyOrJWTAuth(Permission::ReconRead),
+    )
+    .await
+}
+
+/// Reconciliation - List Records
+///
+/// List the payment and refund records of a settlement report in the order of the report, along
+/// with the payment or refund each record was matched to.
+#[utoipa::path(
+    get,
+    path = "/recon/reports/{report_id}/records",
+    params(
+        ("report_id" = String, Path, description = "The identifier of the settlement report"),
+        ("recon_status" = Option<ReconStatus>, Query, description = "Only the records with this outcome"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of records to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of records to skip"),
+    ),
+    responses(
+        (status = 200, description = "Settlement records listed", body = Vec<ReconRecordResponse>),
+        (status = 404, description = "Settlement report does not exist in our records")
+    ),
+    tag = "Reconciliation",
+    operation_id = "List Settlement Records",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconRecordList))]
+pub async fn recon_record_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_types::ReconRecordListConstraints>,
+) -> impl Responder {
+    let report_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query_payload.into_inner(),
+        |state, merchant_account, constraints| {
+            recon::list_recon_records(state, merchant_account, report_id.clone(), constraints)
+        },
+        &auth::ApiKeyOrJWTAuth(Permission::ReconRead),
+    )
+    .await
+}
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::refunds::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::Permission},
     types::api::refunds,
 };
 
@@ -14,9 +14,26 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/refunds",
-    request_body=RefundRequest,
+    request_body(
+        content = RefundRequest,
+        example = json!({
+            "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+            "amount": 654,
+            "reason": "Customer returned the product",
+            "refund_type": "instant"
+        })
+    ),
     responses(
-        (status = 200, description = "Refund created", body = RefundResponse),
+        (status = 200, description = "Refund created", body = RefundResponse, example = json!({
+            "refund_id": "ref_4wrbtorhqbnf2i2xytqnmzosuq",
+            "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+            "amount": 654,
+            "currency": "USD",
+            "reason": "Customer returned the product",
+            "status": "succeeded",
+            "created_at": "2023-05-18T11:25:42.611Z",
+            "updated_at": "2023-05-18T11:25:43.103Z"
+        })),
         (status = 400, description = "Missing Mandatory fields")
     ),
     tag = "Refunds",
@@ -35,7 +52,7 @@ pub async fn refunds_create(
         &req,
         json_payload.into_inner(),
         refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
     )
     .await
 }
@@ -55,7 +72,7 @@ pub async fn refunds_create(
     ),
     tag = "Refunds",
     operation_id = "Retrieve a Refund",
-    security(("api_key" = []))
+    security(("api_key" = []), ("jwt" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsRetrieve))]
 // #[get("/{id}")]
@@ -73,7 +90,7 @@ pub async fn refunds_retrieve(
         |state, merchant_account, refund_id| {
             refund_response_wrapper(state, merchant_account, refund_id, refund_retrieve_core)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyOrJWTAuth(Permission::RefundRead),
     )
     .await
 }
@@ -94,7 +111,7 @@ pub async fn refunds_retrieve(
     ),
     tag = "Refunds",
     operation_id = "Update a Refund",
-    security(("api_key" = []))
+    security(("api_key" = []), ("jwt" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsUpdate))]
 // #[post("/{id}")]
@@ -112,7 +129,7 @@ pub async fn refunds_update(
         |state, merchant_account, req| {
             refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyOrJWTAuth(Permission::RefundWrite),
     )
     .await
 }
@@ -125,6 +142,7 @@ pub async fn refunds_update(
     path = "/refunds/list",
     params(
         ("payment_id" = String, Query, description = "The identifier for the payment"),
+        ("reason_code" = RefundReason, Query, description = "Only the refunds issued for this reason"),
         ("limit" = i64, Query, description = "Limit on the number of objects to return"),
         ("created" = PrimitiveDateTime, Query, description = "The time at which refund is created"),
         ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the refund created time"),
@@ -138,7 +156,7 @@ pub async fn refunds_update(
     ),
     tag = "Refunds",
     operation_id = "List all Refunds",
-    security(("api_key" = []))
+    security(("api_key" = []), ("jwt" = []))
 )]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
 #[cfg(feature = "olap")]
@@ -153,7 +171,210 @@ pub async fn refunds_list(
         &req,
         payload.into_inner(),
         |state, merchant_account, req| refund_list(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyOrJWTAuth(Permission::RefundRead),
+    )
+    .await
+}
+
+/// Refunds - Bulk Create
+///
+/// To initiate refunds against many payments at once. The refunds are initiated in the background, the returned batch can be retrieved to follow their progress
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk",
+    request_body(
+        content = RefundBulkRequest,
+        example = json!({
+            "refunds": [
+                {
+                    "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+                    "amount": 654,
+                    "reason": "Product recalled"
+                },
+                {
+                    "payment_id": "pay_4wrbtorhqbnf2i2xytqnmzosuq",
+                    "reason": "Product recalled"
+                }
+            ]
+        })
+    ),
+    responses(
+        (status = 200, description = "Refund batch created", body = RefundBulkResponse),
+        (status = 400, description = "Missing Mandatory fields or too many refunds in the batch")
+    ),
+    tag = "Refunds",
+    operation_id = "Create Refunds in Bulk",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBulkCreate))]
+// #[post("/bulk")]
+pub async fn refunds_bulk_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundBulkRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        refund_bulk_create_core,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
+    )
+    .await
+}
+
+/// Refunds - Bulk Create from CSV
+///
+/// To initiate refunds against many payments at once from a CSV file. Its first line names the columns, out of payment_id, refund_id, amount, reason and refund_type, and every other line describes a refund
+#[utoipa::path(
+    post,
+    path = "/refunds/bulk/csv",
+    request_body(
+        content = String,
+        content_type = "text/csv",
+        example = "payment_id,amount,reason\npay_mbabizu24mvu3mela5njyhpit4,654,Product recalled\npay_4wrbtorhqbnf2i2xytqnmzosuq,,Product recalled"
+    ),
+    responses(
+        (status = 200, description = "Refund batch created", body = RefundBulkResponse),
+        (status = 400, description = "Malformed CSV file or too many refunds in the batch")
+    ),
+    tag = "Refunds",
+    operation_id = "Create Refunds in Bulk from CSV",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBulkCreate))]
+// #[post("/bulk/csv")]
+pub async fn refunds_bulk_create_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: String,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, payload| async move {
+            let req = parse_refund_bulk_csv(&payload)?;
+            refund_bulk_create_core(state, merchant_account, req).await
+        },
+        &auth::ApiKeyAuth(Permission::RefundWrite),
+    )
+    .await
+}
+
+/// Refunds - Bulk Retrieve
+///
+/// To retrieve a batch of refunds initiated together, along with the outcome of every refund initiated so far
+#[utoipa::path(
+    get,
+    path = "/refunds/bulk/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the batch of refunds")
+    ),
+    responses(
+        (status = 200, description = "Refund batch retrieved", body = RefundBulkResponse),
+        (status = 404, description = "Refund batch does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Refund Batch",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBulkRetrieve))]
+// #[get("/bulk/{batch_id}")]
+pub async fn refunds_bulk_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        refund_bulk_retrieve_core,
+        &auth::ApiKeyOrJWTAuth(Permission::RefundRead),
+    )
+    .await
+}
+
+/// Refunds - Credit Create
+///
+/// To credit funds to a payment method without a payment to refund, such as a goodwill gesture. Credits are only issued for merchants whose credit policy enables them, through connectors accepting unreferenced refunds
+#[utoipa::path(
+    post,
+    path = "/refunds/credits",
+    request_body(
+        content = CreditRequest,
+        example = json!({
+            "amount": 500,
+            "currency": "USD",
+            "connector": "cybersource",
+            "payment_method_data": {
+                "card": {
+                    "card_number": "4111111111111111",
+                    "card_exp_month": "12",
+                    "card_exp_year": "2030",
+                    "card_holder_name": "John Doe",
+                    "card_cvc": "123"
+                }
+            },
+            "reason": "Goodwill credit for the delayed delivery"
+        })
+    ),
+    responses(
+        (status = 200, description = "Credit issued", body = CreditResponse),
+        (status = 400, description = "Credits not enabled for the merchant or not supported by the connector")
+    ),
+    tag = "Refunds",
+    operation_id = "Create a Credit",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreditCreate))]
+// #[post("/credits")]
+pub async fn refunds_credit_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::CreditRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        credit_create_core,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
+    )
+    .await
+}
+
+/// Refunds - Credit Retrieve
+///
+/// To retrieve a credit issued without a payment to refund
+#[utoipa::path(
+    get,
+    path = "/refunds/credits/{credit_id}",
+    params(
+        ("credit_id" = String, Path, description = "The identifier for the credit")
+    ),
+    responses(
+        (status = 200, description = "Credit retrieved", body = CreditResponse),
+        (status = 404, description = "Credit does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Credit",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreditRetrieve))]
+// #[get("/credits/{credit_id}")]
+pub async fn refunds_credit_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        credit_retrieve_core,
+        &auth::ApiKeyOrJWTAuth(Permission::RefundRead),
     )
     .await
 }
@@ -0,0 +1,99 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::routing,
+    services::{api, authentication as auth, authorization::Permission},
+    types::api::RoutingConfigCreateRequest,
+};
+
+/// Routing - Create
+///
+/// To create a routing configuration. The configuration is stored on the merchant account and is used to route payments only once it is activated
+#[utoipa::path(
+    post,
+    path = "/routing",
+    request_body = RoutingConfigCreateRequest,
+    responses(
+        (status = 200, description = "Routing configuration created", body = RoutingConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Routing",
+    operation_id = "Create a Routing Configuration",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigCreate))]
+pub async fn routing_config_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<RoutingConfigCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        routing::create_routing_config,
+        &auth::ApiKeyOrJWTAuth(Permission::RoutingWrite),
+    )
+    .await
+}
+
+/// Routing - List
+///
+/// To list the routing configurations of the merchant, along with the one currently active
+#[utoipa::path(
+    get,
+    path = "/routing",
+    responses(
+        (status = 200, description = "Routing configurations retrieved", body = Vec<RoutingConfigResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Routing",
+    operation_id = "List Routing Configurations",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigList))]
+pub async fn routing_config_list(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |_, merchant_account, _| routing::list_routing_configs(merchant_account),
+        &auth::ApiKeyOrJWTAuth(Permission::RoutingRead),
+    )
+    .await
+}
+
+/// Routing - Activate
+///
+/// To activate a routing configuration, after which the merchant's payments are routed according to its rules
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_id}/activate",
+    params(
+        ("routing_id" = String, Path, description = "The identifier for the routing configuration")
+    ),
+    responses(
+        (status = 200, description = "Routing configuration activated", body = RoutingConfigResponse),
+        (status = 404, description = "Routing configuration does not exist in our records")
+    ),
+    tag = "Routing",
+    operation_id = "Activate a Routing Configuration",
+    security(("api_key" = []), ("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingConfigActivate))]
+pub async fn routing_config_activate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        routing::activate_routing_config,
+        &auth::ApiKeyOrJWTAuth(Permission::RoutingWrite),
+    )
+    .await
+}
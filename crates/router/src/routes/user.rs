@@ -0,0 +1,96 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::user,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// User - Sign Up
+///
+/// Sign up to the dashboard. A merchant account is created for the user, who is assigned the
+/// admin role for it.
+#[utoipa::path(
+    post,
+    path = "/user/signup",
+    request_body = SignUpRequest,
+    responses(
+        (status = 200, description = "User signed up", body = DashboardEntryResponse),
+        (status = 400, description = "A user with the email already exists")
+    ),
+    tag = "User",
+    operation_id = "Sign Up a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserSignUp))]
+pub async fn user_signup(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::SignUpRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| user::signup(state, payload),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+/// User - Login
+///
+/// Log in to the dashboard with the email address and password of the user.
+#[utoipa::path(
+    post,
+    path = "/user/login",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "User logged in", body = DashboardEntryResponse),
+        (status = 401, description = "Invalid email or password")
+    ),
+    tag = "User",
+    operation_id = "Log In a User"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserLogin))]
+pub async fn user_login(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::LoginRequest>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| user::login(state, payload),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+/// User - Refresh Token
+///
+/// Issue a new JWT to a dashboard user, in exchange for a JWT that has not expired yet.
+#[utoipa::path(
+    post,
+    path = "/user/refresh",
+    responses(
+        (status = 200, description = "Token refreshed", body = DashboardEntryResponse),
+        (status = 401, description = "Invalid JWT")
+    ),
+    tag = "User",
+    operation_id = "Refresh a User Token",
+    security(("jwt" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRefreshToken))]
+pub async fn user_refresh_token(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, user_from_token, _| user::refresh_token(state, user_from_token),
+        &auth::UserJWTAuth,
+    )
+    .await
+}
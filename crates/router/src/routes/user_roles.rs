@@ -0,0 +1,196 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::user_roles,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+/// User Role - Create
+///
+/// Assign a role to a dashboard user for the merchant account. The role determines the
+/// operations the user is permitted to perform.
+#[utoipa::path(
+    post,
+    path = "/user_roles/{merchant_id}",
+    params (("merchant_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = CreateUserRoleRequest,
+    responses(
+        (status = 200, description = "User role created", body = UserRoleResponse),
+        (status = 400, description = "The user already has a role for the merchant account")
+    ),
+    tag = "User Roles",
+    operation_id = "Create a User Role",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleCreate))]
+pub async fn user_role_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_types::CreateUserRoleRequest>,
+) -> impl Responder {
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| async {
+            user_roles::create_user_role(&*state.store, merchant_id.clone(), payload).await
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// User Role - Retrieve
+///
+/// Retrieve the role of a dashboard user for the merchant account.
+#[utoipa::path(
+    get,
+    path = "/user_roles/{merchant_id}/{user_id}",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("user_id" = String, Path, description = "The unique identifier for the dashboard user")
+    ),
+    responses(
+        (status = 200, description = "User role retrieved", body = UserRoleResponse),
+        (status = 404, description = "User role not found")
+    ),
+    tag = "User Roles",
+    operation_id = "Retrieve a User Role",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleRetrieve))]
+pub async fn user_role_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (merchant_id, user_id) = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (&merchant_id, &user_id),
+        |state, _, (merchant_id, user_id)| {
+            user_roles::retrieve_user_role(&*state.store, merchant_id, user_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// User Role - Update
+///
+/// Change the role of a dashboard user for the merchant account.
+#[utoipa::path(
+    post,
+    path = "/user_roles/{merchant_id}/{user_id}",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("user_id" = String, Path, description = "The unique identifier for the dashboard user")
+    ),
+    request_body = UpdateUserRoleRequest,
+    responses(
+        (status = 200, description = "User role updated", body = UserRoleResponse),
+        (status = 404, description = "User role not found")
+    ),
+    tag = "User Roles",
+    operation_id = "Update a User Role",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleUpdate))]
+pub async fn user_role_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_types::UpdateUserRoleRequest>,
+) -> impl Responder {
+    let (merchant_id, user_id) = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (&merchant_id, &user_id, json_payload.into_inner()),
+        |state, _, (merchant_id, user_id, payload)| {
+            user_roles::update_user_role(&*state.store, merchant_id, user_id, payload)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// User Role - Delete
+///
+/// Remove the role of a dashboard user for the merchant account. Once removed, the user can no
+/// longer access the merchant account.
+#[utoipa::path(
+    delete,
+    path = "/user_roles/{merchant_id}/{user_id}",
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("user_id" = String, Path, description = "The unique identifier for the dashboard user")
+    ),
+    responses(
+        (status = 200, description = "User role deleted", body = DeleteUserRoleResponse),
+        (status = 404, description = "User role not found")
+    ),
+    tag = "User Roles",
+    operation_id = "Delete a User Role",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleDelete))]
+pub async fn user_role_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (merchant_id, user_id) = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (&merchant_id, &user_id),
+        |state, _, (merchant_id, user_id)| {
+            user_roles::delete_user_role(&*state.store, merchant_id, user_id)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+/// User Role - List
+///
+/// List the roles of all dashboard users of the merchant account.
+#[utoipa::path(
+    get,
+    path = "/user_roles/{merchant_id}/list",
+    params (("merchant_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "List of user roles retrieved successfully", body = Vec<UserRoleResponse>),
+    ),
+    tag = "User Roles",
+    operation_id = "List all User Roles of a merchant account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UserRoleList))]
+pub async fn user_role_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &merchant_id,
+        |state, _, merchant_id| user_roles::list_user_roles(&*state.store, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
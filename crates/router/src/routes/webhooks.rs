@@ -27,3 +27,21 @@ pub async fn receive_incoming_webhook(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::OutgoingWebhookRetry))]
+pub async fn retry_outgoing_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let event_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        event_id,
+        |state, _, event_id| webhooks::retry_outgoing_webhook_delivery(state, event_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
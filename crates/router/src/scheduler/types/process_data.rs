@@ -11,7 +11,7 @@ pub struct ProcessData {
     process_tracker: ProcessTracker,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RetryMapping {
     pub start_after: i32,
     pub frequency: Vec<i32>,
@@ -4,8 +4,15 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod authorization_expiry;
+pub mod incoming_webhook;
+pub mod merchant_account_redaction;
+pub mod outgoing_webhook_retry;
 pub mod payment_sync;
+pub mod recon_report;
+pub mod refund_bulk;
 pub mod refund_router;
+pub mod scheduled_capture;
 
 macro_rules! runners {
     ($($body:tt),*) => {
@@ -44,7 +51,14 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    IncomingWebhookWorkflow,
+    ScheduledCaptureWorkflow,
+    AuthorizationExpiryWorkflow,
+    MerchantAccountRedactionWorkflow,
+    RefundBulkWorkflow,
+    ReconReportWorkflow
 }
 
 #[async_trait]
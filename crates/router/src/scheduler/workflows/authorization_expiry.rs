@@ -0,0 +1,125 @@
+use super::{AuthorizationExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        payments::{self as payment_flows, helpers},
+        webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: payment_flows::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The policy is read again, as the merchant may have changed it since the payment was
+        // authorized
+        let policy = match helpers::get_authorization_expiry_policy(&merchant_account)? {
+            Some(policy) if payment_intent.status == enums::IntentStatus::RequiresCapture => policy,
+            _ => {
+                let id = process.id.clone();
+                return process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await;
+            }
+        };
+
+        match policy.action {
+            api::AuthorizationExpiryAction::Void => {
+                payment_flows::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account,
+                    payment_flows::PaymentCancel,
+                    api::PaymentsCancelRequest {
+                        payment_id: tracking_data.payment_id,
+                        cancellation_reason: Some("authorization_expiring".to_string()),
+                    },
+                    services::AuthFlow::Merchant,
+                    payment_flows::CallConnectorAction::Trigger,
+                )
+                .await?;
+
+                process
+                    .finish_with_status(db, "AUTO_VOIDED".to_string())
+                    .await
+            }
+
+            api::AuthorizationExpiryAction::Notify => {
+                let payments_response =
+                    payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+                        state,
+                        merchant_account.clone(),
+                        payment_flows::PaymentStatus,
+                        api::PaymentsRetrieveRequest {
+                            resource_id: api::PaymentIdType::PaymentIntentId(
+                                tracking_data.payment_id.clone(),
+                            ),
+                            merchant_id: Some(tracking_data.merchant_id),
+                            force_sync: false,
+                            connector: None,
+                            param: None,
+                        },
+                        services::AuthFlow::Merchant,
+                        payment_flows::CallConnectorAction::Avoid,
+                    )
+                    .await?;
+
+                if let services::ApplicationResponse::Json(payments_response) = payments_response {
+                    webhooks::create_event_and_trigger_outgoing_webhook(
+                        state.clone(),
+                        merchant_account,
+                        enums::EventType::PaymentAuthorizationExpiring,
+                        enums::EventClass::Payments,
+                        Some(tracking_data.payment_id.clone()),
+                        tracking_data.payment_id,
+                        enums::EventObjectType::PaymentDetails,
+                        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                    )
+                    .await?;
+                }
+
+                process
+                    .finish_with_status(db, "EXPIRY_NOTIFIED".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,164 @@
+use redis_interface::RedisEntryId;
+
+use super::{IncomingWebhookWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks as webhooks_core,
+    db::StorageInterface,
+    errors, logger,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+/// Delay after which a webhook waiting on an earlier webhook for the same object is picked up
+/// again.
+const ORDERING_WAIT_TIME_IN_SECS: i64 = 5;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for IncomingWebhookWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: webhooks_core::IncomingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IncomingWebhookTrackingData")?;
+
+        let unfinished_processes = db
+            .find_unfinished_processes_by_tag(&tracking_data.get_ordering_key())
+            .await?;
+        if has_earlier_process(&process, &unfinished_processes) {
+            let retry_count = process.retry_count;
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(ORDERING_WAIT_TIME_IN_SECS));
+            db.update_process_tracker(
+                process,
+                storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                    status: storage::enums::ProcessTrackerStatus::Pending,
+                    retry_count,
+                    schedule_time,
+                },
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        match webhooks_core::process_incoming_webhook(
+            state.clone(),
+            merchant_account,
+            tracking_data,
+        )
+        .await
+        {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed while processing incoming webhook");
+                match webhooks_core::get_incoming_webhook_retry_schedule_time(
+                    &state.conf.webhooks,
+                    process.retry_count + 1,
+                ) {
+                    Some(schedule_time) => process.retry(db, schedule_time).await,
+                    None => dead_letter_process(state, process, error.to_string()).await,
+                }
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Checks whether a webhook for the same object was received before the one being processed and
+/// has not been processed yet.
+fn has_earlier_process(
+    process: &storage::ProcessTracker,
+    unfinished_processes: &[storage::ProcessTracker],
+) -> bool {
+    unfinished_processes.iter().any(|unfinished_process| {
+        (unfinished_process.created_at, &unfinished_process.id) < (process.created_at, &process.id)
+    })
+}
+
+async fn dead_letter_process(
+    state: &AppState,
+    process: storage::ProcessTracker,
+    error: String,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let dead_letter_entry = vec![
+        ("process_id", process.id.clone()),
+        ("tracking_data", process.tracking_data.to_string()),
+        ("error", error),
+    ];
+
+    // The process is finished regardless, as its details are still kept in the process tracker
+    if let Err(error) = db
+        .stream_append_entry(
+            &state.conf.webhooks.incoming_dead_letter_stream,
+            &RedisEntryId::AutoGeneratedID,
+            dead_letter_entry,
+        )
+        .await
+    {
+        logger::error!(?error, process_id = %process.id, "Failed to dead letter incoming webhook");
+    }
+
+    process
+        .finish_with_status(db, "DEAD_LETTERED".to_string())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    fn get_process(id: &str, created_at: time::PrimitiveDateTime) -> storage::ProcessTracker {
+        storage::ProcessTracker {
+            id: id.to_string(),
+            name: Some("INCOMING_WEBHOOK".to_string()),
+            tag: vec!["WEBHOOK".to_string()],
+            runner: Some("INCOMING_WEBHOOK_WORKFLOW".to_string()),
+            retry_count: 0,
+            schedule_time: Some(created_at),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: "Pending".to_string(),
+            status: storage::enums::ProcessTrackerStatus::ProcessStarted,
+            event: vec![],
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_incoming_webhooks_are_processed_in_order() {
+        let now = common_utils::date_time::now();
+        let earlier = get_process("earlier", now);
+        let later = get_process("later", now.saturating_add(time::Duration::seconds(1)));
+        let unfinished_processes = vec![earlier.clone(), later.clone()];
+
+        assert!(!has_earlier_process(&earlier, &unfinished_processes));
+        assert!(has_earlier_process(&later, &unfinished_processes));
+        assert!(!has_earlier_process(&later, &[later.clone()]));
+    }
+}
@@ -0,0 +1,55 @@
+use super::{MerchantAccountRedactionWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{admin, customers},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MerchantAccountRedactionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: admin::MerchantAccountRedactionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantAccountRedactionTrackingData")?;
+
+        let merchant_account = db
+            .find_deleted_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        // The customers redacted before the account was closed, or by an earlier run of the
+        // workflow, are left as they are
+        let customers_to_redact = db
+            .list_customers_by_merchant_id(&merchant_account.merchant_id)
+            .await?
+            .into_iter()
+            .filter(|customer| customer.name.as_deref() != Some(customers::REDACTED));
+
+        for customer in customers_to_redact {
+            customers::redact_customer(state, &merchant_account, customer).await?;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,112 @@
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks as webhooks_core,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: webhooks_core::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let last_delivery_attempt = db
+            .find_webhook_delivery_attempts_by_event_id(&tracking_data.event_id)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+        if last_delivery_attempt.delivery_status == enums::WebhookDeliveryStatus::Delivered {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let delivery_attempt = webhooks_core::deliver_webhook_to_merchant(
+            state,
+            &merchant_account,
+            &tracking_data.event_id,
+            last_delivery_attempt.request_body,
+            last_delivery_attempt.attempt_number + 1,
+        )
+        .await?;
+
+        match delivery_attempt.delivery_status {
+            enums::WebhookDeliveryStatus::Delivered => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            enums::WebhookDeliveryStatus::Failed => {
+                match webhooks_core::get_outgoing_webhook_retry_schedule_time(
+                    &state.conf.webhooks,
+                    delivery_attempt.attempt_number,
+                ) {
+                    Some(schedule_time) => process.retry(db, schedule_time).await,
+                    None => {
+                        process
+                            .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                            .await
+                    }
+                }
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::configs::settings;
+
+    #[test]
+    fn test_outgoing_webhook_retry_backoff() {
+        let webhooks_settings = settings::WebhooksSettings {
+            outgoing_enabled: true,
+            outgoing_max_delivery_attempts: 3,
+            outgoing_retry_base_delay_in_secs: 60,
+            ..Default::default()
+        };
+
+        let first_retry =
+            webhooks_core::get_outgoing_webhook_retry_schedule_time(&webhooks_settings, 1).unwrap();
+        let second_retry =
+            webhooks_core::get_outgoing_webhook_retry_schedule_time(&webhooks_settings, 2).unwrap();
+        let delay_difference = (second_retry - first_retry).whole_seconds();
+
+        assert!((59..=61).contains(&delay_difference));
+        assert!(
+            webhooks_core::get_outgoing_webhook_retry_schedule_time(&webhooks_settings, 3)
+                .is_none()
+        );
+    }
+}
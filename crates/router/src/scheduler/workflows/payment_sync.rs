@@ -2,6 +2,7 @@ use router_env::logger;
 
 use super::{PaymentsSyncWorkflow, ProcessTrackerWorkflow};
 use crate::{
+    configs::settings,
     core::payments::{self as payment_flows, operations},
     db::{get_and_deserialize_key, StorageInterface},
     errors,
@@ -9,7 +10,7 @@ use crate::{
     scheduler::{consumer, process_data, utils},
     types::{
         api,
-        storage::{self, enums, ProcessTrackerExt},
+        storage::{self, ProcessTrackerExt},
     },
     utils::{OptionExt, ValueExt},
 };
@@ -45,32 +46,29 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
         )
         .await?;
 
-        let terminal_status = vec![
-            enums::AttemptStatus::RouterDeclined,
-            enums::AttemptStatus::Charged,
-            enums::AttemptStatus::AutoRefunded,
-            enums::AttemptStatus::Voided,
-            enums::AttemptStatus::VoidFailed,
-            enums::AttemptStatus::CaptureFailed,
-            enums::AttemptStatus::Failure,
-        ];
-        match &payment_data.payment_attempt.status {
-            status if terminal_status.contains(status) => {
+        let payment_sync_settings = &state.conf.payment_sync;
+        let payment_attempt = payment_data.payment_attempt;
+        match &payment_attempt.status {
+            status if !payment_sync_settings.sync_statuses.contains(status) => {
                 let id = process.id.clone();
                 process
                     .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
                     .await?
             }
+            _ if is_sync_cutoff_reached(payment_sync_settings, payment_attempt.created_at) => {
+                process
+                    .finish_with_status(db, "SYNC_CUTOFF_REACHED".to_string())
+                    .await?
+            }
             _ => {
-                let connector = payment_data
-                    .payment_attempt
+                let connector = payment_attempt
                     .connector
-                    .clone()
                     .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
                 retry_sync_task(
                     db,
+                    payment_sync_settings,
                     connector,
-                    payment_data.payment_attempt.merchant_id,
+                    payment_attempt.merchant_id,
                     process,
                 )
                 .await?
@@ -89,8 +87,32 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
     }
 }
 
+/// Checks whether a payment attempt is too old to keep syncing it with the connector.
+pub fn is_sync_cutoff_reached(
+    payment_sync_settings: &settings::PaymentSyncSettings,
+    created_at: time::PrimitiveDateTime,
+) -> bool {
+    common_utils::date_time::now() - created_at
+        > time::Duration::seconds(payment_sync_settings.max_sync_age_in_secs)
+}
+
+/// Returns the retry schedule of a connector given in the configuration, or the default one.
+pub fn get_configured_pt_mapping(
+    payment_sync_settings: &settings::PaymentSyncSettings,
+    connector: &str,
+) -> process_data::ConnectorPTMapping {
+    match payment_sync_settings.connector_schedules.get(connector) {
+        Some(retry_mapping) => process_data::ConnectorPTMapping {
+            default_mapping: retry_mapping.clone(),
+            ..Default::default()
+        },
+        None => process_data::ConnectorPTMapping::default(),
+    }
+}
+
 pub async fn get_sync_process_schedule_time(
     db: &dyn StorageInterface,
+    payment_sync_settings: &settings::PaymentSyncSettings,
     connector: &str,
     merchant_id: &str,
     retry_count: i32,
@@ -101,7 +123,7 @@ pub async fn get_sync_process_schedule_time(
         Ok(x) => x,
         Err(err) => {
             logger::info!("Redis Mapping Error: {}", err);
-            process_data::ConnectorPTMapping::default()
+            get_configured_pt_mapping(payment_sync_settings, connector)
         }
     };
     let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
@@ -111,12 +133,19 @@ pub async fn get_sync_process_schedule_time(
 
 pub async fn retry_sync_task(
     db: &dyn StorageInterface,
+    payment_sync_settings: &settings::PaymentSyncSettings,
     connector: String,
     merchant_id: String,
     pt: storage::ProcessTracker,
 ) -> Result<(), errors::ProcessTrackerError> {
-    let schedule_time =
-        get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
+    let schedule_time = get_sync_process_schedule_time(
+        db,
+        payment_sync_settings,
+        &connector,
+        &merchant_id,
+        pt.retry_count,
+    )
+    .await?;
 
     match schedule_time {
         Some(s_time) => pt.retry(db, s_time).await,
@@ -144,4 +173,57 @@ mod tests {
             vec![cpt_default.start_after, cpt_default.frequency[0]]
         );
     }
+
+    #[test]
+    fn test_get_configured_schedule_time() {
+        let payment_sync_settings = settings::PaymentSyncSettings {
+            connector_schedules: std::collections::HashMap::from([(
+                "adyen".to_string(),
+                process_data::RetryMapping {
+                    start_after: 30,
+                    frequency: vec![600, 3600],
+                    count: vec![2, 1],
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let third_retry_time_delta = utils::get_schedule_time(
+            get_configured_pt_mapping(&payment_sync_settings, "adyen"),
+            "-",
+            3,
+        );
+        let fourth_retry_time_delta = utils::get_schedule_time(
+            get_configured_pt_mapping(&payment_sync_settings, "adyen"),
+            "-",
+            4,
+        );
+        let default_first_retry_time_delta = utils::get_schedule_time(
+            get_configured_pt_mapping(&payment_sync_settings, "stripe"),
+            "-",
+            1,
+        );
+
+        assert_eq!(third_retry_time_delta, Some(3600));
+        assert_eq!(fourth_retry_time_delta, None);
+        assert_eq!(default_first_retry_time_delta, Some(300));
+    }
+
+    #[test]
+    fn test_sync_cutoff() {
+        let payment_sync_settings = settings::PaymentSyncSettings {
+            max_sync_age_in_secs: 3600,
+            ..Default::default()
+        };
+        let now = common_utils::date_time::now();
+
+        assert!(!is_sync_cutoff_reached(
+            &payment_sync_settings,
+            now - time::Duration::minutes(30)
+        ));
+        assert!(is_sync_cutoff_reached(
+            &payment_sync_settings,
+            now - time::Duration::hours(2)
+        ));
+    }
 }
@@ -0,0 +1,75 @@
+use super::{ProcessTrackerWorkflow, RefundBulkWorkflow};
+use crate::{
+    core::refunds,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::{Encode, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for RefundBulkWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: refunds::RefundBulkTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RefundBulkTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        // The outcome of every refund is saved as soon as it is known, a run picking up the batch
+        // again starts after the refunds initiated by the previous one
+        let pending_refunds = tracking_data
+            .refunds
+            .iter()
+            .skip(tracking_data.results.len())
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut process = process;
+        for refund in pending_refunds {
+            let result = refunds::initiate_refund_of_batch(state, &merchant_account, refund).await;
+            tracking_data.results.push(result);
+            process = db
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(
+                            Encode::<refunds::RefundBulkTrackingData>::encode_to_value(
+                                &tracking_data,
+                            )?,
+                        ),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -23,3 +23,26 @@ impl ProcessTrackerWorkflow for RefundWorkflowRouter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::configs::settings;
+
+    #[test]
+    fn test_refund_sync_backoff() {
+        let refund_settings = settings::Refund {
+            sync_max_attempts: 3,
+            sync_base_delay_in_secs: 60,
+            ..Default::default()
+        };
+
+        let first_sync = refund_flow::get_refund_sync_schedule_time(&refund_settings, 0).unwrap();
+        let second_sync = refund_flow::get_refund_sync_schedule_time(&refund_settings, 1).unwrap();
+        let delay_difference = (second_sync - first_sync).whole_seconds();
+
+        assert!((59..=61).contains(&delay_difference));
+        assert!(refund_flow::get_refund_sync_schedule_time(&refund_settings, 2).is_none());
+    }
+}
@@ -0,0 +1,178 @@
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, ScheduledCaptureWorkflow};
+use crate::{
+    configs::settings,
+    core::payments as payment_flows,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: payment_flows::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        let now = common_utils::date_time::now();
+        let is_expired = payment_intent
+            .capture_expires_at
+            .map_or(false, |capture_expires_at| now >= capture_expires_at);
+
+        match payment_intent.status {
+            enums::IntentStatus::RequiresCapture if is_expired => {
+                payment_flows::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account,
+                    payment_flows::PaymentCancel,
+                    api::PaymentsCancelRequest {
+                        payment_id: tracking_data.payment_id,
+                        cancellation_reason: Some("scheduled_capture_expired".to_string()),
+                    },
+                    services::AuthFlow::Merchant,
+                    payment_flows::CallConnectorAction::Trigger,
+                )
+                .await?;
+
+                process
+                    .finish_with_status(db, "AUTO_VOIDED".to_string())
+                    .await
+            }
+
+            enums::IntentStatus::RequiresCapture => {
+                let capture_response =
+                    payment_flows::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+                        state,
+                        merchant_account,
+                        payment_flows::PaymentCapture,
+                        api::PaymentsCaptureRequest {
+                            payment_id: Some(tracking_data.payment_id),
+                            merchant_id: Some(tracking_data.merchant_id),
+                            ..Default::default()
+                        },
+                        services::AuthFlow::Merchant,
+                        payment_flows::CallConnectorAction::Trigger,
+                    )
+                    .await;
+
+                match capture_response {
+                    Ok(_) => {
+                        let id = process.id.clone();
+                        process
+                            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                            .await
+                    }
+                    Err(error) => {
+                        logger::error!(?error, "Scheduled capture failed");
+                        let schedule_time = get_scheduled_capture_retry_time(
+                            &state.conf.scheduled_capture,
+                            payment_intent.capture_expires_at,
+                        );
+                        process.retry(db, schedule_time).await
+                    }
+                }
+            }
+
+            // The payment is still being authorized, so capturing it is attempted again later
+            enums::IntentStatus::RequiresCustomerAction | enums::IntentStatus::Processing
+                if !is_expired =>
+            {
+                let schedule_time = get_scheduled_capture_retry_time(
+                    &state.conf.scheduled_capture,
+                    payment_intent.capture_expires_at,
+                );
+                process.retry(db, schedule_time).await
+            }
+
+            enums::IntentStatus::RequiresCustomerAction | enums::IntentStatus::Processing => {
+                process
+                    .finish_with_status(db, "CAPTURE_EXPIRED".to_string())
+                    .await
+            }
+
+            // The payment has been captured, cancelled or has failed in the meantime
+            _ => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Returns the time at which a scheduled capture is attempted again, which is no later than the
+/// expiry of the capture so that the authorization can be voided in time.
+fn get_scheduled_capture_retry_time(
+    scheduled_capture_settings: &settings::ScheduledCaptureSettings,
+    capture_expires_at: Option<time::PrimitiveDateTime>,
+) -> time::PrimitiveDateTime {
+    let retry_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        scheduled_capture_settings.retry_interval_in_secs,
+    ));
+
+    match capture_expires_at {
+        Some(capture_expires_at) => retry_time.min(capture_expires_at),
+        None => retry_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_scheduled_capture_retry_is_capped_at_expiry() {
+        let scheduled_capture_settings = settings::ScheduledCaptureSettings {
+            expiry_in_secs: 86400,
+            retry_interval_in_secs: 900,
+        };
+        let capture_expires_at =
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(60));
+
+        let retry_time =
+            get_scheduled_capture_retry_time(&scheduled_capture_settings, Some(capture_expires_at));
+        let uncapped_retry_time =
+            get_scheduled_capture_retry_time(&scheduled_capture_settings, None);
+
+        assert_eq!(retry_time, capture_expires_at);
+        assert!(uncapped_retry_time > capture_expires_at);
+    }
+}
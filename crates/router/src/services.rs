@@ -1,15 +1,21 @@
 pub mod api;
 pub mod authentication;
-#[cfg(feature = "basilisk")]
+pub mod authorization;
+#[cfg(any(feature = "basilisk", feature = "internal_locker"))]
 pub mod encryption;
+#[cfg(feature = "kafka_events")]
+pub mod kafka;
 pub mod logger;
+pub mod rate_limiter;
+pub mod secrets_manager;
 
 use std::sync::{atomic, Arc};
 
+use common_utils::secrets_manager::SecretsManager;
 use redis_interface::{errors::RedisError, PubSubInterface};
 
 pub use self::api::*;
-#[cfg(feature = "basilisk")]
+#[cfg(any(feature = "basilisk", feature = "internal_locker"))]
 pub use self::encryption::*;
 use crate::{
     async_spawn,
@@ -26,6 +32,7 @@ pub struct Store {
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
+    pub(crate) secrets_manager: Option<Arc<dyn SecretsManager>>,
 }
 
 #[cfg(feature = "kv_store")]
@@ -64,6 +71,7 @@ impl Store {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
             },
+            secrets_manager: secrets_manager::get_secrets_manager(&config.secrets_manager).await,
         }
     }
 
@@ -2,6 +2,7 @@ mod client;
 pub(crate) mod request;
 
 use std::{
+    any::TypeId,
     collections::HashMap,
     fmt::Debug,
     future::Future,
@@ -9,25 +10,32 @@ use std::{
     time::{Duration, Instant},
 };
 
-use actix_web::{body, HttpRequest, HttpResponse, Responder};
+use actix_web::{
+    body,
+    http::header::{HeaderName, HeaderValue},
+    HttpRequest, HttpResponse, Responder,
+};
+use api_models::api_version::{ApiVersion, VersionedResponse};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::{report, IntoReport, Report, ResultExt};
 use masking::ExposeOptionInterface;
-use router_env::{instrument, tracing, Tag};
+use router_env::{instrument, opentelemetry::KeyValue, tracing, Tag};
 use serde::Serialize;
 
+pub(crate) use self::client::get_timeout;
 use self::request::{ContentType, HeaderExt, RequestBuilderExt};
 pub use self::request::{Method, Request, RequestBuilder};
 use crate::{
     configs::settings::Connectors,
     core::{
+        audit_logs, connector_health, connector_payloads,
         errors::{self, CustomResult, RouterResult},
         payments,
     },
     db::StorageInterface,
-    logger,
-    routes::{app::AppStateInfo, AppState},
-    services::authentication as auth,
+    headers, logger,
+    routes::{app::AppStateInfo, metrics, AppState},
+    services::{authentication as auth, rate_limiter},
     types::{
         self,
         api::{self},
@@ -187,11 +195,69 @@ where
             match connector_integration.build_request(req, &state.conf.connectors)? {
                 Some(request) => {
                     logger::debug!(connector_request=?request);
-                    let response = call_connector_api(state, request).await;
+                    let connector_request_payload =
+                        if connector_payloads::is_capture_enabled(&*state.store, &req.merchant_id)
+                            .await
+                        {
+                            Some(connector_payloads::mask_request(
+                                &request,
+                                get_flow_name::<T>(),
+                            ))
+                        } else {
+                            None
+                        };
+
+                    let start_instant = Instant::now();
+                    let response = call_connector_api_with_retries(
+                        state,
+                        request,
+                        &req.connector,
+                        is_retriable_flow::<T>(),
+                    )
+                    .await;
                     logger::debug!(connector_response=?response);
+
+                    let latency = start_instant.elapsed();
+
+                    let (outcome, is_failure) = match &response {
+                        Ok(Ok(_)) => ("success", false),
+                        Ok(Err(body)) => ("error_response", body.status_code >= 500),
+                        Err(_) => ("failure", true),
+                    };
+                    connector_health::record_connector_call(
+                        &state.conf.connector_health,
+                        &req.connector,
+                        is_failure,
+                        latency,
+                    );
+
+                    let connector_attributes = [
+                        KeyValue::new("connector", req.connector.clone()),
+                        KeyValue::new("flow", get_flow_name::<T>()),
+                        KeyValue::new("outcome", outcome),
+                    ];
+                    metrics::CONNECTOR_CALL_COUNT.add(&metrics::CONTEXT, 1, &connector_attributes);
+                    metrics::CONNECTOR_REQUEST_TIME.record(
+                        &metrics::CONTEXT,
+                        latency.as_secs_f64(),
+                        &connector_attributes,
+                    );
+
+                    if let Some(connector_request_payload) = connector_request_payload {
+                        connector_payloads::record_connector_payloads(
+                            &*state.store,
+                            &req.merchant_id,
+                            &req.payment_id,
+                            &req.attempt_id,
+                            connector_request_payload,
+                            connector_payloads::mask_response(&response),
+                        )
+                        .await;
+                    }
+
                     match response {
                         Ok(body) => {
-                            let response = match body {
+                            let mut response = match body {
                                 Ok(body) => connector_integration.handle_response(req, body)?,
                                 Err(body) => {
                                     let error = connector_integration.get_error_response(body)?;
@@ -200,6 +266,7 @@ where
                                     router_data
                                 }
                             };
+                            response.connector_latency = Some(latency);
                             Ok(response)
                         }
                         Err(error) => Err(error
@@ -212,14 +279,86 @@ where
     }
 }
 
+/// Name of the flow type, without its module path, to be used as a metric attribute.
+fn get_flow_name<F>() -> &'static str {
+    std::any::type_name::<F>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+}
+
+/// Syncs only read the state of a payment or a refund at the connector, so sending them again
+/// can't make the connector act twice.
+fn is_retriable_flow<F: 'static>() -> bool {
+    let flow = TypeId::of::<F>();
+    flow == TypeId::of::<api::PSync>() || flow == TypeId::of::<api::RSync>()
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &AppState,
     request: Request,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    let timeout = Duration::from_secs(state.conf.connector_request.timeout_in_secs);
+    send_and_handle_request(state, &request, None, timeout).await
+}
+
+/// Calls the API of a connector with the timeout configured for the connector. Requests of
+/// retriable flows are sent again after network failures, timeouts and 5xx responses, as
+/// configured in the connector request settings.
+#[instrument(skip_all)]
+async fn call_connector_api_with_retries(
+    state: &AppState,
+    request: Request,
+    connector: &str,
+    is_retriable: bool,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    let request_settings = &state.conf.connector_request;
+    let timeout = client::get_timeout(request_settings, connector);
+    let max_retries = if is_retriable {
+        request_settings.retries.max_retries
+    } else {
+        0
+    };
+
+    let mut retry_count = 0;
+    loop {
+        let response = send_and_handle_request(state, &request, Some(connector), timeout).await;
+        let should_retry = match &response {
+            Ok(Ok(_)) => false,
+            Ok(Err(body)) => body.status_code >= 500,
+            Err(error) => matches!(
+                error.current_context(),
+                errors::ApiClientError::RequestTimeoutReceived
+                    | errors::ApiClientError::RequestNotSent(_)
+                    | errors::ApiClientError::ConnectionClosed(_)
+            ),
+        };
+        if !should_retry || retry_count >= max_retries {
+            return response;
+        }
+
+        let delay = client::get_retry_delay(&request_settings.retries, retry_count);
+        retry_count += 1;
+        logger::warn!(connector, retry_count, ?delay, "Retrying connector request");
+        metrics::CONNECTOR_REQUEST_RETRY_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[KeyValue::new("connector", connector.to_string())],
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+async fn send_and_handle_request(
+    state: &AppState,
+    request: &Request,
+    connector: Option<&str>,
+    timeout: Duration,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
 
-    let response = send_request(state, request).await;
+    let response = send_request(state, request, connector, timeout).await;
 
     let elapsed_time = current_time.elapsed();
     logger::info!(request_time=?elapsed_time);
@@ -230,18 +369,36 @@ pub async fn call_connector_api(
 #[instrument(skip_all)]
 async fn send_request(
     state: &AppState,
-    request: Request,
+    request: &Request,
+    connector: Option<&str>,
+    timeout: Duration,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
     logger::debug!(method=?request.method, headers=?request.headers, payload=?request.payload, ?request);
     let url = &request.url;
-    let should_bypass_proxy = client::proxy_bypass_urls(&state.conf.locker).contains(url);
-    let client = client::create_client(
-        &state.conf.proxy,
-        should_bypass_proxy,
-        request.certificate,
-        request.certificate_key,
-    )?;
-    let headers = request.headers.construct_header_map()?;
+    let connector_client = match connector {
+        // A certificate of the merchant sent with the request takes precedence over the client
+        // settings of the connector
+        Some(connector) if request.certificate.is_none() => client::get_connector_client(
+            &state.conf.connectors,
+            &state.conf.connector_request,
+            connector,
+        )?,
+        _ => None,
+    };
+    let client = match connector_client {
+        Some(client) => client,
+        None => {
+            let should_bypass_proxy = client::proxy_bypass_urls(&state.conf.locker).contains(url);
+            client::create_client(
+                &state.conf.proxy,
+                &state.conf.connector_request,
+                should_bypass_proxy,
+                request.certificate.clone(),
+                request.certificate_key.clone(),
+            )?
+        }
+    };
+    let headers = request.headers.clone().construct_header_map()?;
     match request.method {
         Method::Get => client.get(url),
         Method::Post => {
@@ -266,24 +423,27 @@ async fn send_request(
                     client.body(url_encoded_payload)
                 }
                 // If payload needs processing the body cannot have default
-                None => client.body(request.payload.expose_option().unwrap_or_default()),
+                None => client.body(request.payload.clone().expose_option().unwrap_or_default()),
             }
         }
 
         Method::Put => {
             client
                 .put(url)
-                .body(request.payload.expose_option().unwrap_or_default()) // If payload needs processing the body cannot have default
+                .body(request.payload.clone().expose_option().unwrap_or_default())
+            // If payload needs processing the body cannot have default
         }
         Method::Delete => client.delete(url),
     }
     .add_headers(headers)
-    .timeout(Duration::from_secs(crate::consts::REQUEST_TIME_OUT))
+    .timeout(timeout)
     .send()
     .await
     .map_err(|error| match error {
         error if error.is_timeout() => errors::ApiClientError::RequestTimeoutReceived,
-        _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+        // Only a failure to connect guarantees that the connector never received the request
+        error if error.is_connect() => errors::ApiClientError::RequestNotSent(error.to_string()),
+        _ => errors::ApiClientError::ConnectionClosed(error.to_string()),
     })
     .into_report()
     .attach_printable("Unable to send request to connector")
@@ -437,15 +597,43 @@ where
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
+    U: auth::AuthInfo,
     A: AppStateInfo,
     CustomResult<ApplicationResponse<Q>, E>: ReportSwitchExt<ApplicationResponse<Q>, OErr>,
     CustomResult<U, errors::ApiErrorResponse>: ReportSwitchExt<U, OErr>,
+    CustomResult<(), errors::ApiErrorResponse>: ReportSwitchExt<(), OErr>,
 {
-    let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), state)
-        .await
-        .switch()?;
-    func(state, auth_out, payload).await.switch()
+    let audit_context = audit_logs::AuditContext::new(request.method().as_str(), request.path());
+
+    audit_logs::with_audit_context(audit_context, async {
+        let auth_out: CustomResult<U, OErr> = api_auth
+            .authenticate_and_fetch(request.headers(), state)
+            .await
+            .switch();
+
+        let auth_out = match auth_out {
+            Ok(auth_out) => auth_out,
+            Err(error) => return Err(error),
+        };
+
+        let rate_limit_out: CustomResult<(), OErr> = match auth_out.get_merchant_id() {
+            Some(merchant_id) => rate_limiter::check_merchant_rate_limit(
+                &*state.store(),
+                &state.conf().rate_limit,
+                merchant_id,
+                request.path(),
+            )
+            .await
+            .switch(),
+            None => Ok(()),
+        };
+
+        match rate_limit_out {
+            Ok(()) => func(state, auth_out, payload).await.switch(),
+            Err(error) => Err(error),
+        }
+    })
+    .await
 }
 
 #[instrument(
@@ -464,6 +652,7 @@ where
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
+    U: auth::AuthInfo,
     A: AppStateInfo,
     CustomResult<ApplicationResponse<Q>, E>:
         ReportSwitchExt<ApplicationResponse<Q>, api_models::errors::types::ApiErrorResponse>,
@@ -517,9 +706,109 @@ where
         time_taken_ms = request_duration.as_millis(),
     );
 
+    let request_attributes = [
+        KeyValue::new(
+            "route",
+            request
+                .match_pattern()
+                .unwrap_or_else(|| String::from("unknown")),
+        ),
+        KeyValue::new("method", request_method.to_owned()),
+        KeyValue::new("status_code", i64::from(response_code)),
+    ];
+    metrics::REQUESTS_RECEIVED.add(&metrics::CONTEXT, 1, &request_attributes);
+    metrics::REQUEST_TIME.record(
+        &metrics::CONTEXT,
+        request_duration.as_secs_f64(),
+        &request_attributes,
+    );
+
     res
 }
 
+/// The version of the API requested with the `X-Api-Version` header, the latest one when the
+/// request does not specify it
+pub fn get_api_version(request: &HttpRequest) -> RouterResult<ApiVersion> {
+    match request.headers().get(headers::X_API_VERSION) {
+        Some(version) => version
+            .to_str()
+            .ok()
+            .and_then(|version| version.parse().ok())
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "{} must be a version of the API, such as {}",
+                        headers::X_API_VERSION,
+                        ApiVersion::LATEST
+                    ),
+                })
+            }),
+        None => Ok(ApiVersion::LATEST),
+    }
+}
+
+/// Same as [`server_wrap`], serializing the response in the schema of the version of the API
+/// requested with the `X-Api-Version` header. The version the response is in is echoed in the
+/// same header.
+pub async fn versioned_server_wrap<'a, 'b, A, T, U, Q, F, Fut>(
+    state: &'b A,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+) -> HttpResponse
+where
+    F: Fn(&'b A, U, T) -> Fut,
+    Fut: Future<Output = RouterResult<ApplicationResponse<Q>>>,
+    Q: VersionedResponse + Debug,
+    T: Debug,
+    U: auth::AuthInfo,
+    A: AppStateInfo,
+{
+    let api_version = match get_api_version(request) {
+        Ok(api_version) => api_version,
+        Err(error) => return log_and_return_error_response(error),
+    };
+
+    let mut response = server_wrap(
+        state,
+        request,
+        payload,
+        |state, auth, payload| {
+            let response = func(state, auth, payload);
+            async move {
+                match response.await? {
+                    ApplicationResponse::Json(response) => response
+                        .to_versioned_value(api_version)
+                        .into_report()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable_lazy(|| {
+                            format!("Failed to serialize the response in API version {api_version}")
+                        })
+                        .map(ApplicationResponse::Json),
+                    ApplicationResponse::StatusOk => Ok(ApplicationResponse::StatusOk),
+                    ApplicationResponse::TextPlain(text) => {
+                        Ok(ApplicationResponse::TextPlain(text))
+                    }
+                    ApplicationResponse::JsonForRedirection(response) => {
+                        Ok(ApplicationResponse::JsonForRedirection(response))
+                    }
+                    ApplicationResponse::Form(form) => Ok(ApplicationResponse::Form(form)),
+                }
+            }
+        },
+        api_auth,
+    )
+    .await;
+
+    if let Ok(header_name) = HeaderName::try_from(headers::X_API_VERSION) {
+        response
+            .headers_mut()
+            .insert(header_name, HeaderValue::from_static(api_version.into()));
+    }
+    response
+}
+
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
 where
     T: actix_web::ResponseError + error_stack::Context + Clone,
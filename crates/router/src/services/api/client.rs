@@ -1,9 +1,15 @@
+use std::{collections::HashMap, time::Duration};
+
 use base64::Engine;
 use error_stack::{IntoReport, ResultExt};
 use once_cell::sync::OnceCell;
+use rand::Rng;
 
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{
+        ConnectorClientSettings, ConnectorRequestRetries, ConnectorRequestSettings, Connectors,
+        Locker, Proxy,
+    },
     consts,
     core::errors::{self, CustomResult},
 };
@@ -14,6 +20,7 @@ const HTTPS_PROXY: &str = "ROUTER_HTTPS_PROXY";
 static PLAIN_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static HTTPS_PROXY_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static HTTP_PROXY_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+static CONNECTOR_CLIENTS: OnceCell<HashMap<String, reqwest::Client>> = OnceCell::new();
 
 enum ProxyType {
     Http,
@@ -35,8 +42,18 @@ impl ProxyType {
     }
 }
 
+fn get_client_builder(request_settings: &ConnectorRequestSettings) -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .pool_idle_timeout(Duration::from_secs(
+            request_settings.pool_idle_timeout_in_secs,
+        ))
+        .pool_max_idle_per_host(request_settings.pool_max_idle_per_host)
+}
+
 fn create_base_client(
     proxy: Option<(ProxyType, String)>,
+    request_settings: &ConnectorRequestSettings,
 ) -> CustomResult<reqwest::Client, errors::ApiClientError> {
     Ok(match proxy {
         None => &PLAIN_CLIENT,
@@ -44,7 +61,7 @@ fn create_base_client(
         Some((ProxyType::Https, _)) => &HTTPS_PROXY_CLIENT,
     }
     .get_or_try_init(|| {
-        let mut cb = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+        let mut cb = get_client_builder(request_settings);
         cb = match proxy {
             None => cb,
             Some((proxy_type, url)) => cb.proxy(
@@ -69,13 +86,14 @@ fn create_base_client(
 // Precedence will be the environment variables, followed by the config.
 pub(super) fn create_client(
     proxy: &Proxy,
+    request_settings: &ConnectorRequestSettings,
     should_bypass_proxy: bool,
     client_certificate: Option<String>,
     client_certificate_key: Option<String>,
 ) -> CustomResult<reqwest::Client, errors::ApiClientError> {
     if client_certificate.is_none() && client_certificate_key.is_none() {
         return match should_bypass_proxy {
-            true => create_base_client(None),
+            true => create_base_client(None, request_settings),
             false => create_base_client(
                 ProxyType::Https
                     .get_proxy_url(proxy)
@@ -85,31 +103,107 @@ pub(super) fn create_client(
                             .get_proxy_url(proxy)
                             .map(|url| (ProxyType::Http, url))
                     }),
+                request_settings,
             ),
         };
     }
-    let mut client_builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+    let mut client_builder = get_client_builder(request_settings);
 
     if !should_bypass_proxy {
-        if let Some(url) = ProxyType::Http.get_proxy_url(proxy) {
-            client_builder = client_builder.proxy(
-                reqwest::Proxy::http(url)
-                    .into_report()
-                    .change_context(errors::ApiClientError::InvalidProxyConfiguration)
-                    .attach_printable_lazy(|| "HTTP proxy configuration error")?,
-            );
-        }
-        if let Some(url) = ProxyType::Https.get_proxy_url(proxy) {
-            client_builder = client_builder.proxy(
-                reqwest::Proxy::https(url)
-                    .into_report()
-                    .change_context(errors::ApiClientError::InvalidProxyConfiguration)
-                    .attach_printable_lazy(|| "HTTPS proxy configuration error")?,
-            );
-        }
+        client_builder = add_proxies(
+            client_builder,
+            ProxyType::Http.get_proxy_url(proxy),
+            ProxyType::Https.get_proxy_url(proxy),
+        )?;
+    }
+
+    add_client_certificate(client_builder, client_certificate, client_certificate_key)?
+        .build()
+        .into_report()
+        .change_context(errors::ApiClientError::ClientConstructionFailed)
+        .attach_printable_lazy(|| "Error with client library")
+}
+
+/// Returns the client of a connector which has client settings of its own. The clients of all such
+/// connectors are built on first use, so that their connections are pooled like the ones of the
+/// other clients.
+pub(super) fn get_connector_client(
+    connectors: &Connectors,
+    request_settings: &ConnectorRequestSettings,
+    connector: &str,
+) -> CustomResult<Option<reqwest::Client>, errors::ApiClientError> {
+    let connector_clients = CONNECTOR_CLIENTS.get_or_try_init(|| {
+        connectors
+            .client_settings
+            .iter()
+            .map(|(connector, client_settings)| {
+                create_connector_client(client_settings, request_settings)
+                    .attach_printable_lazy(|| format!("Error with client of {connector}"))
+                    .map(|client| (connector.to_owned(), client))
+            })
+            .collect::<CustomResult<HashMap<_, _>, _>>()
+    })?;
+
+    Ok(connector_clients.get(connector).cloned())
+}
+
+fn create_connector_client(
+    client_settings: &ConnectorClientSettings,
+    request_settings: &ConnectorRequestSettings,
+) -> CustomResult<reqwest::Client, errors::ApiClientError> {
+    let mut client_builder = get_client_builder(request_settings);
+
+    // The proxy of the connector is used as configured, the proxy environment variables being
+    // meant for the proxy of the application
+    if let Some(proxy) = &client_settings.proxy {
+        client_builder = add_proxies(
+            client_builder,
+            proxy.http_url.clone(),
+            proxy.https_url.clone(),
+        )?;
+    }
+
+    add_client_certificate(
+        client_builder,
+        client_settings.client_certificate.clone(),
+        client_settings.client_certificate_key.clone(),
+    )?
+    .build()
+    .into_report()
+    .change_context(errors::ApiClientError::ClientConstructionFailed)
+    .attach_printable_lazy(|| "Error with client library")
+}
+
+fn add_proxies(
+    mut client_builder: reqwest::ClientBuilder,
+    http_url: Option<String>,
+    https_url: Option<String>,
+) -> CustomResult<reqwest::ClientBuilder, errors::ApiClientError> {
+    if let Some(url) = http_url {
+        client_builder = client_builder.proxy(
+            reqwest::Proxy::http(url)
+                .into_report()
+                .change_context(errors::ApiClientError::InvalidProxyConfiguration)
+                .attach_printable_lazy(|| "HTTP proxy configuration error")?,
+        );
     }
+    if let Some(url) = https_url {
+        client_builder = client_builder.proxy(
+            reqwest::Proxy::https(url)
+                .into_report()
+                .change_context(errors::ApiClientError::InvalidProxyConfiguration)
+                .attach_printable_lazy(|| "HTTPS proxy configuration error")?,
+        );
+    }
+    Ok(client_builder)
+}
 
-    client_builder = match (client_certificate, client_certificate_key) {
+fn add_client_certificate(
+    client_builder: reqwest::ClientBuilder,
+    client_certificate: Option<String>,
+    client_certificate_key: Option<String>,
+) -> CustomResult<reqwest::ClientBuilder, errors::ApiClientError> {
+    Ok(match (client_certificate, client_certificate_key) {
         (Some(encoded_cert), Some(encoded_cert_key)) => {
             let decoded_cert = consts::BASE64_ENGINE
                 .decode(encoded_cert)
@@ -139,13 +233,7 @@ pub(super) fn create_client(
             client_builder.identity(identity)
         }
         _ => client_builder,
-    };
-
-    client_builder
-        .build()
-        .into_report()
-        .change_context(errors::ApiClientError::ClientConstructionFailed)
-        .attach_printable_lazy(|| "Error with client library")
+    })
 }
 
 pub(super) fn proxy_bypass_urls(locker: &Locker) -> Vec<String> {
@@ -161,3 +249,63 @@ pub(super) fn proxy_bypass_urls(locker: &Locker) -> Vec<String> {
         format!("{basilisk_host}/tokenize/delete/token"),
     ]
 }
+
+pub(crate) fn get_timeout(
+    request_settings: &ConnectorRequestSettings,
+    connector: &str,
+) -> Duration {
+    Duration::from_secs(
+        request_settings
+            .connector_timeouts_in_secs
+            .get(connector)
+            .copied()
+            .unwrap_or(request_settings.timeout_in_secs),
+    )
+}
+
+/// Exponential backoff with half of the delay being random, so that the retries of requests which
+/// failed together, during an outage of the connector for example, are spread out.
+pub(super) fn get_retry_delay(retries: &ConnectorRequestRetries, retry_count: u32) -> Duration {
+    let delay = retries
+        .base_delay_in_millis
+        .saturating_mul(2_u64.saturating_pow(retry_count))
+        .min(retries.max_delay_in_millis);
+    let jitter = rand::thread_rng().gen_range(0..=delay / 2);
+    Duration::from_millis(delay - delay / 2 + jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout_of_connector() {
+        let request_settings = ConnectorRequestSettings {
+            connector_timeouts_in_secs: [("adyen".to_string(), 60)].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_timeout(&request_settings, "adyen"),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            get_timeout(&request_settings, "stripe"),
+            Duration::from_secs(consts::REQUEST_TIME_OUT)
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_is_bounded() {
+        let retries = ConnectorRequestRetries {
+            max_retries: 5,
+            base_delay_in_millis: 100,
+            max_delay_in_millis: 1000,
+        };
+        for (retry_count, expected_delay) in [(0, 100), (1, 200), (2, 400), (3, 800), (4, 1000)] {
+            let delay = get_retry_delay(&retries, retry_count);
+            assert!(delay >= Duration::from_millis(expected_delay / 2));
+            assert!(delay <= Duration::from_millis(expected_delay));
+        }
+        assert!(get_retry_delay(&retries, u32::MAX) <= Duration::from_millis(1000));
+    }
+}
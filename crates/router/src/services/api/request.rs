@@ -72,7 +72,7 @@ impl Request {
     }
 
     pub fn add_certificate_key(&mut self, certificate_key: Option<String>) {
-        self.certificate = certificate_key;
+        self.certificate_key = certificate_key;
     }
 }
 
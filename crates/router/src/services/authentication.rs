@@ -2,17 +2,52 @@ use actix_web::http::header::HeaderMap;
 use api_models::{payment_methods::ListPaymentMethodRequest, payments::PaymentsRequest};
 use async_trait::async_trait;
 use error_stack::{report, IntoReport, ResultExt};
-use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 
 use crate::{
-    core::errors::{self, RouterResult},
+    configs::settings::Settings,
+    consts,
+    core::{
+        api_keys, audit_logs,
+        errors::{self, RouterResult},
+    },
     db::StorageInterface,
     routes::{app::AppStateInfo, AppState},
-    services::api,
-    types::storage,
+    services::{
+        api,
+        authorization::{self, Permission},
+    },
+    types::storage::{
+        self,
+        enums::{AuditActorType, RoleName},
+    },
     utils::OptionExt,
 };
 
+/// Details of the caller, common to the outputs of the authenticators.
+pub trait AuthInfo {
+    /// The merchant the API is called on behalf of, if any.
+    fn get_merchant_id(&self) -> Option<&str>;
+}
+
+impl AuthInfo for () {
+    fn get_merchant_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AuthInfo for storage::MerchantAccount {
+    fn get_merchant_id(&self) -> Option<&str> {
+        Some(&self.merchant_id)
+    }
+}
+
+impl AuthInfo for UserFromToken {
+    fn get_merchant_id(&self) -> Option<&str> {
+        Some(&self.merchant_id)
+    }
+}
+
 #[async_trait]
 pub trait AuthenticateAndFetch<T, A>
 where
@@ -25,8 +60,14 @@ where
     ) -> RouterResult<T>;
 }
 
+/// Authenticates merchants with their API keys, permitting only the API keys whose role allows
+/// the operation the route performs.
 #[derive(Debug)]
-pub struct ApiKeyAuth;
+pub struct ApiKeyAuth(pub Permission);
+
+/// Role of the plaintext API keys stored with merchant accounts created before API keys were
+/// hashed. The keys do not carry a role of their own, so they are only permitted to read.
+const LEGACY_API_KEY_ROLE: RoleName = RoleName::ReadOnly;
 
 #[async_trait]
 impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for ApiKeyAuth
@@ -40,17 +81,56 @@ where
     ) -> RouterResult<storage::MerchantAccount> {
         let api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
-            .store()
-            .find_merchant_account_by_api_key(api_key)
-            .await
-            .map_err(|e| {
-                if e.current_context().is_db_not_found() {
-                    e.change_context(errors::ApiErrorResponse::Unauthorized)
-                } else {
-                    e.change_context(errors::ApiErrorResponse::InternalServerError)
-                }
-            })
+        let store = state.store();
+
+        let (merchant_account, role, key_id) =
+            match api_keys::find_api_key_by_plaintext(&*store, api_key).await? {
+                Some(stored_api_key) => (
+                    store
+                        .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
+                        .await,
+                    stored_api_key.role,
+                    Some(stored_api_key.key_id),
+                ),
+                // Merchant accounts created before API keys were hashed are authenticated with the
+                // plaintext API key stored with the merchant account
+                None => (
+                    store.find_merchant_account_by_api_key(api_key).await,
+                    LEGACY_API_KEY_ROLE,
+                    None,
+                ),
+            };
+
+        let merchant_account = merchant_account.map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(errors::ApiErrorResponse::Unauthorized)
+            } else {
+                e.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })?;
+        authorization::check_permission(role, self.0)?;
+        audit_logs::set_actor(AuditActorType::ApiKey, key_id);
+
+        Ok(merchant_account)
+    }
+}
+
+/// Permits any request, for the routes that authenticate the caller themselves, such as logging
+/// in to the dashboard.
+#[derive(Debug)]
+pub struct NoAuth;
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<(), A> for NoAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        _request_headers: &HeaderMap,
+        _state: &A,
+    ) -> RouterResult<()> {
+        Ok(())
     }
 }
 
@@ -74,6 +154,7 @@ where
             Err(report!(errors::ApiErrorResponse::Unauthorized)
                 .attach_printable("Admin Authentication Failure"))?;
         }
+        audit_logs::set_actor(AuditActorType::Admin, None);
         Ok(())
     }
 }
@@ -114,7 +195,7 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
     ) -> RouterResult<storage::MerchantAccount> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
+        let merchant_account = state
             .store
             .find_merchant_account_by_publishable_key(publishable_key)
             .await
@@ -124,7 +205,10 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
                 } else {
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
-            })
+            })?;
+        audit_logs::set_actor(AuditActorType::PublishableKey, None);
+
+        Ok(merchant_account)
     }
 }
 
@@ -153,13 +237,20 @@ where
     }
 }
 
+/// Authenticates dashboard users with their JWT, permitting only the users whose role for the
+/// merchant account allows the operation the route performs.
+#[derive(Debug)]
+pub struct JWTAuthMerchant(pub Permission);
+
+/// Tokens issued before users were assigned roles do not identify the user, and are rejected
 #[derive(serde::Deserialize)]
 struct JwtAuthPayloadFetchMerchantAccount {
     merchant_id: String,
+    user_id: String,
 }
 
 #[async_trait]
-impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for JWTAuth
+impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for JWTAuthMerchant
 where
     A: AppStateInfo + Sync,
 {
@@ -171,14 +262,121 @@ where
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
         let payload = decode_jwt::<JwtAuthPayloadFetchMerchantAccount>(token, state)?;
-        state
-            .store()
+        let store = state.store();
+
+        let role = store
+            .find_user_role_by_user_id_merchant_id(&payload.user_id, &payload.merchant_id)
+            .await
+            .map_err(|e| {
+                if e.current_context().is_db_not_found() {
+                    e.change_context(errors::ApiErrorResponse::AccessForbidden)
+                } else {
+                    e.change_context(errors::ApiErrorResponse::InternalServerError)
+                }
+            })?
+            .role;
+        authorization::check_permission(role, self.0)?;
+        audit_logs::set_actor(AuditActorType::User, Some(payload.user_id));
+
+        store
             .find_merchant_account_by_merchant_id(&payload.merchant_id)
             .await
             .change_context(errors::ApiErrorResponse::InvalidJwtToken)
     }
 }
 
+/// Authenticates the request with the JWT of a dashboard user when the `Authorization` header is
+/// present, and with an API key otherwise.
+#[derive(Debug)]
+pub struct ApiKeyOrJWTAuth(pub Permission);
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<storage::MerchantAccount, A> for ApiKeyOrJWTAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<storage::MerchantAccount> {
+        if is_jwt_auth(request_headers) {
+            JWTAuthMerchant(self.0)
+                .authenticate_and_fetch(request_headers, state)
+                .await
+        } else {
+            ApiKeyAuth(self.0)
+                .authenticate_and_fetch(request_headers, state)
+                .await
+        }
+    }
+}
+
+/// Claims of the JWTs issued to dashboard users
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AuthToken {
+    pub user_id: String,
+    pub merchant_id: String,
+    pub exp: u64,
+}
+
+impl AuthToken {
+    pub fn new_token(
+        user_id: String,
+        merchant_id: String,
+        settings: &Settings,
+    ) -> RouterResult<String> {
+        let exp = u64::try_from(
+            common_utils::date_time::now_unix_timestamp() + consts::JWT_TOKEN_TIME_IN_SECS,
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let token_payload = Self {
+            user_id,
+            merchant_id,
+            exp,
+        };
+        let key = EncodingKey::from_secret(settings.secrets.jwt_secret.as_bytes());
+
+        encode(&Header::default(), &token_payload, &key)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode JWT")
+    }
+}
+
+#[derive(Debug)]
+pub struct UserFromToken {
+    pub user_id: String,
+    pub merchant_id: String,
+}
+
+/// Authenticates dashboard users with their JWT, without fetching the merchant account the token
+/// grants access to.
+#[derive(Debug)]
+pub struct UserJWTAuth;
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<UserFromToken, A> for UserJWTAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<UserFromToken> {
+        let mut token = get_jwt(request_headers)?;
+        token = strip_jwt_token(token)?;
+        let payload = decode_jwt::<AuthToken>(token, state)?;
+
+        Ok(UserFromToken {
+            user_id: payload.user_id,
+            merchant_id: payload.merchant_id,
+        })
+    }
+}
+
 pub trait ClientSecretFetch {
     fn get_client_secret(&self) -> Option<&String>;
 }
@@ -210,6 +408,7 @@ where
 
 pub fn get_auth_type_and_flow(
     headers: &HeaderMap,
+    permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, AppState>>,
     api::AuthFlow,
@@ -219,12 +418,13 @@ pub fn get_auth_type_and_flow(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(permission)), api::AuthFlow::Merchant))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, T>>,
     api::AuthFlow,
@@ -253,18 +453,19 @@ where
         .into());
     }
 
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(permission)), api::AuthFlow::Merchant))
 }
 
 pub async fn is_ephemeral_auth(
     headers: &HeaderMap,
     db: &dyn StorageInterface,
     customer_id: &str,
+    permission: Permission,
 ) -> RouterResult<Box<dyn AuthenticateAndFetch<storage::MerchantAccount, AppState>>> {
     let api_key = get_api_key(headers)?;
 
     if !api_key.starts_with("epk") {
-        return Ok(Box::new(ApiKeyAuth));
+        return Ok(Box::new(ApiKeyAuth(permission)));
     }
 
     let ephemeral_key = db
@@ -321,3 +522,80 @@ pub fn strip_jwt_token(token: &str) -> RouterResult<&str> {
         .strip_prefix("Bearer ")
         .ok_or_else(|| errors::ApiErrorResponse::InvalidJwtToken.into())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::header::{self, HeaderValue};
+
+    use super::*;
+
+    const JWT_SECRET: &str = "test_jwt_secret";
+
+    /// State of a test which cannot reach the store, so that the tests fail if a token is not
+    /// rejected before its user or merchant account is looked up
+    struct StorelessAppState;
+
+    impl AppStateInfo for StorelessAppState {
+        fn conf(&self) -> Settings {
+            let mut conf = Settings::default();
+            conf.secrets.jwt_secret = JWT_SECRET.to_string();
+            conf
+        }
+        fn flow_name(&self) -> String {
+            "test".to_string()
+        }
+        #[allow(clippy::unreachable)]
+        fn store(&self) -> Box<dyn StorageInterface> {
+            unreachable!("the token was not rejected before reaching the store")
+        }
+    }
+
+    #[derive(serde::Serialize)]
+    struct TokenWithoutUser {
+        merchant_id: String,
+        exp: u64,
+    }
+
+    #[tokio::test]
+    async fn test_jwt_without_user_cannot_call_admin_only_route() {
+        let token = encode(
+            &Header::default(),
+            &TokenWithoutUser {
+                merchant_id: "merchant_123".to_string(),
+                exp: u64::try_from(
+                    common_utils::date_time::now_unix_timestamp() + consts::JWT_TOKEN_TIME_IN_SECS,
+                )
+                .unwrap(),
+            },
+            &EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+        )
+        .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {token}")).unwrap(),
+        );
+
+        let result = JWTAuthMerchant(Permission::RoutingWrite)
+            .authenticate_and_fetch(&headers, &StorelessAppState)
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidJwtToken
+        ));
+    }
+
+    #[test]
+    fn test_legacy_api_key_cannot_call_admin_only_route() {
+        assert!(
+            authorization::check_permission(LEGACY_API_KEY_ROLE, Permission::RoutingWrite).is_err()
+        );
+        assert!(
+            authorization::check_permission(LEGACY_API_KEY_ROLE, Permission::PaymentWrite).is_err()
+        );
+        assert!(
+            authorization::check_permission(LEGACY_API_KEY_ROLE, Permission::PaymentRead).is_ok()
+        );
+    }
+}
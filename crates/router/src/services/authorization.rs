@@ -0,0 +1,141 @@
+use error_stack::report;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::storage::enums::RoleName,
+};
+
+/// Operation on the resources of a merchant account that an API requires the caller to be
+/// permitted to perform.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Permission {
+    PaymentRead,
+    PaymentWrite,
+    RefundRead,
+    RefundWrite,
+    DisputeRead,
+    DisputeWrite,
+    CustomerRead,
+    CustomerWrite,
+    MandateRead,
+    MandateWrite,
+    PayoutRead,
+    PayoutWrite,
+    RoutingRead,
+    RoutingWrite,
+    EventRead,
+    EventWrite,
+    AuditLogRead,
+    AnalyticsRead,
+    ReconRead,
+    ReconWrite,
+    BlocklistRead,
+    BlocklistWrite,
+}
+
+impl Permission {
+    fn is_read(self) -> bool {
+        matches!(
+            self,
+            Self::PaymentRead
+                | Self::RefundRead
+                | Self::DisputeRead
+                | Self::CustomerRead
+                | Self::MandateRead
+                | Self::PayoutRead
+                | Self::RoutingRead
+                | Self::EventRead
+                | Self::AuditLogRead
+                | Self::AnalyticsRead
+                | Self::ReconRead
+                | Self::BlocklistRead
+        )
+    }
+}
+
+pub fn role_has_permission(role: RoleName, permission: Permission) -> bool {
+    match role {
+        RoleName::Admin => true,
+        RoleName::Operator => permission != Permission::RoutingWrite,
+        RoleName::Developer => {
+            permission.is_read()
+                || matches!(
+                    permission,
+                    Permission::RoutingWrite | Permission::EventWrite
+                )
+        }
+        RoleName::ReadOnly => permission.is_read(),
+    }
+}
+
+pub fn check_permission(role: RoleName, permission: Permission) -> RouterResult<()> {
+    if role_has_permission(role, permission) {
+        Ok(())
+    } else {
+        Err(
+            report!(errors::ApiErrorResponse::AccessForbidden).attach_printable(format!(
+                "Role {role} is not permitted to perform {permission:?}"
+            )),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_permissions() {
+        assert!(role_has_permission(
+            RoleName::Admin,
+            Permission::RoutingWrite
+        ));
+
+        assert!(role_has_permission(
+            RoleName::Operator,
+            Permission::RefundWrite
+        ));
+        assert!(!role_has_permission(
+            RoleName::Operator,
+            Permission::RoutingWrite
+        ));
+
+        assert!(role_has_permission(
+            RoleName::Developer,
+            Permission::RoutingWrite
+        ));
+        assert!(role_has_permission(
+            RoleName::Developer,
+            Permission::PaymentRead
+        ));
+        assert!(!role_has_permission(
+            RoleName::Developer,
+            Permission::PaymentWrite
+        ));
+        assert!(role_has_permission(
+            RoleName::Developer,
+            Permission::EventWrite
+        ));
+
+        assert!(role_has_permission(
+            RoleName::ReadOnly,
+            Permission::DisputeRead
+        ));
+        assert!(role_has_permission(
+            RoleName::ReadOnly,
+            Permission::AuditLogRead
+        ));
+        assert!(role_has_permission(
+            RoleName::ReadOnly,
+            Permission::AnalyticsRead
+        ));
+        assert!(!role_has_permission(
+            RoleName::ReadOnly,
+            Permission::CustomerWrite
+        ));
+        assert!(!role_has_permission(
+            RoleName::ReadOnly,
+            Permission::EventWrite
+        ));
+    }
+}
@@ -0,0 +1,338 @@
+//! Publishes the lifecycle events of payments, payment attempts and refunds to Kafka, for analytics
+//! stores such as ClickHouse to consume without querying the database.
+//!
+//! Every message is the JSON of the resource as of the event, along with the version of its schema
+//! and the time of the event. The schema version of a resource is incremented whenever a field is
+//! removed or changes type, so that consumers can tell the messages apart.
+
+use error_stack::{IntoReport, ResultExt};
+use rdkafka::{
+    config::ClientConfig,
+    producer::{FutureProducer, FutureRecord},
+};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::KafkaSettings,
+    core::errors::CustomResult,
+    logger,
+    routes::AppState,
+    types::storage::{self, enums as storage_enums},
+    utils,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum KafkaError {
+    #[error("Failed to create the Kafka producer")]
+    InitializationFailed,
+    #[error("Failed to serialize the event")]
+    SerializationFailed,
+    #[error("Failed to publish the event")]
+    PublishFailed,
+}
+
+/// Resource whose lifecycle events are published to Kafka
+pub trait KafkaMessage: Serialize {
+    /// Version of the schema of the messages of the resource
+    const SCHEMA_VERSION: u16;
+
+    /// Topic the events of the resource are published to
+    fn topic(settings: &KafkaSettings) -> &str;
+
+    /// Key of the messages, the events of a resource are kept in order within a partition
+    fn key(&self) -> String;
+}
+
+#[derive(Serialize)]
+struct KafkaEvent<'a, T: KafkaMessage> {
+    #[serde(flatten)]
+    message: &'a T,
+    schema_version: u16,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    event_timestamp: PrimitiveDateTime,
+}
+
+#[derive(Clone)]
+pub struct KafkaProducer {
+    producer: FutureProducer,
+    settings: KafkaSettings,
+}
+
+impl std::fmt::Debug for KafkaProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaProducer")
+            .field("brokers", &self.settings.brokers)
+            .finish()
+    }
+}
+
+impl KafkaProducer {
+    pub fn create(settings: &KafkaSettings) -> CustomResult<Self, KafkaError> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", settings.brokers.join(","))
+            .set(
+                "message.timeout.ms",
+                settings.message_timeout_ms.to_string(),
+            )
+            .create()
+            .into_report()
+            .change_context(KafkaError::InitializationFailed)?;
+
+        Ok(Self {
+            producer,
+            settings: settings.clone(),
+        })
+    }
+
+    /// Queues the event to be published. The delivery of the event is not awaited, so that
+    /// publishing never holds up the API call.
+    pub fn log_event<T: KafkaMessage>(&self, message: &T) -> CustomResult<(), KafkaError> {
+        let event = KafkaEvent {
+            message,
+            schema_version: T::SCHEMA_VERSION,
+            event_timestamp: common_utils::date_time::now(),
+        };
+        let payload = utils::Encode::<KafkaEvent<'_, T>>::encode_to_string_of_json(&event)
+            .change_context(KafkaError::SerializationFailed)?;
+        let key = message.key();
+
+        self.producer
+            .send_result(
+                FutureRecord::to(T::topic(&self.settings))
+                    .key(&key)
+                    .payload(&payload),
+            )
+            .map_err(|(error, _)| error)
+            .into_report()
+            .change_context(KafkaError::PublishFailed)?;
+
+        Ok(())
+    }
+}
+
+/// Publishes the event when events are enabled. Failures are only logged, and do not fail the
+/// operation the event is about.
+pub fn log_event<T: KafkaMessage>(state: &AppState, message: &T) {
+    if let Some(producer) = &state.event_producer {
+        if let Err(error) = producer.log_event(message) {
+            logger::error!(?error, key = %message.key(), "Failed to publish the event to Kafka");
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct KafkaPaymentIntent<'a> {
+    pub payment_id: &'a str,
+    pub merchant_id: &'a str,
+    pub status: storage_enums::IntentStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub amount_captured: Option<i64>,
+    pub customer_id: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub metadata: Option<&'a serde_json::Value>,
+    pub connector_id: Option<&'a str>,
+    pub setup_future_usage: Option<storage_enums::FutureUsage>,
+    pub off_session: Option<bool>,
+    pub profile_id: Option<&'a str>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl<'a> From<&'a storage::PaymentIntent> for KafkaPaymentIntent<'a> {
+    fn from(intent: &'a storage::PaymentIntent) -> Self {
+        Self {
+            payment_id: &intent.payment_id,
+            merchant_id: &intent.merchant_id,
+            status: intent.status,
+            amount: intent.amount,
+            currency: intent.currency,
+            amount_captured: intent.amount_captured,
+            customer_id: intent.customer_id.as_deref(),
+            description: intent.description.as_deref(),
+            metadata: intent.metadata.as_ref(),
+            connector_id: intent.connector_id.as_deref(),
+            setup_future_usage: intent.setup_future_usage,
+            off_session: intent.off_session,
+            profile_id: intent.profile_id.as_deref(),
+            created_at: intent.created_at,
+            modified_at: intent.modified_at,
+        }
+    }
+}
+
+impl<'a> KafkaMessage for KafkaPaymentIntent<'a> {
+    const SCHEMA_VERSION: u16 = 1;
+
+    fn topic(settings: &KafkaSettings) -> &str {
+        &settings.payment_intent_topic
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id, self.payment_id)
+    }
+}
+
+#[derive(Serialize)]
+pub struct KafkaPaymentAttempt<'a> {
+    pub payment_id: &'a str,
+    pub merchant_id: &'a str,
+    pub attempt_id: &'a str,
+    pub status: storage_enums::AttemptStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: Option<&'a str>,
+    pub connector_transaction_id: Option<&'a str>,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub authentication_type: Option<storage_enums::AuthenticationType>,
+    pub amount_to_capture: Option<i64>,
+    pub error_code: Option<&'a str>,
+    pub error_message: Option<&'a str>,
+    pub connector_latency_in_millis: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl<'a> From<&'a storage::PaymentAttempt> for KafkaPaymentAttempt<'a> {
+    fn from(attempt: &'a storage::PaymentAttempt) -> Self {
+        Self {
+            payment_id: &attempt.payment_id,
+            merchant_id: &attempt.merchant_id,
+            attempt_id: &attempt.attempt_id,
+            status: attempt.status,
+            amount: attempt.amount,
+            currency: attempt.currency,
+            connector: attempt.connector.as_deref(),
+            connector_transaction_id: attempt.connector_transaction_id.as_deref(),
+            payment_method: attempt.payment_method,
+            payment_method_type: attempt.payment_method_type,
+            capture_method: attempt.capture_method,
+            authentication_type: attempt.authentication_type,
+            amount_to_capture: attempt.amount_to_capture,
+            error_code: attempt.error_code.as_deref(),
+            error_message: attempt.error_message.as_deref(),
+            connector_latency_in_millis: attempt.connector_latency_in_millis,
+            created_at: attempt.created_at,
+            modified_at: attempt.modified_at,
+        }
+    }
+}
+
+impl<'a> KafkaMessage for KafkaPaymentAttempt<'a> {
+    const SCHEMA_VERSION: u16 = 1;
+
+    fn topic(settings: &KafkaSettings) -> &str {
+        &settings.payment_attempt_topic
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id, self.attempt_id)
+    }
+}
+
+#[derive(Serialize)]
+pub struct KafkaRefund<'a> {
+    pub refund_id: &'a str,
+    pub payment_id: &'a str,
+    pub merchant_id: &'a str,
+    pub attempt_id: &'a str,
+    pub connector: &'a str,
+    pub connector_refund_id: Option<&'a str>,
+    pub refund_status: storage_enums::RefundStatus,
+    pub refund_type: storage_enums::RefundType,
+    pub total_amount: i64,
+    pub refund_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub refund_reason_code: Option<storage_enums::RefundReason>,
+    pub refund_error_code: Option<&'a str>,
+    pub refund_error_message: Option<&'a str>,
+    pub sent_to_gateway: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl<'a> From<&'a storage::Refund> for KafkaRefund<'a> {
+    fn from(refund: &'a storage::Refund) -> Self {
+        Self {
+            refund_id: &refund.refund_id,
+            payment_id: &refund.payment_id,
+            merchant_id: &refund.merchant_id,
+            attempt_id: &refund.attempt_id,
+            connector: &refund.connector,
+            connector_refund_id: refund.connector_refund_id.as_deref(),
+            refund_status: refund.refund_status,
+            refund_type: refund.refund_type,
+            total_amount: refund.total_amount,
+            refund_amount: refund.refund_amount,
+            currency: refund.currency,
+            refund_reason_code: refund.refund_reason_code,
+            refund_error_code: refund.refund_error_code.as_deref(),
+            refund_error_message: refund.refund_error_message.as_deref(),
+            sent_to_gateway: refund.sent_to_gateway,
+            created_at: refund.created_at,
+            modified_at: refund.updated_at,
+        }
+    }
+}
+
+impl<'a> KafkaMessage for KafkaRefund<'a> {
+    const SCHEMA_VERSION: u16 = 1;
+
+    fn topic(settings: &KafkaSettings) -> &str {
+        &settings.refund_topic
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id, self.refund_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestMessage {
+        resource_id: &'static str,
+    }
+
+    impl KafkaMessage for TestMessage {
+        const SCHEMA_VERSION: u16 = 2;
+
+        fn topic(settings: &KafkaSettings) -> &str {
+            &settings.refund_topic
+        }
+
+        fn key(&self) -> String {
+            self.resource_id.to_string()
+        }
+    }
+
+    #[test]
+    fn test_event_envelope() {
+        let message = TestMessage {
+            resource_id: "ref_1",
+        };
+        let event = KafkaEvent {
+            message: &message,
+            schema_version: TestMessage::SCHEMA_VERSION,
+            event_timestamp: common_utils::date_time::now(),
+        };
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(value["resource_id"], "ref_1");
+        assert_eq!(value["schema_version"], 2);
+        assert!(value.get("event_timestamp").is_some());
+    }
+}
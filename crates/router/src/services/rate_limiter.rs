@@ -0,0 +1,128 @@
+//! Rate limiting of API requests with token buckets kept in redis, so that the limits hold across
+//! the instances of the application. Requests are let through when the buckets can't be reached,
+//! rather than failing every API call while redis is unavailable.
+
+use error_stack::report;
+
+use crate::{
+    configs::settings::{RateLimit, RateLimitSettings},
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+};
+
+async fn check_rate_limit(
+    db: &dyn StorageInterface,
+    key: &str,
+    limit: RateLimit,
+) -> RouterResult<()> {
+    match db
+        .take_rate_limit_token(key, limit.burst, limit.requests_per_second)
+        .await
+    {
+        Ok(None) => Ok(()),
+        Ok(Some(retry_after_ms)) => Err(report!(errors::ApiErrorResponse::TooManyRequests {
+            retry_after: retry_after_ms.saturating_add(999) / 1000,
+        })
+        .attach_printable(format!("Rate limit exceeded for {key}"))),
+        Err(error) => {
+            logger::error!(?error, "Failed to check the rate limit of the request");
+            Ok(())
+        }
+    }
+}
+
+/// Checks the limit on the requests made with the API key, before the request is authenticated.
+pub async fn check_api_key_rate_limit(
+    db: &dyn StorageInterface,
+    settings: &RateLimitSettings,
+    api_key: &str,
+) -> RouterResult<()> {
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    // The bucket is keyed by a hash of the API key, so that API keys are not stored in redis
+    let key = format!(
+        "rate_limit_api_key_{}",
+        blake3::hash(api_key.as_bytes()).to_hex()
+    );
+    check_rate_limit(db, &key, settings.api_key).await
+}
+
+/// Checks the limits on the requests made on behalf of the merchant, across all routes and to the
+/// route being called.
+pub async fn check_merchant_rate_limit(
+    db: &dyn StorageInterface,
+    settings: &RateLimitSettings,
+    merchant_id: &str,
+    path: &str,
+) -> RouterResult<()> {
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    check_rate_limit(
+        db,
+        &format!("rate_limit_merchant_{merchant_id}"),
+        settings.merchant,
+    )
+    .await?;
+
+    match get_route_rate_limit(settings, path) {
+        Some((route, limit)) => {
+            check_rate_limit(
+                db,
+                &format!("rate_limit_merchant_{merchant_id}_route_{route}"),
+                limit,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Returns the limit of the route with the longest path prefix matching the path, if any.
+fn get_route_rate_limit<'a>(
+    settings: &'a RateLimitSettings,
+    path: &str,
+) -> Option<(&'a str, RateLimit)> {
+    settings
+        .routes
+        .iter()
+        .filter(|(route, _)| path.starts_with(route.as_str()))
+        .max_by_key(|(route, _)| route.len())
+        .map(|(route, limit)| (route.as_str(), *limit))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_route_rate_limit_selection() {
+        let limit = |burst| RateLimit {
+            burst,
+            requests_per_second: 1,
+        };
+        let settings = RateLimitSettings {
+            routes: HashMap::from([
+                ("/payments".to_string(), limit(10)),
+                ("/payments/list".to_string(), limit(5)),
+            ]),
+            ..Default::default()
+        };
+
+        let (route, route_limit) = get_route_rate_limit(&settings, "/payments/list").unwrap();
+        assert_eq!(route, "/payments/list");
+        assert_eq!(route_limit.burst, 5);
+
+        let (route, _) = get_route_rate_limit(&settings, "/payments/pay_123/confirm").unwrap();
+        assert_eq!(route, "/payments");
+
+        assert!(get_route_rate_limit(&settings, "/refunds").is_none());
+    }
+}
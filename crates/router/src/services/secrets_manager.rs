@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use base64::Engine;
+use common_utils::{
+    errors::{CustomResult, SecretsManagementError},
+    secrets_manager::SecretsManager,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+#[cfg(not(feature = "kms"))]
+use crate::services::logger;
+use crate::{
+    configs::settings::{SecretsManagerBackend, SecretsManagerSettings},
+    consts,
+};
+
+const GCP_KMS_BASE_URL: &str = "https://cloudkms.googleapis.com/v1";
+const GCP_METADATA_ACCESS_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// Creates the secrets manager for the configured backend, if connector credentials are to be
+/// encrypted at rest.
+pub async fn get_secrets_manager(
+    settings: &SecretsManagerSettings,
+) -> Option<Arc<dyn SecretsManager>> {
+    match settings.backend {
+        SecretsManagerBackend::NoEncryption => None,
+
+        #[cfg(feature = "kms")]
+        SecretsManagerBackend::AwsKms => Some(Arc::new(AwsKmsSecretsManager::new(settings).await)),
+
+        #[cfg(not(feature = "kms"))]
+        SecretsManagerBackend::AwsKms => {
+            logger::error!("The AWS KMS secrets manager requires the KMS feature to be enabled");
+            None
+        }
+
+        SecretsManagerBackend::GcpKms => Some(Arc::new(GcpKmsSecretsManager::new(settings))),
+    }
+}
+
+#[cfg(feature = "kms")]
+#[derive(Debug)]
+pub struct AwsKmsSecretsManager {
+    key_id: String,
+    client: aws_sdk_kms::Client,
+}
+
+#[cfg(feature = "kms")]
+impl AwsKmsSecretsManager {
+    pub async fn new(settings: &SecretsManagerSettings) -> Self {
+        use aws_config::meta::region::RegionProviderChain;
+        use aws_sdk_kms::Region;
+
+        let region_provider =
+            RegionProviderChain::first_try(Region::new(settings.aws_region.clone()));
+        let shared_config = aws_config::from_env().region(region_provider).load().await;
+
+        Self {
+            key_id: settings.key_id.clone(),
+            client: aws_sdk_kms::Client::new(&shared_config),
+        }
+    }
+}
+
+#[cfg(feature = "kms")]
+#[async_trait::async_trait]
+impl SecretsManager for AwsKmsSecretsManager {
+    fn current_key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    async fn encrypt_data_key(
+        &self,
+        data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+        let response = self
+            .client
+            .encrypt()
+            .key_id(&self.key_id)
+            .plaintext(aws_sdk_kms::types::Blob::new(data_key))
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable("Failed to encrypt data key with AWS KMS")?;
+
+        response
+            .ciphertext_blob()
+            .map(|ciphertext| ciphertext.as_ref().to_vec())
+            .ok_or(SecretsManagementError::EncryptionFailed)
+            .into_report()
+            .attach_printable("Missing ciphertext in AWS KMS response")
+    }
+
+    async fn decrypt_data_key(
+        &self,
+        key_id: &str,
+        encrypted_data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+        let response = self
+            .client
+            .decrypt()
+            .key_id(key_id)
+            .ciphertext_blob(aws_sdk_kms::types::Blob::new(encrypted_data_key))
+            .send()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable("Failed to decrypt data key with AWS KMS")?;
+
+        response
+            .plaintext()
+            .map(|plaintext| plaintext.as_ref().to_vec())
+            .ok_or(SecretsManagementError::DecryptionFailed)
+            .into_report()
+            .attach_printable("Missing plaintext in AWS KMS response")
+    }
+}
+
+/// Secrets manager backed by GCP Cloud KMS, authenticating as the service account of the
+/// instance the application runs on.
+#[derive(Debug)]
+pub struct GcpKmsSecretsManager {
+    key_name: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GcpAccessTokenResponse {
+    access_token: Secret<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct GcpKmsEncryptRequest {
+    plaintext: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GcpKmsEncryptResponse {
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct GcpKmsDecryptRequest {
+    ciphertext: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GcpKmsDecryptResponse {
+    plaintext: Secret<String>,
+}
+
+impl GcpKmsSecretsManager {
+    pub fn new(settings: &SecretsManagerSettings) -> Self {
+        Self {
+            key_name: settings.key_id.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn get_access_token(&self) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let response: GcpAccessTokenResponse = self
+            .client
+            .get(GCP_METADATA_ACCESS_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable("Failed to fetch GCP access token from the metadata server")?
+            .json()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable("Failed to deserialize GCP access token")?;
+
+        Ok(response.access_token)
+    }
+
+    async fn call_kms<Req, Res>(
+        &self,
+        key_name: &str,
+        operation: &str,
+        request: &Req,
+    ) -> CustomResult<Res, SecretsManagementError>
+    where
+        Req: serde::Serialize + Sync,
+        Res: serde::de::DeserializeOwned,
+    {
+        let access_token = self.get_access_token().await?;
+
+        self.client
+            .post(format!("{GCP_KMS_BASE_URL}/{key_name}:{operation}"))
+            .bearer_auth(access_token.peek())
+            .json(request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable_lazy(|| format!("GCP KMS {operation} request failed"))?
+            .json()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::KeyManagementServiceError)
+            .attach_printable_lazy(|| format!("Failed to deserialize GCP KMS {operation} response"))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsManager for GcpKmsSecretsManager {
+    fn current_key_id(&self) -> &str {
+        &self.key_name
+    }
+
+    async fn encrypt_data_key(
+        &self,
+        data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+        let request = GcpKmsEncryptRequest {
+            plaintext: Secret::new(consts::BASE64_ENGINE.encode(data_key)),
+        };
+        let response: GcpKmsEncryptResponse =
+            self.call_kms(&self.key_name, "encrypt", &request).await?;
+
+        consts::BASE64_ENGINE
+            .decode(response.ciphertext)
+            .into_report()
+            .change_context(SecretsManagementError::EncryptionFailed)
+            .attach_printable("Failed to decode ciphertext in GCP KMS response")
+    }
+
+    async fn decrypt_data_key(
+        &self,
+        key_id: &str,
+        encrypted_data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, SecretsManagementError> {
+        let request = GcpKmsDecryptRequest {
+            ciphertext: consts::BASE64_ENGINE.encode(encrypted_data_key),
+        };
+        let response: GcpKmsDecryptResponse = self.call_kms(key_id, "decrypt", &request).await?;
+
+        consts::BASE64_ENGINE
+            .decode(response.plaintext.peek())
+            .into_report()
+            .change_context(SecretsManagementError::DecryptionFailed)
+            .attach_printable("Failed to decode plaintext in GCP KMS response")
+    }
+}
@@ -10,10 +10,10 @@ pub mod api;
 pub mod storage;
 pub mod transformers;
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Duration};
 
 pub use api_models::enums::Connector;
-use common_utils::pii::Email;
+use common_utils::pii::{self, Email};
 use error_stack::{IntoReport, ResultExt};
 
 use self::{api::payments, storage::enums as storage_enums};
@@ -24,6 +24,9 @@ pub type PaymentsAuthorizeRouterData =
     RouterData<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsAuthorizeSessionTokenRouterData =
     RouterData<api::AuthorizeSessionToken, AuthorizeSessionTokenData, PaymentsResponseData>;
+pub type PaymentsInitRouterData =
+    RouterData<api::InitPayment, PaymentsAuthorizeData, PaymentsResponseData>;
+pub type PaymentsPretaskRouterData<F> = RouterData<F, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
@@ -33,6 +36,15 @@ pub type PaymentsSessionRouterData =
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type RefundCreditRouterData = RouterData<api::Credit, CreditsData, RefundsResponseData>;
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type FraudCheckRouterData<F> = RouterData<F, FraudCheckData, FraudCheckResponseData>;
+pub type FraudCheckPreAuthRouterData = FraudCheckRouterData<api::FrmPreAuth>;
+pub type FraudCheckPostAuthRouterData = FraudCheckRouterData<api::FrmPostAuth>;
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+pub type MandateRevokeRouterData =
+    RouterData<api::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
 
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -50,6 +62,12 @@ pub type PaymentsCaptureResponseRouterData<R> =
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
+pub type CreditsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, CreditsData, RefundsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+pub type FraudCheckResponseRouterData<F, R> =
+    ResponseRouterData<F, R, FraudCheckData, FraudCheckResponseData>;
 
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -58,6 +76,26 @@ pub type PaymentsPreAuthorizeType = dyn services::ConnectorIntegration<
     AuthorizeSessionTokenData,
     PaymentsResponseData,
 >;
+pub type PaymentsInitType = dyn services::ConnectorIntegration<
+    api::InitPayment,
+    PaymentsAuthorizeData,
+    PaymentsResponseData,
+>;
+pub type ConnectorCustomerType = dyn services::ConnectorIntegration<
+    api::CreateConnectorCustomer,
+    PaymentsAuthorizeData,
+    PaymentsResponseData,
+>;
+pub type BankAccountCreateType = dyn services::ConnectorIntegration<
+    api::CreateBankAccount,
+    PaymentsAuthorizeData,
+    PaymentsResponseData,
+>;
+pub type MandateCreateType = dyn services::ConnectorIntegration<
+    api::CreateMandate,
+    PaymentsAuthorizeData,
+    PaymentsResponseData,
+>;
 pub type PaymentsSyncType =
     dyn services::ConnectorIntegration<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureType =
@@ -71,6 +109,38 @@ pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
+pub type RefundCreditType =
+    dyn services::ConnectorIntegration<api::Credit, CreditsData, RefundsResponseData>;
+
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+pub type PayoutSyncType =
+    dyn services::ConnectorIntegration<api::PoSync, PayoutsData, PayoutsResponseData>;
+pub type PayoutQuoteType =
+    dyn services::ConnectorIntegration<api::PoQuote, PayoutsData, PayoutsResponseData>;
+pub type PayoutRecipientType =
+    dyn services::ConnectorIntegration<api::PoRecipient, PayoutsData, PayoutsResponseData>;
+
+pub type FraudCheckPreAuthType =
+    dyn services::ConnectorIntegration<api::FrmPreAuth, FraudCheckData, FraudCheckResponseData>;
+pub type FraudCheckPostAuthType =
+    dyn services::ConnectorIntegration<api::FrmPostAuth, FraudCheckData, FraudCheckResponseData>;
+
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    SubmitEvidenceResponse,
+>;
+
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
 
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -106,6 +176,9 @@ pub struct RouterData<Flow, Request, Response> {
 
     /// Contains any error response that the connector returns.
     pub payment_method_id: Option<String>,
+
+    /// Time taken by the connector to respond to the request made for the flow, if it was called.
+    pub connector_latency: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
@@ -127,6 +200,56 @@ pub struct PaymentsAuthorizeData {
     pub order_details: Option<api_models::payments::OrderDetails>,
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub payer_document: Option<api_models::payments::PayerDocument>,
+    pub installments: Option<api_models::payments::InstallmentsData>,
+    pub split_payments: Option<api_models::payments::SplitPaymentsBreakdown>,
+    /// Decrypted Apple Pay token, for connectors which do not accept the encrypted token
+    pub apple_pay_decrypted_data: Option<ApplePayDecryptedData>,
+    /// Network chosen by the client to process a co-badged card on, sent to the connectors which
+    /// accept a brand selector
+    pub card_network: Option<api_models::enums::CardNetwork>,
+    /// Exemption from strong customer authentication to request from the issuer of the card
+    pub sca_exemption: Option<api_models::enums::ScaExemptionType>,
+    /// Result of the 3DS authentication made through the external 3DS server, for the connector to
+    /// authorize the payment as authenticated
+    pub external_authentication_data: Option<ExternalAuthenticationData>,
+    /// Payment credentials of a Click to Pay checkout, retrieved from the SRC system of the card
+    /// network
+    pub click_to_pay_token_data: Option<ClickToPayTokenData>,
+    /// Data for features of specific connectors, given with the payment
+    pub connector_metadata: Option<api_models::payments::ConnectorMetadata>,
+    /// Network transaction id of the customer-initiated transaction the saved card of an
+    /// off-session payment was stored with, for the payment to be made as a merchant-initiated
+    /// transaction referring to it at any connector
+    pub network_transaction_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalAuthenticationData {
+    /// Cardholder authentication verification value computed by the issuer
+    pub cavv: masking::Secret<String>,
+    /// Electronic commerce indicator
+    pub eci: Option<String>,
+    /// Transaction identifier assigned by the directory server of the card network
+    pub ds_trans_id: Option<String>,
+    /// Version of the 3DS protocol the payment was authenticated with
+    pub message_version: String,
+    /// Status of the authentication given by the issuer, Y when the cardholder is authenticated
+    /// and A when the authentication was attempted
+    pub trans_status: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClickToPayTokenData {
+    /// Network token of the card the customer checked out with
+    pub network_token: masking::Secret<String, pii::CardNumber>,
+    pub token_exp_month: masking::Secret<String>,
+    /// Four digit expiry year of the network token
+    pub token_exp_year: masking::Secret<String>,
+    /// Cryptogram generated by the SRC system for this payment
+    pub cryptogram: masking::Secret<String>,
+    pub eci: Option<String>,
+    pub card_network: api_models::enums::CardNetwork,
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +258,15 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    pub multiple_capture_data: Option<MultipleCaptureRequestData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultipleCaptureRequestData {
+    /// Position of this capture among the captures made on the payment, starting from 1
+    pub capture_sequence: i16,
+    /// Unique reference for this capture, to be sent to the connector where supported
+    pub capture_reference: String,
 }
 
 #[derive(Debug, Clone)]
@@ -143,6 +275,10 @@ pub struct AuthorizeSessionTokenData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    /// For connectors which tokenize the payment method before authorizing the payment
+    pub payment_method_data: payments::PaymentMethodData,
+    /// For connectors which fix the capture method when creating an order ahead of the payment
+    pub capture_method: Option<storage_enums::CaptureMethod>,
 }
 
 #[derive(Debug, Clone)]
@@ -151,6 +287,8 @@ pub struct PaymentsSyncData {
     pub connector_transaction_id: ResponseId,
     pub encoded_data: Option<String>,
     pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub payment_method_data: Option<payments::PaymentMethodData>,
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -206,6 +344,15 @@ pub enum PaymentsResponseData {
         redirection_data: Option<services::RedirectForm>,
         mandate_reference: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        /// Identifier of the transaction at the card network, referred to by the later
+        /// merchant-initiated transactions on the payment method
+        network_txn_id: Option<String>,
+        /// Result of the address verification of the card, normalized from the code of the
+        /// connector
+        avs_result: Option<storage_enums::AvsResult>,
+        /// Result of the verification of the security code of the card, normalized from the code
+        /// of the connector
+        cvv_result: Option<storage_enums::CvvResult>,
     },
     SessionResponse {
         session_token: api::SessionToken,
@@ -248,10 +395,112 @@ pub struct RefundsData {
     /// Amount for the payment against which this refund is issued
     pub amount: i64,
     pub reason: Option<String>,
+    /// Structured reason of the refund, for connectors accepting one out of a fixed set of reasons
+    pub reason_code: Option<storage_enums::RefundReason>,
     /// Amount to be refunded
     pub refund_amount: i64,
     /// Arbitrary metadata required for refund
     pub connector_metadata: Option<serde_json::Value>,
+    /// Metadata the merchant attached to the refund
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    /// Only available while the payout is being created, as the method data is not stored
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// Reference of the payout at the connector, to confirm or cancel a submitted payout
+    pub connector_payout_id: Option<String>,
+    /// Quote of the payout at the connector, set while the payout is being created
+    pub quote_id: Option<String>,
+    /// Recipient of the payout at the connector, set while the payout is being created
+    pub recipient_id: Option<String>,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub description: Option<String>,
+}
+
+/// A refund to a payment method without a prior payment, with the connector's response as
+/// [`RefundsResponseData`]
+#[derive(Debug, Clone)]
+pub struct CreditsData {
+    pub credit_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    /// Only available while the credit is being issued, as the payment method data is not stored
+    pub payment_method_data: Option<payments::PaymentMethodData>,
+    pub customer_id: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// A payment screened by a fraud check connector, with the decision of the connector as
+/// [`FraudCheckResponseData`]
+#[derive(Debug, Clone)]
+pub struct FraudCheckData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    /// Only available while the payment is being confirmed, as the payment method data is not stored
+    pub payment_method_data: Option<payments::PaymentMethodData>,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub browser_info: Option<BrowserInformation>,
+    pub order_details: Option<api_models::payments::OrderDetails>,
+    /// The payment connector the payment is authorized with, once it is routed
+    pub payment_connector: Option<String>,
+    /// The outcome of the authorization, for checks made after the payment is authorized
+    pub authorization_status: Option<storage_enums::AttemptStatus>,
+    pub connector_transaction_id: Option<String>,
+    pub error_code: Option<String>,
+}
+
+/// The decision of a fraud check connector, also the resource object of its webhooks
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FraudCheckResponseData {
+    /// The identifier of the case of the payment at the fraud check connector
+    pub frm_reference_id: Option<String>,
+    pub status: api_models::enums::FraudCheckStatus,
+    pub reason: Option<String>,
+}
+
+/// The `paymentData` of an Apple Pay payment token
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ApplePayEncryptedData {
+    pub version: String,
+    pub data: String,
+    pub signature: String,
+    pub header: ApplePayTokenHeader,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayTokenHeader {
+    pub ephemeral_public_key: String,
+    pub public_key_hash: String,
+    pub transaction_id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayDecryptedData {
+    pub application_primary_account_number: masking::Secret<String, pii::CardNumber>,
+    /// Expiry date of the account number in the format `YYMMDD`
+    pub application_expiration_date: String,
+    pub currency_code: String,
+    pub transaction_amount: i64,
+    pub device_manufacturer_identifier: masking::Secret<String>,
+    pub payment_data_type: String,
+    pub payment_data: ApplePayCryptogramData,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayCryptogramData {
+    pub online_payment_cryptogram: masking::Secret<String>,
+    pub eci_indicator: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -275,6 +524,52 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub access_activity_log: Option<String>,
+    pub billing_address: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub product_description: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub service_date: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: String,
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
@@ -334,6 +629,8 @@ pub struct ErrorResponse {
     pub message: String,
     pub reason: Option<String>,
     pub status_code: u16,
+    /// Connector independent code of the error, when the code of the connector is known.
+    pub unified_code: Option<storage_enums::UnifiedCode>,
 }
 
 impl ErrorResponse {
@@ -349,6 +646,7 @@ impl ErrorResponse {
             .error_message(),
             reason: None,
             status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+            unified_code: None,
         }
     }
 }
@@ -386,6 +684,7 @@ impl From<errors::ApiErrorResponse> for ErrorResponse {
                 errors::ApiErrorResponse::ExternalConnectorError { status_code, .. } => status_code,
                 _ => 500,
             },
+            unified_code: None,
         }
     }
 }
@@ -396,6 +695,35 @@ impl Default for ErrorResponse {
     }
 }
 
+impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsInitRouterData {
+    fn from(data: &&mut PaymentsAuthorizeRouterData) -> Self {
+        Self {
+            flow: PhantomData,
+            request: data.request.clone(),
+            merchant_id: data.merchant_id.clone(),
+            connector: data.connector.clone(),
+            attempt_id: data.attempt_id.clone(),
+            status: data.status,
+            payment_method: data.payment_method,
+            connector_auth_type: data.connector_auth_type.clone(),
+            description: data.description.clone(),
+            return_url: data.return_url.clone(),
+            router_return_url: data.router_return_url.clone(),
+            address: data.address.clone(),
+            auth_type: data.auth_type,
+            connector_meta_data: data.connector_meta_data.clone(),
+            amount_captured: data.amount_captured,
+            access_token: data.access_token.clone(),
+            response: data.response.clone(),
+            payment_method_id: data.payment_method_id.clone(),
+            connector_latency: None,
+            payment_id: data.payment_id.clone(),
+            session_token: data.session_token.clone(),
+            reference_id: data.reference_id.clone(),
+        }
+    }
+}
+
 impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsAuthorizeSessionTokenRouterData {
     fn from(data: &&mut PaymentsAuthorizeRouterData) -> Self {
         Self {
@@ -405,6 +733,8 @@ impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsAuthorizeSessionTokenRo
                 currency: data.request.currency,
                 connector_transaction_id: data.payment_id.clone(),
                 amount: data.request.amount,
+                payment_method_data: data.request.payment_method_data.clone(),
+                capture_method: data.request.capture_method,
             },
             merchant_id: data.merchant_id.clone(),
             connector: data.connector.clone(),
@@ -422,9 +752,100 @@ impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsAuthorizeSessionTokenRo
             access_token: data.access_token.clone(),
             response: data.response.clone(),
             payment_method_id: data.payment_method_id.clone(),
+            connector_latency: None,
             payment_id: data.payment_id.clone(),
             session_token: data.session_token.clone(),
             reference_id: data.reference_id.clone(),
         }
     }
 }
+
+impl From<&&mut PaymentsAuthorizeRouterData>
+    for PaymentsPretaskRouterData<api::CreateConnectorCustomer>
+{
+    fn from(data: &&mut PaymentsAuthorizeRouterData) -> Self {
+        create_payment_pretask_router_data(data)
+    }
+}
+
+impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsPretaskRouterData<api::CreateBankAccount> {
+    fn from(data: &&mut PaymentsAuthorizeRouterData) -> Self {
+        create_payment_pretask_router_data(data)
+    }
+}
+
+impl From<&&mut PaymentsAuthorizeRouterData> for PaymentsPretaskRouterData<api::CreateMandate> {
+    fn from(data: &&mut PaymentsAuthorizeRouterData) -> Self {
+        create_payment_pretask_router_data(data)
+    }
+}
+
+fn create_payment_pretask_router_data<F>(
+    data: &PaymentsAuthorizeRouterData,
+) -> PaymentsPretaskRouterData<F> {
+    RouterData {
+        flow: PhantomData,
+        request: data.request.clone(),
+        merchant_id: data.merchant_id.clone(),
+        connector: data.connector.clone(),
+        attempt_id: data.attempt_id.clone(),
+        status: data.status,
+        payment_method: data.payment_method,
+        connector_auth_type: data.connector_auth_type.clone(),
+        description: data.description.clone(),
+        return_url: data.return_url.clone(),
+        router_return_url: data.router_return_url.clone(),
+        address: data.address.clone(),
+        auth_type: data.auth_type,
+        connector_meta_data: data.connector_meta_data.clone(),
+        amount_captured: data.amount_captured,
+        access_token: data.access_token.clone(),
+        response: data.response.clone(),
+        payment_method_id: data.payment_method_id.clone(),
+        connector_latency: None,
+        payment_id: data.payment_id.clone(),
+        session_token: data.session_token.clone(),
+        reference_id: data.reference_id.clone(),
+    }
+}
+
+impl From<&&mut PayoutsRouterData<api::PoCreate>> for PayoutsRouterData<api::PoQuote> {
+    fn from(data: &&mut PayoutsRouterData<api::PoCreate>) -> Self {
+        create_payout_pretask_router_data(data)
+    }
+}
+
+impl From<&&mut PayoutsRouterData<api::PoCreate>> for PayoutsRouterData<api::PoRecipient> {
+    fn from(data: &&mut PayoutsRouterData<api::PoCreate>) -> Self {
+        create_payout_pretask_router_data(data)
+    }
+}
+
+fn create_payout_pretask_router_data<F>(
+    data: &PayoutsRouterData<api::PoCreate>,
+) -> PayoutsRouterData<F> {
+    RouterData {
+        flow: PhantomData,
+        request: data.request.clone(),
+        merchant_id: data.merchant_id.clone(),
+        connector: data.connector.clone(),
+        attempt_id: data.attempt_id.clone(),
+        status: data.status,
+        payment_method: data.payment_method,
+        connector_auth_type: data.connector_auth_type.clone(),
+        description: data.description.clone(),
+        return_url: data.return_url.clone(),
+        router_return_url: data.router_return_url.clone(),
+        address: data.address.clone(),
+        auth_type: data.auth_type,
+        connector_meta_data: data.connector_meta_data.clone(),
+        amount_captured: data.amount_captured,
+        access_token: data.access_token.clone(),
+        response: data.response.clone(),
+        payment_method_id: data.payment_method_id.clone(),
+        connector_latency: None,
+        payment_id: data.payment_id.clone(),
+        session_token: data.session_token.clone(),
+        reference_id: data.reference_id.clone(),
+    }
+}
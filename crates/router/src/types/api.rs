@@ -1,21 +1,34 @@
 pub mod admin;
+pub mod analytics;
 pub mod api_keys;
+pub mod audit_logs;
+pub mod blocklist;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod enums;
+pub mod fraud_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod routing;
+pub mod token_migration;
+pub mod user;
+pub mod user_roles;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
 
 use error_stack::{report, IntoReport, ResultExt};
 
+pub use self::mandates::{ConnectorMandateRevoke, MandateRevoke};
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
-    webhooks::*,
+    admin::*, analytics::*, api_keys::*, audit_logs::*, blocklist::*, configs::*, customers::*,
+    disputes::*, fraud_check::*, payment_methods::*, payments::*, payouts::*, recon::*, refunds::*,
+    routing::*, token_migration::*, user::*, user_roles::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -58,6 +71,13 @@ pub trait ConnectorCommon {
     /// The base URL for interacting with the connector's API.
     fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str;
 
+    /// Identifier of the connector as a Google Pay gateway, sent as the `gateway` tokenization
+    /// parameter in Google Pay session tokens.
+    /// Defaults to `None` for connectors which do not accept Google Pay gateway tokens.
+    fn gpay_gateway(&self) -> Option<&'static str> {
+        None
+    }
+
     /// common error response for a connector if it is same in all case
     fn build_error_response(
         &self,
@@ -68,6 +88,7 @@ pub trait ConnectorCommon {
             code: consts::NO_ERROR_CODE.to_string(),
             message: consts::NO_ERROR_MESSAGE.to_string(),
             reason: None,
+            unified_code: None,
         })
     }
 }
@@ -86,10 +107,75 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
     }
 }
 
+/// Capabilities declared by a connector, against which payments and refunds are validated before
+/// calling the connector, so that requests the connector can't process fail with an actionable
+/// error instead of a connector error. Each capability defaults to no restriction.
+pub trait ConnectorSpecifications {
+    /// Payment methods accepted by the connector, `None` if it accepts all of them.
+    fn supported_payment_methods(&self) -> Option<&'static [api_enums::PaymentMethod]> {
+        None
+    }
+
+    /// Currencies accepted by the connector, `None` if it accepts all of them.
+    fn supported_currencies(&self) -> Option<&'static [api_enums::Currency]> {
+        None
+    }
+
+    /// ISO 3166-1 alpha-2 codes of the billing countries accepted by the connector, `None` if it
+    /// accepts all of them.
+    fn supported_countries(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// Capture methods supported by the connector, `None` if it supports all of them.
+    fn supported_capture_methods(&self) -> Option<&'static [api_enums::CaptureMethod]> {
+        None
+    }
+
+    /// Number of days after a payment during which the connector accepts refunds of the payment,
+    /// `None` if the connector doesn't limit it.
+    fn refund_window_days(&self) -> Option<i64> {
+        None
+    }
+
+    /// Whether mandates can be set up and charged through the connector.
+    fn supports_mandates(&self) -> bool {
+        true
+    }
+
+    /// Whether payment methods can be verified through an authorization of zero amount at the
+    /// connector, so that they can be charged later without the customer.
+    fn supports_zero_amount_authorization(&self) -> bool {
+        false
+    }
+
+    /// Whether card payments can be authorized with the result of a 3DS authentication made
+    /// outside of the connector, such as through the external 3DS server.
+    fn supports_external_authentication(&self) -> bool {
+        false
+    }
+
+    /// Whether funds can be refunded to a payment method without a prior payment to refund, as
+    /// credits issued through the connector.
+    fn supports_unreferenced_refunds(&self) -> bool {
+        false
+    }
+}
+
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook + ConnectorAccessToken
+    Send
+    + Refund
+    + Payment
+    + Payouts
+    + Dispute
+    + ConnectorMandateRevoke
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
+    + ConnectorSpecifications
 {
 }
 
@@ -100,11 +186,15 @@ pub struct Pe;
 impl<
         T: Refund
             + Payment
+            + Payouts
+            + Dispute
+            + ConnectorMandateRevoke
             + Debug
             + ConnectorRedirectResponse
             + Send
             + IncomingWebhook
-            + ConnectorAccessToken,
+            + ConnectorAccessToken
+            + ConnectorSpecifications,
     > Connector for T
 {
 }
@@ -115,6 +205,9 @@ type BoxedConnector = Box<&'static (dyn Connector + Sync)>;
 // SessionTokenFromMetadata will avoid calling the connector instead create the session token ( for sdk )
 pub enum GetToken {
     Metadata,
+    /// The Click to Pay session token is created from the metadata as well, with the details of the
+    /// SRC initiator added to it
+    ClickToPayMetadata,
     Connector,
 }
 
@@ -128,6 +221,8 @@ pub enum ConnectorCallType {
     Routing,
     Multiple(Vec<ConnectorData>),
     Single(ConnectorData),
+    /// Connectors in the order of priority, the payment moves to the next one on retriable failures
+    Failover(Vec<ConnectorData>),
 }
 
 impl ConnectorCallType {
@@ -162,23 +257,40 @@ impl ConnectorData {
         match connector_name {
             "aci" => Ok(Box::new(&connector::Aci)),
             "adyen" => Ok(Box::new(&connector::Adyen)),
+            "affirm" => Ok(Box::new(&connector::Affirm)),
+            "afterpay_clearpay" => Ok(Box::new(&connector::AfterpayClearpay)),
             "airwallex" => Ok(Box::new(&connector::Airwallex)),
+            "alipay" => Ok(Box::new(&connector::Alipay)),
             "applepay" => Ok(Box::new(&connector::Applepay)),
             "authorizedotnet" => Ok(Box::new(&connector::Authorizedotnet)),
             "bambora" => Ok(Box::new(&connector::Bambora)),
             "bluesnap" => Ok(Box::new(&connector::Bluesnap)),
             "braintree" => Ok(Box::new(&connector::Braintree)),
             "checkout" => Ok(Box::new(&connector::Checkout)),
+            "cryptopay" => Ok(Box::new(&connector::Cryptopay)),
             "cybersource" => Ok(Box::new(&connector::Cybersource)),
             "dlocal" => Ok(Box::new(&connector::Dlocal)),
+            #[cfg(feature = "dummy_connector")]
+            "dummy" => Ok(Box::new(&connector::Dummy)),
             "fiserv" => Ok(Box::new(&connector::Fiserv)),
             "globalpay" => Ok(Box::new(&connector::Globalpay)),
+            "gocardless" => Ok(Box::new(&connector::Gocardless)),
             "klarna" => Ok(Box::new(&connector::Klarna)),
+            "mercadopago" => Ok(Box::new(&connector::Mercadopago)),
+            "mollie" => Ok(Box::new(&connector::Mollie)),
             "nuvei" => Ok(Box::new(&connector::Nuvei)),
+            "paypal" => Ok(Box::new(&connector::Paypal)),
+            "paysafe" => Ok(Box::new(&connector::Paysafe)),
+            "paytm" => Ok(Box::new(&connector::Paytm)),
             "payu" => Ok(Box::new(&connector::Payu)),
             "rapyd" => Ok(Box::new(&connector::Rapyd)),
+            "razorpay" => Ok(Box::new(&connector::Razorpay)),
             "shift4" => Ok(Box::new(&connector::Shift4)),
             "stripe" => Ok(Box::new(&connector::Stripe)),
+            "trustly" => Ok(Box::new(&connector::Trustly)),
+            "twocheckout" => Ok(Box::new(&connector::Twocheckout)),
+            "wechatpay" => Ok(Box::new(&connector::Wechatpay)),
+            "wise" => Ok(Box::new(&connector::Wise)),
             "worldline" => Ok(Box::new(&connector::Worldline)),
             "worldpay" => Ok(Box::new(&connector::Worldpay)),
             "multisafepay" => Ok(Box::new(&connector::Multisafepay)),
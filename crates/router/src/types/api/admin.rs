@@ -1,8 +1,13 @@
 pub use api_models::admin::{
-    CreateMerchantAccount, DeleteMcaResponse, DeleteMerchantAccountResponse,
-    MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
-    PaymentConnectorCreate, PaymentMethodsEnabled, RoutingAlgorithm, ToggleKVRequest,
-    ToggleKVResponse, WebhookDetails,
+    AuthorizationExpiryPolicy, BusinessProfileCreate, BusinessProfileResponse,
+    CardVerificationPolicy, ConnectorPayloadsResponse, CreateMerchantAccount, CreditPolicy,
+    DeleteBusinessProfileResponse, DeleteMcaResponse, DeleteMerchantAccountResponse,
+    DuplicatePaymentPolicy, FraudCheckPolicy, MerchantAccountResponse, MerchantConnectorId,
+    MerchantConnectorKeyRotationResponse, MerchantConnectorWebhookDetails, MerchantDetails,
+    MerchantId, OrganizationCreateRequest, OrganizationResponse, PaymentConnectorCreate,
+    PaymentMethodsEnabled, RoutingAlgorithm, ScaExemptionPolicy, ToggleConnectorPayloadsRequest,
+    ToggleConnectorPayloadsResponse, ToggleKVRequest, ToggleKVResponse, VelocityPolicy,
+    WebhookDetails,
 };
 
 use crate::types::{storage, transformers::ForeignFrom};
@@ -26,6 +31,31 @@ impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            authorization_expiry_policy: item.authorization_expiry_policy,
+            sca_exemption_policy: item.sca_exemption_policy,
+            credit_policy: item.credit_policy,
+            fraud_check_policy: item.fraud_check_policy,
+            velocity_policy: item.velocity_policy,
+            duplicate_payment_policy: item.duplicate_payment_policy,
+            card_verification_policy: item.card_verification_policy,
+            test_mode: item.test_mode,
+            organization_id: item.organization_id,
+        }
+    }
+}
+
+impl ForeignFrom<storage::BusinessProfile> for BusinessProfileResponse {
+    fn foreign_from(item: storage::BusinessProfile) -> Self {
+        Self {
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            profile_name: item.profile_name,
+            business_country: item.business_country,
+            business_label: item.business_label,
+            webhook_details: item.webhook_details,
+            routing_algorithm: item.routing_algorithm,
+            created: item.created_at,
+            modified: item.modified_at,
         }
     }
 }
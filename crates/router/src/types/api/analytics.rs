@@ -0,0 +1,4 @@
+pub use api_models::analytics::{
+    AnalyticsGranularity, AnalyticsTimeRange, PaymentAnalyticsBucket, PaymentAnalyticsDimension,
+    PaymentAnalyticsFilters, PaymentAnalyticsRequest, PaymentAnalyticsResponse,
+};
@@ -0,0 +1,32 @@
+pub use api_models::disputes::{DisputeListConstraints, DisputeResponse, SubmitEvidenceRequest};
+use time::PrimitiveDateTime;
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Default, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisputePayload {
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: api_models::enums::DisputeStage,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Evidence;
+
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::SubmitEvidenceResponse>
+{
+}
+
+pub trait Dispute: ConnectorCommon + SubmitEvidence {}
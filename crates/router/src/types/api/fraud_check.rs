@@ -0,0 +1,70 @@
+use std::{fmt::Debug, str::FromStr};
+
+use error_stack::{IntoReport, ResultExt};
+
+use super::{ConnectorCommon, IncomingWebhook};
+use crate::{
+    connector,
+    core::errors::{self, CustomResult},
+    services::api,
+    types::{self, api::enums as api_enums},
+};
+
+/// Screens a payment before it is authorized
+#[derive(Debug, Clone)]
+pub struct FrmPreAuth;
+
+pub trait FraudCheckPreAuth:
+    api::ConnectorIntegration<FrmPreAuth, types::FraudCheckData, types::FraudCheckResponseData>
+{
+}
+
+/// Sends an authorized payment, with the outcome of its authorization, for review
+#[derive(Debug, Clone)]
+pub struct FrmPostAuth;
+
+pub trait FraudCheckPostAuth:
+    api::ConnectorIntegration<FrmPostAuth, types::FraudCheckData, types::FraudCheckResponseData>
+{
+}
+
+/// Fraud and risk management connectors. They don't process payments, so they are kept apart
+/// from [`super::Connector`].
+pub trait FraudCheck:
+    ConnectorCommon + FraudCheckPreAuth + FraudCheckPostAuth + IncomingWebhook + Debug + Send
+{
+}
+
+impl<
+        T: ConnectorCommon + FraudCheckPreAuth + FraudCheckPostAuth + IncomingWebhook + Debug + Send,
+    > FraudCheck for T
+{
+}
+
+type BoxedFraudCheckConnector = Box<&'static (dyn FraudCheck + Sync)>;
+
+pub struct FraudCheckConnectorData {
+    pub connector: BoxedFraudCheckConnector,
+    pub connector_name: api_enums::FrmConnectors,
+}
+
+impl FraudCheckConnectorData {
+    pub fn get_connector_by_name(name: &str) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector_name = api_enums::FrmConnectors::from_str(name)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidConnectorName)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("invalid fraud check connector name: {name}"))?;
+        Ok(Self {
+            connector: Self::convert_connector(connector_name),
+            connector_name,
+        })
+    }
+
+    fn convert_connector(connector_name: api_enums::FrmConnectors) -> BoxedFraudCheckConnector {
+        match connector_name {
+            api_enums::FrmConnectors::Signifyd => Box::new(&connector::Signifyd),
+            api_enums::FrmConnectors::Riskified => Box::new(&connector::Riskified),
+        }
+    }
+}
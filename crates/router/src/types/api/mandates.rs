@@ -10,14 +10,27 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api,
+        self, api,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
     utils::OptionExt,
 };
 
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+}
+
 newtype!(
     pub MandateCardDetails = mandates::MandateCardDetails,
     derives = (Default, Debug, Deserialize, Serialize)
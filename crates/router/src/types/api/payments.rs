@@ -1,13 +1,15 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
+    ConnectorAttemptResponse, CustomerAcceptance, FraudCheckDetails, MandateData, MandateTxnType,
+    MandateType, MandateValidationFields, NextAction, NextActionType, OnlineMandate, PayLaterData,
+    PaymentAttemptListResponse, PaymentAttemptResponse, PaymentIdType, PaymentListConstraints,
+    PaymentListResponse, PaymentListSortBy, PaymentMethodData, PaymentMethodDataResponse,
+    PaymentOp, PaymentRetrieveBody, PaymentSearchRequest, PaymentSearchResponse,
+    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
     PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
     PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    PgRedirectResponse, PhoneDetails, QrCodeInformation, RedirectionResponse, SessionToken,
+    UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -67,6 +69,9 @@ pub struct Authorize;
 
 #[derive(Debug, Clone)]
 pub struct AuthorizeSessionToken;
+
+#[derive(Debug, Clone)]
+pub struct InitPayment;
 #[derive(Debug, Clone)]
 pub struct Capture;
 
@@ -81,6 +86,20 @@ pub struct Session;
 #[derive(Debug, Clone)]
 pub struct Verify;
 
+/// Registers the customer of a payment, for connectors which only debit the bank accounts of
+/// customers known to them
+#[derive(Debug, Clone)]
+pub struct CreateConnectorCustomer;
+
+/// Registers the bank account of a bank debit with the customer created at the connector
+#[derive(Debug, Clone)]
+pub struct CreateBankAccount;
+
+/// Sets up the debit mandate on a bank account registered at the connector, for connectors which
+/// only debit bank accounts through a mandate
+#[derive(Debug, Clone)]
+pub struct CreateMandate;
+
 pub(crate) trait PaymentIdTypeExt {
     fn get_payment_intent_id(&self) -> errors::CustomResult<String, errors::ValidationError>;
 }
@@ -129,6 +148,16 @@ impl ForeignFrom<storage::PaymentIntent> for PaymentsResponse {
             description: item.description,
             metadata: item.metadata,
             customer_id: item.customer_id,
+            split_payments: item
+                .split_payments
+                .and_then(|split_payments| serde_json::from_value(split_payments).ok()),
+            forex_quote: item
+                .forex_quote
+                .and_then(|forex_quote| serde_json::from_value(forex_quote).ok()),
+            profile_id: item.profile_id,
+            connector_metadata: item
+                .connector_metadata
+                .and_then(|connector_metadata| serde_json::from_value(connector_metadata).ok()),
             ..Default::default()
         }
     }
@@ -193,6 +222,8 @@ mod payments_test {
             card_cvc: "123".to_string().into(),
             card_issuer: Some("HDFC".to_string()),
             card_network: Some(api_models::enums::CardNetwork::Visa),
+            card_issuing_country: None,
+            card_type: None,
         }
     }
 
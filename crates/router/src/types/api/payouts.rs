@@ -0,0 +1,54 @@
+pub use api_models::payouts::{
+    BankPayout, CardPayout, PayoutCreateRequest, PayoutCreateResponse, PayoutMethodData,
+    PayoutRetrieveBody, PayoutRetrieveRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+
+pub trait PayoutCreate:
+    api::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PoSync;
+
+pub trait PayoutSync:
+    api::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+/// Quotes the exchange rate of a payout, for connectors which need a quote before the payout
+/// is created
+#[derive(Debug, Clone)]
+pub struct PoQuote;
+
+/// Registers the payout method data as a recipient, for connectors which only pay out to
+/// recipients known to them
+#[derive(Debug, Clone)]
+pub struct PoRecipient;
+
+pub trait Payouts:
+    ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutCancel + PayoutSync
+{
+}
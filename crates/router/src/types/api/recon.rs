@@ -0,0 +1,4 @@
+pub use api_models::recon::{
+    ReconRecordListConstraints, ReconRecordResponse, ReconReportFetchRequest,
+    ReconReportListConstraints, ReconReportResponse, ReconReportUploadParams,
+};
@@ -1,5 +1,6 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
+    CreditRequest, CreditResponse, RefundBulkItem, RefundBulkRequest, RefundBulkResponse,
+    RefundBulkStatus, RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
 };
 
 use super::ConnectorCommon;
@@ -24,6 +25,8 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
 pub struct Execute;
 #[derive(Debug, Clone)]
 pub struct RSync;
+#[derive(Debug, Clone)]
+pub struct Credit;
 
 pub trait RefundExecute:
     api::ConnectorIntegration<Execute, types::RefundsData, types::RefundsResponseData>
@@ -35,4 +38,9 @@ pub trait RefundSync:
 {
 }
 
-pub trait Refund: ConnectorCommon + RefundExecute + RefundSync {}
+pub trait RefundCredit:
+    api::ConnectorIntegration<Credit, types::CreditsData, types::RefundsResponseData>
+{
+}
+
+pub trait Refund: ConnectorCommon + RefundExecute + RefundSync + RefundCredit {}
@@ -0,0 +1,19 @@
+pub use api_models::routing::{
+    AmountBand, ConnectorVolumeSplit, RoutingConditions, RoutingConfig, RoutingConfigCreateRequest,
+    RoutingConfigResponse, RoutingRule,
+};
+
+use crate::types::transformers::ForeignFrom;
+
+impl ForeignFrom<(RoutingConfig, bool)> for RoutingConfigResponse {
+    fn foreign_from((config, is_active): (RoutingConfig, bool)) -> Self {
+        Self {
+            routing_id: config.routing_id,
+            name: config.name,
+            rules: config.rules,
+            default_connectors: config.default_connectors,
+            is_active,
+            created_at: config.created_at,
+        }
+    }
+}
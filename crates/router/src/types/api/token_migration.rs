@@ -0,0 +1,4 @@
+pub use api_models::token_migration::{
+    AdyenRecurringDetailExport, StripeCardExport, StripeCustomerExport, TokenExport,
+    TokenMigrationCardResponse, TokenMigrationRequest, TokenMigrationResponse,
+};
@@ -0,0 +1 @@
+pub use api_models::user::{DashboardEntryResponse, LoginRequest, SignUpRequest};
@@ -0,0 +1,3 @@
+pub use api_models::user_roles::{
+    CreateUserRoleRequest, DeleteUserRoleResponse, UpdateUserRoleRequest, UserRoleResponse,
+};
@@ -1,17 +1,88 @@
 pub use api_models::webhooks::{
-    IncomingWebhookDetails, IncomingWebhookEvent, IncomingWebhookRequestDetails,
-    MerchantWebhookConfig, OutgoingWebhook, OutgoingWebhookContent, WebhookFlow,
+    EventListConstraints, EventResponse, EventRetrieveResponse, IncomingWebhookDetails,
+    IncomingWebhookEvent, IncomingWebhookRequestDetails, MerchantWebhookConfig, OutgoingWebhook,
+    OutgoingWebhookContent, WebhookDeliveryAttemptResponse, WebhookFlow,
 };
-use error_stack::ResultExt;
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
 
-use super::ConnectorCommon;
+use super::{ConnectorCommon, DisputePayload};
 use crate::{
-    core::errors::{self, CustomResult},
+    consts,
+    core::{
+        errors::{self, CustomResult},
+        webhooks::utils as webhooks_utils,
+    },
     db::StorageInterface,
-    services,
+    headers, services,
     utils::crypto,
 };
 
+/// How a connector authenticates the webhooks it sends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookSourceVerificationScheme {
+    /// Webhooks are not authenticated, and are trusted as they are
+    NoVerification,
+    HmacSha256,
+    HmacSha512,
+    RsaSha1,
+    RsaSha256,
+    /// The signature is the MD5 digest of the message
+    Md5,
+    /// The signature is the SHA-256 digest of the message
+    Sha256,
+    /// Webhooks carry the `username:password` pair the merchant configured in a basic
+    /// `Authorization` header
+    BasicAuth,
+}
+
+impl WebhookSourceVerificationScheme {
+    pub fn get_algorithm(self) -> Box<dyn crypto::VerifySignature + Send> {
+        match self {
+            Self::NoVerification => Box::new(crypto::NoAlgorithm),
+            Self::HmacSha256 => Box::new(crypto::HmacSha256),
+            Self::HmacSha512 => Box::new(crypto::HmacSha512),
+            Self::RsaSha1 => Box::new(crypto::RsaSha1),
+            Self::RsaSha256 => Box::new(crypto::RsaSha256),
+            Self::Md5 => Box::new(crypto::Md5),
+            Self::Sha256 => Box::new(crypto::Sha256),
+            Self::BasicAuth => Box::new(BasicAuthCredentials),
+        }
+    }
+}
+
+/// Checks the credentials of a basic `Authorization` header, passed as the signature, against the
+/// ones the merchant configured, passed as the secret
+struct BasicAuthCredentials;
+
+impl crypto::VerifySignature for BasicAuthCredentials {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        _msg: &[u8],
+    ) -> CustomResult<bool, common_utils::errors::CryptoError> {
+        Ok(ring::constant_time::verify_slices_are_equal(secret, signature).is_ok())
+    }
+}
+
+fn get_basic_auth_credentials(
+    request: &IncomingWebhookRequestDetails<'_>,
+) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+    let credentials = request
+        .headers
+        .get(headers::AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Basic "))
+        .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+        .into_report()?;
+
+    consts::BASE64_ENGINE
+        .decode(credentials.trim())
+        .into_report()
+        .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+}
+
 #[async_trait::async_trait]
 pub trait IncomingWebhook: ConnectorCommon + Sync {
     fn get_webhook_body_decoding_algorithm(
@@ -57,26 +128,33 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
             .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
     }
 
-    fn get_webhook_source_verification_algorithm(
+    /// Declares how the connector authenticates its webhooks, which decides the algorithm they
+    /// are verified with and whether a secret is needed for it
+    fn get_webhook_source_verification_scheme(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::NoAlgorithm))
+    ) -> CustomResult<WebhookSourceVerificationScheme, errors::ConnectorError> {
+        Ok(WebhookSourceVerificationScheme::NoVerification)
     }
 
+    /// The secret is the one configured with the merchant connector account, for every scheme
+    /// but [`WebhookSourceVerificationScheme::NoVerification`]
     async fn get_webhook_source_verification_merchant_secret(
         &self,
-        _db: &dyn StorageInterface,
-        _merchant_id: &str,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
     ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        Ok(Vec::new())
+        webhooks_utils::get_webhook_source_verification_secret(db, self.id(), merchant_id).await
     }
 
     fn get_webhook_source_verification_signature(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        Ok(Vec::new())
+        match self.get_webhook_source_verification_scheme(request)? {
+            WebhookSourceVerificationScheme::BasicAuth => get_basic_auth_credentials(request),
+            _ => Ok(Vec::new()),
+        }
     }
 
     fn get_webhook_source_verification_message(
@@ -94,9 +172,12 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         request: &IncomingWebhookRequestDetails<'_>,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::ConnectorError> {
-        let algorithm = self
-            .get_webhook_source_verification_algorithm(request)
+        let scheme = self
+            .get_webhook_source_verification_scheme(request)
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        if scheme == WebhookSourceVerificationScheme::NoVerification {
+            return Ok(true);
+        }
 
         let signature = self
             .get_webhook_source_verification_signature(request)
@@ -109,7 +190,8 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
             .get_webhook_source_verification_message(request, merchant_id, &secret)
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
 
-        algorithm
+        scheme
+            .get_algorithm()
             .verify_signature(&secret, &signature, &message)
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
     }
@@ -129,9 +211,25 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError>;
 
+    fn get_dispute_details(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<DisputePayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("get_dispute_details method".to_string()).into())
+    }
+
+    async fn get_webhook_api_response_merchant_secret(
+        &self,
+        _db: &dyn StorageInterface,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(Vec::new())
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
+        _merchant_secret: &[u8],
     ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
     {
         Ok(services::api::ApplicationResponse::StatusOk)
@@ -1,20 +1,35 @@
 pub mod address;
+pub mod analytics;
 pub mod api_keys;
+pub mod audit_log;
+pub mod blocklist;
+pub mod business_profile;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod credits;
 pub mod customers;
+pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod locker_card;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
+pub mod recon;
 pub mod reverse_lookup;
+pub mod user;
+pub mod user_role;
+pub mod webhook_delivery_attempt;
 
 mod query;
 pub mod refund;
@@ -23,8 +38,10 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, configs::*, connector_response::*, customers::*, events::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    address::*, analytics::*, api_keys::*, audit_log::*, blocklist::*, business_profile::*,
+    capture::*, configs::*, connector_response::*, credits::*, customers::*, dispute::*, events::*,
+    locker_card::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, organization::*, payment_attempt::*,
+    payment_intent::*, payment_method::*, payouts::*, process_tracker::*, recon::*, refund::*,
+    reverse_lookup::*, user::*, user_role::*, webhook_delivery_attempt::*,
 };
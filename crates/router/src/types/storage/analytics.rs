@@ -0,0 +1,173 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{
+    sql_types::{Array, BigInt, Double, Nullable, Text, Timestamp},
+    QueryableByName,
+};
+use error_stack::{IntoReport, ResultExt};
+use storage_models::errors;
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection::PgPooledConn,
+    logger,
+    types::{api, storage::enums as storage_enums, transformers::ForeignFrom},
+};
+
+/// Statuses of the payment attempts which were authorized or charged
+pub const SUCCESS_ATTEMPT_STATUSES: [storage_enums::AttemptStatus; 3] = [
+    storage_enums::AttemptStatus::Authorized,
+    storage_enums::AttemptStatus::Charged,
+    storage_enums::AttemptStatus::PartialCharged,
+];
+
+/// Statuses of the payment attempts which were completed at the connector, whose latency is
+/// aggregated
+pub const COMPLETED_ATTEMPT_STATUSES: [storage_enums::AttemptStatus; 5] = [
+    storage_enums::AttemptStatus::Authorized,
+    storage_enums::AttemptStatus::Charged,
+    storage_enums::AttemptStatus::PartialCharged,
+    storage_enums::AttemptStatus::AuthorizationFailed,
+    storage_enums::AttemptStatus::Failure,
+];
+
+/// Comma separated list of the statuses, quoted to be used in a query
+pub fn quote_statuses(statuses: &[storage_enums::AttemptStatus]) -> String {
+    statuses
+        .iter()
+        .map(|status| format!("'{status}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Aggregations of the payment attempts of a time bucket and group. The fields which the
+/// aggregations are not grouped by are None. Read from the database, or from the query responses
+/// of ClickHouse.
+#[derive(Debug, QueryableByName, serde::Deserialize)]
+pub struct PaymentAnalyticsRow {
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub time_bucket: Option<PrimitiveDateTime>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub connector: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub payment_method: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub currency: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub status: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    pub payment_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub success_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_amount: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub avg_latency_ms: Option<f64>,
+}
+
+#[async_trait::async_trait]
+pub trait PaymentAnalyticsDbExt: Sized {
+    async fn aggregate(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        req: &api::PaymentAnalyticsRequest,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentAnalyticsDbExt for PaymentAnalyticsRow {
+    async fn aggregate(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        req: &api::PaymentAnalyticsRequest,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        // Only the names of the columns and the granularity, none of which come from the request
+        // as is, are written in the query, the values are bound
+        let time_bucket = match req.granularity {
+            Some(granularity) => format!("date_trunc('{granularity}', created_at)"),
+            None => "NULL::TIMESTAMP".to_string(),
+        };
+        let dimension = |dimension: api::PaymentAnalyticsDimension, column: &str| {
+            if req.group_by.contains(&dimension) {
+                format!("{column}::TEXT")
+            } else {
+                "NULL::TEXT".to_string()
+            }
+        };
+        let query = format!(
+            "SELECT {time_bucket} AS time_bucket, \
+                {} AS connector, {} AS payment_method, {} AS currency, {} AS status, \
+                COUNT(*) AS payment_count, \
+                COUNT(*) FILTER (WHERE status IN ({success})) AS success_count, \
+                COALESCE(SUM(amount) FILTER (WHERE status IN ({success})), 0)::BIGINT \
+                    AS total_amount, \
+                (AVG(EXTRACT(EPOCH FROM (modified_at - created_at)) * 1000) \
+                    FILTER (WHERE status IN ({completed})))::DOUBLE PRECISION AS avg_latency_ms \
+            FROM payment_attempt \
+            WHERE merchant_id = $1 AND created_at >= $2 AND created_at < $3 \
+                AND (cardinality($4) = 0 OR connector = ANY($4)) \
+                AND (cardinality($5) = 0 OR payment_method = ANY($5)) \
+                AND (cardinality($6) = 0 OR currency::TEXT = ANY($6)) \
+                AND (cardinality($7) = 0 OR status::TEXT = ANY($7)) \
+            GROUP BY 1, 2, 3, 4, 5 \
+            ORDER BY 1, 2, 3, 4, 5",
+            dimension(api::PaymentAnalyticsDimension::Connector, "connector"),
+            dimension(
+                api::PaymentAnalyticsDimension::PaymentMethod,
+                "payment_method"
+            ),
+            dimension(api::PaymentAnalyticsDimension::Currency, "currency"),
+            dimension(api::PaymentAnalyticsDimension::Status, "status"),
+            success = quote_statuses(&SUCCESS_ATTEMPT_STATUSES),
+            completed = quote_statuses(&COMPLETED_ATTEMPT_STATUSES),
+        );
+
+        let filters = &req.filters;
+        let query = diesel::sql_query(query)
+            .bind::<Text, _>(merchant_id.to_owned())
+            .bind::<Timestamp, _>(req.time_range.start_time)
+            .bind::<Timestamp, _>(end_time)
+            .bind::<Array<Text>, _>(
+                filters
+                    .connector
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Text>, _>(
+                filters
+                    .payment_method
+                    .iter()
+                    .map(|payment_method| {
+                        storage_enums::PaymentMethod::foreign_from(*payment_method).to_string()
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Text>, _>(
+                filters
+                    .currency
+                    .iter()
+                    .map(|currency| storage_enums::Currency::foreign_from(*currency).to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Text>, _>(
+                filters
+                    .status
+                    .iter()
+                    .map(|status| storage_enums::AttemptStatus::foreign_from(*status).to_string())
+                    .collect::<Vec<_>>(),
+            );
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error aggregating payment attempts")
+    }
+}
@@ -0,0 +1,61 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::audit_log::{AuditLog, AuditLogNew};
+use storage_models::{errors, schema::audit_log::dsl};
+
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
+
+#[async_trait::async_trait]
+pub trait AuditLogDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        audit_log_list_constraints: api_models::audit_logs::AuditLogListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogDbExt for AuditLog {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        audit_log_list_constraints: api_models::audit_logs::AuditLogListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(resource_type) = audit_log_list_constraints.resource_type {
+            let storage_resource_type: storage_models::enums::AuditResourceType =
+                resource_type.foreign_into();
+            filter = filter.filter(dsl::resource_type.eq(storage_resource_type));
+        }
+        if let Some(resource_id) = audit_log_list_constraints.resource_id {
+            filter = filter.filter(dsl::resource_id.eq(resource_id));
+        }
+        if let Some(actor_id) = audit_log_list_constraints.actor_id {
+            filter = filter.filter(dsl::actor_id.eq(actor_id));
+        }
+        if let Some(created_lt) = audit_log_list_constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = audit_log_list_constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(limit) = audit_log_list_constraints.limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering audit logs by constraints")
+    }
+}
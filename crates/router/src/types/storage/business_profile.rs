@@ -0,0 +1,3 @@
+pub use storage_models::business_profile::{
+    BusinessProfile, BusinessProfileNew, BusinessProfileUpdate,
+};
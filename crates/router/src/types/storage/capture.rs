@@ -0,0 +1 @@
+pub use storage_models::capture::{Capture, CaptureNew, CaptureUpdate};
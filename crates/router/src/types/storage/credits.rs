@@ -0,0 +1 @@
+pub use storage_models::credits::{Credit, CreditNew, CreditUpdate};
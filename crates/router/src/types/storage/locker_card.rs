@@ -0,0 +1 @@
+pub use storage_models::locker_card::{LockerCard, LockerCardNew};
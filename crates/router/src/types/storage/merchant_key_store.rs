@@ -0,0 +1 @@
+pub use storage_models::merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew};
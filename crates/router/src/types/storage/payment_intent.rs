@@ -1,16 +1,28 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{
+    associations::HasTable,
+    dsl::sql,
+    sql_types::{Bool, Text},
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, QueryDsl,
+};
 use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 pub use storage_models::{
     errors,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
-    schema::payment_intent::dsl,
+    schema::{
+        customers::dsl as customer_dsl, payment_attempt::dsl as attempt_dsl, payment_intent::dsl,
+    },
 };
 
-use crate::{connection::PgPooledConn, core::errors::CustomResult, types::api};
+use crate::{
+    connection::PgPooledConn,
+    core::errors::CustomResult,
+    types::{api, storage::enums as storage_enums, transformers::ForeignFrom},
+};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for PaymentIntent {}
@@ -22,6 +34,12 @@ pub trait PaymentIntentDbExt: Sized {
         merchant_id: &str,
         pc: &api::PaymentListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        req: &api::PaymentSearchRequest,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -33,19 +51,19 @@ impl PaymentIntentDbExt for PaymentIntent {
         pc: &api::PaymentListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let customer_id = &pc.customer_id;
-        let starting_after = &pc.starting_after;
-        let ending_before = &pc.ending_before;
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
         // when https://github.com/rust-lang/rust/issues/52662 becomes stable
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
             .into_boxed();
 
         if let Some(customer_id) = customer_id {
             filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
         }
+        if let Some(profile_id) = &pc.profile_id {
+            filter = filter.filter(dsl::profile_id.eq(profile_id.to_owned()));
+        }
         if let Some(created) = pc.created {
             filter = filter.filter(dsl::created_at.eq(created));
         }
@@ -59,30 +77,155 @@ impl PaymentIntentDbExt for PaymentIntent {
             filter = filter.filter(dsl::created_at.le(created_lte));
         }
         if let Some(created_gte) = pc.created_gte {
-            filter = filter.filter(dsl::created_at.gt(created_gte));
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(status) = pc.status {
+            filter =
+                filter.filter(dsl::status.eq(storage_enums::IntentStatus::foreign_from(status)));
+        }
+        if let Some(currency) = pc.currency {
+            filter =
+                filter.filter(dsl::currency.eq(storage_enums::Currency::foreign_from(currency)));
         }
-        if let Some(starting_after) = starting_after {
-            let id = Self::find_by_payment_id_merchant_id(conn, starting_after, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.gt(id));
+        if let Some(amount_gte) = pc.amount_gte {
+            filter = filter.filter(dsl::amount.ge(amount_gte));
         }
-        if let Some(ending_before) = ending_before {
-            let id = Self::find_by_payment_id_merchant_id(conn, ending_before, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.lt(id.to_owned()));
+        if let Some(amount_lte) = pc.amount_lte {
+            filter = filter.filter(dsl::amount.le(amount_lte));
+        }
+        if pc.connector.is_some() || pc.payment_method.is_some() {
+            let mut attempts = attempt_dsl::payment_attempt
+                .select(attempt_dsl::payment_id)
+                .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                .into_boxed();
+            if let Some(connector) = pc.connector {
+                attempts = attempts.filter(attempt_dsl::connector.eq(connector.to_string()));
+            }
+            if let Some(payment_method) = pc.payment_method {
+                attempts = attempts.filter(
+                    attempt_dsl::payment_method
+                        .eq(storage_enums::PaymentMethod::foreign_from(payment_method)),
+                );
+            }
+            filter = filter.filter(dsl::payment_id.eq_any(attempts));
+        }
+
+        // The payments before `ending_before` are fetched in the reverse order, so that the limit
+        // keeps those closest to it, and are put back in order once fetched
+        let cursor = match (&pc.starting_after, &pc.ending_before) {
+            (Some(payment_id), _) | (None, Some(payment_id)) => {
+                Some(Self::find_by_payment_id_merchant_id(conn, payment_id, merchant_id).await?)
+            }
+            (None, None) => None,
+        };
+        let reverse = pc.ending_before.is_some();
+        let ascending = matches!(pc.sort_order, api::enums::SortOrder::Asc) != reverse;
+
+        // Orders the payments by the column, then by their creation, and keeps those coming after
+        // the cursor in that order
+        macro_rules! sort_by {
+            ($column:expr, $field:ident) => {{
+                if let Some(cursor) = &cursor {
+                    filter = if ascending {
+                        filter.filter(
+                            $column
+                                .gt(cursor.$field)
+                                .or($column.eq(cursor.$field).and(dsl::id.gt(cursor.id))),
+                        )
+                    } else {
+                        filter.filter(
+                            $column
+                                .lt(cursor.$field)
+                                .or($column.eq(cursor.$field).and(dsl::id.lt(cursor.id))),
+                        )
+                    };
+                }
+                filter = if ascending {
+                    filter.order(($column.asc(), dsl::id.asc()))
+                } else {
+                    filter.order(($column.desc(), dsl::id.desc()))
+                };
+            }};
+        }
+        match pc.sort_by {
+            api::PaymentListSortBy::CreatedAt => sort_by!(dsl::created_at, created_at),
+            api::PaymentListSortBy::Amount => sort_by!(dsl::amount, amount),
         }
 
         filter = filter.limit(pc.limit);
 
         crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
+        let mut payment_intents: Vec<Self> = filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")?;
+        if reverse {
+            payment_intents.reverse();
+        }
+        Ok(payment_intents)
+    }
+
+    #[instrument(skip(conn))]
+    async fn search(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        req: &api::PaymentSearchRequest,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(customer_email) = &req.customer_email {
+            let customers = customer_dsl::customers
+                .select(customer_dsl::customer_id.nullable())
+                .filter(customer_dsl::merchant_id.eq(merchant_id.to_owned()))
+                .filter(customer_dsl::email.eq(customer_email.peek().to_owned()));
+            filter = filter.filter(dsl::customer_id.eq_any(customers));
+        }
+        if let Some(metadata) = &req.metadata {
+            for (key, value) in metadata {
+                filter = filter.filter(
+                    sql::<Bool>("payment_intent.metadata ->> ")
+                        .bind::<Text, _>(key.to_owned())
+                        .sql(" = ")
+                        .bind::<Text, _>(value.to_owned()),
+                );
+            }
+        }
+        if req.card_last4.is_some() || req.connector_transaction_id.is_some() {
+            let mut attempts = attempt_dsl::payment_attempt
+                .select(attempt_dsl::payment_id)
+                .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                .into_boxed();
+            if let Some(connector_transaction_id) = &req.connector_transaction_id {
+                attempts = attempts.filter(
+                    attempt_dsl::connector_transaction_id.eq(connector_transaction_id.to_owned()),
+                );
+            }
+            // The card details of the attempts are only kept in the additional payment data
+            if let Some(card_last4) = &req.card_last4 {
+                attempts = attempts.filter(
+                    sql::<Bool>("payment_attempt.payment_method_data -> 'card' ->> 'last4' = ")
+                        .bind::<Text, _>(card_last4.to_owned()),
+                );
+            }
+            filter = filter.filter(dsl::payment_id.eq_any(attempts));
+        }
+
+        filter = filter
+            .order((dsl::created_at.desc(), dsl::id.desc()))
+            .limit(req.limit);
+
+        crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
         filter
             .get_results_async(conn)
             .await
             .into_report()
             .change_context(errors::DatabaseError::NotFound)
-            .attach_printable_lazy(|| "Error filtering records by predicate")
+            .attach_printable_lazy(|| "Error searching records by predicate")
     }
 }
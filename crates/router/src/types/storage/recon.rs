@@ -0,0 +1,81 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::recon::{
+    ReconRecord, ReconRecordNew, ReconRecordUpdate, ReconReport, ReconReportNew, ReconReportUpdate,
+};
+use storage_models::{enums as storage_enums, errors, schema::recon_record::dsl};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait ReconRecordDbExt: Sized {
+    async fn filter_by_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+        recon_status: Option<storage_enums::ReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn get_status_counts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<(storage_enums::ReconStatus, i64)>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl ReconRecordDbExt for ReconRecord {
+    async fn filter_by_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+        recon_status: Option<storage_enums::ReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::report_id.eq(report_id.to_owned()))
+            .order(dsl::line_number.asc())
+            .into_boxed();
+
+        if let Some(recon_status) = recon_status {
+            filter = filter.filter(dsl::recon_status.eq(recon_status));
+        }
+        filter = filter.limit(limit).offset(offset);
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering recon records by report")
+    }
+
+    async fn get_status_counts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<(storage_enums::ReconStatus, i64)>, errors::DatabaseError> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::report_id.eq(report_id.to_owned()))
+            .group_by(dsl::recon_status)
+            .select((dsl::recon_status, diesel::dsl::count_star()));
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error counting recon records by status")
+    }
+}
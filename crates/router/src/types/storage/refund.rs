@@ -7,7 +7,7 @@ pub use storage_models::refund::{
 };
 use storage_models::{errors, schema::refund::dsl};
 
-use crate::{connection::PgPooledConn, logger};
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignFrom};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for Refund {}
@@ -44,6 +44,11 @@ impl RefundDbExt for Refund {
             }
         };
 
+        if let Some(reason_code) = refund_list_details.reason_code {
+            filter = filter.filter(dsl::refund_reason_code.eq(
+                storage_models::enums::RefundReason::foreign_from(reason_code),
+            ));
+        }
         if let Some(created) = refund_list_details.created {
             filter = filter.filter(dsl::created_at.eq(created));
         }
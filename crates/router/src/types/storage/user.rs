@@ -0,0 +1 @@
+pub use storage_models::user::{User, UserNew};
@@ -0,0 +1 @@
+pub use storage_models::user_role::{UserRole, UserRoleNew, UserRoleUpdate};
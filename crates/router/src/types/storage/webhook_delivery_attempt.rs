@@ -0,0 +1,3 @@
+pub use storage_models::webhook_delivery_attempt::{
+    WebhookDeliveryAttempt, WebhookDeliveryAttemptNew,
+};
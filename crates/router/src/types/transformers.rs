@@ -127,11 +127,11 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             storage_enums::AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             storage_enums::AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
-            storage_enums::AttemptStatus::Authorized => Self::RequiresCapture,
+            storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartialCharged => Self::RequiresCapture,
             storage_enums::AttemptStatus::AuthenticationPending => Self::RequiresCustomerAction,
 
-            storage_enums::AttemptStatus::PartialCharged
-            | storage_enums::AttemptStatus::Started
+            storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -156,6 +156,7 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
     fn foreign_try_from(value: api_enums::IntentStatus) -> Result<Self, Self::Error> {
         match value {
             api_enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -169,10 +170,11 @@ impl ForeignTryFrom<storage_enums::RefundStatus> for storage_enums::EventType {
     fn foreign_try_from(value: storage_enums::RefundStatus) -> Result<Self, Self::Error> {
         match value {
             storage_enums::RefundStatus::Success => Ok(Self::RefundSucceeded),
-            storage_enums::RefundStatus::Failure => Ok(Self::RefundFailed),
-            _ => Err(errors::ValidationError::IncorrectValueProvided {
-                field_name: "refund_status",
-            }),
+            storage_enums::RefundStatus::Failure
+            | storage_enums::RefundStatus::TransactionFailure => Ok(Self::RefundFailed),
+            storage_enums::RefundStatus::Pending | storage_enums::RefundStatus::ManualReview => {
+                Ok(Self::RefundUpdated)
+            }
         }
     }
 }
@@ -193,12 +195,115 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::DisputeStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::DisputeStatus::DisputeOpened => Ok(Self::DisputeOpened),
+            storage_enums::DisputeStatus::DisputeExpired => Ok(Self::DisputeExpired),
+            storage_enums::DisputeStatus::DisputeAccepted => Ok(Self::DisputeAccepted),
+            storage_enums::DisputeStatus::DisputeCancelled => Ok(Self::DisputeCancelled),
+            storage_enums::DisputeStatus::DisputeChallenged => Ok(Self::DisputeChallenged),
+            storage_enums::DisputeStatus::DisputeWon => Ok(Self::DisputeWon),
+            storage_enums::DisputeStatus::DisputeLost => Ok(Self::DisputeLost),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::DisputeStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::DisputeOpened => Ok(Self::DisputeOpened),
+            api_models::webhooks::IncomingWebhookEvent::DisputeExpired => Ok(Self::DisputeExpired),
+            api_models::webhooks::IncomingWebhookEvent::DisputeAccepted => {
+                Ok(Self::DisputeAccepted)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeCancelled => {
+                Ok(Self::DisputeCancelled)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeChallenged => {
+                Ok(Self::DisputeChallenged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeWon => Ok(Self::DisputeWon),
+            api_models::webhooks::IncomingWebhookEvent::DisputeLost => Ok(Self::DisputeLost),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<storage_enums::MandateStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::MandateStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::MandateStatus::Active => Ok(Self::MandateActive),
+            storage_enums::MandateStatus::Revoked => Ok(Self::MandateRevoked),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "mandate_status",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::MandateStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::MandateActive => Ok(Self::Active),
+            api_models::webhooks::IncomingWebhookEvent::MandateRevoked => Ok(Self::Revoked),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::EventType> for api_enums::EventType {
     fn foreign_from(event_type: storage_enums::EventType) -> Self {
         frunk::labelled_convert_from(event_type)
     }
 }
 
+impl ForeignFrom<api_enums::EventType> for storage_enums::EventType {
+    fn foreign_from(event_type: api_enums::EventType) -> Self {
+        frunk::labelled_convert_from(event_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::AuditActorType> for api_enums::AuditActorType {
+    fn foreign_from(actor_type: storage_enums::AuditActorType) -> Self {
+        frunk::labelled_convert_from(actor_type)
+    }
+}
+
+impl ForeignFrom<api_enums::AuditResourceType> for storage_enums::AuditResourceType {
+    fn foreign_from(resource_type: api_enums::AuditResourceType) -> Self {
+        frunk::labelled_convert_from(resource_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::AuditResourceType> for api_enums::AuditResourceType {
+    fn foreign_from(resource_type: storage_enums::AuditResourceType) -> Self {
+        frunk::labelled_convert_from(resource_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::EventObjectType> for api_enums::EventObjectType {
+    fn foreign_from(object_type: storage_enums::EventObjectType) -> Self {
+        frunk::labelled_convert_from(object_type)
+    }
+}
+
 impl ForeignFrom<api_enums::FutureUsage> for storage_enums::FutureUsage {
     fn foreign_from(future_usage: api_enums::FutureUsage) -> Self {
         frunk::labelled_convert_from(future_usage)
@@ -229,6 +334,139 @@ impl ForeignFrom<storage_enums::CaptureMethod> for api_enums::CaptureMethod {
     }
 }
 
+impl ForeignFrom<storage_enums::CaptureStatus> for api_enums::CaptureStatus {
+    fn foreign_from(status: storage_enums::CaptureStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutStatus> for api_enums::PayoutStatus {
+    fn foreign_from(status: storage_enums::PayoutStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::RoleName> for storage_enums::RoleName {
+    fn foreign_from(role: api_enums::RoleName) -> Self {
+        frunk::labelled_convert_from(role)
+    }
+}
+
+impl ForeignFrom<storage_enums::RoleName> for api_enums::RoleName {
+    fn foreign_from(role: storage_enums::RoleName) -> Self {
+        frunk::labelled_convert_from(role)
+    }
+}
+
+impl ForeignFrom<api_enums::PayoutType> for storage_enums::PayoutType {
+    fn foreign_from(payout_type: api_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutType> for api_enums::PayoutType {
+    fn foreign_from(payout_type: storage_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<api_enums::RefundReason> for storage_enums::RefundReason {
+    fn foreign_from(reason: api_enums::RefundReason) -> Self {
+        frunk::labelled_convert_from(reason)
+    }
+}
+
+impl ForeignFrom<storage_enums::RefundReason> for api_enums::RefundReason {
+    fn foreign_from(reason: storage_enums::RefundReason) -> Self {
+        frunk::labelled_convert_from(reason)
+    }
+}
+
+impl ForeignFrom<api_enums::ReconReportSource> for storage_enums::ReconReportSource {
+    fn foreign_from(source: api_enums::ReconReportSource) -> Self {
+        frunk::labelled_convert_from(source)
+    }
+}
+
+impl ForeignFrom<storage_enums::ReconReportSource> for api_enums::ReconReportSource {
+    fn foreign_from(source: storage_enums::ReconReportSource) -> Self {
+        frunk::labelled_convert_from(source)
+    }
+}
+
+impl ForeignFrom<storage_enums::ReconReportStatus> for api_enums::ReconReportStatus {
+    fn foreign_from(status: storage_enums::ReconReportStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::ReconRecordType> for api_enums::ReconRecordType {
+    fn foreign_from(record_type: storage_enums::ReconRecordType) -> Self {
+        frunk::labelled_convert_from(record_type)
+    }
+}
+
+impl ForeignFrom<api_enums::ReconStatus> for storage_enums::ReconStatus {
+    fn foreign_from(status: api_enums::ReconStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::ReconStatus> for api_enums::ReconStatus {
+    fn foreign_from(status: storage_enums::ReconStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage_enums::WebhookDeliveryStatus> for api_enums::WebhookDeliveryStatus {
+    fn foreign_from(status: storage_enums::WebhookDeliveryStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::DisputeStage> for storage_enums::DisputeStage {
+    fn foreign_from(dispute_stage: api_enums::DisputeStage) -> Self {
+        frunk::labelled_convert_from(dispute_stage)
+    }
+}
+
+impl ForeignFrom<storage_enums::DisputeStage> for api_enums::DisputeStage {
+    fn foreign_from(dispute_stage: storage_enums::DisputeStage) -> Self {
+        frunk::labelled_convert_from(dispute_stage)
+    }
+}
+
+impl ForeignFrom<api_enums::DisputeStatus> for storage_enums::DisputeStatus {
+    fn foreign_from(dispute_status: api_enums::DisputeStatus) -> Self {
+        frunk::labelled_convert_from(dispute_status)
+    }
+}
+
+impl ForeignFrom<storage_enums::DisputeStatus> for api_enums::DisputeStatus {
+    fn foreign_from(dispute_status: storage_enums::DisputeStatus) -> Self {
+        frunk::labelled_convert_from(dispute_status)
+    }
+}
+
+impl ForeignFrom<storage_enums::UnifiedCode> for api_enums::UnifiedCode {
+    fn foreign_from(unified_code: storage_enums::UnifiedCode) -> Self {
+        frunk::labelled_convert_from(unified_code)
+    }
+}
+
+impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStatus {
+    fn foreign_from(status: storage_enums::AttemptStatus) -> Self {
+        match status {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged => Self::Charged,
+            storage_enums::AttemptStatus::Failure | storage_enums::AttemptStatus::CaptureFailed => {
+                Self::Failed
+            }
+            _ => Self::Pending,
+        }
+    }
+}
+
 impl ForeignFrom<api_enums::AuthenticationType> for storage_enums::AuthenticationType {
     fn foreign_from(auth_type: api_enums::AuthenticationType) -> Self {
         frunk::labelled_convert_from(auth_type)
@@ -326,6 +564,11 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount>
                 .change_context(errors::ApiErrorResponse::InternalServerError)?,
             None => None,
         };
+        let connector_webhook_details = merchant_ca
+            .connector_webhook_details
+            .map(|webhook_details| webhook_details.parse_value("MerchantConnectorWebhookDetails"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
         Ok(Self {
             connector_type: merchant_ca.connector_type.foreign_into(),
@@ -338,6 +581,7 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount>
             disabled: merchant_ca.disabled,
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
+            connector_webhook_details,
         })
     }
 }
@@ -403,6 +647,7 @@ impl
             )),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            role: api_key.role.foreign_into(),
         }
     }
 }
@@ -420,6 +665,7 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
             prefix: format!("{}-{}", api_key.key_id, api_key.prefix).into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            role: api_key.role.foreign_into(),
         }
     }
 }
@@ -434,6 +680,19 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            role: api_key.role.map(ForeignInto::foreign_into),
+        }
+    }
+}
+
+impl ForeignFrom<storage::UserRole> for api_models::user_roles::UserRoleResponse {
+    fn foreign_from(user_role: storage::UserRole) -> Self {
+        Self {
+            user_id: user_role.user_id,
+            merchant_id: user_role.merchant_id,
+            role: user_role.role.foreign_into(),
+            created: user_role.created_at,
+            last_modified: user_role.last_modified_at,
         }
     }
 }
@@ -443,3 +702,201 @@ impl ForeignFrom<storage_enums::AttemptStatus> for api_enums::AttemptStatus {
         frunk::labelled_convert_from(status)
     }
 }
+
+impl ForeignFrom<api_enums::AttemptStatus> for storage_enums::AttemptStatus {
+    fn foreign_from(status: api_enums::AttemptStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
+    fn foreign_from(capture: storage::Capture) -> Self {
+        Self {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.foreign_into(),
+            connector: capture.connector,
+            capture_sequence: capture.capture_sequence,
+            connector_capture_id: capture.connector_capture_id,
+            error_code: capture.error_code,
+            error_message: capture.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Payouts> for api_types::PayoutCreateResponse {
+    fn foreign_from(payout: storage::Payouts) -> Self {
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            connector: payout.connector,
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            customer_id: payout.customer_id,
+            description: payout.description,
+            metadata: payout.metadata,
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            created_at: payout.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
+    fn foreign_from(dispute: storage::Dispute) -> Self {
+        Self {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_stage: dispute.dispute_stage.foreign_into(),
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            created_at: dispute.dispute_created_at,
+            updated_at: dispute.updated_at,
+            received_at: dispute.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::AuditLog> for api_types::AuditLogResponse {
+    fn foreign_from(audit_log: storage::AuditLog) -> Self {
+        Self {
+            audit_log_id: audit_log.audit_log_id,
+            merchant_id: audit_log.merchant_id,
+            actor_type: audit_log.actor_type.foreign_into(),
+            actor_id: audit_log.actor_id,
+            http_method: audit_log.http_method,
+            route: audit_log.route,
+            resource_type: audit_log.resource_type.foreign_into(),
+            resource_id: audit_log.resource_id,
+            previous_status: audit_log.previous_status,
+            new_status: audit_log.new_status,
+            created: audit_log.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<api_enums::BlocklistDataKind> for storage_enums::BlocklistDataKind {
+    fn foreign_from(data_kind: api_enums::BlocklistDataKind) -> Self {
+        frunk::labelled_convert_from(data_kind)
+    }
+}
+
+impl ForeignFrom<storage_enums::BlocklistDataKind> for api_enums::BlocklistDataKind {
+    fn foreign_from(data_kind: storage_enums::BlocklistDataKind) -> Self {
+        frunk::labelled_convert_from(data_kind)
+    }
+}
+
+impl ForeignFrom<api_enums::AvsResult> for storage_enums::AvsResult {
+    fn foreign_from(avs_result: api_enums::AvsResult) -> Self {
+        frunk::labelled_convert_from(avs_result)
+    }
+}
+
+impl ForeignFrom<storage_enums::AvsResult> for api_enums::AvsResult {
+    fn foreign_from(avs_result: storage_enums::AvsResult) -> Self {
+        frunk::labelled_convert_from(avs_result)
+    }
+}
+
+impl ForeignFrom<api_enums::CvvResult> for storage_enums::CvvResult {
+    fn foreign_from(cvv_result: api_enums::CvvResult) -> Self {
+        frunk::labelled_convert_from(cvv_result)
+    }
+}
+
+impl ForeignFrom<storage_enums::CvvResult> for api_enums::CvvResult {
+    fn foreign_from(cvv_result: storage_enums::CvvResult) -> Self {
+        frunk::labelled_convert_from(cvv_result)
+    }
+}
+
+impl ForeignFrom<storage::Blocklist> for api_types::BlocklistResponse {
+    fn foreign_from(blocklist_entry: storage::Blocklist) -> Self {
+        Self {
+            fingerprint_id: blocklist_entry.fingerprint_id,
+            data_kind: blocklist_entry.data_kind.foreign_into(),
+            created: blocklist_entry.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ReconReport> for api_types::ReconReportResponse {
+    fn foreign_from(report: storage::ReconReport) -> Self {
+        Self {
+            report_id: report.report_id,
+            merchant_id: report.merchant_id,
+            connector: report.connector,
+            source: report.source.foreign_into(),
+            status: report.status.foreign_into(),
+            file_name: report.file_name,
+            total_records: report.total_records,
+            matched_records: report.matched_records,
+            mismatched_records: report.mismatched_records,
+            missing_records: report.missing_records,
+            error_message: report.error_message,
+            created: report.created_at,
+            updated: report.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ReconRecord> for api_types::ReconRecordResponse {
+    fn foreign_from(record: storage::ReconRecord) -> Self {
+        Self {
+            line_number: record.line_number,
+            record_type: record.record_type.foreign_into(),
+            connector_transaction_id: record.connector_transaction_id,
+            amount: record.amount,
+            currency: record.currency,
+            settlement_status: record.settlement_status,
+            recon_status: record.recon_status.foreign_into(),
+            payment_id: record.payment_id,
+            attempt_id: record.attempt_id,
+            refund_id: record.refund_id,
+            expected_amount: record.expected_amount,
+            expected_currency: record.expected_currency,
+            mismatch_reason: record.mismatch_reason,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Event> for api_types::EventResponse {
+    fn foreign_from(event: storage::Event) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id,
+            event_type: event.event_type.foreign_into(),
+            is_webhook_notified: event.is_webhook_notified,
+            payment_id: event.intent_reference_id,
+            object_id: event.primary_object_id,
+            object_type: event.primary_object_type.foreign_into(),
+            created: event.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::WebhookDeliveryAttempt> for api_types::WebhookDeliveryAttemptResponse {
+    fn foreign_from(delivery_attempt: storage::WebhookDeliveryAttempt) -> Self {
+        Self {
+            event_id: delivery_attempt.event_id,
+            merchant_id: delivery_attempt.merchant_id,
+            attempt_number: delivery_attempt.attempt_number,
+            delivery_status: delivery_attempt.delivery_status.foreign_into(),
+            response_status_code: delivery_attempt.response_status_code,
+            error_message: delivery_attempt.error_message,
+            created_at: delivery_attempt.created_at,
+        }
+    }
+}
@@ -41,6 +41,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: Secret::new("999".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -55,9 +57,20 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_latency: None,
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
@@ -94,9 +107,12 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             refund_amount: 100,
             connector_metadata: None,
             reason: None,
+            reason_code: None,
+            metadata: None,
             connector_refund_id: None,
         },
         payment_method_id: None,
+        connector_latency: None,
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::default(),
         connector_meta_data: None,
@@ -168,6 +184,8 @@ async fn payments_create_failure() {
                 card_cvc: Secret::new("99".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             });
 
         let response = services::api::execute_connector_processing_step(
@@ -69,6 +69,8 @@ impl AdyenTest {
                 card_cvc: Secret::new(card_cvc.to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -83,6 +85,16 @@ impl AdyenTest {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         })
     }
 }
@@ -0,0 +1,125 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct AffirmTest;
+impl ConnectorActions for AffirmTest {}
+impl utils::Connector for AffirmTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Affirm;
+        types::api::ConnectorData {
+            connector: Box::new(&Affirm),
+            connector_name: types::Connector::Affirm,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .affirm
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "affirm".to_string()
+    }
+}
+
+static CONNECTOR: AffirmTest = AffirmTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress {
+            shipping: Some(api::Address {
+                address: Some(api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    line1: Some(Secret::new("325 Pacific Ave".to_string())),
+                    city: Some("San Francisco".to_string()),
+                    state: Some(Secret::new("CA".to_string())),
+                    zip: Some(Secret::new("94111".to_string())),
+                    country: Some("US".to_string()),
+                    ..Default::default()
+                }),
+                phone: None,
+            }),
+            ..Default::default()
+        }),
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::PayLater(
+            api_models::payments::PayLaterData::AffirmRedirect {
+                financing_program: None,
+                billing_email: None,
+            },
+        ),
+        amount: 50000,
+        email: Some(Secret::new("test_user@testuser.com".to_string())),
+        order_details: Some(api_models::payments::OrderDetails {
+            product_name: "iphone 13".to_string(),
+            quantity: 1,
+        }),
+        payment_method_type: Some(enums::PaymentMethodType::Affirm),
+        payment_experience: Some(enums::PaymentExperience::RedirectToUrl),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a checkout and redirects the customer to Affirm to accept the loan.
+#[actix_web::test]
+async fn should_redirect_customer_to_affirm_checkout() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Rejects checkouts without a shipping address, which Affirm needs for the loan application.
+#[actix_web::test]
+async fn should_fail_checkout_without_shipping_address() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            Some(utils::PaymentInfo {
+                router_return_url: Some("https://hyperswitch.io".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
+
+// Rejects automatic capture, as Affirm loans are captured once the order is fulfilled.
+#[actix_web::test]
+async fn should_fail_payment_with_automatic_capture() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
@@ -0,0 +1,121 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct AfterpayClearpayTest;
+impl ConnectorActions for AfterpayClearpayTest {}
+impl utils::Connector for AfterpayClearpayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::AfterpayClearpay;
+        types::api::ConnectorData {
+            connector: Box::new(&AfterpayClearpay),
+            connector_name: types::Connector::AfterpayClearpay,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .afterpay_clearpay
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "afterpay_clearpay".to_string()
+    }
+}
+
+static CONNECTOR: AfterpayClearpayTest = AfterpayClearpayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress {
+            billing: Some(api::Address {
+                address: Some(api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    line1: Some(Secret::new("1 Martin Place".to_string())),
+                    city: Some("Sydney".to_string()),
+                    state: Some(Secret::new("NSW".to_string())),
+                    zip: Some(Secret::new("2000".to_string())),
+                    country: Some("AU".to_string()),
+                    ..Default::default()
+                }),
+                phone: None,
+            }),
+            ..Default::default()
+        }),
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::PayLater(
+            api_models::payments::PayLaterData::AfterpayClearpayRedirect {
+                billing_email: Secret::new("test_user@testuser.com".to_string()),
+                billing_name: Secret::new("John".to_string()),
+            },
+        ),
+        currency: enums::Currency::AUD,
+        payment_method_type: Some(enums::PaymentMethodType::AfterpayClearpay),
+        payment_experience: Some(enums::PaymentExperience::RedirectToUrl),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a checkout and redirects the customer to Afterpay to confirm it.
+#[actix_web::test]
+async fn should_redirect_customer_to_afterpay_checkout() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Creates the checkout against the Clearpay deployment for GBP payments.
+#[actix_web::test]
+async fn should_redirect_customer_to_clearpay_checkout_for_gbp() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                currency: enums::Currency::GBP,
+                ..get_default_payment_authorize_data().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Rejects manual capture, as checkouts are captured as soon as they are confirmed.
+#[actix_web::test]
+async fn should_fail_payment_with_manual_capture() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
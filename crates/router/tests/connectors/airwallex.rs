@@ -61,6 +61,8 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
             card_cvc: Secret::new("123".to_string()),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         }),
         capture_method: Some(storage_models::enums::CaptureMethod::Manual),
         ..utils::PaymentAuthorizeType::default().0
@@ -126,6 +128,8 @@ async fn should_sync_authorized_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             get_default_payment_info(),
         )
@@ -257,6 +261,8 @@ async fn should_sync_auto_captured_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             get_default_payment_info(),
         )
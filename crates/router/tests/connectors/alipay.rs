@@ -0,0 +1,128 @@
+use api_models::payments::AliPayRedirection;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct AlipayTest;
+impl ConnectorActions for AlipayTest {}
+impl utils::Connector for AlipayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Alipay;
+        types::api::ConnectorData {
+            connector: Box::new(&Alipay),
+            connector_name: types::Connector::Alipay,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .alipay
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "alipay".to_string()
+    }
+}
+
+static CONNECTOR: AlipayTest = AlipayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        currency: enums::Currency::USD,
+        payment_method_data: types::api::PaymentMethodData::Wallet(
+            api::WalletData::AliPayRedirect(AliPayRedirection {}),
+        ),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Wallet Positive Tests
+// Creates a payment, which the customer completes on the Alipay cashier page.
+#[actix_web::test]
+async fn should_redirect_payment_to_cashier_page() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Synchronizes a payment the customer has not paid yet.
+#[actix_web::test]
+async fn should_sync_unpaid_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    assert_ne!(txn_id, None, "Empty connector transaction id");
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::AuthenticationPending,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Wallet Negative scenarios
+// Rejects payments that are meant to be captured manually, as Alipay captures them at once.
+#[actix_web::test]
+async fn should_fail_manual_capture_payment() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
+
+// Refunds a payment the customer has not paid.
+#[actix_web::test]
+async fn should_fail_refund_for_unpaid_payment() {
+    let response = CONNECTOR
+        .make_payment_and_refund(
+            get_default_payment_authorize_data(),
+            None,
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert!(response.response.is_err());
+}
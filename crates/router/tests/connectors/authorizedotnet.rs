@@ -41,6 +41,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: Secret::new("999".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -55,8 +57,19 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         },
         payment_method_id: None,
+        connector_latency: None,
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::default(),
         connector_meta_data: None,
@@ -94,10 +107,13 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             refund_amount: 1,
             connector_metadata: None,
             reason: None,
+            reason_code: None,
+            metadata: None,
             connector_refund_id: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_latency: None,
         address: PaymentAddress::default(),
         amount_captured: None,
         access_token: None,
@@ -142,6 +158,43 @@ async fn payments_create_success() {
     );
 }
 
+#[actix_web::test]
+#[ignore]
+async fn payments_authorize_manual_capture_success() {
+    let conf = Settings::new().unwrap();
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    static CV: Authorizedotnet = Authorizedotnet;
+    let connector = types::api::ConnectorData {
+        connector: Box::new(&CV),
+        connector_name: types::Connector::Authorizedotnet,
+        get_token: types::api::GetToken::Connector,
+    };
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        types::api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let mut request = construct_payment_router_data();
+    request.request.capture_method = Some(enums::CaptureMethod::Manual);
+
+    let response = services::api::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &request,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .unwrap();
+
+    println!("{response:?}");
+
+    assert!(
+        response.status == enums::AttemptStatus::Authorized,
+        "The payment was not authorized"
+    );
+}
+
 #[actix_web::test]
 #[ignore]
 async fn payments_create_failure() {
@@ -171,6 +224,8 @@ async fn payments_create_failure() {
                 card_cvc: Secret::new("999".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             });
 
         let response = services::api::execute_connector_processing_step(
@@ -102,6 +102,8 @@ async fn should_sync_authorized_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
@@ -213,6 +215,8 @@ async fn should_sync_auto_captured_payment() {
                 ),
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Automatic),
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
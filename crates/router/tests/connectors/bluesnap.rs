@@ -96,6 +96,8 @@ async fn should_sync_authorized_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
@@ -231,6 +233,8 @@ async fn should_sync_auto_captured_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
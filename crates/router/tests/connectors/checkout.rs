@@ -38,6 +38,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: "123".to_string().into(),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -52,9 +54,58 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_latency: None,
+        address: PaymentAddress::default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    }
+}
+
+fn construct_verify_router_data() -> types::VerifyRouterData {
+    let payment_router_data = construct_payment_router_data();
+
+    types::RouterData {
+        flow: PhantomData,
+        merchant_id: payment_router_data.merchant_id,
+        connector: payment_router_data.connector,
+        payment_id: payment_router_data.payment_id,
+        attempt_id: payment_router_data.attempt_id,
+        status: payment_router_data.status,
+        router_return_url: None,
+        auth_type: payment_router_data.auth_type,
+        payment_method: payment_router_data.payment_method,
+        connector_auth_type: payment_router_data.connector_auth_type,
+        description: payment_router_data.description,
+        return_url: None,
+        request: types::VerifyRequestData {
+            currency: payment_router_data.request.currency,
+            payment_method_data: payment_router_data.request.payment_method_data,
+            confirm: true,
+            statement_descriptor_suffix: None,
+            mandate_id: None,
+            setup_future_usage: Some(enums::FutureUsage::OffSession),
+            off_session: None,
+            setup_mandate_details: None,
+        },
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: None,
+        connector_latency: None,
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
@@ -91,10 +142,13 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             refund_amount: 10,
             connector_metadata: None,
             reason: None,
+            reason_code: None,
+            metadata: None,
             connector_refund_id: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
+        connector_latency: None,
         address: PaymentAddress::default(),
         amount_captured: None,
         access_token: None,
@@ -141,6 +195,49 @@ async fn test_checkout_payment_success() {
     );
 }
 
+#[actix_web::test]
+#[ignore]
+async fn test_checkout_zero_amount_verification_success() {
+    use router::{configs::settings::Settings, connector::Checkout, services};
+
+    let conf = Settings::new().unwrap();
+    static CV: Checkout = Checkout;
+    let connector = types::api::ConnectorData {
+        connector: Box::new(&CV),
+        connector_name: types::Connector::Checkout,
+        get_token: types::api::GetToken::Connector,
+    };
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        types::api::Verify,
+        types::VerifyRequestData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    let request = construct_verify_router_data();
+
+    let response = services::api::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &request,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert!(
+        matches!(
+            response.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                mandate_reference: Some(_),
+                ..
+            })
+        ),
+        "The card was verified without a reusable source"
+    );
+}
+
 #[actix_web::test]
 #[ignore]
 async fn test_checkout_refund_success() {
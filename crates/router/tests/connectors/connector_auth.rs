@@ -5,21 +5,36 @@ use serde::Deserialize;
 pub(crate) struct ConnectorAuthentication {
     pub aci: Option<BodyKey>,
     pub adyen: Option<BodyKey>,
+    pub affirm: Option<BodyKey>,
+    pub afterpay_clearpay: Option<BodyKey>,
     pub airwallex: Option<BodyKey>,
+    pub alipay: Option<BodyKey>,
     pub authorizedotnet: Option<BodyKey>,
     pub bambora: Option<BodyKey>,
     pub bluesnap: Option<BodyKey>,
+    pub braintree: Option<SignatureKey>,
     pub checkout: Option<BodyKey>,
+    pub cryptopay: Option<BodyKey>,
     pub cybersource: Option<SignatureKey>,
     pub dlocal: Option<SignatureKey>,
     pub fiserv: Option<SignatureKey>,
     pub globalpay: Option<HeaderKey>,
+    pub gocardless: Option<HeaderKey>,
+    pub mercadopago: Option<HeaderKey>,
+    pub mollie: Option<HeaderKey>,
     pub multisafepay: Option<HeaderKey>,
     pub nuvei: Option<SignatureKey>,
+    pub paypal: Option<SignatureKey>,
+    pub paysafe: Option<BodyKey>,
+    pub paytm: Option<SignatureKey>,
     pub payu: Option<BodyKey>,
     pub rapyd: Option<BodyKey>,
+    pub razorpay: Option<BodyKey>,
     pub shift4: Option<HeaderKey>,
     pub stripe: Option<HeaderKey>,
+    pub trustly: Option<SignatureKey>,
+    pub twocheckout: Option<BodyKey>,
+    pub wechatpay: Option<SignatureKey>,
     pub worldpay: Option<BodyKey>,
     pub worldline: Option<SignatureKey>,
 }
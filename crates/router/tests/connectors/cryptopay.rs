@@ -0,0 +1,106 @@
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct CryptopayTest;
+impl ConnectorActions for CryptopayTest {}
+impl utils::Connector for CryptopayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Cryptopay;
+        types::api::ConnectorData {
+            connector: Box::new(&Cryptopay),
+            connector_name: types::Connector::Cryptopay,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .cryptopay
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "cryptopay".to_string()
+    }
+}
+
+static CONNECTOR: CryptopayTest = CryptopayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_payment_authorize_data(pay_currency: Option<&str>) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::Crypto(api_models::payments::CryptoData {
+            pay_currency: pay_currency.map(ToString::to_string),
+        }),
+        currency: enums::Currency::EUR,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates an invoice, which the customer pays on the hosted page of Cryptopay.
+#[actix_web::test]
+async fn should_redirect_to_invoice_page() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(Some("BTC")),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Syncs an invoice the customer has not paid yet.
+#[actix_web::test]
+async fn should_sync_unpaid_invoice() {
+    let authorize_response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(Some("BTC")),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("PSync response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Fails the payment when the cryptocurrency the customer pays with is not given.
+#[actix_web::test]
+async fn should_fail_payment_without_pay_currency() {
+    let response = CONNECTOR
+        .make_payment(get_payment_authorize_data(None), get_default_payment_info())
+        .await;
+    assert!(response.is_err());
+}
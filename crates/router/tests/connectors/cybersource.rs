@@ -124,6 +124,8 @@ async fn should_sync_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
@@ -90,6 +90,8 @@ async fn should_sync_authorized_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             Some(get_payment_info()),
         )
@@ -202,6 +204,8 @@ async fn should_sync_auto_captured_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                payment_method_data: None,
+                connector_meta: None,
             }),
             Some(get_payment_info()),
         )
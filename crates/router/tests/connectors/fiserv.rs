@@ -51,6 +51,8 @@ async fn should_only_authorize_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 capture_method: Some(storage_models::enums::CaptureMethod::Manual),
                 ..utils::PaymentAuthorizeType::default().0
@@ -75,6 +77,8 @@ async fn should_authorize_and_capture_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 ..utils::PaymentAuthorizeType::default().0
             }),
@@ -98,6 +102,8 @@ async fn should_capture_already_authorized_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 capture_method: Some(storage_models::enums::CaptureMethod::Manual),
                 ..utils::PaymentAuthorizeType::default().0
@@ -133,6 +139,8 @@ async fn should_fail_payment_for_missing_cvc() {
                     card_cvc: Secret::new("".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 ..utils::PaymentAuthorizeType::default().0
             }),
@@ -0,0 +1,168 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct GocardlessTest;
+impl ConnectorActions for GocardlessTest {}
+impl utils::Connector for GocardlessTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Gocardless;
+        types::api::ConnectorData {
+            connector: Box::new(&Gocardless),
+            connector_name: types::Connector::Gocardless,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .gocardless
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "gocardless".to_string()
+    }
+}
+
+static CONNECTOR: GocardlessTest = GocardlessTest {};
+
+fn get_billing_details() -> api_models::payments::BankDebitBilling {
+    api_models::payments::BankDebitBilling {
+        name: Secret::new("John Doe".to_string()),
+        email: Secret::new("test_user@testuser.com".to_string()),
+    }
+}
+
+fn get_ach_payment_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::BankDebit(
+            api_models::payments::BankDebitData::AchBankDebit {
+                billing_details: get_billing_details(),
+                account_number: Secret::new("2715500356".to_string()),
+                routing_number: Secret::new("026073150".to_string()),
+                bank_account_type: None,
+            },
+        ),
+        currency: enums::Currency::USD,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+fn get_sepa_payment_data(iban: &str) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::BankDebit(
+            api_models::payments::BankDebitData::SepaBankDebit {
+                billing_details: get_billing_details(),
+                iban: Secret::new(iban.to_string()),
+            },
+        ),
+        currency: enums::Currency::EUR,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+fn get_transaction_id(response: &types::PaymentsAuthorizeRouterData) -> Option<String> {
+    match &response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId(transaction_id),
+            ..
+        }) => Some(transaction_id.clone()),
+        _ => None,
+    }
+}
+
+// Debits a bank account in the United States through a newly set up ACH mandate.
+#[actix_web::test]
+async fn should_make_ach_payment() {
+    let response = CONNECTOR
+        .make_payment(get_ach_payment_data(), None)
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+    let mandate_reference = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            mandate_reference, ..
+        }) => mandate_reference,
+        _ => None,
+    };
+    assert!(mandate_reference.is_some());
+}
+
+// Debits a bank account in the Single Euro Payments Area through a newly set up SEPA mandate.
+#[actix_web::test]
+async fn should_make_sepa_payment() {
+    let response = CONNECTOR
+        .make_payment(get_sepa_payment_data("DE89370400440532013000"), None)
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
+// Debits the bank account again through the mandate set up with an earlier payment.
+#[actix_web::test]
+async fn should_make_payment_on_mandate() {
+    let response = CONNECTOR
+        .make_payment(get_sepa_payment_data("DE89370400440532013000"), None)
+        .await
+        .expect("Authorize payment response");
+    let mandate_reference = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            mandate_reference, ..
+        }) => mandate_reference,
+        _ => None,
+    };
+
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: "mandate_id".to_string(),
+                    connector_mandate_id: mandate_reference,
+                    network_transaction_id: None,
+                }),
+                off_session: Some(true),
+                ..get_sepa_payment_data("DE89370400440532013000").unwrap()
+            }),
+            None,
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
+// Cancels a debit before it is submitted to the bank of the customer.
+#[actix_web::test]
+async fn should_void_payment() {
+    let response = CONNECTOR
+        .make_payment(get_sepa_payment_data("DE89370400440532013000"), None)
+        .await
+        .expect("Authorize payment response");
+    let response = CONNECTOR
+        .void_payment(
+            get_transaction_id(&response).expect("Missing transaction id"),
+            None,
+            None,
+        )
+        .await
+        .expect("Void payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+// Fails the payment without debiting when GoCardless rejects the bank account.
+#[actix_web::test]
+async fn should_fail_payment_for_invalid_iban() {
+    let response = CONNECTOR
+        .make_payment(get_sepa_payment_data("DE00000000000000000000"), None)
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Failure);
+    assert!(response.response.is_err());
+}
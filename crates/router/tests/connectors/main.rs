@@ -2,22 +2,37 @@
 
 mod aci;
 mod adyen;
+mod affirm;
+mod afterpay_clearpay;
 mod airwallex;
+mod alipay;
 mod authorizedotnet;
 mod bambora;
 mod bluesnap;
+mod braintree;
 mod checkout;
 mod connector_auth;
+mod cryptopay;
 mod cybersource;
 mod dlocal;
 mod fiserv;
 mod globalpay;
+mod gocardless;
+mod mercadopago;
+mod mollie;
 mod multisafepay;
 mod nuvei;
+mod paypal;
+mod paysafe;
+mod paytm;
 mod payu;
 mod rapyd;
+mod razorpay;
 mod shift4;
 mod stripe;
+mod trustly;
+mod twocheckout;
 mod utils;
+mod wechatpay;
 mod worldline;
 mod worldpay;
@@ -0,0 +1,124 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct MollieTest;
+impl ConnectorActions for MollieTest {}
+impl utils::Connector for MollieTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Mollie;
+        types::api::ConnectorData {
+            connector: Box::new(&Mollie),
+            connector_name: types::Connector::Mollie,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .mollie
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "mollie".to_string()
+    }
+}
+
+static CONNECTOR: MollieTest = MollieTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        connector_meta_data: Some(serde_json::json!({
+            "webhook_url": "https://hyperswitch.io/webhooks/mollie",
+        })),
+        ..Default::default()
+    })
+}
+
+fn get_payment_authorize_data(
+    bank_redirect_data: api_models::payments::BankRedirectData,
+    currency: enums::Currency,
+) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::BankRedirect(bank_redirect_data),
+        currency,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+async fn assert_redirected_to_bank(payment_data: Option<types::PaymentsAuthorizeData>) {
+    let response = CONNECTOR
+        .make_payment(payment_data, get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Creates an iDEAL payment, which redirects the customer straight to the page of their bank.
+#[actix_web::test]
+async fn should_redirect_ideal_payment_to_bank() {
+    assert_redirected_to_bank(get_payment_authorize_data(
+        api_models::payments::BankRedirectData::Ideal {
+            billing_details: api_models::payments::BankRedirectBilling {
+                billing_name: Secret::new("John Doe".to_string()),
+            },
+            bank_name: api_models::enums::BankNames::Ing,
+        },
+        enums::Currency::EUR,
+    ))
+    .await;
+}
+
+// Creates a Przelewy24 payment, which redirects the customer to Przelewy24 to pick their bank.
+#[actix_web::test]
+async fn should_redirect_przelewy24_payment_to_bank() {
+    assert_redirected_to_bank(get_payment_authorize_data(
+        api_models::payments::BankRedirectData::Przelewy24 {
+            billing_details: api_models::payments::BankRedirectBilling {
+                billing_name: Secret::new("John Doe".to_string()),
+            },
+            bank_name: None,
+            billing_email: Secret::new("test_user@testuser.com".to_string()),
+        },
+        enums::Currency::PLN,
+    ))
+    .await;
+}
+
+// Fails the payment when the webhook url is not configured for the merchant.
+#[actix_web::test]
+async fn should_fail_payment_without_webhook_url() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(
+                api_models::payments::BankRedirectData::Giropay {
+                    billing_details: api_models::payments::BankRedirectBilling {
+                        billing_name: Secret::new("John Doe".to_string()),
+                    },
+                },
+                enums::Currency::EUR,
+            ),
+            Some(utils::PaymentInfo {
+                connector_meta_data: None,
+                ..get_default_payment_info().unwrap()
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
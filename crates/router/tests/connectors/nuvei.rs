@@ -103,13 +103,12 @@ async fn should_sync_authorized_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
-            }),
-            Some(PaymentInfo {
-                connector_meta_data: Some(json!({
+                payment_method_data: None,
+                connector_meta: Some(json!({
                     "session_token": authorize_response.session_token.unwrap()
                 })),
-                ..Default::default()
             }),
+            None,
         )
         .await
         .expect("PSync response");
@@ -198,19 +197,49 @@ async fn should_sync_auto_captured_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
-            }),
-            Some(PaymentInfo {
-                connector_meta_data: Some(json!({
+                payment_method_data: None,
+                connector_meta: Some(json!({
                     "session_token": authorize_response.session_token.unwrap()
                 })),
-                ..Default::default()
             }),
+            None,
         )
         .await
         .unwrap();
     assert_eq!(response.status, enums::AttemptStatus::Charged,);
 }
 
+// Creates a 3DS2 payment for a card that requires a challenge.
+#[actix_web::test]
+async fn should_redirect_three_ds_payment_for_challenge() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
+                    card_number: Secret::new(String::from("2221008123677736")),
+                    card_holder_name: Secret::new(String::from("CL-BRW1")),
+                    ..utils::CCardType::default().0
+                }),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            Some(PaymentInfo {
+                auth_type: Some(enums::AuthenticationType::ThreeDs),
+                router_return_url: Some(String::from("https://hyperswitch.io/")),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some(), "Missing challenge redirection");
+}
+
 // Refunds a payment using the automatic capture flow (Non 3DS).
 #[actix_web::test]
 async fn should_refund_auto_captured_payment() {
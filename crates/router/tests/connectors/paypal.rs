@@ -0,0 +1,101 @@
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct PaypalTest;
+impl ConnectorActions for PaypalTest {}
+impl utils::Connector for PaypalTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Paypal;
+        types::api::ConnectorData {
+            connector: Box::new(&Paypal),
+            connector_name: types::Connector::Paypal,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .paypal
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "paypal".to_string()
+    }
+}
+
+static CONNECTOR: PaypalTest = PaypalTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        access_token: Some(types::AccessToken {
+            token: "<access_token>".to_string(),
+            expires: 32400,
+        }),
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::Wallet(
+            api_models::payments::WalletData::PaypalRedirect(
+                api_models::payments::PaypalRedirection {},
+            ),
+        ),
+        currency: enums::Currency::USD,
+        payment_method_type: Some(enums::PaymentMethodType::Paypal),
+        payment_experience: Some(enums::PaymentExperience::RedirectToUrl),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates an order and redirects the customer to PayPal to approve it.
+#[actix_web::test]
+async fn should_redirect_customer_to_approve_order() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Creates an order to be authorized rather than captured on approval, for manual capture.
+#[actix_web::test]
+async fn should_redirect_customer_to_approve_order_for_manual_capture() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Rejects payment methods other than the PayPal wallet.
+#[actix_web::test]
+async fn should_fail_payment_for_card() {
+    let response = CONNECTOR
+        .make_payment(None, get_default_payment_info())
+        .await;
+    assert!(response.is_err());
+}
@@ -0,0 +1,191 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct PaysafeTest;
+impl ConnectorActions for PaysafeTest {}
+impl utils::Connector for PaysafeTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Paysafe;
+        types::api::ConnectorData {
+            connector: Box::new(&Paysafe),
+            connector_name: types::Connector::Paysafe,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .paysafe
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "paysafe".to_string()
+    }
+}
+
+static CONNECTOR: PaysafeTest = PaysafeTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress {
+            billing: Some(api::Address {
+                address: Some(api::AddressDetails {
+                    line1: Some(Secret::new("100 Queen Street West".to_string())),
+                    city: Some("Toronto".to_string()),
+                    state: Some(Secret::new("ON".to_string())),
+                    zip: Some(Secret::new("M5H 2N2".to_string())),
+                    country: Some("CA".to_string()),
+                    ..Default::default()
+                }),
+                phone: None,
+            }),
+            ..Default::default()
+        }),
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        currency: enums::Currency::CAD,
+        email: Some(Secret::new("test_user@testuser.com".to_string())),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Cards Positive Tests
+// Creates a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_only_authorize_payment() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+// Captures a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_capture_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_capture_payment(
+            get_default_payment_authorize_data(),
+            None,
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Capture payment response");
+    assert!(matches!(
+        response.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::CaptureInitiated
+    ));
+}
+
+// Voids a payment using the manual capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_void_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_void_payment(
+            get_default_payment_authorize_data(),
+            Some(types::PaymentsCancelData {
+                amount: Some(100),
+                ..utils::PaymentCancelType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Void payment response");
+    assert!(matches!(
+        response.status,
+        enums::AttemptStatus::Voided | enums::AttemptStatus::VoidInitiated
+    ));
+}
+
+// Creates a payment using the automatic capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_make_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Charged);
+}
+
+// Refunds a payment using the automatic capture flow (Non 3DS).
+#[actix_web::test]
+async fn should_refund_auto_captured_payment() {
+    let response = CONNECTOR
+        .make_payment_and_refund(
+            get_default_payment_authorize_data(),
+            None,
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_ne!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Failure,
+    );
+}
+
+// Redirects the customer to Paysafecard to redeem the voucher.
+#[actix_web::test]
+async fn should_redirect_customer_to_paysafecard() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethodData::Voucher(
+                    api_models::payments::VoucherData::Paysafecard {},
+                ),
+                currency: enums::Currency::EUR,
+                ..get_default_payment_authorize_data().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Cards Negative scenarios
+// Creates a payment with incorrect CVC.
+#[actix_web::test]
+async fn should_fail_payment_for_incorrect_cvc() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: types::api::PaymentMethodData::Card(api::Card {
+                    card_cvc: Secret::new("12345".to_string()),
+                    ..utils::CCardType::default().0
+                }),
+                ..get_default_payment_authorize_data().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert!(response.response.is_err());
+}
@@ -0,0 +1,157 @@
+use api_models::payments::{PaytmRedirection, UpiData};
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct PaytmTest;
+impl ConnectorActions for PaytmTest {}
+impl utils::Connector for PaytmTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Paytm;
+        types::api::ConnectorData {
+            connector: Box::new(&Paytm),
+            connector_name: types::Connector::Paytm,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .paytm
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "paytm".to_string()
+    }
+}
+
+static CONNECTOR: PaytmTest = PaytmTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_payment_authorize_data(
+    payment_method_data: types::api::PaymentMethodData,
+) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        currency: enums::Currency::INR,
+        payment_method_data,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+fn get_redirection_data(
+    response: Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> Option<router::services::RedirectForm> {
+    match response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    }
+}
+
+// UPI Positive Tests
+// Creates a UPI collect payment, which stays pending until the customer approves it in their app.
+#[actix_web::test]
+async fn should_make_upi_collect_payment() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(types::api::PaymentMethodData::Upi(UpiData::UpiCollect {
+                vpa_id: Secret::new("7777777777@paytm".to_string()),
+            })),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
+// Creates a UPI intent payment, which hands the customer over to a UPI app.
+#[actix_web::test]
+async fn should_make_upi_intent_payment() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(types::api::PaymentMethodData::Upi(UpiData::UpiIntent {})),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    assert!(get_redirection_data(response.response).is_some());
+}
+
+// Wallet Positive Tests
+// Creates a wallet payment, which is completed on the Paytm payment page.
+#[actix_web::test]
+async fn should_redirect_wallet_payment_to_payment_page() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(types::api::PaymentMethodData::Wallet(
+                api::WalletData::PaytmRedirect(PaytmRedirection {}),
+            )),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    assert!(get_redirection_data(response.response).is_some());
+}
+
+// Synchronizes a UPI collect payment, which is pending until the customer approves it.
+#[actix_web::test]
+async fn should_sync_upi_collect_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(types::api::PaymentMethodData::Upi(UpiData::UpiCollect {
+                vpa_id: Secret::new("7777777777@paytm".to_string()),
+            })),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    assert_ne!(txn_id, None, "Empty connector transaction id");
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Pending,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
+// UPI Negative scenarios
+// Creates a UPI collect payment against an invalid UPI id.
+#[actix_web::test]
+async fn should_fail_payment_for_invalid_vpa() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(types::api::PaymentMethodData::Upi(UpiData::UpiCollect {
+                vpa_id: Secret::new("invalid-vpa".to_string()),
+            })),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert!(response.response.is_err());
+}
@@ -72,6 +72,8 @@ async fn should_authorize_card_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -118,6 +120,8 @@ async fn should_authorize_gpay_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -152,6 +156,8 @@ async fn should_capture_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -172,6 +178,8 @@ async fn should_capture_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -209,6 +217,8 @@ async fn should_sync_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -253,6 +263,8 @@ async fn should_void_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -288,6 +300,8 @@ async fn should_refund_succeeded_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -310,6 +324,8 @@ async fn should_refund_succeeded_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    payment_method_data: None,
+                    connector_meta: None,
                 }),
                 get_default_payment_info(),
             )
@@ -46,6 +46,8 @@ async fn should_only_authorize_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 capture_method: Some(storage_models::enums::CaptureMethod::Manual),
                 ..utils::PaymentAuthorizeType::default().0
@@ -70,6 +72,8 @@ async fn should_authorize_and_capture_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
+                    card_type: None,
                 }),
                 ..utils::PaymentAuthorizeType::default().0
             }),
@@ -0,0 +1,105 @@
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct TrustlyTest;
+impl ConnectorActions for TrustlyTest {}
+impl utils::Connector for TrustlyTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Trustly;
+        types::api::ConnectorData {
+            connector: Box::new(&Trustly),
+            connector_name: types::Connector::Trustly,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .trustly
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "trustly".to_string()
+    }
+}
+
+static CONNECTOR: TrustlyTest = TrustlyTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress {
+            billing: Some(api::Address {
+                address: Some(api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    country: Some("SE".to_string()),
+                    ..Default::default()
+                }),
+                phone: None,
+            }),
+            ..Default::default()
+        }),
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        connector_meta_data: Some(serde_json::json!({
+            "notification_url": "https://hyperswitch.io/webhooks/trustly",
+        })),
+        ..Default::default()
+    })
+}
+
+fn get_default_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethodData::BankRedirect(
+            api_models::payments::BankRedirectData::Trustly {
+                country: "SE".to_string(),
+            },
+        ),
+        currency: enums::Currency::SEK,
+        email: Some(Secret::new("test_user@testuser.com".to_string())),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates a deposit, which redirects the customer to their bank through Trustly.
+#[actix_web::test]
+async fn should_redirect_customer_to_trustly() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Fails the deposit when the notification url is not configured for the merchant.
+#[actix_web::test]
+async fn should_fail_deposit_without_notification_url() {
+    let response = CONNECTOR
+        .make_payment(
+            get_default_payment_authorize_data(),
+            Some(utils::PaymentInfo {
+                connector_meta_data: None,
+                ..get_default_payment_info().unwrap()
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
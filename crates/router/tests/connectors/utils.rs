@@ -313,6 +313,8 @@ pub trait ConnectorActions: Connector {
                 refund_amount: 100,
                 connector_metadata: None,
                 reason: None,
+                reason_code: None,
+                metadata: None,
                 connector_refund_id: Some(refund_id),
             }),
             payment_info,
@@ -374,6 +376,7 @@ pub trait ConnectorActions: Connector {
             request: req,
             response: Err(types::ErrorResponse::default()),
             payment_method_id: None,
+            connector_latency: None,
             address: info
                 .clone()
                 .and_then(|a| a.address)
@@ -463,6 +466,8 @@ impl Default for CCardType {
             card_cvc: Secret::new("999".to_string()),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         })
     }
 }
@@ -486,6 +491,16 @@ impl Default for PaymentAuthorizeType {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         };
         Self(data)
     }
@@ -498,6 +513,7 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            multiple_capture_data: None,
         })
     }
 }
@@ -538,6 +554,8 @@ impl Default for PaymentSyncType {
             ),
             encoded_data: None,
             capture_method: None,
+            payment_method_data: None,
+            connector_meta: None,
         };
         Self(data)
     }
@@ -553,6 +571,8 @@ impl Default for PaymentRefundType {
             refund_amount: 100,
             connector_metadata: None,
             reason: Some("Customer returned product".to_string()),
+            reason_code: None,
+            metadata: None,
             connector_refund_id: None,
         };
         Self(data)
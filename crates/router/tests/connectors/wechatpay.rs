@@ -0,0 +1,155 @@
+use api_models::payments::{WeChatPayQr, WeChatPayRedirection};
+use router::types::{self, api, storage::enums};
+use serde_json::json;
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+#[derive(Clone, Copy)]
+struct WechatpayTest;
+impl ConnectorActions for WechatpayTest {}
+impl utils::Connector for WechatpayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Wechatpay;
+        types::api::ConnectorData {
+            connector: Box::new(&Wechatpay),
+            connector_name: types::Connector::Wechatpay,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .wechatpay
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "wechatpay".to_string()
+    }
+}
+
+static CONNECTOR: WechatpayTest = WechatpayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        router_return_url: Some("https://hyperswitch.io".to_string()),
+        connector_meta_data: Some(json!({
+            "notify_url": "https://hyperswitch.io/webhooks/wechatpay"
+        })),
+        ..Default::default()
+    })
+}
+
+fn get_payment_authorize_data(
+    wallet_data: api::WalletData,
+) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        currency: enums::Currency::CNY,
+        payment_method_data: types::api::PaymentMethodData::Wallet(wallet_data),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Wallet Positive Tests
+// Creates a native payment, whose QR code is scanned by the customer with the WeChat app.
+#[actix_web::test]
+async fn should_make_qr_code_payment() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(api::WalletData::WeChatPayQr(WeChatPayQr {})),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let connector_metadata = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            connector_metadata, ..
+        }) => connector_metadata,
+        _ => None,
+    };
+    assert!(connector_metadata
+        .and_then(|metadata| metadata.get("qr_code_payload").cloned())
+        .is_some());
+}
+
+// Creates an H5 payment, which is completed on the WeChat Pay payment page.
+#[actix_web::test]
+async fn should_redirect_h5_payment() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(api::WalletData::WeChatPayRedirect(WeChatPayRedirection {})),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }) => redirection_data,
+        _ => None,
+    };
+    assert!(redirection_data.is_some());
+}
+
+// Synchronizes a payment the customer has not paid yet.
+#[actix_web::test]
+async fn should_sync_unpaid_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(api::WalletData::WeChatPayQr(WeChatPayQr {})),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    assert_ne!(txn_id, None, "Empty connector transaction id");
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::AuthenticationPending,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Wallet Negative scenarios
+// Rejects payments that are meant to be captured manually, as WeChat Pay captures them at once.
+#[actix_web::test]
+async fn should_fail_manual_capture_payment() {
+    let response = CONNECTOR
+        .authorize_payment(
+            get_payment_authorize_data(api::WalletData::WeChatPayQr(WeChatPayQr {})),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
+
+// Creates a payment without the notify url in the connector metadata.
+#[actix_web::test]
+async fn should_fail_payment_without_notify_url() {
+    let response = CONNECTOR
+        .make_payment(
+            get_payment_authorize_data(api::WalletData::WeChatPayQr(WeChatPayQr {})),
+            Some(utils::PaymentInfo {
+                router_return_url: Some("https://hyperswitch.io".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
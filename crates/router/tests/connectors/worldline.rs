@@ -71,6 +71,8 @@ impl WorldlineTest {
                 card_cvc: Secret::new(card_cvc.to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
+                card_type: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -85,6 +87,16 @@ impl WorldlineTest {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            payer_document: None,
+            installments: None,
+            split_payments: None,
+            apple_pay_decrypted_data: None,
+            card_network: None,
+            sca_exemption: None,
+            external_authentication_data: None,
+            click_to_pay_token_data: None,
+            connector_metadata: None,
+            network_transaction_id: None,
         })
     }
 }
@@ -214,6 +226,8 @@ async fn should_sync_manual_auth_payment() {
                 ),
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Manual),
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
@@ -247,6 +261,8 @@ async fn should_sync_auto_auth_payment() {
                 ),
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Automatic),
+                payment_method_data: None,
+                connector_meta: None,
             }),
             None,
         )
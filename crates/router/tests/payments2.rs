@@ -67,6 +67,8 @@ async fn payments_create_core() {
             card_cvc: "123".to_string().into(),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         })),
         payment_method: Some(api_enums::PaymentMethod::Card),
         shipping: Some(api::Address {
@@ -219,6 +221,8 @@ async fn payments_create_core_adyen_no_redirect() {
             card_cvc: "737".to_string().into(),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
+            card_type: None,
         })),
         payment_method: Some(api_enums::PaymentMethod::Card),
         shipping: Some(api::Address {
@@ -9,8 +9,8 @@ async fn payouts_todo() {
     let client = awc::Client::default();
     let mut response;
     let mut response_body;
-    let get_endpoints = vec!["retrieve", "accounts"];
-    let post_endpoints = vec!["create", "update", "reverse", "cancel"];
+    let get_endpoints = vec!["accounts"];
+    let post_endpoints = vec!["update", "reverse"];
 
     for endpoint in get_endpoints {
         response = client
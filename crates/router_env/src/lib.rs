@@ -18,6 +18,7 @@ pub mod vergen;
 #[doc(inline)]
 pub use logger::*;
 pub use opentelemetry;
+pub use prometheus;
 pub use tracing;
 #[cfg(feature = "actix_web")]
 pub use tracing_actix_web;
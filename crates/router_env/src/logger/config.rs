@@ -90,6 +90,19 @@ pub struct LogTelemetry {
     pub enabled: bool,
     /// Sampling rate for traces
     pub sampling_rate: Option<f64>,
+    /// Where metrics are exported to.
+    pub metrics_exporter: MetricsExporter,
+}
+
+/// Exporter of metrics.
+#[derive(Default, Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsExporter {
+    /// Push metrics to an OpenTelemetry collector over OTLP
+    #[default]
+    Otlp,
+    /// Keep metrics in a Prometheus registry, to be scraped from the application
+    Prometheus,
 }
 
 /// Telemetry / tracing.
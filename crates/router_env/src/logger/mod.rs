@@ -14,7 +14,7 @@ pub mod types;
 pub use types::{Category, Flow, Level, Tag};
 
 mod setup;
-pub use setup::{setup, TelemetryGuard};
+pub use setup::{prometheus_registry, setup, TelemetryGuard};
 
 pub mod formatter;
 pub use formatter::FormattingLayer;
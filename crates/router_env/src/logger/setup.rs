@@ -3,18 +3,23 @@
 //!
 use std::{path::PathBuf, time::Duration};
 
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use opentelemetry::{
     global, runtime,
     sdk::{
         export::metrics::aggregation::cumulative_temporality_selector,
-        metrics::{controllers::BasicController, selectors::simple},
+        metrics::{
+            controllers::{self, BasicController},
+            processors,
+            selectors::simple,
+        },
         propagation::TraceContextPropagator,
         trace, Resource,
     },
     KeyValue,
 };
 use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_prometheus::PrometheusExporter;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{filter, fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
 
@@ -128,7 +133,7 @@ pub fn setup<Str: AsRef<str>>(
     // Returning the WorkerGuard for logs to be printed until it is dropped
     Ok(TelemetryGuard {
         _log_guards: guards,
-        _metric_controller: setup_metrics(),
+        _metric_controller: setup_metrics(conf.telemetry.metrics_exporter),
     })
 }
 
@@ -143,7 +148,38 @@ static HISTOGRAM_BUCKETS: Lazy<[f64; 15]> = Lazy::new(|| {
     buckets
 });
 
-fn setup_metrics() -> Option<BasicController> {
+static PROMETHEUS_EXPORTER: OnceCell<PrometheusExporter> = OnceCell::new();
+
+/// Registry the metrics of the application are kept in, when they are exported to Prometheus.
+pub fn prometheus_registry() -> Option<&'static prometheus::Registry> {
+    PROMETHEUS_EXPORTER.get().map(PrometheusExporter::registry)
+}
+
+fn setup_metrics(exporter: config::MetricsExporter) -> Option<BasicController> {
+    match exporter {
+        config::MetricsExporter::Otlp => setup_otlp_metrics(),
+        config::MetricsExporter::Prometheus => {
+            setup_prometheus_metrics();
+            None
+        }
+    }
+}
+
+fn setup_prometheus_metrics() {
+    let controller = controllers::basic(processors::factory(
+        simple::histogram(*HISTOGRAM_BUCKETS),
+        cumulative_temporality_selector(),
+    ))
+    .build();
+
+    // The exporter installs the controller as the global meter provider
+    let exporter = opentelemetry_prometheus::exporter(controller).init();
+    if PROMETHEUS_EXPORTER.set(exporter).is_err() {
+        eprintln!("Prometheus metrics exporter is already set up");
+    }
+}
+
+fn setup_otlp_metrics() -> Option<BasicController> {
     opentelemetry_otlp::new_pipeline()
         .metrics(
             simple::histogram(*HISTOGRAM_BUCKETS),
@@ -72,6 +72,8 @@ pub enum Flow {
     PaymentConnectorsDelete,
     /// Payment connectors list flow.
     PaymentConnectorsList,
+    /// Payment connectors key rotation flow.
+    PaymentConnectorsKeyRotate,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -102,6 +104,8 @@ pub enum Flow {
     PaymentMethodsList,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer default payment method set flow.
+    CustomerPaymentMethodsSetDefault,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -126,6 +130,10 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments search flow.
+    PaymentsSearch,
+    /// Payment attempts list flow.
+    PaymentAttemptsList,
     /// Payouts create flow
     PayoutsCreate,
     /// Payouts retrieve flow.
@@ -134,6 +142,8 @@ pub enum Flow {
     PayoutsUpdate,
     /// Payouts reverse flow.
     PayoutsReverse,
+    /// Payouts confirm flow.
+    PayoutsConfirm,
     /// Payouts cancel flow.
     PayoutsCancel,
     /// Payouts accounts flow.
@@ -146,8 +156,24 @@ pub enum Flow {
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Bulk refunds create flow.
+    RefundsBulkCreate,
+    /// Bulk refunds retrieve flow.
+    RefundsBulkRetrieve,
+    /// Credits create flow.
+    RefundsCreditCreate,
+    /// Credits retrieve flow.
+    RefundsCreditRetrieve,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Outgoing Webhook Retry
+    OutgoingWebhookRetry,
+    /// Webhook events list flow
+    WebhookEventList,
+    /// Webhook event retrieve flow
+    WebhookEventRetrieve,
+    /// Webhook event replay flow
+    WebhookEventReplay,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -160,6 +186,76 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// User role create flow
+    UserRoleCreate,
+    /// User role retrieve flow
+    UserRoleRetrieve,
+    /// User role update flow
+    UserRoleUpdate,
+    /// User role delete flow
+    UserRoleDelete,
+    /// User roles list flow
+    UserRoleList,
+    /// User sign up flow
+    UserSignUp,
+    /// User login flow
+    UserLogin,
+    /// User token refresh flow
+    UserRefreshToken,
+    /// Organization create flow
+    OrganizationCreate,
+    /// Organization retrieve flow
+    OrganizationRetrieve,
+    /// Business profile create flow
+    BusinessProfileCreate,
+    /// Business profile retrieve flow
+    BusinessProfileRetrieve,
+    /// Business profile update flow
+    BusinessProfileUpdate,
+    /// Business profile delete flow
+    BusinessProfileDelete,
+    /// Business profile list flow
+    BusinessProfileList,
+    /// Dispute Retrieve flow
+    DisputesRetrieve,
+    /// Dispute List flow
+    DisputesList,
+    /// Dispute evidence submission flow
+    DisputesEvidenceSubmit,
+    /// Routing config create flow
+    RoutingConfigCreate,
+    /// Routing configs list flow
+    RoutingConfigList,
+    /// Routing config activate flow
+    RoutingConfigActivate,
+    /// Forex quote flow
+    ForexQuote,
+    /// Audit logs list flow
+    AuditLogList,
+    /// Payment analytics flow
+    PaymentAnalytics,
+    /// Reconciliation report fetch flow
+    ReconReportFetch,
+    /// Reconciliation report upload flow
+    ReconReportUpload,
+    /// Reconciliation reports list flow
+    ReconReportList,
+    /// Reconciliation report retrieve flow
+    ReconReportRetrieve,
+    /// Reconciliation records list flow
+    ReconRecordList,
+    /// Add to blocklist flow
+    AddToBlocklist,
+    /// Delete from blocklist flow
+    DeleteFromBlocklist,
+    /// List blocklist entries flow
+    ListBlocklist,
+    /// Connectors health flow
+    ConnectorHealth,
+    /// Token migration flow
+    TokenMigration,
+    /// GraphQL request flow
+    GraphqlRequest,
 }
 
 /// Category of log event.
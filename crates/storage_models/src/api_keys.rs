@@ -2,7 +2,7 @@ use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{enums as storage_enums, schema::api_keys};
 
 #[derive(Debug, Identifiable, Queryable)]
 #[diesel(table_name = api_keys, primary_key(key_id))]
@@ -17,6 +17,7 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub role: storage_enums::RoleName,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +33,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub role: storage_enums::RoleName,
 }
 
 #[derive(Debug)]
@@ -41,6 +43,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        role: Option<storage_enums::RoleName>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +57,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub role: Option<storage_enums::RoleName>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +68,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                role,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                role,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                role: None,
             },
         }
     }
@@ -90,6 +97,12 @@ impl From<String> for HashedApiKey {
     }
 }
 
+impl HashedApiKey {
+    pub fn get_hash(&self) -> &str {
+        &self.0
+    }
+}
+
 mod diesel_impl {
     use diesel::{
         backend::Backend,
@@ -0,0 +1,37 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub audit_log_id: String,
+    pub merchant_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub http_method: Option<String>,
+    pub route: Option<String>,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub previous_status: Option<String>,
+    pub new_status: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLog {
+    pub id: i32,
+    pub audit_log_id: String,
+    pub merchant_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub http_method: Option<String>,
+    pub route: Option<String>,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub previous_status: Option<String>,
+    pub new_status: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::blocklist};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = blocklist)]
+pub struct Blocklist {
+    pub id: i32,
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    pub data_kind: storage_enums::BlocklistDataKind,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = blocklist)]
+pub struct BlocklistNew {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
+    pub data_kind: storage_enums::BlocklistDataKind,
+    pub created_at: Option<PrimitiveDateTime>,
+}
@@ -0,0 +1,76 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::business_profile;
+
+/// A line of business of a merchant, such as the business in a country or under a label. The
+/// payments made for a business profile are routed and notified with the routing algorithm and
+/// webhook details of the profile, instead of those of the merchant account.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = business_profile, primary_key(profile_id))]
+pub struct BusinessProfile {
+    pub profile_id: String,
+    pub merchant_id: String,
+    pub profile_name: String,
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    pub webhook_details: Option<serde_json::Value>,
+    pub routing_algorithm: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = business_profile)]
+pub struct BusinessProfileNew {
+    pub profile_id: String,
+    pub merchant_id: String,
+    pub profile_name: String,
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    pub webhook_details: Option<serde_json::Value>,
+    pub routing_algorithm: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+pub enum BusinessProfileUpdate {
+    Update {
+        profile_name: Option<String>,
+        business_country: Option<String>,
+        business_label: Option<String>,
+        webhook_details: Option<serde_json::Value>,
+        routing_algorithm: Option<serde_json::Value>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = business_profile)]
+pub struct BusinessProfileUpdateInternal {
+    profile_name: Option<String>,
+    business_country: Option<String>,
+    business_label: Option<String>,
+    webhook_details: Option<serde_json::Value>,
+    routing_algorithm: Option<serde_json::Value>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
+    fn from(business_profile_update: BusinessProfileUpdate) -> Self {
+        match business_profile_update {
+            BusinessProfileUpdate::Update {
+                profile_name,
+                business_country,
+                business_label,
+                webhook_details,
+                routing_algorithm,
+            } => Self {
+                profile_name,
+                business_country,
+                business_label,
+                webhook_details,
+                routing_algorithm,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
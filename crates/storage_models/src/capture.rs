@@ -0,0 +1,104 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::captures};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = captures, primary_key(capture_id))]
+pub struct Capture {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub authorized_attempt_id: String,
+    pub connector_capture_id: Option<String>,
+    pub capture_sequence: i16,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
+)]
+#[diesel(table_name = captures)]
+pub struct CaptureNew {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+    pub authorized_attempt_id: String,
+    pub connector_capture_id: Option<String>,
+    pub capture_sequence: i16,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CaptureUpdate {
+    ResponseUpdate {
+        status: storage_enums::CaptureStatus,
+        connector_capture_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::CaptureStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+pub struct CaptureUpdateInternal {
+    pub status: Option<storage_enums::CaptureStatus>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub connector_capture_id: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CaptureUpdate> for CaptureUpdateInternal {
+    fn from(capture_update: CaptureUpdate) -> Self {
+        let now = Some(common_utils::date_time::now());
+        match capture_update {
+            CaptureUpdate::ResponseUpdate {
+                status,
+                connector_capture_id,
+            } => Self {
+                status: Some(status),
+                connector_capture_id,
+                modified_at: now,
+                ..Self::default()
+            },
+            CaptureUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: now,
+                ..Self::default()
+            },
+        }
+    }
+}
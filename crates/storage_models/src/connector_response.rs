@@ -17,6 +17,8 @@ pub struct ConnectorResponseNew {
     pub connector_transaction_id: Option<String>,
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
+    pub connector_request_payload: Option<serde_json::Value>,
+    pub connector_response_payload: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -33,6 +35,8 @@ pub struct ConnectorResponse {
     pub connector_transaction_id: Option<String>,
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
+    pub connector_request_payload: Option<serde_json::Value>,
+    pub connector_response_payload: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, AsChangeset, Serialize)]
@@ -43,6 +47,8 @@ pub struct ConnectorResponseUpdateInternal {
     pub modified_at: Option<PrimitiveDateTime>,
     pub encoded_data: Option<String>,
     pub connector_name: Option<String>,
+    pub connector_request_payload: Option<serde_json::Value>,
+    pub connector_response_payload: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -56,6 +62,12 @@ pub enum ConnectorResponseUpdate {
     ErrorUpdate {
         connector_name: Option<String>,
     },
+    /// The masked payloads of the last call made to the connector, captured for the merchants who
+    /// opted in to it.
+    PayloadUpdate {
+        connector_request_payload: serde_json::Value,
+        connector_response_payload: serde_json::Value,
+    },
 }
 
 impl ConnectorResponseUpdate {
@@ -77,6 +89,12 @@ impl ConnectorResponseUpdate {
             encoded_data: connector_response_update
                 .encoded_data
                 .or(source.encoded_data),
+            connector_request_payload: connector_response_update
+                .connector_request_payload
+                .or(source.connector_request_payload),
+            connector_response_payload: connector_response_update
+                .connector_response_payload
+                .or(source.connector_response_payload),
             ..source
         }
     }
@@ -102,6 +120,15 @@ impl From<ConnectorResponseUpdate> for ConnectorResponseUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Self::default()
             },
+            ConnectorResponseUpdate::PayloadUpdate {
+                connector_request_payload,
+                connector_response_payload,
+            } => Self {
+                connector_request_payload: Some(connector_request_payload),
+                connector_response_payload: Some(connector_response_payload),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Self::default()
+            },
         }
     }
 }
@@ -0,0 +1,105 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::credits};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = credits)]
+pub struct Credit {
+    pub id: i32,
+    pub credit_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_credit_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::RefundStatus,
+    pub reason: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
+)]
+#[diesel(table_name = credits)]
+pub struct CreditNew {
+    pub credit_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_credit_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::RefundStatus,
+    pub reason: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CreditUpdate {
+    ResponseUpdate {
+        status: storage_enums::RefundStatus,
+        connector_credit_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::RefundStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = credits)]
+pub struct CreditUpdateInternal {
+    pub status: Option<storage_enums::RefundStatus>,
+    pub connector_credit_id: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CreditUpdate> for CreditUpdateInternal {
+    fn from(credit_update: CreditUpdate) -> Self {
+        let now = Some(common_utils::date_time::now());
+        match credit_update {
+            CreditUpdate::ResponseUpdate {
+                status,
+                connector_credit_id,
+            } => Self {
+                status: Some(status),
+                connector_credit_id,
+                modified_at: now,
+                ..Self::default()
+            },
+            CreditUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: now,
+                ..Self::default()
+            },
+        }
+    }
+}
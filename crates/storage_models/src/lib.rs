@@ -1,7 +1,12 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
+pub mod blocklist;
+pub mod business_profile;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod credits;
 pub mod customers;
 pub mod dispute;
 pub mod enums;
@@ -10,18 +15,26 @@ pub mod errors;
 pub mod events;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod locker_card;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod query;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod schema;
+pub mod user;
+pub mod user_role;
+pub mod webhook_delivery_attempt;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
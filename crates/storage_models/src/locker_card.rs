@@ -0,0 +1,33 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::locker_card;
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = locker_card)]
+pub struct LockerCard {
+    pub id: i32,
+    pub card_id: String,
+    pub external_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub card_fingerprint: String,
+    pub card_global_fingerprint: String,
+    /// Card details, encrypted with the data encryption key of the merchant
+    pub card_data: Vec<u8>,
+    pub nickname: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = locker_card)]
+pub struct LockerCardNew {
+    pub card_id: String,
+    pub external_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub card_fingerprint: String,
+    pub card_global_fingerprint: String,
+    pub card_data: Vec<u8>,
+    pub nickname: Option<String>,
+}
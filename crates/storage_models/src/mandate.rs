@@ -52,6 +52,23 @@ pub struct MandateNew {
     pub connector_mandate_id: Option<String>,
 }
 
+impl Mandate {
+    /// Whether the mandate is allowed to move from its current status to `status`.
+    /// A revoked mandate is terminal and cannot be reactivated.
+    pub fn can_transition_to(&self, status: storage_enums::MandateStatus) -> bool {
+        use storage_enums::MandateStatus;
+
+        match (self.mandate_status, status) {
+            (MandateStatus::Revoked, _) => false,
+            (current, next) if current == next => false,
+            (_, MandateStatus::Pending) => false,
+            (MandateStatus::Pending, _)
+            | (MandateStatus::Active, _)
+            | (MandateStatus::Inactive, _) => true,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum MandateUpdate {
     StatusUpdate {
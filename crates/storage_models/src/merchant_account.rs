@@ -1,5 +1,6 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::StrongSecret;
+use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::merchant_account};
 
@@ -33,6 +34,18 @@ pub struct MerchantAccount {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub routing_rules: Option<serde_json::Value>,
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+    pub sca_exemption_policy: Option<serde_json::Value>,
+    pub credit_policy: Option<serde_json::Value>,
+    pub fraud_check_policy: Option<serde_json::Value>,
+    pub velocity_policy: Option<serde_json::Value>,
+    pub duplicate_payment_policy: Option<serde_json::Value>,
+    pub card_verification_policy: Option<serde_json::Value>,
+    pub test_mode: bool,
+    pub organization_id: Option<String>,
+    pub is_deleted: bool,
+    pub deleted_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -53,6 +66,16 @@ pub struct MerchantAccountNew {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub routing_rules: Option<serde_json::Value>,
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+    pub sca_exemption_policy: Option<serde_json::Value>,
+    pub credit_policy: Option<serde_json::Value>,
+    pub fraud_check_policy: Option<serde_json::Value>,
+    pub velocity_policy: Option<serde_json::Value>,
+    pub duplicate_payment_policy: Option<serde_json::Value>,
+    pub card_verification_policy: Option<serde_json::Value>,
+    pub test_mode: Option<bool>,
+    pub organization_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -72,10 +95,25 @@ pub enum MerchantAccountUpdate {
         locker_id: Option<String>,
         metadata: Option<serde_json::Value>,
         routing_algorithm: Option<serde_json::Value>,
+        authorization_expiry_policy: Option<serde_json::Value>,
+        sca_exemption_policy: Option<serde_json::Value>,
+        credit_policy: Option<serde_json::Value>,
+        fraud_check_policy: Option<serde_json::Value>,
+        velocity_policy: Option<serde_json::Value>,
+        duplicate_payment_policy: Option<serde_json::Value>,
+        card_verification_policy: Option<serde_json::Value>,
+        test_mode: Option<bool>,
     },
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
     },
+    RoutingUpdate {
+        routing_algorithm: Option<serde_json::Value>,
+        routing_rules: Option<serde_json::Value>,
+    },
+    /// Closes the merchant account, leaving a tombstone of it in place of the account. The
+    /// contact details of the merchant are cleared.
+    SoftDelete { deleted_at: PrimitiveDateTime },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -96,6 +134,17 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<serde_json::Value>,
     routing_algorithm: Option<serde_json::Value>,
+    routing_rules: Option<serde_json::Value>,
+    authorization_expiry_policy: Option<serde_json::Value>,
+    sca_exemption_policy: Option<serde_json::Value>,
+    credit_policy: Option<serde_json::Value>,
+    fraud_check_policy: Option<serde_json::Value>,
+    velocity_policy: Option<serde_json::Value>,
+    duplicate_payment_policy: Option<serde_json::Value>,
+    card_verification_policy: Option<serde_json::Value>,
+    test_mode: Option<bool>,
+    is_deleted: Option<bool>,
+    deleted_at: Option<PrimitiveDateTime>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -116,6 +165,14 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                authorization_expiry_policy,
+                sca_exemption_policy,
+                credit_policy,
+                fraud_check_policy,
+                velocity_policy,
+                duplicate_payment_policy,
+                card_verification_policy,
+                test_mode,
             } => Self {
                 merchant_name,
                 api_key,
@@ -131,12 +188,34 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                authorization_expiry_policy,
+                sca_exemption_policy,
+                credit_policy,
+                fraud_check_policy,
+                velocity_policy,
+                duplicate_payment_policy,
+                card_verification_policy,
+                test_mode,
                 ..Default::default()
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
                 ..Default::default()
             },
+            MerchantAccountUpdate::RoutingUpdate {
+                routing_algorithm,
+                routing_rules,
+            } => Self {
+                routing_algorithm,
+                routing_rules,
+                ..Default::default()
+            },
+            MerchantAccountUpdate::SoftDelete { deleted_at } => Self {
+                merchant_details: Some(serde_json::Value::Null),
+                is_deleted: Some(true),
+                deleted_at: Some(deleted_at),
+                ..Default::default()
+            },
         }
     }
 }
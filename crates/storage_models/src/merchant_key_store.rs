@@ -0,0 +1,21 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::merchant_key_store;
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStore {
+    pub id: i32,
+    pub merchant_id: String,
+    /// Data encryption key of the merchant, encrypted with the key encryption key of the locker
+    pub key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStoreNew {
+    pub merchant_id: String,
+    pub key: Vec<u8>,
+}
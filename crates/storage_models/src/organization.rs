@@ -0,0 +1,20 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::organization;
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = organization, primary_key(org_id))]
+pub struct Organization {
+    pub org_id: String,
+    pub org_name: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = organization)]
+pub struct OrganizationNew {
+    pub org_id: String,
+    pub org_name: Option<String>,
+}
@@ -40,6 +40,16 @@ pub struct PaymentAttempt {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub connector_attempts: Option<serde_json::Value>,
+    pub unified_code: Option<storage_enums::UnifiedCode>,
+    pub unified_message: Option<String>,
+    pub connector_latency_in_millis: Option<i64>,
+    pub network_transaction_id: Option<String>,
+    pub external_three_ds_authentication: Option<serde_json::Value>,
+    pub fraud_check: Option<serde_json::Value>,
+    pub duplicate_of: Option<String>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
 }
 
 #[derive(
@@ -80,6 +90,16 @@ pub struct PaymentAttemptNew {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub connector_attempts: Option<serde_json::Value>,
+    pub unified_code: Option<storage_enums::UnifiedCode>,
+    pub unified_message: Option<String>,
+    pub connector_latency_in_millis: Option<i64>,
+    pub network_transaction_id: Option<String>,
+    pub external_three_ds_authentication: Option<serde_json::Value>,
+    pub fraud_check: Option<serde_json::Value>,
+    pub duplicate_of: Option<String>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +118,7 @@ pub enum PaymentAttemptUpdate {
     UpdateTrackers {
         payment_token: Option<String>,
         connector: Option<String>,
+        payment_method_data: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -127,6 +148,10 @@ pub enum PaymentAttemptUpdate {
         payment_method_id: Option<Option<String>>,
         mandate_id: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        connector_latency_in_millis: Option<i64>,
+        network_transaction_id: Option<String>,
+        avs_result: Option<storage_enums::AvsResult>,
+        cvv_result: Option<storage_enums::CvvResult>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -136,6 +161,21 @@ pub enum PaymentAttemptUpdate {
         status: storage_enums::AttemptStatus,
         error_code: Option<String>,
         error_message: Option<String>,
+        unified_code: Option<storage_enums::UnifiedCode>,
+        unified_message: Option<String>,
+        connector_latency_in_millis: Option<i64>,
+    },
+    ConnectorAttemptsUpdate {
+        connector_attempts: Option<serde_json::Value>,
+    },
+    ExternalAuthenticationUpdate {
+        external_three_ds_authentication: serde_json::Value,
+    },
+    FraudCheckUpdate {
+        fraud_check: serde_json::Value,
+    },
+    DuplicatePaymentUpdate {
+        duplicate_of: String,
     },
 }
 
@@ -161,6 +201,16 @@ pub struct PaymentAttemptUpdateInternal {
     payment_method_data: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_experience: Option<storage_enums::PaymentExperience>,
+    connector_attempts: Option<serde_json::Value>,
+    unified_code: Option<storage_enums::UnifiedCode>,
+    unified_message: Option<String>,
+    connector_latency_in_millis: Option<i64>,
+    network_transaction_id: Option<String>,
+    external_three_ds_authentication: Option<serde_json::Value>,
+    fraud_check: Option<serde_json::Value>,
+    duplicate_of: Option<String>,
+    avs_result: Option<storage_enums::AvsResult>,
+    cvv_result: Option<storage_enums::CvvResult>,
 }
 
 impl PaymentAttemptUpdate {
@@ -183,6 +233,23 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            payment_method_data: pa_update.payment_method_data.or(source.payment_method_data),
+            connector_attempts: pa_update.connector_attempts.or(source.connector_attempts),
+            unified_code: pa_update.unified_code.or(source.unified_code),
+            unified_message: pa_update.unified_message.or(source.unified_message),
+            connector_latency_in_millis: pa_update
+                .connector_latency_in_millis
+                .or(source.connector_latency_in_millis),
+            network_transaction_id: pa_update
+                .network_transaction_id
+                .or(source.network_transaction_id),
+            external_three_ds_authentication: pa_update
+                .external_three_ds_authentication
+                .or(source.external_three_ds_authentication),
+            fraud_check: pa_update.fraud_check.or(source.fraud_check),
+            duplicate_of: pa_update.duplicate_of.or(source.duplicate_of),
+            avs_result: pa_update.avs_result.or(source.avs_result),
+            cvv_result: pa_update.cvv_result.or(source.cvv_result),
             ..source
         }
     }
@@ -266,6 +333,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_id,
                 mandate_id,
                 connector_metadata,
+                connector_latency_in_millis,
+                network_transaction_id,
+                avs_result,
+                cvv_result,
             } => Self {
                 status: Some(status),
                 connector,
@@ -275,6 +346,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 mandate_id,
                 connector_metadata,
+                connector_latency_in_millis,
+                network_transaction_id,
+                avs_result,
+                cvv_result,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -282,11 +357,17 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status,
                 error_code,
                 error_message,
+                unified_code,
+                unified_message,
+                connector_latency_in_millis,
             } => Self {
                 connector,
                 status: Some(status),
                 error_message,
                 error_code,
+                unified_code,
+                unified_message,
+                connector_latency_in_millis,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -297,9 +378,33 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
+                payment_method_data,
             } => Self {
                 payment_token,
                 connector,
+                payment_method_data,
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::ConnectorAttemptsUpdate { connector_attempts } => Self {
+                connector_attempts,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::ExternalAuthenticationUpdate {
+                external_three_ds_authentication,
+            } => Self {
+                external_three_ds_authentication: Some(external_three_ds_authentication),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::FraudCheckUpdate { fraud_check } => Self {
+                fraud_check: Some(fraud_check),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::DuplicatePaymentUpdate { duplicate_of } => Self {
+                duplicate_of: Some(duplicate_of),
+                modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
         }
@@ -29,6 +29,12 @@ pub struct PaymentIntent {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
+    pub split_payments: Option<serde_json::Value>,
+    pub forex_quote: Option<serde_json::Value>,
+    pub capture_on: Option<PrimitiveDateTime>,
+    pub capture_expires_at: Option<PrimitiveDateTime>,
+    pub profile_id: Option<String>,
+    pub connector_metadata: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -65,6 +71,12 @@ pub struct PaymentIntentNew {
     pub client_secret: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub split_payments: Option<serde_json::Value>,
+    pub forex_quote: Option<serde_json::Value>,
+    pub capture_on: Option<PrimitiveDateTime>,
+    pub capture_expires_at: Option<PrimitiveDateTime>,
+    pub profile_id: Option<String>,
+    pub connector_metadata: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +113,11 @@ pub enum PaymentIntentUpdate {
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
         return_url: Option<String>,
+        split_payments: Option<serde_json::Value>,
+        forex_quote: Option<serde_json::Value>,
+        connector_metadata: Option<serde_json::Value>,
+        statement_descriptor_name: Option<String>,
+        statement_descriptor_suffix: Option<String>,
     },
 }
 
@@ -121,6 +138,11 @@ pub struct PaymentIntentUpdateInternal {
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
     pub modified_at: Option<PrimitiveDateTime>,
+    pub split_payments: Option<serde_json::Value>,
+    pub forex_quote: Option<serde_json::Value>,
+    pub connector_metadata: Option<serde_json::Value>,
+    pub statement_descriptor_name: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -147,6 +169,17 @@ impl PaymentIntentUpdate {
             shipping_address_id: internal_update
                 .shipping_address_id
                 .or(source.shipping_address_id),
+            split_payments: internal_update.split_payments.or(source.split_payments),
+            forex_quote: internal_update.forex_quote.or(source.forex_quote),
+            connector_metadata: internal_update
+                .connector_metadata
+                .or(source.connector_metadata),
+            statement_descriptor_name: internal_update
+                .statement_descriptor_name
+                .or(source.statement_descriptor_name),
+            statement_descriptor_suffix: internal_update
+                .statement_descriptor_suffix
+                .or(source.statement_descriptor_suffix),
             modified_at: common_utils::date_time::now(),
             ..source
         }
@@ -165,6 +198,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_address_id,
                 billing_address_id,
                 return_url,
+                split_payments,
+                forex_quote,
+                connector_metadata,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -176,6 +214,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 billing_address_id,
                 modified_at: Some(common_utils::date_time::now()),
                 return_url,
+                split_payments,
+                forex_quote,
+                connector_metadata,
+                statement_descriptor_name,
+                statement_descriptor_suffix,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate { metadata } => Self {
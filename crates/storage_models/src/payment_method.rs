@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
@@ -30,6 +30,8 @@ pub struct PaymentMethod {
     pub payment_method_issuer: Option<String>,
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<serde_json::Value>,
+    pub card_type: Option<String>,
+    pub network_transaction_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -55,6 +57,8 @@ pub struct PaymentMethodNew {
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub card_type: Option<String>,
+    pub network_transaction_id: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -82,6 +86,35 @@ impl Default for PaymentMethodNew {
             created_at: now,
             last_modified: now,
             metadata: Option::default(),
+            card_type: Option::default(),
+            network_transaction_id: Option::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodUpdate {
+    NetworkTransactionIdUpdate {
+        network_transaction_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_methods)]
+pub struct PaymentMethodUpdateInternal {
+    network_transaction_id: Option<String>,
+    last_modified: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
+    fn from(payment_method_update: PaymentMethodUpdate) -> Self {
+        match payment_method_update {
+            PaymentMethodUpdate::NetworkTransactionIdUpdate {
+                network_transaction_id,
+            } => Self {
+                network_transaction_id,
+                last_modified: Some(common_utils::date_time::now()),
+            },
         }
     }
 }
@@ -0,0 +1,107 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payouts)]
+pub struct Payouts {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
+)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoutsUpdate {
+    ResponseUpdate {
+        status: storage_enums::PayoutStatus,
+        connector_payout_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsUpdateInternal {
+    pub status: Option<storage_enums::PayoutStatus>,
+    pub connector_payout_id: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutsUpdate> for PayoutsUpdateInternal {
+    fn from(payout_update: PayoutsUpdate) -> Self {
+        let now = Some(common_utils::date_time::now());
+        match payout_update {
+            PayoutsUpdate::ResponseUpdate {
+                status,
+                connector_payout_id,
+            } => Self {
+                status: Some(status),
+                connector_payout_id,
+                modified_at: now,
+                ..Self::default()
+            },
+            PayoutsUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: now,
+                ..Self::default()
+            },
+        }
+    }
+}
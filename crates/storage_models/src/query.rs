@@ -1,17 +1,31 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
+pub mod blocklist;
+pub mod business_profile;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod credits;
 pub mod customers;
+pub mod dispute;
 pub mod events;
 pub mod generics;
+pub mod locker_card;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod user;
+pub mod user_role;
+pub mod webhook_delivery_attempt;
@@ -0,0 +1,58 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    credits::{Credit, CreditNew, CreditUpdate, CreditUpdateInternal},
+    errors,
+    schema::credits::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CreditNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Credit> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Credit {
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, credit: CreditUpdate) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::credit_id.eq(self.credit_id.to_owned())),
+            CreditUpdateInternal::from(credit),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_credit_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        credit_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::credit_id.eq(credit_id.to_owned())),
+        )
+        .await
+    }
+}
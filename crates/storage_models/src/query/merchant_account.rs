@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -52,7 +52,9 @@ impl MerchantAccount {
             _,
         >(
             conn,
-            dsl::merchant_id.eq(merchant_id.to_owned()),
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_deleted.eq(false)),
             MerchantAccountUpdateInternal::from(merchant_account),
         )
         .await
@@ -76,7 +78,23 @@ impl MerchantAccount {
     ) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
-            dsl::merchant_id.eq(merchant_id.to_owned()),
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_deleted.eq(false)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_deleted_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_deleted.eq(true)),
         )
         .await
     }
@@ -85,7 +103,9 @@ impl MerchantAccount {
     pub async fn find_by_api_key(conn: &PgPooledConn, api_key: &str) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
-            dsl::api_key.eq(api_key.to_owned()),
+            dsl::api_key
+                .eq(api_key.to_owned())
+                .and(dsl::is_deleted.eq(false)),
         )
         .await
     }
@@ -97,7 +117,26 @@ impl MerchantAccount {
     ) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
-            dsl::publishable_key.eq(publishable_key.to_owned()),
+            dsl::publishable_key
+                .eq(publishable_key.to_owned())
+                .and(dsl::is_deleted.eq(false)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_organization_id(
+        conn: &PgPooledConn,
+        organization_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::organization_id
+                .eq(organization_id.to_owned())
+                .and(dsl::is_deleted.eq(false)),
+            None,
+            None,
+            Some(dsl::id.asc()),
         )
         .await
     }
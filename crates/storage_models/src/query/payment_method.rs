@@ -3,7 +3,10 @@ use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    payment_method::{PaymentMethod, PaymentMethodNew},
+    errors,
+    payment_method::{
+        PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
+    },
     schema::payment_methods::dsl,
     PgPooledConn, StorageResult,
 };
@@ -16,6 +19,32 @@ impl PaymentMethodNew {
 }
 
 impl PaymentMethod {
+    #[instrument(skip(conn))]
+    pub async fn update_with_payment_method_id(
+        self,
+        conn: &PgPooledConn,
+        payment_method: PaymentMethodUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_method_id.eq(self.payment_method_id.to_owned()),
+            PaymentMethodUpdateInternal::from(payment_method),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_payment_method_id(
         conn: &PgPooledConn,
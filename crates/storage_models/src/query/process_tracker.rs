@@ -1,4 +1,7 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, PgArrayExpressionMethods,
+    Table,
+};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -89,6 +92,30 @@ impl ProcessTracker {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_unfinished_processes_by_tag(
+        conn: &PgPooledConn,
+        tag: &str,
+    ) -> StorageResult<Vec<Self>> {
+        let mut processes: Vec<Self> = generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::tag
+                .contains(vec![tag.to_owned()])
+                .and(dsl::status.ne(enums::ProcessTrackerStatus::Finish)),
+            None,
+            None,
+            None,
+        )
+        .await?;
+        processes.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(processes)
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
@@ -0,0 +1,138 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    recon::{
+        ReconRecord, ReconRecordNew, ReconRecordUpdate, ReconRecordUpdateInternal, ReconReport,
+        ReconReportNew, ReconReportUpdate, ReconReportUpdateInternal,
+    },
+    schema::recon_report::dsl,
+    PgPooledConn, StorageResult,
+};
+
+/// Records inserted in a single statement, which stays well below the limit of Postgres on the
+/// number of bind parameters of a statement
+const RECON_RECORD_INSERT_BATCH_SIZE: usize = 1000;
+
+impl ReconReportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconReport {
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        report: ReconReportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::report_id.eq(self.report_id.to_owned())),
+            ReconReportUpdateInternal::from(report),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
+
+impl ReconRecordNew {
+    #[instrument(skip_all)]
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        records: Vec<Self>,
+    ) -> StorageResult<Vec<ReconRecord>> {
+        let mut inserted_records = Vec::with_capacity(records.len());
+        for chunk in records.chunks(RECON_RECORD_INSERT_BATCH_SIZE) {
+            let query =
+                diesel::insert_into(<ReconRecord as HasTable>::table()).values(chunk.to_vec());
+            logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+            inserted_records.extend(
+                query
+                    .get_results_async::<ReconRecord>(conn)
+                    .await
+                    .into_report()
+                    .change_context(errors::DatabaseError::Others)
+                    .attach_printable("Error while inserting recon records")?,
+            );
+        }
+        Ok(inserted_records)
+    }
+}
+
+impl ReconRecord {
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        record: ReconRecordUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            ReconRecordUpdateInternal::from(record),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::webhook_delivery_attempt::dsl,
+    webhook_delivery_attempt::{WebhookDeliveryAttempt, WebhookDeliveryAttemptNew},
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookDeliveryAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookDeliveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookDeliveryAttempt {
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_event_id(
+        conn: &PgPooledConn,
+        event_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::event_id.eq(event_id.to_owned()),
+            None,
+            None,
+            Some(dsl::attempt_number.desc()),
+        )
+        .await
+    }
+}
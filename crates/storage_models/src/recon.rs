@@ -0,0 +1,186 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{recon_record, recon_report},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReport {
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub source: storage_enums::ReconReportSource,
+    pub status: storage_enums::ReconReportStatus,
+    pub file_name: Option<String>,
+    pub total_records: i32,
+    pub matched_records: i32,
+    pub mismatched_records: i32,
+    pub missing_records: i32,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub source: storage_enums::ReconReportSource,
+    pub status: storage_enums::ReconReportStatus,
+    pub file_name: Option<String>,
+    pub total_records: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReconReportUpdate {
+    ProcessingUpdate {
+        total_records: i32,
+    },
+    ResultUpdate {
+        matched_records: i32,
+        mismatched_records: i32,
+        missing_records: i32,
+    },
+    FailureUpdate {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportUpdateInternal {
+    pub status: Option<storage_enums::ReconReportStatus>,
+    pub total_records: Option<i32>,
+    pub matched_records: Option<i32>,
+    pub mismatched_records: Option<i32>,
+    pub missing_records: Option<i32>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReconReportUpdate> for ReconReportUpdateInternal {
+    fn from(report_update: ReconReportUpdate) -> Self {
+        let now = Some(common_utils::date_time::now());
+        match report_update {
+            ReconReportUpdate::ProcessingUpdate { total_records } => Self {
+                status: Some(storage_enums::ReconReportStatus::Processing),
+                total_records: Some(total_records),
+                modified_at: now,
+                ..Self::default()
+            },
+            ReconReportUpdate::ResultUpdate {
+                matched_records,
+                mismatched_records,
+                missing_records,
+            } => Self {
+                status: Some(storage_enums::ReconReportStatus::Completed),
+                matched_records: Some(matched_records),
+                mismatched_records: Some(mismatched_records),
+                missing_records: Some(missing_records),
+                modified_at: now,
+                ..Self::default()
+            },
+            ReconReportUpdate::FailureUpdate { error_message } => Self {
+                status: Some(storage_enums::ReconReportStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: now,
+                ..Self::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = recon_record)]
+pub struct ReconRecord {
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub line_number: i32,
+    pub record_type: storage_enums::ReconRecordType,
+    pub connector_transaction_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub settlement_status: Option<String>,
+    pub recon_status: storage_enums::ReconStatus,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<String>,
+    pub mismatch_reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = recon_record)]
+pub struct ReconRecordNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub line_number: i32,
+    pub record_type: storage_enums::ReconRecordType,
+    pub connector_transaction_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub settlement_status: Option<String>,
+    pub recon_status: storage_enums::ReconStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReconRecordUpdate {
+    MatchUpdate {
+        recon_status: storage_enums::ReconStatus,
+        payment_id: Option<String>,
+        attempt_id: Option<String>,
+        refund_id: Option<String>,
+        expected_amount: Option<i64>,
+        expected_currency: Option<String>,
+        mismatch_reason: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_record)]
+pub struct ReconRecordUpdateInternal {
+    pub recon_status: Option<storage_enums::ReconStatus>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<String>,
+    pub mismatch_reason: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReconRecordUpdate> for ReconRecordUpdateInternal {
+    fn from(record_update: ReconRecordUpdate) -> Self {
+        match record_update {
+            ReconRecordUpdate::MatchUpdate {
+                recon_status,
+                payment_id,
+                attempt_id,
+                refund_id,
+                expected_amount,
+                expected_currency,
+                mismatch_reason,
+            } => Self {
+                recon_status: Some(recon_status),
+                payment_id,
+                attempt_id,
+                refund_id,
+                expected_amount,
+                expected_currency,
+                mismatch_reason,
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -33,6 +33,7 @@ pub struct Refund {
     pub attempt_id: String,
     pub refund_reason: Option<String>,
     pub refund_error_code: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReason>,
 }
 
 #[derive(
@@ -70,6 +71,7 @@ pub struct RefundNew {
     pub description: Option<String>,
     pub attempt_id: String,
     pub refund_reason: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReason>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
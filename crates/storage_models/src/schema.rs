@@ -40,6 +40,79 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        role -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (id) {
+        id -> Int4,
+        audit_log_id -> Varchar,
+        merchant_id -> Varchar,
+        actor_type -> Varchar,
+        actor_id -> Nullable<Varchar>,
+        http_method -> Nullable<Varchar>,
+        route -> Nullable<Varchar>,
+        resource_type -> Varchar,
+        resource_id -> Varchar,
+        previous_status -> Nullable<Varchar>,
+        new_status -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        fingerprint_id -> Varchar,
+        data_kind -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    business_profile (profile_id) {
+        profile_id -> Varchar,
+        merchant_id -> Varchar,
+        profile_name -> Varchar,
+        business_country -> Nullable<Varchar>,
+        business_label -> Nullable<Varchar>,
+        webhook_details -> Nullable<Json>,
+        routing_algorithm -> Nullable<Json>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (capture_id) {
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        authorized_attempt_id -> Varchar,
+        connector_capture_id -> Nullable<Varchar>,
+        capture_sequence -> Int2,
     }
 }
 
@@ -69,6 +142,31 @@ diesel::table! {
         connector_transaction_id -> Nullable<Varchar>,
         authentication_data -> Nullable<Json>,
         encoded_data -> Nullable<Text>,
+        connector_request_payload -> Nullable<Json>,
+        connector_response_payload -> Nullable<Json>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    credits (id) {
+        id -> Int4,
+        credit_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        connector_credit_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        status -> RefundStatus,
+        reason -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -87,6 +185,34 @@ diesel::table! {
         description -> Nullable<Varchar>,
         created_at -> Timestamp,
         metadata -> Nullable<Json>,
+        default_payment_method_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Varchar,
+        currency -> Varchar,
+        dispute_stage -> Varchar,
+        dispute_status -> Varchar,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        dispute_created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        connector -> Varchar,
     }
 }
 
@@ -104,6 +230,26 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        merchant_id -> Nullable<Varchar>,
+        content -> Nullable<Json>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    locker_card (id) {
+        id -> Int4,
+        card_id -> Varchar,
+        external_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        card_fingerprint -> Varchar,
+        card_global_fingerprint -> Varchar,
+        card_data -> Bytea,
+        nickname -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -178,6 +324,18 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        routing_rules -> Nullable<Json>,
+        authorization_expiry_policy -> Nullable<Json>,
+        sca_exemption_policy -> Nullable<Json>,
+        credit_policy -> Nullable<Json>,
+        fraud_check_policy -> Nullable<Json>,
+        velocity_policy -> Nullable<Json>,
+        duplicate_payment_policy -> Nullable<Json>,
+        card_verification_policy -> Nullable<Json>,
+        test_mode -> Bool,
+        organization_id -> Nullable<Varchar>,
+        is_deleted -> Bool,
+        deleted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -196,6 +354,31 @@ diesel::table! {
         payment_methods_enabled -> Nullable<Array<Nullable<Json>>>,
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
+        connector_webhook_details -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_store (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        key -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    organization (org_id) {
+        org_id -> Varchar,
+        org_name -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -237,6 +420,16 @@ diesel::table! {
         payment_experience -> Nullable<Varchar>,
         payment_method_type -> Nullable<Varchar>,
         payment_method_data -> Nullable<Jsonb>,
+        connector_attempts -> Nullable<Jsonb>,
+        unified_code -> Nullable<Varchar>,
+        unified_message -> Nullable<Varchar>,
+        connector_latency_in_millis -> Nullable<Int8>,
+        network_transaction_id -> Nullable<Varchar>,
+        external_three_ds_authentication -> Nullable<Jsonb>,
+        fraud_check -> Nullable<Jsonb>,
+        duplicate_of -> Nullable<Varchar>,
+        avs_result -> Nullable<Varchar>,
+        cvv_result -> Nullable<Varchar>,
     }
 }
 
@@ -267,6 +460,12 @@ diesel::table! {
         setup_future_usage -> Nullable<FutureUsage>,
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
+        split_payments -> Nullable<Jsonb>,
+        forex_quote -> Nullable<Jsonb>,
+        capture_on -> Nullable<Timestamp>,
+        capture_expires_at -> Nullable<Timestamp>,
+        profile_id -> Nullable<Varchar>,
+        connector_metadata -> Nullable<Jsonb>,
     }
 }
 
@@ -296,6 +495,32 @@ diesel::table! {
         payment_method_issuer -> Nullable<Varchar>,
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
+        card_type -> Nullable<Varchar>,
+        network_transaction_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        payout_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        status -> Varchar,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -320,6 +545,54 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_record (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        line_number -> Int4,
+        record_type -> Varchar,
+        connector_transaction_id -> Varchar,
+        amount -> Int8,
+        currency -> Varchar,
+        settlement_status -> Nullable<Varchar>,
+        recon_status -> Varchar,
+        payment_id -> Nullable<Varchar>,
+        attempt_id -> Nullable<Varchar>,
+        refund_id -> Nullable<Varchar>,
+        expected_amount -> Nullable<Int8>,
+        expected_currency -> Nullable<Varchar>,
+        mismatch_reason -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_report (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        source -> Varchar,
+        status -> Varchar,
+        file_name -> Nullable<Varchar>,
+        total_records -> Int4,
+        matched_records -> Int4,
+        mismatched_records -> Int4,
+        missing_records -> Int4,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -349,6 +622,7 @@ diesel::table! {
         attempt_id -> Varchar,
         refund_reason -> Nullable<Varchar>,
         refund_error_code -> Nullable<Text>,
+        refund_reason_code -> Nullable<Varchar>,
     }
 }
 
@@ -364,21 +638,82 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_roles (id) {
+        id -> Int4,
+        user_id -> Varchar,
+        merchant_id -> Varchar,
+        role -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    users (id) {
+        id -> Int4,
+        user_id -> Varchar,
+        email -> Varchar,
+        name -> Varchar,
+        password -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_delivery_attempt (id) {
+        id -> Int4,
+        event_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_number -> Int4,
+        delivery_status -> Varchar,
+        request_body -> Jsonb,
+        response_status_code -> Nullable<Int4>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_log,
+    blocklist,
+    business_profile,
+    captures,
     configs,
     connector_response,
+    credits,
     customers,
+    dispute,
     events,
+    locker_card,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_store,
+    organization,
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
+    recon_record,
+    recon_report,
     refund,
     reverse_lookup,
+    user_roles,
+    users,
+    webhook_delivery_attempt,
 );
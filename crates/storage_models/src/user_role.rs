@@ -0,0 +1,46 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::user_roles};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = user_roles)]
+pub struct UserRole {
+    pub id: i32,
+    pub user_id: String,
+    pub merchant_id: String,
+    pub role: storage_enums::RoleName,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_roles)]
+pub struct UserRoleNew {
+    pub user_id: String,
+    pub merchant_id: String,
+    pub role: storage_enums::RoleName,
+}
+
+#[derive(Debug)]
+pub enum UserRoleUpdate {
+    RoleUpdate { role: storage_enums::RoleName },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_roles)]
+pub struct UserRoleUpdateInternal {
+    role: storage_enums::RoleName,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<UserRoleUpdate> for UserRoleUpdateInternal {
+    fn from(user_role_update: UserRoleUpdate) -> Self {
+        match user_role_update {
+            UserRoleUpdate::RoleUpdate { role } => Self {
+                role,
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
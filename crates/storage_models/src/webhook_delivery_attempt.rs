@@ -0,0 +1,31 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_delivery_attempt};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
+#[diesel(table_name = webhook_delivery_attempt)]
+pub struct WebhookDeliveryAttemptNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    pub delivery_status: storage_enums::WebhookDeliveryStatus,
+    pub request_body: serde_json::Value,
+    pub response_status_code: Option<i32>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = webhook_delivery_attempt)]
+pub struct WebhookDeliveryAttempt {
+    pub id: i32,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    pub delivery_status: storage_enums::WebhookDeliveryStatus,
+    pub request_body: serde_json::Value,
+    pub response_status_code: Option<i32>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
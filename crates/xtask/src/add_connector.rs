@@ -0,0 +1,546 @@
+//!
+//! Scaffolding of new connectors.
+//!
+//! The connector module, its transformers and its tests are rendered from the templates in
+//! `connector-template`, and the connector is registered everywhere connectors are listed, so that
+//! the workspace builds right away and only the `TODO`s of the generated files are left to fill.
+//!
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const TEMPLATE_DIR: &str = "connector-template";
+const CONNECTOR_DIR: &str = "crates/router/src/connector";
+const CONNECTOR_TESTS_DIR: &str = "crates/router/tests/connectors";
+
+/// Configuration files holding the base URLs of the connectors and the connectors supporting cards.
+const CONFIG_FILES: [&str; 4] = [
+    "config/Development.toml",
+    "config/docker_compose.toml",
+    "config/config.example.toml",
+    "loadtest/config/Development.toml",
+];
+
+/// The widest line rustfmt formats lists into.
+const MAX_LINE_WIDTH: usize = 100;
+
+#[derive(Debug, clap::Args)]
+pub struct AddConnectorArgs {
+    /// Name of the connector in snake case, such as `afterpay_clearpay`
+    name: String,
+    /// Base URL of the sandbox of the connector
+    #[arg(long, default_value = "")]
+    base_url: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AddConnectorError {
+    #[error(
+        "Invalid connector name `{0}`, expected a snake case name such as `afterpay_clearpay`"
+    )]
+    InvalidName(String),
+    #[error("Connector `{0}` already exists")]
+    AlreadyExists(String),
+    #[error("Unsupported placeholder `{0}` in the connector template")]
+    UnsupportedPlaceholder(String),
+    #[error("Could not find {anchor} in {}", path.display())]
+    AnchorNotFound { path: PathBuf, anchor: String },
+    #[error("Failed to {action} {}: {source}", path.display())]
+    Io {
+        action: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+type AddConnectorResult<T> = Result<T, AddConnectorError>;
+
+/// The name of a connector, in the cases it is written in.
+#[derive(Debug)]
+struct ConnectorName {
+    /// Used for modules, configuration keys and the serialized connector enums
+    snake_case: String,
+    /// Used for the connector struct and the variants of the connector enums
+    pascal_case: String,
+}
+
+impl ConnectorName {
+    fn new(name: &str) -> AddConnectorResult<Self> {
+        let is_valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            && name.split('_').all(|word| !word.is_empty());
+        if !is_valid {
+            return Err(AddConnectorError::InvalidName(name.to_owned()));
+        }
+
+        Ok(Self {
+            snake_case: name.to_owned(),
+            pascal_case: to_pascal_case(name),
+        })
+    }
+}
+
+fn to_pascal_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+pub fn run(root: &Path, args: &AddConnectorArgs) -> AddConnectorResult<()> {
+    let name = ConnectorName::new(&args.name)?;
+    let connector_file = root
+        .join(CONNECTOR_DIR)
+        .join(format!("{}.rs", name.snake_case));
+    if connector_file.exists() {
+        return Err(AddConnectorError::AlreadyExists(name.snake_case));
+    }
+
+    let generated_files = [
+        ("mod.rs", connector_file),
+        (
+            "transformers.rs",
+            root.join(CONNECTOR_DIR)
+                .join(&name.snake_case)
+                .join("transformers.rs"),
+        ),
+        (
+            "test.rs",
+            root.join(CONNECTOR_TESTS_DIR)
+                .join(format!("{}.rs", name.snake_case)),
+        ),
+    ];
+    for (template, destination) in &generated_files {
+        let template = read_file(&root.join(TEMPLATE_DIR).join(template))?;
+        write_file(destination, &render_template(&template, &name)?)?;
+    }
+
+    register_connector(root, &name, &args.base_url)?;
+    format_files(
+        generated_files
+            .iter()
+            .map(|(_, destination)| destination.as_path()),
+    );
+
+    println!("Added connector `{}`. Next steps:", name.snake_case);
+    for (_, destination) in &generated_files {
+        println!(
+            "- fill the TODOs of {}",
+            destination
+                .strip_prefix(root)
+                .unwrap_or(destination)
+                .display()
+        );
+    }
+    println!(
+        "- add the credentials of the connector to {CONNECTOR_TESTS_DIR}/auth.toml and run its \
+         tests with `cargo test --package router --test connectors -- {}`",
+        name.snake_case
+    );
+    Ok(())
+}
+
+/// Adds the connector wherever connectors are listed.
+fn register_connector(root: &Path, name: &ConnectorName, base_url: &str) -> AddConnectorResult<()> {
+    let ConnectorName {
+        snake_case,
+        pascal_case,
+    } = name;
+
+    edit_file(&root.join("crates/router/src/connector.rs"), |content| {
+        let content =
+            insert_sorted_declaration(content, "pub mod ", &format!("pub mod {snake_case};"))?;
+        add_to_use_list(
+            &content,
+            "pub use self::{",
+            &format!("{snake_case}::{pascal_case}"),
+        )
+    })?;
+    edit_file(&root.join("crates/router/src/types/api.rs"), |content| {
+        insert_sorted_in_block(
+            content,
+            "match connector_name {",
+            &format!("\"{snake_case}\" => Ok(Box::new(&connector::{pascal_case})),"),
+        )
+    })?;
+    edit_file(
+        &root.join("crates/router/src/configs/settings.rs"),
+        |content| {
+            insert_sorted_in_block(
+                content,
+                "pub struct Connectors {",
+                &format!("pub {snake_case}: ConnectorParams,"),
+            )
+        },
+    )?;
+    edit_file(&root.join("crates/api_models/src/enums.rs"), |content| {
+        let variant = format!("{pascal_case},");
+        let content = insert_sorted_in_block(content, "pub enum Connector {", &variant)?;
+        insert_sorted_in_block(&content, "pub enum RoutableConnectors {", &variant)
+    })?;
+
+    for config_file in CONFIG_FILES {
+        edit_file(&root.join(config_file), |content| {
+            let content = add_connector_config_section(content, snake_case, base_url)?;
+            insert_sorted_in_block(&content, "cards = [", &format!("\"{snake_case}\","))
+        })?;
+    }
+
+    let tests_dir = root.join(CONNECTOR_TESTS_DIR);
+    edit_file(&tests_dir.join("main.rs"), |content| {
+        insert_sorted_declaration(content, "mod ", &format!("mod {snake_case};"))
+    })?;
+    edit_file(&tests_dir.join("connector_auth.rs"), |content| {
+        insert_sorted_in_block(
+            content,
+            "pub(crate) struct ConnectorAuthentication {",
+            &format!("pub {snake_case}: Option<HeaderKey>,"),
+        )
+    })?;
+    edit_file(&tests_dir.join("sample_auth.toml"), |content| {
+        Ok(format!(
+            "{}\n\n[{snake_case}]\napi_key = \"API Key\"\n",
+            content.trim_end()
+        ))
+    })
+}
+
+/// Replaces the `{{project-name | <filters>}}` placeholders of a template, which are written for
+/// `cargo generate`, with the name of the connector.
+fn render_template(template: &str, name: &ConnectorName) -> AddConnectorResult<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .map(|end| start + end)
+            .ok_or_else(|| AddConnectorError::UnsupportedPlaceholder(rest[start..].to_owned()))?;
+        rendered.push_str(&render_placeholder(&rest[start + 2..end], name)?);
+        rest = &rest[end + 2..];
+    }
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+fn render_placeholder(placeholder: &str, name: &ConnectorName) -> AddConnectorResult<String> {
+    let unsupported = || AddConnectorError::UnsupportedPlaceholder(placeholder.to_owned());
+    let mut parts = placeholder.split('|').map(str::trim);
+    if parts.next() != Some("project-name") {
+        return Err(unsupported());
+    }
+
+    // The name of the connector is already in lower case
+    parts.try_fold(name.snake_case.clone(), |value, filter| match filter {
+        "downcase" => Ok(value),
+        "pascal_case" => Ok(name.pascal_case.clone()),
+        _ => Err(unsupported()),
+    })
+}
+
+/// Inserts `line` in a list of lines which follows the line containing `header`, and ends with an
+/// empty line or a closing bracket, before the first line sorting after it. Attributes and
+/// comments are kept with the line they annotate.
+fn insert_sorted_in_block(content: &str, header: &str, line: &str) -> Result<String, String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|candidate| candidate.contains(header))
+        .ok_or_else(|| format!("`{header}`"))?
+        + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|candidate| {
+            let candidate = candidate.trim();
+            candidate.is_empty() || candidate.starts_with('}') || candidate.starts_with(']')
+        })
+        .map_or(lines.len(), |end| start + end);
+
+    Ok(insert_sorted(&lines, start..end, line))
+}
+
+/// Inserts `line` among the top-level lines starting with `prefix`, such as module declarations,
+/// before the first one sorting after it.
+fn insert_sorted_declaration(content: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|candidate| candidate.starts_with(prefix))
+        .ok_or_else(|| format!("`{prefix}` declarations"))?;
+    let end = lines
+        .iter()
+        .rposition(|candidate| candidate.starts_with(prefix))
+        .map_or(start, |end| end + 1);
+
+    Ok(insert_sorted(&lines, start..end, line))
+}
+
+fn insert_sorted(lines: &[&str], range: std::ops::Range<usize>, line: &str) -> String {
+    let is_item = |candidate: &str| {
+        let candidate = candidate.trim();
+        !(candidate.is_empty() || candidate.starts_with('#') || candidate.starts_with("//"))
+    };
+    let start = range.start;
+    let items = range
+        .filter(|&index| is_item(lines[index]))
+        .collect::<Vec<_>>();
+
+    let (position, neighbour) = match items.iter().find(|&&index| lines[index].trim() > line) {
+        Some(&index) => {
+            // Attributes and comments stay above the line they annotate
+            let mut position = index;
+            while position > 0
+                && !is_item(lines[position - 1])
+                && !lines[position - 1].trim().is_empty()
+            {
+                position -= 1;
+            }
+            (position, index)
+        }
+        None => items
+            .last()
+            .map_or((start, start), |&index| (index + 1, index)),
+    };
+    let indentation = lines
+        .get(neighbour)
+        .map(|neighbour| &neighbour[..neighbour.len() - neighbour.trim_start().len()])
+        .unwrap_or_default();
+
+    let mut content = lines[..position].join("\n");
+    if position > 0 {
+        content.push('\n');
+    }
+    content.push_str(indentation);
+    content.push_str(line);
+    for remaining in &lines[position..] {
+        content.push('\n');
+        content.push_str(remaining);
+    }
+    content.push('\n');
+    content
+}
+
+/// Adds `item` to a `use` list spanning several lines, which is sorted and wrapped as rustfmt
+/// would.
+fn add_to_use_list(content: &str, header: &str, item: &str) -> Result<String, String> {
+    let start = content
+        .find(header)
+        .map(|start| start + header.len())
+        .ok_or_else(|| format!("`{header}`"))?;
+    let end = content[start..]
+        .find("};")
+        .map(|end| start + end)
+        .ok_or_else(|| format!("the end of `{header}`"))?;
+
+    let mut items = content[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
+    items.push(item);
+    items.sort_unstable();
+
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+    for item in items {
+        if !current_line.is_empty() && current_line.len() + item.len() + 2 > MAX_LINE_WIDTH {
+            lines.push(std::mem::take(&mut current_line));
+        }
+        if current_line.is_empty() {
+            current_line.push_str("    ");
+        } else {
+            current_line.push(' ');
+        }
+        current_line.push_str(item);
+        current_line.push(',');
+    }
+    lines.push(current_line);
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        &content[..start],
+        lines.join("\n"),
+        &content[end..]
+    ))
+}
+
+/// Adds the `[connectors.<name>]` section after the last connector section of a configuration
+/// file.
+fn add_connector_config_section(
+    content: &str,
+    name: &str,
+    base_url: &str,
+) -> Result<String, String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let last_section = lines
+        .iter()
+        .rposition(|line| line.starts_with("[connectors.") && *line != "[connectors.supported]")
+        .ok_or_else(|| "`[connectors.<connector>]` sections".to_owned())?;
+    let section_end = lines[last_section..]
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .map_or(lines.len(), |end| last_section + end);
+
+    let mut edited = lines[..section_end].to_vec();
+    let section = format!("[connectors.{name}]");
+    let base_url = format!("base_url = \"{base_url}\"");
+    edited.extend(["", &section, &base_url]);
+    edited.extend(&lines[section_end..]);
+
+    Ok(edited.join("\n") + "\n")
+}
+
+fn edit_file(
+    path: &Path,
+    edit: impl FnOnce(&str) -> Result<String, String>,
+) -> AddConnectorResult<()> {
+    let content = read_file(path)?;
+    let edited = edit(&content).map_err(|anchor| AddConnectorError::AnchorNotFound {
+        path: path.to_path_buf(),
+        anchor,
+    })?;
+    write_file(path, &edited)
+}
+
+fn read_file(path: &Path) -> AddConnectorResult<String> {
+    fs::read_to_string(path).map_err(|source| AddConnectorError::Io {
+        action: "read",
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write_file(path: &Path, content: &str) -> AddConnectorResult<()> {
+    path.parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .and_then(|_| fs::write(path, content))
+        .map_err(|source| AddConnectorError::Io {
+            action: "write",
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+/// The templates aren't formatted, so the generated files are formatted when rustfmt is available.
+fn format_files<'a>(paths: impl Iterator<Item = &'a Path>) {
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(paths)
+        .status();
+    if !matches!(status, Ok(status) if status.success()) {
+        eprintln!("warning: failed to format the generated files, run `cargo fmt` to format them");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_connector_name() {
+        let name = ConnectorName::new("afterpay_clearpay").unwrap();
+        assert_eq!(name.snake_case, "afterpay_clearpay");
+        assert_eq!(name.pascal_case, "AfterpayClearpay");
+        assert_eq!(ConnectorName::new("shift4").unwrap().pascal_case, "Shift4");
+
+        for invalid_name in ["", "Stripe", "2checkout", "pay-u", "pay__u", "payu_"] {
+            assert!(matches!(
+                ConnectorName::new(invalid_name),
+                Err(AddConnectorError::InvalidName(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let name = ConnectorName::new("afterpay_clearpay").unwrap();
+        let rendered = render_template(
+            "pub struct {{project-name | downcase | pascal_case}};\n\
+             connectors.{{project-name}}.base_url; \"{{project-name| downcase}}\"",
+            &name,
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "pub struct AfterpayClearpay;\n\
+             connectors.afterpay_clearpay.base_url; \"afterpay_clearpay\""
+        );
+
+        assert!(matches!(
+            render_template("{{crate_name}}", &name),
+            Err(AddConnectorError::UnsupportedPlaceholder(_))
+        ));
+        assert!(matches!(
+            render_template("{{project-name | upcase}}", &name),
+            Err(AddConnectorError::UnsupportedPlaceholder(_))
+        ));
+    }
+
+    #[test]
+    fn test_insert_sorted_in_block() {
+        let content = "pub struct Connectors {\n    pub aci: ConnectorParams,\n    #[cfg(feature = \"dummy_connector\")]\n    pub dummy: ConnectorParams,\n    pub fiserv: ConnectorParams,\n\n    pub supported: SupportedConnectors,\n}\n";
+
+        assert_eq!(
+            insert_sorted_in_block(content, "pub struct Connectors {", "pub dlocal: ConnectorParams,")
+                .unwrap(),
+            "pub struct Connectors {\n    pub aci: ConnectorParams,\n    pub dlocal: ConnectorParams,\n    #[cfg(feature = \"dummy_connector\")]\n    pub dummy: ConnectorParams,\n    pub fiserv: ConnectorParams,\n\n    pub supported: SupportedConnectors,\n}\n"
+        );
+        assert_eq!(
+            insert_sorted_in_block(content, "pub struct Connectors {", "pub zen: ConnectorParams,")
+                .unwrap(),
+            "pub struct Connectors {\n    pub aci: ConnectorParams,\n    #[cfg(feature = \"dummy_connector\")]\n    pub dummy: ConnectorParams,\n    pub fiserv: ConnectorParams,\n    pub zen: ConnectorParams,\n\n    pub supported: SupportedConnectors,\n}\n"
+        );
+        assert!(insert_sorted_in_block(content, "pub enum Connector {", "Zen,").is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted_declaration() {
+        let content = "pub mod aci;\n#[cfg(feature = \"dummy_connector\")]\npub mod dummy;\npub mod stripe;\n\npub use self::aci::Aci;\n";
+
+        assert_eq!(
+            insert_sorted_declaration(content, "pub mod ", "pub mod dlocal;").unwrap(),
+            "pub mod aci;\npub mod dlocal;\n#[cfg(feature = \"dummy_connector\")]\npub mod dummy;\npub mod stripe;\n\npub use self::aci::Aci;\n"
+        );
+        assert_eq!(
+            insert_sorted_declaration(content, "pub mod ", "pub mod zen;").unwrap(),
+            "pub mod aci;\n#[cfg(feature = \"dummy_connector\")]\npub mod dummy;\npub mod stripe;\npub mod zen;\n\npub use self::aci::Aci;\n"
+        );
+    }
+
+    #[test]
+    fn test_add_to_use_list() {
+        let content = "pub use self::{\n    aci::Aci, adyen::Adyen, affirm::Affirm, afterpay_clearpay::AfterpayClearpay,\n    airwallex::Airwallex, stripe::Stripe,\n};\n";
+
+        assert_eq!(
+            add_to_use_list(content, "pub use self::{", "dlocal::Dlocal").unwrap(),
+            "pub use self::{\n    aci::Aci, adyen::Adyen, affirm::Affirm, afterpay_clearpay::AfterpayClearpay,\n    airwallex::Airwallex, dlocal::Dlocal, stripe::Stripe,\n};\n"
+        );
+    }
+
+    #[test]
+    fn test_add_connector_config_section() {
+        let content = "[connectors.aci]\nbase_url = \"https://eu-test.oppwa.com/\"\n\n[connectors.supported]\nwallets = [\"klarna\"]\n";
+
+        assert_eq!(
+            add_connector_config_section(content, "dlocal", "https://sandbox.dlocal.com/")
+                .unwrap(),
+            "[connectors.aci]\nbase_url = \"https://eu-test.oppwa.com/\"\n\n[connectors.dlocal]\nbase_url = \"https://sandbox.dlocal.com/\"\n\n[connectors.supported]\nwallets = [\"klarna\"]\n"
+        );
+    }
+}
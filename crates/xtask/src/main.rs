@@ -0,0 +1,40 @@
+//! Development tasks of the repository, run with `cargo xtask <task>` from anywhere in the
+//! workspace.
+
+mod add_connector;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+#[derive(clap::Parser)]
+#[command(bin_name = "cargo xtask")]
+enum Task {
+    /// Scaffold a new connector: its module and transformers, its tests and its entries in the
+    /// connector enums, settings and configuration files.
+    AddConnector(add_connector::AddConnectorArgs),
+}
+
+fn main() -> ExitCode {
+    let result = match <Task as clap::Parser>::parse() {
+        Task::AddConnector(args) => add_connector::run(&workspace_root(), &args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The crate lives in `crates/xtask` of the workspace.
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
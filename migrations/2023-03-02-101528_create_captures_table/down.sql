@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX captures_merchant_id_payment_id_authorized_attempt_id_index;
+
+DROP TABLE captures;
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE captures (
+    capture_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    error_message TEXT,
+    error_code VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    authorized_attempt_id VARCHAR(64) NOT NULL,
+    connector_capture_id VARCHAR(128),
+    capture_sequence SMALLINT NOT NULL
+);
+
+CREATE INDEX captures_merchant_id_payment_id_authorized_attempt_id_index ON captures (
+    merchant_id,
+    payment_id,
+    authorized_attempt_id
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payouts_merchant_id_payout_id_index;
+
+DROP TABLE payouts;
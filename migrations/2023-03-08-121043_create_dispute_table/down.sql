@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX dispute_merchant_id_payment_id_connector_dispute_id_index;
+
+DROP INDEX dispute_merchant_id_dispute_id_index;
+
+DROP TABLE dispute;
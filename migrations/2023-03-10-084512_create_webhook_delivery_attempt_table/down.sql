@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX webhook_delivery_attempt_event_id_index;
+
+DROP TABLE webhook_delivery_attempt;
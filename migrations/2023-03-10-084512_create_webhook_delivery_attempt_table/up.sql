@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE webhook_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    attempt_number INTEGER NOT NULL,
+    delivery_status VARCHAR(32) NOT NULL,
+    request_body JSONB NOT NULL,
+    response_status_code INTEGER,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX webhook_delivery_attempt_event_id_index ON webhook_delivery_attempt (event_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN capture_on TIMESTAMP DEFAULT NULL,
+ADD COLUMN capture_expires_at TIMESTAMP DEFAULT NULL;
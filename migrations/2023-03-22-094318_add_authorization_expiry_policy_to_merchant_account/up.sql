@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account ADD COLUMN authorization_expiry_policy JSON;
+
+ALTER TYPE "EventType" ADD VALUE 'payment_authorization_expiring';
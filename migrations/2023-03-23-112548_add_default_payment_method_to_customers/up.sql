@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN default_payment_method_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX locker_card_merchant_id_customer_id_card_fingerprint_index;
+
+DROP INDEX locker_card_card_id_index;
+
+DROP TABLE locker_card;
+
+DROP INDEX merchant_key_store_merchant_id_index;
+
+DROP TABLE merchant_key_store;
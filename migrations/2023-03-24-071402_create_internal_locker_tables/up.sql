@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE merchant_key_store (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX merchant_key_store_merchant_id_index ON merchant_key_store (merchant_id);
+
+CREATE TABLE locker_card (
+    id SERIAL PRIMARY KEY,
+    card_id VARCHAR(64) NOT NULL,
+    external_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    card_fingerprint VARCHAR(64) NOT NULL,
+    card_global_fingerprint VARCHAR(64) NOT NULL,
+    card_data BYTEA NOT NULL,
+    nickname VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX locker_card_card_id_index ON locker_card (card_id);
+
+CREATE INDEX locker_card_merchant_id_customer_id_card_fingerprint_index ON locker_card (merchant_id, customer_id, card_fingerprint);
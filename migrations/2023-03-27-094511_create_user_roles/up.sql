@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE user_roles (
+    id SERIAL PRIMARY KEY,
+    user_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    role VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX user_roles_user_id_merchant_id_index ON user_roles (user_id, merchant_id);
+
+ALTER TABLE api_keys ADD COLUMN role VARCHAR(64) NOT NULL DEFAULT 'admin';
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN profile_id;
+
+DROP TABLE business_profile;
+
+DROP INDEX merchant_account_organization_id_index;
+
+ALTER TABLE merchant_account DROP COLUMN organization_id;
+
+DROP TABLE organization;
@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE organization (
+    org_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    org_name VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+ALTER TABLE merchant_account ADD COLUMN organization_id VARCHAR(64);
+
+CREATE INDEX merchant_account_organization_id_index ON merchant_account (organization_id);
+
+CREATE TABLE business_profile (
+    profile_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_name VARCHAR(64) NOT NULL,
+    business_country VARCHAR(2),
+    business_label VARCHAR(64),
+    webhook_details JSON,
+    routing_algorithm JSON,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX business_profile_merchant_id_profile_name_index ON business_profile (merchant_id, profile_name);
+
+ALTER TABLE payment_intent ADD COLUMN profile_id VARCHAR(64);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN deleted_at TIMESTAMP;
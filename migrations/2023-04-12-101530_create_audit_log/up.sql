@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE audit_log (
+    id SERIAL PRIMARY KEY,
+    audit_log_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(64),
+    http_method VARCHAR(16),
+    route VARCHAR(255),
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    previous_status VARCHAR(64),
+    new_status VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX audit_log_audit_log_id_index ON audit_log (audit_log_id);
+
+CREATE INDEX audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE 'mandates';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'mandate_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_failed';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_capture_succeeded';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_capture_failed';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_authorization_expired';
+
+ALTER TYPE "EventType" ADD VALUE 'refund_updated';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_expired';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_accepted';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_cancelled';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_challenged';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_active';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_revoked';
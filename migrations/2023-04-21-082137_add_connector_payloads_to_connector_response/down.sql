@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE connector_response DROP COLUMN IF EXISTS connector_request_payload;
+ALTER TABLE connector_response DROP COLUMN IF EXISTS connector_response_payload;
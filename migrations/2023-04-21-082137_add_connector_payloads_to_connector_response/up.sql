@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE connector_response ADD COLUMN IF NOT EXISTS connector_request_payload JSON;
+ALTER TABLE connector_response ADD COLUMN IF NOT EXISTS connector_response_payload JSON;
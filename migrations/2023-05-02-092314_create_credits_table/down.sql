@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX credits_merchant_id_credit_id_index;
+
+DROP TABLE credits;
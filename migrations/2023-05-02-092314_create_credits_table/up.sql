@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE credits (
+    id SERIAL PRIMARY KEY,
+    credit_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    connector_credit_id VARCHAR(128),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status "RefundStatus" NOT NULL,
+    reason VARCHAR(255),
+    metadata JSONB,
+    error_message TEXT,
+    error_code VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX credits_merchant_id_credit_id_index ON credits (merchant_id, credit_id);
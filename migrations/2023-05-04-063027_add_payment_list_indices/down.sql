@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_created_at_index;
+
+DROP INDEX IF EXISTS payment_intent_merchant_id_amount_index;
+
+DROP INDEX IF EXISTS payment_intent_merchant_id_status_index;
+
+DROP INDEX IF EXISTS payment_intent_merchant_id_customer_id_index;
+
+DROP INDEX IF EXISTS payment_attempt_merchant_id_connector_index;
+
+DROP INDEX IF EXISTS payment_attempt_merchant_id_payment_method_index;
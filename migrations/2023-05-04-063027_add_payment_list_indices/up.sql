@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_created_at_index ON payment_intent (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_amount_index ON payment_intent (merchant_id, amount);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_status_index ON payment_intent (merchant_id, status);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_customer_id_index ON payment_intent (merchant_id, customer_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_connector_index ON payment_attempt (merchant_id, connector);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_payment_method_index ON payment_attempt (merchant_id, payment_method);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX recon_record_merchant_id_report_id_index;
+
+DROP TABLE recon_record;
+
+DROP INDEX recon_report_merchant_id_created_at_index;
+
+DROP INDEX recon_report_merchant_id_report_id_index;
+
+DROP TABLE recon_report;
@@ -0,0 +1,44 @@
+-- Your SQL goes here
+CREATE TABLE recon_report (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    source VARCHAR(16) NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    file_name VARCHAR(255),
+    total_records INTEGER NOT NULL DEFAULT 0,
+    matched_records INTEGER NOT NULL DEFAULT 0,
+    mismatched_records INTEGER NOT NULL DEFAULT 0,
+    missing_records INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX recon_report_merchant_id_report_id_index ON recon_report (merchant_id, report_id);
+
+CREATE INDEX recon_report_merchant_id_created_at_index ON recon_report (merchant_id, created_at DESC);
+
+CREATE TABLE recon_record (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    record_type VARCHAR(16) NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency VARCHAR(8) NOT NULL,
+    settlement_status VARCHAR(64),
+    recon_status VARCHAR(32) NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    expected_amount BIGINT,
+    expected_currency VARCHAR(8),
+    mismatch_reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX recon_record_merchant_id_report_id_index ON recon_record (merchant_id, report_id, line_number);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account ADD COLUMN IF NOT EXISTS fraud_check_policy JSON;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS fraud_check JSONB;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS velocity_policy;
+
+DROP INDEX blocklist_merchant_id_fingerprint_id_index;
+
+DROP TABLE blocklist;
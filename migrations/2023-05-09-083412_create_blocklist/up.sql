@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE blocklist (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    fingerprint_id VARCHAR(255) NOT NULL,
+    data_kind VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX blocklist_merchant_id_fingerprint_id_index ON blocklist (merchant_id, fingerprint_id);
+
+ALTER TABLE merchant_account ADD COLUMN IF NOT EXISTS velocity_policy JSON;
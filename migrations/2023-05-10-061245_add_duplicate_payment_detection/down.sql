@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS duplicate_of;
+
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS duplicate_payment_policy;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account ADD COLUMN IF NOT EXISTS duplicate_payment_policy JSON;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS duplicate_of VARCHAR(64);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS cvv_result;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS avs_result;
+
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS card_verification_policy;
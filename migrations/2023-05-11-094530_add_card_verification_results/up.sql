@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account ADD COLUMN IF NOT EXISTS card_verification_policy JSON;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS avs_result VARCHAR(32);
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS cvv_result VARCHAR(32);